@@ -302,6 +302,8 @@ fn boundary_analysis_and_selectivity_demo() -> Result<()> {
         min_value: Precision::Exact(ScalarValue::Int64(Some(1))),
         sum_value: Precision::Absent,
         distinct_count: Precision::Absent,
+        histogram: None,
+        most_common_values: None,
     };
 
     // We can then build our expression boundaries from the column statistics
@@ -369,6 +371,8 @@ fn boundary_analysis_in_conjuctions_demo() -> Result<()> {
         min_value: Precision::Exact(ScalarValue::Int64(Some(14))),
         sum_value: Precision::Absent,
         distinct_count: Precision::Absent,
+        histogram: None,
+        most_common_values: None,
     };
 
     let initial_boundaries =
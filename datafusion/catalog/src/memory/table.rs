@@ -18,28 +18,41 @@
 //! [`MemTable`] for querying `Vec<RecordBatch>` by DataFusion.
 
 use std::any::Any;
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
-use std::fmt::Debug;
+use std::fmt::{self, Debug};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 
 use crate::TableProvider;
 
-use arrow::datatypes::SchemaRef;
+use arrow::array::{AsArray, BooleanArray};
+use arrow::compute::kernels::zip::zip;
+use arrow::compute::{filter_record_batch, not, prep_null_mask_filter};
+use arrow::datatypes::{DataType, Field, Schema, SchemaRef, UInt32Type, UInt64Type};
 use arrow::record_batch::RecordBatch;
 use datafusion_common::error::Result;
-use datafusion_common::{not_impl_err, plan_err, Constraints, DFSchema, SchemaExt};
+use datafusion_common::{
+    exec_err, internal_err, not_impl_err, plan_err, Constraints, DFSchema, SchemaExt,
+    Statistics,
+};
 use datafusion_common_runtime::JoinSet;
 use datafusion_datasource::memory::{MemSink, MemorySourceConfig};
-use datafusion_datasource::sink::DataSinkExec;
+use datafusion_datasource::sink::{DataSink, DataSinkExec};
 use datafusion_datasource::source::DataSourceExec;
-use datafusion_expr::dml::InsertOp;
+use datafusion_execution::{SendableRecordBatchStream, TaskContext};
+use datafusion_expr::dml::{
+    InsertOp, MergeAction, MergeOp, MERGE_CLAUSE_COLUMN, MERGE_TARGET_ROW_COLUMN,
+};
+use datafusion_expr::utils::conjunction;
 use datafusion_expr::{Expr, SortExpr, TableType};
-use datafusion_physical_expr::{create_physical_sort_exprs, LexOrdering};
+use datafusion_physical_expr::{create_physical_sort_exprs, LexOrdering, PhysicalExpr};
 use datafusion_physical_plan::repartition::RepartitionExec;
 use datafusion_physical_plan::{
-    common, ExecutionPlan, ExecutionPlanProperties, Partitioning,
+    common, DisplayAs, DisplayFormatType, ExecutionPlan, ExecutionPlanProperties,
+    Partitioning,
 };
-use datafusion_session::Session;
+use datafusion_session::{Session, TransactionParticipant};
 
 use async_trait::async_trait;
 use futures::StreamExt;
@@ -54,8 +67,19 @@ pub use datafusion_datasource::memory::PartitionData;
 /// data source that can be queried by DataFusion. This allows data to
 /// be pre-loaded into memory and then repeatedly queried without
 /// incurring additional file I/O overhead.
+///
+/// Within a [`Transaction`], the first access to the table takes a snapshot of
+/// its content and the statements of the transaction read and write that
+/// snapshot, which replaces the content of the table when the transaction is
+/// committed. Committing a transaction that wrote to the table fails if the
+/// content of the table was changed since the snapshot was taken, for example
+/// by a transaction of another session committed in the meantime.
+///
+/// [`Transaction`]: datafusion_session::Transaction
 #[derive(Debug)]
 pub struct MemTable {
+    /// The unique id of this table, identifying it in transactions
+    id: usize,
     schema: SchemaRef,
     // batches used to be pub(crate), but it's needed to be public for the tests
     pub batches: Vec<PartitionData>,
@@ -64,6 +88,9 @@ pub struct MemTable {
     /// Optional pre-known sort order(s). Must be `SortExpr`s.
     /// inserting data into this table removes the order
     pub sort_order: Arc<Mutex<Vec<Vec<SortExpr>>>>,
+    /// Statistics computed by `ANALYZE TABLE`, which are removed when
+    /// data is written to this table
+    statistics: Mutex<Option<Statistics>>,
 }
 
 impl MemTable {
@@ -89,6 +116,7 @@ impl MemTable {
         }
 
         Ok(Self {
+            id: Self::new_unique_id(),
             schema,
             batches: partitions
                 .into_iter()
@@ -97,9 +125,15 @@ impl MemTable {
             constraints: Constraints::default(),
             column_defaults: HashMap::new(),
             sort_order: Arc::new(Mutex::new(vec![])),
+            statistics: Mutex::new(None),
         })
     }
 
+    fn new_unique_id() -> usize {
+        static ID: AtomicUsize = AtomicUsize::new(0);
+        ID.fetch_add(1, Ordering::Relaxed)
+    }
+
     /// Assign constraints
     pub fn with_constraints(mut self, constraints: Constraints) -> Self {
         self.constraints = constraints;
@@ -200,6 +234,84 @@ impl MemTable {
         }
         MemTable::try_new(Arc::clone(&schema), data)
     }
+
+    /// Returns the partitions read by `state`: the snapshot of the current
+    /// transaction if there is one, the content of the table otherwise
+    async fn read_partitions(&self, state: &dyn Session) -> Result<Vec<PartitionData>> {
+        self.partitions(state, false).await
+    }
+
+    /// Returns the partitions written by `state`: the snapshot of the current
+    /// transaction if there is one, the content of the table otherwise
+    async fn write_partitions(&self, state: &dyn Session) -> Result<Vec<PartitionData>> {
+        self.partitions(state, true).await
+    }
+
+    /// Returns the partitions accessed by `state`, enlisting this table with a
+    /// snapshot of its content in the current transaction if there is one
+    async fn partitions(
+        &self,
+        state: &dyn Session,
+        write: bool,
+    ) -> Result<Vec<PartitionData>> {
+        let Some(transaction) = state.transaction() else {
+            return Ok(self.batches.clone());
+        };
+        let participant = match transaction.participant(self.id) {
+            Some(participant) => participant,
+            None => {
+                let mut snapshot = Vec::with_capacity(self.batches.len());
+                for partition in &self.batches {
+                    snapshot.push(partition.read().await.clone());
+                }
+                transaction.enlist(
+                    self.id,
+                    Arc::new(MemTransactionParticipant::new(
+                        self.batches.clone(),
+                        snapshot,
+                    )),
+                )
+            }
+        };
+        let Some(participant) = participant
+            .as_any()
+            .downcast_ref::<MemTransactionParticipant>()
+        else {
+            return internal_err!(
+                "Expected MemTable transaction participant, got {participant:?}"
+            );
+        };
+        if write {
+            participant.written.store(true, Ordering::Relaxed);
+        }
+        Ok(participant.batches.clone())
+    }
+
+    /// Returns a plan rewriting the content of this table with `kind`
+    /// applied to the rows matching all of `filters`
+    async fn dml_plan(
+        &self,
+        state: &dyn Session,
+        kind: DmlKind,
+        filters: Vec<Expr>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        // The rows are rewritten, so any sort order or statistics may be messed up
+        *self.sort_order.lock() = vec![];
+        *self.statistics.lock() = None;
+
+        let df_schema = DFSchema::try_from(self.schema.as_ref().clone())?;
+        let predicate = conjunction(filters)
+            .map(|filter| state.create_physical_expr(filter, &df_schema))
+            .transpose()?;
+        let input = self.scan(state, None, &[], None).await?;
+        let sink = MemDmlSink::try_new(
+            self.write_partitions(state).await?,
+            Arc::clone(&self.schema),
+            kind,
+            predicate,
+        )?;
+        Ok(Arc::new(DataSinkExec::new(input, Arc::new(sink), None)))
+    }
 }
 
 #[async_trait]
@@ -220,6 +332,22 @@ impl TableProvider for MemTable {
         TableType::Base
     }
 
+    fn statistics(&self) -> Option<Statistics> {
+        self.statistics.lock().clone()
+    }
+
+    fn set_statistics(&self, statistics: Statistics) -> Result<()> {
+        if statistics.column_statistics.len() != self.schema.fields().len() {
+            return plan_err!(
+                "Expected statistics for {} columns, got {}",
+                self.schema.fields().len(),
+                statistics.column_statistics.len()
+            );
+        }
+        *self.statistics.lock() = Some(statistics);
+        Ok(())
+    }
+
     async fn scan(
         &self,
         state: &dyn Session,
@@ -228,7 +356,7 @@ impl TableProvider for MemTable {
         _limit: Option<usize>,
     ) -> Result<Arc<dyn ExecutionPlan>> {
         let mut partitions = vec![];
-        for arc_inner_vec in self.batches.iter() {
+        for arc_inner_vec in self.read_partitions(state).await?.iter() {
             let inner_vec = arc_inner_vec.read().await;
             partitions.push(inner_vec.clone())
         }
@@ -237,7 +365,9 @@ impl TableProvider for MemTable {
             MemorySourceConfig::try_new(&partitions, self.schema(), projection.cloned())?;
 
         let show_sizes = state.config_options().explain.show_sizes;
-        source = source.with_show_sizes(show_sizes);
+        source = source
+            .with_show_sizes(show_sizes)
+            .with_table_statistics(self.statistics.lock().clone());
 
         // add sort information if present
         let sort_order = self.sort_order.lock();
@@ -273,12 +403,13 @@ impl TableProvider for MemTable {
     /// [`SessionState`]: https://docs.rs/datafusion/latest/datafusion/execution/session_state/struct.SessionState.html
     async fn insert_into(
         &self,
-        _state: &dyn Session,
+        state: &dyn Session,
         input: Arc<dyn ExecutionPlan>,
         insert_op: InsertOp,
     ) -> Result<Arc<dyn ExecutionPlan>> {
-        // If we are inserting into the table, any sort order may be messed up so reset it here
+        // If we are inserting into the table, any sort order or statistics may be messed up so reset them here
         *self.sort_order.lock() = vec![];
+        *self.statistics.lock() = None;
 
         // Create a physical plan from the logical plan.
         // Check that the schema of the plan matches the schema of this table.
@@ -288,11 +419,466 @@ impl TableProvider for MemTable {
         if insert_op != InsertOp::Append {
             return not_impl_err!("{insert_op} not implemented for MemoryTable yet");
         }
-        let sink = MemSink::try_new(self.batches.clone(), Arc::clone(&self.schema))?;
+        let sink = MemSink::try_new(
+            self.write_partitions(state).await?,
+            Arc::clone(&self.schema),
+        )?;
+        Ok(Arc::new(DataSinkExec::new(input, Arc::new(sink), None)))
+    }
+
+    /// Returns an ExecutionPlan that applies a `MERGE INTO` statement to this [`MemTable`].
+    ///
+    /// The content of the table is replaced by the rows of `input` that are
+    /// not deleted by `merge_op`. The merge fails if a clause applies to a
+    /// target row matched by several source rows.
+    async fn merge_into(
+        &self,
+        state: &dyn Session,
+        input: Arc<dyn ExecutionPlan>,
+        merge_op: &MergeOp,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        // The rows are rewritten, so any sort order or statistics may be messed up
+        *self.sort_order.lock() = vec![];
+        *self.statistics.lock() = None;
+
+        let input_schema = input.schema();
+        let Some((clause_field, fields)) = input_schema.fields().split_last() else {
+            return plan_err!("MERGE input must not be empty");
+        };
+        let Some((row_field, table_fields)) = fields.split_last() else {
+            return plan_err!("MERGE input must contain the target row number");
+        };
+        if clause_field.name() != MERGE_CLAUSE_COLUMN
+            || clause_field.data_type() != &DataType::UInt32
+        {
+            return plan_err!(
+                "Expected MERGE input to end with a UInt32 {MERGE_CLAUSE_COLUMN} column, got {clause_field}"
+            );
+        }
+        if row_field.name() != MERGE_TARGET_ROW_COLUMN
+            || row_field.data_type() != &DataType::UInt64
+        {
+            return plan_err!(
+                "Expected a UInt64 {MERGE_TARGET_ROW_COLUMN} column before the {MERGE_CLAUSE_COLUMN} column of the MERGE input, got {row_field}"
+            );
+        }
+        self.schema()
+            .logically_equivalent_names_and_types(&Schema::new(table_fields.to_vec()))?;
+
+        let deleted_clauses = merge_op
+            .clauses
+            .iter()
+            .map(|clause| matches!(clause.action, MergeAction::Delete))
+            .collect();
+        let sink = MemMergeSink::try_new(
+            self.write_partitions(state).await?,
+            self.schema(),
+            deleted_clauses,
+        )?;
         Ok(Arc::new(DataSinkExec::new(input, Arc::new(sink), None)))
     }
 
+    /// Returns an ExecutionPlan that deletes the rows of this [`MemTable`]
+    /// matching all of `filters`.
+    async fn delete_from(
+        &self,
+        state: &dyn Session,
+        filters: Vec<Expr>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        self.dml_plan(state, DmlKind::Delete, filters).await
+    }
+
+    /// Returns an ExecutionPlan that applies `assignments` to the rows of
+    /// this [`MemTable`] matching all of `filters`.
+    async fn update(
+        &self,
+        state: &dyn Session,
+        assignments: Vec<(String, Expr)>,
+        filters: Vec<Expr>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let df_schema = DFSchema::try_from(self.schema.as_ref().clone())?;
+        let assignments = assignments
+            .into_iter()
+            .map(|(name, expr)| {
+                let index = self.schema.index_of(&name)?;
+                let expr = state.create_physical_expr(expr, &df_schema)?;
+                let field = self.schema.field(index);
+                let data_type = expr.data_type(&self.schema)?;
+                if &data_type != field.data_type() {
+                    return plan_err!(
+                        "Cannot assign a value of type {data_type} to column {name} of type {}",
+                        field.data_type()
+                    );
+                }
+                Ok((index, expr))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.dml_plan(state, DmlKind::Update(assignments), filters)
+            .await
+    }
+
     fn get_column_default(&self, column: &str) -> Option<&Expr> {
         self.column_defaults.get(column)
     }
 }
+
+/// The content of a [`MemTable`] as seen and written by a transaction, which
+/// replaces the content of the table on commit
+#[derive(Debug)]
+struct MemTransactionParticipant {
+    /// The partitions of the table
+    target: Vec<PartitionData>,
+    /// The content of the table when the transaction first accessed it
+    snapshot: Vec<Vec<RecordBatch>>,
+    /// The partitions as written by the transaction
+    batches: Vec<PartitionData>,
+    /// Whether the transaction wrote to the table
+    written: AtomicBool,
+}
+
+impl MemTransactionParticipant {
+    fn new(target: Vec<PartitionData>, snapshot: Vec<Vec<RecordBatch>>) -> Self {
+        let batches = snapshot
+            .iter()
+            .map(|partition| Arc::new(RwLock::new(partition.clone())))
+            .collect();
+        Self {
+            target,
+            snapshot,
+            batches,
+            written: AtomicBool::new(false),
+        }
+    }
+
+    /// Errors if the content of a partition of the table is not the one of
+    /// the snapshot anymore
+    fn check_unchanged(&self, partition: usize, content: &[RecordBatch]) -> Result<()> {
+        let snapshot = &self.snapshot[partition];
+        let unchanged = snapshot.len() == content.len()
+            && snapshot.iter().zip(content).all(|(snapshot, batch)| {
+                let same_columns = snapshot
+                    .columns()
+                    .iter()
+                    .zip(batch.columns())
+                    .all(|(a, b)| Arc::ptr_eq(a, b));
+                same_columns || snapshot == batch
+            });
+        if !unchanged {
+            return exec_err!(
+                "Cannot commit the transaction: the table was changed by a \
+                 concurrent transaction since it was first accessed"
+            );
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TransactionParticipant for MemTransactionParticipant {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    async fn prepare(&self) -> Result<()> {
+        if !self.written.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        for (partition, target) in self.target.iter().enumerate() {
+            self.check_unchanged(partition, &target.read().await)?;
+        }
+        Ok(())
+    }
+
+    async fn commit(&self) -> Result<()> {
+        if !self.written.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+        // Lock all the partitions so that no other write can interleave
+        // between the check and the replacement of the content
+        let mut targets = Vec::with_capacity(self.target.len());
+        for target in &self.target {
+            targets.push(target.write().await);
+        }
+        for (partition, target) in targets.iter().enumerate() {
+            self.check_unchanged(partition, target)?;
+        }
+        for (target, batches) in targets.iter_mut().zip(self.batches.iter()) {
+            **target = batches.read().await.clone();
+        }
+        Ok(())
+    }
+}
+
+/// The change applied by a [`MemDmlSink`] to the selected rows
+enum DmlKind {
+    Delete,
+    /// The index of the updated columns and the expression computing their
+    /// new value
+    Update(Vec<(usize, Arc<dyn PhysicalExpr>)>),
+}
+
+/// Replaces the content of a [`MemTable`] with the result of a `DELETE` or
+/// `UPDATE` statement applied to its previous content.
+struct MemDmlSink {
+    /// Target locations for writing data
+    batches: Vec<PartitionData>,
+    /// The schema of the table
+    schema: SchemaRef,
+    /// The change applied to the selected rows
+    kind: DmlKind,
+    /// The predicate selecting the rows, all rows are selected if `None`
+    predicate: Option<Arc<dyn PhysicalExpr>>,
+}
+
+impl MemDmlSink {
+    fn try_new(
+        batches: Vec<PartitionData>,
+        schema: SchemaRef,
+        kind: DmlKind,
+        predicate: Option<Arc<dyn PhysicalExpr>>,
+    ) -> Result<Self> {
+        if batches.is_empty() {
+            return plan_err!("Cannot modify MemTable with zero partitions");
+        }
+        Ok(Self {
+            batches,
+            schema,
+            kind,
+            predicate,
+        })
+    }
+
+    /// Applies the change to the rows of `batch` selected by the predicate,
+    /// returning the resulting batch and the number of changed rows
+    fn apply(&self, batch: RecordBatch) -> Result<(RecordBatch, usize)> {
+        let num_rows = batch.num_rows();
+        let selected = match &self.predicate {
+            Some(predicate) => {
+                let selected = predicate.evaluate(&batch)?.into_array(num_rows)?;
+                prep_null_mask_filter(selected.as_boolean())
+            }
+            None => BooleanArray::from(vec![true; num_rows]),
+        };
+        let count = selected.true_count();
+
+        let batch = match &self.kind {
+            DmlKind::Delete => filter_record_batch(&batch, &not(&selected)?)?,
+            DmlKind::Update(assignments) => {
+                let mut columns = batch.columns().to_vec();
+                for (index, expr) in assignments {
+                    let values = expr
+                        .evaluate_selection(&batch, &selected)?
+                        .into_array(num_rows)?;
+                    columns[*index] = zip(&selected, &values, &columns[*index])?;
+                }
+                // checks the NOT NULL constraints on the updated rows
+                RecordBatch::try_new(Arc::clone(&self.schema), columns)?
+            }
+        };
+        Ok((batch, count))
+    }
+}
+
+impl Debug for MemDmlSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemDmlSink")
+            .field("num_partitions", &self.batches.len())
+            .finish()
+    }
+}
+
+impl DisplayAs for MemDmlSink {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                let partition_count = self.batches.len();
+                let op = match self.kind {
+                    DmlKind::Delete => "delete",
+                    DmlKind::Update(_) => "update",
+                };
+                write!(f, "MemoryTable {op} (partitions={partition_count})")
+            }
+            DisplayFormatType::TreeRender => {
+                write!(f, "")
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl DataSink for MemDmlSink {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> &SchemaRef {
+        &self.schema
+    }
+
+    async fn write_all(
+        &self,
+        mut data: SendableRecordBatchStream,
+        _context: &Arc<TaskContext>,
+    ) -> Result<u64> {
+        let num_partitions = self.batches.len();
+
+        // buffer up the rows remaining after the change round robin style
+        let mut new_batches = vec![vec![]; num_partitions];
+        let mut i = 0;
+        let mut row_count = 0;
+        while let Some(batch) = data.next().await.transpose()? {
+            let (batch, count) = self.apply(batch)?;
+            row_count += count;
+            new_batches[i].push(batch);
+            i = (i + 1) % num_partitions;
+        }
+
+        // replace the previous content of the table
+        for (target, batches) in self.batches.iter().zip(new_batches.into_iter()) {
+            *target.write().await = batches;
+        }
+
+        Ok(row_count as u64)
+    }
+}
+
+/// Replaces the content of a [`MemTable`] with the result of a `MERGE INTO`
+/// statement, see [`MergeOp`] for the format of the input.
+struct MemMergeSink {
+    /// Target locations for writing data
+    batches: Vec<PartitionData>,
+    /// The schema of the table
+    schema: SchemaRef,
+    /// The schema of the input, the table columns followed by the target row
+    /// number and the clause index
+    input_schema: SchemaRef,
+    /// Whether the clause at each index deletes its rows
+    deleted_clauses: Vec<bool>,
+}
+
+impl MemMergeSink {
+    fn try_new(
+        batches: Vec<PartitionData>,
+        schema: SchemaRef,
+        deleted_clauses: Vec<bool>,
+    ) -> Result<Self> {
+        if batches.is_empty() {
+            return plan_err!("Cannot merge into MemTable with zero partitions");
+        }
+        // Keep the nullability of the table, so that the NOT NULL constraints
+        // are checked on the merged rows
+        let fields = schema
+            .fields()
+            .iter()
+            .cloned()
+            .chain([
+                Arc::new(Field::new(MERGE_TARGET_ROW_COLUMN, DataType::UInt64, true)),
+                Arc::new(Field::new(MERGE_CLAUSE_COLUMN, DataType::UInt32, true)),
+            ])
+            .collect::<Vec<_>>();
+        let input_schema = Arc::new(Schema::new(fields));
+        Ok(Self {
+            batches,
+            schema,
+            input_schema,
+            deleted_clauses,
+        })
+    }
+}
+
+impl Debug for MemMergeSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemMergeSink")
+            .field("num_partitions", &self.batches.len())
+            .finish()
+    }
+}
+
+impl DisplayAs for MemMergeSink {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                let partition_count = self.batches.len();
+                write!(f, "MemoryTable merge (partitions={partition_count})")
+            }
+            DisplayFormatType::TreeRender => {
+                write!(f, "")
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl DataSink for MemMergeSink {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> &SchemaRef {
+        &self.input_schema
+    }
+
+    async fn write_all(
+        &self,
+        mut data: SendableRecordBatchStream,
+        _context: &Arc<TaskContext>,
+    ) -> Result<u64> {
+        let num_partitions = self.batches.len();
+        let num_columns = self.schema.fields().len();
+        let table_columns = (0..num_columns).collect::<Vec<_>>();
+
+        // buffer up the rows remaining after the merge round robin style
+        let mut new_batches = vec![vec![]; num_partitions];
+        let mut i = 0;
+        let mut row_count = 0;
+        // Whether a clause applied to each matched target row seen so far
+        let mut matched_rows = HashMap::new();
+        while let Some(batch) = data.next().await.transpose()? {
+            let target_rows = batch.column(num_columns).as_primitive::<UInt64Type>();
+            let clauses = batch.column(num_columns + 1).as_primitive::<UInt32Type>();
+            row_count += clauses.len() - clauses.null_count();
+
+            let keep = target_rows
+                .iter()
+                .zip(clauses.iter())
+                .map(|(target_row, clause)| {
+                    if let Some(target_row) = target_row {
+                        match matched_rows.entry(target_row) {
+                            Entry::Vacant(entry) => {
+                                entry.insert(clause.is_some());
+                            }
+                            // Another copy of an unchanged target row
+                            Entry::Occupied(entry)
+                                if !entry.get() && clause.is_none() =>
+                            {
+                                return Ok(Some(false));
+                            }
+                            Entry::Occupied(_) => {
+                                return exec_err!(
+                                    "MERGE cannot modify a target row matched by more than one source row"
+                                );
+                            }
+                        }
+                    }
+                    match clause {
+                        None => Ok(Some(true)),
+                        Some(clause) => match self.deleted_clauses.get(clause as usize) {
+                            Some(deleted) => Ok(Some(!deleted)),
+                            None => internal_err!("Unknown MERGE clause index {clause}"),
+                        },
+                    }
+                })
+                .collect::<Result<BooleanArray>>()?;
+            let batch = filter_record_batch(&batch.project(&table_columns)?, &keep)?;
+            new_batches[i].push(batch);
+            i = (i + 1) % num_partitions;
+        }
+
+        // replace the previous content of the table
+        for (target, batches) in self.batches.iter().zip(new_batches.into_iter()) {
+            *target.write().await = batches;
+        }
+
+        Ok(row_count as u64)
+    }
+}
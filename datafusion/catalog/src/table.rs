@@ -27,7 +27,7 @@ use datafusion_common::Result;
 use datafusion_common::{not_impl_err, Constraints, Statistics};
 use datafusion_expr::Expr;
 
-use datafusion_expr::dml::InsertOp;
+use datafusion_expr::dml::{InsertOp, MergeOp};
 use datafusion_expr::{
     CreateExternalTable, LogicalPlan, TableProviderFilterPushDown, TableType,
 };
@@ -269,6 +269,17 @@ pub trait TableProvider: Debug + Sync + Send {
         None
     }
 
+    /// Store the statistics computed by `ANALYZE TABLE`, so that they are
+    /// returned by [`Self::statistics`] and used when planning the scans of
+    /// this table.
+    ///
+    /// `statistics` has a column statistics for each field of
+    /// [`Self::schema`]; the statistics of the columns that were not analyzed
+    /// are unknown.
+    fn set_statistics(&self, _statistics: Statistics) -> Result<()> {
+        not_impl_err!("ANALYZE TABLE not implemented for this table")
+    }
+
     /// Return an [`ExecutionPlan`] to insert data into this table, if
     /// supported.
     ///
@@ -297,6 +308,54 @@ pub trait TableProvider: Debug + Sync + Send {
     ) -> Result<Arc<dyn ExecutionPlan>> {
         not_impl_err!("Insert into not implemented for this table")
     }
+
+    /// Return an [`ExecutionPlan`] to apply a `MERGE INTO` statement to
+    /// this table, if supported.
+    ///
+    /// `input` produces the columns of this table followed by the index of
+    /// the clause of `merge_op` applied to each row, as described in
+    /// [`MergeOp`]. Like [`Self::insert_into`], the returned plan should
+    /// return a single row in a UInt64 column called "count", holding the
+    /// number of rows inserted, updated or deleted.
+    async fn merge_into(
+        &self,
+        _state: &dyn Session,
+        _input: Arc<dyn ExecutionPlan>,
+        _merge_op: &MergeOp,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        not_impl_err!("Merge into not implemented for this table")
+    }
+
+    /// Return an [`ExecutionPlan`] to delete the rows of this table matching
+    /// all of `filters`, if supported.
+    ///
+    /// `filters` are evaluated against [`Self::schema`] with unqualified
+    /// column references, and an empty list deletes all the rows. Like
+    /// [`Self::insert_into`], the returned plan should return a single row
+    /// in a UInt64 column called "count", holding the number of rows deleted.
+    async fn delete_from(
+        &self,
+        _state: &dyn Session,
+        _filters: Vec<Expr>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        not_impl_err!("Delete from not implemented for this table")
+    }
+
+    /// Return an [`ExecutionPlan`] to update the rows of this table matching
+    /// all of `filters`, if supported.
+    ///
+    /// Each assignment is a column name and the expression computing its new
+    /// value from the previous row, which is already cast to the type of the
+    /// column. `filters` are handled as in [`Self::delete_from`], and the
+    /// returned plan should return the number of rows updated.
+    async fn update(
+        &self,
+        _state: &dyn Session,
+        _assignments: Vec<(String, Expr)>,
+        _filters: Vec<Expr>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        not_impl_err!("Update not implemented for this table")
+    }
 }
 
 /// A factory which creates [`TableProvider`]s at runtime given a URL.
@@ -17,6 +17,7 @@
 
 //! View data source which uses a LogicalPlan as it's input.
 
+use std::collections::HashMap;
 use std::{any::Any, borrow::Cow, sync::Arc};
 
 use crate::Session;
@@ -25,11 +26,12 @@ use crate::TableProvider;
 use arrow::datatypes::SchemaRef;
 use async_trait::async_trait;
 use datafusion_common::error::Result;
-use datafusion_common::Column;
+use datafusion_common::{Column, Statistics};
 use datafusion_expr::TableType;
 use datafusion_expr::{Expr, LogicalPlan};
 use datafusion_expr::{LogicalPlanBuilder, TableProviderFilterPushDown};
 use datafusion_physical_plan::ExecutionPlan;
+use parking_lot::RwLock;
 
 /// An implementation of `TableProvider` that uses another logical plan.
 #[derive(Debug)]
@@ -153,3 +155,129 @@ impl TableProvider for ViewTable {
         state.create_physical_plan(&plan.build()?).await
     }
 }
+
+/// Where a [`MaterializedView`] stores its content
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MaterializedViewStorage {
+    /// In memory, for the lifetime of the view
+    Memory,
+    /// As files in a directory owned by the view. Each refresh writes to a
+    /// new subdirectory and removes the previous one once it succeeded
+    Files {
+        /// The directory containing the files
+        location: String,
+        /// The file type of the files, e.g. `PARQUET`
+        file_type: String,
+        /// Options of the file type
+        options: HashMap<String, String>,
+    },
+}
+
+/// An implementation of `TableProvider` for a materialized view: a view whose
+/// content is computed by its logical plan when it is created or refreshed,
+/// and stored in another `TableProvider` that its queries scan.
+///
+/// Computing the content is up to the caller, see [`Self::set_content`].
+#[derive(Debug)]
+pub struct MaterializedView {
+    /// LogicalPlan computing the content of the view
+    logical_plan: LogicalPlan,
+    /// Fields of the view
+    table_schema: SchemaRef,
+    /// SQL used to create the view, if available
+    definition: Option<String>,
+    /// Where the content is stored
+    storage: MaterializedViewStorage,
+    /// The table holding the content as of the last refresh
+    content: RwLock<Arc<dyn TableProvider>>,
+}
+
+impl MaterializedView {
+    /// Create a new materialized view, whose current content is held by
+    /// `content`, with the same schema as `logical_plan`.
+    pub fn new(
+        logical_plan: LogicalPlan,
+        definition: Option<String>,
+        storage: MaterializedViewStorage,
+        content: Arc<dyn TableProvider>,
+    ) -> Self {
+        let table_schema = logical_plan.schema().as_ref().to_owned().into();
+        Self {
+            logical_plan,
+            table_schema,
+            definition,
+            storage,
+            content: RwLock::new(content),
+        }
+    }
+
+    /// Get definition ref
+    pub fn definition(&self) -> Option<&String> {
+        self.definition.as_ref()
+    }
+
+    /// Get logical_plan ref
+    pub fn logical_plan(&self) -> &LogicalPlan {
+        &self.logical_plan
+    }
+
+    /// Get where the content is stored
+    pub fn storage(&self) -> &MaterializedViewStorage {
+        &self.storage
+    }
+
+    /// Get the table holding the current content
+    pub fn content(&self) -> Arc<dyn TableProvider> {
+        Arc::clone(&self.content.read())
+    }
+
+    /// Replace the content of the view, e.g. with the result of executing
+    /// [`Self::logical_plan`] again
+    pub fn set_content(&self, content: Arc<dyn TableProvider>) {
+        *self.content.write() = content;
+    }
+}
+
+#[async_trait]
+impl TableProvider for MaterializedView {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.table_schema)
+    }
+
+    fn table_type(&self) -> TableType {
+        TableType::View
+    }
+
+    fn get_table_definition(&self) -> Option<&str> {
+        self.definition.as_deref()
+    }
+
+    fn supports_filters_pushdown(
+        &self,
+        filters: &[&Expr],
+    ) -> Result<Vec<TableProviderFilterPushDown>> {
+        self.content().supports_filters_pushdown(filters)
+    }
+
+    fn statistics(&self) -> Option<Statistics> {
+        self.content().statistics()
+    }
+
+    fn set_statistics(&self, statistics: Statistics) -> Result<()> {
+        self.content().set_statistics(statistics)
+    }
+
+    async fn scan(
+        &self,
+        state: &dyn Session,
+        projection: Option<&Vec<usize>>,
+        filters: &[Expr],
+        limit: Option<usize>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        self.content().scan(state, projection, filters, limit).await
+    }
+}
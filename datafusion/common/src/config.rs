@@ -368,6 +368,24 @@ config_namespace! {
         /// `ListingTableProvider` in DataFusion. Defaults to true.
         pub collect_statistics: bool, default = true
 
+        /// Number of buckets of the equi-depth histograms computed by
+        /// `ANALYZE TABLE` for each analyzed column
+        pub analyze_histogram_buckets: usize, default = 64
+
+        /// Maximum number of most common values, and their number of
+        /// occurrences, computed by `ANALYZE TABLE` for each analyzed column
+        pub analyze_most_common_values: usize, default = 16
+
+        /// Number of non-null values of each column sampled by `ANALYZE TABLE`
+        /// to build its histogram and most common values. Its distinct count is
+        /// estimated with a HyperLogLog sketch if it has more non-null values
+        pub analyze_sample_size: usize, default = 65536
+
+        /// When set to true, `ANALYZE TABLE` keeps all the values of the analyzed
+        /// columns in memory to compute their statistics exactly, instead of a
+        /// sample of them
+        pub analyze_exact_statistics: bool, default = false
+
         /// Number of partitions for query execution. Increasing partitions can increase
         /// concurrency.
         ///
@@ -815,6 +833,18 @@ config_namespace! {
         /// will be collected into a single partition
         pub hash_join_single_partition_threshold_rows: usize, default = 1024 * 128
 
+        /// When set to true, the physical plan optimizer will reorder chains of
+        /// inner equi-joins using the row counts and distinct counts of their
+        /// inputs, to minimize the estimated sizes of intermediate results.
+        /// Joins are only reordered when all their inputs have row count statistics
+        pub enable_join_reordering: bool, default = false
+
+        /// The maximum number of join inputs for which the join reordering finds
+        /// the cheapest join order by dynamic programming. Larger joins are
+        /// ordered greedily, joining the pair of inputs with the smallest
+        /// estimated result first
+        pub join_reordering_dp_threshold: usize, default = 10
+
         /// The default filter selectivity used by Filter Statistics
         /// when an exact selectivity cannot be determined. Valid values are
         /// between 0 (no selectivity) and 100 (all rows are selected).
@@ -1575,6 +1605,7 @@ pub enum ConfigFileType {
     #[cfg(feature = "parquet")]
     PARQUET,
     JSON,
+    AVRO,
 }
 
 /// Represents the configuration options available for handling different table formats within a data processing application.
@@ -1593,6 +1624,9 @@ pub struct TableOptions {
     /// Configuration options for JSON file handling.
     pub json: JsonOptions,
 
+    /// Configuration options for Avro file handling.
+    pub avro: AvroOptions,
+
     /// The current file format that the table operations should assume. This option allows
     /// for dynamic switching between the supported file types (e.g., CSV, Parquet, JSON).
     pub current_format: Option<ConfigFileType>,
@@ -1616,11 +1650,13 @@ impl ConfigField for TableOptions {
                 ConfigFileType::PARQUET => self.parquet.visit(v, "format", ""),
                 ConfigFileType::CSV => self.csv.visit(v, "format", ""),
                 ConfigFileType::JSON => self.json.visit(v, "format", ""),
+                ConfigFileType::AVRO => self.avro.visit(v, "format", ""),
             }
         } else {
             self.csv.visit(v, "csv", "");
             self.parquet.visit(v, "parquet", "");
             self.json.visit(v, "json", "");
+            self.avro.visit(v, "avro", "");
         }
     }
 
@@ -1652,6 +1688,7 @@ impl ConfigField for TableOptions {
                     ConfigFileType::PARQUET => self.parquet.set(rem, value),
                     ConfigFileType::CSV => self.csv.set(rem, value),
                     ConfigFileType::JSON => self.json.set(rem, value),
+                    ConfigFileType::AVRO => self.avro.set(rem, value),
                 }
             }
             _ => _config_err!("Config value \"{key}\" not found on TableOptions"),
@@ -2535,11 +2572,68 @@ impl CsvOptions {
     }
 }
 
+/// The layout of the records in a JSON file
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum JsonFormatType {
+    /// One JSON object per line (NDJSON)
+    #[default]
+    NewlineDelimited,
+    /// A single top-level JSON array of objects
+    Array,
+}
+
+impl FromStr for JsonFormatType {
+    type Err = DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ndjson" | "newline_delimited" | "" => Ok(Self::NewlineDelimited),
+            "array" => Ok(Self::Array),
+            other => Err(DataFusionError::Configuration(format!(
+                "Invalid JSON format: {other}. Expected one of: ndjson, array"
+            ))),
+        }
+    }
+}
+
+impl ConfigField for JsonFormatType {
+    fn visit<V: Visit>(&self, v: &mut V, key: &str, description: &'static str) {
+        v.some(key, self, description)
+    }
+
+    fn set(&mut self, _: &str, value: &str) -> Result<()> {
+        *self = JsonFormatType::from_str(value)?;
+        Ok(())
+    }
+}
+
+impl Display for JsonFormatType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let str = match self {
+            Self::NewlineDelimited => "ndjson",
+            Self::Array => "array",
+        };
+        write!(f, "{str}")
+    }
+}
+
 config_namespace! {
     /// Options controlling JSON format
     pub struct JsonOptions {
         pub compression: CompressionTypeVariant, default = CompressionTypeVariant::UNCOMPRESSED
         pub schema_infer_max_rec: Option<usize>, default = None
+        /// The layout of the JSON files: `ndjson` for one object per line, or
+        /// `array` for a single top-level array of objects
+        pub format: JsonFormatType, default = JsonFormatType::NewlineDelimited
+    }
+}
+
+config_namespace! {
+    /// Options controlling Avro format
+    pub struct AvroOptions {
+        /// (writing) Sets the codec used to compress the data blocks of written
+        /// Avro files. Valid values are: null, deflate, snappy and zstd.
+        pub codec: String, default = "null".to_string()
     }
 }
 
@@ -2661,6 +2755,28 @@ mod tests {
         assert_eq!(table_config.csv.escape.unwrap() as char, '\'');
     }
 
+    #[test]
+    fn json_table_options() {
+        let mut table_config = TableOptions::new();
+        assert_eq!(table_config.json.format, JsonFormatType::NewlineDelimited);
+        table_config.set_config_format(ConfigFileType::JSON);
+        table_config.set("format.format", "array").unwrap();
+        assert_eq!(table_config.json.format, JsonFormatType::Array);
+        table_config.set("format.format", "NDJSON").unwrap();
+        assert_eq!(table_config.json.format, JsonFormatType::NewlineDelimited);
+        assert!(table_config.set("format.format", "csv").is_err());
+    }
+
+    #[test]
+    fn avro_table_options() {
+        let mut table_config = TableOptions::new();
+        assert_eq!(table_config.avro.codec, "null");
+        table_config.set_config_format(ConfigFileType::AVRO);
+        table_config.set("format.codec", "zstd").unwrap();
+        assert_eq!(table_config.avro.codec, "zstd");
+        assert!(table_config.set("format.compression", "gzip").is_err());
+    }
+
     #[test]
     fn warning_only_not_default() {
         use std::sync::atomic::AtomicUsize;
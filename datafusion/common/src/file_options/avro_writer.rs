@@ -17,5 +17,30 @@
 
 //! Options related to how avro files should be written
 
+use crate::{
+    config::AvroOptions,
+    error::{DataFusionError, Result},
+};
+
+/// Options for writing Avro files
 #[derive(Clone, Debug)]
-pub struct AvroWriterOptions {}
+pub struct AvroWriterOptions {
+    /// Codec used to compress the data blocks, see [`AvroOptions::codec`]
+    pub codec: String,
+}
+
+impl AvroWriterOptions {
+    pub fn new(codec: String) -> Self {
+        Self { codec }
+    }
+}
+
+impl TryFrom<&AvroOptions> for AvroWriterOptions {
+    type Error = DataFusionError;
+
+    fn try_from(value: &AvroOptions) -> Result<Self> {
+        Ok(AvroWriterOptions {
+            codec: value.codec.clone(),
+        })
+    }
+}
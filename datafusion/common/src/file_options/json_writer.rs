@@ -18,7 +18,7 @@
 //! Options related to how json files should be written
 
 use crate::{
-    config::JsonOptions,
+    config::{JsonFormatType, JsonOptions},
     error::{DataFusionError, Result},
     parsers::CompressionTypeVariant,
 };
@@ -27,11 +27,22 @@ use crate::{
 #[derive(Clone, Debug)]
 pub struct JsonWriterOptions {
     pub compression: CompressionTypeVariant,
+    /// Whether to write newline delimited JSON or a single JSON array
+    pub format: JsonFormatType,
 }
 
 impl JsonWriterOptions {
     pub fn new(compression: CompressionTypeVariant) -> Self {
-        Self { compression }
+        Self {
+            compression,
+            format: JsonFormatType::default(),
+        }
+    }
+
+    /// Set the layout of the written JSON files
+    pub fn with_format(mut self, format: JsonFormatType) -> Self {
+        self.format = format;
+        self
     }
 }
 
@@ -41,6 +52,7 @@ impl TryFrom<&JsonOptions> for JsonWriterOptions {
     fn try_from(value: &JsonOptions) -> Result<Self> {
         Ok(JsonWriterOptions {
             compression: value.compression,
+            format: value.format,
         })
     }
 }
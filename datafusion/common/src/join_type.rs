@@ -22,7 +22,7 @@ use std::{
     str::FromStr,
 };
 
-use crate::error::_not_impl_err;
+use crate::error::{_not_impl_err, _plan_err};
 use crate::{DataFusionError, Result};
 
 /// Join type
@@ -72,6 +72,15 @@ pub enum JoinType {
     /// Same logic as the LeftMark Join above, however it returns a record for each record from the
     /// right input.
     RightMark,
+    /// As-of Join
+    ///
+    /// Returns one record for each record from the left input, joined with the single record of
+    /// the right input that has equal join keys and is "closest" according to the inequality in
+    /// the join filter, e.g. the most recent quote (`q.ts <= t.ts`) for each trade. If there is
+    /// no such record, NULL values are returned for the columns from the right input.
+    ///
+    /// This join type does not support swapping its inputs.
+    AsOf,
 }
 
 impl JoinType {
@@ -81,9 +90,10 @@ impl JoinType {
 
     /// Returns the `JoinType` if the (2) inputs were swapped
     ///
-    /// Panics if [`Self::supports_swap`] returns false
-    pub fn swap(&self) -> JoinType {
-        match self {
+    /// Returns an error for join types whose inputs can not be swapped,
+    /// such as [`JoinType::AsOf`]
+    pub fn swap(&self) -> Result<JoinType> {
+        Ok(match self {
             JoinType::Inner => JoinType::Inner,
             JoinType::Full => JoinType::Full,
             JoinType::Left => JoinType::Right,
@@ -94,7 +104,10 @@ impl JoinType {
             JoinType::RightAnti => JoinType::LeftAnti,
             JoinType::LeftMark => JoinType::RightMark,
             JoinType::RightMark => JoinType::LeftMark,
-        }
+            JoinType::AsOf => {
+                return _plan_err!("AsOf join does not support swapping inputs")
+            }
+        })
     }
 
     /// Does the join type support swapping inputs?
@@ -126,6 +139,7 @@ impl Display for JoinType {
             JoinType::RightAnti => "RightAnti",
             JoinType::LeftMark => "LeftMark",
             JoinType::RightMark => "RightMark",
+            JoinType::AsOf => "AsOf",
         };
         write!(f, "{join_type}")
     }
@@ -147,6 +161,7 @@ impl FromStr for JoinType {
             "RIGHTANTI" => Ok(JoinType::RightAnti),
             "LEFTMARK" => Ok(JoinType::LeftMark),
             "RIGHTMARK" => Ok(JoinType::RightMark),
+            "ASOF" => Ok(JoinType::AsOf),
             _ => _not_impl_err!("The join type {s} does not exist or is not implemented"),
         }
     }
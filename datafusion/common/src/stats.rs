@@ -17,7 +17,9 @@
 
 //! This module provides data structures to represent statistics
 
+use std::cmp::Ordering;
 use std::fmt::{self, Debug, Display};
+use std::sync::Arc;
 
 use crate::{Result, ScalarValue};
 
@@ -538,6 +540,9 @@ impl Statistics {
             col_stats.max_value = col_stats.max_value.max(&item_col_stats.max_value);
             col_stats.min_value = col_stats.min_value.min(&item_col_stats.min_value);
             col_stats.sum_value = col_stats.sum_value.add(&item_col_stats.sum_value);
+            // the histograms of the parts can not be combined
+            col_stats.histogram = None;
+            col_stats.most_common_values = None;
         }
 
         Ok(Statistics {
@@ -546,6 +551,30 @@ impl Statistics {
             column_statistics,
         })
     }
+
+    /// Fills the statistics that are [`Precision::Absent`] with the ones of
+    /// `other`, which describe the same data, for example statistics computed
+    /// by `ANALYZE TABLE`.
+    ///
+    /// The column statistics are left unchanged if `other` does not have the
+    /// same number of columns.
+    pub fn fill_absent(mut self, other: &Statistics) -> Self {
+        if self.num_rows == Precision::Absent {
+            self.num_rows = other.num_rows;
+        }
+        if self.total_byte_size == Precision::Absent {
+            self.total_byte_size = other.total_byte_size;
+        }
+        if self.column_statistics.len() == other.column_statistics.len() {
+            self.column_statistics = self
+                .column_statistics
+                .into_iter()
+                .zip(&other.column_statistics)
+                .map(|(s, other)| s.fill_absent(other))
+                .collect();
+        }
+        self
+    }
 }
 
 /// Creates an estimate of the number of rows in the output using the given
@@ -599,6 +628,16 @@ impl Display for Statistics {
                 } else {
                     s
                 };
+                let s = if let Some(histogram) = &cs.histogram {
+                    format!("{s} Histogram={histogram}")
+                } else {
+                    s
+                };
+                let s = if let Some(most_common_values) = &cs.most_common_values {
+                    format!("{s} MostCommonValues={most_common_values}")
+                } else {
+                    s
+                };
 
                 s + ")"
             })
@@ -628,6 +667,12 @@ pub struct ColumnStatistics {
     pub sum_value: Precision<ScalarValue>,
     /// Number of distinct values
     pub distinct_count: Precision<usize>,
+    /// Distribution of the non-null values of the column, as computed by
+    /// `ANALYZE TABLE`
+    pub histogram: Option<Arc<Histogram>>,
+    /// The most common non-null values of the column and their number of
+    /// occurrences, as computed by `ANALYZE TABLE`
+    pub most_common_values: Option<Arc<MostCommonValues>>,
 }
 
 impl ColumnStatistics {
@@ -650,6 +695,8 @@ impl ColumnStatistics {
             min_value: Precision::Absent,
             sum_value: Precision::Absent,
             distinct_count: Precision::Absent,
+            histogram: None,
+            most_common_values: None,
         }
     }
 
@@ -683,6 +730,21 @@ impl ColumnStatistics {
         self
     }
 
+    /// Set the histogram
+    pub fn with_histogram(mut self, histogram: Option<Arc<Histogram>>) -> Self {
+        self.histogram = histogram;
+        self
+    }
+
+    /// Set the most common values
+    pub fn with_most_common_values(
+        mut self,
+        most_common_values: Option<Arc<MostCommonValues>>,
+    ) -> Self {
+        self.most_common_values = most_common_values;
+        self
+    }
+
     /// If the exactness of a [`ColumnStatistics`] instance is lost, this
     /// function relaxes the exactness of all information by converting them
     /// [`Precision::Inexact`].
@@ -694,6 +756,358 @@ impl ColumnStatistics {
         self.distinct_count = self.distinct_count.to_inexact();
         self
     }
+
+    /// Fills the statistics that are [`Precision::Absent`], and the missing
+    /// histogram and most common values, with the ones of `other`, which
+    /// describe the same data.
+    pub fn fill_absent(mut self, other: &ColumnStatistics) -> Self {
+        fn fill<T: Debug + Clone + PartialEq + Eq + PartialOrd>(
+            value: &mut Precision<T>,
+            other: &Precision<T>,
+        ) {
+            if *value == Precision::Absent {
+                *value = other.clone();
+            }
+        }
+        fill(&mut self.null_count, &other.null_count);
+        fill(&mut self.max_value, &other.max_value);
+        fill(&mut self.min_value, &other.min_value);
+        fill(&mut self.sum_value, &other.sum_value);
+        fill(&mut self.distinct_count, &other.distinct_count);
+        if self.histogram.is_none() {
+            self.histogram = other.histogram.clone();
+        }
+        if self.most_common_values.is_none() {
+            self.most_common_values = other.most_common_values.clone();
+        }
+        self
+    }
+}
+
+/// An equi-depth histogram of the non-null values of a column: its buckets
+/// hold about the same number of values, so that narrow buckets describe the
+/// frequent values precisely.
+///
+/// The buckets are sorted and do not overlap, so all the occurrences of a
+/// value are in the same bucket.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Histogram {
+    buckets: Vec<HistogramBucket>,
+}
+
+/// A bucket of a [`Histogram`], holding the values between its bounds
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HistogramBucket {
+    /// The smallest value of the bucket
+    pub lower: ScalarValue,
+    /// The largest value of the bucket
+    pub upper: ScalarValue,
+    /// The number of values in the bucket
+    pub count: usize,
+    /// The number of distinct values in the bucket
+    pub distinct_count: usize,
+}
+
+impl Histogram {
+    /// Creates a histogram from its buckets, returning an error if they are
+    /// not sorted or overlap, or if their counts are inconsistent
+    pub fn try_new(buckets: Vec<HistogramBucket>) -> Result<Self> {
+        for bucket in &buckets {
+            if bucket
+                .lower
+                .partial_cmp(&bucket.upper)
+                .is_none_or(|o| o.is_gt())
+            {
+                return _plan_err!(
+                    "Invalid histogram bucket bounds: {} > {}",
+                    bucket.lower,
+                    bucket.upper
+                );
+            }
+            if bucket.distinct_count == 0 || bucket.distinct_count > bucket.count {
+                return _plan_err!(
+                    "Invalid histogram bucket counts: {} values, {} distinct",
+                    bucket.count,
+                    bucket.distinct_count
+                );
+            }
+        }
+        for pair in buckets.windows(2) {
+            if pair[0]
+                .upper
+                .partial_cmp(&pair[1].lower)
+                .is_none_or(|o| o.is_ge())
+            {
+                return _plan_err!(
+                    "Histogram buckets overlap or are not sorted: {} >= {}",
+                    pair[0].upper,
+                    pair[1].lower
+                );
+            }
+        }
+        Ok(Self { buckets })
+    }
+
+    /// The buckets of the histogram, sorted by their bounds
+    pub fn buckets(&self) -> &[HistogramBucket] {
+        &self.buckets
+    }
+
+    /// The number of values in the histogram
+    pub fn count(&self) -> usize {
+        self.buckets.iter().map(|bucket| bucket.count).sum()
+    }
+
+    /// The number of distinct values in the histogram
+    pub fn distinct_count(&self) -> usize {
+        self.buckets
+            .iter()
+            .map(|bucket| bucket.distinct_count)
+            .sum()
+    }
+
+    /// Estimates the number of values between `lower` and `upper`, both
+    /// inclusive, where `None` means unbounded.
+    ///
+    /// The occurrences of the `most_common_values` are counted exactly, the
+    /// other values are assumed to be uniformly distributed in their bucket.
+    pub fn estimate_count_between(
+        &self,
+        lower: Option<&ScalarValue>,
+        upper: Option<&ScalarValue>,
+        most_common_values: Option<&MostCommonValues>,
+    ) -> f64 {
+        if let (Some(lower), Some(upper)) = (lower, upper) {
+            if lower == upper {
+                return self.estimate_count_of(lower, most_common_values);
+            }
+        }
+        self.buckets
+            .iter()
+            .map(|bucket| {
+                let fraction = bucket.fraction_between(lower, upper);
+                if fraction == 0.0 || fraction == 1.0 {
+                    return fraction * bucket.count as f64;
+                }
+                let common = most_common_values
+                    .map(|mcv| mcv.between(Some(&bucket.lower), Some(&bucket.upper)))
+                    .unwrap_or_default();
+                let common_count: usize = common.iter().map(|(_, count)| count).sum();
+                let common_in_range: usize = common
+                    .iter()
+                    .filter(|(value, _)| {
+                        lower.is_none_or(|lower| value >= lower)
+                            && upper.is_none_or(|upper| value <= upper)
+                    })
+                    .map(|(_, count)| count)
+                    .sum();
+                common_in_range as f64
+                    + bucket.count.saturating_sub(common_count) as f64 * fraction
+            })
+            .sum()
+    }
+
+    /// Estimates the number of occurrences of `value`, which is exact if it is
+    /// one of the `most_common_values`, and otherwise the average number of
+    /// occurrences of the other distinct values of its bucket.
+    pub fn estimate_count_of(
+        &self,
+        value: &ScalarValue,
+        most_common_values: Option<&MostCommonValues>,
+    ) -> f64 {
+        if let Some(count) = most_common_values.and_then(|mcv| mcv.count_of(value)) {
+            return count as f64;
+        }
+        let Some(bucket) = self
+            .buckets
+            .iter()
+            .find(|bucket| &bucket.lower <= value && value <= &bucket.upper)
+        else {
+            return 0.0;
+        };
+        let common = most_common_values
+            .map(|mcv| mcv.between(Some(&bucket.lower), Some(&bucket.upper)))
+            .unwrap_or_default();
+        let count = bucket
+            .count
+            .saturating_sub(common.iter().map(|(_, count)| count).sum());
+        let distinct_count = bucket.distinct_count.saturating_sub(common.len());
+        if distinct_count == 0 {
+            0.0
+        } else {
+            count as f64 / distinct_count as f64
+        }
+    }
+}
+
+impl Display for Histogram {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} buckets", self.buckets.len())
+    }
+}
+
+impl HistogramBucket {
+    /// Estimates the fraction of the values of the bucket that are between
+    /// `lower` and `upper`, both inclusive, where `None` means unbounded.
+    ///
+    /// The values are assumed to be uniformly distributed between the bounds
+    /// of the bucket. A single value is estimated to hold `1 / distinct_count`
+    /// of the bucket, and ranges of values of types that can not be
+    /// interpolated, such as strings, half of it.
+    pub fn fraction_between(
+        &self,
+        lower: Option<&ScalarValue>,
+        upper: Option<&ScalarValue>,
+    ) -> f64 {
+        let lower = match lower {
+            Some(lower) if lower > &self.lower => lower,
+            _ => &self.lower,
+        };
+        let upper = match upper {
+            Some(upper) if upper < &self.upper => upper,
+            _ => &self.upper,
+        };
+        match lower.partial_cmp(upper) {
+            Some(Ordering::Less) => {}
+            Some(Ordering::Equal) if self.lower == self.upper => return 1.0,
+            Some(Ordering::Equal) => return 1.0 / self.distinct_count as f64,
+            Some(Ordering::Greater) | None => return 0.0,
+        }
+        if lower == &self.lower && upper == &self.upper {
+            return 1.0;
+        }
+        let (
+            Some(bucket_lower),
+            Some(bucket_upper),
+            Some(range_lower),
+            Some(range_upper),
+        ) = (
+            scalar_position(&self.lower),
+            scalar_position(&self.upper),
+            scalar_position(lower),
+            scalar_position(upper),
+        )
+        else {
+            return 0.5;
+        };
+        // the values of discrete types are counted, including both bounds
+        let step = if self.lower.data_type().is_floating() {
+            0.0
+        } else {
+            1.0
+        };
+        let width = bucket_upper - bucket_lower + step;
+        if width <= 0.0 {
+            return 1.0;
+        }
+        ((range_upper - range_lower + step) / width).clamp(0.0, 1.0)
+    }
+}
+
+/// The position of a numeric or temporal value on the number line, used to
+/// interpolate between the bounds of a [`HistogramBucket`]
+fn scalar_position(value: &ScalarValue) -> Option<f64> {
+    let position = match value {
+        ScalarValue::Int8(Some(v)) => *v as f64,
+        ScalarValue::Int16(Some(v)) => *v as f64,
+        ScalarValue::Int32(Some(v)) => *v as f64,
+        ScalarValue::Int64(Some(v)) => *v as f64,
+        ScalarValue::UInt8(Some(v)) => *v as f64,
+        ScalarValue::UInt16(Some(v)) => *v as f64,
+        ScalarValue::UInt32(Some(v)) => *v as f64,
+        ScalarValue::UInt64(Some(v)) => *v as f64,
+        ScalarValue::Float32(Some(v)) => *v as f64,
+        ScalarValue::Float64(Some(v)) => *v,
+        ScalarValue::Decimal128(Some(v), _, _) => *v as f64,
+        ScalarValue::Date32(Some(v)) => *v as f64,
+        ScalarValue::Date64(Some(v)) => *v as f64,
+        ScalarValue::Time32Second(Some(v)) => *v as f64,
+        ScalarValue::Time32Millisecond(Some(v)) => *v as f64,
+        ScalarValue::Time64Microsecond(Some(v)) => *v as f64,
+        ScalarValue::Time64Nanosecond(Some(v)) => *v as f64,
+        ScalarValue::TimestampSecond(Some(v), _) => *v as f64,
+        ScalarValue::TimestampMillisecond(Some(v), _) => *v as f64,
+        ScalarValue::TimestampMicrosecond(Some(v), _) => *v as f64,
+        ScalarValue::TimestampNanosecond(Some(v), _) => *v as f64,
+        ScalarValue::DurationSecond(Some(v)) => *v as f64,
+        ScalarValue::DurationMillisecond(Some(v)) => *v as f64,
+        ScalarValue::DurationMicrosecond(Some(v)) => *v as f64,
+        ScalarValue::DurationNanosecond(Some(v)) => *v as f64,
+        _ => return None,
+    };
+    position.is_finite().then_some(position)
+}
+
+/// The most common non-null values of a column, sorted by value, with their
+/// number of occurrences.
+///
+/// They complement a [`Histogram`], whose buckets assume that their values
+/// are uniformly distributed, with the exact number of occurrences of the
+/// values that are much more frequent than the others.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MostCommonValues {
+    values: Vec<(ScalarValue, usize)>,
+}
+
+impl MostCommonValues {
+    /// Creates the most common values from values and their number of
+    /// occurrences, returning an error if a value is null, repeated or not
+    /// comparable with the others
+    pub fn try_new(mut values: Vec<(ScalarValue, usize)>) -> Result<Self> {
+        if let Some((value, _)) = values.iter().find(|(value, _)| value.is_null()) {
+            return _plan_err!("Invalid most common value: {value}");
+        }
+        values.sort_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        for pair in values.windows(2) {
+            if pair[0].0.partial_cmp(&pair[1].0).is_none_or(|o| o.is_ge()) {
+                return _plan_err!(
+                    "Most common values are repeated or not comparable: {} and {}",
+                    pair[0].0,
+                    pair[1].0
+                );
+            }
+        }
+        Ok(Self { values })
+    }
+
+    /// The most common values and their number of occurrences, sorted by value
+    pub fn values(&self) -> &[(ScalarValue, usize)] {
+        &self.values
+    }
+
+    /// The most common values between `lower` and `upper`, both inclusive,
+    /// where `None` means unbounded
+    pub fn between(
+        &self,
+        lower: Option<&ScalarValue>,
+        upper: Option<&ScalarValue>,
+    ) -> &[(ScalarValue, usize)] {
+        let start =
+            lower.map_or(0, |lower| self.values.partition_point(|(v, _)| v < lower));
+        let end = upper.map_or(self.values.len(), |upper| {
+            self.values.partition_point(|(v, _)| v <= upper)
+        });
+        &self.values[start..end.max(start)]
+    }
+
+    /// The number of occurrences of `value`, if it is one of the most common
+    /// values
+    pub fn count_of(&self, value: &ScalarValue) -> Option<usize> {
+        self.between(Some(value), Some(value))
+            .first()
+            .map(|(_, count)| *count)
+    }
+
+    /// The total number of occurrences of the most common values
+    pub fn count(&self) -> usize {
+        self.values.iter().map(|(_, count)| count).sum()
+    }
+}
+
+impl Display for MostCommonValues {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} values", self.values.len())
+    }
 }
 
 #[cfg(test)]
@@ -961,6 +1375,8 @@ mod tests {
             min_value: Precision::Exact(ScalarValue::Int64(Some(64))),
             sum_value: Precision::Exact(ScalarValue::Int64(Some(4600))),
             distinct_count: Precision::Exact(100),
+            histogram: None,
+            most_common_values: None,
         }
     }
 
@@ -983,6 +1399,8 @@ mod tests {
                     min_value: Precision::Exact(ScalarValue::Int32(Some(1))),
                     sum_value: Precision::Exact(ScalarValue::Int32(Some(500))),
                     distinct_count: Precision::Absent,
+                    histogram: None,
+                    most_common_values: None,
                 },
                 ColumnStatistics {
                     null_count: Precision::Exact(2),
@@ -990,6 +1408,8 @@ mod tests {
                     min_value: Precision::Exact(ScalarValue::Int32(Some(10))),
                     sum_value: Precision::Exact(ScalarValue::Int32(Some(1000))),
                     distinct_count: Precision::Absent,
+                    histogram: None,
+                    most_common_values: None,
                 },
             ],
         };
@@ -1004,6 +1424,8 @@ mod tests {
                     min_value: Precision::Exact(ScalarValue::Int32(Some(-10))),
                     sum_value: Precision::Exact(ScalarValue::Int32(Some(600))),
                     distinct_count: Precision::Absent,
+                    histogram: None,
+                    most_common_values: None,
                 },
                 ColumnStatistics {
                     null_count: Precision::Exact(3),
@@ -1011,6 +1433,8 @@ mod tests {
                     min_value: Precision::Exact(ScalarValue::Int32(Some(5))),
                     sum_value: Precision::Exact(ScalarValue::Int32(Some(1200))),
                     distinct_count: Precision::Absent,
+                    histogram: None,
+                    most_common_values: None,
                 },
             ],
         };
@@ -1074,6 +1498,8 @@ mod tests {
                 min_value: Precision::Inexact(ScalarValue::Int32(Some(1))),
                 sum_value: Precision::Exact(ScalarValue::Int32(Some(500))),
                 distinct_count: Precision::Absent,
+                histogram: None,
+                most_common_values: None,
             }],
         };
 
@@ -1086,6 +1512,8 @@ mod tests {
                 min_value: Precision::Exact(ScalarValue::Int32(Some(-10))),
                 sum_value: Precision::Absent,
                 distinct_count: Precision::Absent,
+                histogram: None,
+                most_common_values: None,
             }],
         };
 
@@ -1152,4 +1580,106 @@ mod tests {
         let e = Statistics::try_merge_iter(&items, &schema).unwrap_err();
         assert_contains!(e.to_string(), "Error during planning: Cannot merge statistics with different number of columns: 0 vs 1");
     }
+
+    fn histogram_bucket(
+        lower: ScalarValue,
+        upper: ScalarValue,
+        count: usize,
+        distinct_count: usize,
+    ) -> HistogramBucket {
+        HistogramBucket {
+            lower,
+            upper,
+            count,
+            distinct_count,
+        }
+    }
+
+    #[test]
+    fn test_most_common_values() {
+        let mcv = MostCommonValues::try_new(vec![
+            (ScalarValue::from(7_i64), 20),
+            (ScalarValue::from(3_i64), 60),
+        ])
+        .unwrap();
+        assert_eq!(
+            mcv.values(),
+            [
+                (ScalarValue::from(3_i64), 60),
+                (ScalarValue::from(7_i64), 20)
+            ]
+        );
+        assert_eq!(mcv.count(), 80);
+        assert_eq!(mcv.count_of(&ScalarValue::from(7_i64)), Some(20));
+        assert_eq!(mcv.count_of(&ScalarValue::from(5_i64)), None);
+        assert_eq!(
+            mcv.between(Some(&ScalarValue::from(4_i64)), None),
+            [(ScalarValue::from(7_i64), 20)]
+        );
+        assert!(mcv
+            .between(
+                Some(&ScalarValue::from(4_i64)),
+                Some(&ScalarValue::from(5_i64))
+            )
+            .is_empty());
+
+        let e =
+            MostCommonValues::try_new(vec![(ScalarValue::Int64(None), 1)]).unwrap_err();
+        assert_contains!(e.to_string(), "Invalid most common value: NULL");
+        let e = MostCommonValues::try_new(vec![
+            (ScalarValue::from(3_i64), 1),
+            (ScalarValue::from(3_i64), 2),
+        ])
+        .unwrap_err();
+        assert_contains!(e.to_string(), "Most common values are repeated");
+    }
+
+    #[test]
+    fn test_histogram_estimate_count() {
+        let int = |v: i64| ScalarValue::from(v);
+        let histogram = Histogram::try_new(vec![
+            histogram_bucket(int(0), int(9), 100, 10),
+            histogram_bucket(int(10), int(10), 50, 1),
+            histogram_bucket(int(11), int(20), 10, 5),
+        ])
+        .unwrap();
+        // 3 occurs 60 times
+        let mcv = MostCommonValues::try_new(vec![(int(3), 60)]).unwrap();
+
+        assert_eq!(histogram.count(), 160);
+        assert_eq!(histogram.distinct_count(), 16);
+        assert_eq!(histogram.estimate_count_between(None, None, None), 160.0);
+        assert_eq!(
+            histogram.estimate_count_between(Some(&int(0)), Some(&int(4)), None),
+            50.0
+        );
+        assert_eq!(
+            histogram.estimate_count_between(Some(&int(0)), Some(&int(4)), Some(&mcv)),
+            60.0 + 40.0 * 0.5
+        );
+        assert_eq!(
+            histogram.estimate_count_between(Some(&int(10)), None, Some(&mcv)),
+            60.0
+        );
+        assert_eq!(histogram.estimate_count_of(&int(3), Some(&mcv)), 60.0);
+        assert_eq!(histogram.estimate_count_of(&int(5), Some(&mcv)), 40.0 / 9.0);
+        assert_eq!(histogram.estimate_count_of(&int(5), None), 10.0);
+        assert_eq!(histogram.estimate_count_of(&int(10), None), 50.0);
+        assert_eq!(histogram.estimate_count_of(&int(30), None), 0.0);
+
+        // strings can not be interpolated
+        let bucket =
+            histogram_bucket(ScalarValue::from("a"), ScalarValue::from("z"), 10, 5);
+        assert_eq!(
+            bucket.fraction_between(Some(&ScalarValue::from("m")), None),
+            0.5
+        );
+        assert_eq!(
+            bucket.fraction_between(
+                Some(&ScalarValue::from("m")),
+                Some(&ScalarValue::from("m"))
+            ),
+            0.2
+        );
+    }
 }
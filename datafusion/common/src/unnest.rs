@@ -66,6 +66,10 @@ use crate::Column;
 /// `recursions` instruct how a column should be unnested (e.g unnesting a column multiple
 /// time, with depth = 1 and depth = 2). Any unnested column not being mentioned inside this
 /// options is inferred to be unnested with depth = 1
+///
+/// If `ordinality` is set, an additional `Int64` column is appended to the
+/// output that contains the position of each output row among the rows
+/// produced from the same input row, see [`UnnestOrdinality`].
 #[derive(Debug, Clone, PartialEq, PartialOrd, Hash, Eq)]
 pub struct UnnestOptions {
     /// Should nulls in the input be preserved? Defaults to true
@@ -74,6 +78,8 @@ pub struct UnnestOptions {
     /// declare them here. Any unnested columns not being mentioned inside this option
     /// will be unnested with depth = 1
     pub recursions: Vec<RecursionUnnestOption>,
+    /// If set, emit the position of each unnested element as an extra column
+    pub ordinality: Option<UnnestOrdinality>,
 }
 
 /// Instruction on how to unnest a column (mostly with a list type)
@@ -85,12 +91,49 @@ pub struct RecursionUnnestOption {
     pub depth: usize,
 }
 
+/// Instruction to emit the position of each unnested element as an extra
+/// column, such as for `UNNEST(..) WITH ORDINALITY` or `UNNEST(..) WITH OFFSET`
+///
+/// For example, unnesting `c1` with an ordinality column named `ord` that
+/// starts at 1:
+///
+/// ```text
+///      ┌─────────┐ ┌─────┐                ┌─────────┐ ┌─────┐ ┌─────┐
+///      │ {1, 2}  │ │  A  │   Unnest       │    1    │ │  A  │ │  1  │
+///      ├─────────┤ ├─────┤ ────────────▶  ├─────────┤ ├─────┤ ├─────┤
+///      │   {3}   │ │  E  │                │    2    │ │  A  │ │  2  │
+///      └─────────┘ └─────┘                ├─────────┤ ├─────┤ ├─────┤
+///        c1         c2                    │    3    │ │  E  │ │  1  │
+///                                         └─────────┘ └─────┘ └─────┘
+///                                             c1        c2      ord
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd)]
+pub struct UnnestOrdinality {
+    /// Name of the emitted column
+    pub name: String,
+    /// Position of the first element of each list, e.g. `1` for
+    /// `WITH ORDINALITY` and `0` for `WITH OFFSET`
+    pub start: i64,
+}
+
+impl UnnestOrdinality {
+    /// Create a new [`UnnestOrdinality`] emitting a column named `name`,
+    /// counting from `start`
+    pub fn new(name: impl Into<String>, start: i64) -> Self {
+        Self {
+            name: name.into(),
+            start,
+        }
+    }
+}
+
 impl Default for UnnestOptions {
     fn default() -> Self {
         Self {
             // default to true to maintain backwards compatible behavior
             preserve_nulls: true,
             recursions: vec![],
+            ordinality: None,
         }
     }
 }
@@ -113,4 +156,11 @@ impl UnnestOptions {
         self.recursions.push(recursion);
         self
     }
+
+    /// Emit the position of each unnested element as an extra column, as
+    /// described on [`UnnestOrdinality`]
+    pub fn with_ordinality(mut self, ordinality: UnnestOrdinality) -> Self {
+        self.ordinality = Some(ordinality);
+        self
+    }
 }
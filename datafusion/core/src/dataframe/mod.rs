@@ -17,6 +17,8 @@
 
 //! [`DataFrame`] API for building and executing query plans.
 
+#[cfg(feature = "avro")]
+mod avro;
 #[cfg(feature = "parquet")]
 mod parquet;
 
@@ -617,6 +619,108 @@ impl DataFrame {
         })
     }
 
+    /// Return a new `DataFrame` that pivots the values of `pivot_column` into
+    /// columns, equivalent to `PIVOT(aggr_expr FOR pivot_column IN (pivot_values))`.
+    ///
+    /// Every column not referenced by `aggr_expr` or `pivot_column` is grouped
+    /// on. See [`LogicalPlanBuilder::pivot`] for details.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # use datafusion::functions_aggregate::expr_fn::sum;
+    /// # use datafusion_common::assert_batches_sorted_eq;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example_long.csv", CsvReadOptions::new()).await?;
+    /// let df = df.pivot(vec![sum(col("c"))], "a", vec![lit(1), lit(4)], None)?;
+    /// let expected = vec![
+    ///     "+---+---+---+",
+    ///     "| b | 1 | 4 |",
+    ///     "+---+---+---+",
+    ///     "| 2 | 3 |   |",
+    ///     "| 5 |   | 6 |",
+    ///     "| 8 |   |   |",
+    ///     "+---+---+---+"
+    /// ];
+    /// # assert_batches_sorted_eq!(expected, &df.collect().await?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn pivot(
+        self,
+        aggr_expr: Vec<Expr>,
+        pivot_column: &str,
+        pivot_values: Vec<Expr>,
+        default_on_null: Option<Expr>,
+    ) -> Result<DataFrame> {
+        let plan = LogicalPlanBuilder::from(self.plan)
+            .pivot(
+                aggr_expr,
+                Column::from(pivot_column),
+                pivot_values,
+                default_on_null,
+            )?
+            .build()?;
+        Ok(DataFrame {
+            session_state: self.session_state,
+            plan,
+            projection_requires_validation: true,
+        })
+    }
+
+    /// Return a new `DataFrame` that turns `columns` into rows, equivalent to
+    /// `UNPIVOT(value_column FOR name_column IN (columns))`.
+    ///
+    /// Rows where the unpivoted value is null are dropped unless
+    /// `include_nulls` is true.
+    ///
+    /// # Example
+    /// ```
+    /// # use datafusion::prelude::*;
+    /// # use datafusion::error::Result;
+    /// # use datafusion_common::assert_batches_sorted_eq;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<()> {
+    /// let ctx = SessionContext::new();
+    /// let df = ctx.read_csv("tests/data/example_long.csv", CsvReadOptions::new()).await?;
+    /// let df = df.unpivot("value", "name", &["b", "c"], false)?;
+    /// let expected = vec![
+    ///     "+---+------+-------+",
+    ///     "| a | name | value |",
+    ///     "+---+------+-------+",
+    ///     "| 1 | b    | 2     |",
+    ///     "| 1 | c    | 3     |",
+    ///     "| 4 | b    | 5     |",
+    ///     "| 4 | c    | 6     |",
+    ///     "| 7 | b    | 8     |",
+    ///     "| 7 | c    | 9     |",
+    ///     "+---+------+-------+"
+    /// ];
+    /// # assert_batches_sorted_eq!(expected, &df.collect().await?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn unpivot(
+        self,
+        value_column: &str,
+        name_column: &str,
+        columns: &[&str],
+        include_nulls: bool,
+    ) -> Result<DataFrame> {
+        let columns = columns.iter().map(|c| Column::from(*c)).collect::<Vec<_>>();
+        let plan = LogicalPlanBuilder::from(self.plan)
+            .unpivot(value_column, name_column, columns, include_nulls)?
+            .build()?;
+        Ok(DataFrame {
+            session_state: self.session_state,
+            plan,
+            projection_requires_validation: true,
+        })
+    }
+
     /// Return a new DataFrame that adds the result of evaluating one or more
     /// window functions ([`Expr::WindowFunction`]) to the existing columns
     pub fn window(self, window_exprs: Vec<Expr>) -> Result<DataFrame> {
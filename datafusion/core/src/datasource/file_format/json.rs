@@ -36,6 +36,7 @@ mod tests {
         BatchDeserializer, DecoderDeserializer, DeserializerOutput,
     };
     use datafusion_datasource::file_format::FileFormat;
+    use datafusion_datasource_json::array::JsonArrayScanner;
     use datafusion_physical_plan::{collect, ExecutionPlan};
 
     use arrow::compute::concat_batches;
@@ -336,6 +337,116 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_json_array_scanner() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("c1", DataType::Int64, true),
+            Field::new("c2", DataType::Utf8, true),
+            Field::new("c3", DataType::new_list(DataType::Int64, true), true),
+        ]));
+        let input = r#" [
+            {"c1": 1, "c2": "a, [b]", "c3": [1, 2]},
+            {"c1": 2, "c2": "\"}],", "c3": []},
+            {"c1": 3, "c2": null, "c3": null}
+        ] "#;
+
+        // Feed the input one byte at a time, to split the values at every offset
+        let mut scanner = JsonArrayScanner::new();
+        let mut deserializer = json_deserializer(3, &schema)?;
+        for chunk in input.as_bytes().chunks(1) {
+            let mut chunk = chunk.to_vec();
+            scanner.scan(&mut chunk)?;
+            deserializer.digest(Bytes::from(chunk));
+        }
+        scanner.finish()?;
+        deserializer.finish();
+
+        let output = deserializer.next()?;
+        let DeserializerOutput::RecordBatch(batch) = output else {
+            panic!("Expected RecordBatch, got {output:?}");
+        };
+        assert_snapshot!(batches_to_string(&[batch]),@r###"
+            +----+--------+--------+
+            | c1 | c2     | c3     |
+            +----+--------+--------+
+            | 1  | a, [b] | [1, 2] |
+            | 2  | "}],   | []     |
+            | 3  |        |        |
+            +----+--------+--------+
+        "###);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_json_array_scanner_errors() {
+        let mut scanner = JsonArrayScanner::new();
+        let err = scanner.scan(&mut br#"{"c1": 1}"#.to_vec()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: Expected a top-level JSON array, found '{'"
+        );
+
+        let mut scanner = JsonArrayScanner::new();
+        scanner
+            .scan(&mut br#"[{"c1": 1}] x"#[..11].to_vec())
+            .unwrap();
+        let err = scanner.scan(&mut b" x".to_vec()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: Unexpected data after the end of the top-level JSON array"
+        );
+
+        let mut scanner = JsonArrayScanner::new();
+        scanner.scan(&mut br#"[{"c1": 1},"#.to_vec()).unwrap();
+        let err = scanner.finish().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Json error: Unexpected end of file within the top-level JSON array"
+        );
+    }
+
+    #[tokio::test]
+    async fn read_json_array_file() -> Result<()> {
+        let tmp_dir = tempfile::TempDir::new()?;
+        let path = tmp_dir.path().join("array.json");
+        std::fs::write(
+            &path,
+            r#"[{"a": 1, "b": "x"}, {"a": 2, "b": "y"},
+            {"a": 3, "b": null}]"#,
+        )?;
+
+        // Array files must not be split into byte ranges
+        let config = SessionConfig::new()
+            .with_repartition_file_scans(true)
+            .with_repartition_file_min_size(0)
+            .with_target_partitions(4);
+        let ctx = SessionContext::new_with_config(config);
+        ctx.sql(&format!(
+            "CREATE EXTERNAL TABLE t STORED AS JSON LOCATION '{}' \
+             OPTIONS ('format.format' 'array')",
+            path.to_str().unwrap()
+        ))
+        .await?;
+        let result = ctx
+            .sql("SELECT a, b FROM t ORDER BY a")
+            .await?
+            .collect()
+            .await?;
+
+        assert_snapshot!(batches_to_string(&result),@r###"
+            +---+---+
+            | a | b |
+            +---+---+
+            | 1 | x |
+            | 2 | y |
+            | 3 |   |
+            +---+---+
+        "###);
+
+        Ok(())
+    }
+
     fn json_deserializer(
         batch_size: usize,
         schema: &Arc<Schema>,
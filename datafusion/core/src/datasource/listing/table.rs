@@ -54,6 +54,7 @@ use datafusion_physical_plan::{empty::EmptyExec, ExecutionPlan, Statistics};
 use futures::{future, stream, Stream, StreamExt, TryStreamExt};
 use itertools::Itertools;
 use object_store::ObjectStore;
+use parking_lot::Mutex;
 use std::{any::Any, collections::HashMap, str::FromStr, sync::Arc};
 /// Indicates the source of the schema for a [`ListingTable`]
 // PartialEq required for assert_eq! in tests
@@ -934,6 +935,9 @@ pub struct ListingTable {
     options: ListingOptions,
     definition: Option<String>,
     collected_statistics: FileStatisticsCache,
+    /// Statistics of the whole table computed by `ANALYZE TABLE`, which are
+    /// removed when data is inserted into the table
+    analyzed_statistics: Arc<Mutex<Option<Statistics>>>,
     constraints: Constraints,
     column_defaults: HashMap<String, Expr>,
     /// Optional [`SchemaAdapterFactory`] for creating schema adapters
@@ -978,6 +982,7 @@ impl ListingTable {
             options,
             definition: None,
             collected_statistics: Arc::new(DefaultFileStatisticsCache::default()),
+            analyzed_statistics: Arc::new(Mutex::new(None)),
             constraints: Constraints::default(),
             column_defaults: HashMap::new(),
             schema_adapter_factory: config.schema_adapter_factory,
@@ -1163,10 +1168,21 @@ impl TableProvider for ListingTable {
         // at the same time. This is because the limit should be applied after the filters are applied.
         let statistic_file_limit = if filters.is_empty() { limit } else { None };
 
-        let (mut partitioned_file_lists, statistics) = self
+        let (mut partitioned_file_lists, mut statistics) = self
             .list_files_for_scan(state, &partition_filters, statistic_file_limit)
             .await?;
 
+        // The analyzed statistics describe all the files of the table, which
+        // may have changed since they were computed
+        if partition_filters.is_empty() && statistic_file_limit.is_none() {
+            if let Some(analyzed) = self.analyzed_statistics.lock().as_ref() {
+                // the table schema starts with the fields of the file schema
+                let columns = (0..statistics.column_statistics.len()).collect();
+                statistics = statistics
+                    .fill_absent(&analyzed.clone().to_inexact().project(Some(&columns)));
+            }
+        }
+
         // if no files need to be read, return an `EmptyExec`
         if partitioned_file_lists.is_empty() {
             let projected_schema = project_schema(&self.schema(), projection)?;
@@ -1260,6 +1276,22 @@ impl TableProvider for ListingTable {
         self.definition.as_deref()
     }
 
+    fn statistics(&self) -> Option<Statistics> {
+        self.analyzed_statistics.lock().clone()
+    }
+
+    fn set_statistics(&self, statistics: Statistics) -> Result<()> {
+        if statistics.column_statistics.len() != self.table_schema.fields().len() {
+            return plan_err!(
+                "Expected statistics for {} columns, got {}",
+                self.table_schema.fields().len(),
+                statistics.column_statistics.len()
+            );
+        }
+        *self.analyzed_statistics.lock() = Some(statistics);
+        Ok(())
+    }
+
     async fn insert_into(
         &self,
         state: &dyn Session,
@@ -1270,6 +1302,9 @@ impl TableProvider for ListingTable {
         self.schema()
             .logically_equivalent_names_and_types(&input.schema())?;
 
+        // The inserted files are not described by the analyzed statistics
+        *self.analyzed_statistics.lock() = None;
+
         let table_path = &self.table_paths()[0];
         if !table_path.is_collection() {
             return plan_err!(
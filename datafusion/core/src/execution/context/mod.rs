@@ -27,15 +27,19 @@ use crate::execution::session_state::SessionStateBuilder;
 use crate::{
     catalog::listing_schema::ListingSchemaProvider,
     catalog::{
-        CatalogProvider, CatalogProviderList, TableProvider, TableProviderFactory,
+        CatalogProvider, CatalogProviderList, SchemaProvider, TableProvider,
+        TableProviderFactory,
     },
     dataframe::DataFrame,
+    datasource::file_format::format_as_file_type,
     datasource::listing::{
         ListingOptions, ListingTable, ListingTableConfig, ListingTableUrl,
     },
+    datasource::view::{MaterializedView, MaterializedViewStorage},
     datasource::{provider_as_source, MemTable, ViewTable},
     error::{DataFusionError, Result},
     execution::{
+        memory_pool::MemoryConsumer,
         options::ArrowReadOptions,
         runtime_env::{RuntimeEnv, RuntimeEnvBuilder},
         FunctionRegistry,
@@ -43,13 +47,18 @@ use crate::{
     logical_expr::AggregateUDF,
     logical_expr::ScalarUDF,
     logical_expr::{
-        CreateCatalog, CreateCatalogSchema, CreateExternalTable, CreateFunction,
-        CreateMemoryTable, CreateView, DropCatalogSchema, DropFunction, DropTable,
-        DropView, Execute, LogicalPlan, LogicalPlanBuilder, Prepare, SetVariable,
-        TableType, UNNAMED_TABLE,
+        AnalyzeTable, CreateCatalog, CreateCatalogSchema, CreateExternalTable,
+        CreateFunction, CreateMaterializedView, CreateMemoryTable, CreateView,
+        DropCatalogSchema, DropFunction, DropTable, DropView, Execute, LogicalPlan,
+        LogicalPlanBuilder, Prepare, RefreshMaterializedView, SetVariable, TableType,
+        TransactionAccessMode, TransactionConclusion, TransactionEnd, TransactionStart,
+        UNNAMED_TABLE,
     },
     physical_expr::PhysicalExpr,
-    physical_plan::ExecutionPlan,
+    physical_plan::{
+        common::{compute_table_statistics, TableStatisticsOptions},
+        ExecutionPlan,
+    },
     variable::{VarProvider, VarType},
 };
 
@@ -68,7 +77,7 @@ use datafusion_common::{
     config::{ConfigExtension, TableOptions},
     exec_datafusion_err, exec_err, not_impl_err, plan_datafusion_err, plan_err,
     tree_node::{TreeNodeRecursion, TreeNodeVisitor},
-    DFSchema, ParamValues, ScalarValue, SchemaReference, TableReference,
+    DFSchema, GetExt, ParamValues, ScalarValue, SchemaReference, TableReference,
 };
 pub use datafusion_execution::config::SessionConfig;
 use datafusion_execution::registry::SerializerRegistry;
@@ -83,13 +92,15 @@ use datafusion_expr::{
 use datafusion_optimizer::analyzer::type_coercion::TypeCoercion;
 use datafusion_optimizer::Analyzer;
 use datafusion_optimizer::{AnalyzerRule, OptimizerRule};
-use datafusion_session::SessionStore;
+use datafusion_session::{SessionStore, Transaction};
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use futures::TryStreamExt;
 use object_store::ObjectStore;
 use parking_lot::RwLock;
 use url::Url;
+use uuid::Uuid;
 
 mod csv;
 mod json;
@@ -660,6 +671,7 @@ impl SessionContext {
     /// SQL, see [`Self::sql_with_options`] and
     /// [`SQLOptions::verify_plan`].
     pub async fn execute_logical_plan(&self, plan: LogicalPlan) -> Result<DataFrame> {
+        self.check_transaction_access(&plan)?;
         match plan {
             LogicalPlan::Ddl(ddl) => {
                 // Box::pin avoids allocating the stack space within this function's frame
@@ -677,6 +689,15 @@ impl SessionContext {
                     DdlStatement::CreateView(cmd) => {
                         Box::pin(self.create_view(cmd)).await
                     }
+                    DdlStatement::CreateMaterializedView(cmd) => {
+                        Box::pin(self.create_materialized_view(cmd)).await
+                    }
+                    DdlStatement::RefreshMaterializedView(cmd) => {
+                        Box::pin(self.refresh_materialized_view(cmd)).await
+                    }
+                    DdlStatement::AnalyzeTable(cmd) => {
+                        Box::pin(self.analyze_table(cmd)).await
+                    }
                     DdlStatement::CreateCatalogSchema(cmd) => {
                         Box::pin(self.create_catalog_schema(cmd)).await
                     }
@@ -697,7 +718,12 @@ impl SessionContext {
                     ddl => Ok(DataFrame::new(self.state(), LogicalPlan::Ddl(ddl))),
                 }
             }
-            // TODO what about the other statements (like TransactionStart and TransactionEnd)
+            LogicalPlan::Statement(Statement::TransactionStart(start)) => {
+                self.begin_transaction(start)
+            }
+            LogicalPlan::Statement(Statement::TransactionEnd(end)) => {
+                self.end_transaction(end).await
+            }
             LogicalPlan::Statement(Statement::SetVariable(stmt)) => {
                 self.set_variable(stmt).await
             }
@@ -773,6 +799,63 @@ impl SessionContext {
         self.state.read().create_physical_expr(expr, df_schema)
     }
 
+    /// Errors if `plan` writes while a read-only transaction is in progress
+    fn check_transaction_access(&self, plan: &LogicalPlan) -> Result<()> {
+        let read_only = self.state.read().transaction().is_some_and(|transaction| {
+            transaction.access_mode() == &TransactionAccessMode::ReadOnly
+        });
+        if !read_only {
+            return Ok(());
+        }
+        match plan {
+            LogicalPlan::Dml(dml) => {
+                exec_err!("Cannot execute {} in a read-only transaction", dml.op)
+            }
+            LogicalPlan::Copy(_) => {
+                exec_err!("Cannot execute COPY in a read-only transaction")
+            }
+            LogicalPlan::Ddl(ddl) => {
+                exec_err!("Cannot execute {} in a read-only transaction", ddl.name())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Starts a transaction, in which the changes to the tables supporting
+    /// transactions stay private until it is committed
+    fn begin_transaction(&self, start: TransactionStart) -> Result<DataFrame> {
+        {
+            let mut state = self.state.write();
+            if state.transaction().is_some() {
+                return exec_err!("There is already a transaction in progress");
+            }
+            state.set_transaction(Some(Arc::new(Transaction::new(start))));
+        }
+        self.return_empty_dataframe()
+    }
+
+    /// Commits or rolls back the current transaction, starting a new one
+    /// with the same characteristics if `chain` is set
+    async fn end_transaction(&self, end: TransactionEnd) -> Result<DataFrame> {
+        let TransactionEnd { conclusion, chain } = end;
+        let transaction = self.state.write().set_transaction(None);
+        let Some(transaction) = transaction else {
+            return exec_err!("There is no transaction in progress");
+        };
+
+        match conclusion {
+            TransactionConclusion::Commit => transaction.commit().await?,
+            TransactionConclusion::Rollback => transaction.rollback().await?,
+        }
+        if chain {
+            let start = transaction.start().clone();
+            self.state
+                .write()
+                .set_transaction(Some(Arc::new(Transaction::new(start))));
+        }
+        self.return_empty_dataframe()
+    }
+
     // return an empty dataframe
     fn return_empty_dataframe(&self) -> Result<DataFrame> {
         let plan = LogicalPlanBuilder::empty(false).build()?;
@@ -783,11 +866,8 @@ impl SessionContext {
         &self,
         cmd: &CreateExternalTable,
     ) -> Result<DataFrame> {
-        let exist = self.table_exist(cmd.name.clone())?;
-
-        if cmd.temporary {
-            return not_impl_err!("Temporary tables not supported");
-        }
+        let schema = self.schema_for_new_table(&cmd.name, cmd.temporary)?;
+        let exist = schema.table_exist(cmd.name.table());
 
         if exist {
             match cmd.if_not_exists {
@@ -800,10 +880,28 @@ impl SessionContext {
 
         let table_provider: Arc<dyn TableProvider> =
             self.create_custom_table(cmd).await?;
-        self.register_table(cmd.name.clone(), table_provider)?;
+        schema.register_table(cmd.name.table().to_string(), table_provider)?;
         self.return_empty_dataframe()
     }
 
+    /// Returns the schema a table created as `name` is registered in: the
+    /// temporary schema of this session for temporary tables, otherwise the
+    /// schema `name` resolves to in the catalog.
+    fn schema_for_new_table(
+        &self,
+        name: &TableReference,
+        temporary: bool,
+    ) -> Result<Arc<dyn SchemaProvider>> {
+        let state = self.state.read();
+        if !temporary {
+            return state.catalog_schema_for_ref(name.clone());
+        }
+        match name {
+            TableReference::Bare { .. } => Ok(Arc::clone(state.temporary_schema())),
+            _ => exec_err!("Temporary table '{name}' cannot be created in a schema"),
+        }
+    }
+
     async fn create_memory_table(&self, cmd: CreateMemoryTable) -> Result<DataFrame> {
         let CreateMemoryTable {
             name,
@@ -818,15 +916,16 @@ impl SessionContext {
         let input = Arc::unwrap_or_clone(input);
         let input = self.state().optimize(&input)?;
 
-        if temporary {
-            return not_impl_err!("Temporary tables not supported");
-        }
-
-        let table = self.table(name.clone()).await;
-        match (if_not_exists, or_replace, table) {
-            (true, false, Ok(_)) => self.return_empty_dataframe(),
-            (false, true, Ok(_)) => {
-                self.deregister_table(name.clone())?;
+        let schema_provider = self.schema_for_new_table(&name, temporary)?;
+        let table_name = name.table().to_string();
+        match (
+            if_not_exists,
+            or_replace,
+            schema_provider.table_exist(&table_name),
+        ) {
+            (true, false, true) => self.return_empty_dataframe(),
+            (false, true, true) => {
+                schema_provider.deregister_table(&table_name)?;
                 let schema = Arc::new(input.schema().as_ref().into());
                 let physical = DataFrame::new(self.state(), input);
 
@@ -838,13 +937,13 @@ impl SessionContext {
                         .with_column_defaults(column_defaults.into_iter().collect()),
                 );
 
-                self.register_table(name.clone(), table)?;
+                schema_provider.register_table(table_name, table)?;
                 self.return_empty_dataframe()
             }
-            (true, true, Ok(_)) => {
+            (true, true, true) => {
                 exec_err!("'IF NOT EXISTS' cannot coexist with 'REPLACE'")
             }
-            (_, _, Err(_)) => {
+            (_, _, false) => {
                 let df_schema = input.schema();
                 let schema = Arc::new(df_schema.as_ref().into());
                 let physical = DataFrame::new(self.state(), input);
@@ -857,10 +956,10 @@ impl SessionContext {
                         .with_column_defaults(column_defaults.into_iter().collect()),
                 );
 
-                self.register_table(name, table)?;
+                schema_provider.register_table(table_name, table)?;
                 self.return_empty_dataframe()
             }
-            (false, false, Ok(_)) => exec_err!("Table '{name}' already exists"),
+            (false, false, true) => exec_err!("Table '{name}' already exists"),
         }
     }
 
@@ -907,6 +1006,251 @@ impl SessionContext {
         }
     }
 
+    async fn create_materialized_view(
+        &self,
+        cmd: CreateMaterializedView,
+    ) -> Result<DataFrame> {
+        let CreateMaterializedView {
+            name,
+            input,
+            location,
+            file_type,
+            options,
+            or_replace,
+            if_not_exists,
+            definition,
+        } = cmd;
+
+        let exists = self.table_exist(name.clone())?;
+        match (if_not_exists, or_replace, exists) {
+            (true, false, true) => return self.return_empty_dataframe(),
+            (true, true, true) => {
+                return exec_err!("'IF NOT EXISTS' cannot coexist with 'REPLACE'")
+            }
+            (false, false, true) => return exec_err!("Table '{name}' already exists"),
+            _ => {}
+        }
+
+        let storage = match (location, file_type) {
+            (None, None) => MaterializedViewStorage::Memory,
+            (Some(location), Some(file_type)) => MaterializedViewStorage::Files {
+                location,
+                file_type,
+                options: options.into_iter().collect(),
+            },
+            _ => {
+                return plan_err!(
+                "The file type and location of materialized views must be set together"
+            )
+            }
+        };
+
+        // A view replacing a materialized view with the same location owns
+        // that location, which must be empty otherwise so that no user data
+        // is mixed with or overwritten by the content of the view
+        let previous = if exists {
+            self.table_provider(name.clone()).await.ok()
+        } else {
+            None
+        };
+        let previous_content = previous
+            .as_ref()
+            .and_then(|table| table.as_any().downcast_ref::<MaterializedView>())
+            .filter(|view| view.storage() == &storage)
+            .map(|view| view.content());
+        if let (MaterializedViewStorage::Files { location, .. }, None) =
+            (&storage, &previous_content)
+        {
+            let (url, store) = self.materialized_view_location(location)?;
+            if store.list(Some(url.prefix())).try_next().await?.is_some() {
+                return plan_err!(
+                    "The location '{location}' of materialized view '{name}' is not empty"
+                );
+            }
+        }
+
+        let input = Self::apply_type_coercion(Arc::unwrap_or_clone(input))?;
+        let content = self.materialize(&name, input.clone(), &storage).await?;
+        if exists {
+            self.deregister_table(name.clone())?;
+        }
+        let view = MaterializedView::new(input, definition, storage, content);
+        self.register_table(name, Arc::new(view))?;
+        if let Some(previous_content) = previous_content {
+            self.remove_materialized_content(&previous_content).await?;
+        }
+        self.return_empty_dataframe()
+    }
+
+    async fn refresh_materialized_view(
+        &self,
+        cmd: RefreshMaterializedView,
+    ) -> Result<DataFrame> {
+        let RefreshMaterializedView { name, .. } = cmd;
+
+        let table = self.table_provider(name.clone()).await?;
+        let Some(view) = table.as_any().downcast_ref::<MaterializedView>() else {
+            return exec_err!("'{name}' is not a materialized view");
+        };
+        let content = self
+            .materialize(&name, view.logical_plan().clone(), view.storage())
+            .await?;
+        let previous_content = view.content();
+        view.set_content(content);
+        self.remove_materialized_content(&previous_content).await?;
+        self.return_empty_dataframe()
+    }
+
+    /// Computes the statistics of the table by reading the analyzed columns
+    /// of all its rows, and stores them with [`TableProvider::set_statistics`]
+    async fn analyze_table(&self, cmd: AnalyzeTable) -> Result<DataFrame> {
+        let AnalyzeTable { name, columns, .. } = cmd;
+
+        let table = self.table_provider(name).await?;
+        let schema = table.schema();
+        let mut columns = columns
+            .iter()
+            .map(|column| schema.index_of(column))
+            .collect::<Result<Vec<_>, _>>()?;
+        // the optimizer may reorder the projected columns in the table order
+        columns.sort_unstable();
+        let options = {
+            let state = self.state();
+            let options = &state.config_options().execution;
+            TableStatisticsOptions {
+                num_buckets: options.analyze_histogram_buckets,
+                num_most_common_values: options.analyze_most_common_values,
+                sample_size: options.analyze_sample_size,
+                exact: options.analyze_exact_statistics,
+            }
+        };
+
+        let plan = LogicalPlanBuilder::scan(
+            UNNAMED_TABLE,
+            provider_as_source(Arc::clone(&table)),
+            Some(columns.clone()),
+        )?
+        .build()?;
+        let stream = DataFrame::new(self.state(), plan).execute_stream().await?;
+        let reservation =
+            MemoryConsumer::new("AnalyzeTable").register(&self.runtime_env().memory_pool);
+        let statistics =
+            compute_table_statistics(stream, &schema, &columns, options, reservation)
+                .await?;
+        table.set_statistics(statistics)?;
+        self.return_empty_dataframe()
+    }
+
+    /// Executes `plan` and stores its result as described by `storage`,
+    /// returning the table holding the content of materialized view `name`
+    async fn materialize(
+        &self,
+        name: &TableReference,
+        plan: LogicalPlan,
+        storage: &MaterializedViewStorage,
+    ) -> Result<Arc<dyn TableProvider>> {
+        let df_schema = Arc::clone(plan.schema());
+        match storage {
+            MaterializedViewStorage::Memory => {
+                let schema = Arc::new(df_schema.as_ref().into());
+                let batches = DataFrame::new(self.state(), plan)
+                    .collect_partitioned()
+                    .await?;
+                Ok(Arc::new(MemTable::try_new(schema, batches)?))
+            }
+            MaterializedViewStorage::Files {
+                location,
+                file_type,
+                options,
+            } => {
+                let state = self.state();
+                let factory =
+                    state.get_file_format_factory(file_type).ok_or_else(|| {
+                        plan_datafusion_err!(
+                            "There is no registered file format with ext {file_type}"
+                        )
+                    })?;
+
+                // Each materialization writes to a new subdirectory of the
+                // location, so that the previous content stays intact until
+                // the view switches to the new one
+                let location =
+                    format!("{}/{}/", location.trim_end_matches('/'), Uuid::new_v4());
+                let copy = LogicalPlanBuilder::copy_to(
+                    plan,
+                    location.clone(),
+                    format_as_file_type(factory),
+                    options.clone(),
+                    vec![],
+                )?
+                .build()?;
+                if let Err(e) = DataFrame::new(state, copy).collect().await {
+                    let (url, store) = self.materialized_view_location(&location)?;
+                    Self::remove_files(&url, store.as_ref()).await?;
+                    return Err(e);
+                }
+
+                let cmd = CreateExternalTable {
+                    schema: df_schema,
+                    name: name.clone(),
+                    location,
+                    file_type: file_type.clone(),
+                    table_partition_cols: vec![],
+                    if_not_exists: false,
+                    temporary: false,
+                    definition: None,
+                    order_exprs: vec![],
+                    unbounded: false,
+                    options: options.clone(),
+                    constraints: Default::default(),
+                    column_defaults: Default::default(),
+                };
+                self.create_custom_table(&cmd).await
+            }
+        }
+    }
+
+    /// Returns the url and object store of the directory `location` of a
+    /// materialized view
+    fn materialized_view_location(
+        &self,
+        location: &str,
+    ) -> Result<(ListingTableUrl, Arc<dyn ObjectStore>)> {
+        let location = if location.ends_with('/') {
+            location.to_string()
+        } else {
+            format!("{location}/")
+        };
+        let url = ListingTableUrl::parse(location)?;
+        let store = self.runtime_env().object_store(&url)?;
+        Ok((url, store))
+    }
+
+    /// Removes the files of a previous content of a materialized view, once
+    /// the view has switched to a new content
+    async fn remove_materialized_content(
+        &self,
+        content: &Arc<dyn TableProvider>,
+    ) -> Result<()> {
+        let Some(table) = content.as_any().downcast_ref::<ListingTable>() else {
+            return Ok(());
+        };
+        for url in table.table_paths() {
+            let store = self.runtime_env().object_store(url)?;
+            Self::remove_files(url, store.as_ref()).await?;
+        }
+        Ok(())
+    }
+
+    /// Removes all the files in the directory `url`
+    async fn remove_files(url: &ListingTableUrl, store: &dyn ObjectStore) -> Result<()> {
+        let files: Vec<_> = store.list(Some(url.prefix())).try_collect().await?;
+        for file in files {
+            store.delete(&file.location).await?;
+        }
+        Ok(())
+    }
+
     async fn create_catalog_schema(&self, cmd: CreateCatalogSchema) -> Result<DataFrame> {
         let CreateCatalogSchema {
             schema_name,
@@ -1139,11 +1483,13 @@ impl SessionContext {
         let table = table_ref.table().to_owned();
         let maybe_schema = {
             let state = self.state.read();
-            let resolved = state.resolve_table_ref(table_ref);
-            state
-                .catalog_list()
-                .catalog(&resolved.catalog)
-                .and_then(|c| c.schema(&resolved.schema))
+            state.temporary_schema_for_ref(&table_ref).or_else(|| {
+                let resolved = state.resolve_table_ref(table_ref);
+                state
+                    .catalog_list()
+                    .catalog(&resolved.catalog)
+                    .and_then(|c| c.schema(&resolved.schema))
+            })
         };
 
         if let Some(schema) = maybe_schema {
@@ -1903,7 +2249,8 @@ mod tests {
     use std::error::Error;
     use std::path::PathBuf;
 
-    use datafusion_common::test_util::batches_to_string;
+    use datafusion_common::assert_contains;
+    use datafusion_common::test_util::{batches_to_sort_string, batches_to_string};
     use datafusion_common_runtime::SpawnedTask;
     use insta::{allow_duplicates, assert_snapshot};
 
@@ -2319,6 +2666,189 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn temporary_tables_are_session_local() -> Result<()> {
+        let ctx = SessionContext::new();
+        ctx.sql("CREATE TABLE t AS VALUES (1)")
+            .await?
+            .collect()
+            .await?;
+
+        // a second session sharing the catalog of the first one
+        let other = SessionContext::new_with_state(
+            SessionStateBuilder::new_from_existing(ctx.state()).build(),
+        );
+        other
+            .sql("CREATE TEMPORARY TABLE t AS VALUES (2)")
+            .await?
+            .collect()
+            .await?;
+
+        // the temporary table only shadows `t` in the session that created it
+        let result = plan_and_collect(&other, "SELECT * FROM t").await?;
+        assert_snapshot!(batches_to_string(&result), @r"
+        +---------+
+        | column1 |
+        +---------+
+        | 2       |
+        +---------+
+        ");
+        let result = plan_and_collect(&ctx, "SELECT * FROM t").await?;
+        assert_snapshot!(batches_to_string(&result), @r"
+        +---------+
+        | column1 |
+        +---------+
+        | 1       |
+        +---------+
+        ");
+
+        // qualified references skip the temporary tables
+        let result =
+            plan_and_collect(&other, "SELECT * FROM datafusion.public.t").await?;
+        assert_snapshot!(batches_to_string(&result), @r"
+        +---------+
+        | column1 |
+        +---------+
+        | 1       |
+        +---------+
+        ");
+
+        let temporary_schema = Arc::downgrade(other.state().temporary_schema());
+        drop(other);
+
+        assert_eq!(Weak::strong_count(&temporary_schema), 0);
+        assert!(ctx.table_exist("t")?);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn transaction_writes_visible_on_commit() -> Result<()> {
+        let ctx = SessionContext::new();
+        ctx.sql("CREATE TABLE t AS VALUES (1)")
+            .await?
+            .collect()
+            .await?;
+
+        // a second session sharing the catalog of the first one
+        let other = SessionContext::new_with_state(
+            SessionStateBuilder::new_from_existing(ctx.state()).build(),
+        );
+
+        ctx.sql("BEGIN").await?.collect().await?;
+        ctx.sql("INSERT INTO t VALUES (2)").await?.collect().await?;
+
+        // the write is only visible within the transaction
+        let result = plan_and_collect(&ctx, "SELECT count(*) FROM t").await?;
+        assert_snapshot!(batches_to_string(&result), @r"
+        +----------+
+        | count(*) |
+        +----------+
+        | 2        |
+        +----------+
+        ");
+        let result = plan_and_collect(&other, "SELECT count(*) FROM t").await?;
+        assert_snapshot!(batches_to_string(&result), @r"
+        +----------+
+        | count(*) |
+        +----------+
+        | 1        |
+        +----------+
+        ");
+
+        ctx.sql("COMMIT").await?.collect().await?;
+
+        let result = plan_and_collect(&other, "SELECT count(*) FROM t").await?;
+        assert_snapshot!(batches_to_string(&result), @r"
+        +----------+
+        | count(*) |
+        +----------+
+        | 2        |
+        +----------+
+        ");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn transaction_reads_snapshot() -> Result<()> {
+        let ctx = SessionContext::new();
+        ctx.sql("CREATE TABLE t AS VALUES (1)")
+            .await?
+            .collect()
+            .await?;
+        let other = SessionContext::new_with_state(
+            SessionStateBuilder::new_from_existing(ctx.state()).build(),
+        );
+
+        ctx.sql("BEGIN").await?.collect().await?;
+        plan_and_collect(&ctx, "SELECT count(*) FROM t").await?;
+        plan_and_collect(&other, "INSERT INTO t VALUES (2)").await?;
+
+        // the transaction keeps reading the content of its first access
+        let result = plan_and_collect(&ctx, "SELECT count(*) FROM t").await?;
+        assert_snapshot!(batches_to_string(&result), @r"
+        +----------+
+        | count(*) |
+        +----------+
+        | 1        |
+        +----------+
+        ");
+
+        // committing without writing does not conflict
+        ctx.sql("COMMIT").await?.collect().await?;
+        let result = plan_and_collect(&ctx, "SELECT count(*) FROM t").await?;
+        assert_snapshot!(batches_to_string(&result), @r"
+        +----------+
+        | count(*) |
+        +----------+
+        | 2        |
+        +----------+
+        ");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn transaction_conflicting_commit_fails() -> Result<()> {
+        let ctx = SessionContext::new();
+        ctx.sql("CREATE TABLE t AS VALUES (1)")
+            .await?
+            .collect()
+            .await?;
+        ctx.sql("CREATE TABLE u AS VALUES (1)")
+            .await?
+            .collect()
+            .await?;
+        let other = SessionContext::new_with_state(
+            SessionStateBuilder::new_from_existing(ctx.state()).build(),
+        );
+
+        ctx.sql("BEGIN").await?.collect().await?;
+        other.sql("BEGIN").await?.collect().await?;
+        plan_and_collect(&ctx, "INSERT INTO u VALUES (2)").await?;
+        plan_and_collect(&ctx, "INSERT INTO t VALUES (2)").await?;
+        plan_and_collect(&other, "INSERT INTO t VALUES (3)").await?;
+        other.sql("COMMIT").await?.collect().await?;
+
+        let err = ctx.sql("COMMIT").await?.collect().await.unwrap_err();
+        assert_contains!(
+            err.to_string(),
+            "the table was changed by a concurrent transaction"
+        );
+
+        // none of the changes of the failed transaction are committed
+        let result =
+            plan_and_collect(&ctx, "SELECT * FROM t UNION ALL SELECT * FROM u").await?;
+        assert_snapshot!(batches_to_sort_string(&result), @r"
+        +---------+
+        | column1 |
+        +---------+
+        | 1       |
+        | 1       |
+        | 3       |
+        +---------+
+        ");
+        Ok(())
+    }
+
     #[tokio::test]
     async fn custom_type_planner() -> Result<()> {
         let state = SessionStateBuilder::new()
@@ -35,7 +35,7 @@ use datafusion_catalog::information_schema::{
 };
 
 use arrow::datatypes::{DataType, SchemaRef};
-use datafusion_catalog::MemoryCatalogProviderList;
+use datafusion_catalog::{MemoryCatalogProviderList, MemorySchemaProvider};
 use datafusion_catalog::{TableFunction, TableFunctionImpl};
 use datafusion_common::alias::AliasGenerator;
 use datafusion_common::config::{ConfigExtension, ConfigOptions, TableOptions};
@@ -68,7 +68,7 @@ use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
 use datafusion_physical_optimizer::optimizer::PhysicalOptimizer;
 use datafusion_physical_optimizer::PhysicalOptimizerRule;
 use datafusion_physical_plan::ExecutionPlan;
-use datafusion_session::Session;
+use datafusion_session::{Session, Transaction};
 use datafusion_sql::parser::{DFParserBuilder, Statement};
 use datafusion_sql::planner::{ContextProvider, ParserOptions, PlannerContext, SqlToRel};
 
@@ -141,6 +141,10 @@ pub struct SessionState {
     query_planner: Arc<dyn QueryPlanner + Send + Sync>,
     /// Collection of catalogs containing schemas and ultimately TableProviders
     catalog_list: Arc<dyn CatalogProviderList>,
+    /// Session-local schema holding the tables created with `CREATE TEMPORARY
+    /// TABLE`. It is not part of `catalog_list`, so its tables are only visible
+    /// to this session and are dropped together with it.
+    temporary_schema: Arc<dyn SchemaProvider>,
     /// Table Functions
     table_functions: HashMap<String, Arc<TableFunction>>,
     /// Scalar functions that are registered with the context
@@ -179,6 +183,8 @@ pub struct SessionState {
     /// Cache logical plans of prepared statements for later execution.
     /// Key is the prepared statement name.
     prepared_plans: HashMap<String, Arc<PreparedPlan>>,
+    /// The transaction started with `BEGIN`, if any
+    transaction: Option<Arc<Transaction>>,
 }
 
 impl Debug for SessionState {
@@ -206,7 +212,9 @@ impl Debug for SessionState {
             .field("scalar_functions", &self.scalar_functions)
             .field("aggregate_functions", &self.aggregate_functions)
             .field("window_functions", &self.window_functions)
+            .field("temporary_schema", &self.temporary_schema)
             .field("prepared_plans", &self.prepared_plans)
+            .field("transaction", &self.transaction)
             .finish()
     }
 }
@@ -271,6 +279,10 @@ impl Session for SessionState {
     fn task_ctx(&self) -> Arc<TaskContext> {
         self.task_ctx()
     }
+
+    fn transaction(&self) -> Option<&Arc<Transaction>> {
+        self.transaction()
+    }
 }
 
 impl SessionState {
@@ -297,9 +309,42 @@ impl SessionState {
 
     /// Retrieve the [`SchemaProvider`] for a specific [`TableReference`], if it
     /// exists.
+    ///
+    /// Unqualified references to a temporary table of this session resolve to
+    /// the [temporary schema](Self::temporary_schema), shadowing any table of
+    /// the same name in the default schema.
     pub fn schema_for_ref(
         &self,
         table_ref: impl Into<TableReference>,
+    ) -> datafusion_common::Result<Arc<dyn SchemaProvider>> {
+        let table_ref = table_ref.into();
+        match self.temporary_schema_for_ref(&table_ref) {
+            Some(schema) => Ok(schema),
+            None => self.catalog_schema_for_ref(table_ref),
+        }
+    }
+
+    /// Returns the [temporary schema](Self::temporary_schema) if `table_ref`
+    /// is unqualified and names a temporary table of this session.
+    pub(crate) fn temporary_schema_for_ref(
+        &self,
+        table_ref: &TableReference,
+    ) -> Option<Arc<dyn SchemaProvider>> {
+        match table_ref {
+            TableReference::Bare { table }
+                if self.temporary_schema.table_exist(table) =>
+            {
+                Some(Arc::clone(&self.temporary_schema))
+            }
+            _ => None,
+        }
+    }
+
+    /// Retrieve the [`SchemaProvider`] for a specific [`TableReference`] from
+    /// the catalog, ignoring temporary tables.
+    pub(crate) fn catalog_schema_for_ref(
+        &self,
+        table_ref: impl Into<TableReference>,
     ) -> datafusion_common::Result<Arc<dyn SchemaProvider>> {
         let resolved_ref = self.resolve_table_ref(table_ref);
         if self.config.information_schema() && *resolved_ref.schema == *INFORMATION_SCHEMA
@@ -467,9 +512,20 @@ impl SessionState {
         let mut provider = SessionContextProvider {
             state: self,
             tables: HashMap::with_capacity(references.len()),
+            temporary_tables: HashMap::new(),
         };
 
         for reference in references {
+            if let Some(schema) = self.temporary_schema_for_ref(&reference) {
+                let table = reference.table();
+                if let Some(provider_table) = schema.table(table).await? {
+                    provider
+                        .temporary_tables
+                        .insert(table.to_string(), provider_as_source(provider_table));
+                }
+                continue;
+            }
+
             let resolved = self.resolve_table_ref(reference);
             if let Entry::Vacant(v) = provider.tables.entry(resolved) {
                 let resolved = v.key();
@@ -799,6 +855,25 @@ impl SessionState {
         &self.catalog_list
     }
 
+    /// Return the session-local schema holding the temporary tables of this
+    /// session
+    pub fn temporary_schema(&self) -> &Arc<dyn SchemaProvider> {
+        &self.temporary_schema
+    }
+
+    /// Return the transaction started with `BEGIN`, if any
+    pub fn transaction(&self) -> Option<&Arc<Transaction>> {
+        self.transaction.as_ref()
+    }
+
+    /// Set the current transaction, returning the previous one
+    pub(crate) fn set_transaction(
+        &mut self,
+        transaction: Option<Arc<Transaction>>,
+    ) -> Option<Arc<Transaction>> {
+        std::mem::replace(&mut self.transaction, transaction)
+    }
+
     /// set the catalog list
     pub(crate) fn register_catalog_list(
         &mut self,
@@ -1371,6 +1446,7 @@ impl SessionStateBuilder {
             catalog_list: catalog_list.unwrap_or_else(|| {
                 Arc::new(MemoryCatalogProviderList::new()) as Arc<dyn CatalogProviderList>
             }),
+            temporary_schema: Arc::new(MemorySchemaProvider::new()),
             table_functions: table_functions.unwrap_or_default(),
             scalar_functions: HashMap::new(),
             aggregate_functions: HashMap::new(),
@@ -1387,6 +1463,7 @@ impl SessionStateBuilder {
             runtime_env,
             function_factory,
             prepared_plans: HashMap::new(),
+            transaction: None,
         };
 
         if let Some(file_formats) = file_formats {
@@ -1642,6 +1719,8 @@ impl From<SessionState> for SessionStateBuilder {
 struct SessionContextProvider<'a> {
     state: &'a SessionState,
     tables: HashMap<ResolvedTableReference, Arc<dyn TableSource>>,
+    /// Temporary tables referenced by their unqualified name
+    temporary_tables: HashMap<String, Arc<dyn TableSource>>,
 }
 
 impl ContextProvider for SessionContextProvider<'_> {
@@ -1661,6 +1740,11 @@ impl ContextProvider for SessionContextProvider<'_> {
         &self,
         name: TableReference,
     ) -> datafusion_common::Result<Arc<dyn TableSource>> {
+        if let TableReference::Bare { table } = &name {
+            if let Some(source) = self.temporary_tables.get(table.as_ref()) {
+                return Ok(Arc::clone(source));
+            }
+        }
         let name = self.state.resolve_table_ref(name);
         self.tables
             .get(&name)
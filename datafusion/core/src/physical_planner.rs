@@ -42,9 +42,13 @@ use crate::physical_plan::explain::ExplainExec;
 use crate::physical_plan::filter::FilterExec;
 use crate::physical_plan::joins::utils as join_utils;
 use crate::physical_plan::joins::{
-    CrossJoinExec, HashJoinExec, NestedLoopJoinExec, PartitionMode, SortMergeJoinExec,
+    AsofJoinExec, CrossJoinExec, HashJoinExec, NestedLoopJoinExec, PartitionMode,
+    SortMergeJoinExec,
 };
 use crate::physical_plan::limit::{GlobalLimitExec, LocalLimitExec};
+use crate::physical_plan::match_recognize::{
+    MatchDefinitionExpr, MatchMeasureExpr, MatchRecognizeExec,
+};
 use crate::physical_plan::projection::ProjectionExec;
 use crate::physical_plan::repartition::RepartitionExec;
 use crate::physical_plan::sorts::sort::SortExec;
@@ -59,7 +63,7 @@ use crate::schema_equivalence::schema_satisfied_by;
 
 use arrow::array::{builder::StringBuilder, RecordBatch};
 use arrow::compute::SortOptions;
-use arrow::datatypes::{Schema, SchemaRef};
+use arrow::datatypes::{Field, Schema, SchemaRef};
 use datafusion_common::display::ToStringifiedPlan;
 use datafusion_common::tree_node::{
     Transformed, TransformedResult, TreeNode, TreeNodeRecursion, TreeNodeVisitor,
@@ -75,11 +79,13 @@ use datafusion_expr::expr::{
     physical_name, AggregateFunction, AggregateFunctionParams, Alias, GroupingSet,
     WindowFunction, WindowFunctionParams,
 };
-use datafusion_expr::expr_rewriter::unnormalize_cols;
+use datafusion_expr::expr_rewriter::{unnormalize_col, unnormalize_cols};
 use datafusion_expr::logical_plan::builder::wrap_projection_for_join_if_necessary;
+use datafusion_expr::utils::split_conjunction;
 use datafusion_expr::{
-    Analyze, DescribeTable, DmlStatement, Explain, ExplainFormat, Extension, FetchType,
-    Filter, JoinType, RecursiveQuery, SkipType, StringifiedPlan, WindowFrame,
+    ident, lit, Analyze, DescribeTable, DmlStatement, Explain, ExplainFormat,
+    ExprSchemable, Extension, FetchType, Filter, JoinType, MatchAggregate, MatchMeasure,
+    RecursiveQuery, SkipType, StringifiedPlan, SubqueryAlias, WindowFrame,
     WindowFrameBound, WriteOp,
 };
 use datafusion_physical_expr::aggregate::{AggregateExprBuilder, AggregateFunctionExpr};
@@ -586,6 +592,70 @@ impl DefaultPhysicalPlanner {
                     );
                 }
             }
+            LogicalPlan::Dml(DmlStatement {
+                target,
+                op: WriteOp::Merge(merge_op),
+                ..
+            }) => {
+                if let Some(provider) =
+                    target.as_any().downcast_ref::<DefaultTableSource>()
+                {
+                    let input_exec = children.one()?;
+                    provider
+                        .table_provider
+                        .merge_into(session_state, input_exec, merge_op)
+                        .await?
+                } else {
+                    return exec_err!(
+                        "Table source can't be downcasted to DefaultTableSource"
+                    );
+                }
+            }
+            LogicalPlan::Dml(DmlStatement {
+                table_name,
+                target,
+                op: WriteOp::Delete,
+                input,
+                ..
+            }) => {
+                if let Some(provider) =
+                    target.as_any().downcast_ref::<DefaultTableSource>()
+                {
+                    let filters =
+                        extract_dml_filters(input, table_name, &WriteOp::Delete)?;
+                    provider
+                        .table_provider
+                        .delete_from(session_state, filters)
+                        .await?
+                } else {
+                    return exec_err!(
+                        "Table source can't be downcasted to DefaultTableSource"
+                    );
+                }
+            }
+            LogicalPlan::Dml(DmlStatement {
+                table_name,
+                target,
+                op: WriteOp::Update,
+                input,
+                ..
+            }) => {
+                if let Some(provider) =
+                    target.as_any().downcast_ref::<DefaultTableSource>()
+                {
+                    let filters =
+                        extract_dml_filters(input, table_name, &WriteOp::Update)?;
+                    let assignments = extract_update_assignments(input)?;
+                    provider
+                        .table_provider
+                        .update(session_state, assignments, filters)
+                        .await?
+                } else {
+                    return exec_err!(
+                        "Table source can't be downcasted to DefaultTableSource"
+                    );
+                }
+            }
             LogicalPlan::Window(Window { window_expr, .. }) => {
                 if window_expr.is_empty() {
                     return internal_err!("Impossibly got empty window expression");
@@ -945,6 +1015,132 @@ impl DefaultPhysicalPlanner {
                     options.clone(),
                 ))
             }
+            LogicalPlan::MatchRecognize(match_recognize) => {
+                let input_exec = children.one()?;
+                let physical_input_schema = input_exec.schema();
+                let logical_input_schema = match_recognize.input.schema();
+                let execution_props = session_state.execution_props();
+                let partition_by = match_recognize
+                    .partition_by
+                    .iter()
+                    .map(|e| {
+                        self.create_physical_expr(e, logical_input_schema, session_state)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let order_by = create_physical_sort_exprs(
+                    &match_recognize.order_by,
+                    logical_input_schema,
+                    execution_props,
+                )?;
+                let symbols = match_recognize
+                    .symbols()
+                    .into_iter()
+                    .map(String::from)
+                    .collect::<Vec<_>>();
+                let create_aggregate = |expr: &Expr, symbol: Option<&str>| {
+                    let (expr, filter, _) = create_aggregate_expr_and_maybe_filter(
+                        expr,
+                        logical_input_schema,
+                        &physical_input_schema,
+                        execution_props,
+                    )?;
+                    let symbol = symbol
+                        .map(|symbol| {
+                            symbols.iter().position(|s| s == symbol).ok_or_else(|| {
+                                internal_datafusion_err!(
+                                    "Unknown pattern variable {symbol}"
+                                )
+                            })
+                        })
+                        .transpose()?;
+                    Ok::<_, DataFusionError>(MatchMeasureExpr::Aggregate {
+                        expr,
+                        filter,
+                        symbol,
+                    })
+                };
+                let definitions = symbols
+                    .iter()
+                    .map(|symbol| {
+                        let Some(definition) = match_recognize
+                            .definitions
+                            .iter()
+                            .find(|definition| definition.symbol == *symbol)
+                        else {
+                            return Ok(None);
+                        };
+                        // The aggregates of the match in progress are
+                        // computed by the operator and passed to the
+                        // predicate as extra columns
+                        let mut references = vec![];
+                        let mut fields = vec![];
+                        let predicate = definition
+                            .predicate
+                            .clone()
+                            .transform_down(|expr| {
+                                let Some((aggregate, symbol)) =
+                                    MatchAggregate::arguments(&expr)?
+                                else {
+                                    return Ok(Transformed::no(expr));
+                                };
+                                let name =
+                                    format!("__match_recognize_ref_{}", references.len());
+                                fields.push(Field::new(
+                                    &name,
+                                    aggregate.get_type(logical_input_schema)?,
+                                    true,
+                                ));
+                                references.push(create_aggregate(aggregate, symbol)?);
+                                Ok(Transformed::new(
+                                    ident(name),
+                                    true,
+                                    TreeNodeRecursion::Jump,
+                                ))
+                            })
+                            .data()?;
+                        let schema = logical_input_schema.join(
+                            &DFSchema::from_unqualified_fields(
+                                fields.into(),
+                                HashMap::new(),
+                            )?,
+                        )?;
+                        let predicate = self.create_physical_expr(
+                            &predicate,
+                            &schema,
+                            session_state,
+                        )?;
+                        Ok(Some(MatchDefinitionExpr {
+                            predicate,
+                            references,
+                        }))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let measures = match_recognize
+                    .measures
+                    .iter()
+                    .map(|measure| match measure {
+                        MatchMeasure::Aggregate { expr, symbol } => {
+                            create_aggregate(expr, symbol.as_deref())
+                        }
+                        MatchMeasure::MatchNumber => Ok(MatchMeasureExpr::MatchNumber),
+                        MatchMeasure::Classifier => Ok(MatchMeasureExpr::Classifier),
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let schema =
+                    SchemaRef::new(match_recognize.schema.as_ref().to_owned().into());
+                Arc::new(MatchRecognizeExec::try_new(
+                    input_exec,
+                    partition_by,
+                    order_by,
+                    measures,
+                    match_recognize.rows_per_match,
+                    match_recognize.after_match_skip.clone(),
+                    match_recognize.pattern.clone(),
+                    symbols,
+                    definitions,
+                    schema,
+                )?)
+            }
 
             // 2 Children
             LogicalPlan::Join(Join {
@@ -1168,7 +1364,27 @@ impl DefaultPhysicalPlanner {
                 let prefer_hash_join =
                     session_state.config_options().optimizer.prefer_hash_join;
 
-                let join: Arc<dyn ExecutionPlan> = if join_on.is_empty() {
+                let join: Arc<dyn ExecutionPlan> = if *join_type == JoinType::AsOf {
+                    let LogicalPlan::Join(join) = node else {
+                        unreachable!()
+                    };
+                    // The match condition is evaluated separately on each
+                    // input rather than as a join filter
+                    let (left_expr, op, right_expr) = join.asof_match_condition()?;
+                    Arc::new(AsofJoinExec::try_new(
+                        physical_left,
+                        physical_right,
+                        join_on,
+                        create_physical_expr(left_expr, left_df_schema, execution_props)?,
+                        op,
+                        create_physical_expr(
+                            right_expr,
+                            right_df_schema,
+                            execution_props,
+                        )?,
+                        *null_equality,
+                    )?)
+                } else if join_on.is_empty() {
                     if join_filter.is_none() && matches!(join_type, JoinType::Inner) {
                         // cross join if there is no join conditions and no join filter set
                         Arc::new(CrossJoinExec::new(physical_left, physical_right))
@@ -1598,6 +1814,121 @@ fn qualify_join_schema_sides(
     join_schema.with_field_specific_qualified_schema(qualifiers)
 }
 
+/// Extracts the filters of a `DELETE` or `UPDATE` statement on `table_name`
+/// from the input of the [`DmlStatement`], with unqualified column references
+/// so that they can be evaluated against the schema of the table.
+///
+/// Only inputs scanning the target table alone are supported, e.g. the
+/// joins of `UPDATE ... FROM` or of decorrelated subqueries are not.
+fn extract_dml_filters(
+    input: &LogicalPlan,
+    table_name: &TableReference,
+    op: &WriteOp,
+) -> Result<Vec<Expr>> {
+    let mut filters = vec![];
+    input.apply(|node| {
+        match node {
+            LogicalPlan::Filter(filter) => {
+                filters.extend(split_conjunction(&filter.predicate).into_iter().cloned());
+            }
+            LogicalPlan::TableScan(scan) => {
+                if scan.table_name != *table_name {
+                    return not_impl_err!(
+                        "{op} of {table_name} reading from {} is not supported",
+                        scan.table_name
+                    );
+                }
+                filters.extend(scan.filters.iter().cloned());
+            }
+            // The optimizer replaces always false filters with empty relations
+            LogicalPlan::EmptyRelation(_) => filters.push(lit(false)),
+            LogicalPlan::Projection(_) | LogicalPlan::SubqueryAlias(_) => {}
+            LogicalPlan::Join(_) => {
+                return not_impl_err!(
+                    "{op} of {table_name} joined with other relations is not supported"
+                );
+            }
+            other => {
+                return not_impl_err!(
+                    "{op} of {table_name} with a {} input is not supported",
+                    other.display()
+                );
+            }
+        }
+        Ok(TreeNodeRecursion::Continue)
+    })?;
+    Ok(filters.into_iter().map(unnormalize_col).collect())
+}
+
+/// Extracts the `SET` assignments of an `UPDATE` statement from the
+/// projection at the top of the input of its [`DmlStatement`], skipping the
+/// columns that keep their previous value.
+///
+/// The optimizer may compute the subexpressions shared by the assignments in
+/// projections below the top one, so the columns they compute are replaced by
+/// their expressions, down to the columns of the table.
+fn extract_update_assignments(input: &LogicalPlan) -> Result<Vec<(String, Expr)>> {
+    let LogicalPlan::Projection(projection) = input else {
+        return internal_err!(
+            "Expected the input of UPDATE to be a projection, got {}",
+            input.display()
+        );
+    };
+    let mut exprs = projection
+        .expr
+        .iter()
+        .map(|expr| expr.clone().unalias())
+        .collect::<Vec<_>>();
+    let mut plan = projection.input.as_ref();
+    loop {
+        match plan {
+            LogicalPlan::Projection(projection) => {
+                exprs = exprs
+                    .into_iter()
+                    .map(|expr| inline_projection(expr, projection))
+                    .collect::<Result<_>>()?;
+                plan = projection.input.as_ref();
+            }
+            LogicalPlan::Filter(Filter { input, .. })
+            | LogicalPlan::SubqueryAlias(SubqueryAlias { input, .. }) => plan = input,
+            _ => break,
+        }
+    }
+
+    let assignments = exprs
+        .into_iter()
+        .zip(projection.schema.fields())
+        .filter_map(|(expr, field)| {
+            let expr = unnormalize_col(expr);
+            match &expr {
+                Expr::Column(column) if column.name == *field.name() => None,
+                _ => Some((field.name().clone(), expr)),
+            }
+        })
+        .collect();
+    Ok(assignments)
+}
+
+/// Replaces the columns of `expr` computed by `projection` by the expressions
+/// computing them
+fn inline_projection(expr: Expr, projection: &Projection) -> Result<Expr> {
+    expr.transform(|expr| {
+        let Expr::Column(column) = &expr else {
+            return Ok(Transformed::no(expr));
+        };
+        let index = projection
+            .schema
+            .fields()
+            .iter()
+            .position(|field| *field.name() == column.name);
+        Ok(match index {
+            Some(index) => Transformed::yes(projection.expr[index].clone().unalias()),
+            None => Transformed::no(expr),
+        })
+    })
+    .data()
+}
+
 fn get_physical_expr_pair(
     expr: &Expr,
     input_dfschema: &DFSchema,
@@ -65,7 +65,7 @@ use datafusion_catalog::TableProvider;
 use datafusion_common::test_util::{batches_to_sort_string, batches_to_string};
 use datafusion_common::{
     assert_contains, Constraint, Constraints, DataFusionError, ParamValues, ScalarValue,
-    TableReference, UnnestOptions,
+    TableReference, UnnestOptions, UnnestOrdinality,
 };
 use datafusion_common_runtime::SpawnedTask;
 use datafusion_datasource::file_format::format_as_file_type;
@@ -3893,6 +3893,55 @@ async fn unnest_column_nulls() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn unnest_column_with_ordinality() -> Result<()> {
+    let df = table_with_lists_and_nulls().await?;
+
+    let options = UnnestOptions::new()
+        .with_preserve_nulls(true)
+        .with_ordinality(UnnestOrdinality::new("pos", 1));
+    let results = df
+        .clone()
+        .unnest_columns_with_options(&["list"], options)?
+        .collect()
+        .await?;
+    assert_snapshot!(
+       batches_to_string(&results),
+        @r###"
+    +------+----+-----+
+    | list | id | pos |
+    +------+----+-----+
+    | 1    | A  | 1   |
+    | 2    | A  | 2   |
+    |      | B  | 1   |
+    | 3    | D  | 1   |
+    +------+----+-----+
+    "###
+    );
+
+    let options = UnnestOptions::new()
+        .with_preserve_nulls(false)
+        .with_ordinality(UnnestOrdinality::new("offset", 0));
+    let results = df
+        .unnest_columns_with_options(&["list"], options)?
+        .collect()
+        .await?;
+    assert_snapshot!(
+       batches_to_string(&results),
+        @r###"
+    +------+----+--------+
+    | list | id | offset |
+    +------+----+--------+
+    | 1    | A  | 0      |
+    | 2    | A  | 1      |
+    | 3    | D  | 0      |
+    +------+----+--------+
+    "###
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn unnest_fixed_list() -> Result<()> {
     let batch = get_fixed_list_batch()?;
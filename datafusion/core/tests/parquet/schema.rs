@@ -21,7 +21,10 @@ use std::{collections::HashMap, fs, path::Path};
 use tempfile::TempDir;
 
 use super::*;
+use arrow::array::StructArray;
+use datafusion::datasource::source::DataSourceExec;
 use datafusion_common::test_util::batches_to_sort_string;
+use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
 use insta::assert_snapshot;
 
 #[tokio::test]
@@ -185,6 +188,71 @@ async fn schema_merge_can_preserve_metadata() {
     assert_metadata(&actual, &expected_metadata);
 }
 
+#[tokio::test]
+async fn nested_projection_prunes_struct_columns() {
+    let tmp_dir = TempDir::new().unwrap();
+    let table_dir = tmp_dir.path().join("parquet_test");
+    fs::create_dir(&table_dir).expect("Error creating temp dir");
+
+    let a = Arc::new(Field::new("a", DataType::Int32, true));
+    let b = Arc::new(Field::new("b", DataType::Utf8, true));
+    let s = StructArray::from(vec![
+        (
+            Arc::clone(&a),
+            Arc::new(Int32Array::from(vec![1, 2, 3])) as ArrayRef,
+        ),
+        (
+            b,
+            Arc::new(StringArray::from(vec!["x", "y", "z"])) as ArrayRef,
+        ),
+    ]);
+    let batch = RecordBatch::try_from_iter(vec![("s", Arc::new(s) as ArrayRef)]).unwrap();
+    let file = fs::File::create(table_dir.join("part-0.parquet")).unwrap();
+    let mut writer = ArrowWriter::try_new(file, batch.schema(), None).unwrap();
+    writer.write(&batch).unwrap();
+    writer.close().unwrap();
+
+    // a single partition, so the projection is directly on top of the scan
+    let config = SessionConfig::new().with_target_partitions(1);
+    let ctx = SessionContext::new_with_config(config);
+    ctx.register_parquet(
+        "t",
+        table_dir.to_str().unwrap(),
+        ParquetReadOptions::default(),
+    )
+    .await
+    .unwrap();
+
+    let df = ctx.sql("SELECT s['a'] AS a FROM t").await.unwrap();
+    let plan = df.clone().create_physical_plan().await.unwrap();
+
+    // the scan only reads the accessed field of the struct column
+    let mut scan_schema = None;
+    plan.apply(|plan| {
+        if plan.as_any().is::<DataSourceExec>() {
+            scan_schema = Some(plan.schema());
+        }
+        Ok(TreeNodeRecursion::Continue)
+    })
+    .unwrap();
+    let scan_schema = scan_schema.expect("no DataSourceExec");
+    assert_eq!(
+        scan_schema.field(0).data_type(),
+        &DataType::Struct(vec![a].into())
+    );
+
+    let actual = df.collect().await.unwrap();
+    assert_snapshot!(batches_to_sort_string(&actual), @r"
+    +---+
+    | a |
+    +---+
+    | 1 |
+    | 2 |
+    | 3 |
+    +---+
+    ");
+}
+
 fn make_meta(k: impl Into<String>, v: impl Into<String>) -> HashMap<String, String> {
     let mut meta = HashMap::new();
     meta.insert(k.into(), v.into());
@@ -0,0 +1,197 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::sync::Arc;
+
+use crate::physical_optimizer::join_selection::StatisticsExec;
+
+use arrow::datatypes::{DataType, Field, Schema};
+use datafusion_common::config::ConfigOptions;
+use datafusion_common::stats::Precision;
+use datafusion_common::{ColumnStatistics, JoinType, NullEquality, Statistics};
+use datafusion_physical_expr::expressions::col;
+use datafusion_physical_optimizer::join_reorder::JoinReorder;
+use datafusion_physical_optimizer::PhysicalOptimizerRule;
+use datafusion_physical_plan::joins::{HashJoinExec, PartitionMode};
+use datafusion_physical_plan::{displayable, ExecutionPlan};
+
+/// Creates a table with the given row count and `(name, distinct count)`
+/// columns
+fn table(num_rows: Option<usize>, columns: &[(&str, usize)]) -> Arc<dyn ExecutionPlan> {
+    let schema = Schema::new(
+        columns
+            .iter()
+            .map(|(name, _)| Field::new(*name, DataType::Int32, false))
+            .collect::<Vec<_>>(),
+    );
+    let stats = Statistics {
+        num_rows: num_rows.map_or(Precision::Absent, Precision::Inexact),
+        total_byte_size: Precision::Absent,
+        column_statistics: columns
+            .iter()
+            .map(|(_, distinct)| ColumnStatistics {
+                distinct_count: Precision::Inexact(*distinct),
+                ..ColumnStatistics::new_unknown()
+            })
+            .collect(),
+    };
+    Arc::new(StatisticsExec::new(stats, schema))
+}
+
+fn join(
+    left: Arc<dyn ExecutionPlan>,
+    right: Arc<dyn ExecutionPlan>,
+    left_key: &str,
+    right_key: &str,
+) -> Arc<dyn ExecutionPlan> {
+    join_on(left, right, &[(left_key, right_key)])
+}
+
+fn join_on(
+    left: Arc<dyn ExecutionPlan>,
+    right: Arc<dyn ExecutionPlan>,
+    keys: &[(&str, &str)],
+) -> Arc<dyn ExecutionPlan> {
+    let on = keys
+        .iter()
+        .map(|(left_key, right_key)| {
+            (
+                col(left_key, &left.schema()).unwrap(),
+                col(right_key, &right.schema()).unwrap(),
+            )
+        })
+        .collect();
+    Arc::new(
+        HashJoinExec::try_new(
+            left,
+            right,
+            on,
+            None,
+            &JoinType::Inner,
+            None,
+            PartitionMode::Auto,
+            NullEquality::NullEqualsNothing,
+        )
+        .unwrap(),
+    )
+}
+
+/// Joins `t1` and `t2`, which have many matches, before joining the small
+/// `t3`, which only matches a few rows of `t2`
+fn chain_join(t3_rows: Option<usize>) -> Arc<dyn ExecutionPlan> {
+    let t1 = table(Some(1000), &[("t1_a", 1000)]);
+    let t2 = table(Some(1000), &[("t2_a", 10), ("t2_b", 1000)]);
+    let t3 = table(t3_rows, &[("t3_b", 10)]);
+    join(join(t1, t2, "t1_a", "t2_a"), t3, "t2_b", "t3_b")
+}
+
+fn optimize(plan: Arc<dyn ExecutionPlan>, config: &ConfigOptions) -> Vec<String> {
+    let optimized = JoinReorder::new().optimize(plan, config).unwrap();
+    displayable(optimized.as_ref())
+        .indent(true)
+        .to_string()
+        .lines()
+        .map(|line| line.to_string())
+        .collect()
+}
+
+fn enabled_config() -> ConfigOptions {
+    let mut config = ConfigOptions::new();
+    config.optimizer.enable_join_reordering = true;
+    config
+}
+
+const REORDERED_CHAIN: [&str; 6] = [
+    "ProjectionExec: expr=[t1_a@3 as t1_a, t2_a@1 as t2_a, t2_b@2 as t2_b, t3_b@0 as t3_b]",
+    "  HashJoinExec: mode=Auto, join_type=Inner, on=[(t2_a@1, t1_a@0)]",
+    "    HashJoinExec: mode=Auto, join_type=Inner, on=[(t3_b@0, t2_b@1)]",
+    "      StatisticsExec: col_count=1, row_count=Inexact(10)",
+    "      StatisticsExec: col_count=2, row_count=Inexact(1000)",
+    "    StatisticsExec: col_count=1, row_count=Inexact(1000)",
+];
+
+const ORIGINAL_CHAIN: [&str; 5] = [
+    "HashJoinExec: mode=Auto, join_type=Inner, on=[(t2_b@2, t3_b@0)]",
+    "  HashJoinExec: mode=Auto, join_type=Inner, on=[(t1_a@0, t2_a@0)]",
+    "    StatisticsExec: col_count=1, row_count=Inexact(1000)",
+    "    StatisticsExec: col_count=2, row_count=Inexact(1000)",
+    "  StatisticsExec: col_count=1, row_count=Inexact(10)",
+];
+
+#[test]
+fn test_reorder_join_chain() {
+    let optimized = optimize(chain_join(Some(10)), &enabled_config());
+    assert_eq!(optimized, REORDERED_CHAIN);
+}
+
+#[test]
+fn test_reorder_join_chain_greedily() {
+    let mut config = enabled_config();
+    config.optimizer.join_reordering_dp_threshold = 0;
+    let optimized = optimize(chain_join(Some(10)), &config);
+    assert_eq!(optimized, REORDERED_CHAIN);
+}
+
+#[test]
+fn test_join_reordering_disabled() {
+    let optimized = optimize(chain_join(Some(10)), &ConfigOptions::new());
+    assert_eq!(optimized, ORIGINAL_CHAIN);
+}
+
+#[test]
+fn test_no_reordering_without_row_counts() {
+    let optimized = optimize(chain_join(None), &enabled_config());
+    assert_eq!(optimized, ORIGINAL_CHAIN);
+}
+
+#[test]
+fn test_no_reordering_of_cheapest_order() {
+    let t1 = table(Some(1000), &[("t1_a", 1000)]);
+    let t2 = table(Some(1000), &[("t2_a", 10), ("t2_b", 1000)]);
+    let t3 = table(Some(10), &[("t3_b", 10)]);
+    let plan = join(t1, join(t3, t2, "t3_b", "t2_b"), "t1_a", "t2_a");
+    let expected = displayable(plan.as_ref()).indent(true).to_string();
+    let optimized = optimize(plan, &enabled_config());
+    assert_eq!(optimized.join("\n") + "\n", expected);
+}
+
+#[test]
+fn test_reorder_join_with_columns_of_an_input_in_a_class() {
+    // a JOIN b ON a_x = b_k AND a_y = b_k JOIN c ON b_j = c_j, where `a_x`
+    // and `a_y` must both stay equal to `b_k` once `a` is joined last
+    let a = table(Some(1000), &[("a_x", 10), ("a_y", 10)]);
+    let b = table(Some(1000), &[("b_k", 10), ("b_j", 1000)]);
+    let c = table(Some(10), &[("c_j", 10)]);
+    let plan = join(
+        join_on(a, b, &[("a_x", "b_k"), ("a_y", "b_k")]),
+        c,
+        "b_j",
+        "c_j",
+    );
+    let optimized = optimize(plan, &enabled_config());
+    assert_eq!(
+        optimized,
+        [
+            "ProjectionExec: expr=[a_x@3 as a_x, a_y@4 as a_y, b_k@1 as b_k, b_j@2 as b_j, c_j@0 as c_j]",
+            "  HashJoinExec: mode=Auto, join_type=Inner, on=[(b_k@1, a_x@0), (b_k@1, a_y@1)]",
+            "    HashJoinExec: mode=Auto, join_type=Inner, on=[(c_j@0, b_j@1)]",
+            "      StatisticsExec: col_count=1, row_count=Inexact(10)",
+            "      StatisticsExec: col_count=2, row_count=Inexact(1000)",
+            "    StatisticsExec: col_count=2, row_count=Inexact(1000)",
+        ]
+    );
+}
@@ -1228,7 +1228,7 @@ async fn test_cases_without_collect_left_check() -> Result<()> {
             initial_join_type: join_type,
             initial_mode: PartitionMode::CollectLeft,
             expected_sources_unbounded: (SourceType::Bounded, SourceType::Unbounded),
-            expected_join_type: join_type.swap(),
+            expected_join_type: join_type.swap()?,
             expected_mode: PartitionMode::CollectLeft,
             expecting_swap: true,
         });
@@ -1268,7 +1268,7 @@ async fn test_cases_without_collect_left_check() -> Result<()> {
             initial_join_type: join_type,
             initial_mode: PartitionMode::Partitioned,
             expected_sources_unbounded: (SourceType::Bounded, SourceType::Unbounded),
-            expected_join_type: join_type.swap(),
+            expected_join_type: join_type.swap()?,
             expected_mode: PartitionMode::Partitioned,
             expecting_swap: true,
         });
@@ -1323,7 +1323,7 @@ async fn test_not_support_collect_left() -> Result<()> {
             initial_join_type: join_type,
             initial_mode: PartitionMode::Partitioned,
             expected_sources_unbounded: (SourceType::Bounded, SourceType::Unbounded),
-            expected_join_type: join_type.swap(),
+            expected_join_type: join_type.swap()?,
             expected_mode: PartitionMode::Partitioned,
             expecting_swap: true,
         });
@@ -22,6 +22,7 @@ mod combine_partial_final_agg;
 mod enforce_distribution;
 mod enforce_sorting;
 mod filter_pushdown;
+mod join_reorder;
 mod join_selection;
 mod limit_pushdown;
 mod limited_distinct_aggregation;
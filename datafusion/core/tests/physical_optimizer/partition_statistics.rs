@@ -122,6 +122,8 @@ mod test {
             min_value: Precision::Exact(ScalarValue::Int32(Some(min_value))),
             sum_value: Precision::Absent,
             distinct_count: Precision::Absent,
+            histogram: None,
+            most_common_values: None,
         }];
 
         if include_date_column {
@@ -131,6 +133,8 @@ mod test {
                 min_value: Precision::Absent,
                 sum_value: Precision::Absent,
                 distinct_count: Precision::Absent,
+                histogram: None,
+                most_common_values: None,
             });
         }
 
@@ -328,6 +332,8 @@ mod test {
                     min_value: Precision::Exact(ScalarValue::Null),
                     sum_value: Precision::Exact(ScalarValue::Null),
                     distinct_count: Precision::Exact(0),
+                    histogram: None,
+                    most_common_values: None,
                 },
                 ColumnStatistics {
                     null_count: Precision::Exact(0),
@@ -335,6 +341,8 @@ mod test {
                     min_value: Precision::Exact(ScalarValue::Null),
                     sum_value: Precision::Exact(ScalarValue::Null),
                     distinct_count: Precision::Exact(0),
+                    histogram: None,
+                    most_common_values: None,
                 },
             ],
         };
@@ -408,6 +416,8 @@ mod test {
                 min_value: Precision::Exact(ScalarValue::Int32(Some(3))),
                 sum_value: Precision::Absent,
                 distinct_count: Precision::Absent,
+                histogram: None,
+                most_common_values: None,
             });
         let mut expected_statistic_partition_2 =
             create_partition_statistics(8, 48400, 1, 4, true);
@@ -419,6 +429,8 @@ mod test {
                 min_value: Precision::Exact(ScalarValue::Int32(Some(1))),
                 sum_value: Precision::Absent,
                 distinct_count: Precision::Absent,
+                histogram: None,
+                most_common_values: None,
             });
         assert_eq!(statistics[0], expected_statistic_partition_1);
         assert_eq!(statistics[1], expected_statistic_partition_2);
@@ -565,6 +577,8 @@ mod test {
                     min_value: Precision::Exact(ScalarValue::Int32(Some(3))),
                     sum_value: Precision::Absent,
                     distinct_count: Precision::Absent,
+                    histogram: None,
+                    most_common_values: None,
                 },
                 ColumnStatistics::new_unknown(),
                 ColumnStatistics::new_unknown(),
@@ -583,6 +597,8 @@ mod test {
                     min_value: Precision::Exact(ScalarValue::Int32(Some(1))),
                     sum_value: Precision::Absent,
                     distinct_count: Precision::Absent,
+                    histogram: None,
+                    most_common_values: None,
                 },
                 ColumnStatistics::new_unknown(),
                 ColumnStatistics::new_unknown(),
@@ -106,6 +106,8 @@ fn int64_stats() -> ColumnStatistics {
         max_value: Precision::Exact(1_000_000.into()),
         min_value: Precision::Exact(0.into()),
         distinct_count: Precision::Absent,
+        histogram: None,
+        most_common_values: None,
     }
 }
 
@@ -0,0 +1,261 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use datafusion::execution::runtime_env::RuntimeEnvBuilder;
+use datafusion_common::stats::{HistogramBucket, Precision};
+use datafusion_common::{ColumnStatistics, DataFusionError, ScalarValue, Statistics};
+
+use super::*;
+
+async fn create_table(ctx: &SessionContext) -> Result<()> {
+    ctx.sql("CREATE TABLE t (a INT, b VARCHAR)")
+        .await?
+        .collect()
+        .await?;
+    ctx.sql(
+        "INSERT INTO t VALUES (1, 'x'), (2, 'x'), (2, 'y'), (NULL, 'z'), (5, 'z'), (5, 'z')",
+    )
+    .await?
+    .collect()
+    .await?;
+    Ok(())
+}
+
+async fn table_statistics(ctx: &SessionContext) -> Result<Option<Statistics>> {
+    Ok(ctx.table_provider("t").await?.statistics())
+}
+
+fn bucket(
+    lower: i32,
+    upper: i32,
+    count: usize,
+    distinct_count: usize,
+) -> HistogramBucket {
+    HistogramBucket {
+        lower: ScalarValue::Int32(Some(lower)),
+        upper: ScalarValue::Int32(Some(upper)),
+        count,
+        distinct_count,
+    }
+}
+
+#[tokio::test]
+async fn analyze_table() -> Result<()> {
+    let ctx = SessionContext::new();
+    create_table(&ctx).await?;
+    assert_eq!(table_statistics(&ctx).await?, None);
+
+    ctx.sql("ANALYZE TABLE t").await?.collect().await?;
+    let statistics = table_statistics(&ctx).await?.unwrap();
+    assert_eq!(statistics.num_rows, Precision::Exact(6));
+
+    let a = &statistics.column_statistics[0];
+    assert_eq!(a.null_count, Precision::Exact(1));
+    assert_eq!(a.min_value, Precision::Exact(ScalarValue::Int32(Some(1))));
+    assert_eq!(a.max_value, Precision::Exact(ScalarValue::Int32(Some(5))));
+    assert_eq!(a.distinct_count, Precision::Exact(3));
+    assert_eq!(
+        a.histogram.as_ref().unwrap().buckets(),
+        [bucket(1, 1, 1, 1), bucket(2, 2, 2, 1), bucket(5, 5, 2, 1)]
+    );
+    assert_eq!(
+        a.most_common_values.as_ref().unwrap().values(),
+        [
+            (ScalarValue::Int32(Some(2)), 2),
+            (ScalarValue::Int32(Some(5)), 2)
+        ]
+    );
+
+    let b = &statistics.column_statistics[1];
+    assert_eq!(b.null_count, Precision::Exact(0));
+    assert_eq!(b.distinct_count, Precision::Exact(3));
+    assert_eq!(b.histogram.as_ref().unwrap().count(), 6);
+    assert_eq!(
+        b.most_common_values.as_ref().unwrap().values(),
+        [(ScalarValue::Utf8View(Some("z".to_string())), 3)]
+    );
+
+    // the statistics are removed when the content of the table changes
+    ctx.sql("INSERT INTO t VALUES (6, 'z')")
+        .await?
+        .collect()
+        .await?;
+    assert_eq!(table_statistics(&ctx).await?, None);
+    Ok(())
+}
+
+#[tokio::test]
+async fn analyze_table_for_columns() -> Result<()> {
+    let ctx = SessionContext::new();
+    create_table(&ctx).await?;
+    ctx.sql("SET datafusion.execution.analyze_histogram_buckets = 2")
+        .await?
+        .collect()
+        .await?;
+
+    ctx.sql("ANALYZE TABLE t COMPUTE STATISTICS FOR COLUMNS a")
+        .await?
+        .collect()
+        .await?;
+    let statistics = table_statistics(&ctx).await?.unwrap();
+    let a = &statistics.column_statistics[0];
+    assert_eq!(a.distinct_count, Precision::Exact(3));
+    assert_eq!(
+        a.histogram.as_ref().unwrap().buckets(),
+        [bucket(1, 2, 3, 2), bucket(5, 5, 2, 1)]
+    );
+    assert_eq!(
+        statistics.column_statistics[1],
+        ColumnStatistics::new_unknown()
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn analyze_table_filter_estimate() -> Result<()> {
+    let ctx = SessionContext::new();
+    create_table(&ctx).await?;
+    ctx.sql("ANALYZE TABLE t").await?.collect().await?;
+
+    // the histogram of `a` gives the selectivity of the filter: 2 of its 5
+    // values are greater than 2
+    let plan = ctx
+        .sql("SELECT * FROM t WHERE a > 2")
+        .await?
+        .create_physical_plan()
+        .await?;
+    let statistics = plan.partition_statistics(None)?;
+    assert_eq!(statistics.num_rows, Precision::Inexact(3));
+    assert_eq!(
+        statistics.column_statistics[0].min_value,
+        Precision::Inexact(ScalarValue::Int32(Some(3)))
+    );
+    Ok(())
+}
+
+/// Creates table `t` whose column `a` holds `num_rows` values, each of
+/// `0..1000` occurring as often
+async fn create_large_table(ctx: &SessionContext, num_rows: usize) -> Result<()> {
+    ctx.sql(&format!(
+        "CREATE TABLE t AS SELECT value % 1000 AS a FROM generate_series(1, {num_rows})"
+    ))
+    .await?
+    .collect()
+    .await?;
+    ctx.sql("SET datafusion.execution.analyze_sample_size = 1000")
+        .await?
+        .collect()
+        .await?;
+    Ok(())
+}
+
+#[tokio::test]
+async fn analyze_table_sampled() -> Result<()> {
+    let ctx = SessionContext::new();
+    create_large_table(&ctx, 10_000).await?;
+
+    ctx.sql("ANALYZE TABLE t").await?.collect().await?;
+    let statistics = table_statistics(&ctx).await?.unwrap();
+    assert_eq!(statistics.num_rows, Precision::Exact(10_000));
+
+    // the null count, minimum and maximum are exact, the distinct count is
+    // estimated, and the histogram is scaled from the sampled values
+    let a = &statistics.column_statistics[0];
+    assert_eq!(a.null_count, Precision::Exact(0));
+    assert_eq!(a.min_value, Precision::Exact(ScalarValue::Int64(Some(0))));
+    assert_eq!(a.max_value, Precision::Exact(ScalarValue::Int64(Some(999))));
+    let Precision::Inexact(distinct_count) = a.distinct_count else {
+        panic!("unexpected distinct count {:?}", a.distinct_count);
+    };
+    assert!((950..=1050).contains(&distinct_count), "{distinct_count}");
+    let histogram = a.histogram.as_ref().unwrap();
+    assert!(histogram.buckets().len() <= 64);
+    assert!(
+        histogram.count().abs_diff(10_000) <= 64,
+        "{}",
+        histogram.count()
+    );
+    assert_eq!(
+        histogram.buckets().first().unwrap().lower,
+        ScalarValue::Int64(Some(0))
+    );
+    assert_eq!(
+        histogram.buckets().last().unwrap().upper,
+        ScalarValue::Int64(Some(999))
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn analyze_table_exact() -> Result<()> {
+    let ctx = SessionContext::new();
+    create_large_table(&ctx, 10_000).await?;
+    ctx.sql("SET datafusion.execution.analyze_exact_statistics = true")
+        .await?
+        .collect()
+        .await?;
+
+    ctx.sql("ANALYZE TABLE t").await?.collect().await?;
+    let statistics = table_statistics(&ctx).await?.unwrap();
+    let a = &statistics.column_statistics[0];
+    assert_eq!(a.distinct_count, Precision::Exact(1000));
+    let histogram = a.histogram.as_ref().unwrap();
+    assert_eq!(histogram.count(), 10_000);
+    assert_eq!(histogram.distinct_count(), 1000);
+    Ok(())
+}
+
+#[tokio::test]
+async fn analyze_table_memory_limit() -> Result<()> {
+    let runtime = RuntimeEnvBuilder::new()
+        .with_memory_limit(1024 * 1024, 1.0)
+        .build_arc()?;
+    let ctx = SessionContext::new_with_config_rt(SessionConfig::new(), runtime);
+    create_large_table(&ctx, 200_000).await?;
+
+    // the sample and sketch fit in memory
+    ctx.sql("ANALYZE TABLE t").await?.collect().await?;
+    assert!(table_statistics(&ctx).await?.is_some());
+
+    // all the values do not
+    ctx.sql("SET datafusion.execution.analyze_exact_statistics = true")
+        .await?
+        .collect()
+        .await?;
+    let err = ctx.sql("ANALYZE TABLE t").await.unwrap_err();
+    assert!(
+        matches!(err.find_root(), DataFusionError::ResourcesExhausted(_)),
+        "{err}"
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn analyze_table_not_supported() -> Result<()> {
+    let ctx = SessionContext::new();
+    ctx.sql("CREATE VIEW v AS SELECT 1 AS a")
+        .await?
+        .collect()
+        .await?;
+
+    let err = ctx.sql("ANALYZE TABLE v").await.unwrap_err();
+    assert_contains!(
+        err.to_string(),
+        "ANALYZE TABLE not implemented for this table"
+    );
+    Ok(())
+}
@@ -58,6 +58,7 @@ macro_rules! assert_metrics {
 }
 
 pub mod aggregates;
+mod analyze_table;
 pub mod create_drop;
 pub mod explain_analyze;
 pub mod joins;
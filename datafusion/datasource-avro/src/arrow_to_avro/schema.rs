@@ -0,0 +1,167 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use apache_avro::Schema as AvroSchema;
+use arrow::datatypes::{DataType, Field, Fields, Schema, TimeUnit};
+use datafusion_common::{not_impl_err, Result};
+use serde_json::{json, Value as JsonValue};
+
+/// Name of the top level record of the Avro schema
+const TOP_LEVEL_RECORD_NAME: &str = "topLevelRecord";
+
+/// Converts an arrow schema to an avro schema
+///
+/// Nullable fields are written as a union of `null` and the field type. Named
+/// avro types (records and fixed) are named after the path of their field, as
+/// avro requires names to be unique within a schema.
+pub fn to_avro_schema(schema: &Schema) -> Result<AvroSchema> {
+    let avro_schema = record_schema(TOP_LEVEL_RECORD_NAME, schema.fields())?;
+    Ok(AvroSchema::parse(&avro_schema)?)
+}
+
+/// Returns whether `field` is written as a union of `null` and its type
+pub(super) fn is_nullable_union(field: &Field) -> bool {
+    field.is_nullable() && field.data_type() != &DataType::Null
+}
+
+fn record_schema(name: &str, fields: &Fields) -> Result<JsonValue> {
+    let fields = fields
+        .iter()
+        .map(|field| {
+            let path = format!("{name}_{}", field.name());
+            Ok(json!({
+                "name": field.name(),
+                "type": field_schema(&path, field)?,
+            }))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(json!({
+        "type": "record",
+        "name": name,
+        "fields": fields,
+    }))
+}
+
+fn field_schema(path: &str, field: &Field) -> Result<JsonValue> {
+    let schema = data_type_schema(path, field.data_type())?;
+    if is_nullable_union(field) {
+        Ok(json!(["null", schema]))
+    } else {
+        Ok(schema)
+    }
+}
+
+fn data_type_schema(path: &str, data_type: &DataType) -> Result<JsonValue> {
+    Ok(match data_type {
+        DataType::Null => json!("null"),
+        DataType::Boolean => json!("boolean"),
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::UInt8
+        | DataType::UInt16 => json!("int"),
+        DataType::Int64 | DataType::UInt32 => json!("long"),
+        DataType::Float32 => json!("float"),
+        DataType::Float64 => json!("double"),
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => json!("string"),
+        DataType::Binary | DataType::LargeBinary | DataType::BinaryView => {
+            json!("bytes")
+        }
+        DataType::FixedSizeBinary(size) => json!({
+            "type": "fixed",
+            "name": path,
+            "size": size,
+        }),
+        DataType::Decimal128(precision, scale) => json!({
+            "type": "bytes",
+            "logicalType": "decimal",
+            "precision": precision,
+            "scale": scale,
+        }),
+        DataType::Date32 => json!({"type": "int", "logicalType": "date"}),
+        DataType::Time32(TimeUnit::Millisecond) => {
+            json!({"type": "int", "logicalType": "time-millis"})
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            json!({"type": "long", "logicalType": "time-micros"})
+        }
+        DataType::Timestamp(TimeUnit::Second | TimeUnit::Millisecond, _) => {
+            json!({"type": "long", "logicalType": "timestamp-millis"})
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            json!({"type": "long", "logicalType": "timestamp-micros"})
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            json!({"type": "long", "logicalType": "timestamp-nanos"})
+        }
+        DataType::List(item) | DataType::LargeList(item) => json!({
+            "type": "array",
+            "items": field_schema(&format!("{path}_element"), item)?,
+        }),
+        DataType::Struct(fields) => record_schema(path, fields)?,
+        DataType::Dictionary(_, value_type) => data_type_schema(path, value_type)?,
+        other => return not_impl_err!("Writing {other} to Avro is not supported"),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::avro_to_arrow::to_arrow_schema;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_round_trip_schema() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("name", DataType::Utf8, true),
+            Field::new("score", DataType::Float64, true),
+            Field::new(
+                "ts",
+                DataType::Timestamp(TimeUnit::Microsecond, None),
+                false,
+            ),
+            Field::new(
+                "tags",
+                DataType::List(Arc::new(Field::new("element", DataType::Utf8, false))),
+                true,
+            ),
+            Field::new(
+                "point",
+                DataType::Struct(Fields::from(vec![
+                    Field::new("x", DataType::Int64, false),
+                    Field::new("y", DataType::Int64, false),
+                ])),
+                false,
+            ),
+        ]);
+        let avro_schema = to_avro_schema(&schema)?;
+        assert_eq!(to_arrow_schema(&avro_schema)?, schema);
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_type() {
+        let schema =
+            Schema::new(vec![Field::new("d", DataType::Decimal256(40, 2), false)]);
+        let err = to_avro_schema(&schema).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "This feature is not implemented: Writing Decimal256(40, 2) to Avro is not supported"
+        );
+    }
+}
@@ -0,0 +1,267 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::str::FromStr;
+
+use super::schema::is_nullable_union;
+
+use apache_avro::types::Value;
+use apache_avro::{Codec, Decimal};
+use arrow::array::{Array, ArrayRef, ArrowPrimitiveType, AsArray, RecordBatch};
+use arrow::compute::cast;
+use arrow::datatypes::{
+    DataType, Date32Type, Decimal128Type, Field, Fields, Float32Type, Float64Type,
+    Int16Type, Int32Type, Int64Type, Int8Type, Time32MillisecondType,
+    Time64MicrosecondType, TimeUnit, TimestampMicrosecondType, TimestampMillisecondType,
+    TimestampNanosecondType, TimestampSecondType, UInt16Type, UInt32Type, UInt8Type,
+};
+use datafusion_common::{config_err, exec_err, not_impl_err, Result};
+
+/// Parses the name of an avro codec, as accepted by the `codec` option of
+/// [`AvroOptions`](datafusion_common::config::AvroOptions)
+pub fn parse_codec(codec: &str) -> Result<Codec> {
+    let name = match codec.to_lowercase().as_str() {
+        "null" | "uncompressed" => "null",
+        "deflate" => "deflate",
+        "snappy" => "snappy",
+        "zstd" | "zstandard" => "zstandard",
+        _ => {
+            return config_err!(
+                "Unknown or unsupported avro codec: {codec}. \
+                 Valid values are: null, deflate, snappy and zstd"
+            )
+        }
+    };
+    Codec::from_str(name).or_else(|_| config_err!("Unsupported avro codec: {codec}"))
+}
+
+/// Converts the rows of a [`RecordBatch`] into avro records, following the
+/// schema returned by [`to_avro_schema`](super::to_avro_schema)
+pub fn to_avro_records(batch: &RecordBatch) -> Result<Vec<Value>> {
+    let schema = batch.schema();
+    record_values(schema.fields(), batch.columns(), batch.num_rows())
+}
+
+/// Converts the rows of `columns` into avro records with the given `fields`
+fn record_values(
+    fields: &Fields,
+    columns: &[ArrayRef],
+    num_rows: usize,
+) -> Result<Vec<Value>> {
+    let mut columns = fields
+        .iter()
+        .zip(columns)
+        .map(|(field, column)| Ok(field_values(field, column)?.into_iter()))
+        .collect::<Result<Vec<_>>>()?;
+    Ok((0..num_rows)
+        .map(|_| {
+            let fields = fields
+                .iter()
+                .zip(columns.iter_mut())
+                .map(|(field, values)| {
+                    (field.name().clone(), values.next().unwrap_or(Value::Null))
+                })
+                .collect();
+            Value::Record(fields)
+        })
+        .collect())
+}
+
+/// Converts the values of `array` for `field`, wrapping them in unions if
+/// the field is nullable
+fn field_values(field: &Field, array: &ArrayRef) -> Result<Vec<Value>> {
+    let values = array_values(array)?;
+    if !is_nullable_union(field) {
+        return Ok(values);
+    }
+    Ok(values
+        .into_iter()
+        .enumerate()
+        .map(|(row, value)| {
+            if array.is_null(row) {
+                Value::Union(0, Box::new(Value::Null))
+            } else {
+                Value::Union(1, Box::new(value))
+            }
+        })
+        .collect())
+}
+
+fn primitive_values<T: ArrowPrimitiveType>(
+    array: &ArrayRef,
+    f: impl Fn(T::Native) -> Value,
+) -> Vec<Value> {
+    array
+        .as_primitive::<T>()
+        .iter()
+        .map(|value| value.map(&f).unwrap_or(Value::Null))
+        .collect()
+}
+
+/// Converts the values of `array`, with nulls converted to [`Value::Null`]
+fn array_values(array: &ArrayRef) -> Result<Vec<Value>> {
+    Ok(match array.data_type() {
+        DataType::Null => vec![Value::Null; array.len()],
+        DataType::Boolean => array
+            .as_boolean()
+            .iter()
+            .map(|value| value.map(Value::Boolean).unwrap_or(Value::Null))
+            .collect(),
+        DataType::Int8 => primitive_values::<Int8Type>(array, |v| Value::Int(v.into())),
+        DataType::Int16 => primitive_values::<Int16Type>(array, |v| Value::Int(v.into())),
+        DataType::Int32 => primitive_values::<Int32Type>(array, Value::Int),
+        DataType::Int64 => primitive_values::<Int64Type>(array, Value::Long),
+        DataType::UInt8 => primitive_values::<UInt8Type>(array, |v| Value::Int(v.into())),
+        DataType::UInt16 => {
+            primitive_values::<UInt16Type>(array, |v| Value::Int(v.into()))
+        }
+        DataType::UInt32 => {
+            primitive_values::<UInt32Type>(array, |v| Value::Long(v.into()))
+        }
+        DataType::Float32 => primitive_values::<Float32Type>(array, Value::Float),
+        DataType::Float64 => primitive_values::<Float64Type>(array, Value::Double),
+        DataType::Utf8 => string_values(array.as_string::<i32>().iter()),
+        DataType::LargeUtf8 => string_values(array.as_string::<i64>().iter()),
+        DataType::Utf8View => string_values(array.as_string_view().iter()),
+        DataType::Binary => binary_values(array.as_binary::<i32>().iter()),
+        DataType::LargeBinary => binary_values(array.as_binary::<i64>().iter()),
+        DataType::BinaryView => binary_values(array.as_binary_view().iter()),
+        DataType::FixedSizeBinary(size) => array
+            .as_fixed_size_binary()
+            .iter()
+            .map(|value| {
+                value
+                    .map(|v| Value::Fixed(*size as usize, v.to_vec()))
+                    .unwrap_or(Value::Null)
+            })
+            .collect(),
+        DataType::Decimal128(_, _) => primitive_values::<Decimal128Type>(array, |v| {
+            Value::Decimal(Decimal::from(v.to_be_bytes().to_vec()))
+        }),
+        DataType::Date32 => primitive_values::<Date32Type>(array, Value::Date),
+        DataType::Time32(TimeUnit::Millisecond) => {
+            primitive_values::<Time32MillisecondType>(array, Value::TimeMillis)
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            primitive_values::<Time64MicrosecondType>(array, Value::TimeMicros)
+        }
+        DataType::Timestamp(TimeUnit::Second, _) => array
+            .as_primitive::<TimestampSecondType>()
+            .iter()
+            .map(|value| match value {
+                Some(v) => match v.checked_mul(1000) {
+                    Some(millis) => Ok(Value::TimestampMillis(millis)),
+                    None => exec_err!(
+                        "Timestamp of {v} seconds overflows the Avro timestamp-millis range"
+                    ),
+                },
+                None => Ok(Value::Null),
+            })
+            .collect::<Result<_>>()?,
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            primitive_values::<TimestampMillisecondType>(array, Value::TimestampMillis)
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            primitive_values::<TimestampMicrosecondType>(array, Value::TimestampMicros)
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            primitive_values::<TimestampNanosecondType>(array, Value::TimestampNanos)
+        }
+        DataType::List(item) => {
+            let list = array.as_list::<i32>();
+            (0..list.len())
+                .map(|row| {
+                    if list.is_null(row) {
+                        Ok(Value::Null)
+                    } else {
+                        Ok(Value::Array(field_values(item, &list.value(row))?))
+                    }
+                })
+                .collect::<Result<_>>()?
+        }
+        DataType::LargeList(item) => {
+            let list = array.as_list::<i64>();
+            (0..list.len())
+                .map(|row| {
+                    if list.is_null(row) {
+                        Ok(Value::Null)
+                    } else {
+                        Ok(Value::Array(field_values(item, &list.value(row))?))
+                    }
+                })
+                .collect::<Result<_>>()?
+        }
+        DataType::Struct(fields) => {
+            let array = array.as_struct();
+            record_values(fields, array.columns(), array.len())?
+        }
+        DataType::Dictionary(_, value_type) => {
+            array_values(&cast(array.as_ref(), value_type)?)?
+        }
+        other => return not_impl_err!("Writing {other} to Avro is not supported"),
+    })
+}
+
+fn string_values<'a>(values: impl Iterator<Item = Option<&'a str>>) -> Vec<Value> {
+    values
+        .map(|value| {
+            value
+                .map(|v| Value::String(v.to_string()))
+                .unwrap_or(Value::Null)
+        })
+        .collect()
+}
+
+fn binary_values<'a>(values: impl Iterator<Item = Option<&'a [u8]>>) -> Vec<Value> {
+    values
+        .map(|value| {
+            value
+                .map(|v| Value::Bytes(v.to_vec()))
+                .unwrap_or(Value::Null)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::TimestampSecondArray;
+    use std::sync::Arc;
+
+    #[test]
+    fn timestamp_seconds_to_millis() -> Result<()> {
+        let array: ArrayRef =
+            Arc::new(TimestampSecondArray::from(vec![Some(1), None, Some(-2)]));
+        assert_eq!(
+            array_values(&array)?,
+            vec![
+                Value::TimestampMillis(1000),
+                Value::Null,
+                Value::TimestampMillis(-2000)
+            ]
+        );
+
+        let array: ArrayRef = Arc::new(TimestampSecondArray::from(vec![i64::MAX]));
+        let err = array_values(&array).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("overflows the Avro timestamp-millis range"),
+            "{err}"
+        );
+        Ok(())
+    }
+}
@@ -22,49 +22,97 @@ use std::collections::HashMap;
 use std::fmt;
 use std::sync::Arc;
 
+use crate::arrow_to_avro::{parse_codec, to_avro_records, to_avro_schema};
 use crate::avro_to_arrow::read_avro_schema_from_reader;
 use crate::source::AvroSource;
 
 use arrow::datatypes::Schema;
 use arrow::datatypes::SchemaRef;
-use datafusion_common::internal_err;
+use datafusion_common::config::{AvroOptions, ConfigField, ConfigFileType};
+use datafusion_common::file_options::avro_writer::AvroWriterOptions;
 use datafusion_common::parsers::CompressionTypeVariant;
 use datafusion_common::GetExt;
 use datafusion_common::DEFAULT_AVRO_EXTENSION;
+use datafusion_common::{internal_err, not_impl_err, DataFusionError};
 use datafusion_common::{Result, Statistics};
+use datafusion_common_runtime::{JoinSet, SpawnedTask};
+use datafusion_datasource::display::FileGroupDisplay;
 use datafusion_datasource::file::FileSource;
 use datafusion_datasource::file_compression_type::FileCompressionType;
 use datafusion_datasource::file_format::{FileFormat, FileFormatFactory};
 use datafusion_datasource::file_scan_config::{FileScanConfig, FileScanConfigBuilder};
+use datafusion_datasource::file_sink_config::{FileSink, FileSinkConfig};
+use datafusion_datasource::sink::{DataSink, DataSinkExec};
 use datafusion_datasource::source::DataSourceExec;
-use datafusion_physical_plan::ExecutionPlan;
+use datafusion_datasource::write::demux::DemuxedStreamReceiver;
+use datafusion_datasource::write::{
+    get_writer_schema, ObjectWriterBuilder, SharedBuffer,
+};
+use datafusion_execution::{SendableRecordBatchStream, TaskContext};
+use datafusion_expr::dml::InsertOp;
+use datafusion_physical_expr_common::sort_expr::LexRequirement;
+use datafusion_physical_plan::{DisplayAs, DisplayFormatType, ExecutionPlan};
 use datafusion_session::Session;
 
 use async_trait::async_trait;
 use object_store::{GetResultPayload, ObjectMeta, ObjectStore};
+use tokio::io::AsyncWriteExt;
+
+/// Initial writing buffer size. Note this is just a size hint for efficiency. It
+/// will grow beyond the set value if needed.
+const INITIAL_BUFFER_BYTES: usize = 1048576;
+
+/// If the buffered Avro data exceeds this size, it is flushed to object store
+const BUFFER_FLUSH_BYTES: usize = 1024000;
 
 #[derive(Default)]
 /// Factory struct used to create [`AvroFormat`]
-pub struct AvroFormatFactory;
+pub struct AvroFormatFactory {
+    /// the options carried by format factory
+    pub options: Option<AvroOptions>,
+}
 
 impl AvroFormatFactory {
     /// Creates an instance of [`AvroFormatFactory`]
     pub fn new() -> Self {
-        Self {}
+        Self { options: None }
+    }
+
+    /// Creates an instance of [`AvroFormatFactory`] with customized default options
+    pub fn new_with_options(options: AvroOptions) -> Self {
+        Self {
+            options: Some(options),
+        }
     }
 }
 
 impl FileFormatFactory for AvroFormatFactory {
     fn create(
         &self,
-        _state: &dyn Session,
-        _format_options: &HashMap<String, String>,
+        state: &dyn Session,
+        format_options: &HashMap<String, String>,
     ) -> Result<Arc<dyn FileFormat>> {
-        Ok(Arc::new(AvroFormat))
+        let avro_options = match &self.options {
+            None => {
+                let mut table_options = state.default_table_options();
+                table_options.set_config_format(ConfigFileType::AVRO);
+                table_options.alter_with_string_hash_map(format_options)?;
+                table_options.avro
+            }
+            Some(avro_options) => {
+                let mut avro_options = avro_options.clone();
+                for (k, v) in format_options {
+                    avro_options.set(k, v)?;
+                }
+                avro_options
+            }
+        };
+
+        Ok(Arc::new(AvroFormat::default().with_options(avro_options)))
     }
 
     fn default(&self) -> Arc<dyn FileFormat> {
-        Arc::new(AvroFormat)
+        Arc::new(AvroFormat::default())
     }
 
     fn as_any(&self) -> &dyn Any {
@@ -74,7 +122,9 @@ impl FileFormatFactory for AvroFormatFactory {
 
 impl fmt::Debug for AvroFormatFactory {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("AvroFormatFactory").finish()
+        f.debug_struct("AvroFormatFactory")
+            .field("options", &self.options)
+            .finish()
     }
 }
 
@@ -87,7 +137,29 @@ impl GetExt for AvroFormatFactory {
 
 /// Avro [`FileFormat`] implementation.
 #[derive(Default, Debug)]
-pub struct AvroFormat;
+pub struct AvroFormat {
+    options: AvroOptions,
+}
+
+impl AvroFormat {
+    /// Set Avro options
+    pub fn with_options(mut self, options: AvroOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Retrieve Avro options
+    pub fn options(&self) -> &AvroOptions {
+        &self.options
+    }
+
+    /// Set the codec used to compress the data blocks of written files
+    /// - defaults to `null` (no compression)
+    pub fn with_codec(mut self, codec: impl Into<String>) -> Self {
+        self.options.codec = codec.into();
+        self
+    }
+}
 
 #[async_trait]
 impl FileFormat for AvroFormat {
@@ -160,7 +232,180 @@ impl FileFormat for AvroFormat {
         Ok(DataSourceExec::from_data_source(config))
     }
 
+    async fn create_writer_physical_plan(
+        &self,
+        input: Arc<dyn ExecutionPlan>,
+        _state: &dyn Session,
+        conf: FileSinkConfig,
+        order_requirements: Option<LexRequirement>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        if conf.insert_op != InsertOp::Append {
+            return not_impl_err!("Overwrites are not implemented yet for Avro");
+        }
+
+        let writer_options = AvroWriterOptions::try_from(&self.options)?;
+        // Validate the codec before any data is written
+        parse_codec(&writer_options.codec)?;
+
+        let sink = Arc::new(AvroSink::new(conf, writer_options));
+
+        Ok(Arc::new(DataSinkExec::new(input, sink, order_requirements)) as _)
+    }
+
     fn file_source(&self) -> Arc<dyn FileSource> {
         Arc::new(AvroSource::new())
     }
 }
+
+/// Implements [`DataSink`] for writing to Avro files
+pub struct AvroSink {
+    /// Config options for writing data
+    config: FileSinkConfig,
+    /// Writer options for underlying Avro writer
+    writer_options: AvroWriterOptions,
+}
+
+impl fmt::Debug for AvroSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AvroSink").finish()
+    }
+}
+
+impl DisplayAs for AvroSink {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                write!(f, "AvroSink(file_groups=",)?;
+                FileGroupDisplay(&self.config.file_group).fmt_as(t, f)?;
+                write!(f, ")")
+            }
+            DisplayFormatType::TreeRender => {
+                writeln!(f, "format: avro")?;
+                write!(f, "file={}", &self.config.original_url)
+            }
+        }
+    }
+}
+
+impl AvroSink {
+    /// Create from config.
+    pub fn new(config: FileSinkConfig, writer_options: AvroWriterOptions) -> Self {
+        Self {
+            config,
+            writer_options,
+        }
+    }
+
+    /// Retrieve the writer options
+    pub fn writer_options(&self) -> &AvroWriterOptions {
+        &self.writer_options
+    }
+}
+
+#[async_trait]
+impl FileSink for AvroSink {
+    fn config(&self) -> &FileSinkConfig {
+        &self.config
+    }
+
+    async fn spawn_writer_tasks_and_join(
+        &self,
+        context: &Arc<TaskContext>,
+        demux_task: SpawnedTask<Result<()>>,
+        mut file_stream_rx: DemuxedStreamReceiver,
+        object_store: Arc<dyn ObjectStore>,
+    ) -> Result<u64> {
+        let mut file_write_tasks: JoinSet<Result<usize>> = JoinSet::new();
+
+        let schema = get_writer_schema(&self.config);
+        let codec = parse_codec(&self.writer_options.codec)?;
+        while let Some((path, mut rx)) = file_stream_rx.recv().await {
+            let shared_buffer = SharedBuffer::new(INITIAL_BUFFER_BYTES);
+            let avro_schema = to_avro_schema(&schema)?;
+            let mut object_store_writer = ObjectWriterBuilder::new(
+                FileCompressionType::UNCOMPRESSED,
+                &path,
+                Arc::clone(&object_store),
+            )
+            .with_buffer_size(Some(
+                context
+                    .session_config()
+                    .options()
+                    .execution
+                    .objectstore_writer_buffer_size,
+            ))
+            .build()?;
+            file_write_tasks.spawn(async move {
+                let mut avro_writer = apache_avro::Writer::with_codec(
+                    &avro_schema,
+                    shared_buffer.clone(),
+                    codec,
+                );
+                let mut row_count = 0;
+                while let Some(batch) = rx.recv().await {
+                    row_count += batch.num_rows();
+                    for record in to_avro_records(&batch)? {
+                        avro_writer.append(record)?;
+                    }
+                    // Close the current data block, so that it can be flushed
+                    avro_writer.flush()?;
+                    let mut buff_to_flush = shared_buffer.buffer.try_lock().unwrap();
+                    if buff_to_flush.len() > BUFFER_FLUSH_BYTES {
+                        object_store_writer
+                            .write_all(buff_to_flush.as_slice())
+                            .await?;
+                        buff_to_flush.clear();
+                    }
+                }
+                // Writes the header if no rows were written
+                avro_writer.flush()?;
+                let final_buff = shared_buffer.buffer.try_lock().unwrap();
+
+                object_store_writer.write_all(final_buff.as_slice()).await?;
+                object_store_writer.shutdown().await?;
+                Ok(row_count)
+            });
+        }
+
+        let mut row_count = 0;
+        while let Some(result) = file_write_tasks.join_next().await {
+            match result {
+                Ok(r) => {
+                    row_count += r?;
+                }
+                Err(e) => {
+                    if e.is_panic() {
+                        std::panic::resume_unwind(e.into_panic());
+                    } else {
+                        unreachable!();
+                    }
+                }
+            }
+        }
+
+        demux_task
+            .join_unwind()
+            .await
+            .map_err(|e| DataFusionError::ExecutionJoin(Box::new(e)))??;
+        Ok(row_count as u64)
+    }
+}
+
+#[async_trait]
+impl DataSink for AvroSink {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn schema(&self) -> &SchemaRef {
+        self.config.output_schema()
+    }
+
+    async fn write_all(
+        &self,
+        data: SendableRecordBatchStream,
+        context: &Arc<TaskContext>,
+    ) -> Result<u64> {
+        FileSink::write_all(self, data, context).await
+    }
+}
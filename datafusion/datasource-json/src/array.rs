@@ -0,0 +1,184 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Support for reading JSON files made of a single top-level array
+//!
+//! The arrow JSON decoder reads a sequence of whitespace separated JSON
+//! values. A file holding a top-level array of objects is turned into such a
+//! sequence by replacing the enclosing brackets and the commas separating the
+//! elements with spaces. As the rewrite preserves the length of the input, it
+//! is applied in place, chunk by chunk, without buffering the whole file.
+
+use std::io::{self, Read};
+
+use arrow::error::ArrowError;
+use bytes::Bytes;
+use datafusion_common::Result;
+use futures::stream::BoxStream;
+use futures::StreamExt;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum ScanState {
+    /// Before the opening `[` of the top-level array
+    #[default]
+    Start,
+    /// Inside the top-level array
+    Array,
+    /// After the closing `]` of the top-level array
+    End,
+}
+
+/// Incrementally rewrites the bytes of a top-level JSON array into whitespace
+/// separated JSON values
+#[derive(Debug, Default)]
+pub struct JsonArrayScanner {
+    state: ScanState,
+    /// Nesting depth of objects and arrays within the top-level array
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+}
+
+impl JsonArrayScanner {
+    /// Creates a new [`JsonArrayScanner`] positioned at the start of a file
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rewrites the next chunk of the file in place
+    pub fn scan(&mut self, buf: &mut [u8]) -> Result<(), ArrowError> {
+        for b in buf.iter_mut() {
+            match self.state {
+                ScanState::Start => match *b {
+                    b'[' => {
+                        *b = b' ';
+                        self.state = ScanState::Array;
+                    }
+                    c if c.is_ascii_whitespace() => {}
+                    c => {
+                        return Err(ArrowError::JsonError(format!(
+                            "Expected a top-level JSON array, found '{}'",
+                            c as char
+                        )))
+                    }
+                },
+                ScanState::Array if self.in_string => {
+                    if self.escaped {
+                        self.escaped = false;
+                    } else if *b == b'\\' {
+                        self.escaped = true;
+                    } else if *b == b'"' {
+                        self.in_string = false;
+                    }
+                }
+                ScanState::Array => match *b {
+                    b'"' => self.in_string = true,
+                    b'{' | b'[' => self.depth += 1,
+                    b']' if self.depth == 0 => {
+                        *b = b' ';
+                        self.state = ScanState::End;
+                    }
+                    b'}' | b']' => {
+                        self.depth = self.depth.checked_sub(1).ok_or_else(|| {
+                            ArrowError::JsonError(format!(
+                                "Unexpected '{}' in JSON array",
+                                *b as char
+                            ))
+                        })?;
+                    }
+                    b',' if self.depth == 0 => *b = b' ',
+                    _ => {}
+                },
+                ScanState::End => {
+                    if !b.is_ascii_whitespace() {
+                        return Err(ArrowError::JsonError(
+                            "Unexpected data after the end of the top-level JSON array"
+                                .to_string(),
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the top-level array was closed, once the whole file has
+    /// been scanned. An empty file is treated as an empty array.
+    pub fn finish(&self) -> Result<(), ArrowError> {
+        match self.state {
+            ScanState::Array => Err(ArrowError::JsonError(
+                "Unexpected end of file within the top-level JSON array".to_string(),
+            )),
+            ScanState::Start | ScanState::End => Ok(()),
+        }
+    }
+}
+
+/// A [`Read`] adapter that rewrites a top-level JSON array into whitespace
+/// separated JSON values, see [`JsonArrayScanner`]
+pub struct JsonArrayReader<R> {
+    inner: R,
+    scanner: JsonArrayScanner,
+}
+
+impl<R: Read> JsonArrayReader<R> {
+    /// Wraps a reader positioned at the start of a JSON array file
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            scanner: JsonArrayScanner::new(),
+        }
+    }
+}
+
+impl<R: Read> Read for JsonArrayReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        let result = if n == 0 {
+            self.scanner.finish()
+        } else {
+            self.scanner.scan(&mut buf[..n])
+        };
+        result.map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        Ok(n)
+    }
+}
+
+/// Rewrites a stream of chunks of a top-level JSON array into whitespace
+/// separated JSON values, see [`JsonArrayScanner`]
+pub fn json_array_stream(
+    input: BoxStream<'static, Result<Bytes>>,
+) -> BoxStream<'static, Result<Bytes>> {
+    futures::stream::unfold(Some((input, JsonArrayScanner::new())), |state| async move {
+        let (mut input, mut scanner) = state?;
+        match input.next().await {
+            Some(Ok(bytes)) => {
+                let mut buf = bytes.to_vec();
+                match scanner.scan(&mut buf) {
+                    Ok(()) => Some((Ok(Bytes::from(buf)), Some((input, scanner)))),
+                    Err(e) => Some((Err(e.into()), None)),
+                }
+            }
+            Some(Err(e)) => Some((Err(e), None)),
+            None => match scanner.finish() {
+                Ok(()) => None,
+                Err(e) => Some((Err(e.into()), None)),
+            },
+        }
+    })
+    .boxed()
+}
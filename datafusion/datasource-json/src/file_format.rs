@@ -15,15 +15,16 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! [`JsonFormat`]: Line delimited JSON [`FileFormat`] abstractions
+//! [`JsonFormat`]: Line delimited and array JSON [`FileFormat`] abstractions
 
 use std::any::Any;
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
-use std::io::BufReader;
+use std::io::{BufReader, Read};
 use std::sync::Arc;
 
+use crate::array::JsonArrayReader;
 use crate::source::JsonSource;
 
 use arrow::array::RecordBatch;
@@ -31,12 +32,14 @@ use arrow::datatypes::{Schema, SchemaRef};
 use arrow::error::ArrowError;
 use arrow::json;
 use arrow::json::reader::{infer_json_schema_from_iterator, ValueIter};
-use datafusion_common::config::{ConfigField, ConfigFileType, JsonOptions};
+use datafusion_common::config::{
+    ConfigField, ConfigFileType, JsonFormatType, JsonOptions,
+};
 use datafusion_common::file_options::json_writer::JsonWriterOptions;
 use datafusion_common::{
-    not_impl_err, GetExt, Result, Statistics, DEFAULT_JSON_EXTENSION,
+    not_impl_err, DataFusionError, GetExt, Result, Statistics, DEFAULT_JSON_EXTENSION,
 };
-use datafusion_common_runtime::SpawnedTask;
+use datafusion_common_runtime::{JoinSet, SpawnedTask};
 use datafusion_datasource::decoder::Decoder;
 use datafusion_datasource::display::FileGroupDisplay;
 use datafusion_datasource::file::FileSource;
@@ -49,7 +52,7 @@ use datafusion_datasource::file_sink_config::{FileSink, FileSinkConfig};
 use datafusion_datasource::sink::{DataSink, DataSinkExec};
 use datafusion_datasource::write::demux::DemuxedStreamReceiver;
 use datafusion_datasource::write::orchestration::spawn_writer_tasks_and_join;
-use datafusion_datasource::write::BatchSerializer;
+use datafusion_datasource::write::{BatchSerializer, ObjectWriterBuilder, SharedBuffer};
 use datafusion_execution::{SendableRecordBatchStream, TaskContext};
 use datafusion_expr::dml::InsertOp;
 use datafusion_physical_expr_common::sort_expr::LexRequirement;
@@ -60,6 +63,11 @@ use async_trait::async_trait;
 use bytes::{Buf, Bytes};
 use datafusion_datasource::source::DataSourceExec;
 use object_store::{GetResultPayload, ObjectMeta, ObjectStore};
+use tokio::io::AsyncWriteExt;
+
+/// Initial writing buffer size for JSON array files. Note this is just a size
+/// hint for efficiency. It will grow beyond the set value if needed.
+const INITIAL_BUFFER_BYTES: usize = 1048576;
 
 #[derive(Default)]
 /// Factory struct used to create [JsonFormat]
@@ -131,7 +139,8 @@ impl Debug for JsonFormatFactory {
     }
 }
 
-/// New line delimited JSON `FileFormat` implementation.
+/// JSON `FileFormat` implementation, reading and writing either new line
+/// delimited JSON or a single top-level JSON array of objects.
 #[derive(Debug, Default)]
 pub struct JsonFormat {
     options: JsonOptions,
@@ -165,6 +174,13 @@ impl JsonFormat {
         self.options.compression = file_compression_type.into();
         self
     }
+
+    /// Set the layout of the JSON files
+    /// - defaults to `JsonFormatType::NewlineDelimited`
+    pub fn with_format(mut self, format: JsonFormatType) -> Self {
+        self.options.format = format;
+        self
+    }
 }
 
 #[async_trait]
@@ -201,6 +217,7 @@ impl FileFormat for JsonFormat {
             .schema_infer_max_rec
             .unwrap_or(DEFAULT_SCHEMA_INFER_MAX_RECORD);
         let file_compression_type = FileCompressionType::from(self.options.compression);
+        let format = self.options.format;
         for object in objects {
             let mut take_while = || {
                 let should_take = records_to_read > 0;
@@ -215,16 +232,12 @@ impl FileFormat for JsonFormat {
                 #[cfg(not(target_arch = "wasm32"))]
                 GetResultPayload::File(file, _) => {
                     let decoder = file_compression_type.convert_read(file)?;
-                    let mut reader = BufReader::new(decoder);
-                    let iter = ValueIter::new(&mut reader, None);
-                    infer_json_schema_from_iterator(iter.take_while(|_| take_while()))?
+                    infer_schema_from_read(decoder, format, &mut take_while)?
                 }
                 GetResultPayload::Stream(_) => {
                     let data = r.bytes().await?;
                     let decoder = file_compression_type.convert_read(data.reader())?;
-                    let mut reader = BufReader::new(decoder);
-                    let iter = ValueIter::new(&mut reader, None);
-                    infer_json_schema_from_iterator(iter.take_while(|_| take_while()))?
+                    infer_schema_from_read(decoder, format, &mut take_while)?
                 }
             };
 
@@ -253,7 +266,7 @@ impl FileFormat for JsonFormat {
         _state: &dyn Session,
         conf: FileScanConfig,
     ) -> Result<Arc<dyn ExecutionPlan>> {
-        let source = Arc::new(JsonSource::new());
+        let source = Arc::new(JsonSource::new().with_format(self.options.format));
         let conf = FileScanConfigBuilder::from(conf)
             .with_file_compression_type(FileCompressionType::from(
                 self.options.compression,
@@ -282,10 +295,34 @@ impl FileFormat for JsonFormat {
     }
 
     fn file_source(&self) -> Arc<dyn FileSource> {
-        Arc::new(JsonSource::default())
+        Arc::new(JsonSource::default().with_format(self.options.format))
     }
 }
 
+/// Infers the schema of the records read from `read`, as long as `take_while`
+/// returns true
+fn infer_schema_from_read(
+    read: impl Read,
+    format: JsonFormatType,
+    mut take_while: impl FnMut() -> bool,
+) -> Result<Schema> {
+    let schema = match format {
+        JsonFormatType::NewlineDelimited => {
+            let mut reader = BufReader::new(read);
+            let iter = ValueIter::new(&mut reader, None);
+            infer_json_schema_from_iterator(iter.take_while(|_| take_while()))?
+        }
+        JsonFormatType::Array => {
+            let reader = BufReader::new(JsonArrayReader::new(read));
+            let iter = serde_json::Deserializer::from_reader(reader)
+                .into_iter::<serde_json::Value>()
+                .map(|value| value.map_err(|e| ArrowError::JsonError(e.to_string())));
+            infer_json_schema_from_iterator(iter.take_while(|_| take_while()))?
+        }
+    };
+    Ok(schema)
+}
+
 impl Default for JsonSerializer {
     fn default() -> Self {
         Self::new()
@@ -354,6 +391,78 @@ impl JsonSink {
     pub fn writer_options(&self) -> &JsonWriterOptions {
         &self.writer_options
     }
+
+    /// Writes each file as a single JSON array. Unlike new line delimited
+    /// JSON, the batches of a file can't be serialized independently of each
+    /// other, as the array must be opened and closed exactly once.
+    async fn spawn_array_writer_tasks_and_join(
+        &self,
+        context: &Arc<TaskContext>,
+        demux_task: SpawnedTask<Result<()>>,
+        mut file_stream_rx: DemuxedStreamReceiver,
+        object_store: Arc<dyn ObjectStore>,
+    ) -> Result<u64> {
+        let mut file_write_tasks: JoinSet<Result<usize>> = JoinSet::new();
+
+        while let Some((path, mut rx)) = file_stream_rx.recv().await {
+            let shared_buffer = SharedBuffer::new(INITIAL_BUFFER_BYTES);
+            let mut object_store_writer = ObjectWriterBuilder::new(
+                self.writer_options.compression.into(),
+                &path,
+                Arc::clone(&object_store),
+            )
+            .with_buffer_size(Some(
+                context
+                    .session_config()
+                    .options()
+                    .execution
+                    .objectstore_writer_buffer_size,
+            ))
+            .build()?;
+            file_write_tasks.spawn(async move {
+                let mut array_writer = json::ArrayWriter::new(shared_buffer.clone());
+                let mut row_count = 0;
+                while let Some(batch) = rx.recv().await {
+                    row_count += batch.num_rows();
+                    array_writer.write(&batch)?;
+                    let mut buff_to_flush = shared_buffer.buffer.try_lock().unwrap();
+                    object_store_writer
+                        .write_all(buff_to_flush.as_slice())
+                        .await?;
+                    buff_to_flush.clear();
+                }
+                // Opens the array if no rows were written, and closes it
+                array_writer.finish()?;
+                let final_buff = shared_buffer.buffer.try_lock().unwrap();
+
+                object_store_writer.write_all(final_buff.as_slice()).await?;
+                object_store_writer.shutdown().await?;
+                Ok(row_count)
+            });
+        }
+
+        let mut row_count = 0;
+        while let Some(result) = file_write_tasks.join_next().await {
+            match result {
+                Ok(r) => {
+                    row_count += r?;
+                }
+                Err(e) => {
+                    if e.is_panic() {
+                        std::panic::resume_unwind(e.into_panic());
+                    } else {
+                        unreachable!();
+                    }
+                }
+            }
+        }
+
+        demux_task
+            .join_unwind()
+            .await
+            .map_err(|e| DataFusionError::ExecutionJoin(Box::new(e)))??;
+        Ok(row_count as u64)
+    }
 }
 
 #[async_trait]
@@ -369,6 +478,17 @@ impl FileSink for JsonSink {
         file_stream_rx: DemuxedStreamReceiver,
         object_store: Arc<dyn ObjectStore>,
     ) -> Result<u64> {
+        if self.writer_options.format == JsonFormatType::Array {
+            return self
+                .spawn_array_writer_tasks_and_join(
+                    context,
+                    demux_task,
+                    file_stream_rx,
+                    object_store,
+                )
+                .await;
+        }
+
         let serializer = Arc::new(JsonSerializer::new()) as _;
         spawn_writer_tasks_and_join(
             context,
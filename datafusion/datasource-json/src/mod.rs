@@ -19,6 +19,7 @@
 // https://github.com/apache/datafusion/issues/11143
 #![cfg_attr(not(test), deny(clippy::clone_on_ref_ptr))]
 
+pub mod array;
 pub mod file_format;
 pub mod source;
 
@@ -15,25 +15,29 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Execution plan for reading line-delimited JSON files
+//! Execution plan for reading line-delimited and array JSON files
 
 use std::any::Any;
 use std::io::{BufReader, Read, Seek, SeekFrom};
 use std::sync::Arc;
 use std::task::Poll;
 
+use crate::array::{json_array_stream, JsonArrayReader};
 use crate::file_format::JsonDecoder;
 
+use datafusion_common::config::JsonFormatType;
 use datafusion_common::error::{DataFusionError, Result};
 use datafusion_common_runtime::JoinSet;
 use datafusion_datasource::decoder::{deserialize_stream, DecoderDeserializer};
 use datafusion_datasource::file_compression_type::FileCompressionType;
+use datafusion_datasource::file_groups::FileGroupPartitioner;
 use datafusion_datasource::file_meta::FileMeta;
 use datafusion_datasource::file_stream::{FileOpenFuture, FileOpener};
 use datafusion_datasource::schema_adapter::SchemaAdapterFactory;
 use datafusion_datasource::{
     as_file_source, calculate_range, ListingTableUrl, PartitionedFile, RangeCalculation,
 };
+use datafusion_physical_expr::LexOrdering;
 use datafusion_physical_plan::{ExecutionPlan, ExecutionPlanProperties};
 
 use arrow::json::ReaderBuilder;
@@ -55,6 +59,7 @@ pub struct JsonOpener {
     projected_schema: SchemaRef,
     file_compression_type: FileCompressionType,
     object_store: Arc<dyn ObjectStore>,
+    format: JsonFormatType,
 }
 
 impl JsonOpener {
@@ -70,8 +75,15 @@ impl JsonOpener {
             projected_schema,
             file_compression_type,
             object_store,
+            format: JsonFormatType::default(),
         }
     }
+
+    /// Sets the layout of the JSON files to open
+    pub fn with_format(mut self, format: JsonFormatType) -> Self {
+        self.format = format;
+        self
+    }
 }
 
 /// JsonSource holds the extra configuration that is necessary for [`JsonOpener`]
@@ -81,6 +93,7 @@ pub struct JsonSource {
     metrics: ExecutionPlanMetricsSet,
     projected_statistics: Option<Statistics>,
     schema_adapter_factory: Option<Arc<dyn SchemaAdapterFactory>>,
+    format: JsonFormatType,
 }
 
 impl JsonSource {
@@ -88,6 +101,18 @@ impl JsonSource {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Initialize a JsonSource with the layout of the JSON files
+    pub fn with_format(&self, format: JsonFormatType) -> Self {
+        let mut conf = self.clone();
+        conf.format = format;
+        conf
+    }
+
+    /// The layout of the JSON files
+    pub fn format(&self) -> JsonFormatType {
+        self.format
+    }
 }
 
 impl From<JsonSource> for Arc<dyn FileSource> {
@@ -110,6 +135,7 @@ impl FileSource for JsonSource {
             projected_schema: base_config.projected_file_schema(),
             file_compression_type: base_config.file_compression_type,
             object_store,
+            format: self.format,
         })
     }
 
@@ -151,6 +177,36 @@ impl FileSource for JsonSource {
         "json"
     }
 
+    /// The elements of a JSON array can't be located from an arbitrary byte
+    /// offset, so array files are never split into byte ranges.
+    fn repartitioned(
+        &self,
+        target_partitions: usize,
+        repartition_file_min_size: usize,
+        output_ordering: Option<LexOrdering>,
+        config: &FileScanConfig,
+    ) -> Result<Option<FileScanConfig>> {
+        if self.format == JsonFormatType::Array
+            || config.file_compression_type.is_compressed()
+            || config.new_lines_in_values
+        {
+            return Ok(None);
+        }
+
+        let repartitioned_file_groups_option = FileGroupPartitioner::new()
+            .with_target_partitions(target_partitions)
+            .with_repartition_file_min_size(repartition_file_min_size)
+            .with_preserve_order_within_groups(output_ordering.is_some())
+            .repartition_file_groups(&config.file_groups);
+
+        if let Some(repartitioned_file_groups) = repartitioned_file_groups_option {
+            let mut source = config.clone();
+            source.file_groups = repartitioned_file_groups;
+            return Ok(Some(source));
+        }
+        Ok(None)
+    }
+
     fn with_schema_adapter_factory(
         &self,
         schema_adapter_factory: Arc<dyn SchemaAdapterFactory>,
@@ -167,7 +223,7 @@ impl FileSource for JsonSource {
 }
 
 impl FileOpener for JsonOpener {
-    /// Open a partitioned NDJSON file.
+    /// Open a partitioned NDJSON file, or a whole JSON array file.
     ///
     /// If `file_meta.range` is `None`, the entire file is opened.
     /// Else `file_meta.range` is `Some(FileRange{start, end})`, which corresponds to the byte range [start, end) within the file.
@@ -185,6 +241,7 @@ impl FileOpener for JsonOpener {
         let schema = Arc::clone(&self.projected_schema);
         let batch_size = self.batch_size;
         let file_compression_type = self.file_compression_type.to_owned();
+        let is_array = self.format == JsonFormatType::Array;
 
         Ok(Box::pin(async move {
             let calculated_range = calculate_range(&file_meta, &store, None).await?;
@@ -217,6 +274,11 @@ impl FileOpener for JsonOpener {
                             file_compression_type.convert_read(file.take(limit as u64))?
                         }
                     };
+                    let bytes: Box<dyn Read + Send> = if is_array {
+                        Box::new(JsonArrayReader::new(bytes))
+                    } else {
+                        bytes
+                    };
 
                     let reader = ReaderBuilder::new(schema)
                         .with_batch_size(batch_size)
@@ -230,7 +292,13 @@ impl FileOpener for JsonOpener {
                     let decoder = ReaderBuilder::new(schema)
                         .with_batch_size(batch_size)
                         .build_decoder()?;
-                    let input = file_compression_type.convert_stream(s.boxed())?.fuse();
+                    let input = file_compression_type.convert_stream(s.boxed())?;
+                    let input = if is_array {
+                        json_array_stream(input)
+                    } else {
+                        input
+                    }
+                    .fuse();
 
                     Ok(deserialize_stream(
                         input,
@@ -1164,6 +1164,8 @@ fn get_col_stats(
                 min_value: min_value.map(Precision::Exact).unwrap_or(Precision::Absent),
                 sum_value: Precision::Absent,
                 distinct_count: Precision::Absent,
+                histogram: None,
+                most_common_values: None,
             }
         })
         .collect()
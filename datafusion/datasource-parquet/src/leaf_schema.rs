@@ -0,0 +1,251 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`LeafSchema`]: a flattened view of the leaf columns of a Parquet file
+
+use std::sync::Arc;
+
+use arrow::datatypes::{DataType, Field, Schema};
+use datafusion_common::Result;
+use parquet::arrow::arrow_reader::statistics::StatisticsConverter;
+use parquet::schema::types::{SchemaDescriptor, Type as SchemaType};
+
+/// A flattened view of the leaf columns of a Parquet file, with one root
+/// column per leaf column named by its dotted path (e.g. `s.a.b`).
+///
+/// [`StatisticsConverter`] only resolves top level, non nested, arrow fields.
+/// Resolving the path of a field nested in struct columns against this schema
+/// instead makes the statistics of the corresponding leaf column available
+/// for pruning.
+#[derive(Debug)]
+pub(crate) struct LeafSchema {
+    /// One field per leaf column, in leaf order. Leaves not reachable through
+    /// struct fields only (e.g. list elements) are [`DataType::Null`]
+    arrow_schema: Schema,
+    /// The leaf columns as root columns, so the root index is the leaf index
+    parquet_schema: SchemaDescriptor,
+}
+
+impl LeafSchema {
+    /// Create a [`LeafSchema`] for a file with the given arrow and parquet
+    /// schemas, or `None` if the file has no struct columns
+    pub(crate) fn try_new(
+        arrow_schema: &Schema,
+        parquet_schema: &SchemaDescriptor,
+    ) -> Result<Option<Self>> {
+        if !arrow_schema
+            .fields()
+            .iter()
+            .any(|field| matches!(field.data_type(), DataType::Struct(_)))
+        {
+            return Ok(None);
+        }
+
+        let (fields, leaves): (Vec<_>, Vec<_>) = parquet_schema
+            .columns()
+            .iter()
+            .map(|column| {
+                let path = column.path();
+                let data_type = leaf_field(arrow_schema, path.parts())
+                    .map(|field| field.data_type().clone())
+                    .unwrap_or(DataType::Null);
+                let field = Field::new(path.string(), data_type, true);
+                (field, column.self_type_ptr())
+            })
+            .unzip();
+
+        let root = SchemaType::group_type_builder(parquet_schema.root_schema().name())
+            .with_fields(leaves)
+            .build()?;
+
+        Ok(Some(Self {
+            arrow_schema: Schema::new(fields),
+            parquet_schema: SchemaDescriptor::new(Arc::new(root)),
+        }))
+    }
+
+    /// Return a [`StatisticsConverter`] for the leaf column with the dotted
+    /// path `column_name`
+    pub(crate) fn statistics_converter(
+        &self,
+        column_name: &str,
+    ) -> Result<StatisticsConverter<'_>> {
+        Ok(StatisticsConverter::try_new(
+            column_name,
+            &self.arrow_schema,
+            &self.parquet_schema,
+        )?)
+    }
+}
+
+/// Return the index of the leaf column with the dotted path `column_name`
+pub(crate) fn leaf_column_index(
+    parquet_schema: &SchemaDescriptor,
+    column_name: &str,
+) -> Option<usize> {
+    parquet_schema
+        .columns()
+        .iter()
+        .position(|column| column.path().string() == column_name)
+}
+
+/// Return the leaf columns of the parquet file to read for the root columns in
+/// `projection`, indices into `file_schema`.
+///
+/// Only the leaves of the fields of a struct column that are also in the
+/// column of the same name in `table_schema` are read, so the struct columns
+/// are read pruned like the table struct columns. If none of them are in the
+/// file, all the leaves of the column are read instead.
+pub(crate) fn leaf_projection(
+    projection: &[usize],
+    file_schema: &Schema,
+    table_schema: &Schema,
+    schema_descr: &SchemaDescriptor,
+) -> Vec<usize> {
+    projection
+        .iter()
+        .flat_map(|&root| {
+            let leaves = (0..schema_descr.num_columns())
+                .filter(|&leaf| schema_descr.get_column_root_idx(leaf) == root)
+                .collect::<Vec<_>>();
+            let Ok(table_field) =
+                table_schema.field_with_name(file_schema.field(root).name())
+            else {
+                return leaves;
+            };
+            let selected = leaves
+                .iter()
+                .copied()
+                .filter(|&leaf| {
+                    contains_leaf(
+                        table_field,
+                        &schema_descr.column(leaf).path().parts()[1..],
+                    )
+                })
+                .collect::<Vec<_>>();
+            if selected.is_empty() {
+                leaves
+            } else {
+                selected
+            }
+        })
+        .collect()
+}
+
+/// Returns true if the leaf column at `path` from `field` is read for `field`:
+/// walks the struct fields of `field` along `path` until reaching a field that
+/// is not a struct
+fn contains_leaf(field: &Field, path: &[String]) -> bool {
+    match (field.data_type(), path.split_first()) {
+        (DataType::Struct(children), Some((name, rest))) => children
+            .find(name)
+            .is_some_and(|(_, child)| contains_leaf(child, rest)),
+        _ => true,
+    }
+}
+
+/// Walk the struct fields of `schema` along `path`, returning the primitive
+/// field at its end, if any
+fn leaf_field<'a>(schema: &'a Schema, path: &[String]) -> Option<&'a Field> {
+    let (root, rest) = path.split_first()?;
+    let mut field = schema.field_with_name(root).ok()?;
+    for name in rest {
+        let DataType::Struct(fields) = field.data_type() else {
+            return None;
+        };
+        field = fields.find(name)?.1.as_ref();
+    }
+    (!field.data_type().is_nested()).then_some(field)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use parquet::arrow::ArrowSchemaConverter;
+
+    #[test]
+    fn test_leaf_schema() {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new_struct(
+                "s",
+                vec![
+                    Field::new("b", DataType::Utf8, true),
+                    Field::new_list(
+                        "l",
+                        Field::new_list_field(DataType::Int64, true),
+                        true,
+                    ),
+                ],
+                true,
+            ),
+        ]);
+        let parquet_schema = ArrowSchemaConverter::new().convert(&schema).unwrap();
+
+        let leaves = LeafSchema::try_new(&schema, &parquet_schema)
+            .unwrap()
+            .unwrap();
+        assert_eq!(leaves.parquet_schema.num_columns(), 3);
+        assert_eq!(leaves.arrow_schema.fields().len(), 3);
+        assert_eq!(leaves.arrow_schema.field(0).name(), "a");
+        assert_eq!(leaves.arrow_schema.field(1).name(), "s.b");
+        assert_eq!(leaves.arrow_schema.field(1).data_type(), &DataType::Utf8);
+        assert_eq!(leaves.arrow_schema.field(2).data_type(), &DataType::Null);
+        for idx in 0..3 {
+            assert_eq!(leaves.parquet_schema.get_column_root_idx(idx), idx);
+        }
+
+        let converter = leaves.statistics_converter("s.b").unwrap();
+        assert_eq!(converter.parquet_column_index(), Some(1));
+
+        assert_eq!(leaf_column_index(&parquet_schema, "s.b"), Some(1));
+        assert_eq!(leaf_column_index(&parquet_schema, "s"), None);
+
+        // only the leaves of the fields of the table struct column are read
+        let table_schema = Schema::new(vec![Field::new_struct(
+            "s",
+            vec![Field::new("b", DataType::Utf8View, true)],
+            true,
+        )]);
+        let projection = [0, 1];
+        assert_eq!(
+            leaf_projection(&projection, &schema, &table_schema, &parquet_schema),
+            vec![0, 1]
+        );
+        let table_schema = Schema::new(vec![Field::new_struct(
+            "s",
+            vec![Field::new("c", DataType::Utf8, true)],
+            true,
+        )]);
+        assert_eq!(
+            leaf_projection(&[1], &schema, &table_schema, &parquet_schema),
+            vec![1, 2]
+        );
+        assert_eq!(
+            leaf_projection(&[1], &schema, &schema, &parquet_schema),
+            vec![1, 2]
+        );
+
+        // no struct columns
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        let parquet_schema = ArrowSchemaConverter::new().convert(&schema).unwrap();
+        assert!(LeafSchema::try_new(&schema, &parquet_schema)
+            .unwrap()
+            .is_none());
+    }
+}
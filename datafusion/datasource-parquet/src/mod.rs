@@ -21,6 +21,7 @@
 
 pub mod access_plan;
 pub mod file_format;
+mod leaf_schema;
 mod metrics;
 mod opener;
 mod page_filter;
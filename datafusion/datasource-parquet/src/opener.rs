@@ -19,6 +19,7 @@
 
 use std::sync::Arc;
 
+use crate::leaf_schema::leaf_projection;
 use crate::page_filter::PagePruningAccessPlanFilter;
 use crate::row_group_filter::RowGroupAccessPlanFilter;
 use crate::{
@@ -121,9 +122,10 @@ impl FileOpener for ParquetOpener {
         let projected_schema =
             SchemaRef::from(self.logical_file_schema.project(&self.projection)?);
         let schema_adapter_factory = Arc::clone(&self.schema_adapter_factory);
-        let schema_adapter = self
-            .schema_adapter_factory
-            .create(projected_schema, Arc::clone(&self.logical_file_schema));
+        let schema_adapter = self.schema_adapter_factory.create(
+            Arc::clone(&projected_schema),
+            Arc::clone(&self.logical_file_schema),
+        );
         let mut predicate = self.predicate.clone();
         let logical_file_schema = Arc::clone(&self.logical_file_schema);
         let partition_fields = self.partition_fields.clone();
@@ -298,9 +300,16 @@ impl FileOpener for ParquetOpener {
             let (schema_mapping, adapted_projections) =
                 schema_adapter.map_schema(&physical_file_schema)?;
 
-            let mask = ProjectionMask::roots(
+            // Only read the fields of struct columns in the projected schema,
+            // which may have been pruned by a nested projection
+            let mask = ProjectionMask::leaves(
                 builder.parquet_schema(),
-                adapted_projections.iter().cloned(),
+                leaf_projection(
+                    &adapted_projections,
+                    &physical_file_schema,
+                    &projected_schema,
+                    builder.parquet_schema(),
+                ),
             );
 
             // Filter pushdown: evaluate predicates during scan
@@ -21,6 +21,7 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use super::metrics::ParquetFileMetrics;
+use crate::leaf_schema::LeafSchema;
 use crate::ParquetAccessPlan;
 
 use arrow::array::BooleanArray;
@@ -184,6 +185,15 @@ impl PagePruningAccessPlanFilter {
             return access_plan;
         };
 
+        // resolves the page index of fields nested in struct columns
+        let leaf_schema = match LeafSchema::try_new(arrow_schema, parquet_schema) {
+            Ok(leaf_schema) => leaf_schema,
+            Err(e) => {
+                debug!("Ignoring error building leaf column schema: {e}");
+                None
+            }
+        };
+
         // track the total number of rows that should be skipped
         let mut total_skip = 0;
         // track the total number of rows that should not be skipped
@@ -200,11 +210,19 @@ impl PagePruningAccessPlanFilter {
                     .single_column()
                     .expect("Page pruning requires single column predicates");
 
-                let converter = StatisticsConverter::try_new(
-                    column.name(),
-                    arrow_schema,
-                    parquet_schema,
-                );
+                let converter = match &leaf_schema {
+                    Some(leaf_schema)
+                        if arrow_schema.index_of(column.name()).is_err() =>
+                    {
+                        leaf_schema.statistics_converter(column.name())
+                    }
+                    _ => StatisticsConverter::try_new(
+                        column.name(),
+                        arrow_schema,
+                        parquet_schema,
+                    )
+                    .map_err(Into::into),
+                };
 
                 let converter = match converter {
                     Ok(converter) => converter,
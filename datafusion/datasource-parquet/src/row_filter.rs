@@ -60,11 +60,11 @@
 //!    still be sorted by size.
 
 use std::cmp::Ordering;
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
 
 use arrow::array::BooleanArray;
-use arrow::datatypes::{DataType, Schema, SchemaRef};
+use arrow::datatypes::{DataType, Fields, Schema, SchemaRef};
 use arrow::error::{ArrowError, Result as ArrowResult};
 use arrow::record_batch::RecordBatch;
 use parquet::arrow::arrow_reader::{ArrowPredicate, RowFilter};
@@ -76,12 +76,15 @@ use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion, TreeNodeVisitor}
 use datafusion_common::Result;
 use datafusion_datasource::schema_adapter::{SchemaAdapterFactory, SchemaMapper};
 use datafusion_physical_expr::expressions::Column;
-use datafusion_physical_expr::utils::reassign_predicate_columns;
+use datafusion_physical_expr::utils::{
+    nested_field_access, prune_struct_field, reassign_predicate_columns,
+};
 use datafusion_physical_expr::{split_conjunction, PhysicalExpr};
 
 use datafusion_physical_plan::metrics;
 
 use super::ParquetFileMetrics;
+use crate::leaf_schema::leaf_projection;
 
 /// A "compiled" predicate passed to `ParquetRecordBatchStream` to perform
 /// row-level filtering during parquet decoding.
@@ -92,13 +95,14 @@ use super::ParquetFileMetrics;
 ///
 /// An expression can be evaluated as a `DatafusionArrowPredicate` if it:
 /// * Does not reference any projected columns
-/// * Does not reference columns with non-primitive types (e.g. structs / lists)
+/// * Does not reference columns with non-primitive types (e.g. structs / lists),
+///   other than primitive fields nested in struct columns (e.g. `s['a']['b']`)
 #[derive(Debug)]
 pub(crate) struct DatafusionArrowPredicate {
     /// the filter expression
     physical_expr: Arc<dyn PhysicalExpr>,
-    /// Path to the columns in the parquet schema required to evaluate the
-    /// expression
+    /// Path to the leaf columns in the parquet schema required to evaluate
+    /// the expression
     projection_mask: ProjectionMask,
     /// how many rows were filtered out by this predicate
     rows_pruned: metrics::Count,
@@ -125,7 +129,7 @@ impl DatafusionArrowPredicate {
 
         Ok(Self {
             physical_expr,
-            projection_mask: ProjectionMask::roots(
+            projection_mask: ProjectionMask::leaves(
                 metadata.file_metadata().schema_descr(),
                 candidate.projection,
             ),
@@ -183,9 +187,10 @@ pub(crate) struct FilterCandidate {
     required_bytes: usize,
     /// Can this filter use an index (e.g. a page index) to prune rows?
     can_use_index: bool,
-    /// The projection to read from the file schema to get the columns
-    /// required to pass thorugh a `SchemaMapper` to the table schema
-    /// upon which we then evaluate the filter expression.
+    /// The leaf columns to read from the file to get the columns required
+    /// to pass thorugh a `SchemaMapper` to the table schema upon which we
+    /// then evaluate the filter expression. Only the fields of struct columns
+    /// accessed by the filter expression are read.
     projection: Vec<usize>,
     ///  A `SchemaMapper` used to map batches read from the file schema to
     /// the filter's projection of the table schema.
@@ -223,6 +228,10 @@ pub(crate) struct FilterCandidate {
 /// schema that contains only the columns that this filter references.
 /// If any columns from the file schema are missing from a particular file they are
 /// added by the `SchemaAdapter`, by default as `NULL`.
+///
+/// Struct columns in the filter schema only contain the fields nested in them
+/// that are accessed by the predicate, and only the corresponding leaf columns
+/// are read from the file.
 struct FilterCandidateBuilder {
     expr: Arc<dyn PhysicalExpr>,
     /// The schema of this parquet file.
@@ -260,30 +269,53 @@ impl FilterCandidateBuilder {
     /// * `Ok(None)` if the expression cannot be used as an ArrowFilter
     /// * `Err(e)` if an error occurs while building the candidate
     pub fn build(self, metadata: &ParquetMetaData) -> Result<Option<FilterCandidate>> {
-        let Some(required_indices_into_table_schema) =
-            pushdown_columns(&self.expr, &self.table_schema)?
+        let Some(PushdownColumns {
+            required_columns: required_indices_into_table_schema,
+            nested_fields,
+        }) = pushdown_columns(&self.expr, &self.table_schema)?
         else {
             return Ok(None);
         };
 
-        let projected_table_schema = Arc::new(
-            self.table_schema
-                .project(&required_indices_into_table_schema)?,
-        );
+        // only keep the fields of struct columns accessed by the expression
+        let projected_fields = required_indices_into_table_schema
+            .iter()
+            .map(|&idx| {
+                let field = self.table_schema.field(idx);
+                match nested_fields.get(field.name()) {
+                    Some(paths) => {
+                        let paths = paths.iter().map(Vec::as_slice).collect::<Vec<_>>();
+                        Arc::new(prune_struct_field(field, &paths))
+                    }
+                    None => Arc::clone(&self.table_schema.fields()[idx]),
+                }
+            })
+            .collect::<Fields>();
+        let projected_table_schema = Arc::new(Schema::new_with_metadata(
+            projected_fields,
+            self.table_schema.metadata().clone(),
+        ));
 
         let (schema_mapper, projection_into_file_schema) = self
             .schema_adapter_factory
             .create(Arc::clone(&projected_table_schema), self.table_schema)
             .map_schema(&self.file_schema)?;
 
-        let required_bytes = size_of_columns(&projection_into_file_schema, metadata)?;
-        let can_use_index = columns_sorted(&projection_into_file_schema, metadata)?;
+        let projection_into_file_leaves = leaf_projection(
+            &projection_into_file_schema,
+            &self.file_schema,
+            &projected_table_schema,
+            metadata.file_metadata().schema_descr(),
+        );
+
+        let required_bytes = size_of_columns(&projection_into_file_leaves, metadata)?;
+        let can_use_index = columns_sorted(&projection_into_file_leaves, metadata)?;
 
         Ok(Some(FilterCandidate {
             expr: self.expr,
             required_bytes,
             can_use_index,
-            projection: projection_into_file_schema,
+            projection: projection_into_file_leaves,
             schema_mapper: Arc::clone(&schema_mapper),
             filter_schema: Arc::clone(&projected_table_schema),
         }))
@@ -303,6 +335,8 @@ struct PushdownChecker<'schema> {
     projected_columns: bool,
     // Indices into the table schema of the columns required to evaluate the expression
     required_columns: BTreeSet<usize>,
+    // Paths of the primitive fields accessed in struct columns, by column name
+    nested_fields: BTreeMap<String, BTreeSet<Vec<String>>>,
     table_schema: &'schema Schema,
 }
 
@@ -312,6 +346,7 @@ impl<'schema> PushdownChecker<'schema> {
             non_primitive_columns: false,
             projected_columns: false,
             required_columns: BTreeSet::default(),
+            nested_fields: BTreeMap::default(),
             table_schema,
         }
     }
@@ -333,6 +368,23 @@ impl<'schema> PushdownChecker<'schema> {
         None
     }
 
+    fn check_nested_field(
+        &mut self,
+        node: &Arc<dyn PhysicalExpr>,
+    ) -> Option<TreeNodeRecursion> {
+        let (column, path, field) = nested_field_access(node, self.table_schema)?;
+        if field.data_type().is_nested() {
+            return None;
+        }
+        let idx = self.table_schema.index_of(column.name()).ok()?;
+        self.required_columns.insert(idx);
+        self.nested_fields
+            .entry(column.name().to_string())
+            .or_default()
+            .insert(path.into_iter().map(String::from).collect());
+        Some(TreeNodeRecursion::Jump)
+    }
+
     #[inline]
     fn prevents_pushdown(&self) -> bool {
         self.non_primitive_columns || self.projected_columns
@@ -343,6 +395,10 @@ impl TreeNodeVisitor<'_> for PushdownChecker<'_> {
     type Node = Arc<dyn PhysicalExpr>;
 
     fn f_down(&mut self, node: &Self::Node) -> Result<TreeNodeRecursion> {
+        if let Some(recursion) = self.check_nested_field(node) {
+            return Ok(recursion);
+        }
+
         if let Some(column) = node.as_any().downcast_ref::<Column>() {
             if let Some(recursion) = self.check_single_column(column.name()) {
                 return Ok(recursion);
@@ -360,11 +416,22 @@ impl TreeNodeVisitor<'_> for PushdownChecker<'_> {
 fn pushdown_columns(
     expr: &Arc<dyn PhysicalExpr>,
     table_schema: &Schema,
-) -> Result<Option<Vec<usize>>> {
+) -> Result<Option<PushdownColumns>> {
     let mut checker = PushdownChecker::new(table_schema);
     expr.visit(&mut checker)?;
-    Ok((!checker.prevents_pushdown())
-        .then_some(checker.required_columns.into_iter().collect()))
+    Ok((!checker.prevents_pushdown()).then(|| PushdownColumns {
+        required_columns: checker.required_columns.into_iter().collect(),
+        nested_fields: checker.nested_fields,
+    }))
+}
+
+/// The columns required to evaluate an expression that can be pushed down
+struct PushdownColumns {
+    /// Indices into the table schema of the required columns
+    required_columns: Vec<usize>,
+    /// Paths of the primitive fields accessed in struct columns, by column name.
+    /// Struct columns in this map are not otherwise accessed.
+    nested_fields: BTreeMap<String, BTreeSet<Vec<String>>>,
 }
 
 /// Recurses through expr as a tree, finds all `column`s, and checks if any of them would prevent
@@ -382,7 +449,7 @@ pub fn can_expr_be_pushed_down_with_schemas(
     }
 }
 
-/// Calculate the total compressed size of all `Column`'s required for
+/// Calculate the total compressed size of all leaf columns required for
 /// predicate `Expr`.
 ///
 /// This value represents the total amount of IO required to evaluate the
@@ -492,14 +559,17 @@ mod test {
     use super::*;
     use datafusion_common::ScalarValue;
 
+    use arrow::array::{ArrayRef, Int32Array, StringArray, StructArray};
     use arrow::datatypes::{Field, TimeUnit::Nanosecond};
+    use bytes::Bytes;
     use datafusion_datasource::schema_adapter::DefaultSchemaAdapterFactory;
-    use datafusion_expr::{col, Expr};
+    use datafusion_expr::{col, lit, Expr};
+    use datafusion_functions::core::expr_fn::get_field;
     use datafusion_physical_expr::planner::logical2physical;
     use datafusion_physical_plan::metrics::{Count, Time};
 
     use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
-    use parquet::arrow::parquet_to_arrow_schema;
+    use parquet::arrow::{parquet_to_arrow_schema, ArrowWriter};
     use parquet::file::reader::{FileReader, SerializedFileReader};
 
     // We should ignore predicate that read non-primitive columns
@@ -668,6 +738,99 @@ mod test {
         assert!(can_expr_be_pushed_down_with_schemas(&expr, &table_schema));
     }
 
+    #[test]
+    fn nested_struct_fields_pushdown() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new_struct(
+                "s",
+                vec![
+                    Field::new("a", DataType::Int32, true),
+                    Field::new("b", DataType::Utf8, true),
+                ],
+                true,
+            ),
+            Field::new("c", DataType::Int32, true),
+        ]));
+        let s = StructArray::from(vec![
+            (
+                Arc::new(Field::new("a", DataType::Int32, true)),
+                Arc::new(Int32Array::from(vec![Some(1), Some(2), None, Some(4)]))
+                    as ArrayRef,
+            ),
+            (
+                Arc::new(Field::new("b", DataType::Utf8, true)),
+                Arc::new(StringArray::from(vec!["w", "x", "y", "z"])) as ArrayRef,
+            ),
+        ]);
+        let c = Int32Array::from(vec![1, 2, 3, 4]);
+        let batch =
+            RecordBatch::try_new(Arc::clone(&schema), vec![Arc::new(s), Arc::new(c)])
+                .unwrap();
+
+        let mut buf = vec![];
+        let mut writer = ArrowWriter::try_new(&mut buf, Arc::clone(&schema), None)
+            .expect("creating writer");
+        writer.write(&batch).expect("writing batch");
+        writer.close().expect("closing writer");
+
+        let parquet_reader_builder =
+            ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buf))
+                .expect("creating reader");
+        let metadata = parquet_reader_builder.metadata().clone();
+        let file_schema = parquet_reader_builder.schema().clone();
+
+        // s['a'] > 1
+        let expr = get_field(col("s"), "a").gt(lit(1));
+        let expr = logical2physical(&expr, &schema);
+        assert!(can_expr_be_pushed_down_with_schemas(&expr, &schema));
+
+        let candidate = FilterCandidateBuilder::new(
+            expr,
+            file_schema,
+            Arc::clone(&schema),
+            Arc::new(DefaultSchemaAdapterFactory),
+        )
+        .build(&metadata)
+        .expect("building candidate")
+        .expect("candidate expected");
+
+        // only the leaf column `s.a` is read
+        assert_eq!(candidate.projection, vec![0]);
+        assert_eq!(
+            candidate.filter_schema.field(0).data_type(),
+            &DataType::Struct(vec![Field::new("a", DataType::Int32, true)].into())
+        );
+
+        let mut row_filter = DatafusionArrowPredicate::try_new(
+            candidate,
+            &metadata,
+            Count::new(),
+            Count::new(),
+            Time::new(),
+        )
+        .expect("creating filter predicate");
+
+        let mut parquet_reader = parquet_reader_builder
+            .with_projection(row_filter.projection().clone())
+            .build()
+            .expect("building reader");
+        let rb = parquet_reader
+            .next()
+            .expect("expected record batch")
+            .expect("expected error free record batch");
+        assert_eq!(rb.num_columns(), 1);
+
+        let filtered = row_filter.evaluate(rb).expect("evaluating filter");
+        assert_eq!(
+            filtered,
+            BooleanArray::from(vec![Some(false), Some(true), None, Some(true)])
+        );
+
+        // accessing the struct itself still prevents pushdown
+        let expr = logical2physical(&col("s").is_not_null(), &schema);
+        assert!(!can_expr_be_pushed_down_with_schemas(&expr, &schema));
+    }
+
     fn get_basic_table_schema() -> Schema {
         let testdata = datafusion_common::test_util::parquet_test_data();
         let file = std::fs::File::open(format!("{testdata}/alltypes_plain.parquet"))
@@ -19,6 +19,7 @@ use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use super::{ParquetAccessPlan, ParquetFileMetrics};
+use crate::leaf_schema::{leaf_column_index, LeafSchema};
 use arrow::array::{ArrayRef, BooleanArray};
 use arrow::datatypes::Schema;
 use datafusion_common::pruning::PruningStatistics;
@@ -121,10 +122,19 @@ impl RowGroupAccessPlanFilter {
             .map(|&i| &groups[i])
             .collect::<Vec<_>>();
 
+        let leaf_schema = match LeafSchema::try_new(arrow_schema, parquet_schema) {
+            Ok(leaf_schema) => leaf_schema,
+            Err(e) => {
+                log::debug!("Ignoring error building leaf column schema: {e}");
+                None
+            }
+        };
+
         let pruning_stats = RowGroupPruningStatistics {
             parquet_schema,
             row_group_metadatas,
             arrow_schema,
+            leaf_schema,
         };
 
         // try to prune the row groups in a single call
@@ -176,8 +186,14 @@ impl RowGroupAccessPlanFilter {
             let mut column_sbbf = HashMap::with_capacity(literal_columns.len());
 
             for column_name in literal_columns {
-                let Some((column_idx, _field)) =
+                // fields nested in struct columns are named by their leaf
+                // column path
+                let Some(column_idx) =
                     parquet_column(builder.parquet_schema(), arrow_schema, &column_name)
+                        .map(|(column_idx, _field)| column_idx)
+                        .or_else(|| {
+                            leaf_column_index(builder.parquet_schema(), &column_name)
+                        })
                 else {
                     continue;
                 };
@@ -366,6 +382,8 @@ struct RowGroupPruningStatistics<'a> {
     parquet_schema: &'a SchemaDescriptor,
     row_group_metadatas: Vec<&'a RowGroupMetaData>,
     arrow_schema: &'a Schema,
+    /// Resolves the statistics of fields nested in struct columns
+    leaf_schema: Option<LeafSchema>,
 }
 
 impl<'a> RowGroupPruningStatistics<'a> {
@@ -378,11 +396,16 @@ impl<'a> RowGroupPruningStatistics<'a> {
         &'a self,
         column: &'b Column,
     ) -> Result<StatisticsConverter<'a>> {
-        Ok(StatisticsConverter::try_new(
-            &column.name,
-            self.arrow_schema,
-            self.parquet_schema,
-        )?)
+        match &self.leaf_schema {
+            Some(leaf_schema) if self.arrow_schema.index_of(&column.name).is_err() => {
+                leaf_schema.statistics_converter(&column.name)
+            }
+            _ => Ok(StatisticsConverter::try_new(
+                &column.name,
+                self.arrow_schema,
+                self.parquet_schema,
+            )?),
+        }
     }
 }
 
@@ -725,6 +725,17 @@ impl FileSource for ParquetSource {
         .with_updated_node(source))
     }
 
+    fn filter(&self) -> Option<Arc<dyn PhysicalExpr>> {
+        self.predicate.clone()
+    }
+
+    /// Only the leaf columns of the fields of struct columns in the file
+    /// schema are read, unless a custom [`SchemaAdapterFactory`] is used, as
+    /// it may not map whole struct columns to pruned ones
+    fn supports_nested_projection(&self) -> bool {
+        self.schema_adapter_factory.is_none()
+    }
+
     fn with_schema_adapter_factory(
         &self,
         schema_adapter_factory: Arc<dyn SchemaAdapterFactory>,
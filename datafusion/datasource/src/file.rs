@@ -125,6 +125,27 @@ pub trait FileSource: Send + Sync {
         ))
     }
 
+    /// Return the filter expression evaluated by this [`FileSource`] while
+    /// scanning files, if any, in terms of the file schema.
+    ///
+    /// The default implementation returns `None`.
+    fn filter(&self) -> Option<Arc<dyn PhysicalExpr>> {
+        None
+    }
+
+    /// Can this [`FileSource`] read only some of the fields of struct columns?
+    ///
+    /// If true, struct columns of the file schema of which only some nested
+    /// fields are accessed by a projection (through `get_field`) are pruned to
+    /// those fields when the projection is pushed down into the scan, and the
+    /// [`FileSource`] must read files with the whole struct columns into the
+    /// pruned struct columns.
+    ///
+    /// The default implementation returns `false`.
+    fn supports_nested_projection(&self) -> bool {
+        false
+    }
+
     /// Set optional schema adapter factory.
     ///
     /// [`SchemaAdapterFactory`] allows user to specify how fields from the
@@ -42,9 +42,12 @@ use arrow::{
         RecordBatchOptions,
     },
     buffer::Buffer,
-    datatypes::{ArrowNativeType, DataType, Field, Schema, SchemaRef, UInt16Type},
+    datatypes::{
+        ArrowNativeType, DataType, Field, Fields, Schema, SchemaRef, UInt16Type,
+    },
 };
 use datafusion_common::config::ConfigOptions;
+use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
 use datafusion_common::{
     exec_err, ColumnStatistics, Constraints, DataFusionError, Result, ScalarValue,
     Statistics,
@@ -54,6 +57,9 @@ use datafusion_execution::{
 };
 use datafusion_physical_expr::expressions::Column;
 use datafusion_physical_expr::schema_rewriter::PhysicalExprAdapterFactory;
+use datafusion_physical_expr::utils::{
+    nested_field_access, prune_struct_field, reassign_predicate_columns,
+};
 use datafusion_physical_expr::{EquivalenceProperties, Partitioning};
 use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
 use datafusion_physical_expr_common::sort_expr::{LexOrdering, PhysicalSortExpr};
@@ -621,24 +627,28 @@ impl DataSource for FileScanConfig {
         // If there is any non-column or alias-carrier expression, Projection should not be removed.
         let no_aliases = all_alias_free_columns(projection.expr());
 
-        Ok((no_aliases && !partitioned_columns_in_proj).then(|| {
-            let file_scan = self.clone();
-            let source = Arc::clone(&file_scan.file_source);
-            let new_projections = new_projections_for_columns(
-                projection,
-                &file_scan
-                    .projection
-                    .clone()
-                    .unwrap_or_else(|| (0..self.file_schema.fields().len()).collect()),
-            );
-            DataSourceExec::from_data_source(
-                FileScanConfigBuilder::from(file_scan)
-                    // Assign projected statistics to source
-                    .with_projection(Some(new_projections))
-                    .with_source(source)
-                    .build(),
-            ) as _
-        }))
+        if !no_aliases || partitioned_columns_in_proj {
+            // The scan may still only read the fields of struct columns
+            // accessed by the projection
+            return self.try_swapping_with_nested_projection(projection);
+        }
+
+        let file_scan = self.clone();
+        let source = Arc::clone(&file_scan.file_source);
+        let new_projections = new_projections_for_columns(
+            projection,
+            &file_scan
+                .projection
+                .clone()
+                .unwrap_or_else(|| (0..self.file_schema.fields().len()).collect()),
+        );
+        Ok(Some(DataSourceExec::from_data_source(
+            FileScanConfigBuilder::from(file_scan)
+                // Assign projected statistics to source
+                .with_projection(Some(new_projections))
+                .with_source(source)
+                .build(),
+        ) as _))
     }
 
     fn try_pushdown_filters(
@@ -669,6 +679,80 @@ impl DataSource for FileScanConfig {
 }
 
 impl FileScanConfig {
+    /// Prune the struct columns of the file schema to the fields nested in
+    /// them accessed by `projection` through `get_field`, and only project
+    /// the columns accessed by `projection`, which is kept on top of the scan.
+    ///
+    /// Returns `None` if the [`FileSource`] does not support nested
+    /// projections, or if no struct column can be pruned.
+    fn try_swapping_with_nested_projection(
+        &self,
+        projection: &ProjectionExec,
+    ) -> Result<Option<Arc<dyn ExecutionPlan>>> {
+        if !self.file_source.supports_nested_projection() {
+            return Ok(None);
+        }
+
+        let projected_schema = self.projected_schema();
+        let mut accessed = HashMap::new();
+        for (expr, _) in projection.expr() {
+            collect_field_accesses(expr, &projected_schema, &mut accessed)?;
+        }
+
+        // The fields accessed by the filter of the file source must be kept
+        let mut accessed_by_source = accessed.clone();
+        if let Some(filter) = self.file_source.filter() {
+            collect_field_accesses(&filter, &self.file_schema, &mut accessed_by_source)?;
+        }
+
+        let file_fields = self
+            .file_schema
+            .fields()
+            .iter()
+            .map(|field| match accessed_by_source.get(field.name()) {
+                Some(Some(paths)) => {
+                    let paths = paths.iter().map(Vec::as_slice).collect::<Vec<_>>();
+                    Arc::new(prune_struct_field(field, &paths))
+                }
+                _ => Arc::clone(field),
+            })
+            .collect::<Fields>();
+        if &file_fields == self.file_schema.fields() {
+            return Ok(None);
+        }
+
+        let new_projection = self
+            .projection_indices()
+            .into_iter()
+            .zip(projected_schema.fields())
+            .filter(|(_, field)| accessed.contains_key(field.name()))
+            .map(|(idx, _)| idx)
+            .collect();
+
+        let mut file_scan = self.clone();
+        file_scan.file_schema = Arc::new(Schema::new_with_metadata(
+            file_fields,
+            self.file_schema.metadata().clone(),
+        ));
+        let source = DataSourceExec::from_data_source(
+            FileScanConfigBuilder::from(file_scan)
+                .with_projection(Some(new_projection))
+                .build(),
+        );
+
+        let source_schema = source.schema();
+        let exprs = projection
+            .expr()
+            .iter()
+            .map(|(expr, alias)| {
+                let expr =
+                    reassign_predicate_columns(Arc::clone(expr), &source_schema, false)?;
+                Ok((expr, alias.clone()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Some(Arc::new(ProjectionExec::try_new(exprs, source)?)))
+    }
+
     fn projection_indices(&self) -> Vec<usize> {
         match &self.projection {
             Some(proj) => proj.clone(),
@@ -1319,6 +1403,32 @@ fn create_output_array(
 ///
 ///              DataSourceExec
 ///```
+/// Collect the columns of `schema` referenced by `expr` into `accessed` by
+/// name, with the paths of the fields nested in them accessed through
+/// `get_field`, or `None` if the whole column is referenced
+fn collect_field_accesses(
+    expr: &Arc<dyn PhysicalExpr>,
+    schema: &Schema,
+    accessed: &mut HashMap<String, Option<Vec<Vec<String>>>>,
+) -> Result<()> {
+    expr.apply(|expr| {
+        if let Some((column, path, _)) = nested_field_access(expr, schema) {
+            let paths = accessed
+                .entry(column.name().to_string())
+                .or_insert_with(|| Some(vec![]));
+            if let Some(paths) = paths {
+                paths.push(path.into_iter().map(String::from).collect());
+            }
+            return Ok(TreeNodeRecursion::Jump);
+        }
+        if let Some(column) = expr.as_any().downcast_ref::<Column>() {
+            accessed.insert(column.name().to_string(), None);
+        }
+        Ok(TreeNodeRecursion::Continue)
+    })?;
+    Ok(())
+}
+
 fn get_projected_output_ordering(
     base_config: &FileScanConfig,
     projected_schema: &SchemaRef,
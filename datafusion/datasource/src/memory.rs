@@ -71,6 +71,9 @@ pub struct MemorySourceConfig {
     /// The maximum number of records to read from this plan. If `None`,
     /// all records after filtering are returned.
     fetch: Option<usize>,
+    /// Statistics of the data before projection computed by `ANALYZE TABLE`,
+    /// which complete the ones computed from the batches
+    table_statistics: Option<Statistics>,
 }
 
 impl DataSource for MemorySourceConfig {
@@ -169,11 +172,14 @@ impl DataSource for MemorySourceConfig {
         };
 
         if let Some(repartitioned) = maybe_repartitioned {
-            Ok(Some(Arc::new(Self::try_new(
-                &repartitioned,
-                self.original_schema(),
-                self.projection.clone(),
-            )?)))
+            Ok(Some(Arc::new(
+                Self::try_new(
+                    &repartitioned,
+                    self.original_schema(),
+                    self.projection.clone(),
+                )?
+                .with_table_statistics(self.table_statistics.clone()),
+            )))
         } else {
             Ok(None)
         }
@@ -195,11 +201,16 @@ impl DataSource for MemorySourceConfig {
     }
 
     fn statistics(&self) -> Result<Statistics> {
-        Ok(common::compute_record_batch_statistics(
+        let statistics = common::compute_record_batch_statistics(
             &self.partitions,
             &self.schema,
             self.projection.clone(),
-        ))
+        );
+        Ok(match &self.table_statistics {
+            Some(table_statistics) => statistics
+                .fill_absent(&table_statistics.clone().project(self.projection.as_ref())),
+            None => statistics,
+        })
     }
 
     fn with_fetch(&self, limit: Option<usize>) -> Option<Arc<dyn DataSource>> {
@@ -225,12 +236,16 @@ impl DataSource for MemorySourceConfig {
                     self.projection().as_ref().unwrap_or(&all_projections),
                 );
 
-                MemorySourceConfig::try_new_exec(
+                MemorySourceConfig::try_new(
                     self.partitions(),
                     self.original_schema(),
                     Some(new_projections),
                 )
-                .map(|e| e as _)
+                .map(|source| {
+                    let source =
+                        source.with_table_statistics(self.table_statistics.clone());
+                    DataSourceExec::from_data_source(source) as _
+                })
             })
             .transpose()
     }
@@ -253,6 +268,7 @@ impl MemorySourceConfig {
             sort_information: vec![],
             show_sizes: true,
             fetch: None,
+            table_statistics: None,
         })
     }
 
@@ -353,6 +369,7 @@ impl MemorySourceConfig {
             sort_information: vec![],
             show_sizes: true,
             fetch: None,
+            table_statistics: None,
         };
         Ok(DataSourceExec::from_data_source(source))
     }
@@ -369,6 +386,14 @@ impl MemorySourceConfig {
         self
     }
 
+    /// Set the statistics of the data before projection computed by
+    /// `ANALYZE TABLE`, such as distinct counts and histograms, which are
+    /// returned for the statistics that can not be computed from the batches
+    pub fn with_table_statistics(mut self, statistics: Option<Statistics>) -> Self {
+        self.table_statistics = statistics;
+        self
+    }
+
     /// Ref to partitions
     pub fn partitions(&self) -> &[Vec<RecordBatch>] {
         &self.partitions
@@ -944,6 +969,8 @@ mod tests {
                     max_value: Precision::Absent,
                     min_value: Precision::Absent,
                     sum_value: Precision::Absent,
+                    histogram: None,
+                    most_common_values: None,
                 },],
             }
         );
@@ -414,6 +414,8 @@ pub fn generate_test_files(num_files: usize, overlap_factor: f64) -> Vec<FileGro
                     min_value: Precision::Exact(ScalarValue::Float64(Some(min))),
                     sum_value: Precision::Absent,
                     distinct_count: Precision::Absent,
+                    histogram: None,
+                    most_common_values: None,
                 }],
             })),
             extensions: None,
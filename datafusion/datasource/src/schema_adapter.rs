@@ -1018,6 +1018,8 @@ mod tests {
             min_value: min_value.map_or_else(|| Precision::Absent, Precision::Exact),
             max_value: max_value.map_or_else(|| Precision::Absent, Precision::Exact),
             sum_value: sum_value.map_or_else(|| Precision::Absent, Precision::Exact),
+            histogram: None,
+            most_common_values: None,
         }
     }
 }
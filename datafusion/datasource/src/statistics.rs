@@ -370,6 +370,8 @@ pub async fn get_statistics_with_limit(
                         min_value: file_min,
                         sum_value: file_sum,
                         distinct_count: _,
+                        histogram: _,
+                        most_common_values: _,
                     } = file_col_stats;
 
                     col_stats.null_count = col_stats.null_count.add(file_nc);
@@ -365,6 +365,11 @@ pub enum Expr {
     OuterReferenceColumn(DataType, Column),
     /// Unnest expression
     Unnest(Unnest),
+    /// A lambda function, such as `x -> x + 1`. Only valid as an argument of
+    /// a higher-order function like `array_transform`.
+    Lambda(Lambda),
+    /// A reference to a parameter of an enclosing [`Expr::Lambda`]
+    LambdaVariable(LambdaVariable),
 }
 
 impl Default for Expr {
@@ -619,6 +624,57 @@ impl Unnest {
     }
 }
 
+/// Lambda function, such as `x -> x + 1` or `(acc, x) -> acc + x`.
+///
+/// The parameters are referenced in the body with [`Expr::LambdaVariable`],
+/// while [`Expr::Column`]s in the body refer to the input of the plan, and are
+/// evaluated for the row the lambda is applied on.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Hash, Debug)]
+pub struct Lambda {
+    /// The names of the parameters
+    pub params: Vec<String>,
+    /// The body of the lambda
+    pub body: Box<Expr>,
+}
+
+impl Lambda {
+    /// Create a new Lambda expression.
+    pub fn new(params: Vec<String>, body: Expr) -> Self {
+        Self {
+            params,
+            body: Box::new(body),
+        }
+    }
+}
+
+impl Display for Lambda {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.params.as_slice() {
+            [param] => write!(f, "{param} -> {}", self.body),
+            params => write!(f, "({}) -> {}", params.join(", "), self.body),
+        }
+    }
+}
+
+/// Reference to a parameter of an enclosing [`Lambda`]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Hash, Debug)]
+pub struct LambdaVariable {
+    /// The name of the parameter
+    pub name: String,
+    /// The type of the values the parameter is bound to
+    pub data_type: DataType,
+}
+
+impl LambdaVariable {
+    /// Create a new LambdaVariable expression.
+    pub fn new(name: impl Into<String>, data_type: DataType) -> Self {
+        Self {
+            name: name.into(),
+            data_type,
+        }
+    }
+}
+
 /// Alias expression
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Alias {
@@ -1473,6 +1529,8 @@ impl Expr {
             #[expect(deprecated)]
             Expr::Wildcard { .. } => "Wildcard",
             Expr::Unnest { .. } => "Unnest",
+            Expr::Lambda { .. } => "Lambda",
+            Expr::LambdaVariable { .. } => "LambdaVariable",
         }
     }
 
@@ -1993,6 +2051,9 @@ impl Expr {
                 matches!(op, Operator::And | Operator::Or)
             }
             Expr::Case { .. } => true,
+            // The body of a lambda is only evaluated for the elements it is
+            // applied to
+            Expr::Lambda { .. } => true,
             // Use explicit pattern match instead of a default
             // implementation, so that in the future if someone adds
             // new Expr types, they will check here as well
@@ -2027,6 +2088,7 @@ impl Expr {
             | Expr::Wildcard { .. }
             | Expr::WindowFunction(..)
             | Expr::Literal(..)
+            | Expr::LambdaVariable(..)
             | Expr::Placeholder(..) => false,
         }
     }
@@ -2614,6 +2676,15 @@ impl HashNode for Expr {
                 column.hash(state);
             }
             Expr::Unnest(Unnest { expr: _expr }) => {}
+            Expr::Lambda(Lambda {
+                params,
+                body: _body,
+            }) => {
+                params.hash(state);
+            }
+            Expr::LambdaVariable(variable) => {
+                variable.hash(state);
+            }
         };
     }
 }
@@ -2813,6 +2884,13 @@ impl Display for SchemaDisplay<'_> {
             Expr::Unnest(Unnest { expr }) => {
                 write!(f, "UNNEST({})", SchemaDisplay(expr))
             }
+            Expr::Lambda(Lambda { params, body }) => match params.as_slice() {
+                [param] => write!(f, "{param} -> {}", SchemaDisplay(body)),
+                params => {
+                    write!(f, "({}) -> {}", params.join(", "), SchemaDisplay(body))
+                }
+            },
+            Expr::LambdaVariable(LambdaVariable { name, .. }) => write!(f, "{name}"),
             Expr::ScalarFunction(ScalarFunction { func, args }) => {
                 match func.schema_name(args) {
                     Ok(name) => {
@@ -3064,6 +3142,10 @@ impl Display for SqlDisplay<'_> {
             Expr::Unnest(Unnest { expr }) => {
                 write!(f, "UNNEST({})", SqlDisplay(expr))
             }
+            Expr::Lambda(Lambda { params, body }) => match params.as_slice() {
+                [param] => write!(f, "{param} -> {}", SqlDisplay(body)),
+                params => write!(f, "({}) -> {}", params.join(", "), SqlDisplay(body)),
+            },
             Expr::SimilarTo(Like {
                 negated,
                 expr,
@@ -3404,6 +3486,8 @@ impl Display for Expr {
             Expr::Unnest(Unnest { expr }) => {
                 write!(f, "{UNNEST_COLUMN_PREFIX}({expr})")
             }
+            Expr::Lambda(lambda) => write!(f, "{lambda}"),
+            Expr::LambdaVariable(LambdaVariable { name, .. }) => write!(f, "{name}"),
         }
     }
 }
@@ -18,8 +18,8 @@
 use super::{Between, Expr, Like};
 use crate::expr::{
     AggregateFunction, AggregateFunctionParams, Alias, BinaryExpr, Cast, FieldMetadata,
-    InList, InSubquery, Placeholder, ScalarFunction, TryCast, Unnest, WindowFunction,
-    WindowFunctionParams,
+    InList, InSubquery, Lambda, LambdaVariable, Placeholder, ScalarFunction, TryCast,
+    Unnest, WindowFunction, WindowFunctionParams,
 };
 use crate::type_coercion::functions::{
     data_types_with_scalar_udf, fields_with_aggregate_udf, fields_with_window_udf,
@@ -128,6 +128,10 @@ impl ExprSchemable for Expr {
             }
             Expr::Cast(Cast { data_type, .. })
             | Expr::TryCast(TryCast { data_type, .. }) => Ok(data_type.clone()),
+            Expr::Lambda(Lambda { body, .. }) => body.get_type(schema),
+            Expr::LambdaVariable(LambdaVariable { data_type, .. }) => {
+                Ok(data_type.clone())
+            }
             Expr::Unnest(Unnest { expr }) => {
                 let arg_data_type = expr.get_type(schema)?;
                 // Unnest's output type is the inner type of the list
@@ -296,6 +300,7 @@ impl ExprSchemable for Expr {
                 }
             }
             Expr::Cast(Cast { expr, .. }) => expr.nullable(input_schema),
+            Expr::Lambda(Lambda { body, .. }) => body.nullable(input_schema),
             Expr::ScalarFunction(_func) => {
                 let (_, nullable) = self.data_type_and_nullable(input_schema)?;
                 Ok(nullable)
@@ -312,6 +317,7 @@ impl ExprSchemable for Expr {
             Expr::ScalarVariable(_, _)
             | Expr::TryCast { .. }
             | Expr::Unnest(_)
+            | Expr::LambdaVariable(_)
             | Expr::Placeholder(_) => Ok(true),
             Expr::IsNull(_)
             | Expr::IsNotNull(_)
@@ -559,6 +565,8 @@ impl ExprSchemable for Expr {
             | Expr::Wildcard { .. }
             | Expr::GroupingSet(_)
             | Expr::Placeholder(_)
+            | Expr::Lambda(_)
+            | Expr::LambdaVariable(_)
             | Expr::Unnest(_) => Ok(Arc::new(Field::new(
                 &schema_name,
                 self.get_type(schema)?,
@@ -107,10 +107,13 @@ pub use udaf::{
     SetMonotonicity, StatisticsArgs,
 };
 pub use udf::{
-    scalar_doc_sections, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDF, ScalarUDFImpl,
+    scalar_doc_sections, ReturnFieldArgs, ScalarFunctionArgs, ScalarLambda, ScalarUDF,
+    ScalarUDFImpl,
 };
 pub use udwf::{window_doc_sections, ReversedUDWF, WindowUDF, WindowUDFImpl};
-pub use window_frame::{WindowFrame, WindowFrameBound, WindowFrameUnits};
+pub use window_frame::{
+    WindowFrame, WindowFrameBound, WindowFrameExclusion, WindowFrameUnits,
+};
 
 #[cfg(test)]
 #[ctor::ctor]
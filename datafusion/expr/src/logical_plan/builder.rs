@@ -24,7 +24,7 @@ use std::iter::once;
 use std::sync::Arc;
 
 use crate::dml::CopyTo;
-use crate::expr::{Alias, PlannedReplaceSelectItem, Sort as SortExpr};
+use crate::expr::{AggregateFunction, Alias, PlannedReplaceSelectItem, Sort as SortExpr};
 use crate::expr_rewriter::{
     coerce_plan_expr_for_schema, normalize_col,
     normalize_col_with_schemas_and_ambiguity_check, normalize_cols, normalize_sorts,
@@ -43,8 +43,9 @@ use crate::utils::{
     group_window_expr_by_sort_keys,
 };
 use crate::{
-    and, binary_expr, lit, DmlStatement, ExplainOption, Expr, ExprSchemable, Operator,
-    RecursiveQuery, Statement, TableProviderFilterPushDown, TableSource, WriteOp,
+    and, binary_expr, lit, when, DmlStatement, ExplainOption, Expr, ExprSchemable,
+    Operator, RecursiveQuery, Statement, TableProviderFilterPushDown, TableSource,
+    WriteOp,
 };
 
 use super::dml::InsertOp;
@@ -1250,6 +1251,175 @@ impl LogicalPlanBuilder {
             .map(Self::new)
     }
 
+    /// Pivot rows into columns.
+    ///
+    /// For each value in `pivot_values` and each aggregate in `aggr_expr`, an
+    /// output column is produced that aggregates only the rows where
+    /// `pivot_column` equals that value. The remaining input columns, i.e. the
+    /// ones not referenced by `aggr_expr` or `pivot_column`, are grouped on.
+    ///
+    /// Output columns are named after the pivot value, which may be aliased
+    /// (e.g. `lit("JAN").alias("jan")`). When there is more than one aggregate
+    /// or the aggregate is aliased, columns are named `<value>_<aggregate>`.
+    ///
+    /// If `default_on_null` is set, it replaces NULL aggregate results, for
+    /// example for pivot values without any matching rows.
+    pub fn pivot(
+        self,
+        aggr_expr: impl IntoIterator<Item = impl Into<Expr>>,
+        pivot_column: impl Into<Column>,
+        pivot_values: impl IntoIterator<Item = impl Into<Expr>>,
+        default_on_null: Option<Expr>,
+    ) -> Result<Self> {
+        let aggr_expr = normalize_cols(aggr_expr, &self.plan)?;
+        let pivot_column = normalize_col(Expr::Column(pivot_column.into()), &self.plan)?;
+        let pivot_values = pivot_values.into_iter().map(Into::into).collect::<Vec<_>>();
+        if aggr_expr.is_empty() {
+            return plan_err!("PIVOT requires at least one aggregate expression");
+        }
+        if pivot_values.is_empty() {
+            return plan_err!("PIVOT requires at least one pivot value");
+        }
+
+        let mut pivoted_columns = HashSet::new();
+        expr_to_columns(&pivot_column, &mut pivoted_columns)?;
+        for expr in &aggr_expr {
+            expr_to_columns(expr, &mut pivoted_columns)?;
+        }
+        let group_expr = self
+            .plan
+            .schema()
+            .columns()
+            .into_iter()
+            .filter(|c| !pivoted_columns.contains(c))
+            .map(Expr::Column)
+            .collect::<Vec<_>>();
+
+        let name_by_aggregate =
+            aggr_expr.len() > 1 || aggr_expr.iter().any(|e| matches!(e, Expr::Alias(_)));
+        let mut pivot_aggr_expr =
+            Vec::with_capacity(pivot_values.len() * aggr_expr.len());
+        let mut pivot_names = Vec::with_capacity(pivot_aggr_expr.capacity());
+        for value in pivot_values {
+            let (value, value_name) = match value {
+                Expr::Alias(Alias { expr, name, .. }) => (*expr, name),
+                Expr::Literal(ref v, _) => {
+                    let name = v.to_string();
+                    (value, name)
+                }
+                _ => {
+                    let name = value.schema_name().to_string();
+                    (value, name)
+                }
+            };
+            for expr in &aggr_expr {
+                let (aggr, aggr_name) = match expr {
+                    Expr::Alias(Alias { expr, name, .. }) => {
+                        (expr.as_ref(), name.clone())
+                    }
+                    _ => (expr, expr.schema_name().to_string()),
+                };
+                let Expr::AggregateFunction(AggregateFunction { func, params }) = aggr
+                else {
+                    return plan_err!("PIVOT expects aggregate functions, got {aggr}");
+                };
+                let predicate =
+                    binary_expr(pivot_column.clone(), Operator::Eq, value.clone());
+                let mut params = params.clone();
+                params.filter = Some(Box::new(match params.filter.take() {
+                    Some(filter) => and(*filter, predicate),
+                    None => predicate,
+                }));
+                let name = if name_by_aggregate {
+                    format!("{value_name}_{aggr_name}")
+                } else {
+                    value_name.clone()
+                };
+                pivot_aggr_expr.push(
+                    Expr::AggregateFunction(AggregateFunction {
+                        func: Arc::clone(func),
+                        params,
+                    })
+                    .alias(&name),
+                );
+                pivot_names.push(name);
+            }
+        }
+
+        let plan = self.aggregate(group_expr.clone(), pivot_aggr_expr)?;
+        let Some(default_on_null) = default_on_null else {
+            return Ok(plan);
+        };
+        let pivot_exprs = pivot_names
+            .into_iter()
+            .map(|name| {
+                let column = Expr::Column(Column::from_name(&name));
+                Ok(when(column.clone().is_null(), default_on_null.clone())
+                    .otherwise(column)?
+                    .alias(name))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        plan.project(group_expr.into_iter().chain(pivot_exprs))
+    }
+
+    /// Unpivot columns into rows.
+    ///
+    /// Every input row produces one output row per column in `columns`, with
+    /// the column name in `name_column` and its value in `value_column`. The
+    /// remaining input columns are passed through unchanged. Rows whose value
+    /// is NULL are dropped unless `include_nulls` is set.
+    pub fn unpivot(
+        self,
+        value_column: impl Into<String>,
+        name_column: impl Into<String>,
+        columns: impl IntoIterator<Item = impl Into<Column>>,
+        include_nulls: bool,
+    ) -> Result<Self> {
+        let value_column = value_column.into();
+        let name_column = name_column.into();
+        let schema = self.plan.schema();
+        let unpivot_columns = columns
+            .into_iter()
+            .map(|c| {
+                schema
+                    .qualified_field_from_column(&c.into())
+                    .map(Column::from)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if unpivot_columns.is_empty() {
+            return plan_err!("UNPIVOT requires at least one column");
+        }
+
+        let passthrough_exprs = schema
+            .columns()
+            .into_iter()
+            .filter(|c| !unpivot_columns.contains(c))
+            .map(Expr::Column)
+            .collect::<Vec<_>>();
+        let inputs = unpivot_columns
+            .into_iter()
+            .map(|c| {
+                let value = Expr::Column(c.clone());
+                let mut builder = Self::new_from_arc(Arc::clone(&self.plan));
+                if !include_nulls {
+                    builder = builder.filter(value.clone().is_not_null())?;
+                }
+                let exprs = passthrough_exprs
+                    .iter()
+                    .cloned()
+                    .chain([lit(c.name).alias(&name_column), value.alias(&value_column)]);
+                builder.project(exprs)?.build().map(Arc::new)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if inputs.len() == 1 {
+            return Ok(Self::new_from_arc(inputs.into_iter().next().unwrap()));
+        }
+        Union::try_new_with_loose_types(inputs)
+            .map(LogicalPlan::Union)
+            .map(Self::new)
+    }
+
     /// Create an expression to represent the explanation of the plan
     ///
     /// if `analyze` is true, runs the actual plan and produces
@@ -1603,7 +1773,7 @@ pub fn build_join_schema(
                 .collect::<Vec<_>>();
             left_fields.into_iter().chain(right_fields).collect()
         }
-        JoinType::Left => {
+        JoinType::Left | JoinType::AsOf => {
             // left then right, right set to nullable in case of not matched scenario
             let left_fields = left_fields
                 .map(|(q, f)| (q.cloned(), Arc::clone(f)))
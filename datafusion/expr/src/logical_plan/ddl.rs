@@ -41,6 +41,12 @@ pub enum DdlStatement {
     CreateMemoryTable(CreateMemoryTable),
     /// Creates a new view.
     CreateView(CreateView),
+    /// Creates a new materialized view.
+    CreateMaterializedView(CreateMaterializedView),
+    /// Recomputes the content of a materialized view.
+    RefreshMaterializedView(RefreshMaterializedView),
+    /// Computes the statistics of a table.
+    AnalyzeTable(AnalyzeTable),
     /// Creates a new catalog schema.
     CreateCatalogSchema(CreateCatalogSchema),
     /// Creates a new catalog (aka "Database").
@@ -67,7 +73,16 @@ impl DdlStatement {
                 schema
             }
             DdlStatement::CreateMemoryTable(CreateMemoryTable { input, .. })
-            | DdlStatement::CreateView(CreateView { input, .. }) => input.schema(),
+            | DdlStatement::CreateView(CreateView { input, .. })
+            | DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                input,
+                ..
+            }) => input.schema(),
+            DdlStatement::RefreshMaterializedView(RefreshMaterializedView {
+                schema,
+                ..
+            }) => schema,
+            DdlStatement::AnalyzeTable(AnalyzeTable { schema, .. }) => schema,
             DdlStatement::CreateCatalogSchema(CreateCatalogSchema { schema, .. }) => {
                 schema
             }
@@ -88,6 +103,9 @@ impl DdlStatement {
             DdlStatement::CreateExternalTable(_) => "CreateExternalTable",
             DdlStatement::CreateMemoryTable(_) => "CreateMemoryTable",
             DdlStatement::CreateView(_) => "CreateView",
+            DdlStatement::CreateMaterializedView(_) => "CreateMaterializedView",
+            DdlStatement::RefreshMaterializedView(_) => "RefreshMaterializedView",
+            DdlStatement::AnalyzeTable(_) => "AnalyzeTable",
             DdlStatement::CreateCatalogSchema(_) => "CreateCatalogSchema",
             DdlStatement::CreateCatalog(_) => "CreateCatalog",
             DdlStatement::CreateIndex(_) => "CreateIndex",
@@ -109,6 +127,12 @@ impl DdlStatement {
                 vec![input]
             }
             DdlStatement::CreateView(CreateView { input, .. }) => vec![input],
+            DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                input,
+                ..
+            }) => vec![input],
+            DdlStatement::RefreshMaterializedView(_) => vec![],
+            DdlStatement::AnalyzeTable(_) => vec![],
             DdlStatement::CreateIndex(_) => vec![],
             DdlStatement::DropTable(_) => vec![],
             DdlStatement::DropView(_) => vec![],
@@ -153,6 +177,32 @@ impl DdlStatement {
                     DdlStatement::CreateView(CreateView { name, .. }) => {
                         write!(f, "CreateView: {name:?}")
                     }
+                    DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                        name,
+                        location,
+                        ..
+                    }) => match location {
+                        Some(location) => write!(
+                            f,
+                            "CreateMaterializedView: {name:?} location={location}"
+                        ),
+                        None => write!(f, "CreateMaterializedView: {name:?}"),
+                    },
+                    DdlStatement::RefreshMaterializedView(RefreshMaterializedView {
+                        name,
+                        ..
+                    }) => {
+                        write!(f, "RefreshMaterializedView: {name:?}")
+                    }
+                    DdlStatement::AnalyzeTable(AnalyzeTable {
+                        name, columns, ..
+                    }) => {
+                        write!(
+                            f,
+                            "AnalyzeTable: {name:?} columns=[{}]",
+                            columns.join(", ")
+                        )
+                    }
                     DdlStatement::CreateCatalogSchema(CreateCatalogSchema {
                         schema_name,
                         ..
@@ -330,6 +380,68 @@ pub struct CreateView {
     pub temporary: bool,
 }
 
+/// Creates a materialized view, whose content is computed when it is created
+/// or refreshed rather than each time it is queried.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Hash)]
+pub struct CreateMaterializedView {
+    /// The view name
+    pub name: TableReference,
+    /// The logical plan computing the content of the view
+    pub input: Arc<LogicalPlan>,
+    /// The location of the files storing the content, stored in memory if `None`
+    pub location: Option<String>,
+    /// The file type of the files at `location`
+    pub file_type: Option<String>,
+    /// Options of the file type, sorted by key
+    pub options: Vec<(String, String)>,
+    /// Option to replace the view if it already exists
+    pub or_replace: bool,
+    /// Option to not error if the view already exists
+    pub if_not_exists: bool,
+    /// SQL used to create the view, if available
+    pub definition: Option<String>,
+}
+
+/// Recomputes the content of a materialized view.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RefreshMaterializedView {
+    /// The view name
+    pub name: TableReference,
+    /// Dummy schema
+    pub schema: DFSchemaRef,
+}
+
+// Manual implementation needed because of `schema` field. Comparison excludes this field.
+impl PartialOrd for RefreshMaterializedView {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.name.partial_cmp(&other.name)
+    }
+}
+
+/// Computes the statistics of a table (`ANALYZE TABLE`) and stores them with
+/// [`TableProvider::set_statistics`].
+///
+/// [`TableProvider::set_statistics`]: https://docs.rs/datafusion/latest/datafusion/datasource/trait.TableProvider.html#method.set_statistics
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AnalyzeTable {
+    /// The table name
+    pub name: TableReference,
+    /// The columns whose distinct counts and histograms are computed
+    pub columns: Vec<String>,
+    /// Dummy schema
+    pub schema: DFSchemaRef,
+}
+
+// Manual implementation needed because of `schema` field. Comparison excludes this field.
+impl PartialOrd for AnalyzeTable {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.name.partial_cmp(&other.name) {
+            Some(Ordering::Equal) => self.columns.partial_cmp(&other.columns),
+            cmp => cmp,
+        }
+    }
+}
+
 /// Creates a catalog (aka "Database").
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct CreateCatalog {
@@ -22,9 +22,9 @@ use std::fmt;
 
 use crate::{
     expr_vec_fmt, Aggregate, DescribeTable, Distinct, DistinctOn, DmlStatement, Expr,
-    Filter, Join, Limit, LogicalPlan, Partitioning, Projection, RecursiveQuery,
-    Repartition, Sort, Subquery, SubqueryAlias, TableProviderFilterPushDown, TableScan,
-    Unnest, Values, Window,
+    Filter, Join, Limit, LogicalPlan, MatchRecognize, Partitioning, Projection,
+    RecursiveQuery, Repartition, Sort, Subquery, SubqueryAlias,
+    TableProviderFilterPushDown, TableScan, Unnest, Values, Window,
 };
 
 use crate::dml::CopyTo;
@@ -651,6 +651,27 @@ impl<'a, 'b> PgJsonVisitor<'a, 'b> {
                     "StructColumn": expr_vec_fmt!(struct_type_columns),
                 })
             }
+            LogicalPlan::MatchRecognize(MatchRecognize {
+                partition_by,
+                order_by,
+                measures,
+                rows_per_match,
+                after_match_skip,
+                pattern,
+                definitions,
+                ..
+            }) => {
+                json!({
+                    "Node Type": "MatchRecognize",
+                    "Partition By": expr_vec_fmt!(partition_by),
+                    "Order By": expr_vec_fmt!(order_by),
+                    "Measures": expr_vec_fmt!(measures),
+                    "Rows Per Match": rows_per_match.to_string(),
+                    "After Match Skip": after_match_skip.to_string(),
+                    "Pattern": pattern.to_string(),
+                    "Define": expr_vec_fmt!(definitions),
+                })
+            }
         }
     }
 }
@@ -25,7 +25,7 @@ use arrow::datatypes::{DataType, Field, Schema};
 use datafusion_common::file_options::file_type::FileType;
 use datafusion_common::{DFSchemaRef, TableReference};
 
-use crate::{LogicalPlan, TableSource};
+use crate::{Expr, LogicalPlan, TableSource};
 
 /// Operator that copies the contents of a database to file(s)
 #[derive(Clone)]
@@ -126,6 +126,10 @@ impl CopyTo {
 /// * `UPDATE` - Modifies existing rows in the table. Currently NOT supported by
 ///   the [`TableProvider`] trait or builtin sources.
 ///
+/// * `MERGE` - Updates, deletes or inserts rows depending on whether they
+///   match a source relation. Calls [`TableProvider::merge_into`], see
+///   [`MergeOp`] for the shape of the input.
+///
 /// * `CREATE TABLE AS SELECT` - Creates a new table and populates it with data
 ///   from a query. This is similar to the `INSERT` operation, but it creates a new
 ///   table instead of modifying an existing one.
@@ -134,6 +138,7 @@ impl CopyTo {
 ///
 /// [`TableProvider`]: https://docs.rs/datafusion/latest/datafusion/datasource/trait.TableProvider.html
 /// [`TableProvider::insert_into`]: https://docs.rs/datafusion/latest/datafusion/datasource/trait.TableProvider.html#method.insert_into
+/// [`TableProvider::merge_into`]: https://docs.rs/datafusion/latest/datafusion/datasource/trait.TableProvider.html#method.merge_into
 #[derive(Clone)]
 pub struct DmlStatement {
     /// The table name
@@ -233,6 +238,8 @@ pub enum WriteOp {
     Update,
     /// `CREATE TABLE AS SELECT` operation
     Ctas,
+    /// `MERGE INTO` operation
+    Merge(MergeOp),
 }
 
 impl WriteOp {
@@ -243,6 +250,7 @@ impl WriteOp {
             WriteOp::Delete => "Delete",
             WriteOp::Update => "Update",
             WriteOp::Ctas => "Ctas",
+            WriteOp::Merge(_) => "Merge",
         }
     }
 }
@@ -284,6 +292,115 @@ impl Display for InsertOp {
     }
 }
 
+/// Name of the column, added after the columns of the target table, that
+/// holds the index of the [`MergeClause`] applied to each input row of a
+/// `MERGE` [`DmlStatement`].
+pub const MERGE_CLAUSE_COLUMN: &str = "__merge_clause";
+
+/// Name of the column, added between the columns of the target table and
+/// [`MERGE_CLAUSE_COLUMN`], that identifies the target row of each input row
+/// of a `MERGE` [`DmlStatement`] matched by a source row.
+pub const MERGE_TARGET_ROW_COLUMN: &str = "__merge_target_row";
+
+/// A `MERGE INTO` operation.
+///
+/// The input of the [`DmlStatement`] is computed from a full outer join
+/// of the target table and the source relation on [`Self::on`]. It
+/// contains the columns of the target table, a nullable `UInt64` column
+/// named [`MERGE_TARGET_ROW_COLUMN`] and a nullable `UInt32` column named
+/// [`MERGE_CLAUSE_COLUMN`]:
+///
+/// * If the clause index is `NULL`, the row is an existing row of the
+///   target table that is left unchanged.
+/// * Otherwise the row was handled by `clauses[index]`: for an update it
+///   holds the new values of the target row, for an insert the values of the
+///   new row, and for a delete the values of the deleted target row.
+///
+/// Source rows that no clause applies to are not part of the input, so the
+/// input describes the whole content of the table after the merge once the
+/// deleted rows are removed.
+///
+/// The target row number is only set for the rows joining a target row with
+/// a source row. A target row matched by several source rows appears once per
+/// match: implementations must fail if a clause applies to any of them, and
+/// otherwise keep a single copy of the unchanged row.
+///
+/// The expressions of the operation are only informative: they refer to the
+/// columns of the joined target table and source relation, and have already
+/// been applied by the input plan.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct MergeOp {
+    /// The condition joining the target table and the source relation
+    pub on: Expr,
+    /// The `WHEN [NOT] MATCHED` clauses, in the order they are checked
+    pub clauses: Vec<MergeClause>,
+}
+
+impl MergeOp {
+    /// Create a new `MERGE INTO` operation
+    pub fn new(on: Expr, clauses: Vec<MergeClause>) -> Self {
+        Self { on, clauses }
+    }
+}
+
+/// A `WHEN [NOT] MATCHED [AND <predicate>] THEN <action>` clause of a
+/// [`MergeOp`]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct MergeClause {
+    /// Which rows of the join the clause applies to
+    pub kind: MergeClauseKind,
+    /// The optional additional condition of the clause
+    pub predicate: Option<Expr>,
+    /// What to do with the rows the clause applies to
+    pub action: MergeAction,
+}
+
+/// The rows of a `MERGE` a [`MergeClause`] applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
+pub enum MergeClauseKind {
+    /// `WHEN MATCHED`: a target row joined with a source row
+    Matched,
+    /// `WHEN NOT MATCHED [BY TARGET]`: a source row without any target row
+    NotMatchedByTarget,
+    /// `WHEN NOT MATCHED BY SOURCE`: a target row without any source row
+    NotMatchedBySource,
+}
+
+impl Display for MergeClauseKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MergeClauseKind::Matched => write!(f, "WHEN MATCHED"),
+            MergeClauseKind::NotMatchedByTarget => write!(f, "WHEN NOT MATCHED"),
+            MergeClauseKind::NotMatchedBySource => {
+                write!(f, "WHEN NOT MATCHED BY SOURCE")
+            }
+        }
+    }
+}
+
+/// The action of a [`MergeClause`]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub enum MergeAction {
+    /// `UPDATE SET column = value, ...`
+    Update(Vec<(String, Expr)>),
+    /// `INSERT [(column, ...)] VALUES (value, ...)`, with one value per
+    /// column of the target table
+    Insert(Vec<Expr>),
+    /// `DELETE`
+    Delete,
+}
+
+impl MergeAction {
+    /// Return a descriptive name of this [`MergeAction`]
+    pub fn name(&self) -> &str {
+        match self {
+            MergeAction::Update(_) => "UPDATE",
+            MergeAction::Insert(_) => "INSERT",
+            MergeAction::Delete => "DELETE",
+        }
+    }
+}
+
 fn make_count_schema() -> DFSchemaRef {
     Arc::new(
         Schema::new(vec![Field::new("count", DataType::UInt64, false)])
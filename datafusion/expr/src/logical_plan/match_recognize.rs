@@ -0,0 +1,708 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Logical plan node for `MATCH_RECOGNIZE` row pattern recognition
+
+use std::any::Any;
+use std::cmp::Ordering;
+use std::fmt::{self, Display, Formatter};
+use std::sync::Arc;
+
+use arrow::array::new_null_array;
+use arrow::compute::concat;
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::tree_node::{Transformed, TreeNodeContainer, TreeNodeRecursion};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{
+    exec_err, internal_err, plan_err, DFSchema, DFSchemaRef, Result, ScalarValue,
+};
+
+use crate::expr::{AggregateFunction, Alias, ScalarFunction, Sort as SortExpr};
+use crate::utils::exprlist_to_fields;
+use crate::{
+    expr_vec_fmt, lit, ColumnarValue, Expr, ExprSchemable, LogicalPlan,
+    ScalarFunctionArgs, ScalarUDF, ScalarUDFImpl, Signature, Volatility,
+};
+
+/// Finds the sequences of rows of its input that match a row pattern, as
+/// used to implement SQL `MATCH_RECOGNIZE`.
+///
+/// The input is divided into partitions by `partition_by` and each partition
+/// is ordered by `order_by`. Each row is then mapped to the pattern variables
+/// (symbols) whose `DEFINE` predicate it satisfies, and the `pattern` is
+/// matched against the resulting sequence of rows.
+///
+/// # Output Schema
+///
+/// With [`RowsPerMatch::OneRow`] the output has one row per match, containing
+/// the `partition_by` expressions followed by the `measures`. With
+/// [`RowsPerMatch::AllRows`] the output has one row per matched input row,
+/// containing the input columns followed by the `measures`.
+///
+/// The measures are the primitive values the SQL `MEASURES` clause is
+/// computed from, so the SQL planner usually adds a projection on top of this
+/// node.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MatchRecognize {
+    /// The incoming logical plan
+    pub input: Arc<LogicalPlan>,
+    /// The expressions dividing the input into independently matched partitions
+    pub partition_by: Vec<Expr>,
+    /// The order of the rows within a partition
+    pub order_by: Vec<SortExpr>,
+    /// The values computed for each match
+    pub measures: Vec<MatchMeasure>,
+    /// Whether one row is produced per match or one row per matched row
+    pub rows_per_match: RowsPerMatch,
+    /// Where the next match attempt starts after a match
+    pub after_match_skip: AfterMatchSkip,
+    /// The pattern to match
+    pub pattern: RowPattern,
+    /// The predicates a row must satisfy to be mapped to a symbol. Symbols
+    /// without a definition match any row.
+    pub definitions: Vec<PatternDefinition>,
+    /// The output schema
+    pub schema: DFSchemaRef,
+}
+
+impl MatchRecognize {
+    /// Create a new `MatchRecognize` node, computing its output schema
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        input: Arc<LogicalPlan>,
+        partition_by: Vec<Expr>,
+        order_by: Vec<SortExpr>,
+        measures: Vec<MatchMeasure>,
+        rows_per_match: RowsPerMatch,
+        after_match_skip: AfterMatchSkip,
+        pattern: RowPattern,
+        definitions: Vec<PatternDefinition>,
+    ) -> Result<Self> {
+        let symbols = pattern.symbols();
+        if symbols.is_empty() {
+            return plan_err!("MATCH_RECOGNIZE pattern must contain a pattern variable");
+        }
+        for definition in &definitions {
+            if !symbols.contains(&definition.symbol.as_str()) {
+                return plan_err!(
+                    "Pattern variable {} is defined but not used in the pattern",
+                    definition.symbol
+                );
+            }
+        }
+        if let AfterMatchSkip::ToFirst(symbol) | AfterMatchSkip::ToLast(symbol) =
+            &after_match_skip
+        {
+            if !symbols.contains(&symbol.as_str()) {
+                return plan_err!(
+                    "AFTER MATCH SKIP refers to unknown pattern variable {symbol}"
+                );
+            }
+        }
+        for measure in &measures {
+            if let MatchMeasure::Aggregate { expr, symbol } = measure {
+                if measure.aggregate_function().is_none() {
+                    return internal_err!(
+                        "MATCH_RECOGNIZE measure must be an aggregate function, got {expr}"
+                    );
+                }
+                if let Some(symbol) = symbol {
+                    if !symbols.contains(&symbol.as_str()) {
+                        return plan_err!(
+                            "MATCH_RECOGNIZE measure refers to unknown pattern variable {symbol}"
+                        );
+                    }
+                }
+            }
+        }
+
+        let input_schema = input.schema();
+        let mut fields = match rows_per_match {
+            RowsPerMatch::OneRow => exprlist_to_fields(&partition_by, &input)?,
+            RowsPerMatch::AllRows => input_schema
+                .iter()
+                .map(|(q, f)| (q.cloned(), Arc::clone(f)))
+                .collect(),
+        };
+        for measure in &measures {
+            fields.push((None, Arc::new(measure.to_field(input_schema)?)));
+        }
+        let schema =
+            DFSchema::new_with_metadata(fields, input_schema.metadata().clone())?;
+
+        Ok(Self {
+            input,
+            partition_by,
+            order_by,
+            measures,
+            rows_per_match,
+            after_match_skip,
+            pattern,
+            definitions,
+            schema: Arc::new(schema),
+        })
+    }
+
+    /// Returns a copy of this node with new expressions, in the order of
+    /// [`LogicalPlan::expressions`], and a new input
+    pub fn with_new_exprs(&self, exprs: Vec<Expr>, input: LogicalPlan) -> Result<Self> {
+        let num_aggregates = self
+            .measures
+            .iter()
+            .filter(|m| matches!(m, MatchMeasure::Aggregate { .. }))
+            .count();
+        let expected = self.partition_by.len()
+            + self.order_by.len()
+            + self.definitions.len()
+            + num_aggregates;
+        if exprs.len() != expected {
+            return internal_err!(
+                "MatchRecognize expects {expected} expressions, got {}",
+                exprs.len()
+            );
+        }
+
+        let mut exprs = exprs.into_iter();
+        let partition_by = exprs.by_ref().take(self.partition_by.len()).collect();
+        let order_by = self
+            .order_by
+            .iter()
+            .zip(exprs.by_ref())
+            .map(|(sort, expr)| sort.with_expr(expr))
+            .collect();
+        let definitions = self
+            .definitions
+            .iter()
+            .zip(exprs.by_ref())
+            .map(|(definition, predicate)| PatternDefinition {
+                symbol: definition.symbol.clone(),
+                predicate,
+            })
+            .collect();
+        let measures = self
+            .measures
+            .iter()
+            .map(|measure| match measure {
+                MatchMeasure::Aggregate { symbol, .. } => MatchMeasure::Aggregate {
+                    // the number of expressions was checked above
+                    expr: exprs.next().unwrap(),
+                    symbol: symbol.clone(),
+                },
+                other => other.clone(),
+            })
+            .collect();
+
+        Self::try_new(
+            Arc::new(input),
+            partition_by,
+            order_by,
+            measures,
+            self.rows_per_match,
+            self.after_match_skip.clone(),
+            self.pattern.clone(),
+            definitions,
+        )
+    }
+
+    /// The pattern variables, in order of their first appearance in the pattern
+    pub fn symbols(&self) -> Vec<&str> {
+        self.pattern.symbols()
+    }
+}
+
+// Manual implementation needed because of `schema` field. Comparison excludes this field.
+impl PartialOrd for MatchRecognize {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        #[derive(PartialEq, PartialOrd)]
+        struct ComparableMatchRecognize<'a> {
+            input: &'a Arc<LogicalPlan>,
+            partition_by: &'a Vec<Expr>,
+            order_by: &'a Vec<SortExpr>,
+            measures: &'a Vec<MatchMeasure>,
+            rows_per_match: &'a RowsPerMatch,
+            after_match_skip: &'a AfterMatchSkip,
+            pattern: &'a RowPattern,
+            definitions: &'a Vec<PatternDefinition>,
+        }
+        fn comparable(m: &MatchRecognize) -> ComparableMatchRecognize<'_> {
+            ComparableMatchRecognize {
+                input: &m.input,
+                partition_by: &m.partition_by,
+                order_by: &m.order_by,
+                measures: &m.measures,
+                rows_per_match: &m.rows_per_match,
+                after_match_skip: &m.after_match_skip,
+                pattern: &m.pattern,
+                definitions: &m.definitions,
+            }
+        }
+        comparable(self).partial_cmp(&comparable(other))
+    }
+}
+
+impl Display for MatchRecognize {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "MatchRecognize: ")?;
+        if !self.partition_by.is_empty() {
+            write!(f, "partition_by=[{}], ", expr_vec_fmt!(self.partition_by))?;
+        }
+        if !self.order_by.is_empty() {
+            write!(f, "order_by=[{}], ", expr_vec_fmt!(self.order_by))?;
+        }
+        write!(
+            f,
+            "measures=[{}], rows_per_match={}, after_match_skip={}, pattern=[{}]",
+            expr_vec_fmt!(self.measures),
+            self.rows_per_match,
+            self.after_match_skip,
+            self.pattern
+        )?;
+        if !self.definitions.is_empty() {
+            write!(f, ", define=[{}]", expr_vec_fmt!(self.definitions))?;
+        }
+        Ok(())
+    }
+}
+
+/// A value computed for each match of a [`MatchRecognize`]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub enum MatchMeasure {
+    /// An aggregate function evaluated on the rows of the match mapped to
+    /// `symbol`, or on all rows of the match if `symbol` is `None`.
+    ///
+    /// With [`RowsPerMatch::AllRows`] the aggregate has running semantics: it
+    /// is evaluated on the rows of the match up to and including the current
+    /// row.
+    Aggregate { expr: Expr, symbol: Option<String> },
+    /// The sequential number of the match within its partition, starting at 1
+    MatchNumber,
+    /// The symbol the last (or, with [`RowsPerMatch::AllRows`], the current)
+    /// row of the match is mapped to
+    Classifier,
+}
+
+impl MatchMeasure {
+    /// The name of the output column of this measure
+    pub fn name(&self) -> String {
+        match self {
+            MatchMeasure::Aggregate { expr, symbol: None } => {
+                expr.schema_name().to_string()
+            }
+            MatchMeasure::Aggregate {
+                expr,
+                symbol: Some(symbol),
+            } => format!("{} FILTER (CLASSIFIER() = '{symbol}')", expr.schema_name()),
+            MatchMeasure::MatchNumber => "MATCH_NUMBER()".to_string(),
+            MatchMeasure::Classifier => "CLASSIFIER()".to_string(),
+        }
+    }
+
+    /// The output field of this measure
+    pub fn to_field(&self, input_schema: &DFSchema) -> Result<Field> {
+        let (data_type, nullable) = match self {
+            MatchMeasure::Aggregate { expr, .. } => (expr.get_type(input_schema)?, true),
+            MatchMeasure::MatchNumber => (DataType::UInt64, false),
+            // empty matches have no last row
+            MatchMeasure::Classifier => (DataType::Utf8, true),
+        };
+        Ok(Field::new(self.name(), data_type, nullable))
+    }
+
+    /// Returns the aggregate function of this measure, if any, without any
+    /// alias added by optimizer rules
+    pub fn aggregate_function(&self) -> Option<&AggregateFunction> {
+        let MatchMeasure::Aggregate { expr, .. } = self else {
+            return None;
+        };
+        match expr {
+            Expr::AggregateFunction(function) => Some(function),
+            Expr::Alias(Alias { expr, .. }) => match expr.as_ref() {
+                Expr::AggregateFunction(function) => Some(function),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl Display for MatchMeasure {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl<'a> TreeNodeContainer<'a, Expr> for MatchMeasure {
+    fn apply_elements<F: FnMut(&'a Expr) -> Result<TreeNodeRecursion>>(
+        &'a self,
+        mut f: F,
+    ) -> Result<TreeNodeRecursion> {
+        match self {
+            MatchMeasure::Aggregate { expr, .. } => f(expr),
+            MatchMeasure::MatchNumber | MatchMeasure::Classifier => {
+                Ok(TreeNodeRecursion::Continue)
+            }
+        }
+    }
+
+    fn map_elements<F: FnMut(Expr) -> Result<Transformed<Expr>>>(
+        self,
+        mut f: F,
+    ) -> Result<Transformed<Self>> {
+        match self {
+            MatchMeasure::Aggregate { expr, symbol } => {
+                Ok(f(expr)?.update_data(|expr| MatchMeasure::Aggregate { expr, symbol }))
+            }
+            MatchMeasure::MatchNumber | MatchMeasure::Classifier => {
+                Ok(Transformed::no(self))
+            }
+        }
+    }
+}
+
+/// The `DEFINE` predicate of a pattern variable
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub struct PatternDefinition {
+    /// The pattern variable
+    pub symbol: String,
+    /// The predicate a row must satisfy to be mapped to `symbol`, evaluated on
+    /// the input schema. It may use [`MatchNavigation`] to access other rows
+    /// and [`MatchAggregate`] to access the rows of the match in progress.
+    pub predicate: Expr,
+}
+
+impl Display for PatternDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} AS {}", self.symbol, self.predicate)
+    }
+}
+
+impl<'a> TreeNodeContainer<'a, Expr> for PatternDefinition {
+    fn apply_elements<F: FnMut(&'a Expr) -> Result<TreeNodeRecursion>>(
+        &'a self,
+        mut f: F,
+    ) -> Result<TreeNodeRecursion> {
+        f(&self.predicate)
+    }
+
+    fn map_elements<F: FnMut(Expr) -> Result<Transformed<Expr>>>(
+        self,
+        mut f: F,
+    ) -> Result<Transformed<Self>> {
+        f(self.predicate)?.map_data(|predicate| {
+            Ok(Self {
+                symbol: self.symbol,
+                predicate,
+            })
+        })
+    }
+}
+
+/// Whether a [`MatchRecognize`] produces one row per match or one row per
+/// matched row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
+pub enum RowsPerMatch {
+    /// `ONE ROW PER MATCH`
+    OneRow,
+    /// `ALL ROWS PER MATCH`. Empty matches produce no rows.
+    AllRows,
+}
+
+impl Display for RowsPerMatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RowsPerMatch::OneRow => write!(f, "ONE ROW PER MATCH"),
+            RowsPerMatch::AllRows => write!(f, "ALL ROWS PER MATCH"),
+        }
+    }
+}
+
+/// Where the next match attempt of a [`MatchRecognize`] starts after a match
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub enum AfterMatchSkip {
+    /// `AFTER MATCH SKIP PAST LAST ROW`: at the row following the match
+    PastLastRow,
+    /// `AFTER MATCH SKIP TO NEXT ROW`: at the row following the first row of
+    /// the match
+    ToNextRow,
+    /// `AFTER MATCH SKIP TO FIRST <symbol>`: at the first row of the match
+    /// mapped to the symbol
+    ToFirst(String),
+    /// `AFTER MATCH SKIP TO LAST <symbol>`: at the last row of the match
+    /// mapped to the symbol
+    ToLast(String),
+}
+
+impl Display for AfterMatchSkip {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AfterMatchSkip::PastLastRow => write!(f, "PAST LAST ROW"),
+            AfterMatchSkip::ToNextRow => write!(f, "TO NEXT ROW"),
+            AfterMatchSkip::ToFirst(symbol) => write!(f, "TO FIRST {symbol}"),
+            AfterMatchSkip::ToLast(symbol) => write!(f, "TO LAST {symbol}"),
+        }
+    }
+}
+
+/// A regular expression over pattern variables, as used in the `PATTERN`
+/// clause of `MATCH_RECOGNIZE`
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+pub enum RowPattern {
+    /// A row mapped to the pattern variable
+    Symbol(String),
+    /// `^`: the start of the partition
+    Start,
+    /// `$`: the end of the partition
+    End,
+    /// A sequence of patterns
+    Concat(Vec<RowPattern>),
+    /// `a | b`: the first alternative that leads to a match
+    Alternation(Vec<RowPattern>),
+    /// A (greedy) repetition of a pattern, between `min` and `max` times
+    Repetition {
+        pattern: Box<RowPattern>,
+        min: u32,
+        max: Option<u32>,
+    },
+}
+
+impl RowPattern {
+    /// The pattern variables, in order of their first appearance
+    pub fn symbols(&self) -> Vec<&str> {
+        let mut symbols = vec![];
+        self.collect_symbols(&mut symbols);
+        symbols
+    }
+
+    fn collect_symbols<'a>(&'a self, symbols: &mut Vec<&'a str>) {
+        match self {
+            RowPattern::Symbol(symbol) => {
+                if !symbols.contains(&symbol.as_str()) {
+                    symbols.push(symbol);
+                }
+            }
+            RowPattern::Start | RowPattern::End => {}
+            RowPattern::Concat(patterns) | RowPattern::Alternation(patterns) => {
+                patterns.iter().for_each(|p| p.collect_symbols(symbols))
+            }
+            RowPattern::Repetition { pattern, .. } => pattern.collect_symbols(symbols),
+        }
+    }
+}
+
+impl Display for RowPattern {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RowPattern::Symbol(symbol) => write!(f, "{symbol}"),
+            RowPattern::Start => write!(f, "^"),
+            RowPattern::End => write!(f, "$"),
+            RowPattern::Concat(patterns) => {
+                let patterns = patterns
+                    .iter()
+                    .map(|p| match p {
+                        RowPattern::Alternation(_) => format!("({p})"),
+                        _ => p.to_string(),
+                    })
+                    .collect::<Vec<_>>();
+                write!(f, "{}", patterns.join(" "))
+            }
+            RowPattern::Alternation(patterns) => {
+                let patterns = patterns.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+                write!(f, "{}", patterns.join(" | "))
+            }
+            RowPattern::Repetition { pattern, min, max } => {
+                match pattern.as_ref() {
+                    RowPattern::Symbol(_) | RowPattern::Start | RowPattern::End => {
+                        write!(f, "{pattern}")?
+                    }
+                    _ => write!(f, "({pattern})")?,
+                }
+                match (min, max) {
+                    (0, None) => write!(f, "*"),
+                    (1, None) => write!(f, "+"),
+                    (0, Some(1)) => write!(f, "?"),
+                    (min, None) => write!(f, "{{{min},}}"),
+                    (min, Some(max)) if min == max => write!(f, "{{{min}}}"),
+                    (min, Some(max)) => write!(f, "{{{min},{max}}}"),
+                }
+            }
+        }
+    }
+}
+
+/// The `PREV` and `NEXT` navigation functions of `DEFINE` predicates.
+///
+/// `PREV(expr, n)` evaluates `expr` on the row `n` rows before the current
+/// row of the partition and `NEXT(expr, n)` on the row `n` rows after it,
+/// returning `NULL` if there is no such row. The offset `n` must be a
+/// non-negative `Int64` literal.
+///
+/// The functions are only meaningful when evaluated on consecutive rows of a
+/// partition including the rows they access, which is how [`MatchRecognize`]
+/// evaluates its definitions. They are volatile so that they are never
+/// evaluated during constant folding.
+#[derive(Debug)]
+pub struct MatchNavigation {
+    name: &'static str,
+    forward: bool,
+    signature: Signature,
+}
+
+impl MatchNavigation {
+    /// The `PREV` function
+    pub fn prev() -> Self {
+        Self::new("prev", false)
+    }
+
+    /// The `NEXT` function
+    pub fn next() -> Self {
+        Self::new("next", true)
+    }
+
+    /// Whether the function accesses following rows (`NEXT`) rather than
+    /// preceding ones (`PREV`)
+    pub fn is_forward(&self) -> bool {
+        self.forward
+    }
+
+    fn new(name: &'static str, forward: bool) -> Self {
+        Self {
+            name,
+            forward,
+            signature: Signature::any(2, Volatility::Volatile),
+        }
+    }
+}
+
+impl ScalarUDFImpl for MatchNavigation {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [value, offset] = take_function_args(self.name, args.args)?;
+        let ColumnarValue::Scalar(ScalarValue::Int64(Some(offset))) = offset else {
+            return exec_err!("{} expects a constant Int64 offset", self.name);
+        };
+        if offset < 0 {
+            return exec_err!("{} offset must not be negative", self.name);
+        }
+        let value = value.into_array(args.number_rows)?;
+        let offset = (offset as usize).min(value.len());
+        let remaining = value.len() - offset;
+        let nulls = new_null_array(value.data_type(), offset);
+        let shifted = if self.forward {
+            concat(&[&value.slice(offset, remaining), &nulls])?
+        } else {
+            concat(&[&nulls, &value.slice(0, remaining)])?
+        };
+        Ok(ColumnarValue::Array(shifted))
+    }
+}
+
+/// The `match_aggregate` function of `DEFINE` predicates, which evaluates an
+/// aggregate function on the rows of the match in progress.
+///
+/// `match_aggregate(agg, symbol)` evaluates the aggregate function call `agg`
+/// on the rows of the match mapped to the pattern variable `symbol`, a `Utf8`
+/// literal, or on all the rows of the match if `symbol` is `NULL`. The row the
+/// predicate is evaluated on is mapped to the pattern variable being defined.
+/// For example, `A.price` in the definition of another pattern variable is
+/// `match_aggregate(last_value(price), 'A')`.
+///
+/// The function can only be evaluated by [`MatchRecognize`], which replaces it
+/// with the value of the aggregate. It is volatile so that it is never
+/// evaluated during constant folding.
+#[derive(Debug)]
+pub struct MatchAggregate {
+    signature: Signature,
+}
+
+impl Default for MatchAggregate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MatchAggregate {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(2, Volatility::Volatile),
+        }
+    }
+
+    /// Returns `match_aggregate(aggregate, symbol)`
+    pub fn call(aggregate: Expr, symbol: Option<String>) -> Expr {
+        Expr::ScalarFunction(ScalarFunction::new_udf(
+            Arc::new(ScalarUDF::from(Self::new())),
+            vec![aggregate, lit(ScalarValue::Utf8(symbol))],
+        ))
+    }
+
+    /// Returns the aggregate and the pattern variable of `expr` if it is a
+    /// `match_aggregate` call
+    pub fn arguments(expr: &Expr) -> Result<Option<(&Expr, Option<&str>)>> {
+        let Expr::ScalarFunction(ScalarFunction { func, args }) = expr else {
+            return Ok(None);
+        };
+        if !func.inner().as_any().is::<Self>() {
+            return Ok(None);
+        }
+        match args.as_slice() {
+            [aggregate, Expr::Literal(ScalarValue::Utf8(symbol), _)] => {
+                Ok(Some((aggregate, symbol.as_deref())))
+            }
+            _ => internal_err!(
+                "match_aggregate expects an aggregate and a Utf8 literal, got {}",
+                expr_vec_fmt!(args)
+            ),
+        }
+    }
+}
+
+impl ScalarUDFImpl for MatchAggregate {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "match_aggregate"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    fn invoke_with_args(&self, _args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        exec_err!("match_aggregate can only be evaluated by MatchRecognize")
+    }
+}
@@ -22,6 +22,7 @@ pub mod dml;
 mod extension;
 pub(crate) mod invariants;
 pub use invariants::{assert_expected_schema, check_subquery_expr, InvariantLevel};
+mod match_recognize;
 mod plan;
 mod statement;
 pub mod tree_node;
@@ -32,11 +33,17 @@ pub use builder::{
     LogicalTableSource, UNNAMED_TABLE,
 };
 pub use ddl::{
-    CreateCatalog, CreateCatalogSchema, CreateExternalTable, CreateFunction,
-    CreateFunctionBody, CreateIndex, CreateMemoryTable, CreateView, DdlStatement,
-    DropCatalogSchema, DropFunction, DropTable, DropView, OperateFunctionArg,
+    AnalyzeTable, CreateCatalog, CreateCatalogSchema, CreateExternalTable,
+    CreateFunction, CreateFunctionBody, CreateIndex, CreateMaterializedView,
+    CreateMemoryTable, CreateView, DdlStatement, DropCatalogSchema, DropFunction,
+    DropTable, DropView, OperateFunctionArg, RefreshMaterializedView,
 };
 pub use dml::{DmlStatement, WriteOp};
+pub use match_recognize::{
+    AfterMatchSkip, MatchAggregate, MatchMeasure, MatchNavigation, MatchRecognize,
+    PatternDefinition,
+    RowPattern, RowsPerMatch,
+};
 pub use plan::{
     projection_schema, Aggregate, Analyze, ColumnUnnestList, DescribeTable, Distinct,
     DistinctOn, EmptyRelation, Explain, ExplainFormat, ExplainOption, Extension,
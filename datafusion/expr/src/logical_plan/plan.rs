@@ -29,6 +29,7 @@ use super::invariants::{
     assert_always_invariants_at_current_node, assert_executable_invariants,
     InvariantLevel,
 };
+use super::match_recognize::MatchRecognize;
 use super::DdlStatement;
 use crate::builder::{change_redundant_column, unnest_with_options};
 use crate::expr::{Placeholder, Sort as SortExpr, WindowFunction, WindowFunctionParams};
@@ -44,8 +45,8 @@ use crate::utils::{
 };
 use crate::{
     build_join_schema, expr_vec_fmt, requalify_sides_if_needed, BinaryExpr,
-    CreateMemoryTable, CreateView, Execute, Expr, ExprSchemable, LogicalPlanBuilder,
-    Operator, Prepare, TableProviderFilterPushDown, TableSource,
+    CreateMaterializedView, CreateMemoryTable, CreateView, Execute, Expr, ExprSchemable,
+    LogicalPlanBuilder, Operator, Prepare, TableProviderFilterPushDown, TableSource,
     WindowFunctionDefinition,
 };
 
@@ -288,6 +289,9 @@ pub enum LogicalPlan {
     Unnest(Unnest),
     /// A variadic query (e.g. "Recursive CTEs")
     RecursiveQuery(RecursiveQuery),
+    /// Finds sequences of rows matching a row pattern. This is used to
+    /// implement SQL `MATCH_RECOGNIZE`.
+    MatchRecognize(MatchRecognize),
 }
 
 impl Default for LogicalPlan {
@@ -352,6 +356,7 @@ impl LogicalPlan {
                 // we take the schema of the static term as the schema of the entire recursive query
                 static_term.schema()
             }
+            LogicalPlan::MatchRecognize(MatchRecognize { schema, .. }) => schema,
         }
     }
 
@@ -474,6 +479,7 @@ impl LogicalPlan {
                 recursive_term,
                 ..
             }) => vec![static_term, recursive_term],
+            LogicalPlan::MatchRecognize(MatchRecognize { input, .. }) => vec![input],
             LogicalPlan::Statement(stmt) => stmt.inputs(),
             // plans without inputs
             LogicalPlan::TableScan { .. }
@@ -547,7 +553,11 @@ impl LogicalPlan {
                 join_type,
                 ..
             }) => match join_type {
-                JoinType::Inner | JoinType::Left | JoinType::Right | JoinType::Full => {
+                JoinType::Inner
+                | JoinType::Left
+                | JoinType::Right
+                | JoinType::Full
+                | JoinType::AsOf => {
                     if left.schema().fields().is_empty() {
                         right.head_output_expr()
                     } else {
@@ -592,7 +602,8 @@ impl LogicalPlan {
             | LogicalPlan::Copy(_)
             | LogicalPlan::Ddl(_)
             | LogicalPlan::DescribeTable(_)
-            | LogicalPlan::Unnest(_) => Ok(None),
+            | LogicalPlan::Unnest(_)
+            | LogicalPlan::MatchRecognize(_) => Ok(None),
         }
     }
 
@@ -749,6 +760,27 @@ impl LogicalPlan {
                 // Update schema with unnested column type.
                 unnest_with_options(Arc::unwrap_or_clone(input), exec_columns, options)
             }
+            LogicalPlan::MatchRecognize(MatchRecognize {
+                input,
+                partition_by,
+                order_by,
+                measures,
+                rows_per_match,
+                after_match_skip,
+                pattern,
+                definitions,
+                schema: _,
+            }) => MatchRecognize::try_new(
+                input,
+                partition_by,
+                order_by,
+                measures,
+                rows_per_match,
+                after_match_skip,
+                pattern,
+                definitions,
+            )
+            .map(LogicalPlan::MatchRecognize),
         }
     }
 
@@ -1018,6 +1050,16 @@ impl LogicalPlan {
                     definition: definition.clone(),
                 })))
             }
+            LogicalPlan::Ddl(DdlStatement::CreateMaterializedView(create)) => {
+                self.assert_no_expressions(expr)?;
+                let input = self.only_input(inputs)?;
+                Ok(LogicalPlan::Ddl(DdlStatement::CreateMaterializedView(
+                    CreateMaterializedView {
+                        input: Arc::new(input),
+                        ..create.clone()
+                    },
+                )))
+            }
             LogicalPlan::Extension(e) => Ok(LogicalPlan::Extension(Extension {
                 node: e.node.with_exprs_and_inputs(expr, inputs)?,
             })),
@@ -1142,6 +1184,12 @@ impl LogicalPlan {
                     unnest_with_options(input, columns.clone(), options.clone())?;
                 Ok(new_plan)
             }
+            LogicalPlan::MatchRecognize(match_recognize) => {
+                let input = self.only_input(inputs)?;
+                match_recognize
+                    .with_new_exprs(expr, input)
+                    .map(LogicalPlan::MatchRecognize)
+            }
         }
     }
 
@@ -1341,9 +1389,10 @@ impl LogicalPlan {
                         (left_max, right_max, _) => Some(left_max * right_max),
                     }
                 }
-                JoinType::LeftSemi | JoinType::LeftAnti | JoinType::LeftMark => {
-                    left.max_rows()
-                }
+                JoinType::LeftSemi
+                | JoinType::LeftAnti
+                | JoinType::LeftMark
+                | JoinType::AsOf => left.max_rows(),
                 JoinType::RightSemi | JoinType::RightAnti | JoinType::RightMark => {
                     right.max_rows()
                 }
@@ -1368,7 +1417,7 @@ impl LogicalPlan {
                 Distinct::All(input) | Distinct::On(DistinctOn { input, .. }),
             ) => input.max_rows(),
             LogicalPlan::Values(v) => Some(v.values.len()),
-            LogicalPlan::Unnest(_) => None,
+            LogicalPlan::Unnest(_) | LogicalPlan::MatchRecognize(_) => None,
             LogicalPlan::Ddl(_)
             | LogicalPlan::Explain(_)
             | LogicalPlan::Analyze(_)
@@ -1726,6 +1775,9 @@ impl LogicalPlan {
                     }) => {
                         write!(f, "RecursiveQuery: is_distinct={is_distinct}")
                     }
+                    LogicalPlan::MatchRecognize(match_recognize) => {
+                        write!(f, "{match_recognize}")
+                    }
                     LogicalPlan::Values(Values { ref values, .. }) => {
                         let str_values: Vec<_> = values
                             .iter()
@@ -2004,7 +2056,8 @@ impl LogicalPlan {
                     LogicalPlan::Unnest(Unnest {
                         input: plan,
                         list_type_columns: list_col_indices,
-                        struct_type_columns: struct_col_indices, .. }) => {
+                        struct_type_columns: struct_col_indices,
+                        options, .. }) => {
                         let input_columns = plan.schema().columns();
                         let list_type_columns = list_col_indices
                             .iter()
@@ -2019,7 +2072,12 @@ impl LogicalPlan {
                         // get items from input_columns indexed by list_col_indices
                         write!(f, "Unnest: lists[{}] structs[{}]",
                         expr_vec_fmt!(list_type_columns),
-                        expr_vec_fmt!(struct_type_columns))
+                        expr_vec_fmt!(struct_type_columns))?;
+                        if let Some(ordinality) = &options.ordinality {
+                            write!(f, " ordinality[{}|start={}]",
+                            ordinality.name, ordinality.start)?;
+                        }
+                        Ok(())
                     }
                 }
             }
@@ -3821,6 +3879,7 @@ impl Join {
             || original_join.join_type == JoinType::Left
             || original_join.join_type == JoinType::Right
             || original_join.join_type == JoinType::Full
+            || original_join.join_type == JoinType::AsOf
         {
             (left_sch, right_sch, requalified) =
                 requalify_sides_if_needed(left_sch.clone(), right_sch.clone())?;
@@ -3853,6 +3912,49 @@ impl Join {
             requalified,
         ))
     }
+
+    /// Returns the match condition of a [`JoinType::AsOf`] join as
+    /// `(left_expr, op, right_expr)`, where `left_expr` only references the
+    /// left input, `right_expr` only references the right input and `op` is
+    /// one of `>=`, `>`, `<=` or `<`.
+    ///
+    /// The condition is normalized so that it can be read as
+    /// `left_expr op right_expr`, swapping the operator if it was written
+    /// with the right input first.
+    pub fn asof_match_condition(&self) -> Result<(&Expr, Operator, &Expr)> {
+        let Some(filter) = &self.filter else {
+            return plan_err!("ASOF join requires a match condition");
+        };
+        let Expr::BinaryExpr(BinaryExpr { left, op, right }) = filter else {
+            return plan_err!(
+                "ASOF join match condition must be a single comparison, got {filter}"
+            );
+        };
+        if !matches!(
+            op,
+            Operator::GtEq | Operator::Gt | Operator::LtEq | Operator::Lt
+        ) {
+            return plan_err!(
+                "ASOF join match condition must use one of >=, >, <= or <, got {op}"
+            );
+        }
+
+        let references_only = |expr: &Expr, plan: &LogicalPlan| {
+            let columns = expr.column_refs();
+            !columns.is_empty() && columns.iter().all(|c| plan.schema().has_column(c))
+        };
+        if references_only(left, &self.left) && references_only(right, &self.right) {
+            Ok((left.as_ref(), *op, right.as_ref()))
+        } else if references_only(left, &self.right) && references_only(right, &self.left)
+        {
+            // `op.swap()` is always defined for comparison operators
+            Ok((right.as_ref(), op.swap().unwrap(), left.as_ref()))
+        } else {
+            plan_err!(
+                "ASOF join match condition must compare an expression of the left input with an expression of the right input, got {filter}"
+            )
+        }
+    }
 }
 
 // Manual implementation needed because of `schema` field. Comparison excludes this field.
@@ -4097,7 +4199,7 @@ impl Unnest {
         // 4.unnest_col2_depth_1: int
         // Meaning the placeholder column will be replaced by its unnested variation(s), note
         // the plural.
-        let fields = input_schema
+        let mut fields = input_schema
             .iter()
             .enumerate()
             .map(|(index, (original_qualifier, original_field))| {
@@ -4181,6 +4283,15 @@ impl Unnest {
             .flatten()
             .collect::<Vec<_>>();
 
+        if let Some(ordinality) = &options.ordinality {
+            // The position column is computed from the unnested columns
+            dependency_indices.push(input_schema.index_of_column(&exec_columns[0])?);
+            fields.push((
+                None,
+                Arc::new(Field::new(&ordinality.name, DataType::Int64, false)),
+            ));
+        }
+
         let metadata = input_schema.metadata().clone();
         let df_schema = DFSchema::new_with_metadata(fields, metadata)?;
         // We can use the existing functional dependencies:
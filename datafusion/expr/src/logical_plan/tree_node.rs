@@ -38,11 +38,11 @@
 //! * [`LogicalPlan::expressions`]: Return a copy of the plan's expressions
 
 use crate::{
-    dml::CopyTo, Aggregate, Analyze, CreateMemoryTable, CreateView, DdlStatement,
-    Distinct, DistinctOn, DmlStatement, Execute, Explain, Expr, Extension, Filter, Join,
-    Limit, LogicalPlan, Partitioning, Prepare, Projection, RecursiveQuery, Repartition,
-    Sort, Statement, Subquery, SubqueryAlias, TableScan, Union, Unnest,
-    UserDefinedLogicalNode, Values, Window,
+    dml::CopyTo, Aggregate, Analyze, CreateMaterializedView, CreateMemoryTable,
+    CreateView, DdlStatement, Distinct, DistinctOn, DmlStatement, Execute, Explain, Expr,
+    Extension, Filter, Join, Limit, LogicalPlan, MatchRecognize, Partitioning, Prepare,
+    Projection, RecursiveQuery, Repartition, Sort, Statement, Subquery, SubqueryAlias,
+    TableScan, Union, Unnest, UserDefinedLogicalNode, Values, Window,
 };
 use datafusion_common::tree_node::TreeNodeRefContainer;
 
@@ -290,6 +290,27 @@ impl TreeNode for LogicalPlan {
                             temporary,
                         })
                     }),
+                    DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                        name,
+                        input,
+                        location,
+                        file_type,
+                        options,
+                        or_replace,
+                        if_not_exists,
+                        definition,
+                    }) => input.map_elements(f)?.update_data(|input| {
+                        DdlStatement::CreateMaterializedView(CreateMaterializedView {
+                            name,
+                            input,
+                            location,
+                            file_type,
+                            options,
+                            or_replace,
+                            if_not_exists,
+                            definition,
+                        })
+                    }),
                     // no inputs in these statements
                     DdlStatement::CreateExternalTable(_)
                     | DdlStatement::CreateCatalogSchema(_)
@@ -297,6 +318,8 @@ impl TreeNode for LogicalPlan {
                     | DdlStatement::CreateIndex(_)
                     | DdlStatement::DropTable(_)
                     | DdlStatement::DropView(_)
+                    | DdlStatement::RefreshMaterializedView(_)
+                    | DdlStatement::AnalyzeTable(_)
                     | DdlStatement::DropCatalogSchema(_)
                     | DdlStatement::CreateFunction(_)
                     | DdlStatement::DropFunction(_) => Transformed::no(ddl),
@@ -337,6 +360,29 @@ impl TreeNode for LogicalPlan {
                     })
                 },
             ),
+            LogicalPlan::MatchRecognize(MatchRecognize {
+                input,
+                partition_by,
+                order_by,
+                measures,
+                rows_per_match,
+                after_match_skip,
+                pattern,
+                definitions,
+                schema,
+            }) => input.map_elements(f)?.update_data(|input| {
+                LogicalPlan::MatchRecognize(MatchRecognize {
+                    input,
+                    partition_by,
+                    order_by,
+                    measures,
+                    rows_per_match,
+                    after_match_skip,
+                    pattern,
+                    definitions,
+                    schema,
+                })
+            }),
             LogicalPlan::Statement(stmt) => match stmt {
                 Statement::Prepare(p) => p
                     .input
@@ -452,6 +498,15 @@ impl LogicalPlan {
                 sort_expr,
                 ..
             })) => (on_expr, select_expr, sort_expr).apply_ref_elements(f),
+            LogicalPlan::MatchRecognize(MatchRecognize {
+                partition_by,
+                order_by,
+                measures,
+                definitions,
+                ..
+            }) => (partition_by, order_by, definitions)
+                .apply_ref_elements(&mut f)?
+                .visit_sibling(|| measures.apply_elements(&mut f)),
             LogicalPlan::Limit(Limit { skip, fetch, .. }) => {
                 (skip, fetch).apply_ref_elements(f)
             }
@@ -631,6 +686,31 @@ impl LogicalPlan {
                     LogicalPlan::Limit(Limit { skip, fetch, input })
                 })
             }
+            LogicalPlan::MatchRecognize(MatchRecognize {
+                input,
+                partition_by,
+                order_by,
+                measures,
+                rows_per_match,
+                after_match_skip,
+                pattern,
+                definitions,
+                schema,
+            }) => (partition_by, order_by, (definitions, measures))
+                .map_elements(f)?
+                .update_data(|(partition_by, order_by, (definitions, measures))| {
+                    LogicalPlan::MatchRecognize(MatchRecognize {
+                        input,
+                        partition_by,
+                        order_by,
+                        measures,
+                        rows_per_match,
+                        after_match_skip,
+                        pattern,
+                        definitions,
+                        schema,
+                    })
+                }),
             LogicalPlan::Statement(stmt) => match stmt {
                 Statement::Execute(e) => {
                     e.parameters.map_elements(f)?.update_data(|parameters| {
@@ -22,6 +22,7 @@ use crate::expr::schema_name_from_exprs_comma_separated_without_space;
 use crate::simplify::{ExprSimplifyResult, SimplifyInfo};
 use crate::sort_properties::{ExprProperties, SortProperties};
 use crate::{udf_equals_hash, ColumnarValue, Documentation, Expr, Signature};
+use arrow::array::{ArrayRef, UInt32Array};
 use arrow::datatypes::{DataType, Field, FieldRef};
 use datafusion_common::{not_impl_err, ExprSchema, Result, ScalarValue};
 use datafusion_expr_common::interval_arithmetic::Interval;
@@ -209,6 +210,26 @@ impl ScalarUDF {
         self.inner.invoke_with_args(args)
     }
 
+    /// Invoke the function on `args` and the lambda functions passed to it.
+    ///
+    /// See [`ScalarUDFImpl::invoke_with_lambdas`] for details.
+    pub fn invoke_with_lambdas(
+        &self,
+        args: ScalarFunctionArgs,
+        lambdas: Vec<Option<Arc<dyn ScalarLambda>>>,
+    ) -> Result<ColumnarValue> {
+        self.inner.invoke_with_lambdas(args, lambdas)
+    }
+
+    /// See [`ScalarUDFImpl::lambda_parameters`] for more details.
+    pub fn lambda_parameters(
+        &self,
+        index: usize,
+        arg_types: &[DataType],
+    ) -> Result<Vec<DataType>> {
+        self.inner.lambda_parameters(index, arg_types)
+    }
+
     /// Get the circuits of inner implementation
     pub fn short_circuits(&self) -> bool {
         self.inner.short_circuits()
@@ -321,6 +342,27 @@ impl ScalarFunctionArgs {
     }
 }
 
+/// A lambda function passed as an argument to a scalar function, bound to the
+/// record batch the function is evaluated on.
+///
+/// See [`ScalarUDFImpl::invoke_with_lambdas`] for more information
+pub trait ScalarLambda: Debug + Send + Sync {
+    /// The parameters declared by the lambda, which may be fewer than the
+    /// parameters offered by the function (see
+    /// [`ScalarUDFImpl::lambda_parameters`])
+    fn params(&self) -> &[FieldRef];
+
+    /// Evaluates the body of the lambda once per element of `args`.
+    ///
+    /// `args` holds the values of the parameters offered by the function, all
+    /// of the same length. Values of parameters not declared by the lambda
+    /// are ignored. `rows` has the same length as the arguments and holds,
+    /// for each element, the index of the row of the record batch it
+    /// originates from, which is used to evaluate the columns referenced in
+    /// the body.
+    fn evaluate(&self, args: &[ArrayRef], rows: &UInt32Array) -> Result<ArrayRef>;
+}
+
 /// Information about arguments passed to the function
 ///
 /// This structure contains metadata about how the function was called
@@ -556,6 +598,45 @@ pub trait ScalarUDFImpl: Debug + Send + Sync {
     /// to arrays, which will likely be simpler code, but be slower.
     fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue>;
 
+    /// Invoke a higher-order function, which takes lambda functions such as
+    /// `x -> x + 1` as arguments.
+    ///
+    /// `lambdas` has one entry per argument, which is set for the arguments
+    /// that are lambda functions. The corresponding entries of `args` are
+    /// null placeholders, whose fields are the result fields of the bodies.
+    ///
+    /// The default implementation ignores the lambdas and calls
+    /// [`Self::invoke_with_args`].
+    fn invoke_with_lambdas(
+        &self,
+        args: ScalarFunctionArgs,
+        _lambdas: Vec<Option<Arc<dyn ScalarLambda>>>,
+    ) -> Result<ColumnarValue> {
+        self.invoke_with_args(args)
+    }
+
+    /// Returns the types of the parameters of the lambda function passed as
+    /// the argument at position `index`, given the types of the arguments
+    /// preceding it.
+    ///
+    /// For example, `array_transform(array, x -> ...)` offers the element
+    /// type of `array` as the type of `x`. A lambda may declare fewer
+    /// parameters than offered.
+    ///
+    /// The default implementation returns an error, as functions don't accept
+    /// lambda functions by default.
+    fn lambda_parameters(
+        &self,
+        index: usize,
+        _arg_types: &[DataType],
+    ) -> Result<Vec<DataType>> {
+        not_impl_err!(
+            "Function {} does not accept a lambda function as argument {}",
+            self.name(),
+            index + 1
+        )
+    }
+
     /// Optionally apply per-UDF simplification / rewrite rules.
     ///
     /// This can be used to apply function specific simplification rules during
@@ -805,6 +886,22 @@ impl ScalarUDFImpl for AliasedScalarUDFImpl {
         self.inner.invoke_with_args(args)
     }
 
+    fn invoke_with_lambdas(
+        &self,
+        args: ScalarFunctionArgs,
+        lambdas: Vec<Option<Arc<dyn ScalarLambda>>>,
+    ) -> Result<ColumnarValue> {
+        self.inner.invoke_with_lambdas(args, lambdas)
+    }
+
+    fn lambda_parameters(
+        &self,
+        index: usize,
+        arg_types: &[DataType],
+    ) -> Result<Vec<DataType>> {
+        self.inner.lambda_parameters(index, arg_types)
+    }
+
     fn aliases(&self) -> &[String] {
         &self.aliases
     }
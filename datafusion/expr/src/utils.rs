@@ -305,6 +305,8 @@ pub fn expr_to_columns(expr: &Expr, accum: &mut HashSet<Column>) -> Result<()> {
             | Expr::ScalarSubquery(_)
             | Expr::Wildcard { .. }
             | Expr::Placeholder(_)
+            | Expr::Lambda(_)
+            | Expr::LambdaVariable(_)
             | Expr::OuterReferenceColumn { .. } => {}
         }
         Ok(TreeNodeRecursion::Continue)
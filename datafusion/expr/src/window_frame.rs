@@ -44,6 +44,13 @@ pub struct WindowFrame {
     pub start_bound: WindowFrameBound,
     /// Ending frame boundary
     pub end_bound: WindowFrameBound,
+    /// Rows of the frame that are excluded by the `EXCLUDE` clause.
+    ///
+    /// `sqlparser` has no representation of this clause, so SQL that spells it
+    /// out is rejected by the parser and the unparser cannot emit it. Until
+    /// that changes, it can only be set through [`WindowFrame::with_exclusion`]
+    /// (e.g. from the DataFrame API or a deserialized plan).
+    pub exclusion: WindowFrameExclusion,
     /// Flag indicating whether the frame is causal (i.e. computing the result
     /// for the current row doesn't depend on any subsequent rows).
     ///
@@ -100,6 +107,9 @@ impl fmt::Display for WindowFrame {
             "{} BETWEEN {} AND {}",
             self.units, self.start_bound, self.end_bound
         )?;
+        if self.has_exclusion() {
+            write!(f, " {}", self.exclusion)?;
+        }
         Ok(())
     }
 }
@@ -108,9 +118,13 @@ impl fmt::Debug for WindowFrame {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "WindowFrame {{ units: {:?}, start_bound: {:?}, end_bound: {:?}, is_causal: {:?} }}",
-            self.units, self.start_bound, self.end_bound, self.causal
+            "WindowFrame {{ units: {:?}, start_bound: {:?}, end_bound: {:?}, ",
+            self.units, self.start_bound, self.end_bound
         )?;
+        if self.has_exclusion() {
+            write!(f, "exclusion: {:?}, ", self.exclusion)?;
+        }
+        write!(f, "is_causal: {:?} }}", self.causal)?;
         Ok(())
     }
 }
@@ -162,6 +176,7 @@ impl WindowFrame {
                 },
                 start_bound: WindowFrameBound::Preceding(ScalarValue::UInt64(None)),
                 end_bound: WindowFrameBound::CurrentRow,
+                exclusion: WindowFrameExclusion::NoOthers,
                 causal: strict,
             }
         } else {
@@ -172,6 +187,7 @@ impl WindowFrame {
                 units: WindowFrameUnits::Rows,
                 start_bound: WindowFrameBound::Preceding(ScalarValue::UInt64(None)),
                 end_bound: WindowFrameBound::Following(ScalarValue::UInt64(None)),
+                exclusion: WindowFrameExclusion::NoOthers,
                 causal: false,
             }
         }
@@ -200,6 +216,7 @@ impl WindowFrame {
             WindowFrameBound::CurrentRow => WindowFrameBound::CurrentRow,
         };
         Self::new_bounds(self.units, start_bound, end_bound)
+            .with_exclusion(self.exclusion)
     }
 
     /// Get whether window frame is causal
@@ -243,10 +260,26 @@ impl WindowFrame {
             units,
             start_bound,
             end_bound,
+            exclusion: WindowFrameExclusion::NoOthers,
             causal,
         }
     }
 
+    /// Sets the `EXCLUDE` clause of the window frame.
+    ///
+    /// Excluding rows other than `NO OTHERS` never makes a frame depend on
+    /// subsequent rows, so the causality of the frame is unchanged.
+    pub fn with_exclusion(mut self, exclusion: WindowFrameExclusion) -> Self {
+        self.exclusion = exclusion;
+        self
+    }
+
+    /// Returns whether the window frame excludes any rows via its `EXCLUDE`
+    /// clause, i.e. the clause is not `EXCLUDE NO OTHERS`.
+    pub fn has_exclusion(&self) -> bool {
+        self.exclusion != WindowFrameExclusion::NoOthers
+    }
+
     /// Regularizes the ORDER BY clause of the window frame.
     pub fn regularize_order_bys(&self, order_by: &mut Vec<Sort>) -> Result<()> {
         match self.units {
@@ -487,6 +520,36 @@ impl From<ast::WindowFrameUnits> for WindowFrameUnits {
     }
 }
 
+/// The `EXCLUDE` clause of a window frame removes rows from the frame that
+/// would otherwise be included by the frame boundaries. Peers of a row are
+/// the rows that have equal values for all terms of the window `ORDER BY`
+/// clause (all rows of the partition are peers if there is no `ORDER BY`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Hash)]
+pub enum WindowFrameExclusion {
+    /// `EXCLUDE NO OTHERS`: no rows are excluded. This is the default.
+    #[default]
+    NoOthers,
+    /// `EXCLUDE CURRENT ROW`: the current row is excluded from the frame.
+    CurrentRow,
+    /// `EXCLUDE GROUP`: the current row and all of its peers are excluded
+    /// from the frame.
+    Group,
+    /// `EXCLUDE TIES`: the peers of the current row are excluded from the
+    /// frame, but the current row itself is not.
+    Ties,
+}
+
+impl fmt::Display for WindowFrameExclusion {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            WindowFrameExclusion::NoOthers => "EXCLUDE NO OTHERS",
+            WindowFrameExclusion::CurrentRow => "EXCLUDE CURRENT ROW",
+            WindowFrameExclusion::Group => "EXCLUDE GROUP",
+            WindowFrameExclusion::Ties => "EXCLUDE TIES",
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -610,4 +673,38 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_window_frame_exclusion() {
+        let frame = WindowFrame::new_bounds(
+            WindowFrameUnits::Rows,
+            WindowFrameBound::Preceding(ScalarValue::UInt64(Some(2))),
+            WindowFrameBound::Following(ScalarValue::UInt64(Some(1))),
+        );
+        assert!(!frame.has_exclusion());
+        assert_eq!(
+            frame.to_string(),
+            "ROWS BETWEEN 2 PRECEDING AND 1 FOLLOWING"
+        );
+
+        let frame = frame.with_exclusion(WindowFrameExclusion::Ties);
+        assert!(frame.has_exclusion());
+        assert_eq!(
+            frame.to_string(),
+            "ROWS BETWEEN 2 PRECEDING AND 1 FOLLOWING EXCLUDE TIES"
+        );
+        assert_eq!(
+            format!("{frame:?}"),
+            "WindowFrame { units: Rows, start_bound: Preceding(UInt64(2)), \
+             end_bound: Following(UInt64(1)), exclusion: Ties, is_causal: false }"
+        );
+
+        // Reversing the frame keeps the exclusion
+        let reversed = frame.reverse();
+        assert_eq!(reversed.exclusion, WindowFrameExclusion::Ties);
+        assert_eq!(
+            reversed.to_string(),
+            "ROWS BETWEEN 1 PRECEDING AND 2 FOLLOWING EXCLUDE TIES"
+        );
+    }
 }
@@ -35,6 +35,7 @@
 //! This module also borrows some code structure from [pdatastructs.rs](https://github.com/crepererum/pdatastructs.rs/blob/3997ed50f6b6871c9e53c4c5e0f48f431405fc63/src/hyperloglog.rs).
 
 use ahash::RandomState;
+use datafusion_common::{internal_err, DataFusionError, Result, ScalarValue};
 use std::hash::Hash;
 use std::marker::PhantomData;
 
@@ -47,7 +48,7 @@ const NUM_REGISTERS: usize = 1_usize << HLL_P;
 const HLL_P_MASK: u64 = (NUM_REGISTERS as u64) - 1;
 
 #[derive(Clone, Debug)]
-pub(crate) struct HyperLogLog<T>
+pub struct HyperLogLog<T>
 where
     T: Hash + ?Sized,
 {
@@ -90,7 +91,7 @@ where
     /// Creates a HyperLogLog from already populated registers
     /// note that this method should not be invoked in untrusted environment
     /// because the internal structure of registers are not examined.
-    pub(crate) fn new_with_registers(registers: [u8; NUM_REGISTERS]) -> Self {
+    pub fn new_with_registers(registers: [u8; NUM_REGISTERS]) -> Self {
         Self {
             registers,
             phantom: PhantomData,
@@ -231,6 +232,38 @@ where
     }
 }
 
+impl<T: Hash> From<&HyperLogLog<T>> for ScalarValue {
+    fn from(v: &HyperLogLog<T>) -> ScalarValue {
+        let values = v.as_ref().to_vec();
+        ScalarValue::Binary(Some(values))
+    }
+}
+
+impl<T: Hash> TryFrom<&[u8]> for HyperLogLog<T> {
+    type Error = DataFusionError;
+    fn try_from(v: &[u8]) -> Result<HyperLogLog<T>> {
+        let arr: [u8; 16384] = v.try_into().map_err(|_| {
+            DataFusionError::Internal(
+                "Impossibly got invalid binary array from states".into(),
+            )
+        })?;
+        Ok(HyperLogLog::<T>::new_with_registers(arr))
+    }
+}
+
+impl<T: Hash> TryFrom<&ScalarValue> for HyperLogLog<T> {
+    type Error = DataFusionError;
+    fn try_from(v: &ScalarValue) -> Result<HyperLogLog<T>> {
+        if let ScalarValue::Binary(Some(slice)) = v {
+            slice.as_slice().try_into()
+        } else {
+            internal_err!(
+                "Impossibly got invalid scalar value while converting to HyperLogLog"
+            )
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{HyperLogLog, NUM_REGISTERS};
@@ -33,6 +33,7 @@
 
 pub mod accumulator;
 pub mod aggregate;
+pub mod hyperloglog;
 pub mod merge_arrays;
 pub mod min_max;
 pub mod order;
@@ -28,9 +28,7 @@ use arrow::datatypes::{
 };
 use arrow::{array::ArrayRef, datatypes::DataType, datatypes::Field};
 use datafusion_common::ScalarValue;
-use datafusion_common::{
-    downcast_value, internal_err, not_impl_err, DataFusionError, Result,
-};
+use datafusion_common::{downcast_value, not_impl_err, DataFusionError, Result};
 use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
 use datafusion_expr::utils::format_state_name;
 use datafusion_expr::{
@@ -50,38 +48,6 @@ make_udaf_expr_and_func!(
     approx_distinct_udaf
 );
 
-impl<T: Hash> From<&HyperLogLog<T>> for ScalarValue {
-    fn from(v: &HyperLogLog<T>) -> ScalarValue {
-        let values = v.as_ref().to_vec();
-        ScalarValue::Binary(Some(values))
-    }
-}
-
-impl<T: Hash> TryFrom<&[u8]> for HyperLogLog<T> {
-    type Error = DataFusionError;
-    fn try_from(v: &[u8]) -> Result<HyperLogLog<T>> {
-        let arr: [u8; 16384] = v.try_into().map_err(|_| {
-            DataFusionError::Internal(
-                "Impossibly got invalid binary array from states".into(),
-            )
-        })?;
-        Ok(HyperLogLog::<T>::new_with_registers(arr))
-    }
-}
-
-impl<T: Hash> TryFrom<&ScalarValue> for HyperLogLog<T> {
-    type Error = DataFusionError;
-    fn try_from(v: &ScalarValue) -> Result<HyperLogLog<T>> {
-        if let ScalarValue::Binary(Some(slice)) = v {
-            slice.as_slice().try_into()
-        } else {
-            internal_err!(
-                "Impossibly got invalid scalar value while converting to HyperLogLog"
-            )
-        }
-    }
-}
-
 #[derive(Debug)]
 struct NumericHLLAccumulator<T>
 where
@@ -77,7 +77,7 @@ pub mod count;
 pub mod covariance;
 pub mod first_last;
 pub mod grouping;
-pub mod hyperloglog;
+pub use datafusion_functions_aggregate_common::hyperloglog;
 pub mod median;
 pub mod min_max;
 pub mod nth_value;
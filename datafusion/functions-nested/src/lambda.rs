@@ -0,0 +1,802 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`ScalarUDFImpl`] definitions for the higher-order array functions, which
+//! take a lambda function as argument: array_transform, array_filter,
+//! array_any_match and array_reduce.
+
+use arrow::array::{
+    Array, ArrayRef, BooleanArray, BooleanBuilder, GenericListArray, Int32Array,
+    OffsetSizeTrait, UInt32Array, UInt64Array,
+};
+use arrow::buffer::OffsetBuffer;
+use arrow::compute::kernels::filter::{filter, prep_null_mask_filter};
+use arrow::compute::kernels::nullif::nullif;
+use arrow::compute::{cast, interleave, take};
+use arrow::datatypes::{
+    DataType,
+    DataType::{Boolean, FixedSizeList, LargeList, List, Null},
+    Field,
+};
+use datafusion_common::cast::{as_boolean_array, as_generic_list_array};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{exec_err, plan_err, Result};
+use datafusion_expr::{
+    ColumnarValue, Documentation, ScalarFunctionArgs, ScalarLambda, ScalarUDFImpl,
+    Signature, Volatility,
+};
+use datafusion_macros::user_doc;
+use std::any::Any;
+use std::sync::Arc;
+
+make_udf_expr_and_func!(
+    ArrayTransform,
+    array_transform,
+    array lambda,
+    "applies a lambda function to each element of an array.",
+    array_transform_udf
+);
+
+make_udf_expr_and_func!(
+    ArrayFilter,
+    array_filter,
+    array lambda,
+    "keeps the elements of an array for which a lambda function returns true.",
+    array_filter_udf
+);
+
+make_udf_expr_and_func!(
+    ArrayAnyMatch,
+    array_any_match,
+    array lambda,
+    "returns true if a lambda function returns true for any element of an array.",
+    array_any_match_udf
+);
+
+make_udf_expr_and_func!(
+    ArrayReduce,
+    array_reduce,
+    "reduces the elements of an array to a single value with a lambda function.",
+    array_reduce_udf
+);
+
+#[user_doc(
+    doc_section(label = "Array Functions"),
+    description = "Returns an array with the result of applying a lambda function to each element of the input array. The lambda function takes the element and, optionally, its (0-based) index as parameters.",
+    syntax_example = "array_transform(array, lambda)",
+    sql_example = r#"```sql
+> select array_transform([1, 2, 3], x -> x * 2);
++----------------------------------------------------------+
+| array_transform(List([1,2,3]),x -> x * Int64(2))         |
++----------------------------------------------------------+
+| [2, 4, 6]                                                |
++----------------------------------------------------------+
+> select array_transform([1, 2, 3], (x, i) -> x + i);
++----------------------------------------------------------+
+| array_transform(List([1,2,3]),(x, i) -> x + i)           |
++----------------------------------------------------------+
+| [1, 3, 5]                                                |
++----------------------------------------------------------+
+```"#,
+    argument(
+        name = "array",
+        description = "Array expression. Can be a constant, column, or function, and any combination of array operators."
+    ),
+    argument(
+        name = "lambda",
+        description = "Lambda function `x -> expr` or `(x, i) -> expr`. Columns referenced in `expr` are evaluated for the row of the array."
+    )
+)]
+#[derive(Debug)]
+pub struct ArrayTransform {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl Default for ArrayTransform {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArrayTransform {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+            aliases: vec![String::from("list_transform")],
+        }
+    }
+}
+
+impl ScalarUDFImpl for ArrayTransform {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "array_transform"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [array, lambda] = take_function_args(self.name(), arg_types)?;
+        let field = Arc::new(Field::new_list_field(lambda.clone(), true));
+        Ok(match array {
+            LargeList(_) => LargeList(field),
+            _ => List(field),
+        })
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [_, _] = take_function_args(self.name(), arg_types)?;
+        coerce_array_argument(self.name(), arg_types)
+    }
+
+    fn lambda_parameters(
+        &self,
+        index: usize,
+        arg_types: &[DataType],
+    ) -> Result<Vec<DataType>> {
+        match (index, arg_types) {
+            (1, [array]) => Ok(vec![element_type(self.name(), array)?, DataType::Int32]),
+            _ => plan_err!(
+                "{} expects a lambda function as second argument",
+                self.name()
+            ),
+        }
+    }
+
+    fn invoke_with_args(&self, _args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        exec_err!(
+            "{} expects a lambda function as second argument",
+            self.name()
+        )
+    }
+
+    fn invoke_with_lambdas(
+        &self,
+        args: ScalarFunctionArgs,
+        lambdas: Vec<Option<Arc<dyn ScalarLambda>>>,
+    ) -> Result<ColumnarValue> {
+        let lambda = lambda_argument(self.name(), &lambdas, 1)?;
+        let array = args.args[0].to_array(args.number_rows)?;
+        let result = match array.data_type() {
+            List(_) => array_transform_inner::<i32>(&array, lambda.as_ref())?,
+            LargeList(_) => array_transform_inner::<i64>(&array, lambda.as_ref())?,
+            other => return exec_err!("{} does not support type {other}", self.name()),
+        };
+        Ok(ColumnarValue::Array(result))
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[user_doc(
+    doc_section(label = "Array Functions"),
+    description = "Returns an array with the elements of the input array for which a lambda function returns true. The lambda function takes the element and, optionally, its (0-based) index as parameters.",
+    syntax_example = "array_filter(array, lambda)",
+    sql_example = r#"```sql
+> select array_filter([1, 2, 3, 4], x -> x % 2 = 0);
++-----------------------------------------------------------------+
+| array_filter(List([1,2,3,4]),x -> x % Int64(2) = Int64(0))      |
++-----------------------------------------------------------------+
+| [2, 4]                                                          |
++-----------------------------------------------------------------+
+```"#,
+    argument(
+        name = "array",
+        description = "Array expression. Can be a constant, column, or function, and any combination of array operators."
+    ),
+    argument(
+        name = "lambda",
+        description = "Lambda function `x -> predicate` or `(x, i) -> predicate`. Elements for which the predicate is null are removed."
+    )
+)]
+#[derive(Debug)]
+pub struct ArrayFilter {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl Default for ArrayFilter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArrayFilter {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+            aliases: vec![String::from("list_filter")],
+        }
+    }
+}
+
+impl ScalarUDFImpl for ArrayFilter {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "array_filter"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [array, lambda] = take_function_args(self.name(), arg_types)?;
+        check_predicate(self.name(), lambda)?;
+        Ok(array.clone())
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [_, _] = take_function_args(self.name(), arg_types)?;
+        coerce_array_argument(self.name(), arg_types)
+    }
+
+    fn lambda_parameters(
+        &self,
+        index: usize,
+        arg_types: &[DataType],
+    ) -> Result<Vec<DataType>> {
+        match (index, arg_types) {
+            (1, [array]) => Ok(vec![element_type(self.name(), array)?, DataType::Int32]),
+            _ => plan_err!(
+                "{} expects a lambda function as second argument",
+                self.name()
+            ),
+        }
+    }
+
+    fn invoke_with_args(&self, _args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        exec_err!(
+            "{} expects a lambda function as second argument",
+            self.name()
+        )
+    }
+
+    fn invoke_with_lambdas(
+        &self,
+        args: ScalarFunctionArgs,
+        lambdas: Vec<Option<Arc<dyn ScalarLambda>>>,
+    ) -> Result<ColumnarValue> {
+        let lambda = lambda_argument(self.name(), &lambdas, 1)?;
+        let array = args.args[0].to_array(args.number_rows)?;
+        let result = match array.data_type() {
+            List(_) => array_filter_inner::<i32>(&array, lambda.as_ref())?,
+            LargeList(_) => array_filter_inner::<i64>(&array, lambda.as_ref())?,
+            other => return exec_err!("{} does not support type {other}", self.name()),
+        };
+        Ok(ColumnarValue::Array(result))
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[user_doc(
+    doc_section(label = "Array Functions"),
+    description = "Returns true if a lambda function returns true for any element of the array. Returns null if the lambda function returns null for some elements and false for the others, and false otherwise.",
+    syntax_example = "array_any_match(array, lambda)",
+    sql_example = r#"```sql
+> select array_any_match([1, 2, 3], x -> x > 2);
++-------------------------------------------------------+
+| array_any_match(List([1,2,3]),x -> x > Int64(2))      |
++-------------------------------------------------------+
+| true                                                  |
++-------------------------------------------------------+
+```"#,
+    argument(
+        name = "array",
+        description = "Array expression. Can be a constant, column, or function, and any combination of array operators."
+    ),
+    argument(name = "lambda", description = "Lambda function `x -> predicate`.")
+)]
+#[derive(Debug)]
+pub struct ArrayAnyMatch {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl Default for ArrayAnyMatch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArrayAnyMatch {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+            aliases: vec![String::from("list_any_match")],
+        }
+    }
+}
+
+impl ScalarUDFImpl for ArrayAnyMatch {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "array_any_match"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        let [_, lambda] = take_function_args(self.name(), arg_types)?;
+        check_predicate(self.name(), lambda)?;
+        Ok(Boolean)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [_, _] = take_function_args(self.name(), arg_types)?;
+        coerce_array_argument(self.name(), arg_types)
+    }
+
+    fn lambda_parameters(
+        &self,
+        index: usize,
+        arg_types: &[DataType],
+    ) -> Result<Vec<DataType>> {
+        match (index, arg_types) {
+            (1, [array]) => Ok(vec![element_type(self.name(), array)?]),
+            _ => plan_err!(
+                "{} expects a lambda function as second argument",
+                self.name()
+            ),
+        }
+    }
+
+    fn invoke_with_args(&self, _args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        exec_err!(
+            "{} expects a lambda function as second argument",
+            self.name()
+        )
+    }
+
+    fn invoke_with_lambdas(
+        &self,
+        args: ScalarFunctionArgs,
+        lambdas: Vec<Option<Arc<dyn ScalarLambda>>>,
+    ) -> Result<ColumnarValue> {
+        let lambda = lambda_argument(self.name(), &lambdas, 1)?;
+        let array = args.args[0].to_array(args.number_rows)?;
+        let result = match array.data_type() {
+            List(_) => array_any_match_inner::<i32>(&array, lambda.as_ref())?,
+            LargeList(_) => array_any_match_inner::<i64>(&array, lambda.as_ref())?,
+            other => return exec_err!("{} does not support type {other}", self.name()),
+        };
+        Ok(ColumnarValue::Array(result))
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+#[user_doc(
+    doc_section(label = "Array Functions"),
+    description = "Reduces the elements of an array to a single value. Starting from the initial value, the merge lambda function is applied to the accumulated value and each element in turn. The result of the merge function is cast to the type of the initial value. The optional finish lambda function is applied to the final accumulated value.",
+    syntax_example = "array_reduce(array, initial_value, merge[, finish])",
+    sql_example = r#"```sql
+> select array_reduce([1, 2, 3], 0, (acc, x) -> acc + x);
++----------------------------------------------------------------+
+| array_reduce(List([1,2,3]),Int64(0),(acc, x) -> acc + x)       |
++----------------------------------------------------------------+
+| 6                                                              |
++----------------------------------------------------------------+
+> select array_reduce([1, 2, 3], 0, (acc, x) -> acc + x, acc -> acc * 10);
++---------------------------------------------------------------------------------+
+| array_reduce(List([1,2,3]),Int64(0),(acc, x) -> acc + x,acc -> acc * Int64(10)) |
++---------------------------------------------------------------------------------+
+| 60                                                                              |
++---------------------------------------------------------------------------------+
+```"#,
+    argument(
+        name = "array",
+        description = "Array expression. Can be a constant, column, or function, and any combination of array operators."
+    ),
+    argument(
+        name = "initial_value",
+        description = "Initial value of the accumulated value, which determines its type."
+    ),
+    argument(
+        name = "merge",
+        description = "Lambda function `(acc, x) -> expr` combining the accumulated value with an element."
+    ),
+    argument(
+        name = "finish",
+        description = "Optional lambda function `acc -> expr` applied to the final accumulated value."
+    )
+)]
+#[derive(Debug)]
+pub struct ArrayReduce {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl Default for ArrayReduce {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArrayReduce {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+            aliases: vec![String::from("list_reduce")],
+        }
+    }
+}
+
+impl ScalarUDFImpl for ArrayReduce {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "array_reduce"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        match arg_types {
+            [_, initial_value, _] => Ok(initial_value.clone()),
+            [_, _, _, finish] => Ok(finish.clone()),
+            _ => plan_err!(
+                "{} function requires 3 or 4 arguments, got {}",
+                self.name(),
+                arg_types.len()
+            ),
+        }
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if !(3..=4).contains(&arg_types.len()) {
+            return plan_err!(
+                "{} function requires 3 or 4 arguments, got {}",
+                self.name(),
+                arg_types.len()
+            );
+        }
+        coerce_array_argument(self.name(), arg_types)
+    }
+
+    fn lambda_parameters(
+        &self,
+        index: usize,
+        arg_types: &[DataType],
+    ) -> Result<Vec<DataType>> {
+        match (index, arg_types) {
+            (2, [array, initial_value]) => Ok(vec![
+                initial_value.clone(),
+                element_type(self.name(), array)?,
+            ]),
+            (3, [_, initial_value, _]) => Ok(vec![initial_value.clone()]),
+            _ => plan_err!(
+                "{} expects lambda functions as third and fourth arguments",
+                self.name()
+            ),
+        }
+    }
+
+    fn invoke_with_args(&self, _args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        exec_err!(
+            "{} expects a lambda function as third argument",
+            self.name()
+        )
+    }
+
+    fn invoke_with_lambdas(
+        &self,
+        args: ScalarFunctionArgs,
+        lambdas: Vec<Option<Arc<dyn ScalarLambda>>>,
+    ) -> Result<ColumnarValue> {
+        let merge = lambda_argument(self.name(), &lambdas, 2)?;
+        let finish = match args.args.len() {
+            4 => Some(lambda_argument(self.name(), &lambdas, 3)?.as_ref()),
+            _ => None,
+        };
+        let array = args.args[0].to_array(args.number_rows)?;
+        let initial_value = args.args[1].to_array(args.number_rows)?;
+        let result = match array.data_type() {
+            List(_) => {
+                array_reduce_inner::<i32>(&array, initial_value, merge.as_ref(), finish)?
+            }
+            LargeList(_) => {
+                array_reduce_inner::<i64>(&array, initial_value, merge.as_ref(), finish)?
+            }
+            other => return exec_err!("{} does not support type {other}", self.name()),
+        };
+        Ok(ColumnarValue::Array(result))
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn documentation(&self) -> Option<&Documentation> {
+        self.doc()
+    }
+}
+
+/// Coerces the array argument of a higher-order function to a `List` or
+/// `LargeList`, leaving the other arguments unchanged
+fn coerce_array_argument(name: &str, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+    let mut arg_types = arg_types.to_vec();
+    arg_types[0] = match &arg_types[0] {
+        List(field) | FixedSizeList(field, _) => List(Arc::clone(field)),
+        LargeList(field) => LargeList(Arc::clone(field)),
+        Null => List(Arc::new(Field::new_list_field(Null, true))),
+        other => return plan_err!("{name} does not support type {other}"),
+    };
+    Ok(arg_types)
+}
+
+/// Returns the type of the elements of the array argument of a higher-order
+/// function
+fn element_type(name: &str, array: &DataType) -> Result<DataType> {
+    match array {
+        List(field) | LargeList(field) | FixedSizeList(field, _) => {
+            Ok(field.data_type().clone())
+        }
+        Null => Ok(Null),
+        other => plan_err!("{name} does not support type {other}"),
+    }
+}
+
+fn check_predicate(name: &str, lambda: &DataType) -> Result<()> {
+    match lambda {
+        Boolean | Null => Ok(()),
+        other => {
+            plan_err!("{name} expects a lambda function returning a boolean, got {other}")
+        }
+    }
+}
+
+fn lambda_argument<'a>(
+    name: &str,
+    lambdas: &'a [Option<Arc<dyn ScalarLambda>>],
+    index: usize,
+) -> Result<&'a Arc<dyn ScalarLambda>> {
+    match lambdas.get(index) {
+        Some(Some(lambda)) => Ok(lambda),
+        _ => exec_err!("{name} expects a lambda function as argument {}", index + 1),
+    }
+}
+
+/// The elements of a list array, with the rows they belong to and their
+/// (0-based) index within their list
+struct ListElements {
+    values: ArrayRef,
+    rows: UInt32Array,
+    indices: Int32Array,
+}
+
+impl ListElements {
+    fn new<O: OffsetSizeTrait>(list: &GenericListArray<O>) -> Self {
+        let offsets = list.value_offsets();
+        let start = offsets[0].as_usize();
+        let end = offsets[list.len()].as_usize();
+
+        let mut rows = Vec::with_capacity(end - start);
+        let mut indices = Vec::with_capacity(end - start);
+        for (row, window) in offsets.windows(2).enumerate() {
+            let len = (window[1] - window[0]).as_usize();
+            rows.extend(std::iter::repeat_n(row as u32, len));
+            indices.extend(0..len as i32);
+        }
+
+        Self {
+            values: list.values().slice(start, end - start),
+            rows: UInt32Array::from(rows),
+            indices: Int32Array::from(indices),
+        }
+    }
+
+    /// The arguments of a lambda function applied to the elements
+    fn args(&self) -> [ArrayRef; 2] {
+        [Arc::clone(&self.values), Arc::new(self.indices.clone())]
+    }
+}
+
+/// Offsets of the values of `list`, rebased to start at 0
+fn rebased_offsets<O: OffsetSizeTrait>(list: &GenericListArray<O>) -> OffsetBuffer<O> {
+    let start = list.value_offsets()[0];
+    OffsetBuffer::new(
+        list.value_offsets()
+            .iter()
+            .map(|offset| *offset - start)
+            .collect::<Vec<_>>()
+            .into(),
+    )
+}
+
+fn array_transform_inner<O: OffsetSizeTrait>(
+    array: &ArrayRef,
+    lambda: &dyn ScalarLambda,
+) -> Result<ArrayRef> {
+    let list = as_generic_list_array::<O>(array)?;
+    let elements = ListElements::new(list);
+    let values = lambda.evaluate(&elements.args(), &elements.rows)?;
+
+    let field = Arc::new(Field::new_list_field(values.data_type().clone(), true));
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        field,
+        rebased_offsets(list),
+        values,
+        list.nulls().cloned(),
+    )?))
+}
+
+fn array_filter_inner<O: OffsetSizeTrait>(
+    array: &ArrayRef,
+    lambda: &dyn ScalarLambda,
+) -> Result<ArrayRef> {
+    let list = as_generic_list_array::<O>(array)?;
+    let elements = ListElements::new(list);
+    let predicate = lambda.evaluate(&elements.args(), &elements.rows)?;
+    let predicate = match predicate.data_type() {
+        Null => BooleanArray::new_null(predicate.len()),
+        _ => as_boolean_array(&predicate)?.clone(),
+    };
+    // Elements for which the predicate is null are removed
+    let predicate = prep_null_mask_filter(&predicate);
+
+    let offsets = rebased_offsets(list);
+    let lengths = offsets.windows(2).map(|window| {
+        let (start, end) = (window[0].as_usize(), window[1].as_usize());
+        predicate.slice(start, end - start).true_count()
+    });
+    let offsets = OffsetBuffer::<O>::from_lengths(lengths);
+    let values = filter(&elements.values, &predicate)?;
+
+    let (field, _, _, nulls) = list.clone().into_parts();
+    Ok(Arc::new(GenericListArray::<O>::try_new(
+        field, offsets, values, nulls,
+    )?))
+}
+
+fn array_any_match_inner<O: OffsetSizeTrait>(
+    array: &ArrayRef,
+    lambda: &dyn ScalarLambda,
+) -> Result<ArrayRef> {
+    let list = as_generic_list_array::<O>(array)?;
+    let elements = ListElements::new(list);
+    let predicate = lambda.evaluate(&elements.args(), &elements.rows)?;
+    let predicate = match predicate.data_type() {
+        Null => BooleanArray::new_null(predicate.len()),
+        _ => as_boolean_array(&predicate)?.clone(),
+    };
+
+    let mut builder = BooleanBuilder::with_capacity(list.len());
+    for (row, window) in rebased_offsets(list).windows(2).enumerate() {
+        if list.is_null(row) {
+            builder.append_null();
+            continue;
+        }
+        let (start, end) = (window[0].as_usize(), window[1].as_usize());
+        let matches = predicate.slice(start, end - start);
+        if matches.true_count() > 0 {
+            builder.append_value(true);
+        } else if matches.null_count() > 0 {
+            builder.append_null();
+        } else {
+            builder.append_value(false);
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Reduces the lists one element index at a time, merging the elements at
+/// that index into the accumulated values of the lists that are long enough
+fn array_reduce_inner<O: OffsetSizeTrait>(
+    array: &ArrayRef,
+    initial_value: ArrayRef,
+    merge: &dyn ScalarLambda,
+    finish: Option<&dyn ScalarLambda>,
+) -> Result<ArrayRef> {
+    let list = as_generic_list_array::<O>(array)?;
+    let offsets = list.value_offsets();
+    let lengths = (0..list.len())
+        .map(|row| match list.is_null(row) {
+            true => 0,
+            false => (offsets[row + 1] - offsets[row]).as_usize(),
+        })
+        .collect::<Vec<_>>();
+    let max_length = lengths.iter().copied().max().unwrap_or(0);
+
+    let acc_type = initial_value.data_type().clone();
+    let mut acc = initial_value;
+    for index in 0..max_length {
+        let rows = UInt32Array::from_iter_values(
+            (0..list.len())
+                .filter(|row| lengths[*row] > index)
+                .map(|row| row as u32),
+        );
+        let element_indices = UInt64Array::from_iter_values(
+            rows.values()
+                .iter()
+                .map(|row| (offsets[*row as usize].as_usize() + index) as u64),
+        );
+
+        let accs = take(&acc, &rows, None)?;
+        let elements = take(list.values(), &element_indices, None)?;
+        let merged = merge.evaluate(&[accs, elements], &rows)?;
+        let merged = cast(&merged, &acc_type)?;
+
+        // Scatter the merged values back into the accumulated values
+        let mut next = 0;
+        let scatter = (0..list.len())
+            .map(|row| {
+                if lengths[row] > index {
+                    next += 1;
+                    (1, next - 1)
+                } else {
+                    (0, row)
+                }
+            })
+            .collect::<Vec<_>>();
+        acc = interleave(&[acc.as_ref(), merged.as_ref()], &scatter)?;
+    }
+
+    let result = match finish {
+        Some(finish) => {
+            let rows = UInt32Array::from_iter_values(0..list.len() as u32);
+            finish.evaluate(&[acc], &rows)?
+        }
+        None => acc,
+    };
+
+    // A null array reduces to null
+    match list.nulls() {
+        Some(nulls) => Ok(nullif(
+            result.as_ref(),
+            &BooleanArray::new(!nulls.inner(), None),
+        )?),
+        None => Ok(result),
+    }
+}
@@ -47,6 +47,7 @@ pub mod except;
 pub mod expr_ext;
 pub mod extract;
 pub mod flatten;
+pub mod lambda;
 pub mod length;
 pub mod make_array;
 pub mod map;
@@ -94,6 +95,10 @@ pub mod expr_fn {
     pub use super::extract::array_pop_front;
     pub use super::extract::array_slice;
     pub use super::flatten::flatten;
+    pub use super::lambda::array_any_match;
+    pub use super::lambda::array_filter;
+    pub use super::lambda::array_reduce;
+    pub use super::lambda::array_transform;
     pub use super::length::array_length;
     pub use super::make_array::make_array;
     pub use super::map_entries::map_entries;
@@ -150,6 +155,10 @@ pub fn all_default_nested_functions() -> Vec<Arc<ScalarUDF>> {
         length::array_length_udf(),
         distance::array_distance_udf(),
         flatten::flatten_udf(),
+        lambda::array_transform_udf(),
+        lambda::array_filter_udf(),
+        lambda::array_any_match_udf(),
+        lambda::array_reduce_udf(),
         min_max::array_max_udf(),
         min_max::array_min_udf(),
         sort::array_sort_udf(),
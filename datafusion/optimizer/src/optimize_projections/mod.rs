@@ -30,8 +30,8 @@ use datafusion_common::{
 };
 use datafusion_expr::expr::Alias;
 use datafusion_expr::{
-    logical_plan::LogicalPlan, Aggregate, Distinct, Expr, Projection, TableScan, Unnest,
-    Window,
+    logical_plan::LogicalPlan, Aggregate, Distinct, Expr, Projection, RowsPerMatch,
+    TableScan, Unnest, Window,
 };
 
 use crate::optimize_projections::required_indices::RequiredIndices;
@@ -393,6 +393,17 @@ fn optimize_projections(
             });
             vec![required_indices.append(&additional_necessary_child_indices)]
         }
+        LogicalPlan::MatchRecognize(match_recognize) => {
+            let input = match_recognize.input.as_ref();
+            let required_indices = match match_recognize.rows_per_match {
+                // The input columns are part of the output
+                RowsPerMatch::AllRows => RequiredIndices::new_for_all_exprs(input),
+                RowsPerMatch::OneRow => RequiredIndices::new()
+                    .with_projection_beneficial()
+                    .with_plan_exprs(&plan, input.schema())?,
+            };
+            vec![required_indices]
+        }
     };
 
     // Required indices are currently ordered (child0, child1, ...)
@@ -716,7 +727,8 @@ fn split_join_requirements(
         | JoinType::Right
         | JoinType::Full
         | JoinType::LeftMark
-        | JoinType::RightMark => {
+        | JoinType::RightMark
+        | JoinType::AsOf => {
             // Decrease right side indices by `left_len` so that they point to valid
             // positions within the right child:
             indices.split_off(left_len)
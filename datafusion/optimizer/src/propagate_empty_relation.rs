@@ -94,12 +94,12 @@ impl OptimizerRule for PropagateEmptyRelation {
                             schema: Arc::clone(&join.schema),
                         }),
                     )),
-                    JoinType::Left if left_empty => Ok(Transformed::yes(
-                        LogicalPlan::EmptyRelation(EmptyRelation {
+                    JoinType::Left | JoinType::AsOf if left_empty => Ok(
+                        Transformed::yes(LogicalPlan::EmptyRelation(EmptyRelation {
                             produce_one_row: false,
                             schema: Arc::clone(&join.schema),
-                        }),
-                    )),
+                        })),
+                    ),
                     JoinType::Right if right_empty => Ok(Transformed::yes(
                         LogicalPlan::EmptyRelation(EmptyRelation {
                             produce_one_row: false,
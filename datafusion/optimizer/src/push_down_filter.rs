@@ -162,7 +162,7 @@ pub struct PushDownFilter {}
 pub(crate) fn lr_is_preserved(join_type: JoinType) -> (bool, bool) {
     match join_type {
         JoinType::Inner => (true, true),
-        JoinType::Left => (true, false),
+        JoinType::Left | JoinType::AsOf => (true, false),
         JoinType::Right => (false, true),
         JoinType::Full => (false, false),
         // No columns from the right side of the join can be referenced in output
@@ -186,7 +186,7 @@ pub(crate) fn lr_is_preserved(join_type: JoinType) -> (bool, bool) {
 pub(crate) fn on_lr_is_preserved(join_type: JoinType) -> (bool, bool) {
     match join_type {
         JoinType::Inner => (true, true),
-        JoinType::Left => (false, true),
+        JoinType::Left | JoinType::AsOf => (false, true),
         JoinType::Right => (true, false),
         JoinType::Full => (false, false),
         JoinType::LeftSemi | JoinType::RightSemi => (true, true),
@@ -259,6 +259,7 @@ fn can_evaluate_as_join_condition(predicate: &Expr) -> Result<bool> {
         Expr::Column(_)
         | Expr::Literal(_, _)
         | Expr::Placeholder(_)
+        | Expr::LambdaVariable(_)
         | Expr::ScalarVariable(_, _) => Ok(TreeNodeRecursion::Jump),
         Expr::Exists { .. }
         | Expr::InSubquery(_)
@@ -287,6 +288,7 @@ fn can_evaluate_as_join_condition(predicate: &Expr) -> Result<bool> {
         | Expr::Cast(_)
         | Expr::TryCast(_)
         | Expr::InList { .. }
+        | Expr::Lambda(_)
         | Expr::ScalarFunction(_) => Ok(TreeNodeRecursion::Continue),
         // TODO: remove the next line after `Expr::Wildcard` is removed
         #[expect(deprecated)]
@@ -687,7 +689,7 @@ fn infer_join_predicates_from_on_filters(
             on_filters,
             inferred_predicates,
         ),
-        JoinType::Left | JoinType::LeftSemi | JoinType::LeftMark => {
+        JoinType::Left | JoinType::LeftSemi | JoinType::LeftMark | JoinType::AsOf => {
             infer_join_predicates_impl::<true, false>(
                 join_col_keys,
                 on_filters,
@@ -905,8 +907,16 @@ impl OptimizerRule for PushDownFilter {
                         });
                     let contains_struct_columns =
                         unnest_struct_columns.iter().any(|c| accum.contains(c));
-
-                    if contains_list_columns || contains_struct_columns {
+                    let contains_ordinality_column = unnest
+                        .options
+                        .ordinality
+                        .as_ref()
+                        .is_some_and(|o| accum.contains(&Column::from_name(&o.name)));
+
+                    if contains_list_columns
+                        || contains_struct_columns
+                        || contains_ordinality_column
+                    {
                         unnest_predicates.push(predicate);
                     } else {
                         non_unnest_predicates.push(predicate);
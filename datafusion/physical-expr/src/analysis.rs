@@ -26,7 +26,7 @@ use crate::utils::collect_columns;
 use crate::PhysicalExpr;
 
 use arrow::datatypes::Schema;
-use datafusion_common::stats::Precision;
+use datafusion_common::stats::{Histogram, MostCommonValues, Precision};
 use datafusion_common::{
     internal_datafusion_err, internal_err, ColumnStatistics, Result, ScalarValue,
 };
@@ -89,6 +89,12 @@ pub struct ExprBoundaries {
     pub interval: Option<Interval>,
     /// Maximum number of distinct values this expression can produce, if known.
     pub distinct_count: Precision<usize>,
+    /// Distribution of the non-null values of the column, if known, used to
+    /// estimate the selectivity of the shrinking of `interval`
+    pub histogram: Option<Arc<Histogram>>,
+    /// Most common non-null values of the column, if known, which refine the
+    /// estimations of `histogram`
+    pub most_common_values: Option<Arc<MostCommonValues>>,
 }
 
 impl ExprBoundaries {
@@ -124,6 +130,8 @@ impl ExprBoundaries {
             column,
             interval: Some(interval),
             distinct_count: col_stats.distinct_count,
+            histogram: col_stats.histogram.clone(),
+            most_common_values: col_stats.most_common_values.clone(),
         })
     }
 
@@ -139,6 +147,8 @@ impl ExprBoundaries {
                     column: Column::new(field.name(), i),
                     interval: Some(Interval::make_unbounded(field.data_type())?),
                     distinct_count: Precision::Absent,
+                    histogram: None,
+                    most_common_values: None,
                 })
             })
             .collect()
@@ -150,7 +160,8 @@ impl ExprBoundaries {
 /// The function accepts boundaries of the input columns in the `context` parameter.
 /// It then tries to tighten these boundaries based on the provided `expr`.
 /// The resulting selectivity value is calculated by comparing the initial and final boundaries.
-/// The computation assumes that the data within the column is uniformly distributed and not sorted.
+/// The computation assumes that the data within the column is uniformly distributed and not sorted,
+/// unless the column has a [`Histogram`] describing its distribution.
 ///
 /// # Arguments
 ///
@@ -267,6 +278,10 @@ fn shrink_boundaries(
 /// This function calculates the filter predicate's selectivity by comparing
 /// the initial and pruned column boundaries. Selectivity is defined as the
 /// ratio of rows in a table that satisfy the filter's predicate.
+///
+/// The selectivity of a column with a [`Histogram`] is the estimated ratio of
+/// its values in the target interval to its values in the initial interval,
+/// and the values of other columns are assumed to be uniformly distributed.
 fn calculate_selectivity(
     target_boundaries: &[ExprBoundaries],
     initial_boundaries: &[ExprBoundaries],
@@ -282,8 +297,11 @@ fn calculate_selectivity(
     let mut acc: f64 = 1.0;
     for (initial, target) in initial_boundaries.iter().zip(target_boundaries) {
         match (initial.interval.as_ref(), target.interval.as_ref()) {
-            (Some(initial), Some(target)) => {
-                acc *= cardinality_ratio(initial, target);
+            (Some(initial_interval), Some(target_interval)) => {
+                acc *= histogram_ratio(initial, initial_interval, target_interval)
+                    .unwrap_or_else(|| {
+                        cardinality_ratio(initial_interval, target_interval)
+                    });
             }
             (None, Some(_)) => {
                 return internal_err!(
@@ -297,12 +315,44 @@ fn calculate_selectivity(
     Ok(acc)
 }
 
+/// Estimates the ratio of the values of the `initial` column in its
+/// `target_interval` to its values in its `initial_interval` from its
+/// histogram, if it has one and the interval has been shrunk.
+fn histogram_ratio(
+    initial: &ExprBoundaries,
+    initial_interval: &Interval,
+    target_interval: &Interval,
+) -> Option<f64> {
+    let histogram = initial.histogram.as_deref()?;
+    if initial_interval == target_interval {
+        return None;
+    }
+    let most_common_values = initial.most_common_values.as_deref();
+    // null bounds are unbounded
+    let bound = |value: &ScalarValue| (!value.is_null()).then_some(value);
+    let count_between = |interval: &Interval| {
+        histogram.estimate_count_between(
+            bound(interval.lower()),
+            bound(interval.upper()),
+            most_common_values,
+        )
+    };
+    let initial_count = count_between(initial_interval);
+    if initial_count <= 0.0 {
+        return None;
+    }
+    Some((count_between(target_interval) / initial_count).clamp(0.0, 1.0))
+}
+
 #[cfg(test)]
 mod tests {
     use std::sync::Arc;
 
     use arrow::datatypes::{DataType, Field, Schema};
-    use datafusion_common::{assert_contains, DFSchema};
+    use datafusion_common::stats::{
+        Histogram, HistogramBucket, MostCommonValues, Precision,
+    };
+    use datafusion_common::{assert_contains, ColumnStatistics, DFSchema, ScalarValue};
     use datafusion_expr::{
         col, execution_props::ExecutionProps, interval_arithmetic::Interval, lit, Expr,
     };
@@ -438,4 +488,57 @@ mod tests {
         .unwrap_err();
         assert_contains!(analysis_error.to_string(), expected_error);
     }
+
+    #[test]
+    fn test_analyze_selectivity_with_histogram() {
+        let schema = Arc::new(Schema::new(vec![make_field("a", DataType::Int32)]));
+        let bucket = |lower, upper, count, distinct_count| HistogramBucket {
+            lower: ScalarValue::Int32(Some(lower)),
+            upper: ScalarValue::Int32(Some(upper)),
+            count,
+            distinct_count,
+        };
+        // 90 values between 1 and 10, half of them 1, and 10 between 11 and 100
+        let histogram =
+            Histogram::try_new(vec![bucket(1, 10, 90, 10), bucket(11, 100, 10, 10)])
+                .unwrap();
+        let most_common_values =
+            MostCommonValues::try_new(vec![(ScalarValue::Int32(Some(1)), 45)]).unwrap();
+        let statistics = ColumnStatistics::new_unknown()
+            .with_min_value(Precision::Exact(ScalarValue::Int32(Some(1))))
+            .with_max_value(Precision::Exact(ScalarValue::Int32(Some(100))))
+            .with_histogram(Some(Arc::new(histogram)))
+            .with_most_common_values(Some(Arc::new(most_common_values)));
+
+        let test_cases = vec![
+            (col("a").lt_eq(lit(10)), 0.9),
+            (col("a").gt(lit(10)), 0.1),
+            (col("a").eq(lit(1)), 0.45),
+            // the 45 other values between 1 and 10 are uniformly distributed
+            (col("a").eq(lit(2)), 0.05),
+            (col("a").gt(lit(50)), 0.1 * 50.0 / 90.0),
+            (
+                col("a").gt_eq(lit(2)).and(col("a").lt_eq(lit(5))),
+                0.45 * 4.0 / 10.0,
+            ),
+        ];
+        for (expr, expected) in test_cases {
+            let context = AnalysisContext::try_from_statistics(
+                &schema,
+                std::slice::from_ref(&statistics),
+            )
+            .unwrap();
+            let df_schema = DFSchema::try_from(Arc::clone(&schema)).unwrap();
+            let physical_expr =
+                create_physical_expr(&expr, &df_schema, &ExecutionProps::new()).unwrap();
+            let selectivity = analyze(&physical_expr, context, df_schema.as_ref())
+                .unwrap()
+                .selectivity
+                .unwrap();
+            assert!(
+                (selectivity - expected).abs() < 1e-9,
+                "expected selectivity {expected} for {expr:?}, got {selectivity}"
+            );
+        }
+    }
 }
@@ -0,0 +1,226 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Physical lambda function expression: [`LambdaExpr`]
+
+use std::any::Any;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Formatter};
+use std::hash::Hash;
+use std::sync::Arc;
+
+use crate::physical_expr::PhysicalExpr;
+use crate::utils::collect_columns;
+
+use arrow::array::{
+    new_null_array, Array, ArrayRef, RecordBatch, RecordBatchOptions, UInt32Array,
+};
+use arrow::compute::take;
+use arrow::datatypes::{DataType, Field, FieldRef, Schema};
+use datafusion_common::{exec_err, internal_err, Result};
+use datafusion_expr::{ColumnarValue, ScalarLambda};
+
+/// Metadata key holding the name of a lambda parameter, on the fields appended
+/// to the input schema for the parameters of a lambda function
+pub const LAMBDA_PARAMETER_KEY: &str = "datafusion.lambda_parameter";
+
+/// Returns the field for the lambda parameter `name` of type `data_type`,
+/// appended to a schema with `index` fields.
+///
+/// The field is named after its position, so that parameters don't conflict
+/// with the input columns nor with the parameters of enclosing lambdas.
+pub fn lambda_parameter_field(index: usize, name: &str, data_type: DataType) -> Field {
+    Field::new(format!("__lambda_parameter_{index}"), data_type, true).with_metadata(
+        HashMap::from([(LAMBDA_PARAMETER_KEY.to_string(), name.to_string())]),
+    )
+}
+
+/// A lambda function, such as `x -> x + 1`, passed as argument to a
+/// [`ScalarFunctionExpr`](crate::ScalarFunctionExpr).
+///
+/// The body is evaluated against the columns of the input followed by the
+/// parameters of the lambda, see [`lambda_parameter_field`]. Columns of the
+/// input referenced in the body are evaluated for the rows the elements passed
+/// to the lambda originate from.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct LambdaExpr {
+    params: Vec<FieldRef>,
+    body: Arc<dyn PhysicalExpr>,
+    return_field: FieldRef,
+}
+
+impl LambdaExpr {
+    /// Create a new lambda function, whose `body` is planned against `schema`,
+    /// which ends with the fields of `params`
+    pub fn try_new(
+        params: Vec<FieldRef>,
+        body: Arc<dyn PhysicalExpr>,
+        schema: &Schema,
+    ) -> Result<Self> {
+        let return_field = body.return_field(schema)?;
+        Ok(Self {
+            params,
+            body,
+            return_field,
+        })
+    }
+
+    /// The parameters of the lambda
+    pub fn params(&self) -> &[FieldRef] {
+        &self.params
+    }
+
+    /// The body of the lambda
+    pub fn body(&self) -> &Arc<dyn PhysicalExpr> {
+        &self.body
+    }
+
+    /// Binds the lambda to the record batch of the function it is passed to
+    pub fn bind(&self, batch: &RecordBatch) -> Arc<dyn ScalarLambda> {
+        Arc::new(BoundLambda {
+            params: self.params.clone(),
+            body: Arc::clone(&self.body),
+            batch: batch.clone(),
+        })
+    }
+
+    fn param_names(&self) -> Vec<&str> {
+        self.params
+            .iter()
+            .map(|p| {
+                p.metadata()
+                    .get(LAMBDA_PARAMETER_KEY)
+                    .map_or(p.name().as_str(), |name| name.as_str())
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for LambdaExpr {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.param_names().as_slice() {
+            [param] => write!(f, "{param} -> {}", self.body),
+            params => write!(f, "({}) -> {}", params.join(", "), self.body),
+        }
+    }
+}
+
+impl PhysicalExpr for LambdaExpr {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn data_type(&self, _input_schema: &Schema) -> Result<DataType> {
+        Ok(self.return_field.data_type().clone())
+    }
+
+    fn nullable(&self, _input_schema: &Schema) -> Result<bool> {
+        Ok(self.return_field.is_nullable())
+    }
+
+    fn return_field(&self, _input_schema: &Schema) -> Result<FieldRef> {
+        Ok(Arc::clone(&self.return_field))
+    }
+
+    fn evaluate(&self, _batch: &RecordBatch) -> Result<ColumnarValue> {
+        internal_err!(
+            "Lambda function {self} can only be evaluated by the function it is passed to"
+        )
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn PhysicalExpr>> {
+        vec![&self.body]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        mut children: Vec<Arc<dyn PhysicalExpr>>,
+    ) -> Result<Arc<dyn PhysicalExpr>> {
+        Ok(Arc::new(Self {
+            params: self.params.clone(),
+            body: children.swap_remove(0),
+            return_field: Arc::clone(&self.return_field),
+        }))
+    }
+
+    fn fmt_sql(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.param_names().as_slice() {
+            [param] => write!(f, "{param} -> ")?,
+            params => write!(f, "({}) -> ", params.join(", "))?,
+        }
+        self.body.fmt_sql(f)
+    }
+}
+
+/// A [`LambdaExpr`] bound to a record batch
+#[derive(Debug)]
+struct BoundLambda {
+    params: Vec<FieldRef>,
+    body: Arc<dyn PhysicalExpr>,
+    batch: RecordBatch,
+}
+
+impl ScalarLambda for BoundLambda {
+    fn params(&self) -> &[FieldRef] {
+        &self.params
+    }
+
+    fn evaluate(&self, args: &[ArrayRef], rows: &UInt32Array) -> Result<ArrayRef> {
+        if args.len() < self.params.len() {
+            return exec_err!(
+                "Lambda function expects {} argument(s), got {}",
+                self.params.len(),
+                args.len()
+            );
+        }
+
+        // Only the input columns referenced in the body are gathered for the
+        // elements, the others are replaced with nulls
+        let referenced = collect_columns(&self.body)
+            .into_iter()
+            .map(|c| c.index())
+            .collect::<HashSet<_>>();
+        let input_schema = self.batch.schema();
+        let mut fields = Vec::with_capacity(input_schema.fields().len() + args.len());
+        let mut columns = Vec::with_capacity(fields.capacity());
+        for (index, field) in input_schema.fields().iter().enumerate() {
+            if referenced.contains(&index) {
+                fields.push(Arc::clone(field));
+                columns.push(take(self.batch.column(index), rows, None)?);
+            } else {
+                fields.push(Arc::new(field.as_ref().clone().with_nullable(true)));
+                columns.push(new_null_array(field.data_type(), rows.len()));
+            }
+        }
+        for (param, arg) in self.params.iter().zip(args) {
+            fields.push(Arc::new(
+                param
+                    .as_ref()
+                    .clone()
+                    .with_data_type(arg.data_type().clone()),
+            ));
+            columns.push(Arc::clone(arg));
+        }
+
+        let batch = RecordBatch::try_new_with_options(
+            Arc::new(Schema::new(fields)),
+            columns,
+            &RecordBatchOptions::new().with_row_count(Some(rows.len())),
+        )?;
+        self.body.evaluate(&batch)?.into_array(rows.len())
+    }
+}
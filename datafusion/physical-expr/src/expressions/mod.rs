@@ -26,6 +26,7 @@ mod dynamic_filters;
 mod in_list;
 mod is_not_null;
 mod is_null;
+mod lambda;
 mod like;
 mod literal;
 mod negative;
@@ -47,6 +48,7 @@ pub use dynamic_filters::DynamicFilterPhysicalExpr;
 pub use in_list::{in_list, InListExpr};
 pub use is_not_null::{is_not_null, IsNotNullExpr};
 pub use is_null::{is_null, IsNullExpr};
+pub use lambda::{lambda_parameter_field, LambdaExpr, LAMBDA_PARAMETER_KEY};
 pub use like::{like, LikeExpr};
 pub use literal::{lit, Literal};
 pub use negative::{negative, NegativeExpr};
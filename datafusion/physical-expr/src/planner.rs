@@ -19,22 +19,28 @@ use std::sync::Arc;
 
 use crate::ScalarFunctionExpr;
 use crate::{
-    expressions::{self, binary, like, similar_to, Column, Literal},
+    expressions::{
+        self, binary, lambda_parameter_field, like, similar_to, Column, LambdaExpr,
+        Literal, LAMBDA_PARAMETER_KEY,
+    },
     PhysicalExpr,
 };
 
 use arrow::datatypes::Schema;
 use datafusion_common::{
-    exec_err, not_impl_err, plan_err, DFSchema, Result, ScalarValue, ToDFSchema,
+    exec_err, not_impl_err, plan_datafusion_err, plan_err, DFSchema, Result, ScalarValue,
+    ToDFSchema,
 };
 use datafusion_expr::execution_props::ExecutionProps;
 use datafusion_expr::expr::{
-    Alias, Cast, FieldMetadata, InList, Placeholder, ScalarFunction,
+    Alias, Cast, FieldMetadata, InList, Lambda, LambdaVariable, Placeholder,
+    ScalarFunction,
 };
 use datafusion_expr::var_provider::is_system_variables;
 use datafusion_expr::var_provider::VarType;
 use datafusion_expr::{
-    binary_expr, lit, Between, BinaryExpr, Expr, Like, Operator, TryCast,
+    binary_expr, lit, Between, BinaryExpr, Expr, ExprSchemable, Like, Operator,
+    ScalarUDF, TryCast,
 };
 
 /// [PhysicalExpr] evaluate DataFusion expressions such as `A + 1`, or `CAST(c1
@@ -315,8 +321,16 @@ pub fn create_physical_expr(
             execution_props,
         )?),
         Expr::ScalarFunction(ScalarFunction { func, args }) => {
-            let physical_args =
-                create_physical_exprs(args, input_dfschema, execution_props)?;
+            let physical_args = if args.iter().any(|arg| matches!(arg, Expr::Lambda(_))) {
+                create_function_args_with_lambdas(
+                    func,
+                    args,
+                    input_dfschema,
+                    execution_props,
+                )?
+            } else {
+                create_physical_exprs(args, input_dfschema, execution_props)?
+            };
 
             Ok(Arc::new(ScalarFunctionExpr::try_new(
                 Arc::clone(func),
@@ -378,12 +392,86 @@ pub fn create_physical_expr(
         Expr::Placeholder(Placeholder { id, .. }) => {
             exec_err!("Placeholder '{id}' was not provided a value for execution.")
         }
+        Expr::LambdaVariable(LambdaVariable { name, .. }) => {
+            // The innermost parameter with the name shadows the others
+            let index = input_dfschema
+                .fields()
+                .iter()
+                .rposition(|f| f.metadata().get(LAMBDA_PARAMETER_KEY) == Some(name))
+                .ok_or_else(|| plan_datafusion_err!("Unknown lambda parameter {name}"))?;
+            Ok(Arc::new(Column::new(
+                input_dfschema.field(index).name(),
+                index,
+            )))
+        }
+        Expr::Lambda(_) => {
+            plan_err!(
+                "Lambda functions are only supported as arguments of scalar functions"
+            )
+        }
         other => {
             not_impl_err!("Physical plan does not support logical expression {other:?}")
         }
     }
 }
 
+/// Create the physical arguments of a scalar function, some of which are lambda
+/// functions.
+///
+/// The body of a lambda is planned against the input schema extended with the
+/// parameters of the lambda, whose types are determined by the function from
+/// the types of the arguments preceding the lambda.
+fn create_function_args_with_lambdas(
+    func: &ScalarUDF,
+    args: &[Expr],
+    input_dfschema: &DFSchema,
+    execution_props: &ExecutionProps,
+) -> Result<Vec<Arc<dyn PhysicalExpr>>> {
+    args.iter()
+        .enumerate()
+        .map(|(index, arg)| {
+            let Expr::Lambda(Lambda { params, body }) = arg else {
+                return create_physical_expr(arg, input_dfschema, execution_props);
+            };
+
+            let arg_types = args[..index]
+                .iter()
+                .map(|e| e.get_type(input_dfschema))
+                .collect::<Result<Vec<_>>>()?;
+            let param_types = func.lambda_parameters(index, &arg_types)?;
+            if params.len() > param_types.len() {
+                return plan_err!(
+                    "Lambda function for argument {} of {}() can have at most {} parameter(s), got {}",
+                    index + 1,
+                    func.name(),
+                    param_types.len(),
+                    params.len()
+                );
+            }
+
+            let offset = input_dfschema.fields().len();
+            let param_fields = params
+                .iter()
+                .zip(param_types)
+                .enumerate()
+                .map(|(i, (name, data_type))| {
+                    Arc::new(lambda_parameter_field(offset + i, name, data_type))
+                })
+                .collect::<Vec<_>>();
+            let lambda_dfschema = input_dfschema.join(&DFSchema::from_unqualified_fields(
+                param_fields.clone().into(),
+                Default::default(),
+            )?)?;
+            let body = create_physical_expr(body, &lambda_dfschema, execution_props)?;
+            Ok(Arc::new(LambdaExpr::try_new(
+                param_fields,
+                body,
+                lambda_dfschema.as_arrow(),
+            )?) as Arc<dyn PhysicalExpr>)
+        })
+        .collect()
+}
+
 /// Create vector of Physical Expression from a vector of logical expression
 pub fn create_physical_exprs<'a, I>(
     exprs: I,
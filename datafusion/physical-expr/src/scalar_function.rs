@@ -34,7 +34,7 @@ use std::fmt::{self, Debug, Formatter};
 use std::hash::Hash;
 use std::sync::Arc;
 
-use crate::expressions::Literal;
+use crate::expressions::{LambdaExpr, Literal};
 use crate::PhysicalExpr;
 
 use arrow::array::{Array, RecordBatch};
@@ -179,11 +179,24 @@ impl PhysicalExpr for ScalarFunctionExpr {
     }
 
     fn evaluate(&self, batch: &RecordBatch) -> Result<ColumnarValue> {
-        let args = self
-            .args
-            .iter()
-            .map(|e| e.evaluate(batch))
-            .collect::<Result<Vec<_>>>()?;
+        // Lambda functions are bound to the batch and passed to the function
+        // separately, with a null placeholder in their place in the arguments
+        let mut args = Vec::with_capacity(self.args.len());
+        let mut lambdas = Vec::with_capacity(self.args.len());
+        for arg in &self.args {
+            match arg.as_any().downcast_ref::<LambdaExpr>() {
+                Some(lambda) => {
+                    args.push(ColumnarValue::Scalar(ScalarValue::try_from(
+                        lambda.data_type(batch.schema_ref())?,
+                    )?));
+                    lambdas.push(Some(lambda.bind(batch)));
+                }
+                None => {
+                    args.push(arg.evaluate(batch)?);
+                    lambdas.push(None);
+                }
+            }
+        }
 
         let arg_fields = self
             .args
@@ -197,12 +210,17 @@ impl PhysicalExpr for ScalarFunctionExpr {
             .all(|arg| matches!(arg, ColumnarValue::Scalar(_)));
 
         // evaluate the function
-        let output = self.fun.invoke_with_args(ScalarFunctionArgs {
+        let args = ScalarFunctionArgs {
             args,
             arg_fields,
             number_rows: batch.num_rows(),
             return_field: Arc::clone(&self.return_field),
-        })?;
+        };
+        let output = if lambdas.iter().any(Option::is_some) {
+            self.fun.invoke_with_lambdas(args, lambdas)?
+        } else {
+            self.fun.invoke_with_args(args)?
+        };
 
         if let ColumnarValue::Array(array) = &output {
             if array.len() != batch.num_rows() {
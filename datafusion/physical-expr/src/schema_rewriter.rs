@@ -20,7 +20,7 @@
 use std::sync::Arc;
 
 use arrow::compute::can_cast_types;
-use arrow::datatypes::{FieldRef, Schema, SchemaRef};
+use arrow::datatypes::{DataType, FieldRef, Schema, SchemaRef};
 use datafusion_common::{
     exec_err,
     tree_node::{Transformed, TransformedResult, TreeNode},
@@ -282,10 +282,13 @@ impl<'a> DefaultPhysicalExprAdapterRewriter<'a> {
             };
         let physical_field = self.physical_file_schema.field(physical_column_index);
 
-        let column = match (
-            column.index() == physical_column_index,
-            logical_field.data_type() == physical_field.data_type(),
-        ) {
+        // A struct column pruned to some of its fields (see `prune_struct_field`)
+        // can be evaluated against the whole physical struct column, as its
+        // fields are accessed by name
+        let types_match = logical_field.data_type() == physical_field.data_type()
+            || is_pruned_struct(logical_field.data_type(), physical_field.data_type());
+
+        let column = match (column.index() == physical_column_index, types_match) {
             // If the column index matches and the data types match, we can use the column as is
             (true, true) => return Ok(Transformed::no(expr)),
             // If the indexes or data types do not match, we need to create a new column expression
@@ -295,7 +298,7 @@ impl<'a> DefaultPhysicalExprAdapterRewriter<'a> {
             }
         };
 
-        if logical_field.data_type() == physical_field.data_type() {
+        if types_match {
             // If the data types match, we can use the column as is
             return Ok(Transformed::yes(Arc::new(column)));
         }
@@ -330,6 +333,21 @@ impl<'a> DefaultPhysicalExprAdapterRewriter<'a> {
     }
 }
 
+/// Returns true if `logical` is a struct type with a subset of the fields of
+/// the struct type `physical`
+fn is_pruned_struct(logical: &DataType, physical: &DataType) -> bool {
+    let (DataType::Struct(logical), DataType::Struct(physical)) = (logical, physical)
+    else {
+        return false;
+    };
+    logical.iter().all(|logical| {
+        physical.find(logical.name()).is_some_and(|(_, physical)| {
+            logical.data_type() == physical.data_type()
+                || is_pruned_struct(logical.data_type(), physical.data_type())
+        })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::expressions::{col, lit};
@@ -495,6 +513,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_rewrite_pruned_struct_column() -> Result<()> {
+        let physical_schema = Schema::new(vec![Field::new_struct(
+            "s",
+            vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, true),
+            ],
+            true,
+        )]);
+        let logical_schema = Schema::new(vec![Field::new_struct(
+            "s",
+            vec![Field::new("b", DataType::Utf8, true)],
+            true,
+        )]);
+
+        let factory = DefaultPhysicalExprAdapterFactory;
+        let adapter = factory.create(Arc::new(logical_schema), Arc::new(physical_schema));
+        let column_expr = Arc::new(Column::new("s", 0)) as Arc<dyn PhysicalExpr>;
+
+        // The fields of the pruned struct are read from the physical struct
+        let result = adapter.rewrite(Arc::clone(&column_expr))?;
+        assert!(std::ptr::eq(
+            column_expr.as_ref() as *const dyn PhysicalExpr,
+            result.as_ref() as *const dyn PhysicalExpr
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_non_nullable_missing_column_error() {
         let physical_schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
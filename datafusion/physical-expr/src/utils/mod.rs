@@ -21,12 +21,13 @@ pub use guarantee::{Guarantee, LiteralGuarantee};
 use std::borrow::Borrow;
 use std::sync::Arc;
 
-use crate::expressions::{BinaryExpr, Column};
+use crate::expressions::{BinaryExpr, Column, Literal};
 use crate::tree_node::ExprContext;
 use crate::PhysicalExpr;
 use crate::PhysicalSortExpr;
+use crate::ScalarFunctionExpr;
 
-use arrow::datatypes::Schema;
+use arrow::datatypes::{DataType, Field, Fields, Schema};
 use datafusion_common::tree_node::{
     Transformed, TransformedResult, TreeNode, TreeNodeRecursion,
 };
@@ -238,6 +239,86 @@ pub fn collect_columns(expr: &Arc<dyn PhysicalExpr>) -> HashSet<Column> {
     columns
 }
 
+/// If `expr` accesses a field nested in a struct column of `schema` through a
+/// chain of `get_field` calls with literal field names, such as
+/// `payload['user']['country']`, returns the column, the names of the fields
+/// from the column to the accessed field, and the accessed field.
+///
+/// Returns `None` for any other expression, including accesses to the values
+/// of map columns, which also use `get_field`.
+pub fn nested_field_access<'a>(
+    expr: &'a Arc<dyn PhysicalExpr>,
+    schema: &'a Schema,
+) -> Option<(&'a Column, Vec<&'a str>, &'a Field)> {
+    let mut path = vec![];
+    let mut expr = expr;
+    let column = loop {
+        if let Some(column) = expr.as_any().downcast_ref::<Column>() {
+            break column;
+        }
+        let function = expr.as_any().downcast_ref::<ScalarFunctionExpr>()?;
+        let [base, name] = function.args() else {
+            return None;
+        };
+        if function.name() != "get_field" {
+            return None;
+        }
+        let name = name.as_any().downcast_ref::<Literal>()?;
+        path.push(name.value().try_as_str().flatten()?);
+        expr = base;
+    };
+    if path.is_empty() {
+        return None;
+    }
+    path.reverse();
+
+    let mut field = schema.field_with_name(column.name()).ok()?;
+    for name in &path {
+        let DataType::Struct(fields) = field.data_type() else {
+            return None;
+        };
+        field = fields.find(name)?.1.as_ref();
+    }
+    Some((column, path, field))
+}
+
+/// The name of a field nested in a struct `column`, its `path` from the
+/// column joined with dots like the paths of Parquet columns, such as
+/// `payload.user.country`
+pub fn nested_field_name(column: &str, path: &[&str]) -> String {
+    std::iter::once(column)
+        .chain(path.iter().copied())
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Prune a struct `field` to only the fields nested in it along `paths`, the
+/// names of the fields from `field` to the accessed fields, keeping the order
+/// of the fields. Accessed fields are kept whole.
+pub fn prune_struct_field<S: AsRef<str>>(field: &Field, paths: &[&[S]]) -> Field {
+    let DataType::Struct(children) = field.data_type() else {
+        return field.clone();
+    };
+    if paths.iter().any(|path| path.is_empty()) {
+        return field.clone();
+    }
+    let children = children
+        .iter()
+        .filter_map(|child| {
+            let child_paths = paths
+                .iter()
+                .filter_map(|path| {
+                    let (name, rest) = path.split_first()?;
+                    (name.as_ref() == child.name()).then_some(rest)
+                })
+                .collect::<Vec<_>>();
+            (!child_paths.is_empty())
+                .then(|| Arc::new(prune_struct_field(child, &child_paths)))
+        })
+        .collect::<Fields>();
+    field.clone().with_data_type(DataType::Struct(children))
+}
+
 /// Re-assign column indices referenced in predicate according to given schema.
 /// This may be helpful when dealing with projections.
 pub fn reassign_predicate_columns(
@@ -561,4 +642,90 @@ pub(crate) mod tests {
         assert_eq!(collect_columns(&expr3), expected);
         Ok(())
     }
+
+    #[test]
+    fn test_nested_field_access() {
+        use crate::planner::logical2physical;
+        use datafusion_functions::core::expr_fn::get_field;
+
+        let user = Field::new_struct(
+            "user",
+            vec![Field::new("country", DataType::Utf8, true)],
+            true,
+        );
+        let schema = Schema::new(vec![
+            Field::new_struct("payload", vec![user], true),
+            Field::new_map(
+                "tags",
+                "entries",
+                Field::new("keys", DataType::Utf8, false),
+                Field::new("values", DataType::Utf8, true),
+                false,
+                true,
+            ),
+        ]);
+
+        let country = get_field(
+            get_field(datafusion_expr::col("payload"), "user"),
+            "country",
+        );
+        let expr = logical2physical(&country, &schema);
+        let (column, path, field) = nested_field_access(&expr, &schema).unwrap();
+        assert_eq!(column, &Column::new("payload", 0));
+        assert_eq!(path, ["user", "country"]);
+        assert_eq!(field.name(), "country");
+        assert_eq!(
+            nested_field_name(column.name(), &path),
+            "payload.user.country"
+        );
+
+        // map values and whole columns are not nested fields
+        let tag = get_field(datafusion_expr::col("tags"), "a");
+        let expr = logical2physical(&tag, &schema);
+        assert!(nested_field_access(&expr, &schema).is_none());
+        let expr = logical2physical(&datafusion_expr::col("payload"), &schema);
+        assert!(nested_field_access(&expr, &schema).is_none());
+    }
+
+    #[test]
+    fn test_prune_struct_field() {
+        let user = Field::new_struct(
+            "user",
+            vec![
+                Field::new("name", DataType::Utf8, true),
+                Field::new("country", DataType::Utf8, true),
+            ],
+            true,
+        );
+        let payload = Field::new_struct(
+            "payload",
+            vec![Field::new("id", DataType::Int64, false), user.clone()],
+            true,
+        );
+
+        let paths: [&[&str]; 1] = [&["user", "country"]];
+        let pruned = prune_struct_field(&payload, &paths);
+        let expected = Field::new_struct(
+            "payload",
+            vec![Field::new_struct(
+                "user",
+                vec![Field::new("country", DataType::Utf8, true)],
+                true,
+            )],
+            true,
+        );
+        assert_eq!(pruned, expected);
+
+        // accessed fields are kept whole, in the original order
+        let paths: [&[&str]; 3] = [&["user"], &["id"], &["user", "name"]];
+        assert_eq!(prune_struct_field(&payload, &paths), payload);
+        let paths: [&[&str]; 1] = [&["user"]];
+        let pruned = prune_struct_field(&payload, &paths);
+        assert_eq!(pruned, Field::new_struct("payload", vec![user], true));
+
+        // unknown fields are ignored
+        let paths: [&[&str]; 1] = [&["other"]];
+        let pruned = prune_struct_field(&payload, &paths);
+        assert_eq!(pruned, Field::new_struct("payload", Fields::empty(), true));
+    }
 }
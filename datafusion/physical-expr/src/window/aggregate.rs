@@ -100,6 +100,9 @@ impl PlainAggregateWindowExpr {
     //  2. Bound is `CurrentRow` while using `Range` units with no order by clause
     //  This results in an invalid range specification. Following PostgreSQL’s convention,
     //  we interpret this as the entire partition being used for the current window frame.
+    //
+    // Frames with an EXCLUDE clause are never constant, since the excluded rows depend on
+    // the current row.
     fn is_window_constant_in_partition(
         order_by: &[PhysicalSortExpr],
         window_frame: &WindowFrame,
@@ -111,7 +114,8 @@ impl PlainAggregateWindowExpr {
             _ => bound.is_unbounded(),
         };
 
-        is_constant_bound(&window_frame.start_bound)
+        !window_frame.has_exclusion()
+            && is_constant_bound(&window_frame.start_bound)
             && is_constant_bound(&window_frame.end_bound)
     }
 }
@@ -152,14 +156,17 @@ impl WindowExpr for PlainAggregateWindowExpr {
         // non-sliding aggregations will never call `retract_batch`, this value
         // can safely increase, and we can remove "old" parts of the state.
         // This enables us to run queries involving UNBOUNDED PRECEDING frames
-        // using bounded memory for suitable aggregations.
+        // using bounded memory for suitable aggregations. Frames with an EXCLUDE
+        // clause are re-aggregated for every row, so they need all rows kept.
         for partition_row in partition_batches.keys() {
             let window_state =
                 window_agg_state.get_mut(partition_row).ok_or_else(|| {
                     DataFusionError::Execution("Cannot find state".to_string())
                 })?;
             let state = &mut window_state.state;
-            if self.window_frame.start_bound.is_unbounded() {
+            if self.window_frame.start_bound.is_unbounded()
+                && !self.window_frame.has_exclusion()
+            {
                 state.window_frame_range.start =
                     state.window_frame_range.end.saturating_sub(1);
             }
@@ -209,7 +216,7 @@ impl WindowExpr for PlainAggregateWindowExpr {
     }
 
     fn uses_bounded_memory(&self) -> bool {
-        !self.window_frame.end_bound.is_unbounded()
+        !self.window_frame.end_bound.is_unbounded() && !self.window_frame.has_exclusion()
     }
 
     fn create_window_fn(&self) -> Result<WindowFn> {
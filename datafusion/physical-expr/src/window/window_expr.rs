@@ -22,17 +22,19 @@ use std::sync::Arc;
 
 use crate::PhysicalExpr;
 
-use arrow::array::{new_empty_array, Array, ArrayRef};
+use arrow::array::{new_empty_array, Array, ArrayRef, UInt64Array};
 use arrow::compute::kernels::sort::SortColumn;
-use arrow::compute::SortOptions;
+use arrow::compute::{take, SortOptions};
 use arrow::datatypes::FieldRef;
 use arrow::record_batch::RecordBatch;
-use datafusion_common::utils::compare_rows;
+use datafusion_common::utils::{compare_rows, get_row_at_idx};
 use datafusion_common::{internal_err, DataFusionError, Result, ScalarValue};
 use datafusion_expr::window_state::{
     PartitionBatchState, WindowAggState, WindowFrameContext, WindowFrameStateGroups,
 };
-use datafusion_expr::{Accumulator, PartitionEvaluator, WindowFrame, WindowFrameBound};
+use datafusion_expr::{
+    Accumulator, PartitionEvaluator, WindowFrame, WindowFrameBound, WindowFrameExclusion,
+};
 use datafusion_physical_expr_common::sort_expr::PhysicalSortExpr;
 
 use indexmap::IndexMap;
@@ -197,6 +199,54 @@ pub trait AggregateWindowExpr: WindowExpr {
     /// for all rows in the partition.
     fn is_constant_in_partition(&self) -> bool;
 
+    /// Calculates the accumulator result for `cur_range`, leaving out the rows
+    /// removed by the `EXCLUDE` clause of the window frame for the row at `idx`.
+    ///
+    /// Excluded rows punch a hole into the frame that moves along with the
+    /// current row, so the result cannot be maintained incrementally and is
+    /// computed from scratch with a fresh accumulator instead.
+    fn get_aggregate_result_with_exclusion(
+        &self,
+        cur_range: &Range<usize>,
+        value_slice: &[ArrayRef],
+        order_bys: &[ArrayRef],
+        idx: usize,
+    ) -> Result<ScalarValue> {
+        let exclusion = self.get_window_frame().exclusion;
+        let peers = match exclusion {
+            WindowFrameExclusion::NoOthers | WindowFrameExclusion::CurrentRow => {
+                idx..idx + 1
+            }
+            WindowFrameExclusion::Group | WindowFrameExclusion::Ties => {
+                peer_range(order_bys, cur_range, idx)?
+            }
+        };
+        let is_excluded = |row: usize| match exclusion {
+            WindowFrameExclusion::NoOthers => false,
+            WindowFrameExclusion::CurrentRow | WindowFrameExclusion::Group => {
+                peers.contains(&row)
+            }
+            WindowFrameExclusion::Ties => row != idx && peers.contains(&row),
+        };
+        let indices = UInt64Array::from_iter_values(
+            cur_range
+                .clone()
+                .filter(|row| !is_excluded(*row))
+                .map(|row| row as u64),
+        );
+        let values = value_slice
+            .iter()
+            .map(|v| take(v, &indices, None))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut accumulator = self.get_accumulator()?;
+        self.get_aggregate_result_inside_range(
+            &(0..0),
+            &(0..indices.len()),
+            &values,
+            &mut accumulator,
+        )
+    }
+
     /// Evaluates the window function against the batch.
     fn aggregate_evaluate(&self, batch: &RecordBatch) -> Result<ArrayRef> {
         let mut accumulator = self.get_accumulator()?;
@@ -311,6 +361,7 @@ pub trait AggregateWindowExpr: WindowExpr {
         let length = values[0].len();
         let mut row_wise_results: Vec<ScalarValue> = vec![];
         let is_causal = self.get_window_frame().is_causal();
+        let has_exclusion = self.get_window_frame().has_exclusion();
         while idx < length {
             // Start search from the last_range. This squeezes searched range.
             let cur_range =
@@ -329,12 +380,18 @@ pub trait AggregateWindowExpr: WindowExpr {
             {
                 break;
             }
-            let value = self.get_aggregate_result_inside_range(
-                last_range,
-                &cur_range,
-                &values,
-                accumulator,
-            )?;
+            let value = if has_exclusion {
+                self.get_aggregate_result_with_exclusion(
+                    &cur_range, &values, &order_bys, idx,
+                )?
+            } else {
+                self.get_aggregate_result_inside_range(
+                    last_range,
+                    &cur_range,
+                    &values,
+                    accumulator,
+                )?
+            };
             // Update last range
             *last_range = cur_range;
             row_wise_results.push(value);
@@ -351,6 +408,27 @@ pub trait AggregateWindowExpr: WindowExpr {
     }
 }
 
+/// Returns the range of peers of the row at `idx`, i.e. the contiguous rows
+/// that have the same ORDER BY values. Since only peers inside `cur_range`
+/// matter, the search does not extend past its boundaries (but always covers
+/// `idx`, which may fall outside `cur_range`).
+fn peer_range(
+    order_bys: &[ArrayRef],
+    cur_range: &Range<usize>,
+    idx: usize,
+) -> Result<Range<usize>> {
+    let current = get_row_at_idx(order_bys, idx)?;
+    let mut start = idx;
+    while start > cur_range.start && get_row_at_idx(order_bys, start - 1)? == current {
+        start -= 1;
+    }
+    let mut end = idx + 1;
+    while end < cur_range.end && get_row_at_idx(order_bys, end)? == current {
+        end += 1;
+    }
+    Ok(start..end)
+}
+
 /// Determines whether the end bound calculation for a window frame context is
 /// safe, meaning that the end bound stays the same, regardless of future data,
 /// based on the current sort expressions and ORDER BY columns. This function
@@ -0,0 +1,549 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! The [`JoinReorder`] rule reorders chains of inner equi-joins, using the
+//! statistics of their inputs to estimate the sizes of intermediate results.
+
+use std::sync::Arc;
+
+use crate::PhysicalOptimizerRule;
+
+use datafusion_common::config::ConfigOptions;
+use datafusion_common::error::Result;
+use datafusion_common::tree_node::{
+    Transformed, TransformedResult, TreeNode, TreeNodeRecursion,
+};
+use datafusion_common::{internal_datafusion_err, JoinType, NullEquality};
+use datafusion_physical_expr::expressions::Column;
+use datafusion_physical_expr::PhysicalExprRef;
+use datafusion_physical_plan::joins::{HashJoinExec, PartitionMode};
+use datafusion_physical_plan::projection::ProjectionExec;
+use datafusion_physical_plan::{ExecutionPlan, ExecutionPlanProperties};
+
+/// The maximum number of inputs of the joins the rule reorders
+const MAX_INPUTS: usize = 64;
+
+/// The maximum number of inputs of the joins ordered by dynamic programming,
+/// whatever the configured threshold, as its cost grows as `3^n`
+const MAX_DP_INPUTS: usize = 14;
+
+/// The [`JoinReorder`] rule reorders chains of inner [`HashJoinExec`]s whose
+/// join keys are columns, possibly separated by projections of columns.
+///
+/// The join conditions are grouped into equivalence classes of columns, so
+/// that any two inputs with columns in the same class can be joined. The size
+/// of a join of inputs is estimated from their row counts, assuming that the
+/// columns of a class are independent and their values uniformly distributed:
+/// for each class with columns of at least two of the joined inputs, the
+/// product of the row counts is divided by the distinct counts of all but the
+/// class's column with the fewest distinct values. A class may hold several
+/// columns of an input, which are all made equal when it is joined.
+///
+/// The cheapest join order, with the smallest sum of the estimated sizes of
+/// the joins, is found by dynamic programming over the subsets of the inputs
+/// for joins of up to `join_reordering_dp_threshold` inputs, and greedily for
+/// larger joins, by repeatedly joining the pair of inputs or joins with the
+/// smallest estimated result. The joins are reordered only if the new order is
+/// cheaper than the original one, and a projection restores the original
+/// order of the columns.
+#[derive(Default, Debug)]
+pub struct JoinReorder {}
+
+impl JoinReorder {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl PhysicalOptimizerRule for JoinReorder {
+    fn optimize(
+        &self,
+        plan: Arc<dyn ExecutionPlan>,
+        config: &ConfigOptions,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        if !config.optimizer.enable_join_reordering {
+            return Ok(plan);
+        }
+        reorder_joins(plan, config.optimizer.join_reordering_dp_threshold)
+    }
+
+    fn name(&self) -> &str {
+        "join_reorder"
+    }
+
+    fn schema_check(&self) -> bool {
+        true
+    }
+}
+
+fn reorder_joins(
+    plan: Arc<dyn ExecutionPlan>,
+    dp_threshold: usize,
+) -> Result<Arc<dyn ExecutionPlan>> {
+    plan.transform_down(|plan| {
+        let Some(graph) = JoinGraph::try_new(&plan) else {
+            return Ok(Transformed::no(plan));
+        };
+        let Some(tree) = graph.cheapest_order(dp_threshold)? else {
+            // smaller chains of joins below may still be reordered
+            return Ok(Transformed::no(plan));
+        };
+        // the inputs of the joins may contain other joins to reorder
+        let inputs = graph
+            .inputs
+            .iter()
+            .map(|input| reorder_joins(Arc::clone(input), dp_threshold))
+            .collect::<Result<Vec<_>>>()?;
+        let reordered = graph.with_inputs(inputs).build(&tree, &plan)?;
+        Ok(Transformed::new(reordered, true, TreeNodeRecursion::Jump))
+    })
+    .data()
+}
+
+/// A column of an input of a [`JoinGraph`], as the index of the input and the
+/// index of the column in its schema
+type InputColumn = (usize, usize);
+
+/// An order in which to join the inputs of a [`JoinGraph`]
+#[derive(Debug, Clone, PartialEq)]
+enum JoinTree {
+    Input(usize),
+    Join(Box<JoinTree>, Box<JoinTree>),
+}
+
+impl JoinTree {
+    fn shift(self, offset: usize) -> Self {
+        match self {
+            JoinTree::Input(input) => JoinTree::Input(input + offset),
+            JoinTree::Join(left, right) => JoinTree::Join(
+                Box::new(left.shift(offset)),
+                Box::new(right.shift(offset)),
+            ),
+        }
+    }
+}
+
+/// A chain of inner equi-joins flattened into its inputs and join conditions
+#[derive(Debug)]
+struct JoinGraph {
+    inputs: Vec<Arc<dyn ExecutionPlan>>,
+    /// The output columns of the joins
+    columns: Vec<InputColumn>,
+    /// The pairs of columns the joins require to be equal
+    conditions: Vec<(InputColumn, InputColumn)>,
+    /// The original join order
+    tree: JoinTree,
+    partition_mode: PartitionMode,
+}
+
+impl JoinGraph {
+    /// Flattens the joins of `plan`, returning `None` if it does not join at
+    /// least three bounded inputs
+    fn try_new(plan: &Arc<dyn ExecutionPlan>) -> Option<Self> {
+        Self::flatten(plan).filter(|graph| {
+            (3..=MAX_INPUTS).contains(&graph.inputs.len())
+                && !graph
+                    .inputs
+                    .iter()
+                    .any(|input| input.boundedness().is_unbounded())
+        })
+    }
+
+    fn input(plan: &Arc<dyn ExecutionPlan>) -> Self {
+        Self {
+            inputs: vec![Arc::clone(plan)],
+            columns: (0..plan.schema().fields().len()).map(|c| (0, c)).collect(),
+            conditions: vec![],
+            tree: JoinTree::Input(0),
+            partition_mode: PartitionMode::Auto,
+        }
+    }
+
+    fn flatten(plan: &Arc<dyn ExecutionPlan>) -> Option<Self> {
+        if let Some(projection) = plan.as_any().downcast_ref::<ProjectionExec>() {
+            let mut graph = Self::flatten(projection.input())?;
+            graph.columns = projection
+                .expr()
+                .iter()
+                .map(|(expr, _)| {
+                    let column = expr.as_any().downcast_ref::<Column>()?;
+                    Some(graph.columns[column.index()])
+                })
+                .collect::<Option<_>>()?;
+            return Some(graph);
+        }
+
+        let join = plan.as_any().downcast_ref::<HashJoinExec>()?;
+        if join.join_type() != &JoinType::Inner
+            || join.filter().is_some()
+            || join.null_equality() != NullEquality::NullEqualsNothing
+        {
+            return None;
+        }
+        let left = Self::flatten(join.left()).unwrap_or_else(|| Self::input(join.left()));
+        let right =
+            Self::flatten(join.right()).unwrap_or_else(|| Self::input(join.right()));
+        let offset = left.inputs.len();
+        let shift = |(input, column): InputColumn| (input + offset, column);
+
+        let on = join
+            .on()
+            .iter()
+            .map(|(l, r)| {
+                let l = l.as_any().downcast_ref::<Column>()?;
+                let r = r.as_any().downcast_ref::<Column>()?;
+                Some((left.columns[l.index()], shift(right.columns[r.index()])))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let mut columns = left.columns;
+        columns.extend(right.columns.into_iter().map(shift));
+        if let Some(projection) = &join.projection {
+            columns = projection.iter().map(|i| columns[*i]).collect();
+        }
+        let mut conditions = left.conditions;
+        conditions.extend(
+            right
+                .conditions
+                .into_iter()
+                .map(|(l, r)| (shift(l), shift(r))),
+        );
+        conditions.extend(on);
+        let mut inputs = left.inputs;
+        inputs.extend(right.inputs);
+
+        Some(Self {
+            inputs,
+            columns,
+            conditions,
+            tree: JoinTree::Join(Box::new(left.tree), Box::new(right.tree.shift(offset))),
+            partition_mode: *join.partition_mode(),
+        })
+    }
+
+    fn with_inputs(self, inputs: Vec<Arc<dyn ExecutionPlan>>) -> Self {
+        Self { inputs, ..self }
+    }
+
+    /// Groups the columns of the join conditions into classes of columns
+    /// which are all equal
+    fn equivalence_classes(&self) -> Vec<Vec<InputColumn>> {
+        let mut classes: Vec<Vec<InputColumn>> = vec![];
+        for (a, b) in &self.conditions {
+            let find = |column| classes.iter().position(|class| class.contains(column));
+            match (find(a), find(b)) {
+                (Some(i), Some(j)) if i == j => {}
+                (Some(i), Some(j)) => {
+                    let merged = classes.swap_remove(i.max(j));
+                    classes[i.min(j)].extend(merged);
+                }
+                (Some(i), None) => classes[i].push(*b),
+                (None, Some(j)) => classes[j].push(*a),
+                (None, None) => classes.push(vec![*a, *b]),
+            }
+        }
+        classes
+    }
+
+    /// Returns the cheapest join order if it is cheaper than the original
+    /// order, or `None` if it is not or the statistics of an input are
+    /// missing
+    fn cheapest_order(&self, dp_threshold: usize) -> Result<Option<JoinTree>> {
+        let Some(estimator) = CardinalityEstimator::try_new(self)? else {
+            return Ok(None);
+        };
+        let tree = if self.inputs.len() <= dp_threshold.min(MAX_DP_INPUTS) {
+            estimator.dynamic_programming_order()
+        } else {
+            estimator.greedy_order()
+        };
+        let original_cost = estimator.cost(&self.tree).1;
+        Ok(tree.filter(|tree| estimator.cost(tree).1 < original_cost))
+    }
+
+    /// Builds the joins of `tree`, projecting their columns to the schema of
+    /// the original `plan`
+    fn build(
+        &self,
+        tree: &JoinTree,
+        plan: &Arc<dyn ExecutionPlan>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        let classes = self.equivalence_classes();
+        let (joins, columns) = self.build_joins(tree, &classes)?;
+        let exprs = self
+            .columns
+            .iter()
+            .zip(plan.schema().fields())
+            .map(|(column, field)| {
+                let index = columns
+                    .iter()
+                    .position(|c| c == column)
+                    .ok_or_else(|| internal_datafusion_err!("Join column not found"))?;
+                Ok((column_expr(&joins, index), field.name().clone()))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Arc::new(ProjectionExec::try_new(exprs, joins)?))
+    }
+
+    fn build_joins(
+        &self,
+        tree: &JoinTree,
+        classes: &[Vec<InputColumn>],
+    ) -> Result<(Arc<dyn ExecutionPlan>, Vec<InputColumn>)> {
+        match tree {
+            JoinTree::Input(input) => {
+                let plan = Arc::clone(&self.inputs[*input]);
+                let columns = (0..plan.schema().fields().len())
+                    .map(|c| (*input, c))
+                    .collect();
+                Ok((plan, columns))
+            }
+            JoinTree::Join(left, right) => {
+                let (left, left_columns) = self.build_joins(left, classes)?;
+                let (right, right_columns) = self.build_joins(right, classes)?;
+                // the columns of a class on both sides are all made equal to
+                // a column of the other side, as the columns of an input are
+                // not equal until it is joined with another input of the class
+                let on = classes
+                    .iter()
+                    .flat_map(|class| {
+                        let position = |columns: &[InputColumn]| {
+                            class
+                                .iter()
+                                .filter_map(|c| {
+                                    columns.iter().position(|column| column == c)
+                                })
+                                .collect::<Vec<_>>()
+                        };
+                        let (l, r) = (position(&left_columns), position(&right_columns));
+                        let (Some(&l0), Some(&r0)) = (l.first(), r.first()) else {
+                            return vec![];
+                        };
+                        l.iter()
+                            .map(|&l| (l, r0))
+                            .chain(r.iter().skip(1).map(|&r| (l0, r)))
+                            .map(|(l, r)| (column_expr(&left, l), column_expr(&right, r)))
+                            .collect()
+                    })
+                    .collect();
+                let join = HashJoinExec::try_new(
+                    left,
+                    right,
+                    on,
+                    None,
+                    &JoinType::Inner,
+                    None,
+                    self.partition_mode,
+                    NullEquality::NullEqualsNothing,
+                )?;
+                let mut columns = left_columns;
+                columns.extend(right_columns);
+                Ok((Arc::new(join), columns))
+            }
+        }
+    }
+}
+
+fn column_expr(plan: &Arc<dyn ExecutionPlan>, index: usize) -> PhysicalExprRef {
+    Arc::new(Column::new(plan.schema().field(index).name(), index))
+}
+
+/// Estimates the sizes of the joins of subsets of the inputs of a
+/// [`JoinGraph`], represented as bit sets
+struct CardinalityEstimator {
+    /// The row counts of the inputs
+    rows: Vec<f64>,
+    /// The equivalence classes of the join columns, with their distinct counts
+    classes: Vec<Vec<(usize, f64)>>,
+}
+
+impl CardinalityEstimator {
+    fn try_new(graph: &JoinGraph) -> Result<Option<Self>> {
+        let mut rows = vec![];
+        let mut statistics = vec![];
+        for input in &graph.inputs {
+            let stats = input.partition_statistics(None)?;
+            let Some(num_rows) = stats.num_rows.get_value() else {
+                return Ok(None);
+            };
+            rows.push(*num_rows as f64);
+            statistics.push(stats);
+        }
+        let classes = graph
+            .equivalence_classes()
+            .into_iter()
+            .map(|class| {
+                class
+                    .into_iter()
+                    .map(|(input, column)| {
+                        // without a distinct count, assume the values are unique
+                        let distinct = statistics[input]
+                            .column_statistics
+                            .get(column)
+                            .and_then(|stats| stats.distinct_count.get_value())
+                            .map_or(rows[input], |d| *d as f64);
+                        (input, distinct.clamp(1.0, rows[input].max(1.0)))
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(Some(Self { rows, classes }))
+    }
+
+    fn cardinality(&self, set: u64) -> f64 {
+        let contains = |input: usize| set & (1 << input) != 0;
+        let mut rows = (0..self.rows.len())
+            .filter(|input| contains(*input))
+            .map(|input| self.rows[input])
+            .product::<f64>();
+        for class in &self.classes {
+            let columns = class
+                .iter()
+                .filter(|(input, _)| contains(*input))
+                .collect::<Vec<_>>();
+            // the columns of a class are only required to be equal once two
+            // of its inputs are joined
+            if columns.iter().all(|(input, _)| *input == columns[0].0) {
+                continue;
+            }
+            let mut distinct = columns
+                .iter()
+                .map(|(_, distinct)| *distinct)
+                .collect::<Vec<_>>();
+            distinct.sort_by(f64::total_cmp);
+            rows /= distinct.iter().skip(1).product::<f64>();
+        }
+        rows
+    }
+
+    /// Returns true if a join condition relates the two sets of inputs
+    fn connected(&self, left: u64, right: u64) -> bool {
+        self.classes.iter().any(|class| {
+            let contains =
+                |set: u64| class.iter().any(|(input, _)| set & (1 << input) != 0);
+            contains(left) && contains(right)
+        })
+    }
+
+    /// Returns the inputs joined by `tree` and the sum of the estimated sizes
+    /// of its joins
+    fn cost(&self, tree: &JoinTree) -> (u64, f64) {
+        match tree {
+            JoinTree::Input(input) => (1 << input, 0.0),
+            JoinTree::Join(left, right) => {
+                let (left, left_cost) = self.cost(left);
+                let (right, right_cost) = self.cost(right);
+                let set = left | right;
+                (set, left_cost + right_cost + self.cardinality(set))
+            }
+        }
+    }
+
+    /// Joins two trees, with the smaller estimated input on the build side
+    fn join(&self, left: (u64, JoinTree), right: (u64, JoinTree)) -> JoinTree {
+        let (left, right) = if self.cardinality(left.0) <= self.cardinality(right.0) {
+            (left.1, right.1)
+        } else {
+            (right.1, left.1)
+        };
+        JoinTree::Join(Box::new(left), Box::new(right))
+    }
+
+    /// Finds the cheapest bushy join order without cross joins, by computing
+    /// the cheapest order of every connected subset of the inputs from the
+    /// cheapest orders of its subsets
+    fn dynamic_programming_order(&self) -> Option<JoinTree> {
+        let n = self.rows.len();
+        let all = (1_u64 << n) - 1;
+        // the cost of the cheapest order of each subset, and the subset on
+        // one side of its last join
+        let mut best: Vec<Option<(f64, u64)>> = vec![None; 1 << n];
+        for input in 0..n {
+            best[1 << input] = Some((0.0, 0));
+        }
+        for set in 1..=all {
+            if set.count_ones() < 2 {
+                continue;
+            }
+            let cardinality = self.cardinality(set);
+            let lowest = set & set.wrapping_neg();
+            // enumerate the splits whose left side contains the lowest input
+            let mut left = (set - 1) & set;
+            while left != 0 {
+                let right = set ^ left;
+                if left & lowest != 0 {
+                    if let (Some((left_cost, _)), Some((right_cost, _))) =
+                        (best[left as usize], best[right as usize])
+                    {
+                        let cost = left_cost + right_cost + cardinality;
+                        let cheaper = best[set as usize].is_none_or(|(c, _)| cost < c);
+                        if cheaper && self.connected(left, right) {
+                            best[set as usize] = Some((cost, left));
+                        }
+                    }
+                }
+                left = (left - 1) & set;
+            }
+        }
+        self.dynamic_programming_tree(&best, all)
+    }
+
+    fn dynamic_programming_tree(
+        &self,
+        best: &[Option<(f64, u64)>],
+        set: u64,
+    ) -> Option<JoinTree> {
+        let (_, left) = best[set as usize]?;
+        if left == 0 {
+            return Some(JoinTree::Input(set.trailing_zeros() as usize));
+        }
+        let right = set ^ left;
+        let left_tree = self.dynamic_programming_tree(best, left)?;
+        let right_tree = self.dynamic_programming_tree(best, right)?;
+        Some(self.join((left, left_tree), (right, right_tree)))
+    }
+
+    /// Joins the pair of connected inputs or joins with the smallest estimated
+    /// result until all the inputs are joined
+    fn greedy_order(&self) -> Option<JoinTree> {
+        let mut trees = (0..self.rows.len())
+            .map(|input| (1_u64 << input, JoinTree::Input(input)))
+            .collect::<Vec<_>>();
+        while trees.len() > 1 {
+            let mut best: Option<(f64, usize, usize)> = None;
+            for i in 0..trees.len() {
+                for j in i + 1..trees.len() {
+                    let (left, right) = (trees[i].0, trees[j].0);
+                    if !self.connected(left, right) {
+                        continue;
+                    }
+                    let cardinality = self.cardinality(left | right);
+                    if best.is_none_or(|(c, _, _)| cardinality < c) {
+                        best = Some((cardinality, i, j));
+                    }
+                }
+            }
+            let (_, i, j) = best?;
+            // `j > i`, so removing `j` does not move `i`
+            let right = trees.swap_remove(j);
+            let left = trees.swap_remove(i);
+            let set = left.0 | right.0;
+            trees.push((set, self.join(left, right)));
+        }
+        trees.pop().map(|(_, tree)| tree)
+    }
+}
@@ -115,7 +115,7 @@ pub(crate) fn supports_swap(join_type: JoinType) -> bool {
 /// join's inputs.
 #[deprecated(since = "45.0.0", note = "use datafusion-functions-nested instead")]
 #[allow(dead_code)]
-pub(crate) fn swap_join_type(join_type: JoinType) -> JoinType {
+pub(crate) fn swap_join_type(join_type: JoinType) -> Result<JoinType> {
     join_type.swap()
 }
 
@@ -32,6 +32,7 @@ pub mod enforce_distribution;
 pub mod enforce_sorting;
 pub mod ensure_coop;
 pub mod filter_pushdown;
+pub mod join_reorder;
 pub mod join_selection;
 pub mod limit_pushdown;
 pub mod limited_distinct_aggregation;
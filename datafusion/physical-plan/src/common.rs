@@ -19,18 +19,26 @@
 
 use std::fs;
 use std::fs::metadata;
+use std::ops::Range;
 use std::sync::Arc;
 
 use super::SendableRecordBatchStream;
 use crate::stream::RecordBatchReceiverStream;
 use crate::{ColumnStatistics, Statistics};
 
-use arrow::array::Array;
-use arrow::datatypes::Schema;
+use arrow::array::{Array, ArrayRef, AsArray, UInt64Array};
+use arrow::compute::kernels::cmp::lt;
+use arrow::compute::{
+    concat, filter, is_not_null, partition, sort, sort_to_indices, take,
+};
+use arrow::datatypes::{DataType, Schema, UInt64Type};
 use arrow::record_batch::RecordBatch;
-use datafusion_common::stats::Precision;
-use datafusion_common::{plan_err, Result};
+use arrow::row::{RowConverter, SortField};
+use datafusion_common::stats::{Histogram, HistogramBucket, MostCommonValues, Precision};
+use datafusion_common::{plan_err, Result, ScalarValue};
 use datafusion_execution::memory_pool::MemoryReservation;
+use datafusion_functions_aggregate_common::hyperloglog::HyperLogLog;
+use datafusion_functions_aggregate_common::min_max::{max_batch, min_batch};
 
 use futures::{StreamExt, TryStreamExt};
 use parking_lot::Mutex;
@@ -178,6 +186,369 @@ pub fn compute_record_batch_statistics(
     }
 }
 
+/// Options of [`compute_table_statistics`]
+#[derive(Debug, Clone, Copy)]
+pub struct TableStatisticsOptions {
+    /// Maximum number of buckets of the histogram of each column
+    pub num_buckets: usize,
+    /// Maximum number of most common values of each column
+    pub num_most_common_values: usize,
+    /// Number of non-null values of each column that are sampled to build
+    /// its histogram and most common values, unless `exact` is set
+    pub sample_size: usize,
+    /// Keep all the values of the columns to compute their statistics
+    /// exactly, instead of sampling them and estimating their distinct count
+    pub exact: bool,
+}
+
+/// Computes the statistics of all the rows of a table from `input`, whose
+/// columns are the `columns` of the table `schema`, as done by
+/// `ANALYZE TABLE`.
+///
+/// The rows are read once, as they arrive. For each column with a sortable
+/// type, the null count, minimum and maximum are computed exactly, the
+/// distinct count is estimated with a [`HyperLogLog`] sketch, and an
+/// equi-depth [`Histogram`] of at most `num_buckets` buckets and at most
+/// `num_most_common_values` [`MostCommonValues`] are built from a uniform
+/// sample of `sample_size` of its non-null values. All of them are exact if
+/// the column has no more non-null values than that, or if `exact` is set.
+/// Only the null count of the other columns of `columns` is computed, and
+/// the statistics of the columns that are not in `columns` are unknown.
+///
+/// The sketches and samples are accounted in `reservation`, and an error is
+/// returned if they don't fit in memory.
+pub async fn compute_table_statistics(
+    mut input: SendableRecordBatchStream,
+    schema: &Schema,
+    columns: &[usize],
+    options: TableStatisticsOptions,
+    mut reservation: MemoryReservation,
+) -> Result<Statistics> {
+    let sample_size = if options.exact {
+        usize::MAX
+    } else {
+        options.sample_size.max(1)
+    };
+    let mut accumulators = columns
+        .iter()
+        .map(|&index| {
+            ColumnStatisticsAccumulator::try_new(
+                schema.field(index).data_type(),
+                sample_size,
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let accumulators_size = |accumulators: &[ColumnStatisticsAccumulator]| {
+        accumulators
+            .iter()
+            .map(ColumnStatisticsAccumulator::size)
+            .sum::<usize>()
+    };
+    reservation.try_resize(accumulators_size(&accumulators))?;
+
+    let mut num_rows = 0;
+    while let Some(batch) = input.next().await {
+        let batch = batch?;
+        let keys = sample_keys(num_rows, batch.num_rows());
+        num_rows += batch.num_rows();
+        for (accumulator, array) in accumulators.iter_mut().zip(batch.columns()) {
+            accumulator.update(array, &keys)?;
+        }
+        reservation.try_resize(accumulators_size(&accumulators))?;
+    }
+
+    let mut column_statistics = Statistics::unknown_column(schema);
+    for (&index, accumulator) in columns.iter().zip(accumulators) {
+        // the values of the sample are sorted into a copy of it
+        let size = accumulator.size();
+        reservation.try_grow(size)?;
+        column_statistics[index] =
+            accumulator.evaluate(options.num_buckets, options.num_most_common_values)?;
+        reservation.shrink(2 * size);
+    }
+
+    Ok(Statistics {
+        num_rows: Precision::Exact(num_rows),
+        // only the analyzed columns are read
+        total_byte_size: Precision::Absent,
+        column_statistics,
+    })
+}
+
+/// Seed of the hashes of the positions of the rows, which decide the rows
+/// that are sampled
+const SAMPLE_SEED: ahash::RandomState = ahash::RandomState::with_seeds(
+    0x3c5a_6b93_d1f2_0e47,
+    0x9e37_79b9_7f4a_7c15,
+    0x1f83_d9ab_fb41_bd6b,
+    0x5be0_cd19_137e_2179,
+);
+
+/// Returns the sampling keys of the rows at positions `offset..offset + len`
+/// of the table.
+///
+/// The keys are hashes of the positions, so keeping the rows with the
+/// smallest keys samples them uniformly, and in a deterministic way.
+fn sample_keys(offset: usize, len: usize) -> ArrayRef {
+    Arc::new(UInt64Array::from_iter_values(
+        (offset..offset + len).map(|position| SAMPLE_SEED.hash_one(position)),
+    ))
+}
+
+/// Accumulates the statistics of a column of a table as its rows arrive
+struct ColumnStatisticsAccumulator {
+    null_count: usize,
+    /// Converts the values to rows for [`Self::distinct`], if the minimum,
+    /// maximum, distinct count and histogram of the column are computed
+    converter: Option<RowConverter>,
+    min_value: Option<ScalarValue>,
+    max_value: Option<ScalarValue>,
+    /// The number of non-null values
+    num_values: usize,
+    distinct: HyperLogLog<[u8]>,
+    /// The maximum number of values of the sample, which is exceeded until
+    /// the sample is shrunk
+    sample_size: usize,
+    /// The sampled non-null values, and the keys of their rows
+    sample: Vec<ArrayRef>,
+    keys: Vec<ArrayRef>,
+    sample_len: usize,
+    /// Once the sample has been shrunk, only the values of the rows with a
+    /// smaller key are sampled
+    max_key: Option<u64>,
+}
+
+impl ColumnStatisticsAccumulator {
+    fn try_new(data_type: &DataType, sample_size: usize) -> Result<Self> {
+        let converter = is_sortable(data_type)
+            .then(|| RowConverter::new(vec![SortField::new(data_type.clone())]))
+            .transpose()?;
+        Ok(Self {
+            null_count: 0,
+            converter,
+            min_value: None,
+            max_value: None,
+            num_values: 0,
+            distinct: HyperLogLog::new(),
+            sample_size,
+            sample: vec![],
+            keys: vec![],
+            sample_len: 0,
+            max_key: None,
+        })
+    }
+
+    /// Adds the values of `array`, whose rows have the sampling `keys`
+    fn update(&mut self, array: &ArrayRef, keys: &ArrayRef) -> Result<()> {
+        self.null_count += array.logical_null_count();
+        let Some(converter) = &self.converter else {
+            return Ok(());
+        };
+
+        let present = is_not_null(array)?;
+        let values = filter(array, &present)?;
+        if values.is_empty() {
+            return Ok(());
+        }
+        self.num_values += values.len();
+        let min_value = min_batch(&values)?;
+        if self.min_value.as_ref().is_none_or(|min| min_value < *min) {
+            self.min_value = Some(min_value);
+        }
+        let max_value = max_batch(&values)?;
+        if self.max_value.as_ref().is_none_or(|max| max_value > *max) {
+            self.max_value = Some(max_value);
+        }
+        for row in converter.convert_columns(&[Arc::clone(&values)])?.iter() {
+            self.distinct.add(row.as_ref());
+        }
+
+        let keys = filter(keys, &present)?;
+        let (values, keys) = match self.max_key {
+            Some(max_key) => {
+                let sampled = lt(&keys, &UInt64Array::new_scalar(max_key))?;
+                (filter(&values, &sampled)?, filter(&keys, &sampled)?)
+            }
+            None => (values, keys),
+        };
+        if values.is_empty() {
+            return Ok(());
+        }
+        self.sample_len += values.len();
+        self.sample.push(values);
+        self.keys.push(keys);
+        // shrinking the sample only once it doubled amortizes its cost
+        if self.sample_len > self.sample_size.saturating_mul(2) {
+            self.shrink_sample()?;
+        }
+        Ok(())
+    }
+
+    /// Keeps the `sample_size` sampled values with the smallest keys
+    fn shrink_sample(&mut self) -> Result<()> {
+        let keys = concat(&self.keys.iter().map(|k| k.as_ref()).collect::<Vec<_>>())?;
+        let values = concat(&self.sample.iter().map(|v| v.as_ref()).collect::<Vec<_>>())?;
+        let indices = sort_to_indices(&keys, None, Some(self.sample_size))?;
+        let keys = take(&keys, &indices, None)?;
+        self.max_key = Some(keys.as_primitive::<UInt64Type>().value(keys.len() - 1));
+        self.sample = vec![take(&values, &indices, None)?];
+        self.keys = vec![keys];
+        self.sample_len = indices.len();
+        Ok(())
+    }
+
+    /// The memory used by the accumulator, in bytes
+    fn size(&self) -> usize {
+        size_of_val(self)
+            + self
+                .converter
+                .as_ref()
+                .map(RowConverter::size)
+                .unwrap_or_default()
+            + self
+                .sample
+                .iter()
+                .chain(&self.keys)
+                .map(|array| array.get_array_memory_size())
+                .sum::<usize>()
+    }
+
+    /// Computes the statistics of all the values of the column
+    fn evaluate(
+        mut self,
+        num_buckets: usize,
+        num_most_common_values: usize,
+    ) -> Result<ColumnStatistics> {
+        let statistics = ColumnStatistics::new_unknown()
+            .with_null_count(Precision::Exact(self.null_count));
+        if self.converter.is_none() {
+            return Ok(statistics);
+        }
+        if self.sample_len > self.sample_size {
+            self.shrink_sample()?;
+        }
+        let (Some(min_value), Some(max_value)) = (self.min_value, self.max_value) else {
+            return Ok(statistics.with_distinct_count(Precision::Exact(0)));
+        };
+
+        let sample = concat(&self.sample.iter().map(|v| v.as_ref()).collect::<Vec<_>>())?;
+        let values = sort(&sample, None)?;
+        let runs = partition(&[Arc::clone(&values)])?.ranges();
+        let mut buckets = equi_depth_histogram(&values, &runs, num_buckets)?;
+        let mut common = most_common_values(&values, &runs, num_most_common_values)?;
+        let distinct_count = if self.max_key.is_none() {
+            // the sample holds all the values
+            Precision::Exact(runs.len())
+        } else {
+            // the counts of the sample are scaled to all the values
+            let scale = self.num_values as f64 / values.len() as f64;
+            let distinct_count = self.distinct.count().clamp(runs.len(), self.num_values);
+            let distinct_scale = distinct_count as f64 / runs.len() as f64;
+            for bucket in &mut buckets {
+                bucket.count = ((bucket.count as f64 * scale).round() as usize).max(1);
+                bucket.distinct_count = ((bucket.distinct_count as f64 * distinct_scale)
+                    .round() as usize)
+                    .clamp(1, bucket.count);
+            }
+            for (_, count) in &mut common {
+                *count = (*count as f64 * scale).round() as usize;
+            }
+            Precision::Inexact(distinct_count)
+        };
+        // the sample may miss the smallest and largest values
+        if let Some(first) = buckets.first_mut() {
+            first.lower = min_value.clone();
+        }
+        if let Some(last) = buckets.last_mut() {
+            last.upper = max_value.clone();
+        }
+
+        let most_common_values = if common.is_empty() {
+            None
+        } else {
+            Some(Arc::new(MostCommonValues::try_new(common)?))
+        };
+        Ok(statistics
+            .with_min_value(Precision::Exact(min_value))
+            .with_max_value(Precision::Exact(max_value))
+            .with_distinct_count(distinct_count)
+            .with_histogram(Some(Arc::new(Histogram::try_new(buckets)?)))
+            .with_most_common_values(most_common_values))
+    }
+}
+
+/// Returns the at most `limit` most common of the sorted non-null `values`,
+/// whose runs of equal values are `runs`, with their number of occurrences.
+///
+/// Only the values that occur more than once and more often than the average
+/// value are kept, as the histogram already describes the other ones well.
+fn most_common_values(
+    values: &ArrayRef,
+    runs: &[Range<usize>],
+    limit: usize,
+) -> Result<Vec<(ScalarValue, usize)>> {
+    let average = values.len() / runs.len().max(1);
+    let mut common = runs
+        .iter()
+        .filter(|run| run.len() > 1 && run.len() > average)
+        .collect::<Vec<_>>();
+    // the most frequent first, and the smallest values first on ties
+    common.sort_by(|a, b| b.len().cmp(&a.len()).then(a.start.cmp(&b.start)));
+    common.truncate(limit);
+    common
+        .into_iter()
+        .map(|run| Ok((ScalarValue::try_from_array(values, run.start)?, run.len())))
+        .collect()
+}
+
+/// Builds the buckets of an equi-depth histogram of at most `num_buckets`
+/// buckets from the sorted non-null `values`, whose runs of equal values are
+/// `runs`.
+///
+/// A bucket is closed once it holds `values.len() / num_buckets` values
+/// (rounded up), so that the runs are never split between buckets.
+fn equi_depth_histogram(
+    values: &ArrayRef,
+    runs: &[Range<usize>],
+    num_buckets: usize,
+) -> Result<Vec<HistogramBucket>> {
+    let depth = values.len().div_ceil(num_buckets.max(1));
+    let mut buckets = vec![];
+    let mut first_run = 0;
+    let mut count = 0;
+    for (index, run) in runs.iter().enumerate() {
+        count += run.len();
+        if count >= depth || index == runs.len() - 1 {
+            buckets.push(HistogramBucket {
+                lower: ScalarValue::try_from_array(values, runs[first_run].start)?,
+                upper: ScalarValue::try_from_array(values, run.start)?,
+                count,
+                distinct_count: index + 1 - first_run,
+            });
+            first_run = index + 1;
+            count = 0;
+        }
+    }
+    Ok(buckets)
+}
+
+/// Returns true if the minimum, maximum, distinct count and histogram of
+/// the values of `data_type` can be computed
+fn is_sortable(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Interval(_) => false,
+        DataType::Boolean
+        | DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Utf8View
+        | DataType::Binary
+        | DataType::LargeBinary
+        | DataType::BinaryView
+        | DataType::FixedSizeBinary(_) => true,
+        data_type => data_type.is_numeric() || data_type.is_temporal(),
+    }
+}
+
 /// Checks if the given projection is valid for the given schema.
 pub fn can_project(
     schema: &arrow::datatypes::SchemaRef,
@@ -262,6 +633,8 @@ mod tests {
                     min_value: Precision::Absent,
                     sum_value: Precision::Absent,
                     null_count: Precision::Exact(0),
+                    histogram: None,
+                    most_common_values: None,
                 },
                 ColumnStatistics {
                     distinct_count: Precision::Absent,
@@ -269,6 +642,8 @@ mod tests {
                     min_value: Precision::Absent,
                     sum_value: Precision::Absent,
                     null_count: Precision::Exact(0),
+                    histogram: None,
+                    most_common_values: None,
                 },
             ],
         };
@@ -302,6 +677,8 @@ mod tests {
                 min_value: Precision::Absent,
                 sum_value: Precision::Absent,
                 null_count: Precision::Exact(3),
+                histogram: None,
+                most_common_values: None,
             }],
         };
 
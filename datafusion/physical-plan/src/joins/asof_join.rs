@@ -0,0 +1,910 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the ASOF join plan, which matches every left row with the closest
+//! right row according to an inequality "match condition"
+
+use std::any::Any;
+use std::cmp::Ordering;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use super::utils::{
+    build_join_schema, check_join_is_valid, estimate_join_statistics,
+    symmetric_join_output_partitioning, BuildProbeJoinMetrics,
+};
+use super::JoinOn;
+use crate::execution_plan::{boundedness_from_children, EmissionType};
+use crate::expressions::PhysicalSortExpr;
+use crate::metrics::{ExecutionPlanMetricsSet, MetricsSet};
+use crate::spill::get_record_batch_memory_size;
+use crate::stream::RecordBatchStreamAdapter;
+use crate::{
+    DisplayAs, DisplayFormatType, Distribution, ExecutionPlan, ExecutionPlanProperties,
+    PhysicalExpr, PlanProperties, SendableRecordBatchStream, Statistics,
+};
+
+use arrow::array::{new_null_array, Array, ArrayRef, RecordBatch, UInt32Array};
+use arrow::compute::{interleave, take, SortOptions};
+use arrow::datatypes::SchemaRef;
+use arrow::row::{Row, RowConverter, Rows, SortField};
+use datafusion_common::{
+    internal_err, plan_err, JoinSide, JoinType, NullEquality, Result,
+};
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
+use datafusion_execution::TaskContext;
+use datafusion_expr::Operator;
+use datafusion_physical_expr::equivalence::join_equivalence_properties;
+use datafusion_physical_expr::PhysicalExprRef;
+use datafusion_physical_expr_common::sort_expr::{LexOrdering, OrderingRequirements};
+
+use futures::StreamExt;
+
+/// ASOF Join Execution Plan
+///
+/// For every row of the left input, finds at most one row of the right input
+/// with equal `on` keys that is the closest match for the match condition
+/// `left_expr <op> right_expr`, where `<op>` is one of `>=`, `>`, `<=` or `<`:
+///
+/// * `>=` / `>` pick the right row with the greatest value that is still
+///   (strictly) below the left value, i.e. the most recent preceding row.
+/// * `<=` / `<` pick the right row with the smallest value that is still
+///   (strictly) above the left value, i.e. the next following row.
+///
+/// Left rows without a match are emitted with nulls for the right columns, so
+/// the output has exactly one row per left row and preserves the left order.
+///
+/// Both inputs are required to be sorted by `(on keys, match expression)`,
+/// so that the join is a single merge pass over the two inputs. Only the
+/// current batch of the right input and the latest candidate row are kept in
+/// memory; inputs that already satisfy the ordering are not re-sorted.
+#[derive(Debug, Clone)]
+pub struct AsofJoinExec {
+    /// Left (probe) side, streamed through the join
+    left: Arc<dyn ExecutionPlan>,
+    /// Right side, merged with the left side
+    right: Arc<dyn ExecutionPlan>,
+    /// Equi-join keys
+    on: JoinOn,
+    /// Left side of the match condition, evaluated against the left input
+    left_match_expr: PhysicalExprRef,
+    /// Comparison operator of the match condition
+    match_op: Operator,
+    /// Right side of the match condition, evaluated against the right input
+    right_match_expr: PhysicalExprRef,
+    /// Defines the null equality for the join keys
+    null_equality: NullEquality,
+    /// Required ordering of the left input: `(left keys, left_match_expr)`
+    left_sort_exprs: LexOrdering,
+    /// Required ordering of the right input: `(right keys, right_match_expr)`
+    right_sort_exprs: LexOrdering,
+    /// The schema once the join is applied
+    schema: SchemaRef,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+    /// Cache holding plan properties like equivalences, output partitioning etc.
+    cache: PlanProperties,
+}
+
+impl AsofJoinExec {
+    /// Try to create a new [`AsofJoinExec`]
+    pub fn try_new(
+        left: Arc<dyn ExecutionPlan>,
+        right: Arc<dyn ExecutionPlan>,
+        on: JoinOn,
+        left_match_expr: PhysicalExprRef,
+        match_op: Operator,
+        right_match_expr: PhysicalExprRef,
+        null_equality: NullEquality,
+    ) -> Result<Self> {
+        let left_schema = left.schema();
+        let right_schema = right.schema();
+        check_join_is_valid(&left_schema, &right_schema, &on)?;
+
+        if !matches!(
+            match_op,
+            Operator::GtEq | Operator::Gt | Operator::LtEq | Operator::Lt
+        ) {
+            return plan_err!(
+                "ASOF join match condition must use one of >=, >, <= or <, got {match_op}"
+            );
+        }
+        let left_type = left_match_expr.data_type(&left_schema)?;
+        let right_type = right_match_expr.data_type(&right_schema)?;
+        if left_type != right_type {
+            return plan_err!(
+                "ASOF join match condition sides must have the same type, got {left_type} and {right_type}"
+            );
+        }
+
+        let sort_exprs = |keys: Vec<PhysicalExprRef>, match_expr: &PhysicalExprRef| {
+            LexOrdering::new(
+                keys.into_iter()
+                    .chain(std::iter::once(Arc::clone(match_expr)))
+                    .map(|expr| PhysicalSortExpr::new(expr, ASOF_SORT_OPTIONS)),
+            )
+        };
+        let (left_on, right_on): (Vec<_>, Vec<_>) = on.iter().cloned().unzip();
+        let Some(left_sort_exprs) = sort_exprs(left_on, &left_match_expr) else {
+            return plan_err!(
+                "AsofJoinExec requires valid sort expressions for its left side"
+            );
+        };
+        let Some(right_sort_exprs) = sort_exprs(right_on, &right_match_expr) else {
+            return plan_err!(
+                "AsofJoinExec requires valid sort expressions for its right side"
+            );
+        };
+
+        let schema =
+            Arc::new(build_join_schema(&left_schema, &right_schema, &JoinType::AsOf).0);
+        let cache = Self::compute_properties(&left, &right, Arc::clone(&schema), &on)?;
+
+        Ok(Self {
+            left,
+            right,
+            on,
+            left_match_expr,
+            match_op,
+            right_match_expr,
+            null_equality,
+            left_sort_exprs,
+            right_sort_exprs,
+            schema,
+            metrics: ExecutionPlanMetricsSet::new(),
+            cache,
+        })
+    }
+
+    /// Left (probe) side of the join
+    pub fn left(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.left
+    }
+
+    /// Right side of the join
+    pub fn right(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.right
+    }
+
+    /// Set of common columns used to join on
+    pub fn on(&self) -> &[(PhysicalExprRef, PhysicalExprRef)] {
+        &self.on
+    }
+
+    /// The match condition as `(left_expr, operator, right_expr)`
+    pub fn match_condition(&self) -> (&PhysicalExprRef, Operator, &PhysicalExprRef) {
+        (&self.left_match_expr, self.match_op, &self.right_match_expr)
+    }
+
+    /// Get null_equality
+    pub fn null_equality(&self) -> NullEquality {
+        self.null_equality
+    }
+
+    /// This function creates the cache object that stores the plan properties such as schema, equivalence properties, ordering, partitioning, etc.
+    fn compute_properties(
+        left: &Arc<dyn ExecutionPlan>,
+        right: &Arc<dyn ExecutionPlan>,
+        schema: SchemaRef,
+        on: &[(PhysicalExprRef, PhysicalExprRef)],
+    ) -> Result<PlanProperties> {
+        let eq_properties = join_equivalence_properties(
+            left.equivalence_properties().clone(),
+            right.equivalence_properties().clone(),
+            &JoinType::AsOf,
+            schema,
+            &[true, false],
+            Some(JoinSide::Left),
+            on,
+        )?;
+
+        let output_partitioning =
+            symmetric_join_output_partitioning(left, right, &JoinType::AsOf)?;
+
+        Ok(PlanProperties::new(
+            eq_properties,
+            output_partitioning,
+            EmissionType::Incremental,
+            boundedness_from_children([left, right]),
+        ))
+    }
+}
+
+impl DisplayAs for AsofJoinExec {
+    fn fmt_as(&self, t: DisplayFormatType, f: &mut Formatter) -> std::fmt::Result {
+        let on = self
+            .on
+            .iter()
+            .map(|(c1, c2)| format!("({c1}, {c2})"))
+            .collect::<Vec<String>>()
+            .join(", ");
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                write!(
+                    f,
+                    "AsofJoinExec: match_condition={} {} {}, on=[{}]",
+                    self.left_match_expr, self.match_op, self.right_match_expr, on
+                )
+            }
+            DisplayFormatType::TreeRender => {
+                writeln!(
+                    f,
+                    "match_condition={} {} {}",
+                    self.left_match_expr, self.match_op, self.right_match_expr
+                )?;
+                if !on.is_empty() {
+                    writeln!(f, "on={on}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for AsofJoinExec {
+    fn name(&self) -> &'static str {
+        "AsofJoinExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn required_input_distribution(&self) -> Vec<Distribution> {
+        if self.on.is_empty() {
+            return vec![Distribution::SinglePartition, Distribution::SinglePartition];
+        }
+        let (left_expr, right_expr) = self
+            .on
+            .iter()
+            .map(|(l, r)| (Arc::clone(l), Arc::clone(r)))
+            .unzip();
+        vec![
+            Distribution::HashPartitioned(left_expr),
+            Distribution::HashPartitioned(right_expr),
+        ]
+    }
+
+    fn required_input_ordering(&self) -> Vec<Option<OrderingRequirements>> {
+        vec![
+            Some(OrderingRequirements::from(self.left_sort_exprs.clone())),
+            Some(OrderingRequirements::from(self.right_sort_exprs.clone())),
+        ]
+    }
+
+    fn maintains_input_order(&self) -> Vec<bool> {
+        vec![true, false]
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        vec![&self.left, &self.right]
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(AsofJoinExec::try_new(
+            Arc::clone(&children[0]),
+            Arc::clone(&children[1]),
+            self.on.clone(),
+            Arc::clone(&self.left_match_expr),
+            self.match_op,
+            Arc::clone(&self.right_match_expr),
+            self.null_equality,
+        )?))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let left_partitions = self.left.output_partitioning().partition_count();
+        let right_partitions = self.right.output_partitioning().partition_count();
+        if left_partitions != right_partitions {
+            return internal_err!(
+                "Invalid AsofJoinExec, partition count mismatch {left_partitions}!={right_partitions},\
+                 consider using RepartitionExec"
+            );
+        }
+
+        let join_metrics = BuildProbeJoinMetrics::new(partition, &self.metrics);
+        let reservation = MemoryConsumer::new(format!("AsofJoinExec[{partition}]"))
+            .register(context.memory_pool());
+
+        let (left_on, right_on): (Vec<_>, Vec<_>) = self.on.iter().cloned().unzip();
+        let right_stream = self.right.execute(partition, Arc::clone(&context))?;
+        let left_stream = self.left.execute(partition, context)?;
+
+        let left_schema = self.left.schema();
+        let key_converter = if left_on.is_empty() {
+            None
+        } else {
+            Some(RowConverter::new(
+                left_on
+                    .iter()
+                    .map(|expr| {
+                        Ok(SortField::new_with_options(
+                            expr.data_type(&left_schema)?,
+                            ASOF_SORT_OPTIONS,
+                        ))
+                    })
+                    .collect::<Result<_>>()?,
+            )?)
+        };
+        let value_converter = RowConverter::new(vec![SortField::new_with_options(
+            self.left_match_expr.data_type(&left_schema)?,
+            ASOF_SORT_OPTIONS,
+        )])?;
+        let null_row = self
+            .right
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| new_null_array(field.data_type(), 1))
+            .collect::<Vec<_>>();
+
+        let merger = AsofMerger {
+            right: right_stream,
+            left_on,
+            right_on,
+            left_match_expr: Arc::clone(&self.left_match_expr),
+            right_match_expr: Arc::clone(&self.right_match_expr),
+            match_op: self.match_op,
+            null_equality: self.null_equality,
+            schema: Arc::clone(&self.schema),
+            key_converter,
+            value_converter,
+            current: None,
+            idx: 0,
+            exhausted: false,
+            candidate: None,
+            sources: vec![null_row],
+            current_source: None,
+            candidate_source: None,
+            pending_release: 0,
+            reservation,
+            metrics: join_metrics,
+        };
+        let stream = futures::stream::unfold(
+            (merger, left_stream),
+            |(mut merger, mut left)| async move {
+                let batch = match left.next().await? {
+                    Ok(batch) => batch,
+                    Err(e) => return Some((Err(e), (merger, left))),
+                };
+                let output = merger.join_batch(&batch).await;
+                Some((output, (merger, left)))
+            },
+        );
+
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            Arc::clone(&self.schema),
+            stream,
+        )))
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+
+    fn statistics(&self) -> Result<Statistics> {
+        self.partition_statistics(None)
+    }
+
+    fn partition_statistics(&self, partition: Option<usize>) -> Result<Statistics> {
+        if partition.is_some() {
+            return Ok(Statistics::new_unknown(&self.schema()));
+        }
+        estimate_join_statistics(
+            self.left.partition_statistics(None)?,
+            self.right.partition_statistics(None)?,
+            self.on.clone(),
+            &JoinType::AsOf,
+            &self.schema,
+        )
+    }
+}
+
+/// Sort options of the `(on keys, match expression)` ordering required from
+/// both inputs. The row converters use the same options, so that comparing
+/// encoded rows agrees with the input order.
+const ASOF_SORT_OPTIONS: SortOptions = SortOptions {
+    descending: false,
+    nulls_first: true,
+};
+
+/// A batch of the right input together with its encoded keys and match values
+struct RightBatch {
+    columns: Vec<ArrayRef>,
+    num_rows: usize,
+    /// Row encoded join keys, `None` if the join has no equality keys
+    keys: Option<Rows>,
+    /// Row encoded match values
+    values: Rows,
+    /// Match values, used to skip nulls
+    value_array: ArrayRef,
+    /// Memory reserved for this batch
+    size: usize,
+}
+
+/// Where the latest right row that satisfies the match condition lives
+enum CandidateRow {
+    /// Row index in the current right batch
+    Current(usize),
+    /// Copy of the row, taken when the merge moved past its batch
+    Detached(Vec<ArrayRef>),
+}
+
+/// The latest right row that satisfies a preceding (`>=` / `>`) match
+/// condition, with its encoded join keys
+struct Candidate {
+    key: Vec<u8>,
+    row: CandidateRow,
+}
+
+/// Merges the sorted left input of one partition with its sorted right input.
+///
+/// Within a group of equal keys both inputs are ordered by their match values,
+/// so the right cursor only ever moves forward:
+///
+/// * for `>=` / `>` every right row passed over is a better match than the
+///   previous one, and the latest one is remembered as the candidate.
+/// * for `<=` / `<` right rows below the left value can never match a later
+///   left row, and the row under the cursor is the match.
+struct AsofMerger {
+    right: SendableRecordBatchStream,
+    left_on: Vec<PhysicalExprRef>,
+    right_on: Vec<PhysicalExprRef>,
+    left_match_expr: PhysicalExprRef,
+    right_match_expr: PhysicalExprRef,
+    match_op: Operator,
+    null_equality: NullEquality,
+    schema: SchemaRef,
+    key_converter: Option<RowConverter>,
+    value_converter: RowConverter,
+    /// Current right batch and the cursor within it
+    current: Option<RightBatch>,
+    idx: usize,
+    /// Whether the right input has been fully consumed
+    exhausted: bool,
+    candidate: Option<Candidate>,
+    /// Right columns referenced by the output batch being built. The first
+    /// entry is a single all-null row used for unmatched left rows.
+    sources: Vec<Vec<ArrayRef>>,
+    /// Index in `sources` of the current right batch, if referenced
+    current_source: Option<usize>,
+    /// Index in `sources` of the detached candidate, if referenced
+    candidate_source: Option<usize>,
+    /// Memory of right batches that were passed over but are still
+    /// referenced by the output batch being built
+    pending_release: usize,
+    reservation: MemoryReservation,
+    metrics: BuildProbeJoinMetrics,
+}
+
+impl AsofMerger {
+    /// Join a batch of the left input, advancing the right input as needed
+    async fn join_batch(&mut self, left: &RecordBatch) -> Result<RecordBatch> {
+        self.metrics.input_batches.add(1);
+        self.metrics.input_rows.add(left.num_rows());
+
+        let key_arrays = evaluate(&self.left_on, left)?;
+        let left_keys = self
+            .key_converter
+            .as_ref()
+            .map(|converter| converter.convert_columns(&key_arrays))
+            .transpose()?;
+        let value_array = self
+            .left_match_expr
+            .evaluate(left)?
+            .into_array(left.num_rows())?;
+        let left_values = self
+            .value_converter
+            .convert_columns(&[Arc::clone(&value_array)])?;
+
+        let mut indices = Vec::with_capacity(left.num_rows());
+        for i in 0..left.num_rows() {
+            if value_array.is_null(i)
+                || (self.null_equality == NullEquality::NullEqualsNothing
+                    && key_arrays.iter().any(|a| a.is_null(i)))
+            {
+                indices.push((0, 0));
+                continue;
+            }
+            let key = left_keys.as_ref().map(|keys| keys.row(i));
+            indices.push(self.find_match(key, left_values.row(i)).await?);
+        }
+
+        let timer = self.metrics.join_time.timer();
+        let right_columns = (0..self.sources[0].len())
+            .map(|column_idx| {
+                let arrays = self
+                    .sources
+                    .iter()
+                    .map(|source| source[column_idx].as_ref())
+                    .collect::<Vec<_>>();
+                Ok(interleave(&arrays, &indices)?)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let columns = left
+            .columns()
+            .iter()
+            .cloned()
+            .chain(right_columns)
+            .collect();
+        let output = RecordBatch::try_new(Arc::clone(&self.schema), columns)?;
+
+        // Release the right batches that were only kept alive for this output
+        self.sources.truncate(1);
+        self.current_source = None;
+        self.candidate_source = None;
+        self.reservation.shrink(self.pending_release);
+        self.pending_release = 0;
+        self.metrics.build_mem_used.set(self.reservation.size());
+        timer.done();
+
+        self.metrics.output_batches.add(1);
+        self.metrics.baseline.record_output(output.num_rows());
+        Ok(output)
+    }
+
+    /// Advance the right input past all rows that come before the given left
+    /// row, and return the `(source, row)` of its match in `self.sources`
+    async fn find_match(
+        &mut self,
+        key: Option<Row<'_>>,
+        value: Row<'_>,
+    ) -> Result<(usize, usize)> {
+        let following = matches!(self.match_op, Operator::LtEq | Operator::Lt);
+        // Right rows with these values come before the left row
+        let inclusive = matches!(self.match_op, Operator::GtEq | Operator::Lt);
+
+        let mut found = None;
+        while self.load_right().await? {
+            let Some(right) = self.current.as_ref() else {
+                break;
+            };
+            let idx = self.idx;
+            let key_order = match (&right.keys, key) {
+                (Some(keys), Some(key)) => keys.row(idx).cmp(&key),
+                _ => Ordering::Equal,
+            };
+            if key_order == Ordering::Greater {
+                break;
+            }
+            if key_order == Ordering::Equal && !right.value_array.is_null(idx) {
+                let right_value = right.values.row(idx);
+                let before = if inclusive {
+                    right_value <= value
+                } else {
+                    right_value < value
+                };
+                if !before {
+                    if following {
+                        found = Some(idx);
+                    }
+                    break;
+                }
+                if !following {
+                    let key = right
+                        .keys
+                        .as_ref()
+                        .map(|keys| keys.row(idx).as_ref().to_vec())
+                        .unwrap_or_default();
+                    self.candidate = Some(Candidate {
+                        key,
+                        row: CandidateRow::Current(idx),
+                    });
+                    self.candidate_source = None;
+                }
+            }
+            self.idx += 1;
+        }
+
+        if following {
+            return Ok(match found {
+                Some(row) => (self.current_source(), row),
+                None => (0, 0),
+            });
+        }
+
+        let matches_key = |candidate: &Candidate| match key {
+            Some(key) => candidate.key.as_slice() == key.as_ref(),
+            None => true,
+        };
+        // `Some(None)` if the match is the detached candidate
+        let candidate_row = match &self.candidate {
+            Some(candidate) if matches_key(candidate) => match candidate.row {
+                CandidateRow::Current(row) => Some(Some(row)),
+                CandidateRow::Detached(_) => Some(None),
+            },
+            _ => None,
+        };
+        Ok(match candidate_row {
+            Some(Some(row)) => (self.current_source(), row),
+            Some(None) => (self.detached_candidate_source(), 0),
+            None => (0, 0),
+        })
+    }
+
+    /// Make sure the cursor points at a row of the right input, fetching the
+    /// next batch if needed. Returns `false` once the right input is exhausted.
+    async fn load_right(&mut self) -> Result<bool> {
+        if let Some(current) = &self.current {
+            if self.idx < current.num_rows {
+                return Ok(true);
+            }
+        }
+        if let Some(current) = self.current.take() {
+            self.release(current)?;
+        }
+        while !self.exhausted {
+            let Some(batch) = self.right.next().await.transpose()? else {
+                self.exhausted = true;
+                break;
+            };
+            if batch.num_rows() == 0 {
+                continue;
+            }
+            let timer = self.metrics.build_time.timer();
+            self.metrics.build_input_batches.add(1);
+            self.metrics.build_input_rows.add(batch.num_rows());
+
+            let keys = self
+                .key_converter
+                .as_ref()
+                .map(|converter| {
+                    converter.convert_columns(&evaluate(&self.right_on, &batch)?)
+                })
+                .transpose()?;
+            let value_array = self
+                .right_match_expr
+                .evaluate(&batch)?
+                .into_array(batch.num_rows())?;
+            let values = self
+                .value_converter
+                .convert_columns(&[Arc::clone(&value_array)])?;
+            let size = get_record_batch_memory_size(&batch)
+                + keys.as_ref().map(|keys| keys.size()).unwrap_or_default()
+                + values.size();
+            self.reservation.try_grow(size)?;
+            self.metrics.build_mem_used.set(self.reservation.size());
+            timer.done();
+
+            self.current = Some(RightBatch {
+                num_rows: batch.num_rows(),
+                columns: batch.columns().to_vec(),
+                keys,
+                values,
+                value_array,
+                size,
+            });
+            self.idx = 0;
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    /// Drop a right batch the cursor has moved past. A candidate row in it is
+    /// copied out, and its memory stays reserved while the output batch being
+    /// built still references it.
+    fn release(&mut self, batch: RightBatch) -> Result<()> {
+        if let Some(candidate) = self.candidate.as_mut() {
+            if let CandidateRow::Current(row) = candidate.row {
+                let indices = UInt32Array::from(vec![row as u32]);
+                let columns = batch
+                    .columns
+                    .iter()
+                    .map(|column| Ok(take(column.as_ref(), &indices, None)?))
+                    .collect::<Result<Vec<_>>>()?;
+                candidate.row = CandidateRow::Detached(columns);
+            }
+        }
+        if self.current_source.take().is_some() {
+            self.pending_release += batch.size;
+        } else {
+            self.reservation.shrink(batch.size);
+        }
+        Ok(())
+    }
+
+    /// Index in `self.sources` of the current right batch
+    fn current_source(&mut self) -> usize {
+        if let Some(source) = self.current_source {
+            return source;
+        }
+        let columns = self
+            .current
+            .as_ref()
+            .map(|current| current.columns.clone())
+            .unwrap_or_default();
+        self.sources.push(columns);
+        self.current_source = Some(self.sources.len() - 1);
+        self.sources.len() - 1
+    }
+
+    /// Index in `self.sources` of the detached candidate row
+    fn detached_candidate_source(&mut self) -> usize {
+        if let Some(source) = self.candidate_source {
+            return source;
+        }
+        let columns = match self.candidate.as_ref().map(|candidate| &candidate.row) {
+            Some(CandidateRow::Detached(columns)) => columns.clone(),
+            _ => vec![],
+        };
+        self.sources.push(columns);
+        self.candidate_source = Some(self.sources.len() - 1);
+        self.sources.len() - 1
+    }
+}
+
+fn evaluate(exprs: &[PhysicalExprRef], batch: &RecordBatch) -> Result<Vec<ArrayRef>> {
+    exprs
+        .iter()
+        .map(|e| e.evaluate(batch)?.into_array(batch.num_rows()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common;
+    use crate::test::{build_table_i32, TestMemoryExec};
+
+    use datafusion_common::assert_contains;
+    use datafusion_common::test_util::batches_to_string;
+    use datafusion_physical_expr::expressions::Column;
+
+    use insta::assert_snapshot;
+
+    /// Build an input that is already sorted by its first two columns, split
+    /// into batches of `batch_rows` rows
+    fn build_table(
+        a: (&str, &Vec<i32>),
+        b: (&str, &Vec<i32>),
+        c: (&str, &Vec<i32>),
+        batch_rows: usize,
+    ) -> Arc<dyn ExecutionPlan> {
+        let batch = build_table_i32(a, b, c);
+        let schema = batch.schema();
+        let batches = (0..batch.num_rows())
+            .step_by(batch_rows)
+            .map(|offset| batch.slice(offset, batch_rows.min(batch.num_rows() - offset)))
+            .collect();
+        TestMemoryExec::try_new_exec(&[batches], schema, None).unwrap()
+    }
+
+    async fn join_collect(match_op: Operator) -> Result<Vec<RecordBatch>> {
+        // (key, ts, value)
+        let left = build_table(
+            ("a1", &vec![1, 1, 1, 2, 3]),
+            ("t1", &vec![1, 5, 10, 7, 4]),
+            ("c1", &vec![30, 10, 20, 40, 50]),
+            5,
+        );
+        // One row per batch, so that matches span batch boundaries
+        let right = build_table(
+            ("a2", &vec![1, 1, 1, 2, 2]),
+            ("t2", &vec![3, 8, 10, 7, 9]),
+            ("c2", &vec![100, 200, 400, 300, 500]),
+            1,
+        );
+        let on = vec![(
+            Arc::new(Column::new_with_schema("a1", &left.schema())?) as _,
+            Arc::new(Column::new_with_schema("a2", &right.schema())?) as _,
+        )];
+        let left_ts = Arc::new(Column::new_with_schema("t1", &left.schema())?) as _;
+        let right_ts = Arc::new(Column::new_with_schema("t2", &right.schema())?) as _;
+
+        let join = AsofJoinExec::try_new(
+            left,
+            right,
+            on,
+            left_ts,
+            match_op,
+            right_ts,
+            NullEquality::NullEqualsNothing,
+        )?;
+        let stream = join.execute(0, Arc::new(TaskContext::default()))?;
+        common::collect(stream).await
+    }
+
+    #[tokio::test]
+    async fn asof_join_preceding() -> Result<()> {
+        let batches = join_collect(Operator::GtEq).await?;
+        assert_snapshot!(batches_to_string(&batches), @r"
+        +----+----+----+----+----+-----+
+        | a1 | t1 | c1 | a2 | t2 | c2  |
+        +----+----+----+----+----+-----+
+        | 1  | 1  | 30 |    |    |     |
+        | 1  | 5  | 10 | 1  | 3  | 100 |
+        | 1  | 10 | 20 | 1  | 10 | 400 |
+        | 2  | 7  | 40 | 2  | 7  | 300 |
+        | 3  | 4  | 50 |    |    |     |
+        +----+----+----+----+----+-----+
+        ");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn asof_join_strictly_following() -> Result<()> {
+        let batches = join_collect(Operator::Lt).await?;
+        assert_snapshot!(batches_to_string(&batches), @r"
+        +----+----+----+----+----+-----+
+        | a1 | t1 | c1 | a2 | t2 | c2  |
+        +----+----+----+----+----+-----+
+        | 1  | 1  | 30 | 1  | 3  | 100 |
+        | 1  | 5  | 10 | 1  | 8  | 200 |
+        | 1  | 10 | 20 |    |    |     |
+        | 2  | 7  | 40 | 2  | 9  | 500 |
+        | 3  | 4  | 50 |    |    |     |
+        +----+----+----+----+----+-----+
+        ");
+        Ok(())
+    }
+
+    #[test]
+    fn asof_join_requires_sorted_inputs() -> Result<()> {
+        let left = build_table(("a1", &vec![1]), ("t1", &vec![1]), ("c1", &vec![1]), 1);
+        let right = build_table(("a2", &vec![1]), ("t2", &vec![1]), ("c2", &vec![1]), 1);
+        let on = vec![(
+            Arc::new(Column::new_with_schema("a1", &left.schema())?) as _,
+            Arc::new(Column::new_with_schema("a2", &right.schema())?) as _,
+        )];
+        let left_ts = Arc::new(Column::new_with_schema("t1", &left.schema())?) as _;
+        let right_ts = Arc::new(Column::new_with_schema("t2", &right.schema())?) as _;
+        let join = AsofJoinExec::try_new(
+            left,
+            right,
+            on,
+            left_ts,
+            Operator::GtEq,
+            right_ts,
+            NullEquality::NullEqualsNothing,
+        )?;
+
+        let required = join
+            .required_input_ordering()
+            .into_iter()
+            .map(|req| {
+                let req = req.expect("both inputs must be ordered").into_single();
+                req.iter()
+                    .map(|r| r.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(required, vec!["a1@0 ASC, t1@1 ASC", "a2@0 ASC, t2@1 ASC"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn asof_join_invalid_operator() -> Result<()> {
+        let err = join_collect(Operator::Eq).await.unwrap_err();
+        assert_contains!(
+            err.to_string(),
+            "ASOF join match condition must use one of >=, >, <= or <"
+        );
+        Ok(())
+    }
+}
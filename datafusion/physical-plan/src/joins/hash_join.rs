@@ -44,6 +44,8 @@ use crate::projection::{
     ProjectionExec,
 };
 use crate::spill::get_record_batch_memory_size;
+use crate::spill::spill_manager::SpillManager;
+use crate::stream::{EmptyRecordBatchStream, RecordBatchStreamAdapter};
 use crate::ExecutionPlanProperties;
 use crate::{
     common::can_project,
@@ -57,7 +59,7 @@ use crate::{
         symmetric_join_output_partitioning, BuildProbeJoinMetrics, ColumnIndex,
         JoinFilter, JoinHashMapType, StatefulStreamResult,
     },
-    metrics::{ExecutionPlanMetricsSet, MetricsSet},
+    metrics::{ExecutionPlanMetricsSet, MetricsSet, SpillMetrics},
     DisplayAs, DisplayFormatType, Distribution, ExecutionPlan, Partitioning,
     PlanProperties, RecordBatchStream, SendableRecordBatchStream, Statistics,
 };
@@ -67,7 +69,7 @@ use arrow::array::{
     UInt32Array, UInt64Array,
 };
 use arrow::compute::kernels::cmp::{eq, not_distinct};
-use arrow::compute::{and, concat_batches, take, FilterBuilder};
+use arrow::compute::{and, concat_batches, take, take_record_batch, FilterBuilder};
 use arrow::datatypes::{Schema, SchemaRef};
 use arrow::error::ArrowError;
 use arrow::record_batch::RecordBatch;
@@ -75,10 +77,11 @@ use arrow::util::bit_util;
 use datafusion_common::config::ConfigOptions;
 use datafusion_common::utils::memory::estimate_memory_size;
 use datafusion_common::{
-    internal_datafusion_err, internal_err, plan_err, project_schema, JoinSide, JoinType,
-    NullEquality, Result,
+    internal_datafusion_err, internal_err, plan_err, project_schema, DataFusionError,
+    JoinSide, JoinType, NullEquality, Result,
 };
-use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
+use datafusion_execution::disk_manager::RefCountedTempFile;
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryPool, MemoryReservation};
 use datafusion_execution::TaskContext;
 use datafusion_expr::Operator;
 use datafusion_physical_expr::equivalence::{
@@ -96,6 +99,22 @@ use parking_lot::Mutex;
 const HASH_JOIN_SEED: RandomState =
     RandomState::with_seeds('J' as u64, 'O' as u64, 'I' as u64, 'N' as u64);
 
+/// Number of partitions both inputs are split into when the build side of a
+/// partition doesn't fit in memory
+const SPILL_PARTITIONS: usize = 16;
+
+/// Maximum number of times a spilled partition is split again. Rows with equal
+/// join keys always end up in the same partition, so a build side with too
+/// many of them can't be split enough to fit in memory.
+const MAX_SPILL_DEPTH: usize = 4;
+
+/// Seed of the hashes splitting the spilled rows at `depth`, which differs at
+/// each depth so that the rows of a partition are spread over its
+/// sub-partitions
+fn spill_partitioning_random_state(depth: usize) -> RandomState {
+    RandomState::with_seeds('S' as u64, 'P' as u64, 'I' as u64, depth as u64)
+}
+
 /// HashTable and input data for the left (build side) of a join
 struct JoinLeftData {
     /// The hash table with indices into `batch`
@@ -168,6 +187,16 @@ impl JoinLeftData {
 /// partitions using a hash table and an optional filter list to apply post
 /// join.
 ///
+/// # Spilling
+///
+/// In [`PartitionMode::Partitioned`] mode, when the build side of a partition
+/// doesn't fit in memory and the disk manager allows temporary files, both
+/// inputs of the partition are split into spill files by the hash of their
+/// join keys, and each pair of files is joined on its own (a "grace" hash
+/// join). Pairs whose build side still doesn't fit are split again. The build
+/// side of [`PartitionMode::CollectLeft`] mode is shared by all output
+/// partitions and can't spill.
+///
 /// # Join Expressions
 ///
 /// This implementation is optimized for evaluating equijoin predicates  (
@@ -427,6 +456,17 @@ impl HashJoinExec {
         })
     }
 
+    /// Column indices of the output, after the projection
+    fn column_indices_after_projection(&self) -> Vec<ColumnIndex> {
+        match &self.projection {
+            Some(projection) => projection
+                .iter()
+                .map(|i| self.column_indices[*i].clone())
+                .collect(),
+            None => self.column_indices.clone(),
+        }
+    }
+
     /// left (build) side which gets hashed
     pub fn left(&self) -> &Arc<dyn ExecutionPlan> {
         &self.left
@@ -567,7 +607,8 @@ impl HashJoinExec {
                 JoinType::Left
                 | JoinType::LeftAnti
                 | JoinType::LeftMark
-                | JoinType::Full => EmissionType::Both,
+                | JoinType::Full
+                | JoinType::AsOf => EmissionType::Both,
             }
         } else {
             right.pipeline_behavior()
@@ -614,7 +655,7 @@ impl HashJoinExec {
                 .map(|(l, r)| (Arc::clone(r), Arc::clone(l)))
                 .collect(),
             self.filter().map(JoinFilter::swap),
-            &self.join_type().swap(),
+            &self.join_type().swap()?,
             swap_join_projection(
                 left.schema().fields().len(),
                 right.schema().fields().len(),
@@ -808,6 +849,47 @@ impl ExecutionPlan for HashJoinExec {
         }
 
         let join_metrics = BuildProbeJoinMetrics::new(partition, &self.metrics);
+
+        // Spilling reorders the probe side, so it is only possible when no
+        // output ordering is promised
+        if self.mode == PartitionMode::Partitioned
+            && context.runtime_env().disk_manager.tmp_files_enabled()
+            && self.cache.output_ordering().is_none()
+        {
+            let spill_metrics = SpillMetrics::new(&self.metrics, partition);
+            let spill_compression = context.session_config().spill_compression();
+            let join = Arc::new(SpillableHashJoin {
+                schema: self.schema(),
+                on_left,
+                on_right,
+                filter: self.filter.clone(),
+                join_type: self.join_type,
+                column_indices: self.column_indices_after_projection(),
+                random_state: self.random_state.clone(),
+                null_equality: self.null_equality,
+                batch_size: context.session_config().batch_size(),
+                right_side_ordered: self.right.output_ordering().is_some(),
+                partition,
+                memory_pool: Arc::clone(context.memory_pool()),
+                join_metrics: Arc::new(join_metrics),
+                left_spill_manager: SpillManager::new(
+                    context.runtime_env(),
+                    spill_metrics.clone(),
+                    self.left.schema(),
+                )
+                .with_compression_type(spill_compression),
+                right_spill_manager: SpillManager::new(
+                    context.runtime_env(),
+                    spill_metrics,
+                    self.right.schema(),
+                )
+                .with_compression_type(spill_compression),
+            });
+            let left_stream = self.left.execute(partition, Arc::clone(&context))?;
+            let right_stream = self.right.execute(partition, context)?;
+            return Ok(join.execute(left_stream, right_stream));
+        }
+
         let left_fut = match self.mode {
             PartitionMode::CollectLeft => self.left_fut.try_once(|| {
                 let left_stream = self.left.execute(0, Arc::clone(&context))?;
@@ -856,24 +938,15 @@ impl ExecutionPlan for HashJoinExec {
         // over the right that uses this information to issue new batches.
         let right_stream = self.right.execute(partition, context)?;
 
-        // update column indices to reflect the projection
-        let column_indices_after_projection = match &self.projection {
-            Some(projection) => projection
-                .iter()
-                .map(|i| self.column_indices[*i].clone())
-                .collect(),
-            None => self.column_indices.clone(),
-        };
-
         Ok(Box::pin(HashJoinStream {
             schema: self.schema(),
             on_right,
             filter: self.filter.clone(),
             join_type: self.join_type,
             right: right_stream,
-            column_indices: column_indices_after_projection,
+            column_indices: self.column_indices_after_projection(),
             random_state: self.random_state.clone(),
-            join_metrics,
+            join_metrics: Arc::new(join_metrics),
             null_equality: self.null_equality,
             state: HashJoinStreamState::WaitBuildSide,
             build_side: BuildSide::Initial(BuildSideInitialState { left_fut }),
@@ -1026,6 +1099,33 @@ async fn collect_left_input(
         })
         .await?;
 
+    build_left_data(
+        &schema,
+        &batches,
+        num_rows,
+        &random_state,
+        &on_left,
+        &metrics,
+        &mut reservation,
+        with_visited_indices_bitmap,
+        probe_threads_count,
+    )
+}
+
+/// Builds the hash table of the build side `batches`, growing `reservation`
+/// by its size and moving the reservation into the returned `JoinLeftData`
+#[allow(clippy::too_many_arguments)]
+fn build_left_data(
+    schema: &SchemaRef,
+    batches: &[RecordBatch],
+    num_rows: usize,
+    random_state: &RandomState,
+    on_left: &[PhysicalExprRef],
+    metrics: &BuildProbeJoinMetrics,
+    reservation: &mut MemoryReservation,
+    with_visited_indices_bitmap: bool,
+    probe_threads_count: usize,
+) -> Result<JoinLeftData> {
     // Estimation of memory size, required for hashtable, prior to allocation.
     // Final result can be verified using `RawTable.allocation_info()`
     let fixed_size_u32 = size_of::<JoinHashMapU32>();
@@ -1056,11 +1156,11 @@ async fn collect_left_input(
         hashes_buffer.clear();
         hashes_buffer.resize(batch.num_rows(), 0);
         update_hash(
-            &on_left,
+            on_left,
             batch,
             &mut *hashmap,
             offset,
-            &random_state,
+            random_state,
             &mut hashes_buffer,
             0,
             true,
@@ -1068,7 +1168,7 @@ async fn collect_left_input(
         offset += batch.num_rows();
     }
     // Merge all batches into a single batch, so we can directly index into the arrays
-    let single_batch = concat_batches(&schema, batches_iter)?;
+    let single_batch = concat_batches(schema, batches_iter)?;
 
     // Reserve additional memory for visited indices bitmap and create shared builder
     let visited_indices_bitmap = if with_visited_indices_bitmap {
@@ -1097,7 +1197,7 @@ async fn collect_left_input(
         left_values,
         Mutex::new(visited_indices_bitmap),
         AtomicUsize::new(probe_threads_count),
-        reservation,
+        reservation.take(),
     );
 
     Ok(data)
@@ -1279,8 +1379,8 @@ struct HashJoinStream {
     right: SendableRecordBatchStream,
     /// Random state used for hashing initialization
     random_state: RandomState,
-    /// Metrics
-    join_metrics: BuildProbeJoinMetrics,
+    /// Metrics, shared by the streams joining the spilled partitions
+    join_metrics: Arc<BuildProbeJoinMetrics>,
     /// Information of index and left / right placement of columns
     column_indices: Vec<ColumnIndex>,
     /// Defines the null equality for the join.
@@ -1731,6 +1831,254 @@ impl Stream for HashJoinStream {
     }
 }
 
+/// Partition of a [`HashJoinExec`] in [`PartitionMode::Partitioned`] mode
+/// that spills both inputs to disk when its build side doesn't fit in memory.
+///
+/// The build side is collected like in [`collect_left_input`]. If the
+/// reservation can't grow, the collected batches and the rest of the build
+/// side are split into [`SPILL_PARTITIONS`] spill files by the hash of their
+/// join keys, and so is the probe side. As matching rows are in files with
+/// the same index, each pair of files is then joined on its own, splitting it
+/// again with a different hash if its build side still doesn't fit.
+struct SpillableHashJoin {
+    /// Output schema
+    schema: SchemaRef,
+    /// equijoin columns from the left (build side)
+    on_left: Vec<PhysicalExprRef>,
+    /// equijoin columns from the right (probe side)
+    on_right: Vec<PhysicalExprRef>,
+    /// optional join filter
+    filter: Option<JoinFilter>,
+    /// type of the join (left, right, semi, etc)
+    join_type: JoinType,
+    /// Information of index and left / right placement of columns
+    column_indices: Vec<ColumnIndex>,
+    /// Random state used for hashing initialization
+    random_state: RandomState,
+    /// Defines the null equality for the join.
+    null_equality: NullEquality,
+    /// Maximum output batch size
+    batch_size: usize,
+    /// Specifies whether the right side has an ordering to potentially preserve
+    right_side_ordered: bool,
+    /// Output partition executed
+    partition: usize,
+    /// Memory pool of the build side reservations
+    memory_pool: Arc<dyn MemoryPool>,
+    /// Metrics
+    join_metrics: Arc<BuildProbeJoinMetrics>,
+    /// Spills the left (build) side
+    left_spill_manager: SpillManager,
+    /// Spills the right (probe) side
+    right_spill_manager: SpillManager,
+}
+
+impl SpillableHashJoin {
+    /// Joins the `left` and `right` inputs of the partition
+    fn execute(
+        self: &Arc<Self>,
+        left: SendableRecordBatchStream,
+        right: SendableRecordBatchStream,
+    ) -> SendableRecordBatchStream {
+        // The build side input is only counted once, not when its spill files
+        // are read back
+        let build_input_batches = self.join_metrics.build_input_batches.clone();
+        let build_input_rows = self.join_metrics.build_input_rows.clone();
+        let left = Box::pin(RecordBatchStreamAdapter::new(
+            left.schema(),
+            left.inspect_ok(move |batch| {
+                build_input_batches.add(1);
+                build_input_rows.add(batch.num_rows());
+            }),
+        ));
+        self.execute_streams(left, right, 0)
+    }
+
+    /// Joins a pair of spill files, an absent file having no rows
+    fn execute_spill_files(
+        self: &Arc<Self>,
+        left_file: Option<RefCountedTempFile>,
+        right_file: Option<RefCountedTempFile>,
+        depth: usize,
+    ) -> Result<SendableRecordBatchStream> {
+        let left = read_spill_file(&self.left_spill_manager, left_file)?;
+        let right = read_spill_file(&self.right_spill_manager, right_file)?;
+        Ok(self.execute_streams(left, right, depth))
+    }
+
+    fn execute_streams(
+        self: &Arc<Self>,
+        left: SendableRecordBatchStream,
+        right: SendableRecordBatchStream,
+        depth: usize,
+    ) -> SendableRecordBatchStream {
+        let join = Arc::clone(self).join(left, right, depth);
+        Box::pin(RecordBatchStreamAdapter::new(
+            Arc::clone(&self.schema),
+            futures::stream::once(join).try_flatten(),
+        ))
+    }
+
+    /// Collects the build side and probes it with the `right` input, or spills
+    /// both inputs if the build side doesn't fit in memory
+    async fn join(
+        self: Arc<Self>,
+        mut left: SendableRecordBatchStream,
+        right: SendableRecordBatchStream,
+        depth: usize,
+    ) -> Result<SendableRecordBatchStream> {
+        let mut reservation =
+            MemoryConsumer::new(format!("HashJoinInput[{}]", self.partition))
+                .with_can_spill(true)
+                .register(&self.memory_pool);
+
+        let build_timer = self.join_metrics.build_time.timer();
+        let mut batches = vec![];
+        let mut num_rows = 0;
+        let mut exhausted = None;
+        while let Some(batch) = left.next().await.transpose()? {
+            let batch_size = get_record_batch_memory_size(&batch);
+            num_rows += batch.num_rows();
+            batches.push(batch);
+            if let Err(e) = reservation.try_grow(batch_size) {
+                exhausted = Some(e);
+                break;
+            }
+            self.join_metrics.build_mem_used.add(batch_size);
+        }
+
+        let error = match exhausted {
+            None => match build_left_data(
+                &left.schema(),
+                &batches,
+                num_rows,
+                &self.random_state,
+                &self.on_left,
+                &self.join_metrics,
+                &mut reservation,
+                need_produce_result_in_final(self.join_type),
+                1,
+            ) {
+                Ok(left_data) => {
+                    build_timer.done();
+                    return Ok(self.probe(left_data, right, depth));
+                }
+                Err(e) => e,
+            },
+            Some(e) => e,
+        };
+        if !matches!(error.find_root(), DataFusionError::ResourcesExhausted(_))
+            || depth == MAX_SPILL_DEPTH
+        {
+            return Err(error);
+        }
+
+        let buffered = futures::stream::iter(std::mem::take(&mut batches))
+            .map(Ok)
+            .chain(left);
+        let left_files = self
+            .spill_partitioned(&self.left_spill_manager, &self.on_left, buffered, depth)
+            .await?;
+        reservation.free();
+        build_timer.done();
+
+        let right_files = self
+            .spill_partitioned(&self.right_spill_manager, &self.on_right, right, depth)
+            .await?;
+
+        let join = Arc::clone(&self);
+        let partitions = left_files
+            .into_iter()
+            .zip(right_files)
+            .filter(|(left_file, right_file)| left_file.is_some() || right_file.is_some())
+            .map(move |(left_file, right_file)| {
+                join.execute_spill_files(left_file, right_file, depth + 1)
+            });
+        Ok(Box::pin(RecordBatchStreamAdapter::new(
+            Arc::clone(&self.schema),
+            futures::stream::iter(partitions).try_flatten(),
+        )))
+    }
+
+    /// Joins the `right` input with the collected build side
+    fn probe(
+        &self,
+        left_data: JoinLeftData,
+        right: SendableRecordBatchStream,
+        depth: usize,
+    ) -> SendableRecordBatchStream {
+        Box::pin(HashJoinStream {
+            schema: Arc::clone(&self.schema),
+            on_right: self.on_right.clone(),
+            filter: self.filter.clone(),
+            join_type: self.join_type,
+            right,
+            column_indices: self.column_indices.clone(),
+            random_state: self.random_state.clone(),
+            join_metrics: Arc::clone(&self.join_metrics),
+            null_equality: self.null_equality,
+            state: HashJoinStreamState::FetchProbeBatch,
+            build_side: BuildSide::Ready(BuildSideReadyState {
+                left_data: Arc::new(left_data),
+            }),
+            batch_size: self.batch_size,
+            hashes_buffer: vec![],
+            // spill files don't preserve the order of the probe side
+            right_side_ordered: self.right_side_ordered && depth == 0,
+        })
+    }
+
+    /// Writes the rows of `input` into [`SPILL_PARTITIONS`] spill files by
+    /// the hash of their `on` columns, returning `None` for empty files
+    async fn spill_partitioned(
+        &self,
+        spill_manager: &SpillManager,
+        on: &[PhysicalExprRef],
+        mut input: impl Stream<Item = Result<RecordBatch>> + Unpin,
+        depth: usize,
+    ) -> Result<Vec<Option<RefCountedTempFile>>> {
+        let random_state = spill_partitioning_random_state(depth);
+        let mut files = (0..SPILL_PARTITIONS)
+            .map(|_| spill_manager.create_in_progress_file("HashJoinSpill"))
+            .collect::<Result<Vec<_>>>()?;
+        let mut hashes_buffer = vec![];
+        while let Some(batch) = input.next().await.transpose()? {
+            let keys_values = on
+                .iter()
+                .map(|c| c.evaluate(&batch)?.into_array(batch.num_rows()))
+                .collect::<Result<Vec<_>>>()?;
+            hashes_buffer.clear();
+            hashes_buffer.resize(batch.num_rows(), 0);
+            create_hashes(&keys_values, &random_state, &mut hashes_buffer)?;
+
+            let mut indices = vec![vec![]; SPILL_PARTITIONS];
+            for (row, hash) in hashes_buffer.iter().enumerate() {
+                indices[(*hash % SPILL_PARTITIONS as u64) as usize].push(row as u32);
+            }
+            for (file, indices) in files.iter_mut().zip(indices) {
+                if !indices.is_empty() {
+                    let indices = UInt32Array::from(indices);
+                    file.append_batch(&take_record_batch(&batch, &indices)?)?;
+                }
+            }
+        }
+        files.iter_mut().map(|file| file.finish()).collect()
+    }
+}
+
+/// Reads back a spill file written by `spill_manager`
+fn read_spill_file(
+    spill_manager: &SpillManager,
+    file: Option<RefCountedTempFile>,
+) -> Result<SendableRecordBatchStream> {
+    match file {
+        Some(file) => spill_manager.read_spill_as_stream(file),
+        None => Ok(Box::pin(EmptyRecordBatchStream::new(Arc::clone(
+            &spill_manager.schema,
+        )))),
+    }
+}
+
 impl EmbeddedProjection for HashJoinExec {
     fn with_projection(&self, projection: Option<Vec<usize>>) -> Result<Self> {
         self.with_projection(projection)
@@ -1756,6 +2104,7 @@ mod tests {
         ScalarValue,
     };
     use datafusion_execution::config::SessionConfig;
+    use datafusion_execution::disk_manager::{DiskManagerBuilder, DiskManagerMode};
     use datafusion_execution::runtime_env::RuntimeEnvBuilder;
     use datafusion_expr::Operator;
     use datafusion_physical_expr::expressions::{BinaryExpr, Literal};
@@ -4589,8 +4938,12 @@ mod tests {
         ];
 
         for join_type in join_types {
+            // Disable DiskManager to prevent spilling
             let runtime = RuntimeEnvBuilder::new()
                 .with_memory_limit(100, 1.0)
+                .with_disk_manager_builder(
+                    DiskManagerBuilder::default().with_mode(DiskManagerMode::Disabled),
+                )
                 .build_arc()?;
             let session_config = SessionConfig::default().with_batch_size(50);
             let task_ctx = TaskContext::default()
@@ -4628,6 +4981,77 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn partitioned_join_spill() -> Result<()> {
+        let left_batches = (0..10)
+            .map(|i| {
+                let values = (i * 1000..(i + 1) * 1000).collect::<Vec<_>>();
+                build_table_i32(("a1", &values), ("b1", &values), ("c1", &values))
+            })
+            .collect::<Vec<_>>();
+        let left_schema = left_batches[0].schema();
+        let left = TestMemoryExec::try_new_exec(&[left_batches], left_schema, None)?;
+        let right_batches = (0..10)
+            .map(|i| {
+                let values = (i * 1000..(i + 1) * 1000)
+                    .map(|v| v * 2)
+                    .collect::<Vec<_>>();
+                build_table_i32(("a2", &values), ("b2", &values), ("c2", &values))
+            })
+            .collect::<Vec<_>>();
+        let right_schema = right_batches[0].schema();
+        let right = TestMemoryExec::try_new_exec(&[right_batches], right_schema, None)?;
+        let on = vec![(
+            Arc::new(Column::new_with_schema("a1", &left.schema())?) as _,
+            Arc::new(Column::new_with_schema("a2", &right.schema())?) as _,
+        )];
+
+        let join_types = vec![
+            (JoinType::Inner, 5000),
+            (JoinType::Left, 10000),
+            (JoinType::Right, 10000),
+            (JoinType::Full, 15000),
+            (JoinType::LeftSemi, 5000),
+            (JoinType::LeftAnti, 5000),
+            (JoinType::RightSemi, 5000),
+            (JoinType::RightAnti, 5000),
+        ];
+
+        for (join_type, expected_rows) in join_types {
+            // Enable DiskManager to allow spilling
+            let runtime = RuntimeEnvBuilder::new()
+                .with_memory_limit(64 * 1024, 1.0)
+                .with_disk_manager_builder(
+                    DiskManagerBuilder::default()
+                        .with_mode(DiskManagerMode::OsTmpDirectory),
+                )
+                .build_arc()?;
+            let task_ctx = Arc::new(TaskContext::default().with_runtime(runtime));
+
+            let join = HashJoinExec::try_new(
+                Arc::clone(&left) as Arc<dyn ExecutionPlan>,
+                Arc::clone(&right) as Arc<dyn ExecutionPlan>,
+                on.clone(),
+                None,
+                &join_type,
+                None,
+                PartitionMode::Partitioned,
+                NullEquality::NullEqualsNothing,
+            )?;
+
+            let stream = join.execute(0, task_ctx)?;
+            let batches = common::collect(stream).await?;
+            let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+            assert_eq!(num_rows, expected_rows, "{join_type}");
+
+            let metrics = join.metrics().unwrap();
+            assert!(metrics.spill_count().unwrap() > 0);
+            assert!(metrics.spilled_rows().unwrap() > 0);
+        }
+
+        Ok(())
+    }
+
     fn build_table_struct(
         struct_name: &str,
         field_name_and_values: (&str, &Vec<Option<i32>>),
@@ -283,7 +283,8 @@ impl NestedLoopJoinExec {
                 JoinType::Left
                 | JoinType::LeftAnti
                 | JoinType::LeftMark
-                | JoinType::Full => EmissionType::Both,
+                | JoinType::Full
+                | JoinType::AsOf => EmissionType::Both,
             }
         } else {
             right.pipeline_behavior()
@@ -364,7 +365,7 @@ impl NestedLoopJoinExec {
             Arc::clone(right),
             Arc::clone(left),
             self.filter().map(JoinFilter::swap),
-            &self.join_type().swap(),
+            &self.join_type().swap()?,
             swap_join_projection(
                 left.schema().fields().len(),
                 right.schema().fields().len(),
@@ -241,7 +241,8 @@ impl SortMergeJoinExec {
             | JoinType::Full
             | JoinType::LeftAnti
             | JoinType::LeftSemi
-            | JoinType::LeftMark => JoinSide::Left,
+            | JoinType::LeftMark
+            | JoinType::AsOf => JoinSide::Left,
         }
     }
 
@@ -339,7 +340,7 @@ impl SortMergeJoinExec {
                 .map(|(l, r)| (Arc::clone(r), Arc::clone(l)))
                 .collect::<Vec<_>>(),
             self.filter().as_ref().map(JoinFilter::swap),
-            self.join_type().swap(),
+            self.join_type().swap()?,
             self.sort_options.clone(),
             self.null_equality,
         )?;
@@ -49,9 +49,9 @@ use arrow::datatypes::{
     ArrowNativeType, Field, Schema, SchemaBuilder, UInt32Type, UInt64Type,
 };
 use datafusion_common::cast::as_boolean_array;
-use datafusion_common::stats::Precision;
+use datafusion_common::stats::{HistogramBucket, MostCommonValues, Precision};
 use datafusion_common::{
-    plan_err, DataFusionError, JoinSide, JoinType, Result, SharedResult,
+    plan_err, DataFusionError, JoinSide, JoinType, Result, ScalarValue, SharedResult,
 };
 use datafusion_expr::interval_arithmetic::Interval;
 use datafusion_physical_expr::expressions::Column;
@@ -212,6 +212,7 @@ fn output_join_field(old_field: &Field, join_type: &JoinType, is_left: bool) ->
     let force_nullable = match join_type {
         JoinType::Inner => false,
         JoinType::Left => !is_left, // right input is padded with nulls
+        JoinType::AsOf => !is_left, // right input is padded with nulls
         JoinType::Right => is_left, // left input is padded with nulls
         JoinType::Full => true,     // both inputs can be padded with nulls
         JoinType::LeftSemi => false, // doesn't introduce nulls
@@ -270,7 +271,11 @@ pub fn build_join_schema(
     };
 
     let (fields, column_indices): (SchemaBuilder, Vec<ColumnIndex>) = match join_type {
-        JoinType::Inner | JoinType::Left | JoinType::Full | JoinType::Right => {
+        JoinType::Inner
+        | JoinType::Left
+        | JoinType::Full
+        | JoinType::Right
+        | JoinType::AsOf => {
             // left then right
             left_fields().chain(right_fields()).unzip()
         }
@@ -532,6 +537,25 @@ fn estimate_join_cardinality(
                 column_statistics,
             })
         }
+
+        // AsOf joins produce exactly one row for each row of the left input,
+        // matched with at most one row of the right input
+        JoinType::AsOf => {
+            let num_rows = *left_stats.num_rows.get_value()?;
+            Some(PartialJoinStatistics {
+                num_rows,
+                column_statistics: left_stats
+                    .column_statistics
+                    .into_iter()
+                    .chain(
+                        right_stats
+                            .column_statistics
+                            .into_iter()
+                            .map(|stats| stats.to_inexact()),
+                    )
+                    .collect(),
+            })
+        }
     }
 }
 
@@ -548,6 +572,24 @@ fn estimate_inner_join_cardinality(
         return Some(estimation);
     };
 
+    // If join columns have histograms, as computed by `ANALYZE TABLE`, use the
+    // most selective of their estimations, as the matches of all the columns
+    // are among the matches of each of them.
+    if let Some(selectivity) = left_stats
+        .column_statistics
+        .iter()
+        .zip(right_stats.column_statistics.iter())
+        .filter_map(|(left_stat, right_stat)| {
+            histogram_join_selectivity(left_stat, right_stat)
+        })
+        .reduce(f64::min)
+    {
+        let left_num_rows = *left_stats.num_rows.get_value()? as f64;
+        let right_num_rows = *right_stats.num_rows.get_value()? as f64;
+        let cardinality = (left_num_rows * right_num_rows * selectivity).round();
+        return Some(Precision::Inexact(cardinality as usize));
+    }
+
     // The algorithm here is partly based on the non-histogram selectivity estimation
     // from Spark's Catalyst optimizer.
     let mut join_selectivity = Precision::Absent;
@@ -596,6 +638,98 @@ fn estimate_inner_join_cardinality(
     }
 }
 
+/// Estimates the selectivity of the equi-join of two columns, the ratio of
+/// the matching pairs of rows to all the pairs of rows, from their histograms
+/// and most common values, if both columns have a histogram.
+///
+/// The occurrences of the most common values of each side are matched with
+/// the estimated occurrences of the same value on the other side. The other
+/// values of each pair of overlapping buckets are assumed to be uniformly
+/// distributed, and each distinct value of the side with the fewest distinct
+/// values in the overlap to have a match on the other side.
+fn histogram_join_selectivity(
+    left_stat: &ColumnStatistics,
+    right_stat: &ColumnStatistics,
+) -> Option<f64> {
+    let left_histogram = left_stat.histogram.as_deref()?;
+    let right_histogram = right_stat.histogram.as_deref()?;
+    let left_common = left_stat.most_common_values.as_deref();
+    let right_common = right_stat.most_common_values.as_deref();
+
+    let mut matches = 0.0;
+    for (value, count) in left_common.map(|mcv| mcv.values()).unwrap_or_default() {
+        matches += *count as f64 * right_histogram.estimate_count_of(value, right_common);
+    }
+    for (value, count) in right_common.map(|mcv| mcv.values()).unwrap_or_default() {
+        if left_common.and_then(|mcv| mcv.count_of(value)).is_none() {
+            matches +=
+                *count as f64 * left_histogram.estimate_count_of(value, left_common);
+        }
+    }
+    for left_bucket in left_histogram.buckets() {
+        for right_bucket in right_histogram.buckets() {
+            let lower = if left_bucket.lower >= right_bucket.lower {
+                &left_bucket.lower
+            } else {
+                &right_bucket.lower
+            };
+            let upper = if left_bucket.upper <= right_bucket.upper {
+                &left_bucket.upper
+            } else {
+                &right_bucket.upper
+            };
+            if lower.partial_cmp(upper).is_none_or(|o| o.is_gt()) {
+                continue;
+            }
+            let (left_count, left_distinct) =
+                uncommon_values_between(left_bucket, left_common, lower, upper);
+            let (right_count, right_distinct) =
+                uncommon_values_between(right_bucket, right_common, lower, upper);
+            let distinct = left_distinct.max(right_distinct);
+            if distinct > 0.0 {
+                matches += left_count * right_count / distinct;
+            }
+        }
+    }
+
+    // the histograms only hold the non-null values, which are the only ones
+    // that can match
+    let num_rows = |stat: &ColumnStatistics, count: usize| {
+        (count + stat.null_count.get_value().copied().unwrap_or(0)) as f64
+    };
+    let left_num_rows = num_rows(left_stat, left_histogram.count());
+    let right_num_rows = num_rows(right_stat, right_histogram.count());
+    if left_num_rows == 0.0 || right_num_rows == 0.0 {
+        return Some(0.0);
+    }
+    Some((matches / (left_num_rows * right_num_rows)).clamp(0.0, 1.0))
+}
+
+/// Estimates the number of values, and of distinct values, of `bucket`
+/// between `lower` and `upper` that are not among the `most_common_values`
+fn uncommon_values_between(
+    bucket: &HistogramBucket,
+    most_common_values: Option<&MostCommonValues>,
+    lower: &ScalarValue,
+    upper: &ScalarValue,
+) -> (f64, f64) {
+    let common = most_common_values
+        .map(|mcv| mcv.between(Some(&bucket.lower), Some(&bucket.upper)))
+        .unwrap_or_default();
+    let count = bucket
+        .count
+        .saturating_sub(common.iter().map(|(_, count)| count).sum());
+    let distinct_count = bucket.distinct_count.saturating_sub(common.len());
+    if distinct_count == 0 {
+        return (0.0, 0.0);
+    }
+    let fraction = bucket.fraction_between(Some(lower), Some(upper));
+    (
+        count as f64 * fraction,
+        (distinct_count as f64 * fraction).max(1.0),
+    )
+}
+
 /// Estimates if inputs are non-overlapping, using input statistics.
 /// If inputs are disjoint, returns zero estimation, otherwise returns None
 fn estimate_disjoint_inputs(
@@ -980,7 +1114,8 @@ pub(crate) fn build_batch_empty_build_side(
         | JoinType::LeftSemi
         | JoinType::RightSemi
         | JoinType::LeftAnti
-        | JoinType::LeftMark => Ok(RecordBatch::new_empty(Arc::new(schema.clone()))),
+        | JoinType::LeftMark
+        | JoinType::AsOf => Ok(RecordBatch::new_empty(Arc::new(schema.clone()))),
 
         // the remaining joins will return data for the right columns and null for the left ones
         JoinType::Right | JoinType::Full | JoinType::RightAnti | JoinType::RightMark => {
@@ -1026,7 +1161,7 @@ pub(crate) fn adjust_indices_by_join_type(
             // matched
             Ok((left_indices, right_indices))
         }
-        JoinType::Left => {
+        JoinType::Left | JoinType::AsOf => {
             // matched
             Ok((left_indices, right_indices))
             // unmatched left row will be produced in the end of loop, and it has been set in the left visited bitmap
@@ -1413,9 +1548,11 @@ pub(crate) fn symmetric_join_output_partitioning(
     let left_partitioning = left.output_partitioning();
     let right_partitioning = right.output_partitioning();
     let result = match join_type {
-        JoinType::Left | JoinType::LeftSemi | JoinType::LeftAnti | JoinType::LeftMark => {
-            left_partitioning.clone()
-        }
+        JoinType::Left
+        | JoinType::LeftSemi
+        | JoinType::LeftAnti
+        | JoinType::LeftMark
+        | JoinType::AsOf => left_partitioning.clone(),
         JoinType::RightSemi | JoinType::RightAnti | JoinType::RightMark => {
             right_partitioning.clone()
         }
@@ -1447,7 +1584,8 @@ pub(crate) fn asymmetric_join_output_partitioning(
         | JoinType::LeftSemi
         | JoinType::LeftAnti
         | JoinType::Full
-        | JoinType::LeftMark => Partitioning::UnknownPartitioning(
+        | JoinType::LeftMark
+        | JoinType::AsOf => Partitioning::UnknownPartitioning(
             right.output_partitioning().partition_count(),
         ),
     };
@@ -1626,6 +1764,7 @@ mod tests {
     use arrow::array::Int32Array;
     use arrow::datatypes::{DataType, Fields};
     use arrow::error::{ArrowError, Result as ArrowResult};
+    use datafusion_common::stats::Histogram;
     use datafusion_common::stats::Precision::{Absent, Exact, Inexact};
     use datafusion_common::{arrow_datafusion_err, arrow_err, ScalarValue};
     use datafusion_physical_expr::PhysicalSortExpr;
@@ -1827,6 +1966,8 @@ mod tests {
             max_value: max.map(ScalarValue::from),
             sum_value: Absent,
             null_count,
+            histogram: None,
+            most_common_values: None,
         }
     }
 
@@ -2073,6 +2214,62 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_inner_join_cardinality_histogram() -> Result<()> {
+        let histogram = |lower: i64, upper: i64, count, distinct_count| {
+            Some(Arc::new(
+                Histogram::try_new(vec![HistogramBucket {
+                    lower: ScalarValue::from(lower),
+                    upper: ScalarValue::from(upper),
+                    count,
+                    distinct_count,
+                }])
+                .unwrap(),
+            ))
+        };
+        let most_common_value = |value: i64, count| {
+            Some(Arc::new(
+                MostCommonValues::try_new(vec![(ScalarValue::from(value), count)])
+                    .unwrap(),
+            ))
+        };
+        // 1 occurs 50 times, and 51 to 100 once
+        let left_col_stats =
+            create_column_stats(Inexact(1), Inexact(100), Inexact(51), Exact(0))
+                .with_histogram(histogram(1, 100, 100, 51))
+                .with_most_common_values(most_common_value(1, 50));
+        // 1 occurs 91 times, and 2 to 10 once
+        let right_col_stats =
+            create_column_stats(Inexact(1), Inexact(10), Inexact(10), Exact(0))
+                .with_histogram(histogram(1, 10, 100, 10))
+                .with_most_common_values(most_common_value(1, 91));
+        let stats = |col_stats: &ColumnStatistics| Statistics {
+            num_rows: Inexact(100),
+            total_byte_size: Absent,
+            column_statistics: vec![col_stats.clone()],
+        };
+
+        // 50 * 91 matches of the most common value, and 5 * 9 / 9 of the
+        // other values between 1 and 10
+        assert_eq!(
+            estimate_inner_join_cardinality(
+                stats(&left_col_stats),
+                stats(&right_col_stats),
+            ),
+            Some(Inexact(4555))
+        );
+
+        // the distinct counts are used if only one side has a histogram
+        assert_eq!(
+            estimate_inner_join_cardinality(
+                stats(&left_col_stats),
+                stats(&right_col_stats.clone().with_histogram(None)),
+            ),
+            Some(Inexact((100 * 100) / 51))
+        );
+        Ok(())
+    }
+
     #[test]
     fn test_inner_join_cardinality_decimal_range() -> Result<()> {
         let left_col_stats = vec![ColumnStatistics {
@@ -0,0 +1,1900 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines the MATCH_RECOGNIZE execution plan, which finds the sequences of
+//! rows of its input matching a row pattern
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use crate::execution_plan::EmissionType;
+use crate::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
+use crate::{
+    DisplayAs, DisplayFormatType, Distribution, ExecutionPlan, ExecutionPlanProperties,
+    Partitioning, PhysicalExpr, PlanProperties, RecordBatchStream,
+    SendableRecordBatchStream,
+};
+
+use crate::spill::get_record_batch_memory_size;
+
+use arrow::array::{
+    ArrayRef, BooleanArray, RecordBatch, RecordBatchOptions, UInt32Array,
+};
+use arrow::compute::{concat_batches, take, take_record_batch, SortColumn};
+use arrow::datatypes::{Field, Schema, SchemaRef};
+use datafusion_common::cast::as_boolean_array;
+use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
+use datafusion_common::utils::evaluate_partition_ranges;
+use datafusion_common::{exec_err, internal_err, Result, ScalarValue};
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
+use datafusion_execution::TaskContext;
+use datafusion_expr::{AfterMatchSkip, MatchNavigation, RowPattern, RowsPerMatch};
+use datafusion_physical_expr::aggregate::AggregateFunctionExpr;
+use datafusion_physical_expr::expressions::Literal;
+use datafusion_physical_expr::{EquivalenceProperties, ScalarFunctionExpr};
+use datafusion_physical_expr_common::sort_expr::{
+    LexRequirement, OrderingRequirements, PhysicalSortExpr, PhysicalSortRequirement,
+};
+
+use futures::{ready, Stream, StreamExt};
+
+/// A value computed for each match of a [`MatchRecognizeExec`]
+#[derive(Debug, Clone)]
+pub enum MatchMeasureExpr {
+    /// An aggregate function evaluated on the rows of the match mapped to the
+    /// symbol with the given index, or on all rows of the match if `symbol`
+    /// is `None`. Rows for which `filter` is not true are skipped.
+    Aggregate {
+        expr: Arc<AggregateFunctionExpr>,
+        filter: Option<Arc<dyn PhysicalExpr>>,
+        symbol: Option<usize>,
+    },
+    /// The sequential number of the match within its partition, starting at 1
+    MatchNumber,
+    /// The symbol the last (or, with [`RowsPerMatch::AllRows`], the current)
+    /// row of the match is mapped to
+    Classifier,
+}
+
+/// The predicate of a pattern variable of a [`MatchRecognizeExec`]
+#[derive(Debug, Clone)]
+pub struct MatchDefinitionExpr {
+    /// The predicate, evaluated on the input columns followed by one column
+    /// per reference
+    pub predicate: Arc<dyn PhysicalExpr>,
+    /// The aggregates of the match in progress the predicate refers to. They
+    /// are evaluated on the rows of the match before the current row and the
+    /// current row, mapped to the pattern variable being defined.
+    pub references: Vec<MatchMeasureExpr>,
+}
+
+/// Execution plan for `MATCH_RECOGNIZE`.
+///
+/// The input must be partitioned by `partition_by` and sorted by
+/// `partition_by` followed by `order_by`. The operator matches the pattern
+/// starting at each row of a partition as the rows arrive, preferring the
+/// leftmost alternative and the longest repetition (like a backtracking
+/// regular expression engine), and emits the output of each match as soon as
+/// it is found. Only the rows of the match attempt in progress are buffered.
+///
+/// See [`datafusion_expr::MatchRecognize`] for the output schema.
+#[derive(Debug, Clone)]
+pub struct MatchRecognizeExec {
+    /// Input plan
+    input: Arc<dyn ExecutionPlan>,
+    /// The expressions dividing the input into partitions
+    partition_by: Vec<Arc<dyn PhysicalExpr>>,
+    /// The order of the rows within a partition
+    order_by: Vec<PhysicalSortExpr>,
+    /// The values computed for each match
+    measures: Vec<MatchMeasureExpr>,
+    /// Whether one row is produced per match or per matched row
+    rows_per_match: RowsPerMatch,
+    /// Where the next match attempt starts after a match
+    after_match_skip: AfterMatchSkip,
+    /// The pattern to match
+    pattern: RowPattern,
+    /// The pattern variables
+    symbols: Vec<String>,
+    /// The predicate of each pattern variable, if any
+    definitions: Vec<Option<MatchDefinitionExpr>>,
+    /// The compiled pattern
+    matcher: Arc<PatternMatcher>,
+    /// Schema after matching
+    schema: SchemaRef,
+    /// Execution metrics
+    metrics: ExecutionPlanMetricsSet,
+    /// Cache holding plan properties like equivalences, output partitioning etc.
+    cache: PlanProperties,
+}
+
+impl MatchRecognizeExec {
+    /// Create a new execution plan for `MATCH_RECOGNIZE`
+    #[allow(clippy::too_many_arguments)]
+    pub fn try_new(
+        input: Arc<dyn ExecutionPlan>,
+        partition_by: Vec<Arc<dyn PhysicalExpr>>,
+        order_by: Vec<PhysicalSortExpr>,
+        measures: Vec<MatchMeasureExpr>,
+        rows_per_match: RowsPerMatch,
+        after_match_skip: AfterMatchSkip,
+        pattern: RowPattern,
+        symbols: Vec<String>,
+        definitions: Vec<Option<MatchDefinitionExpr>>,
+        schema: SchemaRef,
+    ) -> Result<Self> {
+        if symbols.len() != definitions.len() {
+            return internal_err!(
+                "MatchRecognizeExec expects one definition per symbol, got {} symbols and {} definitions",
+                symbols.len(),
+                definitions.len()
+            );
+        }
+        let matcher = Arc::new(PatternMatcher::try_new(
+            &pattern,
+            &after_match_skip,
+            &symbols,
+        )?);
+        let cache = Self::compute_properties(Arc::clone(&schema), &input);
+        Ok(Self {
+            input,
+            partition_by,
+            order_by,
+            measures,
+            rows_per_match,
+            after_match_skip,
+            pattern,
+            symbols,
+            definitions,
+            matcher,
+            schema,
+            metrics: ExecutionPlanMetricsSet::new(),
+            cache,
+        })
+    }
+
+    /// Input plan
+    pub fn input(&self) -> &Arc<dyn ExecutionPlan> {
+        &self.input
+    }
+
+    /// The expressions dividing the input into partitions
+    pub fn partition_by(&self) -> &[Arc<dyn PhysicalExpr>] {
+        &self.partition_by
+    }
+
+    /// The order of the rows within a partition
+    pub fn order_by(&self) -> &[PhysicalSortExpr] {
+        &self.order_by
+    }
+
+    /// The values computed for each match
+    pub fn measures(&self) -> &[MatchMeasureExpr] {
+        &self.measures
+    }
+
+    /// Whether one row is produced per match or per matched row
+    pub fn rows_per_match(&self) -> RowsPerMatch {
+        self.rows_per_match
+    }
+
+    /// Where the next match attempt starts after a match
+    pub fn after_match_skip(&self) -> &AfterMatchSkip {
+        &self.after_match_skip
+    }
+
+    /// The pattern to match
+    pub fn pattern(&self) -> &RowPattern {
+        &self.pattern
+    }
+
+    /// The pattern variables
+    pub fn symbols(&self) -> &[String] {
+        &self.symbols
+    }
+
+    /// The predicate of each pattern variable, if any
+    pub fn definitions(&self) -> &[Option<MatchDefinitionExpr>] {
+        &self.definitions
+    }
+
+    /// This function creates the cache object that stores the plan properties such as schema, equivalence properties, ordering, partitioning, etc.
+    fn compute_properties(
+        schema: SchemaRef,
+        input: &Arc<dyn ExecutionPlan>,
+    ) -> PlanProperties {
+        // The output columns differ from the input columns, so the input
+        // partitioning can not be expressed in terms of the output
+        let output_partitioning = Partitioning::UnknownPartitioning(
+            input.output_partitioning().partition_count(),
+        );
+        // Matches are emitted as soon as they are found
+        PlanProperties::new(
+            EquivalenceProperties::new(schema),
+            output_partitioning,
+            EmissionType::Incremental,
+            input.boundedness(),
+        )
+    }
+
+    /// Displays the definition of `symbol`, with the aggregates of the match
+    /// in progress it refers to
+    fn definition_display(
+        &self,
+        symbol: &str,
+        definition: &MatchDefinitionExpr,
+    ) -> String {
+        let mut display = format!("{symbol} AS {}", definition.predicate);
+        if !definition.references.is_empty() {
+            let references = definition
+                .references
+                .iter()
+                .filter_map(|reference| match reference {
+                    MatchMeasureExpr::Aggregate { expr, symbol, .. } => {
+                        Some(match symbol {
+                            Some(symbol) => {
+                                format!("{} OF {}", expr.name(), self.symbols[*symbol])
+                            }
+                            None => expr.name().to_string(),
+                        })
+                    }
+                    MatchMeasureExpr::MatchNumber | MatchMeasureExpr::Classifier => None,
+                })
+                .collect::<Vec<_>>();
+            display.push_str(&format!(" WITH [{}]", references.join(", ")));
+        }
+        display
+    }
+
+    /// The names of the measure columns
+    fn measure_names(&self) -> Vec<&str> {
+        let fields = self.schema.fields();
+        fields[fields.len() - self.measures.len()..]
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect()
+    }
+}
+
+impl DisplayAs for MatchRecognizeExec {
+    fn fmt_as(
+        &self,
+        t: DisplayFormatType,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        let definitions = self
+            .symbols
+            .iter()
+            .zip(&self.definitions)
+            .filter_map(|(symbol, definition)| {
+                definition
+                    .as_ref()
+                    .map(|definition| self.definition_display(symbol, definition))
+            })
+            .collect::<Vec<_>>();
+        match t {
+            DisplayFormatType::Default | DisplayFormatType::Verbose => {
+                write!(f, "MatchRecognizeExec: ")?;
+                if !self.partition_by.is_empty() {
+                    let partition_by = self
+                        .partition_by
+                        .iter()
+                        .map(|e| e.to_string())
+                        .collect::<Vec<_>>();
+                    write!(f, "partition_by=[{}], ", partition_by.join(", "))?;
+                }
+                if !self.order_by.is_empty() {
+                    let order_by = self
+                        .order_by
+                        .iter()
+                        .map(|e| e.to_string())
+                        .collect::<Vec<_>>();
+                    write!(f, "order_by=[{}], ", order_by.join(", "))?;
+                }
+                write!(
+                    f,
+                    "measures=[{}], rows_per_match={}, after_match_skip={}, pattern=[{}]",
+                    self.measure_names().join(", "),
+                    self.rows_per_match,
+                    self.after_match_skip,
+                    self.pattern
+                )?;
+                if !definitions.is_empty() {
+                    write!(f, ", define=[{}]", definitions.join(", "))?;
+                }
+                Ok(())
+            }
+            DisplayFormatType::TreeRender => {
+                writeln!(f, "pattern={}", self.pattern)?;
+                if !definitions.is_empty() {
+                    writeln!(f, "define={}", definitions.join(", "))?;
+                }
+                writeln!(f, "measures={}", self.measure_names().join(", "))
+            }
+        }
+    }
+}
+
+impl ExecutionPlan for MatchRecognizeExec {
+    fn name(&self) -> &'static str {
+        "MatchRecognizeExec"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn properties(&self) -> &PlanProperties {
+        &self.cache
+    }
+
+    fn children(&self) -> Vec<&Arc<dyn ExecutionPlan>> {
+        vec![&self.input]
+    }
+
+    fn required_input_ordering(&self) -> Vec<Option<OrderingRequirements>> {
+        let partition_by = self
+            .partition_by
+            .iter()
+            .map(|expr| PhysicalSortRequirement::new(Arc::clone(expr), None));
+        let order_by = self.order_by.iter().map(|sort| {
+            PhysicalSortRequirement::new(Arc::clone(&sort.expr), Some(sort.options))
+        });
+        vec![LexRequirement::new(partition_by.chain(order_by))
+            .map(OrderingRequirements::new)]
+    }
+
+    fn required_input_distribution(&self) -> Vec<Distribution> {
+        if self.partition_by.is_empty() {
+            vec![Distribution::SinglePartition]
+        } else {
+            vec![Distribution::HashPartitioned(self.partition_by.clone())]
+        }
+    }
+
+    fn with_new_children(
+        self: Arc<Self>,
+        children: Vec<Arc<dyn ExecutionPlan>>,
+    ) -> Result<Arc<dyn ExecutionPlan>> {
+        Ok(Arc::new(MatchRecognizeExec::try_new(
+            Arc::clone(&children[0]),
+            self.partition_by.clone(),
+            self.order_by.clone(),
+            self.measures.clone(),
+            self.rows_per_match,
+            self.after_match_skip.clone(),
+            self.pattern.clone(),
+            self.symbols.clone(),
+            self.definitions.clone(),
+            Arc::clone(&self.schema),
+        )?))
+    }
+
+    fn execute(
+        &self,
+        partition: usize,
+        context: Arc<TaskContext>,
+    ) -> Result<SendableRecordBatchStream> {
+        let input_schema = self.input.schema();
+        let definitions = self
+            .definitions
+            .iter()
+            .map(|definition| {
+                definition
+                    .as_ref()
+                    .map(|definition| Definition::try_new(definition, &input_schema))
+                    .transpose()
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let lookbehind = definitions
+            .iter()
+            .flatten()
+            .map(|definition| definition.lookbehind)
+            .max()
+            .unwrap_or(0);
+        let input = self.input.execute(partition, Arc::clone(&context))?;
+        let reservation =
+            MemoryConsumer::new(format!("MatchRecognizeStream[{partition}]"))
+                .register(context.memory_pool());
+        Ok(Box::pin(MatchRecognizeStream {
+            schema: Arc::clone(&self.schema),
+            partition_by: self.partition_by.clone(),
+            evaluator: MatchEvaluator {
+                schema: Arc::clone(&self.schema),
+                partition_by: self.partition_by.clone(),
+                measures: self.measures.clone(),
+                rows_per_match: self.rows_per_match,
+                symbols: self.symbols.clone(),
+            },
+            matcher: Arc::clone(&self.matcher),
+            rows: PartitionRows::new(input.schema(), definitions),
+            input,
+            lookbehind,
+            partition_key: None,
+            state: MatcherState::default(),
+            num_matches: 0,
+            reservation,
+            output: VecDeque::new(),
+            finished: false,
+            baseline_metrics: BaselineMetrics::new(&self.metrics, partition),
+        }))
+    }
+
+    fn metrics(&self) -> Option<MetricsSet> {
+        Some(self.metrics.clone_inner())
+    }
+}
+
+/// Stream for [`MatchRecognizeExec`].
+///
+/// The rows of the current partition are matched as they arrive: a match
+/// attempt that needs rows which are not available yet is suspended and
+/// resumed with the next input batch, and the output of the matches found is
+/// emitted right away. Only the rows from the start of the match attempt in
+/// progress (and the rows before it accessed by `PREV`) are buffered, and
+/// they are accounted in a [`MemoryReservation`].
+struct MatchRecognizeStream {
+    schema: SchemaRef,
+    input: SendableRecordBatchStream,
+    partition_by: Vec<Arc<dyn PhysicalExpr>>,
+    evaluator: MatchEvaluator,
+    matcher: Arc<PatternMatcher>,
+    /// The buffered rows of the current partition
+    rows: PartitionRows,
+    /// The number of rows before the current one accessed by the definitions
+    lookbehind: usize,
+    /// The partition key of the current partition
+    partition_key: Option<Vec<ScalarValue>>,
+    /// The progress of the matching in the current partition
+    state: MatcherState,
+    /// The number of matches found in the current partition
+    num_matches: usize,
+    /// Memory used by the buffered rows
+    reservation: MemoryReservation,
+    /// Output of the matches found
+    output: VecDeque<RecordBatch>,
+    finished: bool,
+    baseline_metrics: BaselineMetrics,
+}
+
+impl MatchRecognizeStream {
+    /// Adds the rows of `batch` to the partitions they belong to and matches
+    /// them
+    fn push_batch(&mut self, batch: RecordBatch) -> Result<()> {
+        let _timer = self.baseline_metrics.elapsed_compute().timer();
+        if self.partition_by.is_empty() {
+            return self.push_rows(batch);
+        }
+
+        let keys = self
+            .partition_by
+            .iter()
+            .map(|expr| expr.evaluate(&batch)?.into_array(batch.num_rows()))
+            .collect::<Result<Vec<_>>>()?;
+        let sort_columns = keys
+            .iter()
+            .map(|values| SortColumn {
+                values: Arc::clone(values),
+                options: None,
+            })
+            .collect::<Vec<_>>();
+        for range in evaluate_partition_ranges(batch.num_rows(), &sort_columns)? {
+            let key = keys
+                .iter()
+                .map(|values| ScalarValue::try_from_array(values, range.start))
+                .collect::<Result<Vec<_>>>()?;
+            if self.partition_key.as_ref().is_some_and(|k| *k != key) {
+                self.finish_partition()?;
+            }
+            self.partition_key = Some(key);
+            self.push_rows(batch.slice(range.start, range.len()))?;
+        }
+        Ok(())
+    }
+
+    /// Adds rows to the current partition and matches them
+    fn push_rows(&mut self, batch: RecordBatch) -> Result<()> {
+        self.rows.push(batch);
+        self.reservation.try_resize(self.rows.memory_size())?;
+        self.match_rows()
+    }
+
+    /// Matches the rest of the current partition, and starts a new one
+    fn finish_partition(&mut self) -> Result<()> {
+        self.rows.complete = true;
+        self.match_rows()?;
+        self.rows.clear();
+        self.state = MatcherState::default();
+        self.num_matches = 0;
+        self.reservation.free();
+        Ok(())
+    }
+
+    /// Resumes matching the current partition with the buffered rows,
+    /// producing the output of the matches found
+    fn match_rows(&mut self) -> Result<()> {
+        let mut matches = self.matcher.find_matches(&mut self.state, &mut self.rows)?;
+        if !matches.is_empty() {
+            let batch = self.rows.concat()?;
+            for m in &mut matches {
+                m.start -= self.rows.base;
+            }
+            if let Some(output) =
+                self.evaluator
+                    .evaluate(&batch, &matches, self.num_matches)?
+            {
+                self.output.push_back(output);
+            }
+            self.num_matches += matches.len();
+        }
+        // The rows before the next match attempt are only needed by `PREV`
+        self.rows
+            .discard_before(self.state.start.saturating_sub(self.lookbehind))
+    }
+
+    #[inline]
+    fn poll_next_inner(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<RecordBatch>>> {
+        loop {
+            if let Some(batch) = self.output.pop_front() {
+                return Poll::Ready(Some(Ok(batch)));
+            }
+            if self.finished {
+                return Poll::Ready(None);
+            }
+            let result = match ready!(self.input.poll_next_unpin(cx)) {
+                Some(Ok(batch)) => self.push_batch(batch),
+                Some(Err(e)) => Err(e),
+                None => {
+                    self.finished = true;
+                    let _timer = self.baseline_metrics.elapsed_compute().timer();
+                    self.finish_partition()
+                }
+            };
+            if let Err(e) = result {
+                return Poll::Ready(Some(Err(e)));
+            }
+        }
+    }
+}
+
+impl Stream for MatchRecognizeStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        let poll = self.poll_next_inner(cx);
+        self.baseline_metrics.record_poll(poll)
+    }
+}
+
+impl RecordBatchStream for MatchRecognizeStream {
+    fn schema(&self) -> SchemaRef {
+        Arc::clone(&self.schema)
+    }
+}
+
+/// The predicate of a pattern variable, with the number of rows before and
+/// after the current one accessed by its `PREV` and `NEXT` calls
+#[derive(Debug)]
+struct Definition {
+    predicate: Arc<dyn PhysicalExpr>,
+    /// The aggregates of the match in progress the predicate refers to
+    references: Vec<MatchMeasureExpr>,
+    /// The input schema followed by the columns of the references
+    schema: SchemaRef,
+    lookbehind: usize,
+    lookahead: usize,
+}
+
+impl Definition {
+    fn try_new(definition: &MatchDefinitionExpr, input_schema: &Schema) -> Result<Self> {
+        let MatchDefinitionExpr {
+            predicate,
+            references,
+        } = definition;
+        let mut fields = input_schema.fields().to_vec();
+        for (i, reference) in references.iter().enumerate() {
+            let MatchMeasureExpr::Aggregate { expr, .. } = reference else {
+                return internal_err!(
+                    "MATCH_RECOGNIZE definitions can only refer to aggregates"
+                );
+            };
+            fields.push(Arc::new(Field::new(
+                format!("__match_recognize_ref_{i}"),
+                expr.field().data_type().clone(),
+                true,
+            )));
+        }
+
+        // Nested calls add up their offsets
+        let mut lookbehind = 0;
+        let mut lookahead = 0;
+        predicate.apply(|expr| {
+            let Some(function) = expr.as_any().downcast_ref::<ScalarFunctionExpr>()
+            else {
+                return Ok(TreeNodeRecursion::Continue);
+            };
+            let Some(navigation) = function
+                .fun()
+                .inner()
+                .as_any()
+                .downcast_ref::<MatchNavigation>()
+            else {
+                return Ok(TreeNodeRecursion::Continue);
+            };
+            let offset = function
+                .args()
+                .get(1)
+                .and_then(|offset| offset.as_any().downcast_ref::<Literal>())
+                .map(|offset| offset.value());
+            let Some(ScalarValue::Int64(Some(offset @ 0..))) = offset else {
+                return internal_err!(
+                    "{} expects a constant non-negative Int64 offset",
+                    function.name()
+                );
+            };
+            if navigation.is_forward() {
+                lookahead += *offset as usize;
+            } else {
+                lookbehind += *offset as usize;
+            }
+            Ok(TreeNodeRecursion::Continue)
+        })?;
+        Ok(Self {
+            predicate: Arc::clone(predicate),
+            references: references.clone(),
+            schema: Arc::new(Schema::new(fields)),
+            lookbehind,
+            lookahead,
+        })
+    }
+
+    /// Evaluates the predicate on the row with index `pos` of the partition,
+    /// when the rows of the match starting at `start` before it are mapped to
+    /// `labels` and the row itself to `symbol`. `batch` holds the rows of the
+    /// partition from `base` on.
+    ///
+    /// The references are evaluated for each row, as they depend on the match
+    /// in progress.
+    fn evaluate_dependent(
+        &self,
+        batch: &RecordBatch,
+        base: usize,
+        symbol: usize,
+        start: usize,
+        labels: &[usize],
+        pos: usize,
+    ) -> Result<bool> {
+        let from = pos.saturating_sub(self.lookbehind);
+        let to = (pos + self.lookahead + 1).min(base + batch.num_rows());
+        let rows = batch.slice(from - base, to - from);
+        let num_rows = rows.num_rows();
+        let mut columns = rows.columns().to_vec();
+        for reference in &self.references {
+            let MatchMeasureExpr::Aggregate {
+                expr,
+                filter,
+                symbol: aggregated,
+            } = reference
+            else {
+                return internal_err!(
+                    "MATCH_RECOGNIZE definitions can only refer to aggregates"
+                );
+            };
+            let indices = labels
+                .iter()
+                .chain(std::iter::once(&symbol))
+                .enumerate()
+                .filter(|(_, s)| aggregated.is_none_or(|aggregated| aggregated == **s))
+                .map(|(offset, _)| (start + offset - base) as u32)
+                .collect::<UInt32Array>();
+            let mut accumulator = expr.create_accumulator()?;
+            if !indices.is_empty() {
+                let match_rows = take_record_batch(batch, &indices)?;
+                // Order sensitive aggregates expect their ordering columns
+                // after the arguments
+                let mut args = expr
+                    .expressions()
+                    .iter()
+                    .chain(expr.order_bys().iter().map(|sort| &sort.expr))
+                    .map(|arg| arg.evaluate(&match_rows)?.into_array(indices.len()))
+                    .collect::<Result<Vec<_>>>()?;
+                if let Some(filter) = filter {
+                    let values =
+                        filter.evaluate(&match_rows)?.into_array(indices.len())?;
+                    let values = as_boolean_array(&values)?;
+                    args = args
+                        .iter()
+                        .map(|arg| Ok(arrow::compute::filter(arg, values)?))
+                        .collect::<Result<Vec<_>>>()?;
+                }
+                accumulator.update_batch(&args)?;
+            }
+            columns.push(accumulator.evaluate()?.to_array_of_size(num_rows)?);
+        }
+        let options = RecordBatchOptions::new().with_row_count(Some(num_rows));
+        let rows = RecordBatch::try_new_with_options(
+            Arc::clone(&self.schema),
+            columns,
+            &options,
+        )?;
+        let values = self.predicate.evaluate(&rows)?.into_array(num_rows)?;
+        let values = as_boolean_array(&values)?;
+        let i = pos - from;
+        Ok(values.is_valid(i) && values.value(i))
+    }
+}
+
+/// The buffered rows of the partition being matched
+///
+/// The pushed batches are kept as they are, and only concatenated when rows
+/// spanning several of them are accessed, so that buffering a long partition
+/// does not copy the rows buffered before over and over.
+struct PartitionRows {
+    schema: SchemaRef,
+    /// The batches holding the rows of the partition from `base` on
+    batches: VecDeque<RecordBatch>,
+    /// The number of rows of the first batch before the row `base`
+    offset: usize,
+    /// The number of buffered rows
+    num_rows: usize,
+    /// The index within the partition of the first buffered row
+    base: usize,
+    /// Whether all the rows of the partition are buffered
+    complete: bool,
+    /// The definition of each pattern variable, if any
+    definitions: Vec<Option<Definition>>,
+    /// The values of the definitions of each pattern variable for
+    /// consecutive rows, starting at the row with the given index
+    values: Vec<Option<(usize, Vec<bool>)>>,
+}
+
+impl PartitionRows {
+    fn new(schema: SchemaRef, definitions: Vec<Option<Definition>>) -> Self {
+        Self {
+            schema,
+            batches: VecDeque::new(),
+            offset: 0,
+            num_rows: 0,
+            base: 0,
+            complete: false,
+            values: vec![None; definitions.len()],
+            definitions,
+        }
+    }
+
+    /// The index within the partition of the row following the buffered rows
+    fn end(&self) -> usize {
+        self.base + self.num_rows
+    }
+
+    fn memory_size(&self) -> usize {
+        self.batches.iter().map(get_record_batch_memory_size).sum()
+    }
+
+    /// Appends rows of the partition
+    fn push(&mut self, batch: RecordBatch) {
+        if batch.num_rows() > 0 {
+            self.num_rows += batch.num_rows();
+            self.batches.push_back(batch);
+        }
+    }
+
+    /// Returns the buffered rows with index `from..to`, concatenating them
+    /// if they span several batches
+    fn slice(&self, from: usize, to: usize) -> Result<RecordBatch> {
+        let mut skip = from - self.base + self.offset;
+        let mut len = to - from;
+        let mut slices = vec![];
+        for batch in &self.batches {
+            if len == 0 {
+                break;
+            }
+            if skip >= batch.num_rows() {
+                skip -= batch.num_rows();
+                continue;
+            }
+            let num_rows = len.min(batch.num_rows() - skip);
+            slices.push(batch.slice(skip, num_rows));
+            skip = 0;
+            len -= num_rows;
+        }
+        match slices.as_slice() {
+            [batch] => Ok(batch.clone()),
+            _ => Ok(concat_batches(&self.schema, &slices)?),
+        }
+    }
+
+    /// Concatenates all the buffered rows into a single batch, and returns it
+    fn concat(&mut self) -> Result<RecordBatch> {
+        let batch = self.slice(self.base, self.end())?;
+        self.batches = VecDeque::from([batch.clone()]);
+        self.offset = 0;
+        Ok(batch)
+    }
+
+    /// Discards the buffered rows before the row with index `start`
+    fn discard_before(&mut self, start: usize) -> Result<()> {
+        let start = start.clamp(self.base, self.end());
+        self.offset += start - self.base;
+        self.num_rows -= start - self.base;
+        self.base = start;
+        while let Some(first) = self.batches.front() {
+            if self.offset < first.num_rows() {
+                break;
+            }
+            self.offset -= first.num_rows();
+            self.batches.pop_front();
+        }
+
+        // Copy the rest of the first batch once at least half of it is
+        // discarded, so that the memory of the discarded rows is released
+        if let Some(first) = self.batches.front_mut() {
+            if self.offset > 0 && self.offset >= first.num_rows() / 2 {
+                let indices = UInt32Array::from_iter_values(
+                    self.offset as u32..first.num_rows() as u32,
+                );
+                *first = take_record_batch(first, &indices)?;
+                self.offset = 0;
+            }
+        }
+        Ok(())
+    }
+
+    /// Discards all the rows, to start a new partition
+    fn clear(&mut self) {
+        self.batches.clear();
+        self.offset = 0;
+        self.num_rows = 0;
+        self.base = 0;
+        self.complete = false;
+        self.values.iter_mut().for_each(|values| *values = None);
+    }
+}
+
+impl MatchRows for PartitionRows {
+    fn satisfies(
+        &mut self,
+        symbol: usize,
+        start: usize,
+        labels: &[usize],
+        pos: usize,
+    ) -> Result<Option<bool>> {
+        let Some(definition) = &self.definitions[symbol] else {
+            return Ok(Some(true));
+        };
+        let end = self.end();
+        if !self.complete && pos + definition.lookahead >= end {
+            return Ok(None);
+        }
+        if pos.saturating_sub(definition.lookbehind) < self.base {
+            return internal_err!(
+                "MATCH_RECOGNIZE row {} is not buffered anymore, the first buffered row is {}",
+                pos.saturating_sub(definition.lookbehind),
+                self.base
+            );
+        }
+        if !definition.references.is_empty() {
+            // The rows of the match in progress and the rows accessed by the
+            // definition
+            let from = start.min(pos.saturating_sub(definition.lookbehind));
+            let to = (pos + definition.lookahead + 1).min(end);
+            let batch = self.slice(from, to)?;
+            return definition
+                .evaluate_dependent(&batch, from, symbol, start, labels, pos)
+                .map(Some);
+        }
+        if let Some((first, values)) = &self.values[symbol] {
+            if let Some(value) = pos.checked_sub(*first).and_then(|i| values.get(i)) {
+                return Ok(Some(*value));
+            }
+        }
+
+        // Evaluate the definition on all the rows from `pos` on whose
+        // following rows are available, including the preceding and
+        // following rows they access
+        let last = if self.complete {
+            end
+        } else {
+            end - definition.lookahead
+        };
+        let from = pos.saturating_sub(definition.lookbehind);
+        let to = (last + definition.lookahead).min(end);
+        let batch = self.slice(from, to)?;
+        let values = definition
+            .predicate
+            .evaluate(&batch)?
+            .into_array(batch.num_rows())?;
+        let values = as_boolean_array(&values)?;
+        let values = (pos - from..last - from)
+            .map(|i| values.is_valid(i) && values.value(i))
+            .collect::<Vec<_>>();
+        let satisfied = values[0];
+        self.values[symbol] = Some((pos, values));
+        Ok(Some(satisfied))
+    }
+
+    fn is_end(&self, pos: usize) -> Option<bool> {
+        if pos < self.end() {
+            Some(false)
+        } else if self.complete {
+            Some(true)
+        } else {
+            None
+        }
+    }
+}
+
+/// Computes the output of the matches of [`MatchRecognizeExec`]
+struct MatchEvaluator {
+    schema: SchemaRef,
+    partition_by: Vec<Arc<dyn PhysicalExpr>>,
+    measures: Vec<MatchMeasureExpr>,
+    rows_per_match: RowsPerMatch,
+    symbols: Vec<String>,
+}
+
+/// The arguments of an aggregate measure, evaluated on the buffered rows
+struct AggregateInput {
+    args: Vec<ArrayRef>,
+    filter: Option<BooleanArray>,
+    symbol: Option<usize>,
+}
+
+impl AggregateInput {
+    /// Whether row `start + offset` of the match is aggregated
+    fn includes(&self, m: &PatternMatch, offset: usize) -> bool {
+        self.symbol.is_none_or(|symbol| m.labels[offset] == symbol)
+            && self.filter.as_ref().is_none_or(|filter| {
+                filter.is_valid(m.start + offset) && filter.value(m.start + offset)
+            })
+    }
+}
+
+impl MatchEvaluator {
+    /// Returns the output for `matches` of the rows of `batch`, or `None` if
+    /// they produce no rows. `num_previous` is the number of matches found
+    /// before in the partition.
+    fn evaluate(
+        &self,
+        batch: &RecordBatch,
+        matches: &[PatternMatch],
+        num_previous: usize,
+    ) -> Result<Option<RecordBatch>> {
+        let num_rows = batch.num_rows();
+        let output_rows = match self.rows_per_match {
+            RowsPerMatch::OneRow => matches.len(),
+            RowsPerMatch::AllRows => matches.iter().map(|m| m.labels.len()).sum(),
+        };
+        if output_rows == 0 {
+            return Ok(None);
+        }
+
+        let aggregate_inputs = self
+            .measures
+            .iter()
+            .map(|measure| {
+                let MatchMeasureExpr::Aggregate {
+                    expr,
+                    filter,
+                    symbol,
+                } = measure
+                else {
+                    return Ok(None);
+                };
+                // Order sensitive aggregates expect their ordering columns
+                // after the arguments
+                let args = expr
+                    .expressions()
+                    .iter()
+                    .chain(expr.order_bys().iter().map(|sort| &sort.expr))
+                    .map(|arg| arg.evaluate(batch)?.into_array(num_rows))
+                    .collect::<Result<Vec<_>>>()?;
+                let filter = filter
+                    .as_ref()
+                    .map(|filter| {
+                        let values = filter.evaluate(batch)?.into_array(num_rows)?;
+                        Ok::<_, datafusion_common::DataFusionError>(
+                            as_boolean_array(&values)?.clone(),
+                        )
+                    })
+                    .transpose()?;
+                Ok(Some(AggregateInput {
+                    args,
+                    filter,
+                    symbol: *symbol,
+                }))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut columns = match self.rows_per_match {
+            RowsPerMatch::OneRow => self.partition_columns(batch, matches.len())?,
+            RowsPerMatch::AllRows => {
+                let indices = matches
+                    .iter()
+                    .flat_map(|m| (m.start..m.start + m.labels.len()).map(|i| i as u32))
+                    .collect::<UInt32Array>();
+                batch
+                    .columns()
+                    .iter()
+                    .map(|column| Ok(take(column, &indices, None)?))
+                    .collect::<Result<Vec<_>>>()?
+            }
+        };
+        for (measure, input) in self.measures.iter().zip(&aggregate_inputs) {
+            let values = match self.rows_per_match {
+                RowsPerMatch::OneRow => {
+                    self.one_row_measure(measure, input, matches, num_previous)?
+                }
+                RowsPerMatch::AllRows => {
+                    self.all_rows_measure(measure, input, matches, num_previous)?
+                }
+            };
+            columns.push(ScalarValue::iter_to_array(values)?);
+        }
+
+        let options = RecordBatchOptions::new().with_row_count(Some(output_rows));
+        Ok(Some(RecordBatch::try_new_with_options(
+            Arc::clone(&self.schema),
+            columns,
+            &options,
+        )?))
+    }
+
+    /// The partition columns of the output, one row per match
+    fn partition_columns(
+        &self,
+        batch: &RecordBatch,
+        num_matches: usize,
+    ) -> Result<Vec<ArrayRef>> {
+        self.partition_by
+            .iter()
+            .map(|expr| {
+                let values = expr.evaluate(batch)?.into_array(batch.num_rows())?;
+                // All rows of the partition have the same value
+                ScalarValue::try_from_array(&values, 0)?.to_array_of_size(num_matches)
+            })
+            .collect()
+    }
+
+    /// The values of a measure, one per match
+    fn one_row_measure(
+        &self,
+        measure: &MatchMeasureExpr,
+        input: &Option<AggregateInput>,
+        matches: &[PatternMatch],
+        num_previous: usize,
+    ) -> Result<Vec<ScalarValue>> {
+        matches
+            .iter()
+            .enumerate()
+            .map(|(i, m)| match measure {
+                MatchMeasureExpr::Aggregate { expr, .. } => {
+                    let Some(input) = input else {
+                        return internal_err!("Missing input of aggregate measure");
+                    };
+                    let mut accumulator = expr.create_accumulator()?;
+                    let indices = (0..m.labels.len())
+                        .filter(|offset| input.includes(m, *offset))
+                        .map(|offset| (m.start + offset) as u32)
+                        .collect::<UInt32Array>();
+                    if !indices.is_empty() {
+                        let values = input
+                            .args
+                            .iter()
+                            .map(|arg| Ok(take(arg, &indices, None)?))
+                            .collect::<Result<Vec<_>>>()?;
+                        accumulator.update_batch(&values)?;
+                    }
+                    accumulator.evaluate()
+                }
+                MatchMeasureExpr::MatchNumber => {
+                    Ok(ScalarValue::UInt64(Some((num_previous + i) as u64 + 1)))
+                }
+                MatchMeasureExpr::Classifier => Ok(ScalarValue::Utf8(
+                    m.labels.last().map(|symbol| self.symbols[*symbol].clone()),
+                )),
+            })
+            .collect()
+    }
+
+    /// The values of a measure, one per matched row. Aggregates are
+    /// evaluated on the rows of the match up to the current row.
+    fn all_rows_measure(
+        &self,
+        measure: &MatchMeasureExpr,
+        input: &Option<AggregateInput>,
+        matches: &[PatternMatch],
+        num_previous: usize,
+    ) -> Result<Vec<ScalarValue>> {
+        let mut values = vec![];
+        for (i, m) in matches.iter().enumerate() {
+            match measure {
+                MatchMeasureExpr::Aggregate { expr, .. } => {
+                    let Some(input) = input else {
+                        return internal_err!("Missing input of aggregate measure");
+                    };
+                    let mut accumulator = expr.create_accumulator()?;
+                    for offset in 0..m.labels.len() {
+                        if input.includes(m, offset) {
+                            let row = input
+                                .args
+                                .iter()
+                                .map(|arg| arg.slice(m.start + offset, 1))
+                                .collect::<Vec<_>>();
+                            accumulator.update_batch(&row)?;
+                        }
+                        values.push(accumulator.evaluate()?);
+                    }
+                }
+                MatchMeasureExpr::MatchNumber => values.extend(std::iter::repeat_n(
+                    ScalarValue::UInt64(Some((num_previous + i) as u64 + 1)),
+                    m.labels.len(),
+                )),
+                MatchMeasureExpr::Classifier => {
+                    values.extend(m.labels.iter().map(|symbol| {
+                        ScalarValue::Utf8(Some(self.symbols[*symbol].clone()))
+                    }))
+                }
+            }
+        }
+        Ok(values)
+    }
+}
+
+/// A match of the pattern
+#[derive(Debug, PartialEq)]
+struct PatternMatch {
+    /// The index of the first row of the match, within the partition or the
+    /// buffered rows
+    start: usize,
+    /// The symbol each row of the match is mapped to
+    labels: Vec<usize>,
+}
+
+/// An instruction of a compiled [`RowPattern`]
+#[derive(Debug, Clone)]
+enum Inst {
+    /// Consumes the current row if it satisfies the symbol
+    Symbol(usize),
+    /// Succeeds at the start of the partition
+    Start,
+    /// Succeeds at the end of the partition
+    End,
+    /// Continues at the first target, and on failure at the second one
+    Split(usize, usize),
+    /// Continues at the target
+    Jump(usize),
+    /// Remembers the current row in the slot
+    Save(usize),
+    /// Fails unless a row was consumed since the slot was saved, which stops
+    /// unbounded repetitions of patterns matching no rows
+    Progress(usize),
+    /// The pattern matched
+    Match,
+}
+
+/// Where the next match attempt starts after a match
+#[derive(Debug)]
+enum Skip {
+    PastLastRow,
+    ToNextRow,
+    ToFirst(usize),
+    ToLast(usize),
+}
+
+/// Matches a [`RowPattern`] using a backtracking virtual machine, which
+/// doesn't recurse so long matches can't overflow the stack
+#[derive(Debug)]
+struct PatternMatcher {
+    program: Vec<Inst>,
+    num_slots: usize,
+    skip: Skip,
+    symbols: Vec<String>,
+}
+
+impl PatternMatcher {
+    fn try_new(
+        pattern: &RowPattern,
+        after_match_skip: &AfterMatchSkip,
+        symbols: &[String],
+    ) -> Result<Self> {
+        let symbol_index = |symbol: &str| match symbols.iter().position(|s| s == symbol) {
+            Some(index) => Ok(index),
+            None => internal_err!("Unknown pattern variable {symbol}"),
+        };
+        let mut matcher = Self {
+            program: vec![],
+            num_slots: 0,
+            skip: match after_match_skip {
+                AfterMatchSkip::PastLastRow => Skip::PastLastRow,
+                AfterMatchSkip::ToNextRow => Skip::ToNextRow,
+                AfterMatchSkip::ToFirst(symbol) => Skip::ToFirst(symbol_index(symbol)?),
+                AfterMatchSkip::ToLast(symbol) => Skip::ToLast(symbol_index(symbol)?),
+            },
+            symbols: symbols.to_vec(),
+        };
+        matcher.compile(pattern, &symbol_index)?;
+        matcher.program.push(Inst::Match);
+        Ok(matcher)
+    }
+
+    fn compile(
+        &mut self,
+        pattern: &RowPattern,
+        symbol_index: &dyn Fn(&str) -> Result<usize>,
+    ) -> Result<()> {
+        match pattern {
+            RowPattern::Symbol(symbol) => {
+                self.program.push(Inst::Symbol(symbol_index(symbol)?))
+            }
+            RowPattern::Start => self.program.push(Inst::Start),
+            RowPattern::End => self.program.push(Inst::End),
+            RowPattern::Concat(patterns) => {
+                for pattern in patterns {
+                    self.compile(pattern, symbol_index)?;
+                }
+            }
+            RowPattern::Alternation(patterns) => {
+                let mut jumps = vec![];
+                for (i, pattern) in patterns.iter().enumerate() {
+                    if i + 1 < patterns.len() {
+                        let split = self.program.len();
+                        self.program.push(Inst::Split(split + 1, 0));
+                        self.compile(pattern, symbol_index)?;
+                        jumps.push(self.program.len());
+                        self.program.push(Inst::Jump(0));
+                        let next = self.program.len();
+                        self.program[split] = Inst::Split(split + 1, next);
+                    } else {
+                        self.compile(pattern, symbol_index)?;
+                    }
+                }
+                let end = self.program.len();
+                for jump in jumps {
+                    self.program[jump] = Inst::Jump(end);
+                }
+            }
+            RowPattern::Repetition { pattern, min, max } => {
+                for _ in 0..*min {
+                    self.compile(pattern, symbol_index)?;
+                }
+                match max {
+                    None => {
+                        let slot = self.num_slots;
+                        self.num_slots += 1;
+                        let split = self.program.len();
+                        self.program.push(Inst::Split(split + 1, 0));
+                        self.program.push(Inst::Save(slot));
+                        self.compile(pattern, symbol_index)?;
+                        self.program.push(Inst::Progress(slot));
+                        self.program.push(Inst::Jump(split));
+                        let end = self.program.len();
+                        self.program[split] = Inst::Split(split + 1, end);
+                    }
+                    Some(max) if max < min => {
+                        return exec_err!(
+                            "Invalid pattern quantifier {{{min},{max}}}: the maximum is smaller than the minimum"
+                        );
+                    }
+                    Some(max) => {
+                        let mut splits = vec![];
+                        for _ in *min..*max {
+                            splits.push(self.program.len());
+                            self.program.push(Inst::Split(0, 0));
+                            self.compile(pattern, symbol_index)?;
+                        }
+                        let end = self.program.len();
+                        for split in splits {
+                            self.program[split] = Inst::Split(split + 1, end);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Finds the matches in the rows of a partition, resuming from `state`.
+    ///
+    /// Returns when the end of the partition is reached, or when a match
+    /// attempt needs rows that are not available yet, in which case it is
+    /// resumed by the next call.
+    fn find_matches(
+        &self,
+        state: &mut MatcherState,
+        rows: &mut dyn MatchRows,
+    ) -> Result<Vec<PatternMatch>> {
+        let mut matches = vec![];
+        loop {
+            let start = state.start;
+            let mut attempt = match state.attempt.take() {
+                Some(attempt) => attempt,
+                None if rows.is_end(start) == Some(false) => {
+                    MatchAttempt::new(start, self.num_slots)
+                }
+                // The partition ended, or its next row is not available yet
+                None => return Ok(matches),
+            };
+            match self.resume(start, &mut attempt, rows)? {
+                Outcome::Matched(labels) => {
+                    state.start = self.next_start(start, &labels)?;
+                    matches.push(PatternMatch { start, labels });
+                }
+                Outcome::Failed => state.start += 1,
+                Outcome::Pending => {
+                    state.attempt = Some(attempt);
+                    return Ok(matches);
+                }
+            }
+        }
+    }
+
+    /// Where the next match attempt starts after the match of `labels`
+    /// starting at `start`
+    fn next_start(&self, start: usize, labels: &[usize]) -> Result<usize> {
+        Ok(match self.skip {
+            Skip::PastLastRow => start + labels.len().max(1),
+            Skip::ToNextRow => start + 1,
+            Skip::ToFirst(symbol) | Skip::ToLast(symbol) => {
+                let mut positions =
+                    labels.iter().enumerate().filter(|(_, s)| **s == symbol);
+                let position = if matches!(self.skip, Skip::ToFirst(_)) {
+                    positions.next()
+                } else {
+                    positions.last()
+                };
+                match position {
+                    Some((offset, _)) if offset > 0 => start + offset,
+                    _ => {
+                        return exec_err!(
+                            "AFTER MATCH SKIP {} failed: the match starting at row {} has no row to skip to",
+                            self.skip_display(),
+                            start + 1
+                        )
+                    }
+                }
+            }
+        })
+    }
+
+    fn skip_display(&self) -> String {
+        match self.skip {
+            Skip::PastLastRow => "PAST LAST ROW".to_string(),
+            Skip::ToNextRow => "TO NEXT ROW".to_string(),
+            Skip::ToFirst(symbol) => format!("TO FIRST {}", self.symbols[symbol]),
+            Skip::ToLast(symbol) => format!("TO LAST {}", self.symbols[symbol]),
+        }
+    }
+
+    /// Runs the match attempt starting at `start` until it finds the
+    /// preferred match, fails, or needs rows that are not available yet
+    fn resume(
+        &self,
+        start: usize,
+        attempt: &mut MatchAttempt,
+        rows: &mut dyn MatchRows,
+    ) -> Result<Outcome> {
+        let MatchAttempt {
+            pc,
+            pos,
+            labels,
+            slots,
+            backtrack,
+        } = attempt;
+        loop {
+            let matched = match &self.program[*pc] {
+                Inst::Symbol(symbol) => {
+                    labels.truncate(*pos - start);
+                    let matched = match rows.is_end(*pos) {
+                        None => return Ok(Outcome::Pending),
+                        Some(true) => false,
+                        Some(false) => {
+                            match rows.satisfies(*symbol, start, labels, *pos)? {
+                                None => return Ok(Outcome::Pending),
+                                Some(matched) => matched,
+                            }
+                        }
+                    };
+                    if matched {
+                        labels.push(*symbol);
+                        *pos += 1;
+                        *pc += 1;
+                    }
+                    matched
+                }
+                Inst::Start => {
+                    *pc += 1;
+                    *pos == 0
+                }
+                Inst::End => {
+                    let Some(end) = rows.is_end(*pos) else {
+                        return Ok(Outcome::Pending);
+                    };
+                    *pc += 1;
+                    end
+                }
+                Inst::Split(preferred, alternative) => {
+                    backtrack.push((*alternative, *pos, slots.clone()));
+                    *pc = *preferred;
+                    true
+                }
+                Inst::Jump(target) => {
+                    *pc = *target;
+                    true
+                }
+                Inst::Save(slot) => {
+                    slots[*slot] = *pos;
+                    *pc += 1;
+                    true
+                }
+                Inst::Progress(slot) => {
+                    *pc += 1;
+                    slots[*slot] != *pos
+                }
+                Inst::Match => {
+                    labels.truncate(*pos - start);
+                    return Ok(Outcome::Matched(std::mem::take(labels)));
+                }
+            };
+            if !matched {
+                match backtrack.pop() {
+                    Some(alternative) => (*pc, *pos, *slots) = alternative,
+                    None => return Ok(Outcome::Failed),
+                }
+            }
+        }
+    }
+}
+
+/// Tells which rows of a partition satisfy the pattern variables, for
+/// [`PatternMatcher::find_matches`]
+trait MatchRows {
+    /// Whether the row with index `pos` satisfies the definition of `symbol`,
+    /// when the rows of the match starting at `start` before it are mapped to
+    /// `labels`. Returns `None` if more rows are needed to tell.
+    fn satisfies(
+        &mut self,
+        symbol: usize,
+        start: usize,
+        labels: &[usize],
+        pos: usize,
+    ) -> Result<Option<bool>>;
+
+    /// Whether `pos` is past the last row of the partition, or `None` if more
+    /// rows are needed to tell
+    fn is_end(&self, pos: usize) -> Option<bool>;
+}
+
+/// The progress of a [`PatternMatcher`] in a partition
+#[derive(Debug, Default)]
+struct MatcherState {
+    /// Where the next match attempt starts
+    start: usize,
+    /// The suspended match attempt starting at `start`, if any
+    attempt: Option<MatchAttempt>,
+}
+
+/// The state of the virtual machine of a match attempt
+#[derive(Debug)]
+struct MatchAttempt {
+    /// The current instruction
+    pc: usize,
+    /// The current row
+    pos: usize,
+    /// The symbol each row of the match so far is mapped to
+    labels: Vec<usize>,
+    slots: Vec<usize>,
+    /// Alternatives to try on failure: instruction, row and slots
+    backtrack: Vec<(usize, usize, Vec<usize>)>,
+}
+
+impl MatchAttempt {
+    fn new(start: usize, num_slots: usize) -> Self {
+        Self {
+            pc: 0,
+            pos: start,
+            labels: vec![],
+            slots: vec![usize::MAX; num_slots],
+            backtrack: vec![],
+        }
+    }
+}
+
+/// The outcome of [`PatternMatcher::resume`]
+enum Outcome {
+    /// The preferred match, with the symbol each of its rows is mapped to
+    Matched(Vec<usize>),
+    /// There is no match
+    Failed,
+    /// More rows are needed
+    Pending,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::collect;
+    use crate::test::TestMemoryExec;
+
+    use arrow::array::{AsArray, Int32Array};
+    use arrow::datatypes::{DataType, Int32Type, UInt64Type};
+    use datafusion_common::DataFusionError;
+    use datafusion_execution::runtime_env::RuntimeEnvBuilder;
+
+    fn symbol(s: &str) -> RowPattern {
+        RowPattern::Symbol(s.to_string())
+    }
+
+    fn repeat(pattern: RowPattern, min: u32, max: Option<u32>) -> RowPattern {
+        RowPattern::Repetition {
+            pattern: Box::new(pattern),
+            min,
+            max,
+        }
+    }
+
+    /// Rows described by one string per symbol, where `x` marks the rows
+    /// satisfying the symbol, of which the first `available` ones are known
+    struct TestRows {
+        symbols: Vec<String>,
+        rows: Vec<Vec<bool>>,
+        available: usize,
+    }
+
+    impl TestRows {
+        fn new(rows: &[(&str, &str)]) -> Self {
+            Self {
+                symbols: rows.iter().map(|(s, _)| s.to_string()).collect(),
+                rows: rows
+                    .iter()
+                    .map(|(_, r)| r.chars().map(|c| c == 'x').collect())
+                    .collect(),
+                available: rows[0].1.len(),
+            }
+        }
+
+        /// Returns the matches as `(start, symbols)`
+        fn format(&self, matches: Vec<PatternMatch>) -> Vec<(usize, String)> {
+            matches
+                .into_iter()
+                .map(|m| {
+                    let labels =
+                        m.labels.iter().map(|l| self.symbols[*l].as_str()).collect();
+                    (m.start, labels)
+                })
+                .collect()
+        }
+    }
+
+    impl MatchRows for TestRows {
+        fn satisfies(
+            &mut self,
+            symbol: usize,
+            _start: usize,
+            _labels: &[usize],
+            pos: usize,
+        ) -> Result<Option<bool>> {
+            Ok(Some(self.rows[symbol][pos]))
+        }
+
+        fn is_end(&self, pos: usize) -> Option<bool> {
+            if pos < self.available {
+                Some(false)
+            } else if self.available == self.rows[0].len() {
+                Some(true)
+            } else {
+                None
+            }
+        }
+    }
+
+    /// Runs `pattern` on all the `rows` and returns the matches as
+    /// `(start, symbols)`
+    fn run(
+        pattern: RowPattern,
+        skip: AfterMatchSkip,
+        rows: &[(&str, &str)],
+    ) -> Result<Vec<(usize, String)>> {
+        let mut rows = TestRows::new(rows);
+        let matcher = PatternMatcher::try_new(&pattern, &skip, &rows.symbols)?;
+        let matches = matcher.find_matches(&mut MatcherState::default(), &mut rows)?;
+        Ok(rows.format(matches))
+    }
+
+    /// Runs `pattern` on the `rows` made available one at a time, and
+    /// returns the matches as `(available rows, start, symbols)`
+    fn run_row_by_row(
+        pattern: RowPattern,
+        rows: &[(&str, &str)],
+    ) -> Result<Vec<(usize, usize, String)>> {
+        let mut rows = TestRows::new(rows);
+        let matcher = PatternMatcher::try_new(
+            &pattern,
+            &AfterMatchSkip::PastLastRow,
+            &rows.symbols,
+        )?;
+        let mut state = MatcherState::default();
+        let mut found = vec![];
+        for available in 0..=rows.rows[0].len() {
+            rows.available = available;
+            let matches = matcher.find_matches(&mut state, &mut rows)?;
+            found.extend(
+                rows.format(matches)
+                    .into_iter()
+                    .map(|(start, labels)| (available, start, labels)),
+            );
+        }
+        Ok(found)
+    }
+
+    #[test]
+    fn greedy_repetition() -> Result<()> {
+        // a b+ c
+        let pattern = RowPattern::Concat(vec![
+            symbol("a"),
+            repeat(symbol("b"), 1, None),
+            symbol("c"),
+        ]);
+        let matches = run(
+            pattern,
+            AfterMatchSkip::PastLastRow,
+            &[("a", "x...x..."), ("b", ".xx..xx."), ("c", "...x...x")],
+        )?;
+        assert_eq!(
+            matches,
+            vec![(0, "abbc".to_string()), (4, "abbc".to_string())]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn row_by_row() -> Result<()> {
+        // a b+ c: a match is found as soon as its last row is available
+        let pattern = RowPattern::Concat(vec![
+            symbol("a"),
+            repeat(symbol("b"), 1, None),
+            symbol("c"),
+        ]);
+        let matches = run_row_by_row(
+            pattern,
+            &[("a", "x...x..."), ("b", ".xx..xx."), ("c", "...x...x")],
+        )?;
+        assert_eq!(
+            matches,
+            vec![(4, 0, "abbc".to_string()), (8, 4, "abbc".to_string())]
+        );
+
+        // a+ $: the match is only known at the end of the partition
+        let pattern =
+            RowPattern::Concat(vec![repeat(symbol("a"), 1, None), RowPattern::End]);
+        let matches = run_row_by_row(pattern, &[("a", ".xxx")])?;
+        assert_eq!(matches, vec![(4, 1, "aaa".to_string())]);
+        Ok(())
+    }
+
+    #[test]
+    fn backtracking() -> Result<()> {
+        // a* a: the repetition gives back the last row
+        let pattern = RowPattern::Concat(vec![repeat(symbol("a"), 0, None), symbol("b")]);
+        let matches = run(
+            pattern,
+            AfterMatchSkip::PastLastRow,
+            &[("a", "xxx."), ("b", "..xx")],
+        )?;
+        assert_eq!(matches, vec![(0, "aab".to_string()), (3, "b".to_string())]);
+        Ok(())
+    }
+
+    #[test]
+    fn alternation_prefers_first() -> Result<()> {
+        let pattern = RowPattern::Alternation(vec![
+            symbol("a"),
+            RowPattern::Concat(vec![symbol("b"), symbol("a")]),
+        ]);
+        let matches = run(
+            pattern,
+            AfterMatchSkip::PastLastRow,
+            &[("a", "xx.x"), ("b", "xxx.")],
+        )?;
+        assert_eq!(
+            matches,
+            vec![
+                (0, "a".to_string()),
+                (1, "a".to_string()),
+                (2, "ba".to_string())
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn bounded_repetition_and_anchors() -> Result<()> {
+        // ^ a{1,2}
+        let pattern =
+            RowPattern::Concat(vec![RowPattern::Start, repeat(symbol("a"), 1, Some(2))]);
+        let matches = run(pattern, AfterMatchSkip::PastLastRow, &[("a", "xxx")])?;
+        assert_eq!(matches, vec![(0, "aa".to_string())]);
+
+        // a $
+        let pattern = RowPattern::Concat(vec![symbol("a"), RowPattern::End]);
+        let matches = run(pattern, AfterMatchSkip::PastLastRow, &[("a", "xxx")])?;
+        assert_eq!(matches, vec![(2, "a".to_string())]);
+        Ok(())
+    }
+
+    #[test]
+    fn empty_matches() -> Result<()> {
+        // (a?)* must not loop forever, and matches no rows where a is false
+        let pattern = repeat(repeat(symbol("a"), 0, Some(1)), 0, None);
+        let matches = run(pattern, AfterMatchSkip::PastLastRow, &[("a", "x.")])?;
+        assert_eq!(matches, vec![(0, "a".to_string()), (1, "".to_string())]);
+        Ok(())
+    }
+
+    #[test]
+    fn skip_modes() -> Result<()> {
+        let pattern = RowPattern::Concat(vec![symbol("a"), repeat(symbol("b"), 1, None)]);
+        let rows = [("a", "xxx."), ("b", ".xxx")];
+
+        let matches = run(pattern.clone(), AfterMatchSkip::ToNextRow, &rows)?;
+        assert_eq!(
+            matches,
+            vec![
+                (0, "abbb".to_string()),
+                (1, "abb".to_string()),
+                (2, "ab".to_string())
+            ]
+        );
+
+        let matches = run(pattern.clone(), AfterMatchSkip::ToFirst("b".into()), &rows)?;
+        assert_eq!(
+            matches,
+            vec![
+                (0, "abbb".to_string()),
+                (1, "abb".to_string()),
+                (2, "ab".to_string())
+            ]
+        );
+
+        // Skipping to the first row of the match would loop forever
+        let err = run(pattern, AfterMatchSkip::ToLast("a".into()), &rows).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("AFTER MATCH SKIP TO LAST a failed"));
+        Ok(())
+    }
+
+    /// A plan matching `pattern` on two batches of three rows, where the
+    /// pattern variable `a` matches any row
+    fn match_number_exec(pattern: RowPattern) -> Result<Arc<MatchRecognizeExec>> {
+        let schema = Arc::new(Schema::new(vec![Field::new("x", DataType::Int32, false)]));
+        let batches = [vec![1, 2, 3], vec![4, 5, 6]]
+            .into_iter()
+            .map(|values| {
+                RecordBatch::try_new(
+                    Arc::clone(&schema),
+                    vec![Arc::new(Int32Array::from(values))],
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let input = TestMemoryExec::try_new_exec(&[batches], schema, None)?;
+        let output_schema = Arc::new(Schema::new(vec![Field::new(
+            "MATCH_NUMBER()",
+            DataType::UInt64,
+            false,
+        )]));
+        Ok(Arc::new(MatchRecognizeExec::try_new(
+            input,
+            vec![],
+            vec![],
+            vec![MatchMeasureExpr::MatchNumber],
+            RowsPerMatch::OneRow,
+            AfterMatchSkip::PastLastRow,
+            pattern,
+            vec!["a".to_string()],
+            vec![None],
+            output_schema,
+        )?))
+    }
+
+    #[tokio::test]
+    async fn emits_matches_incrementally() -> Result<()> {
+        let exec = match_number_exec(symbol("a"))?;
+        let task_ctx = Arc::new(TaskContext::default());
+        let batches = collect(exec.execute(0, Arc::clone(&task_ctx))?).await?;
+
+        // One output batch per input batch, without waiting for the end of
+        // the input
+        let match_numbers = batches
+            .iter()
+            .map(|batch| {
+                batch
+                    .column(0)
+                    .as_primitive::<UInt64Type>()
+                    .values()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(match_numbers, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(task_ctx.memory_pool().reserved(), 0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn buffered_rows_are_accounted() -> Result<()> {
+        // a+ buffers all the rows, as the match may still grow
+        let exec = match_number_exec(repeat(symbol("a"), 1, None))?;
+        let runtime = RuntimeEnvBuilder::default()
+            .with_memory_limit(1, 1.0)
+            .build_arc()?;
+        let task_ctx = Arc::new(TaskContext::default().with_runtime(runtime));
+        let err = collect(exec.execute(0, task_ctx)?).await.unwrap_err();
+        assert!(
+            matches!(err.find_root(), DataFusionError::ResourcesExhausted(_)),
+            "Wrong error type: {err}",
+        );
+        Ok(())
+    }
+    #[test]
+    fn partition_rows_across_batches() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("v", DataType::Int32, false)]));
+        let batch = |values: Vec<i32>| {
+            RecordBatch::try_new(
+                Arc::clone(&schema),
+                vec![Arc::new(Int32Array::from(values))],
+            )
+        };
+        let values = |batch: RecordBatch| {
+            batch
+                .column(0)
+                .as_primitive::<Int32Type>()
+                .values()
+                .to_vec()
+        };
+
+        let mut rows = PartitionRows::new(Arc::clone(&schema), vec![]);
+        rows.push(batch(vec![0, 1, 2])?);
+        rows.push(batch(vec![3])?);
+        rows.push(batch(vec![4, 5, 6, 7])?);
+        assert_eq!(rows.end(), 8);
+        assert_eq!(values(rows.slice(4, 6)?), vec![4, 5]);
+        assert_eq!(values(rows.slice(2, 5)?), vec![2, 3, 4]);
+
+        // Discards the first two batches, and compacts the third one
+        rows.discard_before(6)?;
+        assert_eq!((rows.base, rows.end(), rows.offset), (6, 8, 0));
+        assert_eq!(rows.batches.len(), 1);
+        assert_eq!(values(rows.slice(6, 8)?), vec![6, 7]);
+
+        rows.push(batch(vec![8, 9])?);
+        assert_eq!(values(rows.concat()?), vec![6, 7, 8, 9]);
+        assert_eq!(rows.batches.len(), 1);
+        rows.discard_before(7)?;
+        assert_eq!((rows.base, rows.offset), (7, 1));
+        assert_eq!(values(rows.slice(7, 10)?), vec![7, 8, 9]);
+        Ok(())
+    }
+}
@@ -25,19 +25,21 @@ use std::sync::Arc;
 use std::task::{Context, Poll};
 use std::{any::Any, vec};
 
-use super::common::SharedMemoryReservation;
 use super::metrics::{self, ExecutionPlanMetricsSet, MetricBuilder, MetricsSet};
 use super::{
     DisplayAs, ExecutionPlanProperties, RecordBatchStream, SendableRecordBatchStream,
 };
 use crate::execution_plan::{CardinalityEffect, EvaluationType, SchedulingType};
 use crate::hash_utils::create_hashes;
-use crate::metrics::BaselineMetrics;
+use crate::metrics::{BaselineMetrics, SpillMetrics};
 use crate::projection::{all_columns, make_with_child, update_expr, ProjectionExec};
 use crate::repartition::distributor_channels::{
     channels, partition_aware_channels, DistributionReceiver, DistributionSender,
+    SendError,
 };
 use crate::sorts::streaming_merge::StreamingMergeBuilder;
+use crate::spill::in_progress_spill_file::InProgressSpillFile;
+use crate::spill::spill_manager::SpillManager;
 use crate::stream::RecordBatchStreamAdapter;
 use crate::{DisplayFormatType, ExecutionPlan, Partitioning, PlanProperties, Statistics};
 
@@ -49,7 +51,8 @@ use datafusion_common::utils::transpose;
 use datafusion_common::{internal_err, HashMap};
 use datafusion_common::{not_impl_err, DataFusionError, Result};
 use datafusion_common_runtime::SpawnedTask;
-use datafusion_execution::memory_pool::MemoryConsumer;
+use datafusion_execution::disk_manager::RefCountedTempFile;
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
 use datafusion_execution::TaskContext;
 use datafusion_physical_expr::{EquivalenceProperties, PhysicalExpr};
 use datafusion_physical_expr_common::sort_expr::LexOrdering;
@@ -59,16 +62,262 @@ use crate::filter_pushdown::{
     FilterPushdownPropagation,
 };
 use futures::stream::Stream;
-use futures::{FutureExt, StreamExt, TryStreamExt};
+use futures::{ready, FutureExt, StreamExt, TryStreamExt};
 use log::trace;
 use parking_lot::Mutex;
 
 mod distributor_channels;
 
-type MaybeBatch = Option<Result<RecordBatch>>;
+type MaybeBatch = Option<Result<RepartitionBatch>>;
 type InputPartitionsToCurrentPartitionSender = Vec<DistributionSender<MaybeBatch>>;
 type InputPartitionsToCurrentPartitionReceiver = Vec<DistributionReceiver<MaybeBatch>>;
 
+/// Batch sent to an output partition
+#[derive(Debug)]
+enum RepartitionBatch {
+    /// Batch buffered in memory
+    Memory(RecordBatch),
+    /// Batch spilled to disk, as the memory reservation couldn't grow. It is
+    /// the next batch of the file that was not read yet.
+    Spilled(Arc<SpillFile>),
+}
+
+/// A spill file is finished once the batches spilled to it reach this size,
+/// so that it is read back and deleted even if the receiver lags behind
+const SPILL_FILE_ROLLOVER_SIZE: usize = 64 * 1024 * 1024;
+
+/// A file the batches sent through a channel of an output partition are
+/// spilled to, shared by the spilled batches
+#[derive(Debug, Default)]
+struct SpillFile {
+    /// The finished file and its number of batches, until it is read back
+    finished: Mutex<Option<(RefCountedTempFile, usize)>>,
+}
+
+/// The spill file batches are appended to
+struct SpillWriter {
+    file: InProgressSpillFile,
+    spill_file: Arc<SpillFile>,
+    num_batches: usize,
+    /// Memory size of the spilled batches
+    size: usize,
+}
+
+impl SpillWriter {
+    fn try_new(spill_manager: &SpillManager) -> Result<Self> {
+        Ok(Self {
+            file: spill_manager.create_in_progress_file("RepartitionExec")?,
+            spill_file: Arc::new(SpillFile::default()),
+            num_batches: 0,
+            size: 0,
+        })
+    }
+
+    /// Finishes the file, so that it can be read back
+    fn finish(mut self) -> Result<()> {
+        let Some(file) = self.file.finish()? else {
+            return internal_err!("RepartitionExec spilled no batch");
+        };
+        *self.spill_file.finished.lock() = Some((file, self.num_batches));
+        Ok(())
+    }
+}
+
+impl Debug for SpillWriter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpillWriter")
+            .field("num_batches", &self.num_batches)
+            .field("size", &self.size)
+            .finish()
+    }
+}
+
+/// Accounts for the batches buffered in the channels of an output partition,
+/// spilling them to disk when the memory reservation can't grow.
+///
+/// The batches of each channel are appended to one spill file, which is
+/// finished when the receiver reaches its first batch or when it reaches
+/// [`SPILL_FILE_ROLLOVER_SIZE`], the following batches going to a new file.
+#[derive(Debug)]
+struct PartitionBuffer {
+    reservation: Mutex<MemoryReservation>,
+    spill_manager: SpillManager,
+    /// Whether the disk manager allows spilling
+    can_spill: bool,
+    /// The spill file in progress of each channel, if any
+    spill_writers: Vec<Mutex<Option<SpillWriter>>>,
+}
+
+impl PartitionBuffer {
+    /// Reserves memory for `batch`, sent through `channel`, or spills it if
+    /// the reservation can't grow
+    fn buffer(&self, channel: usize, batch: RecordBatch) -> Result<RepartitionBatch> {
+        let size = batch.get_array_memory_size();
+        let Err(e) = self.reservation.lock().try_grow(size) else {
+            return Ok(RepartitionBatch::Memory(batch));
+        };
+        if !self.can_spill {
+            return Err(e);
+        }
+
+        let mut spill_writer = self.spill_writers[channel].lock();
+        let mut writer = match spill_writer.take() {
+            Some(writer) => writer,
+            None => SpillWriter::try_new(&self.spill_manager)?,
+        };
+        writer.file.append_batch(&batch)?;
+        writer.num_batches += 1;
+        writer.size += size;
+        let spill_file = Arc::clone(&writer.spill_file);
+        if writer.size >= SPILL_FILE_ROLLOVER_SIZE {
+            writer.finish()?;
+        } else {
+            *spill_writer = Some(writer);
+        }
+        Ok(RepartitionBatch::Spilled(spill_file))
+    }
+
+    /// Returns a stream reading back the batches of `spill_file`, sent
+    /// through `channel`, and their number
+    fn read_spill_file(
+        &self,
+        channel: usize,
+        spill_file: &Arc<SpillFile>,
+    ) -> Result<(SendableRecordBatchStream, usize)> {
+        // Batches may still be appended to the file
+        let writer = self.spill_writers[channel]
+            .lock()
+            .take_if(|writer| Arc::ptr_eq(&writer.spill_file, spill_file));
+        if let Some(writer) = writer {
+            writer.finish()?;
+        }
+        let Some((file, num_batches)) = spill_file.finished.lock().take() else {
+            return internal_err!("RepartitionExec spill file was already read");
+        };
+        Ok((self.spill_manager.read_spill_as_stream(file)?, num_batches))
+    }
+
+    /// Releases the memory of a batch that is no longer buffered
+    fn release(&self, batch: &RepartitionBatch) {
+        if let RepartitionBatch::Memory(batch) = batch {
+            self.reservation
+                .lock()
+                .shrink(batch.get_array_memory_size());
+        }
+    }
+}
+
+/// Reads back the batches of a spill file
+struct SpillReader {
+    spill_file: Arc<SpillFile>,
+    stream: SendableRecordBatchStream,
+    num_batches: usize,
+    num_read: usize,
+}
+
+/// Receives the batches sent through a channel of an output partition,
+/// reading back the spilled ones
+struct PartitionReceiver {
+    receiver: DistributionReceiver<MaybeBatch>,
+    buffer: Arc<PartitionBuffer>,
+    /// The index of the channel within the output partition
+    channel: usize,
+    /// The spill file the next batch is read from, if any
+    pending: Option<Arc<SpillFile>>,
+    /// Readers of the spill files with batches left to read. Senders may
+    /// send the batches of different files in an interleaved order.
+    readers: Vec<SpillReader>,
+}
+
+impl PartitionReceiver {
+    fn new(
+        receiver: DistributionReceiver<MaybeBatch>,
+        buffer: Arc<PartitionBuffer>,
+        channel: usize,
+    ) -> Self {
+        Self {
+            receiver,
+            buffer,
+            channel,
+            pending: None,
+            readers: vec![],
+        }
+    }
+
+    /// Polls the next batch in the order they were sent. `Some(None)` signals
+    /// that one of the input partitions is done.
+    fn poll_recv(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Option<Result<RecordBatch>>>> {
+        loop {
+            if let Some(spill_file) = self.pending.clone() {
+                return self
+                    .poll_spilled(cx, &spill_file)
+                    .map(|result| Some(Some(result)));
+            }
+            return Poll::Ready(match ready!(self.receiver.recv().poll_unpin(cx)) {
+                Some(Some(Ok(batch))) => {
+                    self.buffer.release(&batch);
+                    match batch {
+                        RepartitionBatch::Memory(batch) => Some(Some(Ok(batch))),
+                        RepartitionBatch::Spilled(spill_file) => {
+                            self.pending = Some(spill_file);
+                            continue;
+                        }
+                    }
+                }
+                Some(Some(Err(e))) => Some(Some(Err(e))),
+                Some(None) => Some(None),
+                None => None,
+            });
+        }
+    }
+
+    /// Polls the next batch of `spill_file`
+    fn poll_spilled(
+        &mut self,
+        cx: &mut Context<'_>,
+        spill_file: &Arc<SpillFile>,
+    ) -> Poll<Result<RecordBatch>> {
+        let index = match self
+            .readers
+            .iter()
+            .position(|reader| Arc::ptr_eq(&reader.spill_file, spill_file))
+        {
+            Some(index) => index,
+            None => match self.buffer.read_spill_file(self.channel, spill_file) {
+                Ok((stream, num_batches)) => {
+                    self.readers.push(SpillReader {
+                        spill_file: Arc::clone(spill_file),
+                        stream,
+                        num_batches,
+                        num_read: 0,
+                    });
+                    self.readers.len() - 1
+                }
+                Err(e) => {
+                    self.pending = None;
+                    return Poll::Ready(Err(e));
+                }
+            },
+        };
+        let reader = &mut self.readers[index];
+        let result = ready!(reader.stream.poll_next_unpin(cx));
+        self.pending = None;
+        reader.num_read += 1;
+        if reader.num_read == reader.num_batches {
+            self.readers.swap_remove(index);
+        }
+        Poll::Ready(match result {
+            Some(result) => result,
+            None => {
+                internal_err!("RepartitionExec spill file has fewer batches than sent")
+            }
+        })
+    }
+}
+
 #[derive(Debug)]
 struct ConsumingInputStreamsState {
     /// Channels for sending batches from input partitions to output partitions.
@@ -78,7 +327,7 @@ struct ConsumingInputStreamsState {
         (
             InputPartitionsToCurrentPartitionSender,
             InputPartitionsToCurrentPartitionReceiver,
-            SharedMemoryReservation,
+            Arc<PartitionBuffer>,
         ),
     >,
 
@@ -160,8 +409,8 @@ impl RepartitionExecState {
         let streams_and_metrics = match self {
             RepartitionExecState::NotInitialized => {
                 self.ensure_input_streams_initialized(
-                    input,
-                    metrics,
+                    Arc::clone(&input),
+                    metrics.clone(),
                     partitioning.partition_count(),
                     Arc::clone(&context),
                 )?;
@@ -200,13 +449,25 @@ impl RepartitionExecState {
             (txs, rxs)
         };
 
+        let can_spill = context.runtime_env().disk_manager.tmp_files_enabled();
         let mut channels = HashMap::with_capacity(txs.len());
         for (partition, (tx, rx)) in txs.into_iter().zip(rxs).enumerate() {
-            let reservation = Arc::new(Mutex::new(
-                MemoryConsumer::new(format!("{name}[{partition}]"))
-                    .register(context.memory_pool()),
-            ));
-            channels.insert(partition, (tx, rx, reservation));
+            let reservation = MemoryConsumer::new(format!("{name}[{partition}]"))
+                .with_can_spill(can_spill)
+                .register(context.memory_pool());
+            let spill_manager = SpillManager::new(
+                context.runtime_env(),
+                SpillMetrics::new(&metrics, partition),
+                input.schema(),
+            )
+            .with_compression_type(context.session_config().spill_compression());
+            let buffer = Arc::new(PartitionBuffer {
+                reservation: Mutex::new(reservation),
+                spill_manager,
+                can_spill,
+                spill_writers: rx.iter().map(|_| Mutex::new(None)).collect(),
+            });
+            channels.insert(partition, (tx, rx, buffer));
         }
 
         // launch one async task per *input* partition
@@ -216,8 +477,11 @@ impl RepartitionExecState {
         {
             let txs: HashMap<_, _> = channels
                 .iter()
-                .map(|(partition, (tx, _rx, reservation))| {
-                    (*partition, (tx[i].clone(), Arc::clone(reservation)))
+                .map(|(partition, (tx, _rx, buffer))| {
+                    // Without preserving the order, all the inputs send to the
+                    // same channel
+                    let channel = if preserve_order { i } else { 0 };
+                    (*partition, (tx[i].clone(), Arc::clone(buffer), channel))
                 })
                 .collect();
 
@@ -233,7 +497,7 @@ impl RepartitionExecState {
             let wait_for_task = SpawnedTask::spawn(RepartitionExec::wait_for_task(
                 input_task,
                 txs.into_iter()
-                    .map(|(partition, (tx, _reservation))| (partition, tx))
+                    .map(|(partition, (tx, _buffer, _channel))| (partition, tx))
                     .collect(),
             ));
             spawned_tasks.push(wait_for_task);
@@ -674,7 +938,7 @@ impl ExecutionPlan for RepartitionExec {
             let num_input_partitions = input.output_partitioning().partition_count();
 
             // lock scope
-            let (mut rx, reservation, abort_helper) = {
+            let (mut rx, buffer, abort_helper) = {
                 // lock mutexes
                 let mut state = state.lock();
                 let state = state.consume_input_streams(
@@ -688,12 +952,12 @@ impl ExecutionPlan for RepartitionExec {
 
                 // now return stream for the specified *output* partition which will
                 // read from the channel
-                let (_tx, rx, reservation) = state
+                let (_tx, rx, buffer) = state
                     .channels
                     .remove(&partition)
                     .expect("partition not used yet");
 
-                (rx, reservation, Arc::clone(&state.abort_helper))
+                (rx, buffer, Arc::clone(&state.abort_helper))
             };
 
             trace!(
@@ -704,12 +968,16 @@ impl ExecutionPlan for RepartitionExec {
                 // Store streams from all the input partitions:
                 let input_streams = rx
                     .into_iter()
-                    .map(|receiver| {
+                    .enumerate()
+                    .map(|(channel, receiver)| {
                         Box::pin(PerPartitionStream {
                             schema: Arc::clone(&schema_captured),
-                            receiver,
+                            receiver: PartitionReceiver::new(
+                                receiver,
+                                Arc::clone(&buffer),
+                                channel,
+                            ),
                             _drop_helper: Arc::clone(&abort_helper),
-                            reservation: Arc::clone(&reservation),
                         }) as SendableRecordBatchStream
                     })
                     .collect::<Vec<_>>();
@@ -735,9 +1003,8 @@ impl ExecutionPlan for RepartitionExec {
                     num_input_partitions,
                     num_input_partitions_processed: 0,
                     schema: input.schema(),
-                    input: rx.swap_remove(0),
+                    input: PartitionReceiver::new(rx.swap_remove(0), buffer, 0),
                     _drop_helper: abort_helper,
-                    reservation,
                 }) as SendableRecordBatchStream)
             }
         })
@@ -923,7 +1190,7 @@ impl RepartitionExec {
         mut stream: SendableRecordBatchStream,
         mut output_channels: HashMap<
             usize,
-            (DistributionSender<MaybeBatch>, SharedMemoryReservation),
+            (DistributionSender<MaybeBatch>, Arc<PartitionBuffer>, usize),
         >,
         partitioning: Partitioning,
         metrics: RepartitionMetrics,
@@ -947,16 +1214,17 @@ impl RepartitionExec {
 
             for res in partitioner.partition_iter(batch)? {
                 let (partition, batch) = res?;
-                let size = batch.get_array_memory_size();
 
                 let timer = metrics.send_time[partition].timer();
                 // if there is still a receiver, send to it
-                if let Some((tx, reservation)) = output_channels.get_mut(&partition) {
-                    reservation.lock().try_grow(size)?;
+                if let Some((tx, buffer, channel)) = output_channels.get_mut(&partition) {
+                    let batch = buffer.buffer(*channel, batch)?;
 
-                    if tx.send(Some(Ok(batch))).await.is_err() {
+                    if let Err(SendError(element)) = tx.send(Some(Ok(batch))).await {
                         // If the other end has hung up, it was an early shutdown (e.g. LIMIT)
-                        reservation.lock().shrink(size);
+                        if let Some(Ok(batch)) = &element {
+                            buffer.release(batch);
+                        }
                         output_channels.remove(&partition);
                     }
                 }
@@ -1048,13 +1316,10 @@ struct RepartitionStream {
     schema: SchemaRef,
 
     /// channel containing the repartitioned batches
-    input: DistributionReceiver<MaybeBatch>,
+    input: PartitionReceiver,
 
     /// Handle to ensure background tasks are killed when no longer needed.
     _drop_helper: Arc<Vec<SpawnedTask<()>>>,
-
-    /// Memory reservation.
-    reservation: SharedMemoryReservation,
 }
 
 impl Stream for RepartitionStream {
@@ -1065,14 +1330,8 @@ impl Stream for RepartitionStream {
         cx: &mut Context<'_>,
     ) -> Poll<Option<Self::Item>> {
         loop {
-            match self.input.recv().poll_unpin(cx) {
+            match self.input.poll_recv(cx) {
                 Poll::Ready(Some(Some(v))) => {
-                    if let Ok(batch) = &v {
-                        self.reservation
-                            .lock()
-                            .shrink(batch.get_array_memory_size());
-                    }
-
                     return Poll::Ready(Some(v));
                 }
                 Poll::Ready(Some(None)) => {
@@ -1111,13 +1370,10 @@ struct PerPartitionStream {
     schema: SchemaRef,
 
     /// channel containing the repartitioned batches
-    receiver: DistributionReceiver<MaybeBatch>,
+    receiver: PartitionReceiver,
 
     /// Handle to ensure background tasks are killed when no longer needed.
     _drop_helper: Arc<Vec<SpawnedTask<()>>>,
-
-    /// Memory reservation.
-    reservation: SharedMemoryReservation,
 }
 
 impl Stream for PerPartitionStream {
@@ -1127,15 +1383,8 @@ impl Stream for PerPartitionStream {
         mut self: Pin<&mut Self>,
         cx: &mut Context<'_>,
     ) -> Poll<Option<Self::Item>> {
-        match self.receiver.recv().poll_unpin(cx) {
-            Poll::Ready(Some(Some(v))) => {
-                if let Ok(batch) = &v {
-                    self.reservation
-                        .lock()
-                        .shrink(batch.get_array_memory_size());
-                }
-                Poll::Ready(Some(v))
-            }
+        match self.receiver.poll_recv(cx) {
+            Poll::Ready(Some(Some(v))) => Poll::Ready(Some(v)),
             Poll::Ready(Some(None)) => {
                 // Input partition has finished sending batches
                 Poll::Ready(None)
@@ -1176,6 +1425,7 @@ mod tests {
     use datafusion_common::test_util::batches_to_sort_string;
     use datafusion_common::{arrow_datafusion_err, exec_err};
     use datafusion_common_runtime::JoinSet;
+    use datafusion_execution::disk_manager::{DiskManagerBuilder, DiskManagerMode};
     use datafusion_execution::runtime_env::RuntimeEnvBuilder;
     use insta::assert_snapshot;
     use itertools::Itertools;
@@ -1663,9 +1913,12 @@ mod tests {
         let input_partitions = vec![partition];
         let partitioning = Partitioning::RoundRobinBatch(4);
 
-        // setup up context
+        // setup up context, disabling DiskManager to prevent spilling
         let runtime = RuntimeEnvBuilder::default()
             .with_memory_limit(1, 1.0)
+            .with_disk_manager_builder(
+                DiskManagerBuilder::default().with_mode(DiskManagerMode::Disabled),
+            )
             .build_arc()?;
 
         let task_ctx = TaskContext::default().with_runtime(runtime);
@@ -1691,6 +1944,66 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn spill() -> Result<()> {
+        // define input partitions
+        let schema = test_schema();
+        let partition = (0..50)
+            .map(|i| {
+                RecordBatch::try_new(
+                    Arc::clone(&schema),
+                    vec![Arc::new(UInt32Array::from_iter_values(i * 8..(i + 1) * 8))],
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let input_partitions = vec![partition];
+        let partitioning = Partitioning::RoundRobinBatch(2);
+
+        // setup up context
+        let runtime = RuntimeEnvBuilder::default()
+            .with_memory_limit(1, 1.0)
+            .build_arc()?;
+
+        let task_ctx = TaskContext::default().with_runtime(runtime);
+        let task_ctx = Arc::new(task_ctx);
+
+        // create physical plan
+        let exec =
+            TestMemoryExec::try_new_exec(&input_partitions, Arc::clone(&schema), None)?;
+        let exec = RepartitionExec::try_new(exec, partitioning)?;
+
+        // pull partitions
+        for i in 0..exec.partitioning().partition_count() {
+            let stream = exec.execute(i, Arc::clone(&task_ctx))?;
+            let batches = crate::common::collect(stream).await?;
+            let values = batches
+                .iter()
+                .flat_map(|batch| {
+                    batch
+                        .column(0)
+                        .as_any()
+                        .downcast_ref::<UInt32Array>()
+                        .unwrap()
+                        .values()
+                        .to_vec()
+                })
+                .collect::<Vec<_>>();
+
+            // the batches of each output partition are received in order
+            assert_eq!(values.len(), 200);
+            assert!(values.is_sorted());
+        }
+
+        // The batches of each output partition are appended to a spill file
+        // until the receiver reads it, rather than spilled to a file each
+        let metrics = exec.metrics().unwrap();
+        assert!(metrics.spill_count().unwrap() > 0);
+        assert!(metrics.spill_count().unwrap() < 50);
+        assert_eq!(metrics.spilled_rows().unwrap(), 400);
+
+        Ok(())
+    }
+
     /// Create vector batches
     fn create_vec_batches(n: usize) -> Vec<RecordBatch> {
         let batch = create_batch();
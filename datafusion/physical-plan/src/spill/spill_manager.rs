@@ -40,7 +40,7 @@ use crate::{common::spawn_buffered, metrics::SpillMetrics};
 pub struct SpillManager {
     env: Arc<RuntimeEnv>,
     pub(crate) metrics: SpillMetrics,
-    schema: SchemaRef,
+    pub(crate) schema: SchemaRef,
     /// Number of batches to buffer in memory during disk reads
     batch_read_buffer_capacity: usize,
     /// general-purpose compression options
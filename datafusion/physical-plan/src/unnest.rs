@@ -557,8 +557,28 @@ fn build_batch(
     options: &UnnestOptions,
 ) -> Result<Option<RecordBatch>> {
     let transformed = match list_type_columns.len() {
-        0 => flatten_struct_cols(batch.columns(), schema, struct_column_indices),
+        0 => {
+            let mut columns = batch.columns().to_vec();
+            if let Some(ordinality) = &options.ordinality {
+                // Struct unnesting does not produce additional rows
+                columns.push(Arc::new(Int64Array::from_value(
+                    ordinality.start,
+                    batch.num_rows(),
+                )));
+            }
+            flatten_struct_cols(&columns, schema, struct_column_indices)
+        }
         _ => {
+            // To compute the ordinality, the index of each input row is
+            // appended as an extra column that is repeated like any other
+            // column that is not unnested
+            let mut columns = batch.columns().to_vec();
+            if options.ordinality.is_some() {
+                columns.push(Arc::new(Int64Array::from_iter_values(
+                    0..batch.num_rows() as i64,
+                )));
+            }
+
             let mut temp_unnested_result = HashMap::new();
             let max_recursion = list_type_columns
                 .iter()
@@ -573,7 +593,7 @@ fn build_batch(
             // All unnesting results are written to temp_batch
             for depth in (1..=max_recursion).rev() {
                 let input = match depth == max_recursion {
-                    true => batch.columns(),
+                    true => &columns,
                     false => &flatten_arrs,
                 };
                 let Some(temp_result) = list_unnest_at_level(
@@ -653,7 +673,7 @@ fn build_batch(
                 )
                 .collect::<HashMap<_, _>>();
 
-            let ret = flatten_arrs
+            let mut ret = flatten_arrs
                 .into_iter()
                 .enumerate()
                 .flat_map(|(col_idx, arr)| {
@@ -667,12 +687,49 @@ fn build_batch(
                 })
                 .collect::<Vec<_>>();
 
+            if let Some(ordinality) = &options.ordinality {
+                let Some(input_row_indices) = ret.pop() else {
+                    return internal_err!("Missing input row indices for ordinality");
+                };
+                ret.push(Arc::new(positions_within_input_rows(
+                    input_row_indices.as_primitive::<Int64Type>(),
+                    ordinality.start,
+                )));
+            }
+
             flatten_struct_cols(&ret, schema, struct_column_indices)
         }
     }?;
     Ok(Some(transformed))
 }
 
+/// Given the index of the input row that each output row was produced from,
+/// return the position of each output row among the rows produced from the
+/// same input row, counting from `start`.
+///
+/// For example, with `start` = 1:
+///
+/// ```ignore
+/// input_row_indices: [0, 0, 0, 2, 3, 3]
+/// positions:         [1, 2, 3, 1, 1, 2]
+/// ```
+fn positions_within_input_rows(
+    input_row_indices: &PrimitiveArray<Int64Type>,
+    start: i64,
+) -> Int64Array {
+    let mut position = start;
+    let mut previous = None;
+    Int64Array::from_iter_values(input_row_indices.values().iter().map(|index| {
+        position = if previous == Some(*index) {
+            position + 1
+        } else {
+            start
+        };
+        previous = Some(*index);
+        position
+    }))
+}
+
 /// Find the longest list length among the given list arrays for each row.
 ///
 /// For example if we have the following two list arrays:
@@ -956,6 +1013,7 @@ mod tests {
     use arrow::buffer::{NullBuffer, OffsetBuffer};
     use arrow::datatypes::{Field, Int32Type};
     use datafusion_common::test_util::batches_to_string;
+    use datafusion_common::UnnestOrdinality;
     use insta::assert_snapshot;
 
     // Create a GenericListArray with the following list values:
@@ -1139,6 +1197,7 @@ mod tests {
             &UnnestOptions {
                 preserve_nulls: true,
                 recursions: vec![],
+                ordinality: None,
             },
         )?
         .unwrap();
@@ -1173,6 +1232,59 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_build_batch_list_arr_with_ordinality() -> Result<()> {
+        // col1         | col2
+        // [1, 2, 3]    | 'a'
+        // null         | 'b'
+        // [4]          | 'c'
+        let col1 = ListArray::from_iter_primitive::<Int32Type, _, _>(vec![
+            Some(vec![Some(1), Some(2), Some(3)]),
+            None,
+            Some(vec![Some(4)]),
+        ]);
+        let col2 = StringArray::from(vec!["a", "b", "c"]);
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("col1", col1.data_type().clone(), true),
+            Field::new("col2", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![Arc::new(col1) as ArrayRef, Arc::new(col2) as ArrayRef],
+        )?;
+        let out_schema = Arc::new(Schema::new(vec![
+            Field::new("col1", DataType::Int32, true),
+            Field::new("col2", DataType::Utf8, true),
+            Field::new("ordinality", DataType::Int64, false),
+        ]));
+        let list_type_columns = vec![ListUnnest {
+            index_in_input_schema: 0,
+            depth: 1,
+        }];
+        let ret = build_batch(
+            &batch,
+            &out_schema,
+            list_type_columns.as_ref(),
+            &HashSet::default(),
+            &UnnestOptions::new()
+                .with_preserve_nulls(false)
+                .with_ordinality(UnnestOrdinality::new("ordinality", 1)),
+        )?
+        .unwrap();
+
+        assert_snapshot!(batches_to_string(&[ret]), @r"
+        +------+------+------------+
+        | col1 | col2 | ordinality |
+        +------+------+------------+
+        | 1    | a    | 1          |
+        | 2    | a    | 2          |
+        | 3    | a    | 3          |
+        | 4    | c    | 1          |
+        +------+------+------------+
+        ");
+        Ok(())
+    }
+
     #[test]
     fn test_unnest_list_array() -> Result<()> {
         // [A, B, C], [], NULL, [D], NULL, [NULL, F]
@@ -1226,6 +1338,7 @@ mod tests {
         let options = UnnestOptions {
             preserve_nulls,
             recursions: vec![],
+            ordinality: None,
         };
         let longest_length = find_longest_length(list_arrays, &options)?;
         let expected_array = Int64Array::from(expected);
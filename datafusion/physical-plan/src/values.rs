@@ -321,6 +321,8 @@ mod tests {
                     max_value: Precision::Absent,
                     min_value: Precision::Absent,
                     sum_value: Precision::Absent,
+                    histogram: None,
+                    most_common_values: None,
                 },],
             }
         );
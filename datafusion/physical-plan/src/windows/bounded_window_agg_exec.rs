@@ -29,6 +29,7 @@ use std::task::{Context, Poll};
 
 use super::utils::create_schema;
 use crate::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
+use crate::spill::get_record_batch_memory_size;
 use crate::windows::{
     calc_requirements, get_ordered_partition_by_indices, get_partition_by_sort_exprs,
     window_equivalence_properties,
@@ -54,6 +55,7 @@ use datafusion_common::utils::{
 use datafusion_common::{
     arrow_datafusion_err, exec_err, DataFusionError, HashMap, Result,
 };
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
 use datafusion_execution::TaskContext;
 use datafusion_expr::window_state::{PartitionBatchState, WindowAggState};
 use datafusion_expr::ColumnarValue;
@@ -73,6 +75,12 @@ use indexmap::IndexMap;
 use log::debug;
 
 /// Window execution plan
+///
+/// Only the rows still needed by the bounded window frames are buffered, and
+/// they are pruned as results are emitted. Their memory is accounted in the
+/// memory pool, so that other consumers spill to make room for it, but the
+/// operator can't spill and never fails when the pool is exhausted: a frame
+/// spanning many rows (e.g. a large `RANGE` offset) keeps them in memory.
 #[derive(Debug, Clone)]
 pub struct BoundedWindowAggExec {
     /// Input plan
@@ -350,6 +358,9 @@ impl ExecutionPlan for BoundedWindowAggExec {
         partition: usize,
         context: Arc<TaskContext>,
     ) -> Result<SendableRecordBatchStream> {
+        let reservation =
+            MemoryConsumer::new(format!("BoundedWindowAggStream[{partition}]"))
+                .register(context.memory_pool());
         let input = self.input.execute(partition, context)?;
         let search_mode = self.get_search_algo()?;
         let stream = Box::pin(BoundedWindowAggStream::new(
@@ -358,6 +369,7 @@ impl ExecutionPlan for BoundedWindowAggExec {
             input,
             BaselineMetrics::new(&self.metrics, partition),
             search_mode,
+            reservation,
         )?);
         Ok(stream)
     }
@@ -923,6 +935,8 @@ pub struct BoundedWindowAggStream {
     /// Search mode for partition columns. This determines the algorithm with
     /// which we group each partition.
     search_mode: Box<dyn PartitionSearcher>,
+    /// Memory used by `input_buffer` and `partition_buffers`
+    reservation: MemoryReservation,
 }
 
 impl BoundedWindowAggStream {
@@ -966,6 +980,7 @@ impl BoundedWindowAggStream {
         input: SendableRecordBatchStream,
         baseline_metrics: BaselineMetrics,
         search_mode: Box<dyn PartitionSearcher>,
+        reservation: MemoryReservation,
     ) -> Result<Self> {
         let state = window_expr.iter().map(|_| IndexMap::new()).collect();
         let empty_batch = RecordBatch::new_empty(Arc::clone(&schema));
@@ -979,9 +994,25 @@ impl BoundedWindowAggStream {
             window_expr,
             baseline_metrics,
             search_mode,
+            reservation,
         })
     }
 
+    /// Resizes the memory reservation to the size of the buffered batches,
+    /// which are pruned as results are emitted.
+    ///
+    /// The reservation grows even if the pool is exhausted, as the buffered
+    /// rows are needed to compute the results and can't be spilled.
+    fn update_reservation(&mut self) {
+        let size = get_record_batch_memory_size(&self.input_buffer)
+            + self
+                .partition_buffers
+                .values()
+                .map(|state| get_record_batch_memory_size(&state.record_batch))
+                .sum::<usize>();
+        self.reservation.resize(size)
+    }
+
     fn compute_aggregates(&mut self) -> Result<Option<RecordBatch>> {
         // calculate window cols
         for (cur_window_expr, state) in
@@ -1037,7 +1068,9 @@ impl BoundedWindowAggStream {
                     &self.window_expr,
                     &mut self.partition_buffers,
                 )?;
-                if let Some(batch) = self.compute_aggregates()? {
+                let output = self.compute_aggregates()?;
+                self.update_reservation();
+                if let Some(batch) = output {
                     return Poll::Ready(Some(Ok(batch)));
                 }
                 self.poll_next_inner(cx)
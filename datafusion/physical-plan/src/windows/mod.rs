@@ -31,7 +31,7 @@ use crate::{
 
 use arrow::datatypes::{Schema, SchemaRef};
 use arrow_schema::{FieldRef, SortOptions};
-use datafusion_common::{exec_err, Result};
+use datafusion_common::{exec_err, not_impl_err, Result};
 use datafusion_expr::{
     PartitionEvaluator, ReversedUDWF, SetMonotonicity, WindowFrame,
     WindowFunctionDefinition, WindowUDF,
@@ -136,12 +136,27 @@ pub fn create_window_expr(
                 )
             }
         }
-        WindowFunctionDefinition::WindowUDF(fun) => Arc::new(StandardWindowExpr::new(
-            create_udwf_window_expr(fun, args, input_schema, name, ignore_nulls)?,
-            partition_by,
-            order_by,
-            window_frame,
-        )),
+        WindowFunctionDefinition::WindowUDF(fun) => {
+            let udwf_expr =
+                create_udwf_window_expr(fun, args, input_schema, name, ignore_nulls)?;
+            // Rows excluded from the frame are only skipped by aggregate window
+            // functions; functions that ignore the frame are not affected.
+            if window_frame.has_exclusion()
+                && udwf_expr.create_evaluator()?.uses_window_frame()
+            {
+                return not_impl_err!(
+                    "{} is not supported for window function {}",
+                    window_frame.exclusion,
+                    fun.name()
+                );
+            }
+            Arc::new(StandardWindowExpr::new(
+                udwf_expr,
+                partition_by,
+                order_by,
+                window_frame,
+            ))
+        }
     })
 }
 
@@ -653,13 +668,22 @@ mod tests {
     use crate::collect;
     use crate::expressions::col;
     use crate::streaming::StreamingTableExec;
-    use crate::test::assert_is_pending;
     use crate::test::exec::{assert_strong_count_converges_to_zero, BlockingExec};
+    use crate::test::{assert_is_pending, TestMemoryExec};
 
+    use arrow::array::{Int32Array, Int64Array};
     use arrow::compute::SortOptions;
+    use arrow::record_batch::RecordBatch;
     use arrow_schema::{DataType, Field};
+    use datafusion_common::cast::as_int64_array;
+    use datafusion_common::{DataFusionError, ScalarValue};
+    use datafusion_execution::disk_manager::{DiskManagerBuilder, DiskManagerMode};
+    use datafusion_execution::runtime_env::RuntimeEnvBuilder;
     use datafusion_execution::TaskContext;
+    use datafusion_expr::{WindowFrameBound, WindowFrameExclusion, WindowFrameUnits};
     use datafusion_functions_aggregate::count::count_udaf;
+    use datafusion_functions_aggregate::sum::sum_udaf;
+    use datafusion_functions_window::row_number::row_number_udwf;
     use InputOrderMode::{Linear, PartiallySorted, Sorted};
 
     use futures::FutureExt;
@@ -838,6 +862,217 @@ mod tests {
         Ok(())
     }
 
+    /// A [`WindowAggExec`] computing `function` over 4 partitions of 25000
+    /// rows, partitioned by `a`
+    fn window_agg_exec_over_large_partitions(
+        function: WindowFunctionDefinition,
+        args: &[&str],
+    ) -> Result<Arc<WindowAggExec>> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int64, false),
+        ]));
+        let batches = (0..100)
+            .map(|i| {
+                RecordBatch::try_new(
+                    Arc::clone(&schema),
+                    vec![
+                        Arc::new(Int32Array::from(vec![i / 25; 1000])),
+                        Arc::new(Int64Array::from(vec![1; 1000])),
+                    ],
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let ordering = LexOrdering::new(vec![sort_expr("a", &schema)]).unwrap();
+        let input = Arc::new(TestMemoryExec::update_cache(Arc::new(
+            TestMemoryExec::try_new(&[batches], Arc::clone(&schema), None)?
+                .try_with_sort_information(vec![ordering])?,
+        )));
+        let args = args
+            .iter()
+            .map(|name| col(name, &schema))
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Arc::new(WindowAggExec::try_new(
+            vec![create_window_expr(
+                &function,
+                function.name().to_owned(),
+                &args,
+                &[col("a", &schema)?],
+                &[],
+                Arc::new(WindowFrame::new(None)),
+                schema.as_ref(),
+                false,
+                false,
+            )?],
+            input,
+            false,
+        )?))
+    }
+
+    #[tokio::test]
+    async fn test_window_agg_spill() -> Result<()> {
+        let window_agg_exec = window_agg_exec_over_large_partitions(
+            WindowFunctionDefinition::AggregateUDF(sum_udaf()),
+            &["b"],
+        )?;
+
+        let runtime = RuntimeEnvBuilder::new()
+            .with_memory_limit(100 * 1024, 1.0)
+            .build_arc()?;
+        let task_ctx = Arc::new(TaskContext::default().with_runtime(runtime));
+        let batches = collect(Arc::clone(&window_agg_exec) as _, task_ctx).await?;
+
+        let num_rows: usize = batches.iter().map(|batch| batch.num_rows()).sum();
+        assert_eq!(num_rows, 100_000);
+        for batch in &batches {
+            let sums = as_int64_array(batch.column(2))?;
+            assert!(sums.iter().all(|sum| sum == Some(25_000)));
+        }
+        let metrics = window_agg_exec.metrics().unwrap();
+        assert!(metrics.spill_count().unwrap() > 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_window_agg_cannot_spill() -> Result<()> {
+        // ROW_NUMBER can't be evaluated while spilling
+        let window_agg_exec = window_agg_exec_over_large_partitions(
+            WindowFunctionDefinition::WindowUDF(row_number_udwf()),
+            &[],
+        )?;
+        let runtime = RuntimeEnvBuilder::new()
+            .with_memory_limit(100 * 1024, 1.0)
+            .build_arc()?;
+        let task_ctx = Arc::new(TaskContext::default().with_runtime(runtime));
+        let err = collect(window_agg_exec as _, task_ctx).await.unwrap_err();
+        assert!(
+            matches!(err.find_root(), DataFusionError::ResourcesExhausted(_)),
+            "{err}"
+        );
+
+        // SUM can, but not without temporary files
+        let window_agg_exec = window_agg_exec_over_large_partitions(
+            WindowFunctionDefinition::AggregateUDF(sum_udaf()),
+            &["b"],
+        )?;
+        let runtime = RuntimeEnvBuilder::new()
+            .with_memory_limit(100 * 1024, 1.0)
+            .with_disk_manager_builder(
+                DiskManagerBuilder::default().with_mode(DiskManagerMode::Disabled),
+            )
+            .build_arc()?;
+        let task_ctx = Arc::new(TaskContext::default().with_runtime(runtime));
+        let err = collect(window_agg_exec as _, task_ctx).await.unwrap_err();
+        assert!(
+            matches!(err.find_root(), DataFusionError::ResourcesExhausted(_)),
+            "{err}"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_window_frame_exclusion() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int64, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            Arc::clone(&schema),
+            vec![
+                Arc::new(Int32Array::from(vec![1, 1, 2, 3, 3, 3])),
+                Arc::new(Int64Array::from(vec![1, 2, 3, 4, 5, 6])),
+            ],
+        )?;
+        let ordering = LexOrdering::new(vec![sort_expr("a", &schema)]).unwrap();
+        let input: Arc<dyn ExecutionPlan> =
+            Arc::new(TestMemoryExec::update_cache(Arc::new(
+                TestMemoryExec::try_new(&[vec![batch]], Arc::clone(&schema), None)?
+                    .try_with_sort_information(vec![ordering.clone()])?,
+            )));
+        let sum_expr = |window_frame: WindowFrame| {
+            create_window_expr(
+                &WindowFunctionDefinition::AggregateUDF(sum_udaf()),
+                "sum".to_owned(),
+                &[col("b", &schema)?],
+                &[],
+                &ordering,
+                Arc::new(window_frame),
+                schema.as_ref(),
+                false,
+                false,
+            )
+        };
+        let whole_partition = WindowFrame::new_bounds(
+            WindowFrameUnits::Rows,
+            WindowFrameBound::Preceding(ScalarValue::UInt64(None)),
+            WindowFrameBound::Following(ScalarValue::UInt64(None)),
+        );
+        let sliding = WindowFrame::new_bounds(
+            WindowFrameUnits::Rows,
+            WindowFrameBound::Preceding(ScalarValue::UInt64(Some(1))),
+            WindowFrameBound::Following(ScalarValue::UInt64(Some(1))),
+        );
+
+        let test_cases = vec![
+            (
+                whole_partition.clone(),
+                WindowFrameExclusion::NoOthers,
+                vec![21, 21, 21, 21, 21, 21],
+            ),
+            (
+                whole_partition.clone(),
+                WindowFrameExclusion::CurrentRow,
+                vec![20, 19, 18, 17, 16, 15],
+            ),
+            (
+                whole_partition.clone(),
+                WindowFrameExclusion::Group,
+                vec![18, 18, 18, 6, 6, 6],
+            ),
+            (
+                whole_partition,
+                WindowFrameExclusion::Ties,
+                vec![19, 20, 21, 10, 11, 12],
+            ),
+            (
+                sliding.clone(),
+                WindowFrameExclusion::CurrentRow,
+                vec![2, 4, 6, 8, 10, 5],
+            ),
+            (sliding, WindowFrameExclusion::Ties, vec![1, 5, 9, 7, 5, 6]),
+        ];
+        for (window_frame, exclusion, expected) in test_cases {
+            let window_expr = sum_expr(window_frame.with_exclusion(exclusion))?;
+            let plans: Vec<Arc<dyn ExecutionPlan>> = vec![
+                Arc::new(WindowAggExec::try_new(
+                    vec![Arc::clone(&window_expr)],
+                    Arc::clone(&input),
+                    false,
+                )?),
+                Arc::new(BoundedWindowAggExec::try_new(
+                    vec![window_expr],
+                    Arc::clone(&input),
+                    Sorted,
+                    false,
+                )?),
+            ];
+            for plan in plans {
+                let batches = collect(plan, Arc::new(TaskContext::default())).await?;
+                let sums = batches
+                    .iter()
+                    .map(|batch| Ok(as_int64_array(batch.column(2))?.values().to_vec()))
+                    .collect::<Result<Vec<_>>>()?
+                    .concat();
+                assert_eq!(sums, expected, "{exclusion}");
+            }
+        }
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_satisfy_nullable() -> Result<()> {
         let schema = create_test_schema()?;
@@ -18,16 +18,23 @@
 //! Stream and channel implementations for window function expressions.
 
 use std::any::Any;
+use std::collections::VecDeque;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context, Poll};
 
 use super::utils::create_schema;
 use crate::execution_plan::EmissionType;
-use crate::metrics::{BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet};
+use crate::metrics::{
+    BaselineMetrics, ExecutionPlanMetricsSet, MetricsSet, SpillMetrics,
+};
+use crate::spill::get_record_batch_memory_size;
+use crate::spill::in_progress_spill_file::InProgressSpillFile;
+use crate::spill::spill_manager::SpillManager;
+use crate::stream::RecordBatchStreamAdapter;
 use crate::windows::{
     calc_requirements, get_ordered_partition_by_indices, get_partition_by_sort_exprs,
-    window_equivalence_properties,
+    window_equivalence_properties, PlainAggregateWindowExpr,
 };
 use crate::{
     ColumnStatistics, DisplayAs, DisplayFormatType, Distribution, ExecutionPlan,
@@ -35,15 +42,17 @@ use crate::{
     SendableRecordBatchStream, Statistics, WindowExpr,
 };
 
-use arrow::array::ArrayRef;
-use arrow::compute::{concat, concat_batches};
+use arrow::array::{ArrayRef, UInt32Array};
+use arrow::compute::{concat, concat_batches, take_record_batch, SortColumn};
 use arrow::datatypes::SchemaRef;
 use arrow::error::ArrowError;
 use arrow::record_batch::RecordBatch;
 use datafusion_common::stats::Precision;
 use datafusion_common::utils::{evaluate_partition_ranges, transpose};
-use datafusion_common::{internal_err, Result};
+use datafusion_common::{internal_err, Result, ScalarValue};
+use datafusion_execution::memory_pool::{MemoryConsumer, MemoryReservation};
 use datafusion_execution::TaskContext;
+use datafusion_expr::Accumulator;
 use datafusion_physical_expr_common::sort_expr::{
     OrderingRequirements, PhysicalSortExpr,
 };
@@ -51,6 +60,14 @@ use datafusion_physical_expr_common::sort_expr::{
 use futures::{ready, Stream, StreamExt};
 
 /// Window execution plan
+///
+/// The input of each partition is buffered in memory until it ends. When the
+/// memory reservation can't grow, the PARTITION BY partitions that are already
+/// complete are evaluated and emitted. If the last partition alone still
+/// doesn't fit, it is spilled to disk, which is only possible when all window
+/// expressions are aggregates over the whole partition (e.g.
+/// `SUM(x) OVER (PARTITION BY user)`), as they are evaluated as the rows are
+/// spilled.
 #[derive(Debug, Clone)]
 pub struct WindowAggExec {
     /// Input plan
@@ -274,6 +291,19 @@ impl ExecutionPlan for WindowAggExec {
         partition: usize,
         context: Arc<TaskContext>,
     ) -> Result<SendableRecordBatchStream> {
+        let reservation = MemoryConsumer::new(format!("WindowAggStream[{partition}]"))
+            .with_can_spill(true)
+            .register(context.memory_pool());
+        let spill_manager = (context.runtime_env().disk_manager.tmp_files_enabled()
+            && self.window_expr.iter().all(is_partition_aggregate))
+        .then(|| {
+            SpillManager::new(
+                context.runtime_env(),
+                SpillMetrics::new(&self.metrics, partition),
+                self.input.schema(),
+            )
+            .with_compression_type(context.session_config().spill_compression())
+        });
         let input = self.input.execute(partition, context)?;
         let stream = Box::pin(WindowAggStream::new(
             Arc::clone(&self.schema),
@@ -282,6 +312,8 @@ impl ExecutionPlan for WindowAggExec {
             BaselineMetrics::new(&self.metrics, partition),
             self.partition_by_sort_keys()?,
             self.ordered_partition_by_indices.clone(),
+            reservation,
+            spill_manager,
         )?);
         Ok(stream)
     }
@@ -303,6 +335,27 @@ impl ExecutionPlan for WindowAggExec {
     }
 }
 
+/// Returns whether `window_expr` is an aggregate over its whole partition,
+/// which can be evaluated incrementally while the partition is spilled
+fn is_partition_aggregate(window_expr: &Arc<dyn WindowExpr>) -> bool {
+    let window_frame = window_expr.get_window_frame();
+    window_expr.as_any().is::<PlainAggregateWindowExpr>()
+        && !window_frame.has_exclusion()
+        && window_frame.start_bound.is_unbounded()
+        && window_frame.end_bound.is_unbounded()
+}
+
+/// Returns the values of the PARTITION BY columns at `row`
+fn partition_key(
+    partition_columns: &[SortColumn],
+    row: usize,
+) -> Result<Vec<ScalarValue>> {
+    partition_columns
+        .iter()
+        .map(|column| ScalarValue::try_from_array(&column.values, row))
+        .collect()
+}
+
 /// Compute the window aggregate columns
 fn compute_window_aggregates(
     window_expr: &[Arc<dyn WindowExpr>],
@@ -324,10 +377,73 @@ pub struct WindowAggStream {
     partition_by_sort_keys: Vec<PhysicalSortExpr>,
     baseline_metrics: BaselineMetrics,
     ordered_partition_by_indices: Vec<usize>,
+    /// Memory used by `batches`
+    reservation: MemoryReservation,
+    /// Spills the last partition when it doesn't fit in memory, `None` if the
+    /// window expressions can't be evaluated while spilling or if the disk
+    /// manager doesn't allow temporary files
+    spill_manager: Option<SpillManager>,
+    /// Last partition of the input so far, when it is spilled. `batches` is
+    /// then empty.
+    spilled_partition: Option<SpilledPartition>,
+    /// Results not emitted yet, in the order of the input
+    output: VecDeque<SendableRecordBatchStream>,
+}
+
+/// Partition of a [`WindowAggStream`] spilled to disk
+struct SpilledPartition {
+    /// Spill file of the rows of the partition
+    file: InProgressSpillFile,
+    /// Values of the PARTITION BY columns of the partition
+    key: Vec<ScalarValue>,
+    /// Accumulator of each window expression, updated as rows are spilled
+    accumulators: Vec<Box<dyn Accumulator>>,
+}
+
+impl SpilledPartition {
+    /// Spills `batch`, the first rows of the partition with `key`
+    fn try_new(
+        spill_manager: &SpillManager,
+        window_expr: &[Arc<dyn WindowExpr>],
+        key: Vec<ScalarValue>,
+        batch: &RecordBatch,
+    ) -> Result<Self> {
+        let accumulators = window_expr
+            .iter()
+            .map(
+                |expr| match expr.as_any().downcast_ref::<PlainAggregateWindowExpr>() {
+                    Some(expr) => expr.get_aggregate_expr().create_accumulator(),
+                    None => {
+                        internal_err!("Cannot spill window expression {}", expr.name())
+                    }
+                },
+            )
+            .collect::<Result<_>>()?;
+        let mut partition = Self {
+            file: spill_manager.create_in_progress_file("WindowAggExec")?,
+            key,
+            accumulators,
+        };
+        partition.append(window_expr, batch)?;
+        Ok(partition)
+    }
+
+    /// Spills `batch`, whose rows belong to the partition
+    fn append(
+        &mut self,
+        window_expr: &[Arc<dyn WindowExpr>],
+        batch: &RecordBatch,
+    ) -> Result<()> {
+        for (expr, accumulator) in window_expr.iter().zip(&mut self.accumulators) {
+            accumulator.update_batch(&expr.evaluate_args(batch)?)?;
+        }
+        self.file.append_batch(batch)
+    }
 }
 
 impl WindowAggStream {
     /// Create a new WindowAggStream
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         schema: SchemaRef,
         window_expr: Vec<Arc<dyn WindowExpr>>,
@@ -335,6 +451,8 @@ impl WindowAggStream {
         baseline_metrics: BaselineMetrics,
         partition_by_sort_keys: Vec<PhysicalSortExpr>,
         ordered_partition_by_indices: Vec<usize>,
+        reservation: MemoryReservation,
+        spill_manager: Option<SpillManager>,
     ) -> Result<Self> {
         // In WindowAggExec all partition by columns should be ordered.
         if window_expr[0].partition_by().len() != ordered_partition_by_indices.len() {
@@ -349,23 +467,32 @@ impl WindowAggStream {
             baseline_metrics,
             partition_by_sort_keys,
             ordered_partition_by_indices,
+            reservation,
+            spill_manager,
+            spilled_partition: None,
+            output: VecDeque::new(),
         })
     }
 
-    fn compute_aggregates(&self) -> Result<Option<RecordBatch>> {
+    /// Evaluates the PARTITION BY columns of `batch`
+    fn partition_columns(&self, batch: &RecordBatch) -> Result<Vec<SortColumn>> {
+        self.ordered_partition_by_indices
+            .iter()
+            .map(|idx| self.partition_by_sort_keys[*idx].evaluate_to_sort_column(batch))
+            .collect()
+    }
+
+    /// Computes the window expressions over `batch`, whose partitions are
+    /// complete
+    fn compute_aggregates(&self, batch: &RecordBatch) -> Result<Option<RecordBatch>> {
         // record compute time on drop
         let _timer = self.baseline_metrics.elapsed_compute().timer();
 
-        let batch = concat_batches(&self.input.schema(), &self.batches)?;
         if batch.num_rows() == 0 {
             return Ok(None);
         }
 
-        let partition_by_sort_keys = self
-            .ordered_partition_by_indices
-            .iter()
-            .map(|idx| self.partition_by_sort_keys[*idx].evaluate_to_sort_column(&batch))
-            .collect::<Result<Vec<_>>>()?;
+        let partition_by_sort_keys = self.partition_columns(batch)?;
         let partition_points =
             evaluate_partition_ranges(batch.num_rows(), &partition_by_sort_keys)?;
 
@@ -396,6 +523,140 @@ impl WindowAggStream {
             batch_columns,
         )?))
     }
+
+    /// Queues the results of the window expressions over `batch`
+    fn emit_aggregates(&mut self, batch: &RecordBatch) -> Result<()> {
+        if let Some(result) = self.compute_aggregates(batch)? {
+            // Empty record batches should not be emitted.
+            // They need to be treated as  [`Option<RecordBatch>`]es and handled separately
+            debug_assert!(result.num_rows() > 0);
+            self.output
+                .push_back(Box::pin(RecordBatchStreamAdapter::new(
+                    Arc::clone(&self.schema),
+                    futures::stream::iter([Ok(result)]),
+                )));
+        }
+        Ok(())
+    }
+
+    /// Buffers or spills an input batch
+    fn push_batch(&mut self, mut batch: RecordBatch) -> Result<()> {
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        if self.spilled_partition.is_some() {
+            let partition_columns = self.partition_columns(&batch)?;
+            let key = partition_key(&partition_columns, 0)?;
+            let first_partition_end =
+                evaluate_partition_ranges(batch.num_rows(), &partition_columns)?[0].end;
+            if let Some(spilled_partition) = self
+                .spilled_partition
+                .as_mut()
+                .filter(|spilled_partition| spilled_partition.key == key)
+            {
+                spilled_partition
+                    .append(&self.window_expr, &batch.slice(0, first_partition_end))?;
+                if first_partition_end == batch.num_rows() {
+                    return Ok(());
+                }
+                batch = batch
+                    .slice(first_partition_end, batch.num_rows() - first_partition_end);
+            }
+            self.emit_spilled_partition()?;
+        }
+
+        let batch_size = get_record_batch_memory_size(&batch);
+        self.batches.push(batch);
+        if self.reservation.try_grow(batch_size).is_err() {
+            self.shrink()?;
+        }
+        Ok(())
+    }
+
+    /// Emits the complete partitions of `batches`, keeping the last one in
+    /// memory if it fits or spilling it otherwise
+    fn shrink(&mut self) -> Result<()> {
+        let batch = concat_batches(&self.input.schema(), &self.batches)?;
+        self.batches.clear();
+
+        // The input is sorted on the PARTITION BY columns, so only the last
+        // partition may continue in the following batches
+        let partition_columns = self.partition_columns(&batch)?;
+        let ranges = evaluate_partition_ranges(batch.num_rows(), &partition_columns)?;
+        let last_partition = ranges[ranges.len() - 1].clone();
+        let batch = if last_partition.start == 0 {
+            batch
+        } else {
+            self.emit_aggregates(&batch.slice(0, last_partition.start))?;
+            // Copy the partition so that the memory of the emitted rows is freed
+            let indices = UInt32Array::from_iter_values(
+                last_partition.start as u32..last_partition.end as u32,
+            );
+            take_record_batch(&batch, &indices)?
+        };
+
+        let batch_size = get_record_batch_memory_size(&batch);
+        if let Err(e) = self.reservation.try_resize(batch_size) {
+            let Some(spill_manager) = &self.spill_manager else {
+                return Err(e);
+            };
+            let key = partition_key(&partition_columns, last_partition.end - 1)?;
+            self.spilled_partition = Some(SpilledPartition::try_new(
+                spill_manager,
+                &self.window_expr,
+                key,
+                &batch,
+            )?);
+            self.reservation.free();
+        } else {
+            self.batches.push(batch);
+        }
+        Ok(())
+    }
+
+    /// Queues the results of the spilled partition, which is complete
+    fn emit_spilled_partition(&mut self) -> Result<()> {
+        let (Some(mut spilled_partition), Some(spill_manager)) =
+            (self.spilled_partition.take(), &self.spill_manager)
+        else {
+            return Ok(());
+        };
+        let values = spilled_partition
+            .accumulators
+            .iter_mut()
+            .map(|accumulator| accumulator.evaluate())
+            .collect::<Result<Vec<_>>>()?;
+        let Some(file) = spilled_partition.file.finish()? else {
+            return Ok(());
+        };
+
+        let schema = Arc::clone(&self.schema);
+        let output = spill_manager.read_spill_as_stream(file)?.map(move |batch| {
+            let batch = batch?;
+            let mut columns = batch.columns().to_vec();
+            for value in &values {
+                columns.push(value.to_array_of_size(batch.num_rows())?);
+            }
+            Ok(RecordBatch::try_new(Arc::clone(&schema), columns)?)
+        });
+        self.output
+            .push_back(Box::pin(RecordBatchStreamAdapter::new(
+                Arc::clone(&self.schema),
+                output,
+            )));
+        Ok(())
+    }
+
+    /// Queues the results of the last partition, once the input is exhausted
+    fn finish(&mut self) -> Result<()> {
+        self.emit_spilled_partition()?;
+        let batch = concat_batches(&self.input.schema(), &self.batches)?;
+        self.batches.clear();
+        self.emit_aggregates(&batch)?;
+        self.reservation.free();
+        Ok(())
+    }
 }
 
 impl Stream for WindowAggStream {
@@ -416,28 +677,28 @@ impl WindowAggStream {
         &mut self,
         cx: &mut Context<'_>,
     ) -> Poll<Option<Result<RecordBatch>>> {
-        if self.finished {
-            return Poll::Ready(None);
-        }
-
         loop {
-            return Poll::Ready(Some(match ready!(self.input.poll_next_unpin(cx)) {
-                Some(Ok(batch)) => {
-                    self.batches.push(batch);
-                    continue;
+            if let Some(output) = self.output.front_mut() {
+                match ready!(output.poll_next_unpin(cx)) {
+                    Some(result) => return Poll::Ready(Some(result)),
+                    None => {
+                        self.output.pop_front();
+                        continue;
+                    }
                 }
-                Some(Err(e)) => Err(e),
+            }
+            if self.finished {
+                return Poll::Ready(None);
+            }
+
+            match ready!(self.input.poll_next_unpin(cx)) {
+                Some(Ok(batch)) => self.push_batch(batch)?,
+                Some(Err(e)) => return Poll::Ready(Some(Err(e))),
                 None => {
-                    let Some(result) = self.compute_aggregates()? else {
-                        return Poll::Ready(None);
-                    };
                     self.finished = true;
-                    // Empty record batches should not be emitted.
-                    // They need to be treated as  [`Option<RecordBatch>`]es and handled separately
-                    debug_assert!(result.num_rows() > 0);
-                    Ok(result)
+                    self.finish()?;
                 }
-            }));
+            }
         }
     }
 }
@@ -33,7 +33,7 @@ use arrow::ipc::{reader::read_record_batch, root_as_message};
 use datafusion_common::{
     arrow_datafusion_err,
     config::{
-        CsvOptions, JsonOptions, ParquetColumnOptions, ParquetOptions,
+        CsvOptions, JsonFormatType, JsonOptions, ParquetColumnOptions, ParquetOptions,
         TableParquetOptions,
     },
     file_options::{csv_writer::CsvWriterOptions, json_writer::JsonWriterOptions},
@@ -680,6 +680,8 @@ impl From<&protobuf::ColumnStats> for ColumnStatistics {
             } else {
                 Precision::Absent
             },
+            histogram: None,
+            most_common_values: None,
         }
     }
 }
@@ -846,6 +848,24 @@ impl From<CompressionTypeVariant> for protobuf::CompressionTypeVariant {
     }
 }
 
+impl From<protobuf::JsonFormatType> for JsonFormatType {
+    fn from(value: protobuf::JsonFormatType) -> Self {
+        match value {
+            protobuf::JsonFormatType::NewlineDelimited => Self::NewlineDelimited,
+            protobuf::JsonFormatType::Array => Self::Array,
+        }
+    }
+}
+
+impl From<JsonFormatType> for protobuf::JsonFormatType {
+    fn from(value: JsonFormatType) -> Self {
+        match value {
+            JsonFormatType::NewlineDelimited => Self::NewlineDelimited,
+            JsonFormatType::Array => Self::Array,
+        }
+    }
+}
+
 impl TryFrom<&protobuf::CsvWriterOptions> for CsvWriterOptions {
     type Error = DataFusionError;
 
@@ -865,7 +885,7 @@ impl TryFrom<&protobuf::JsonWriterOptions> for JsonWriterOptions {
         opts: &protobuf::JsonWriterOptions,
     ) -> datafusion_common::Result<Self, Self::Error> {
         let compression: CompressionTypeVariant = opts.compression().into();
-        Ok(JsonWriterOptions::new(compression))
+        Ok(JsonWriterOptions::new(compression).with_format(opts.format().into()))
     }
 }
 
@@ -1082,6 +1102,7 @@ impl TryFrom<&protobuf::JsonOptions> for JsonOptions {
         Ok(JsonOptions {
             compression: compression.into(),
             schema_infer_max_rec: proto_opts.schema_infer_max_rec.map(|h| h as usize),
+            format: proto_opts.format().into(),
         })
     }
 }
@@ -793,8 +793,10 @@ impl TryFrom<&JsonWriterOptions> for protobuf::JsonWriterOptions {
         opts: &JsonWriterOptions,
     ) -> datafusion_common::Result<Self, Self::Error> {
         let compression: protobuf::CompressionTypeVariant = opts.compression.into();
+        let format: protobuf::JsonFormatType = opts.format.into();
         Ok(protobuf::JsonWriterOptions {
             compression: compression.into(),
+            format: format.into(),
         })
     }
 }
@@ -944,9 +946,11 @@ impl TryFrom<&JsonOptions> for protobuf::JsonOptions {
 
     fn try_from(opts: &JsonOptions) -> datafusion_common::Result<Self, Self::Error> {
         let compression: protobuf::CompressionTypeVariant = opts.compression.into();
+        let format: protobuf::JsonFormatType = opts.format.into();
         Ok(protobuf::JsonOptions {
             compression: compression.into(),
             schema_infer_max_rec: opts.schema_infer_max_rec.map(|h| h as u64),
+            format: format.into(),
         })
     }
 }
@@ -416,6 +416,9 @@ pub struct DmlNode {
     pub table_name: ::core::option::Option<TableReference>,
     #[prost(message, optional, boxed, tag = "5")]
     pub target: ::core::option::Option<::prost::alloc::boxed::Box<LogicalPlanNode>>,
+    /// The clauses of a MERGE, only set if dml_type is MERGE
+    #[prost(message, optional, tag = "6")]
+    pub merge_op: ::core::option::Option<MergeOpNode>,
 }
 /// Nested message and enum types in `DmlNode`.
 pub mod dml_node {
@@ -438,6 +441,7 @@ pub mod dml_node {
         InsertAppend = 3,
         InsertOverwrite = 4,
         InsertReplace = 5,
+        Merge = 6,
     }
     impl Type {
         /// String value of the enum field names used in the ProtoBuf definition.
@@ -452,6 +456,7 @@ pub mod dml_node {
                 Self::InsertAppend => "INSERT_APPEND",
                 Self::InsertOverwrite => "INSERT_OVERWRITE",
                 Self::InsertReplace => "INSERT_REPLACE",
+                Self::Merge => "MERGE",
             }
         }
         /// Creates an enum from field names used in the ProtoBuf definition.
@@ -463,6 +468,110 @@ pub mod dml_node {
                 "INSERT_APPEND" => Some(Self::InsertAppend),
                 "INSERT_OVERWRITE" => Some(Self::InsertOverwrite),
                 "INSERT_REPLACE" => Some(Self::InsertReplace),
+                "MERGE" => Some(Self::Merge),
+                _ => None,
+            }
+        }
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MergeOpNode {
+    #[prost(message, optional, tag = "1")]
+    pub on: ::core::option::Option<LogicalExprNode>,
+    #[prost(message, repeated, tag = "2")]
+    pub clauses: ::prost::alloc::vec::Vec<MergeClauseNode>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MergeClauseNode {
+    #[prost(enumeration = "merge_clause_node::Kind", tag = "1")]
+    pub kind: i32,
+    #[prost(message, optional, tag = "2")]
+    pub predicate: ::core::option::Option<LogicalExprNode>,
+    #[prost(enumeration = "merge_clause_node::Action", tag = "3")]
+    pub action: i32,
+    /// The columns assigned by an UPDATE
+    #[prost(string, repeated, tag = "4")]
+    pub columns: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// The values assigned by an UPDATE, or the row inserted by an INSERT
+    #[prost(message, repeated, tag = "5")]
+    pub values: ::prost::alloc::vec::Vec<LogicalExprNode>,
+}
+/// Nested message and enum types in `MergeClauseNode`.
+pub mod merge_clause_node {
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Kind {
+        Matched = 0,
+        NotMatchedByTarget = 1,
+        NotMatchedBySource = 2,
+    }
+    impl Kind {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Matched => "MATCHED",
+                Self::NotMatchedByTarget => "NOT_MATCHED_BY_TARGET",
+                Self::NotMatchedBySource => "NOT_MATCHED_BY_SOURCE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "MATCHED" => Some(Self::Matched),
+                "NOT_MATCHED_BY_TARGET" => Some(Self::NotMatchedByTarget),
+                "NOT_MATCHED_BY_SOURCE" => Some(Self::NotMatchedBySource),
+                _ => None,
+            }
+        }
+    }
+    #[derive(
+        Clone,
+        Copy,
+        Debug,
+        PartialEq,
+        Eq,
+        Hash,
+        PartialOrd,
+        Ord,
+        ::prost::Enumeration
+    )]
+    #[repr(i32)]
+    pub enum Action {
+        Update = 0,
+        Insert = 1,
+        Delete = 2,
+    }
+    impl Action {
+        /// String value of the enum field names used in the ProtoBuf definition.
+        ///
+        /// The values are not transformed in any way and thus are considered stable
+        /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+        pub fn as_str_name(&self) -> &'static str {
+            match self {
+                Self::Update => "UPDATE",
+                Self::Insert => "INSERT",
+                Self::Delete => "DELETE",
+            }
+        }
+        /// Creates an enum from field names used in the ProtoBuf definition.
+        pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+            match value {
+                "UPDATE" => Some(Self::Update),
+                "INSERT" => Some(Self::Insert),
+                "DELETE" => Some(Self::Delete),
                 _ => None,
             }
         }
@@ -510,6 +619,15 @@ pub struct UnnestOptions {
     pub preserve_nulls: bool,
     #[prost(message, repeated, tag = "2")]
     pub recursions: ::prost::alloc::vec::Vec<RecursionUnnestOption>,
+    #[prost(message, optional, tag = "3")]
+    pub ordinality: ::core::option::Option<UnnestOrdinality>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnnestOrdinality {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub start: i64,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct RecursionUnnestOption {
@@ -919,6 +1037,8 @@ pub struct WindowFrame {
     pub window_frame_units: i32,
     #[prost(message, optional, tag = "2")]
     pub start_bound: ::core::option::Option<WindowFrameBound>,
+    #[prost(enumeration = "WindowFrameExclusion", tag = "4")]
+    pub exclusion: i32,
     /// "optional" keyword is stable in protoc 3.15 but prost is still on 3.14 (see <https://github.com/tokio-rs/prost/issues/430> and <https://github.com/tokio-rs/prost/pull/455>)
     /// this syntax is ugly but is binary compatible with the "optional" keyword (see <https://stackoverflow.com/questions/42622015/how-to-define-an-optional-field-in-protobuf-3>)
     #[prost(oneof = "window_frame::EndBound", tags = "3")]
@@ -1576,6 +1696,8 @@ pub mod csv_scan_exec_node {
 pub struct JsonScanExecNode {
     #[prost(message, optional, tag = "1")]
     pub base_conf: ::core::option::Option<FileScanExecConf>,
+    #[prost(enumeration = "super::datafusion_common::JsonFormatType", tag = "2")]
+    pub format: i32,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AvroScanExecNode {
@@ -1981,6 +2103,38 @@ impl WindowFrameUnits {
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
 #[repr(i32)]
+pub enum WindowFrameExclusion {
+    ExcludeNoOthers = 0,
+    ExcludeCurrentRow = 1,
+    ExcludeGroup = 2,
+    ExcludeTies = 3,
+}
+impl WindowFrameExclusion {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::ExcludeNoOthers => "EXCLUDE_NO_OTHERS",
+            Self::ExcludeCurrentRow => "EXCLUDE_CURRENT_ROW",
+            Self::ExcludeGroup => "EXCLUDE_GROUP",
+            Self::ExcludeTies => "EXCLUDE_TIES",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EXCLUDE_NO_OTHERS" => Some(Self::ExcludeNoOthers),
+            "EXCLUDE_CURRENT_ROW" => Some(Self::ExcludeCurrentRow),
+            "EXCLUDE_GROUP" => Some(Self::ExcludeGroup),
+            "EXCLUDE_TIES" => Some(Self::ExcludeTies),
+            _ => None,
+        }
+    }
+}
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
 pub enum WindowFrameBoundType {
     CurrentRow = 0,
     Preceding = 1,
@@ -131,8 +131,8 @@ pub mod protobuf {
     pub use datafusion_proto_common::common::proto_error;
     pub use datafusion_proto_common::protobuf_common::{
         ArrowFormat, ArrowOptions, ArrowType, AvroFormat, AvroOptions, CsvFormat,
-        DfSchema, EmptyMessage, Field, JoinSide, NdJsonFormat, ParquetFormat,
-        ScalarValue, Schema,
+        DfSchema, EmptyMessage, Field, JoinSide, JsonFormatType, NdJsonFormat,
+        ParquetFormat, ScalarValue, Schema,
     };
     pub use datafusion_proto_common::{FromProtoError, ToProtoError};
 }
@@ -19,7 +19,7 @@ use std::sync::Arc;
 
 use datafusion::{
     config::{
-        CsvOptions, JsonOptions, ParquetColumnOptions, ParquetOptions,
+        CsvOptions, JsonFormatType, JsonOptions, ParquetColumnOptions, ParquetOptions,
         TableParquetOptions,
     },
     datasource::file_format::{
@@ -243,6 +243,7 @@ impl JsonOptionsProto {
             JsonOptionsProto {
                 compression: options.compression as i32,
                 schema_infer_max_rec: options.schema_infer_max_rec.map(|v| v as u64),
+                format: options.format as i32,
             }
         } else {
             JsonOptionsProto::default()
@@ -261,6 +262,10 @@ impl From<&JsonOptionsProto> for JsonOptions {
                 _ => CompressionTypeVariant::UNCOMPRESSED,
             },
             schema_infer_max_rec: proto.schema_infer_max_rec.map(|v| v as usize),
+            format: match proto.format {
+                1 => JsonFormatType::Array,
+                _ => JsonFormatType::NewlineDelimited,
+            },
         }
     }
 }
@@ -21,8 +21,11 @@ use datafusion::execution::registry::FunctionRegistry;
 use datafusion_common::{
     exec_datafusion_err, internal_err, plan_datafusion_err, NullEquality,
     RecursionUnnestOption, Result, ScalarValue, TableReference, UnnestOptions,
+    UnnestOrdinality,
+};
+use datafusion_expr::dml::{
+    InsertOp, MergeAction, MergeClause, MergeClauseKind, MergeOp,
 };
-use datafusion_expr::dml::InsertOp;
 use datafusion_expr::expr::{Alias, Placeholder, Sort};
 use datafusion_expr::expr::{Unnest, WildcardOptions};
 use datafusion_expr::{
@@ -31,9 +34,9 @@ use datafusion_expr::{
     Between, BinaryExpr, Case, Cast, Expr, GroupingSet,
     GroupingSet::GroupingSets,
     JoinConstraint, JoinType, Like, Operator, TryCast, WindowFrame, WindowFrameBound,
-    WindowFrameUnits,
+    WindowFrameExclusion, WindowFrameUnits,
 };
-use datafusion_expr::{ExprFunctionExt, WriteOp};
+use datafusion_expr::{lit, ExprFunctionExt, WriteOp};
 use datafusion_proto_common::{from_proto::FromOptionalField, FromProtoError as Error};
 
 use crate::protobuf::plan_type::PlanTypeEnum::{
@@ -66,6 +69,10 @@ impl From<&protobuf::UnnestOptions> for UnnestOptions {
                     depth: r.depth as usize,
                 })
                 .collect::<Vec<_>>(),
+            ordinality: opts
+                .ordinality
+                .as_ref()
+                .map(|o| UnnestOrdinality::new(&o.name, o.start)),
         }
     }
 }
@@ -80,6 +87,17 @@ impl From<protobuf::WindowFrameUnits> for WindowFrameUnits {
     }
 }
 
+impl From<protobuf::WindowFrameExclusion> for WindowFrameExclusion {
+    fn from(exclusion: protobuf::WindowFrameExclusion) -> Self {
+        match exclusion {
+            protobuf::WindowFrameExclusion::ExcludeNoOthers => Self::NoOthers,
+            protobuf::WindowFrameExclusion::ExcludeCurrentRow => Self::CurrentRow,
+            protobuf::WindowFrameExclusion::ExcludeGroup => Self::Group,
+            protobuf::WindowFrameExclusion::ExcludeTies => Self::Ties,
+        }
+    }
+}
+
 impl TryFrom<protobuf::TableReference> for TableReference {
     type Error = Error;
 
@@ -166,7 +184,11 @@ impl TryFrom<protobuf::WindowFrame> for WindowFrame {
             })
             .transpose()?
             .unwrap_or(WindowFrameBound::CurrentRow);
-        Ok(WindowFrame::new_bounds(units, start_bound, end_bound))
+        let exclusion = protobuf::WindowFrameExclusion::try_from(window.exclusion)
+            .map_err(|_| Error::unknown("WindowFrameExclusion", window.exclusion))?
+            .into();
+        Ok(WindowFrame::new_bounds(units, start_bound, end_bound)
+            .with_exclusion(exclusion))
     }
 }
 
@@ -206,6 +228,7 @@ impl From<protobuf::JoinType> for JoinType {
             protobuf::JoinType::Rightanti => JoinType::RightAnti,
             protobuf::JoinType::Leftmark => JoinType::LeftMark,
             protobuf::JoinType::Rightmark => JoinType::RightMark,
+            protobuf::JoinType::Asof => JoinType::AsOf,
         }
     }
 }
@@ -239,10 +262,59 @@ impl From<protobuf::dml_node::Type> for WriteOp {
             }
             protobuf::dml_node::Type::InsertReplace => WriteOp::Insert(InsertOp::Replace),
             protobuf::dml_node::Type::Ctas => WriteOp::Ctas,
+            // The clauses are stored separately, see `parse_merge_op`
+            protobuf::dml_node::Type::Merge => {
+                WriteOp::Merge(MergeOp::new(lit(true), vec![]))
+            }
         }
     }
 }
 
+/// Parse the clauses of a `MERGE`, which are not part of its
+/// [`protobuf::dml_node::Type`]
+pub fn parse_merge_op(
+    proto: &protobuf::MergeOpNode,
+    registry: &dyn FunctionRegistry,
+    codec: &dyn LogicalExtensionCodec,
+) -> Result<MergeOp, Error> {
+    use protobuf::merge_clause_node::{Action, Kind};
+
+    let on = parse_required_expr(proto.on.as_ref(), registry, "on", codec)?;
+    let clauses = proto
+        .clauses
+        .iter()
+        .map(|clause| {
+            let kind = match Kind::try_from(clause.kind)
+                .map_err(|_| Error::unknown("MergeClauseKind", clause.kind))?
+            {
+                Kind::Matched => MergeClauseKind::Matched,
+                Kind::NotMatchedByTarget => MergeClauseKind::NotMatchedByTarget,
+                Kind::NotMatchedBySource => MergeClauseKind::NotMatchedBySource,
+            };
+            let values = parse_exprs(&clause.values, registry, codec)?;
+            let action = match Action::try_from(clause.action)
+                .map_err(|_| Error::unknown("MergeAction", clause.action))?
+            {
+                Action::Update => MergeAction::Update(
+                    clause.columns.iter().cloned().zip(values).collect(),
+                ),
+                Action::Insert => MergeAction::Insert(values),
+                Action::Delete => MergeAction::Delete,
+            };
+            Ok(MergeClause {
+                kind,
+                predicate: parse_optional_expr(
+                    clause.predicate.as_ref(),
+                    registry,
+                    codec,
+                )?,
+                action,
+            })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    Ok(MergeOp::new(on, clauses))
+}
+
 pub fn parse_expr(
     proto: &protobuf::LogicalExprNode,
     registry: &dyn FunctionRegistry,
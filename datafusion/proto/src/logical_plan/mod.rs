@@ -73,6 +73,7 @@ use datafusion_expr::{
 };
 use datafusion_expr::{
     AggregateUDF, DmlStatement, FetchType, RecursiveQuery, SkipType, TableSource, Unnest,
+    WriteOp,
 };
 
 use self::to_proto::{serialize_expr, serialize_exprs};
@@ -442,7 +443,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                         FileFormatType::Avro(..) => {
                             #[cfg(feature = "avro")]
                             {
-                                Arc::new(AvroFormat)
+                                Arc::new(AvroFormat::default())
                             }
                             #[cfg(not(feature = "avro"))]
                             panic!("Unable to process avro file since `avro` feature is not enabled");
@@ -966,7 +967,14 @@ impl AsLogicalPlan for LogicalPlanNode {
                 datafusion::logical_expr::DmlStatement::new(
                     from_table_reference(dml_node.table_name.as_ref(), "DML ")?,
                     to_table_source(&dml_node.target, ctx, extension_codec)?,
-                    dml_node.dml_type().into(),
+                    match &dml_node.merge_op {
+                        Some(merge_op) => WriteOp::Merge(from_proto::parse_merge_op(
+                            merge_op,
+                            ctx,
+                            extension_codec,
+                        )?),
+                        None => dml_node.dml_type().into(),
+                    },
                     Arc::new(into_logical_plan!(dml_node.input, ctx, extension_codec)?),
                 ),
             )),
@@ -1688,6 +1696,15 @@ impl AsLogicalPlan for LogicalPlanNode {
             LogicalPlan::Ddl(DdlStatement::CreateIndex(_)) => Err(proto_error(
                 "LogicalPlan serde is not yet implemented for CreateIndex",
             )),
+            LogicalPlan::Ddl(DdlStatement::CreateMaterializedView(_)) => Err(proto_error(
+                "LogicalPlan serde is not yet implemented for CreateMaterializedView",
+            )),
+            LogicalPlan::Ddl(DdlStatement::RefreshMaterializedView(_)) => Err(proto_error(
+                "LogicalPlan serde is not yet implemented for RefreshMaterializedView",
+            )),
+            LogicalPlan::Ddl(DdlStatement::AnalyzeTable(_)) => Err(proto_error(
+                "LogicalPlan serde is not yet implemented for AnalyzeTable",
+            )),
             LogicalPlan::Ddl(DdlStatement::DropTable(_)) => Err(proto_error(
                 "LogicalPlan serde is not yet implemented for DropTable",
             )),
@@ -1726,6 +1743,12 @@ impl AsLogicalPlan for LogicalPlanNode {
                 let input =
                     LogicalPlanNode::try_from_logical_plan(input, extension_codec)?;
                 let dml_type: dml_node::Type = op.into();
+                let merge_op = match op {
+                    WriteOp::Merge(merge_op) => {
+                        Some(to_proto::serialize_merge_op(merge_op, extension_codec)?)
+                    }
+                    _ => None,
+                };
                 Ok(LogicalPlanNode {
                     logical_plan_type: Some(LogicalPlanType::Dml(Box::new(DmlNode {
                         input: Some(Box::new(input)),
@@ -1736,6 +1759,7 @@ impl AsLogicalPlan for LogicalPlanNode {
                         )?)),
                         table_name: Some(table_name.clone().into()),
                         dml_type: dml_type.into(),
+                        merge_op,
                     }))),
                 })
             }
@@ -1766,6 +1790,9 @@ impl AsLogicalPlan for LogicalPlanNode {
             LogicalPlan::DescribeTable(_) => Err(proto_error(
                 "LogicalPlan serde is not yet implemented for DescribeTable",
             )),
+            LogicalPlan::MatchRecognize(_) => Err(proto_error(
+                "LogicalPlan serde is not yet implemented for MatchRecognize",
+            )),
             LogicalPlan::RecursiveQuery(recursive) => {
                 let static_term = LogicalPlanNode::try_from_logical_plan(
                     recursive.static_term.as_ref(),
@@ -22,7 +22,7 @@
 use std::collections::HashMap;
 
 use datafusion_common::{NullEquality, TableReference, UnnestOptions};
-use datafusion_expr::dml::InsertOp;
+use datafusion_expr::dml::{InsertOp, MergeAction, MergeClauseKind, MergeOp};
 use datafusion_expr::expr::{
     self, AggregateFunctionParams, Alias, Between, BinaryExpr, Cast, GroupingSet, InList,
     Like, Placeholder, ScalarFunction, Unnest,
@@ -30,8 +30,8 @@ use datafusion_expr::expr::{
 use datafusion_expr::WriteOp;
 use datafusion_expr::{
     logical_plan::PlanType, logical_plan::StringifiedPlan, Expr, JoinConstraint,
-    JoinType, SortExpr, TryCast, WindowFrame, WindowFrameBound, WindowFrameUnits,
-    WindowFunctionDefinition,
+    JoinType, SortExpr, TryCast, WindowFrame, WindowFrameBound, WindowFrameExclusion,
+    WindowFrameUnits, WindowFunctionDefinition,
 };
 
 use crate::protobuf::RecursionUnnestOption;
@@ -64,6 +64,13 @@ impl From<&UnnestOptions> for protobuf::UnnestOptions {
                     depth: r.depth as u32,
                 })
                 .collect(),
+            ordinality: opts
+                .ordinality
+                .as_ref()
+                .map(|o| protobuf::UnnestOrdinality {
+                    name: o.name.clone(),
+                    start: o.start,
+                }),
         }
     }
 }
@@ -139,6 +146,17 @@ impl From<WindowFrameUnits> for protobuf::WindowFrameUnits {
     }
 }
 
+impl From<WindowFrameExclusion> for protobuf::WindowFrameExclusion {
+    fn from(exclusion: WindowFrameExclusion) -> Self {
+        match exclusion {
+            WindowFrameExclusion::NoOthers => Self::ExcludeNoOthers,
+            WindowFrameExclusion::CurrentRow => Self::ExcludeCurrentRow,
+            WindowFrameExclusion::Group => Self::ExcludeGroup,
+            WindowFrameExclusion::Ties => Self::ExcludeTies,
+        }
+    }
+}
+
 impl TryFrom<&WindowFrameBound> for protobuf::WindowFrameBound {
     type Error = Error;
 
@@ -168,6 +186,7 @@ impl TryFrom<&WindowFrame> for protobuf::WindowFrame {
         Ok(Self {
             window_frame_units: protobuf::WindowFrameUnits::from(window.units).into(),
             start_bound: Some((&window.start_bound).try_into()?),
+            exclusion: protobuf::WindowFrameExclusion::from(window.exclusion).into(),
             end_bound: Some(protobuf::window_frame::EndBound::Bound(
                 (&window.end_bound).try_into()?,
             )),
@@ -574,6 +593,12 @@ pub fn serialize_expr(
             // see discussion in https://github.com/apache/datafusion/issues/2565
             return Err(Error::General("Proto serialization error: Expr::ScalarSubquery(_) | Expr::InSubquery(_) | Expr::Exists { .. } | Exp:OuterReferenceColumn not supported".to_string()));
         }
+        Expr::Lambda(_) | Expr::LambdaVariable(_) => {
+            return Err(Error::General(
+                "Proto serialization error: Expr::Lambda(_) | Expr::LambdaVariable(_) not supported"
+                    .to_string(),
+            ));
+        }
         Expr::GroupingSet(GroupingSet::Cube(exprs)) => protobuf::LogicalExprNode {
             expr_type: Some(ExprType::Cube(CubeNode {
                 expr: serialize_exprs(exprs, codec)?,
@@ -684,6 +709,7 @@ impl From<JoinType> for protobuf::JoinType {
             JoinType::RightAnti => protobuf::JoinType::Rightanti,
             JoinType::LeftMark => protobuf::JoinType::Leftmark,
             JoinType::RightMark => protobuf::JoinType::Rightmark,
+            JoinType::AsOf => protobuf::JoinType::Asof,
         }
     }
 }
@@ -717,6 +743,57 @@ impl From<&WriteOp> for protobuf::dml_node::Type {
             WriteOp::Delete => protobuf::dml_node::Type::Delete,
             WriteOp::Update => protobuf::dml_node::Type::Update,
             WriteOp::Ctas => protobuf::dml_node::Type::Ctas,
+            WriteOp::Merge(_) => protobuf::dml_node::Type::Merge,
         }
     }
 }
+
+/// Serialize the clauses of a `MERGE`, which are not part of its
+/// [`protobuf::dml_node::Type`]
+pub fn serialize_merge_op(
+    merge_op: &MergeOp,
+    codec: &dyn LogicalExtensionCodec,
+) -> Result<protobuf::MergeOpNode, Error> {
+    use protobuf::merge_clause_node::{Action, Kind};
+
+    let clauses = merge_op
+        .clauses
+        .iter()
+        .map(|clause| {
+            let kind = match clause.kind {
+                MergeClauseKind::Matched => Kind::Matched,
+                MergeClauseKind::NotMatchedByTarget => Kind::NotMatchedByTarget,
+                MergeClauseKind::NotMatchedBySource => Kind::NotMatchedBySource,
+            };
+            let (action, columns, values) = match &clause.action {
+                MergeAction::Update(assignments) => (
+                    Action::Update,
+                    assignments
+                        .iter()
+                        .map(|(column, _)| column.clone())
+                        .collect(),
+                    serialize_exprs(assignments.iter().map(|(_, value)| value), codec)?,
+                ),
+                MergeAction::Insert(row) => {
+                    (Action::Insert, vec![], serialize_exprs(row, codec)?)
+                }
+                MergeAction::Delete => (Action::Delete, vec![], vec![]),
+            };
+            Ok(protobuf::MergeClauseNode {
+                kind: kind.into(),
+                predicate: clause
+                    .predicate
+                    .as_ref()
+                    .map(|predicate| serialize_expr(predicate, codec))
+                    .transpose()?,
+                action: action.into(),
+                columns,
+                values,
+            })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    Ok(protobuf::MergeOpNode {
+        on: Some(serialize_expr(&merge_op.on, codec)?),
+        clauses,
+    })
+}
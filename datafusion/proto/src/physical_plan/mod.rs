@@ -723,7 +723,7 @@ impl protobuf::PhysicalPlanNode {
             scan.base_conf.as_ref().unwrap(),
             registry,
             extension_codec,
-            Arc::new(JsonSource::new()),
+            Arc::new(JsonSource::new().with_format(scan.format().into())),
         )?;
         Ok(DataSourceExec::from_data_source(scan_conf))
     }
@@ -2367,7 +2367,8 @@ impl protobuf::PhysicalPlanNode {
 
         if let Some(scan_conf) = data_source.as_any().downcast_ref::<FileScanConfig>() {
             let source = scan_conf.file_source();
-            if let Some(_json_source) = source.as_any().downcast_ref::<JsonSource>() {
+            if let Some(json_source) = source.as_any().downcast_ref::<JsonSource>() {
+                let format: protobuf::JsonFormatType = json_source.format().into();
                 return Ok(Some(protobuf::PhysicalPlanNode {
                     physical_plan_type: Some(PhysicalPlanType::JsonScan(
                         protobuf::JsonScanExecNode {
@@ -2375,6 +2376,7 @@ impl protobuf::PhysicalPlanNode {
                                 scan_conf,
                                 extension_codec,
                             )?),
+                            format: format.into(),
                         },
                     )),
                 }));
@@ -68,7 +68,7 @@ use datafusion_common::{
     internal_datafusion_err, internal_err, not_impl_err, plan_err, DFSchema, DFSchemaRef,
     DataFusionError, Result, ScalarValue, TableReference,
 };
-use datafusion_expr::dml::CopyTo;
+use datafusion_expr::dml::{CopyTo, DmlStatement};
 use datafusion_expr::expr::{
     self, Between, BinaryExpr, Case, Cast, GroupingSet, InList, Like, ScalarFunction,
     Unnest, WildcardOptions,
@@ -77,8 +77,8 @@ use datafusion_expr::logical_plan::{Extension, UserDefinedLogicalNodeCore};
 use datafusion_expr::{
     Accumulator, AggregateUDF, ColumnarValue, ExprFunctionExt, ExprSchemable, Literal,
     LogicalPlan, Operator, PartitionEvaluator, ScalarUDF, Signature, TryCast, Volatility,
-    WindowFrame, WindowFrameBound, WindowFrameUnits, WindowFunctionDefinition, WindowUDF,
-    WindowUDFImpl,
+    WindowFrame, WindowFrameBound, WindowFrameExclusion, WindowFrameUnits,
+    WindowFunctionDefinition, WindowUDF, WindowUDFImpl,
 };
 use datafusion_functions_aggregate::average::avg_udaf;
 use datafusion_functions_aggregate::expr_fn::{
@@ -407,6 +407,10 @@ async fn roundtrip_logical_plan_dml() -> Result<()> {
         "DELETE FROM T1",
         "UPDATE T1 SET a = 1",
         "CREATE TABLE T2 AS SELECT * FROM T1",
+        "MERGE INTO T1 USING (SELECT 1 AS a) s ON T1.a = s.a \
+         WHEN MATCHED AND T1.b > 1 THEN UPDATE SET b = 2 \
+         WHEN MATCHED THEN DELETE \
+         WHEN NOT MATCHED THEN INSERT VALUES (s.a, 3)",
     ];
     for query in queries {
         let plan = ctx.sql(query).await?.into_optimized_plan()?;
@@ -418,6 +422,13 @@ async fn roundtrip_logical_plan_dml() -> Result<()> {
             "failed query roundtrip: {}",
             query
         );
+        if let (
+            LogicalPlan::Dml(DmlStatement { op, .. }),
+            LogicalPlan::Dml(DmlStatement { op: round_trip_op, .. }),
+        ) = (&plan, &logical_round_trip)
+        {
+            assert_eq!(op, round_trip_op, "failed query roundtrip: {query}");
+        }
     }
 
     Ok(())
@@ -855,6 +866,18 @@ async fn roundtrip_logical_plan_unnest() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn roundtrip_logical_plan_unnest_with_ordinality() -> Result<()> {
+    let ctx = SessionContext::new();
+    let query = "SELECT * FROM unnest([1, 2, 3]) WITH ORDINALITY";
+    let plan = ctx.sql(query).await?.into_optimized_plan()?;
+    let bytes = logical_plan_to_bytes(&plan)?;
+    let logical_round_trip = logical_plan_from_bytes(&bytes, &ctx)?;
+    assert_eq!(format!("{plan}"), format!("{logical_round_trip}"));
+    assert_eq!(plan.schema(), logical_round_trip.schema());
+    Ok(())
+}
+
 #[tokio::test]
 async fn roundtrip_expr_api() -> Result<()> {
     let ctx = SessionContext::new();
@@ -2554,7 +2577,17 @@ fn roundtrip_window() {
         WindowFunctionDefinition::AggregateUDF(avg_udaf()),
         vec![col("col1")],
     ))
-    .window_frame(row_number_frame)
+    .window_frame(row_number_frame.clone())
+    .build()
+    .unwrap();
+
+    // 8. with window_frame with an EXCLUDE clause
+    let test_expr8 = Expr::from(expr::WindowFunction::new(
+        WindowFunctionDefinition::AggregateUDF(max_udaf()),
+        vec![col("col1")],
+    ))
+    .order_by(vec![col("col2").sort(true, true)])
+    .window_frame(row_number_frame.with_exclusion(WindowFrameExclusion::Ties))
     .build()
     .unwrap();
 
@@ -2566,7 +2599,8 @@ fn roundtrip_window() {
     roundtrip_expr_test(test_expr4, ctx.clone());
     roundtrip_expr_test(test_expr5, ctx.clone());
     roundtrip_expr_test(test_expr6, ctx.clone());
-    roundtrip_expr_test(text_expr7, ctx);
+    roundtrip_expr_test(text_expr7, ctx.clone());
+    roundtrip_expr_test(test_expr8, ctx);
 }
 
 #[tokio::test]
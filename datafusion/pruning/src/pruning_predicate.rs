@@ -43,7 +43,9 @@ use datafusion_common::{
 };
 use datafusion_common::{Column, DFSchema};
 use datafusion_expr_common::operator::Operator;
-use datafusion_physical_expr::utils::{collect_columns, Guarantee, LiteralGuarantee};
+use datafusion_physical_expr::utils::{
+    collect_columns, nested_field_access, nested_field_name, Guarantee, LiteralGuarantee,
+};
 use datafusion_physical_expr::{expressions as phys_expr, PhysicalExprRef};
 use datafusion_physical_expr_common::physical_expr::snapshot_physical_expr;
 use datafusion_physical_plan::{ColumnarValue, PhysicalExpr};
@@ -80,6 +82,10 @@ use datafusion_physical_plan::{ColumnarValue, PhysicalExpr};
 /// 3. Any source of information that implements the [`PruningStatistics`] trait
 ///    (not just Parquet metadata).
 ///
+/// 4. Fields nested in struct columns, such as `payload['user']['country']`,
+///    whose statistics are looked up by the path of the field
+///    (`payload.user.country`), like the leaf columns of Parquet files.
+///
 /// # Example
 ///
 /// See the [`pruning.rs` example in the `datafusion-examples`] for a complete
@@ -461,11 +467,14 @@ impl PruningPredicate {
         let expr = snapshot_physical_expr(expr)?;
         let unhandled_hook = Arc::new(ConstantUnhandledPredicateHook::default()) as _;
 
+        // prune the fields nested in struct columns like columns
+        let (nested_expr, nested_schema) = rewrite_nested_fields(&expr, &schema)?;
+
         // build predicate expression once
         let mut required_columns = RequiredColumns::new();
         let predicate_expr = build_predicate_expression(
-            &expr,
-            schema.as_ref(),
+            &nested_expr,
+            &nested_schema,
             &mut required_columns,
             &unhandled_hook,
         );
@@ -474,7 +483,7 @@ impl PruningPredicate {
         let predicate_expr =
             PhysicalExprSimplifier::new(&predicate_schema).simplify(predicate_expr)?;
 
-        let literal_guarantees = LiteralGuarantee::analyze(&expr);
+        let literal_guarantees = LiteralGuarantee::analyze(&nested_expr);
 
         Ok(Self {
             schema,
@@ -874,6 +883,46 @@ impl From<Vec<(phys_expr::Column, StatisticsType, Field)>> for RequiredColumns {
     }
 }
 
+/// Rewrites the accesses to the primitive fields nested in struct columns in
+/// `expr`, such as `payload['user']['country']`, as references to columns
+/// named by the path of the field, such as `payload.user.country`, which are
+/// appended to the returned copy of `schema`.
+///
+/// This way, their statistics are requested like the ones of the leaf columns
+/// of Parquet files, which are named by their path.
+fn rewrite_nested_fields(
+    expr: &Arc<dyn PhysicalExpr>,
+    schema: &Schema,
+) -> Result<(Arc<dyn PhysicalExpr>, Schema)> {
+    let mut fields = schema.fields().to_vec();
+    let expr = Arc::clone(expr)
+        .transform_down(|expr| {
+            let Some((column, path, field)) = nested_field_access(&expr, schema) else {
+                return Ok(Transformed::no(expr));
+            };
+            let name = nested_field_name(column.name(), &path);
+            // a column can have the name of a nested field
+            if field.data_type().is_nested() || schema.index_of(&name).is_ok() {
+                return Ok(Transformed::no(expr));
+            }
+            let index = match fields.iter().position(|f| f.name() == &name) {
+                Some(index) => index,
+                None => {
+                    let nested_field = field.clone().with_name(&name).with_nullable(true);
+                    fields.push(Arc::new(nested_field));
+                    fields.len() - 1
+                }
+            };
+            let column = Arc::new(phys_expr::Column::new(&name, index)) as _;
+            Ok(Transformed::yes(column))
+        })
+        .data()?;
+    Ok((
+        expr,
+        Schema::new_with_metadata(fields, schema.metadata().clone()),
+    ))
+}
+
 /// Build a RecordBatch from a list of statistics, creating arrays,
 /// with one row for each PruningStatistics and columns specified in
 /// in the required_columns parameter.
@@ -1878,6 +1927,7 @@ mod tests {
     };
     use datafusion_expr::expr::InList;
     use datafusion_expr::{cast, is_null, try_cast, Expr};
+    use datafusion_functions::core::expr_fn::get_field;
     use datafusion_functions_nested::expr_fn::{array_has, make_array};
     use datafusion_physical_expr::expressions as phys_expr;
     use datafusion_physical_expr::planner::logical2physical;
@@ -4669,6 +4719,47 @@ mod tests {
         // TODO: add other negative test for other case and op
     }
 
+    #[test]
+    fn prune_nested_struct_field() {
+        let schema = Arc::new(Schema::new(vec![Field::new_struct(
+            "s",
+            vec![Field::new("i", DataType::Int32, true)],
+            true,
+        )]));
+        // the statistics of the nested field are named by its path
+        let statistics = TestStatistics::new()
+            .with(
+                "s.i",
+                ContainerStats::new_i32(
+                    vec![Some(-5), Some(1), Some(-11), None, Some(1)], // min
+                    vec![Some(5), Some(11), Some(-1), None, None],     // max
+                ),
+            )
+            .with_contained(
+                "s.i",
+                [ScalarValue::Int32(Some(3))],
+                [Some(true), Some(false), None, None, None],
+            );
+
+        // s['i'] > 0
+        let expr = get_field(col("s"), "i").gt(lit(0));
+        prune_with_expr(expr, &schema, &statistics, &[true, true, false, true, true]);
+
+        // s['i'] = 3
+        let expr = get_field(col("s"), "i").eq(lit(3));
+        prune_with_expr(
+            expr,
+            &schema,
+            &statistics,
+            &[true, false, false, true, true],
+        );
+
+        let expr = logical2physical(&get_field(col("s"), "i").eq(lit(3)), &schema);
+        let p = PruningPredicate::try_new(expr, Arc::clone(&schema)).unwrap();
+        assert_eq!(p.literal_columns(), ["s.i"]);
+        assert_eq!(p.required_columns().single_column().unwrap().name(), "s.i");
+    }
+
     #[test]
     fn prune_with_contained_one_column() {
         let schema = Arc::new(Schema::new(vec![Field::new("s1", DataType::Utf8, true)]));
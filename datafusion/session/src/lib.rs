@@ -26,6 +26,7 @@
 //! * [`Session`] - Manages query execution context, including configurations,
 //!   catalogs, and runtime state
 //! * [`SessionStore`] - Handles session persistence and retrieval
+//! * [`Transaction`] - State of a multi-statement transaction
 //!
 //! The session system enables:
 //! * Configuration management for query execution
@@ -35,5 +36,7 @@
 //! * Query state persistence
 
 pub mod session;
+pub mod transaction;
 
 pub use crate::session::{Session, SessionStore};
+pub use crate::transaction::{Transaction, TransactionParticipant};
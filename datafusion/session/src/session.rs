@@ -15,6 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use crate::Transaction;
 use async_trait::async_trait;
 use datafusion_common::config::{ConfigOptions, TableOptions};
 use datafusion_common::{DFSchema, Result};
@@ -135,6 +136,12 @@ pub trait Session: Send + Sync {
 
     /// Get a new TaskContext to run in this session
     fn task_ctx(&self) -> Arc<TaskContext>;
+
+    /// Return the [`Transaction`] the statements of this session run in, if
+    /// a transaction was started with `BEGIN`
+    fn transaction(&self) -> Option<&Arc<Transaction>> {
+        None
+    }
 }
 
 /// Create a new task context instance from Session
@@ -0,0 +1,172 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! [`Transaction`] state shared by the statements of a multi-statement transaction
+
+use std::any::Any;
+use std::fmt::Debug;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use datafusion_common::Result;
+use datafusion_expr::{
+    TransactionAccessMode, TransactionIsolationLevel, TransactionStart,
+};
+use parking_lot::Mutex;
+
+/// A multi-statement transaction, started by `BEGIN` and ended by `COMMIT`
+/// or `ROLLBACK`.
+///
+/// The statements run within the transaction see it through
+/// [`Session::transaction`]. A [`TableProvider`] supporting transactions keeps
+/// the changes made within a transaction private by enlisting a
+/// [`TransactionParticipant`] the first time it is accessed, which then
+/// applies or discards the changes when the transaction ends.
+///
+/// Tables that do not enlist in the transaction apply their changes
+/// immediately.
+///
+/// [`Session::transaction`]: crate::Session::transaction
+/// [`TableProvider`]: https://docs.rs/datafusion/latest/datafusion/catalog/trait.TableProvider.html
+#[derive(Debug)]
+pub struct Transaction {
+    start: TransactionStart,
+    /// The enlisted participants with their key, in enlistment order
+    participants: Mutex<Vec<(usize, Arc<dyn TransactionParticipant>)>>,
+}
+
+impl Transaction {
+    /// Create a new transaction with the characteristics of `start`
+    pub fn new(start: TransactionStart) -> Self {
+        Self {
+            start,
+            participants: Mutex::new(vec![]),
+        }
+    }
+
+    /// Returns the statement that started this transaction
+    pub fn start(&self) -> &TransactionStart {
+        &self.start
+    }
+
+    /// Returns whether this transaction is allowed to write
+    pub fn access_mode(&self) -> &TransactionAccessMode {
+        &self.start.access_mode
+    }
+
+    /// Returns the isolation level of this transaction
+    pub fn isolation_level(&self) -> &TransactionIsolationLevel {
+        &self.start.isolation_level
+    }
+
+    /// Returns the participant enlisted with `key`, if any
+    pub fn participant(&self, key: usize) -> Option<Arc<dyn TransactionParticipant>> {
+        self.participants
+            .lock()
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, participant)| Arc::clone(participant))
+    }
+
+    /// Enlists `participant` with `key`, which identifies the table it tracks
+    /// the changes of, for example a unique id assigned to the table.
+    ///
+    /// Returns the participant enlisted with `key`, which is the previously
+    /// enlisted one if there is one.
+    pub fn enlist(
+        &self,
+        key: usize,
+        participant: Arc<dyn TransactionParticipant>,
+    ) -> Arc<dyn TransactionParticipant> {
+        let mut participants = self.participants.lock();
+        if let Some((_, existing)) = participants.iter().find(|(k, _)| *k == key) {
+            return Arc::clone(existing);
+        }
+        participants.push((key, Arc::clone(&participant)));
+        participant
+    }
+
+    /// Commits the changes of all the participants, in enlistment order.
+    ///
+    /// All participants are prepared first, if one of them fails the changes
+    /// of all the participants are discarded and its error is returned.
+    /// Otherwise all participants are committed even if one of them fails,
+    /// the first error is returned.
+    pub async fn commit(&self) -> Result<()> {
+        let participants = std::mem::take(&mut *self.participants.lock());
+        for (_, participant) in &participants {
+            if let Err(e) = participant.prepare().await {
+                for (_, participant) in &participants {
+                    // The error of the failed preparation is more relevant
+                    let _ = participant.rollback().await;
+                }
+                return Err(e);
+            }
+        }
+        let mut result = Ok(());
+        for (_, participant) in participants {
+            let committed = participant.commit().await;
+            if result.is_ok() {
+                result = committed;
+            }
+        }
+        result
+    }
+
+    /// Discards the changes of all the participants
+    pub async fn rollback(&self) -> Result<()> {
+        let participants = std::mem::take(&mut *self.participants.lock());
+        let mut result = Ok(());
+        for (_, participant) in participants {
+            let rolled_back = participant.rollback().await;
+            if result.is_ok() {
+                result = rolled_back;
+            }
+        }
+        result
+    }
+}
+
+/// The changes made to a table within a [`Transaction`], see
+/// [`Transaction::enlist`]
+#[async_trait]
+pub trait TransactionParticipant: Debug + Send + Sync {
+    /// Returns the participant as [`Any`] so that it can be downcast to a
+    /// specific implementation
+    fn as_any(&self) -> &dyn Any;
+
+    /// Checks that the changes of the transaction can be committed, for
+    /// example that they do not conflict with changes committed by other
+    /// transactions since this participant was enlisted.
+    ///
+    /// Called for all participants before any of them is committed. The
+    /// default implementation does nothing.
+    async fn prepare(&self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Makes the changes of the transaction visible outside of it
+    async fn commit(&self) -> Result<()>;
+
+    /// Discards the changes of the transaction.
+    ///
+    /// The default implementation does nothing, which is enough for
+    /// participants buffering their changes in memory.
+    async fn rollback(&self) -> Result<()> {
+        Ok(())
+    }
+}
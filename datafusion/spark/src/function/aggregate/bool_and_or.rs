@@ -0,0 +1,198 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::mem::size_of_val;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray};
+use arrow::datatypes::{DataType, Field, FieldRef};
+use datafusion_common::{Result, ScalarValue};
+use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion_expr::utils::format_state_name;
+use datafusion_expr::{Accumulator, AggregateUDFImpl, Signature, Volatility};
+
+/// Spark-compatible `bool_and` aggregate function
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#bool_and>
+///
+/// Returns true if all non-null values are true, or null if there are none.
+#[derive(Debug)]
+pub struct SparkBoolAnd {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl Default for SparkBoolAnd {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkBoolAnd {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::exact(vec![DataType::Boolean], Volatility::Immutable),
+            aliases: vec![String::from("every")],
+        }
+    }
+}
+
+impl AggregateUDFImpl for SparkBoolAnd {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "bool_and"
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn accumulator(&self, _acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        Ok(Box::new(BoolAccumulator::new(BoolOp::And)))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(vec![bool_state_field(args)])
+    }
+}
+
+/// Spark-compatible `bool_or` aggregate function
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#bool_or>
+///
+/// Returns true if at least one value is true, or null if all values are null.
+#[derive(Debug)]
+pub struct SparkBoolOr {
+    signature: Signature,
+    aliases: Vec<String>,
+}
+
+impl Default for SparkBoolOr {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkBoolOr {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::exact(vec![DataType::Boolean], Volatility::Immutable),
+            aliases: vec![String::from("some"), String::from("any")],
+        }
+    }
+}
+
+impl AggregateUDFImpl for SparkBoolOr {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "bool_or"
+    }
+
+    fn aliases(&self) -> &[String] {
+        &self.aliases
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn accumulator(&self, _acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        Ok(Box::new(BoolAccumulator::new(BoolOp::Or)))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(vec![bool_state_field(args)])
+    }
+}
+
+fn bool_state_field(args: StateFieldsArgs) -> FieldRef {
+    Arc::new(Field::new(
+        format_state_name(args.name, "value"),
+        DataType::Boolean,
+        true,
+    ))
+}
+
+#[derive(Debug, Clone, Copy)]
+enum BoolOp {
+    And,
+    Or,
+}
+
+#[derive(Debug)]
+struct BoolAccumulator {
+    op: BoolOp,
+    value: Option<bool>,
+}
+
+impl BoolAccumulator {
+    fn new(op: BoolOp) -> Self {
+        Self { op, value: None }
+    }
+}
+
+impl Accumulator for BoolAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let array = values[0].as_boolean();
+        if array.null_count() == array.len() {
+            return Ok(());
+        }
+        let value = match self.op {
+            BoolOp::And => array.false_count() == 0,
+            BoolOp::Or => array.true_count() > 0,
+        };
+        self.value = Some(match (self.op, self.value) {
+            (_, None) => value,
+            (BoolOp::And, Some(current)) => current && value,
+            (BoolOp::Or, Some(current)) => current || value,
+        });
+        Ok(())
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        Ok(ScalarValue::Boolean(self.value))
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self)
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![ScalarValue::Boolean(self.value)])
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        self.update_batch(states)
+    }
+}
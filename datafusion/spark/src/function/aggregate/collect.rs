@@ -0,0 +1,234 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::collections::HashSet;
+use std::mem::size_of_val;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray};
+use arrow::datatypes::{DataType, Field, FieldRef};
+use datafusion_common::{Result, ScalarValue};
+use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion_expr::utils::format_state_name;
+use datafusion_expr::{Accumulator, AggregateUDFImpl, Signature, Volatility};
+use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
+
+/// Spark-compatible `collect_list` aggregate function
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#collect_list>
+///
+/// Unlike `array_agg`, null values are skipped and an empty array is returned
+/// if there are no non-null values.
+#[derive(Debug)]
+pub struct SparkCollectList {
+    signature: Signature,
+}
+
+impl Default for SparkCollectList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkCollectList {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl AggregateUDFImpl for SparkCollectList {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "collect_list"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::new_list(arg_types[0].clone(), true))
+    }
+
+    fn is_nullable(&self) -> bool {
+        false
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let data_type = acc_args.exprs[0].data_type(acc_args.schema)?;
+        Ok(Box::new(CollectAccumulator::new(data_type, false)))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(vec![collect_state_field(args)])
+    }
+
+    fn default_value(&self, data_type: &DataType) -> Result<ScalarValue> {
+        empty_list(data_type)
+    }
+}
+
+/// Spark-compatible `collect_set` aggregate function
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#collect_set>
+///
+/// Null values are skipped and each distinct value is returned once, in the
+/// order it was first collected.
+#[derive(Debug)]
+pub struct SparkCollectSet {
+    signature: Signature,
+}
+
+impl Default for SparkCollectSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkCollectSet {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::any(1, Volatility::Immutable),
+        }
+    }
+}
+
+impl AggregateUDFImpl for SparkCollectSet {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "collect_set"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::new_list(arg_types[0].clone(), true))
+    }
+
+    fn is_nullable(&self) -> bool {
+        false
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let data_type = acc_args.exprs[0].data_type(acc_args.schema)?;
+        Ok(Box::new(CollectAccumulator::new(data_type, true)))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(vec![collect_state_field(args)])
+    }
+
+    fn default_value(&self, data_type: &DataType) -> Result<ScalarValue> {
+        empty_list(data_type)
+    }
+}
+
+fn collect_state_field(args: StateFieldsArgs) -> FieldRef {
+    Arc::new(Field::new_list(
+        format_state_name(args.name, "values"),
+        Field::new_list_field(args.input_fields[0].data_type().clone(), true),
+        true,
+    ))
+}
+
+fn empty_list(data_type: &DataType) -> Result<ScalarValue> {
+    match data_type {
+        DataType::List(field) => Ok(ScalarValue::List(ScalarValue::new_list_nullable(
+            &[],
+            field.data_type(),
+        ))),
+        _ => ScalarValue::try_from(data_type),
+    }
+}
+
+/// Collects the non-null values of `collect_list` and `collect_set`
+#[derive(Debug)]
+struct CollectAccumulator {
+    data_type: DataType,
+    values: Vec<ScalarValue>,
+    /// The values collected so far, if only distinct values are collected
+    seen: Option<HashSet<ScalarValue>>,
+}
+
+impl CollectAccumulator {
+    fn new(data_type: DataType, distinct: bool) -> Self {
+        Self {
+            data_type,
+            values: vec![],
+            seen: distinct.then(HashSet::new),
+        }
+    }
+
+    fn to_list(&self) -> ScalarValue {
+        ScalarValue::List(ScalarValue::new_list_nullable(
+            &self.values,
+            &self.data_type,
+        ))
+    }
+}
+
+impl Accumulator for CollectAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let array = &values[0];
+        for row in 0..array.len() {
+            if array.is_null(row) {
+                continue;
+            }
+            let value = ScalarValue::try_from_array(array, row)?;
+            match &mut self.seen {
+                Some(seen) if !seen.insert(value.clone()) => {}
+                _ => self.values.push(value),
+            }
+        }
+        Ok(())
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        Ok(self.to_list())
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self) + ScalarValue::size_of_vec(&self.values)
+            - size_of_val(&self.values)
+            + self.data_type.size()
+            - size_of_val(&self.data_type)
+            + self.seen.as_ref().map_or(0, |seen| {
+                ScalarValue::size_of_hashset(seen) - size_of_val(seen)
+            })
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![self.to_list()])
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        let lists = states[0].as_list::<i32>();
+        for list in lists.iter().flatten() {
+            self.update_batch(&[list])?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,256 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::mem::size_of_val;
+use std::sync::Arc;
+
+use crate::function::utils::get_literal_arg;
+use arrow::array::{Array, ArrayRef, AsArray};
+use arrow::datatypes::{DataType, Field, FieldRef};
+use datafusion_common::{exec_err, plan_err, Result, ScalarValue};
+use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion_expr::utils::format_state_name;
+use datafusion_expr::{Accumulator, AggregateUDFImpl, Signature, Volatility};
+
+/// Spark-compatible `first` aggregate function
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#first>
+///
+/// Returns the first value of a group, skipping nulls if the optional second
+/// argument is true or `IGNORE NULLS` is specified.
+#[derive(Debug)]
+pub struct SparkFirst {
+    signature: Signature,
+}
+
+impl Default for SparkFirst {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkFirst {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl AggregateUDFImpl for SparkFirst {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "first"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_first_last(self.name(), arg_types)
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        FirstLastAccumulator::try_new(self.name(), &acc_args, true)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(first_last_state_fields(args))
+    }
+}
+
+/// Spark-compatible `last` aggregate function
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#last>
+///
+/// Returns the last value of a group, skipping nulls if the optional second
+/// argument is true or `IGNORE NULLS` is specified.
+#[derive(Debug)]
+pub struct SparkLast {
+    signature: Signature,
+}
+
+impl Default for SparkLast {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkLast {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl AggregateUDFImpl for SparkLast {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "last"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_first_last(self.name(), arg_types)
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        FirstLastAccumulator::try_new(self.name(), &acc_args, false)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(first_last_state_fields(args))
+    }
+}
+
+fn coerce_first_last(name: &str, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+    match arg_types {
+        [value] => Ok(vec![value.clone()]),
+        [value, DataType::Boolean | DataType::Null] => {
+            Ok(vec![value.clone(), DataType::Boolean])
+        }
+        [_, other] => plan_err!(
+            "Spark `{name}` function expects a boolean second argument, got {other}"
+        ),
+        _ => plan_err!(
+            "Spark `{name}` function requires 1 or 2 arguments, got {}",
+            arg_types.len()
+        ),
+    }
+}
+
+fn first_last_state_fields(args: StateFieldsArgs) -> Vec<FieldRef> {
+    vec![
+        Arc::new(Field::new(
+            format_state_name(args.name, "value"),
+            args.input_fields[0].data_type().clone(),
+            true,
+        )),
+        Arc::new(Field::new(
+            format_state_name(args.name, "is_set"),
+            DataType::Boolean,
+            false,
+        )),
+    ]
+}
+
+/// Keeps the first or last (non-null) value of `first` and `last`
+#[derive(Debug)]
+struct FirstLastAccumulator {
+    first: bool,
+    ignore_nulls: bool,
+    value: ScalarValue,
+    is_set: bool,
+}
+
+impl FirstLastAccumulator {
+    fn try_new(
+        name: &str,
+        acc_args: &AccumulatorArgs,
+        first: bool,
+    ) -> Result<Box<dyn Accumulator>> {
+        let ignore_nulls = match get_literal_arg(name, acc_args.exprs, 1)? {
+            None => false,
+            Some(ScalarValue::Boolean(Some(ignore_nulls))) => ignore_nulls,
+            Some(other) => {
+                return exec_err!(
+                    "Spark `{name}` function expects a non-null boolean ignoreNulls argument, got {other}"
+                )
+            }
+        };
+        Ok(Box::new(Self {
+            first,
+            ignore_nulls: ignore_nulls || acc_args.ignore_nulls,
+            value: ScalarValue::try_from(acc_args.return_type())?,
+            is_set: false,
+        }))
+    }
+
+    /// Returns the row of `array` holding the first or last value, among the
+    /// rows for which `candidate` is true
+    fn find_row(
+        &self,
+        array: &dyn Array,
+        candidate: impl Fn(usize) -> bool,
+    ) -> Option<usize> {
+        let is_match =
+            |row: &usize| candidate(*row) && (!self.ignore_nulls || array.is_valid(*row));
+        if self.first {
+            (0..array.len()).find(is_match)
+        } else {
+            (0..array.len()).rev().find(is_match)
+        }
+    }
+}
+
+impl Accumulator for FirstLastAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        if self.first && self.is_set {
+            return Ok(());
+        }
+        if let Some(row) = self.find_row(values[0].as_ref(), |_| true) {
+            self.value = ScalarValue::try_from_array(&values[0], row)?;
+            self.is_set = true;
+        }
+        Ok(())
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        Ok(self.value.clone())
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self) + self.value.size() - size_of_val(&self.value)
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![
+            self.value.clone(),
+            ScalarValue::Boolean(Some(self.is_set)),
+        ])
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        if self.first && self.is_set {
+            return Ok(());
+        }
+        let is_set = states[1].as_boolean();
+        if let Some(row) = self.find_row(states[0].as_ref(), |row| is_set.value(row)) {
+            self.value = ScalarValue::try_from_array(&states[0], row)?;
+            self.is_set = true;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,224 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::cmp::Ordering;
+use std::mem::size_of_val;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef};
+use arrow::datatypes::{DataType, Field, FieldRef};
+use datafusion_common::{plan_err, Result, ScalarValue};
+use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion_expr::utils::format_state_name;
+use datafusion_expr::{Accumulator, AggregateUDFImpl, Signature, Volatility};
+use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
+
+/// Spark-compatible `max_by` aggregate function
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#max_by>
+///
+/// Returns the value of `x` associated with the maximum value of `y`,
+/// ignoring the rows where `y` is null.
+#[derive(Debug)]
+pub struct SparkMaxBy {
+    signature: Signature,
+}
+
+impl Default for SparkMaxBy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkMaxBy {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl AggregateUDFImpl for SparkMaxBy {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "max_by"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_by(self.name(), arg_types)
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        ByAccumulator::try_new(&acc_args, Ordering::Greater)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(by_state_fields(args))
+    }
+}
+
+/// Spark-compatible `min_by` aggregate function
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#min_by>
+///
+/// Returns the value of `x` associated with the minimum value of `y`,
+/// ignoring the rows where `y` is null.
+#[derive(Debug)]
+pub struct SparkMinBy {
+    signature: Signature,
+}
+
+impl Default for SparkMinBy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkMinBy {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl AggregateUDFImpl for SparkMinBy {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "min_by"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        coerce_by(self.name(), arg_types)
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        ByAccumulator::try_new(&acc_args, Ordering::Less)
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(by_state_fields(args))
+    }
+}
+
+fn coerce_by(name: &str, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+    match arg_types {
+        [_, ord @ DataType::Map(_, _)] => {
+            plan_err!("Spark `{name}` function does not support ordering by type {ord}")
+        }
+        [_, _] => Ok(arg_types.to_vec()),
+        _ => plan_err!(
+            "Spark `{name}` function requires 2 arguments, got {}",
+            arg_types.len()
+        ),
+    }
+}
+
+fn by_state_fields(args: StateFieldsArgs) -> Vec<FieldRef> {
+    vec![
+        Arc::new(Field::new(
+            format_state_name(args.name, "value"),
+            args.input_fields[0].data_type().clone(),
+            true,
+        )),
+        Arc::new(Field::new(
+            format_state_name(args.name, "ord"),
+            args.input_fields[1].data_type().clone(),
+            true,
+        )),
+    ]
+}
+
+/// Keeps the value of `max_by` and `min_by` along with its ordering value
+#[derive(Debug)]
+struct ByAccumulator {
+    /// The ordering of a new ordering value relative to the current one for
+    /// it to replace the current value
+    replace_if: Ordering,
+    value: ScalarValue,
+    /// The ordering value of `value`, null if no row was seen yet
+    ord: ScalarValue,
+}
+
+impl ByAccumulator {
+    fn try_new(
+        acc_args: &AccumulatorArgs,
+        replace_if: Ordering,
+    ) -> Result<Box<dyn Accumulator>> {
+        let ord_type = acc_args.exprs[1].data_type(acc_args.schema)?;
+        Ok(Box::new(Self {
+            replace_if,
+            value: ScalarValue::try_from(acc_args.return_type())?,
+            ord: ScalarValue::try_from(&ord_type)?,
+        }))
+    }
+}
+
+impl Accumulator for ByAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let (array, ords) = (&values[0], &values[1]);
+        for row in 0..ords.len() {
+            if ords.is_null(row) {
+                continue;
+            }
+            let ord = ScalarValue::try_from_array(ords, row)?;
+            if self.ord.is_null() || ord.partial_cmp(&self.ord) == Some(self.replace_if) {
+                self.value = ScalarValue::try_from_array(array, row)?;
+                self.ord = ord;
+            }
+        }
+        Ok(())
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        Ok(self.value.clone())
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self) + self.value.size() - size_of_val(&self.value) + self.ord.size()
+            - size_of_val(&self.ord)
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![self.value.clone(), self.ord.clone()])
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        self.update_batch(states)
+    }
+}
@@ -15,11 +15,125 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub mod bool_and_or;
+pub mod collect;
+pub mod count_if;
+pub mod first_last;
+pub mod max_min_by;
+pub mod percentile;
+pub mod try_avg;
+pub mod try_sum;
+
 use datafusion_expr::AggregateUDF;
 use std::sync::Arc;
 
-pub mod expr_fn {}
+/// Creates a singleton `AggregateUDF` of the `$UDAF` function and a function
+/// named `$NAME` which returns that singleton.
+macro_rules! make_udaf_function {
+    ($UDAF:ty, $NAME:ident) => {
+        #[doc = concat!("Return a [`AggregateUDF`](datafusion_expr::AggregateUDF) implementation of ", stringify!($NAME))]
+        pub fn $NAME() -> Arc<AggregateUDF> {
+            // Singleton instance of the function
+            static INSTANCE: std::sync::LazyLock<Arc<AggregateUDF>> =
+                std::sync::LazyLock::new(|| {
+                    Arc::new(AggregateUDF::new_from_impl(<$UDAF>::new()))
+                });
+            Arc::clone(&INSTANCE)
+        }
+    };
+}
+
+make_udaf_function!(bool_and_or::SparkBoolAnd, bool_and);
+make_udaf_function!(bool_and_or::SparkBoolOr, bool_or);
+make_udaf_function!(collect::SparkCollectList, collect_list);
+make_udaf_function!(collect::SparkCollectSet, collect_set);
+make_udaf_function!(count_if::SparkCountIf, count_if);
+make_udaf_function!(first_last::SparkFirst, first);
+make_udaf_function!(first_last::SparkLast, last);
+make_udaf_function!(max_min_by::SparkMaxBy, max_by);
+make_udaf_function!(max_min_by::SparkMinBy, min_by);
+make_udaf_function!(percentile::SparkPercentile, percentile);
+make_udaf_function!(try_avg::SparkTryAvg, try_avg);
+make_udaf_function!(try_sum::SparkTrySum, try_sum);
+
+pub mod expr_fn {
+    use datafusion_functions::export_functions;
+
+    export_functions!((
+        bool_and,
+        "Returns true if all non-null values are true.",
+        arg1
+    ));
+    export_functions!((
+        bool_or,
+        "Returns true if at least one non-null value is true.",
+        arg1
+    ));
+    export_functions!((
+        collect_list,
+        "Returns an array of the non-null values.",
+        arg1
+    ));
+    export_functions!((
+        collect_set,
+        "Returns an array of the distinct non-null values.",
+        arg1
+    ));
+    export_functions!((
+        count_if,
+        "Returns the number of rows for which the predicate is true.",
+        arg1
+    ));
+    export_functions!((
+        first,
+        "Returns the first value, skipping nulls if the optional second argument is true.",
+        args,
+    ));
+    export_functions!((
+        last,
+        "Returns the last value, skipping nulls if the optional second argument is true.",
+        args,
+    ));
+    export_functions!((
+        max_by,
+        "Returns the value of `x` associated with the maximum value of `y`.",
+        x y
+    ));
+    export_functions!((
+        min_by,
+        "Returns the value of `x` associated with the minimum value of `y`.",
+        x y
+    ));
+    export_functions!((
+        percentile,
+        "Returns the exact percentile(s) of a numeric column at the given percentage(s).",
+        args,
+    ));
+    export_functions!((
+        try_avg,
+        "Returns the mean of the values, or null if their sum overflows.",
+        arg1
+    ));
+    export_functions!((
+        try_sum,
+        "Returns the sum of the values, or null if it overflows.",
+        arg1
+    ));
+}
 
 pub fn functions() -> Vec<Arc<AggregateUDF>> {
-    vec![]
+    vec![
+        bool_and(),
+        bool_or(),
+        collect_list(),
+        collect_set(),
+        count_if(),
+        first(),
+        last(),
+        max_by(),
+        min_by(),
+        percentile(),
+        try_avg(),
+        try_sum(),
+    ]
 }
@@ -0,0 +1,281 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::mem::size_of_val;
+use std::sync::Arc;
+
+use crate::function::utils::get_literal_arg;
+use arrow::array::{Array, ArrayRef, AsArray, Float64Array, Int64Array};
+use arrow::datatypes::{DataType, Field, FieldRef, Float64Type, Int64Type};
+use datafusion_common::utils::SingleRowListArrayBuilder;
+use datafusion_common::{exec_err, plan_err, Result, ScalarValue};
+use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion_expr::utils::format_state_name;
+use datafusion_expr::{Accumulator, AggregateUDFImpl, Signature, Volatility};
+
+/// Spark-compatible `percentile` aggregate function
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#percentile>
+///
+/// Returns the exact percentile(s) of a numeric column, interpolating
+/// linearly between the closest values. The percentage is a constant between
+/// 0 and 1 or an array of such constants, and each value may be weighted by
+/// an optional integer frequency.
+#[derive(Debug)]
+pub struct SparkPercentile {
+    signature: Signature,
+}
+
+impl Default for SparkPercentile {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkPercentile {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl AggregateUDFImpl for SparkPercentile {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "percentile"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        match arg_types.get(1) {
+            Some(DataType::List(_)) => Ok(DataType::new_list(DataType::Float64, true)),
+            _ => Ok(DataType::Float64),
+        }
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if !(2..=3).contains(&arg_types.len()) {
+            return plan_err!(
+                "Spark `percentile` function requires 2 or 3 arguments, got {}",
+                arg_types.len()
+            );
+        }
+        let mut coerced = vec![];
+        for (i, data_type) in arg_types.iter().enumerate() {
+            let target = match (i, data_type) {
+                (0 | 1, t) if t.is_numeric() || t.is_null() => DataType::Float64,
+                (1, DataType::List(f) | DataType::LargeList(f) | DataType::FixedSizeList(f, _))
+                    if f.data_type().is_numeric() =>
+                {
+                    DataType::new_list(DataType::Float64, true)
+                }
+                (2, t) if t.is_integer() || t.is_null() => DataType::Int64,
+                (_, t) => {
+                    return plan_err!(
+                        "Spark `percentile` function does not support argument {} of type {t}",
+                        i + 1
+                    )
+                }
+            };
+            coerced.push(target);
+        }
+        Ok(coerced)
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let percentages = match get_literal_arg(self.name(), acc_args.exprs, 1)? {
+            Some(ScalarValue::Float64(Some(p))) => vec![p],
+            Some(ScalarValue::List(list)) if list.null_count() == 0 => {
+                let values = list.value(0);
+                let values = values.as_primitive::<Float64Type>();
+                if values.null_count() > 0 {
+                    return exec_err!(
+                        "Spark `percentile` function does not support null percentages"
+                    );
+                }
+                values.values().to_vec()
+            }
+            _ => {
+                return exec_err!(
+                    "Spark `percentile` function requires a non-null percentage"
+                )
+            }
+        };
+        if let Some(p) = percentages.iter().find(|p| !(0.0..=1.0).contains(*p)) {
+            return exec_err!("Percentage value must be between 0.0 and 1.0, got {p}");
+        }
+
+        Ok(Box::new(PercentileAccumulator {
+            percentages,
+            return_list: matches!(acc_args.return_type(), DataType::List(_)),
+            values: vec![],
+            counts: vec![],
+        }))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(vec![
+            Arc::new(Field::new_list(
+                format_state_name(args.name, "values"),
+                Field::new_list_field(DataType::Float64, true),
+                true,
+            )),
+            Arc::new(Field::new_list(
+                format_state_name(args.name, "counts"),
+                Field::new_list_field(DataType::Int64, true),
+                true,
+            )),
+        ])
+    }
+}
+
+#[derive(Debug)]
+struct PercentileAccumulator {
+    percentages: Vec<f64>,
+    return_list: bool,
+    values: Vec<f64>,
+    /// The frequency of each value in `values`
+    counts: Vec<i64>,
+}
+
+impl PercentileAccumulator {
+    fn add(&mut self, values: &Float64Array, counts: Option<&Int64Array>) -> Result<()> {
+        for row in 0..values.len() {
+            let count = match counts {
+                Some(counts) if counts.is_null(row) => continue,
+                Some(counts) => counts.value(row),
+                None => 1,
+            };
+            if count < 0 {
+                return exec_err!(
+                    "Spark `percentile` function requires non-negative frequencies, got {count}"
+                );
+            }
+            if values.is_valid(row) && count > 0 {
+                self.values.push(values.value(row));
+                self.counts.push(count);
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the percentile of the values sorted with their frequencies
+    fn percentile(sorted: &[(f64, i64)], total: i64, percentage: f64) -> f64 {
+        // the value at `index` in the sorted values repeated per their frequency
+        let value_at = |index: i64| {
+            let mut cumulative = 0;
+            for (value, count) in sorted {
+                cumulative += count;
+                if cumulative > index {
+                    return *value;
+                }
+            }
+            sorted[sorted.len() - 1].0
+        };
+        let position = (total - 1) as f64 * percentage;
+        let lower = position.floor();
+        let higher = position.ceil();
+        let lower_value = value_at(lower as i64);
+        if lower == higher {
+            lower_value
+        } else {
+            let higher_value = value_at(higher as i64);
+            (higher - position) * lower_value + (position - lower) * higher_value
+        }
+    }
+}
+
+impl Accumulator for PercentileAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let counts = values.get(2).map(|c| c.as_primitive::<Int64Type>());
+        self.add(values[0].as_primitive::<Float64Type>(), counts)
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let results = if self.values.is_empty() {
+            None
+        } else {
+            let mut sorted = self
+                .values
+                .iter()
+                .copied()
+                .zip(self.counts.iter().copied())
+                .collect::<Vec<_>>();
+            sorted.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+            let total = self.counts.iter().sum::<i64>();
+            Some(
+                self.percentages
+                    .iter()
+                    .map(|p| Self::percentile(&sorted, total, *p))
+                    .collect::<Vec<_>>(),
+            )
+        };
+
+        match (results, self.return_list) {
+            (Some(results), true) => {
+                let values = results
+                    .into_iter()
+                    .map(|r| ScalarValue::Float64(Some(r)))
+                    .collect::<Vec<_>>();
+                Ok(ScalarValue::List(ScalarValue::new_list_nullable(
+                    &values,
+                    &DataType::Float64,
+                )))
+            }
+            (Some(results), false) => Ok(ScalarValue::Float64(Some(results[0]))),
+            (None, true) => Ok(ScalarValue::new_null_list(DataType::Float64, true, 1)),
+            (None, false) => Ok(ScalarValue::Float64(None)),
+        }
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self)
+            + self.percentages.capacity() * size_of::<f64>()
+            + self.values.capacity() * size_of::<f64>()
+            + self.counts.capacity() * size_of::<i64>()
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        let values = Float64Array::from(self.values.clone());
+        let counts = Int64Array::from(self.counts.clone());
+        Ok(vec![
+            SingleRowListArrayBuilder::new(Arc::new(values)).build_list_scalar(),
+            SingleRowListArrayBuilder::new(Arc::new(counts)).build_list_scalar(),
+        ])
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        let values = states[0].as_list::<i32>();
+        let counts = states[1].as_list::<i32>();
+        for (values, counts) in values.iter().zip(counts.iter()) {
+            if let (Some(values), Some(counts)) = (values, counts) {
+                self.add(
+                    values.as_primitive::<Float64Type>(),
+                    Some(counts.as_primitive::<Int64Type>()),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
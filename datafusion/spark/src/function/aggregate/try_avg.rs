@@ -0,0 +1,204 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::mem::size_of_val;
+use std::sync::Arc;
+
+use crate::function::aggregate::try_sum::{sum_type, TrySum};
+use arrow::array::{Array, ArrayRef, AsArray};
+use arrow::datatypes::{
+    DataType, Decimal128Type, DecimalType, Field, FieldRef, Int64Type,
+    DECIMAL128_MAX_PRECISION, DECIMAL128_MAX_SCALE,
+};
+use datafusion_common::{plan_err, Result, ScalarValue};
+use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion_expr::utils::format_state_name;
+use datafusion_expr::{Accumulator, AggregateUDFImpl, Signature, Volatility};
+use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
+
+/// Spark-compatible `try_avg` aggregate function
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#try_avg>
+///
+/// Returns the mean of the values, or null if their sum overflows.
+#[derive(Debug)]
+pub struct SparkTryAvg {
+    signature: Signature,
+}
+
+impl Default for SparkTryAvg {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkTryAvg {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl AggregateUDFImpl for SparkTryAvg {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "try_avg"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [data_type] = arg_types else {
+            return plan_err!(
+                "Spark `try_avg` function requires 1 argument, got {}",
+                arg_types.len()
+            );
+        };
+        let coerced = match data_type {
+            DataType::Decimal128(p, s) | DataType::Decimal256(p, s) => {
+                DataType::Decimal128((*p).min(DECIMAL128_MAX_PRECISION), *s)
+            }
+            other => sum_type(self.name(), other)?,
+        };
+        Ok(vec![coerced])
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        match &arg_types[0] {
+            DataType::Decimal128(p, s) => Ok(DataType::Decimal128(
+                DECIMAL128_MAX_PRECISION.min(p + 4),
+                DECIMAL128_MAX_SCALE.min(s + 4),
+            )),
+            _ => Ok(DataType::Float64),
+        }
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        let input_type = acc_args.exprs[0].data_type(acc_args.schema)?;
+        Ok(Box::new(TryAvgAccumulator {
+            sum_type: sum_type(self.name(), &input_type)?,
+            return_type: acc_args.return_type().clone(),
+            sum: TrySum::Empty,
+            count: 0,
+        }))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        let sum_type = sum_type(self.name(), args.input_fields[0].data_type())?;
+        Ok(vec![
+            Arc::new(Field::new(
+                format_state_name(args.name, "sum"),
+                sum_type,
+                true,
+            )),
+            Arc::new(Field::new(
+                format_state_name(args.name, "count"),
+                DataType::Int64,
+                false,
+            )),
+            Arc::new(Field::new(
+                format_state_name(args.name, "overflow"),
+                DataType::Boolean,
+                false,
+            )),
+        ])
+    }
+}
+
+#[derive(Debug)]
+struct TryAvgAccumulator {
+    sum_type: DataType,
+    return_type: DataType,
+    sum: TrySum,
+    count: i64,
+}
+
+impl TryAvgAccumulator {
+    /// Divides a decimal `sum` of scale `scale` by the count, rounding half
+    /// up to the scale of the return type
+    fn decimal_avg(&self, sum: i128, scale: i8) -> Option<ScalarValue> {
+        let DataType::Decimal128(precision, avg_scale) = self.return_type else {
+            return None;
+        };
+        let count = self.count as i128;
+        let scaled = sum.checked_mul(10_i128.checked_pow((avg_scale - scale) as u32)?)?;
+        let (quotient, remainder) = (scaled / count, scaled % count);
+        let avg = if remainder.abs() * 2 >= count {
+            quotient + scaled.signum()
+        } else {
+            quotient
+        };
+        Decimal128Type::is_valid_decimal_precision(avg, precision)
+            .then(|| ScalarValue::Decimal128(Some(avg), precision, avg_scale))
+    }
+}
+
+impl Accumulator for TryAvgAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        let array = values[0].as_ref();
+        self.count += (array.len() - array.null_count()) as i64;
+        self.sum.add_array(array, &self.sum_type)
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        let avg = match (self.sum.to_scalar(&self.sum_type)?, self.count) {
+            (_, 0) => None,
+            (ScalarValue::Int64(Some(sum)), count) => {
+                Some(ScalarValue::Float64(Some(sum as f64 / count as f64)))
+            }
+            (ScalarValue::Float64(Some(sum)), count) => {
+                Some(ScalarValue::Float64(Some(sum / count as f64)))
+            }
+            (ScalarValue::Decimal128(Some(sum), _, scale), _) => {
+                self.decimal_avg(sum, scale)
+            }
+            _ => None,
+        };
+        match avg {
+            Some(avg) => Ok(avg),
+            None => ScalarValue::try_from(&self.return_type),
+        }
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self)
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![
+            self.sum.to_scalar(&self.sum_type)?,
+            ScalarValue::Int64(Some(self.count)),
+            ScalarValue::Boolean(Some(self.sum.is_overflow())),
+        ])
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        let counts = states[1].as_primitive::<Int64Type>();
+        let overflows = states[2].as_boolean();
+        for row in 0..states[0].len() {
+            self.count += counts.value(row);
+            self.sum.merge(states[0].as_ref(), overflows, row)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,261 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::mem::size_of_val;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, BooleanArray};
+use arrow::datatypes::{
+    DataType, Decimal128Type, DecimalType, Field, FieldRef, Float64Type, Int64Type,
+    DECIMAL128_MAX_PRECISION,
+};
+use datafusion_common::{internal_err, plan_err, Result, ScalarValue};
+use datafusion_expr::function::{AccumulatorArgs, StateFieldsArgs};
+use datafusion_expr::utils::format_state_name;
+use datafusion_expr::{Accumulator, AggregateUDFImpl, Signature, Volatility};
+
+/// Spark-compatible `try_sum` aggregate function
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#try_sum>
+///
+/// Returns the sum of the values, or null if the sum overflows.
+#[derive(Debug)]
+pub struct SparkTrySum {
+    signature: Signature,
+}
+
+impl Default for SparkTrySum {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkTrySum {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl AggregateUDFImpl for SparkTrySum {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "try_sum"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [data_type] = arg_types else {
+            return plan_err!(
+                "Spark `try_sum` function requires 1 argument, got {}",
+                arg_types.len()
+            );
+        };
+        Ok(vec![sum_type(self.name(), data_type)?])
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(arg_types[0].clone())
+    }
+
+    fn accumulator(&self, acc_args: AccumulatorArgs) -> Result<Box<dyn Accumulator>> {
+        Ok(Box::new(TrySumAccumulator::new(
+            acc_args.return_type().clone(),
+        )))
+    }
+
+    fn state_fields(&self, args: StateFieldsArgs) -> Result<Vec<FieldRef>> {
+        Ok(vec![
+            Arc::new(Field::new(
+                format_state_name(args.name, "sum"),
+                args.return_type().clone(),
+                true,
+            )),
+            Arc::new(Field::new(
+                format_state_name(args.name, "overflow"),
+                DataType::Boolean,
+                false,
+            )),
+        ])
+    }
+}
+
+/// Returns the type of the sum of values of type `data_type`, to which the
+/// values are coerced: `BIGINT` for integers, `DOUBLE` for floating point
+/// numbers and a decimal with 10 more digits for decimals
+pub(super) fn sum_type(name: &str, data_type: &DataType) -> Result<DataType> {
+    match data_type {
+        DataType::Null => Ok(DataType::Int64),
+        t if t.is_integer() => Ok(DataType::Int64),
+        t if t.is_floating() => Ok(DataType::Float64),
+        DataType::Decimal128(p, s) | DataType::Decimal256(p, s) => Ok(
+            DataType::Decimal128(DECIMAL128_MAX_PRECISION.min(p + 10), *s),
+        ),
+        other => plan_err!("Spark `{name}` function does not support type {other}"),
+    }
+}
+
+/// Sum of the values of `try_sum` and `try_avg`, which overflows if it no
+/// longer fits its type
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum TrySum {
+    Empty,
+    Integer(i128),
+    Float(f64),
+    Overflow,
+}
+
+impl TrySum {
+    /// Adds the values of `array` to a sum of type `sum_type`, as returned by
+    /// [`sum_type`] for the type of the values
+    pub(super) fn add_array(
+        &mut self,
+        array: &dyn Array,
+        sum_type: &DataType,
+    ) -> Result<()> {
+        match array.data_type() {
+            DataType::Int64 => array
+                .as_primitive::<Int64Type>()
+                .iter()
+                .flatten()
+                .for_each(|v| self.add_integer(v as i128, sum_type)),
+            DataType::Decimal128(_, _) => array
+                .as_primitive::<Decimal128Type>()
+                .iter()
+                .flatten()
+                .for_each(|v| self.add_integer(v, sum_type)),
+            DataType::Float64 => array
+                .as_primitive::<Float64Type>()
+                .iter()
+                .flatten()
+                .for_each(|v| self.add_float(v)),
+            other => return internal_err!("Unexpected type {other} for try_sum"),
+        }
+        Ok(())
+    }
+
+    fn add_integer(&mut self, value: i128, sum_type: &DataType) {
+        let sum = match *self {
+            TrySum::Empty => Some(value),
+            TrySum::Integer(sum) => sum.checked_add(value),
+            TrySum::Float(_) | TrySum::Overflow => return,
+        };
+        let fits = |sum: &i128| match sum_type {
+            DataType::Int64 => i64::try_from(*sum).is_ok(),
+            DataType::Decimal128(p, _) => {
+                Decimal128Type::is_valid_decimal_precision(*sum, *p)
+            }
+            _ => true,
+        };
+        *self = sum.filter(fits).map_or(TrySum::Overflow, TrySum::Integer);
+    }
+
+    fn add_float(&mut self, value: f64) {
+        match self {
+            TrySum::Empty => *self = TrySum::Float(value),
+            TrySum::Float(sum) => *sum += value,
+            TrySum::Integer(_) | TrySum::Overflow => {}
+        }
+    }
+
+    /// Merges the partial sum at `row` of the state arrays
+    pub(super) fn merge(
+        &mut self,
+        sums: &dyn Array,
+        overflows: &BooleanArray,
+        row: usize,
+    ) -> Result<()> {
+        if overflows.value(row) {
+            *self = TrySum::Overflow;
+        } else if sums.is_valid(row) {
+            self.add_array(&sums.slice(row, 1), sums.data_type())?;
+        }
+        Ok(())
+    }
+
+    pub(super) fn is_overflow(&self) -> bool {
+        matches!(self, TrySum::Overflow)
+    }
+
+    /// Returns the sum as a value of `data_type`, or null if there were no
+    /// values or the sum overflowed
+    pub(super) fn to_scalar(self, data_type: &DataType) -> Result<ScalarValue> {
+        match (self, data_type) {
+            (TrySum::Integer(sum), DataType::Int64) => {
+                Ok(ScalarValue::Int64(Some(sum as i64)))
+            }
+            (TrySum::Integer(sum), DataType::Decimal128(p, s)) => {
+                Ok(ScalarValue::Decimal128(Some(sum), *p, *s))
+            }
+            (TrySum::Float(sum), DataType::Float64) => {
+                Ok(ScalarValue::Float64(Some(sum)))
+            }
+            _ => ScalarValue::try_from(data_type),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct TrySumAccumulator {
+    data_type: DataType,
+    sum: TrySum,
+}
+
+impl TrySumAccumulator {
+    fn new(data_type: DataType) -> Self {
+        Self {
+            data_type,
+            sum: TrySum::Empty,
+        }
+    }
+}
+
+impl Accumulator for TrySumAccumulator {
+    fn update_batch(&mut self, values: &[ArrayRef]) -> Result<()> {
+        self.sum.add_array(values[0].as_ref(), &self.data_type)
+    }
+
+    fn evaluate(&mut self) -> Result<ScalarValue> {
+        self.sum.to_scalar(&self.data_type)
+    }
+
+    fn size(&self) -> usize {
+        size_of_val(self)
+    }
+
+    fn state(&mut self) -> Result<Vec<ScalarValue>> {
+        Ok(vec![
+            self.sum.to_scalar(&self.data_type)?,
+            ScalarValue::Boolean(Some(self.sum.is_overflow())),
+        ])
+    }
+
+    fn merge_batch(&mut self, states: &[ArrayRef]) -> Result<()> {
+        let overflows = states[1].as_boolean();
+        for row in 0..states[0].len() {
+            self.sum.merge(states[0].as_ref(), overflows, row)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,215 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::function::csv::utils::{coerce_options_arg, CsvOptions};
+use crate::function::ddl::parse_ddl_schema;
+use crate::function::utils::coerce_string_args;
+use arrow::array::{new_empty_array, Array, ArrayRef, AsArray, StructArray};
+use arrow::datatypes::{DataType, Field, FieldRef, Fields};
+use datafusion_common::{internal_err, plan_err, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+
+/// Spark-compatible `from_csv` expression
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#from_csv>
+///
+/// The schema is a constant DDL string of the fields of the returned struct,
+/// such as `a INT, b STRING`. Records are parsed in Spark's `PERMISSIVE` mode:
+/// fields which can not be converted to their type, and missing fields, are
+/// null.
+#[derive(Debug)]
+pub struct SparkFromCsv {
+    signature: Signature,
+}
+
+impl Default for SparkFromCsv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkFromCsv {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for SparkFromCsv {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "from_csv"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let schema = args.scalar_arguments.get(1).copied().flatten();
+        let Some(ddl) = schema.and_then(|sv| sv.try_as_str().flatten()) else {
+            return plan_err!(
+                "Spark `from_csv` function requires its schema to be a constant string"
+            );
+        };
+        match parse_ddl_schema(ddl)? {
+            DataType::Struct(fields) => {
+                if let Some(field) = fields.iter().find(|f| f.data_type().is_nested()) {
+                    return plan_err!(
+                        "Spark `from_csv` function does not support field {} of type {}",
+                        field.name(),
+                        field.data_type()
+                    );
+                }
+                let fields = fields
+                    .iter()
+                    .map(|f| f.as_ref().clone().with_nullable(true))
+                    .collect::<Fields>();
+                Ok(Arc::new(Field::new(
+                    self.name(),
+                    DataType::Struct(fields),
+                    true,
+                )))
+            }
+            other => plan_err!(
+                "Spark `from_csv` function requires a struct schema, got {other}"
+            ),
+        }
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let DataType::Struct(fields) = args.return_field.data_type() else {
+            return internal_err!(
+                "Unexpected return type {} for from_csv",
+                args.return_field.data_type()
+            );
+        };
+        let options = CsvOptions::try_from_arg(self.name(), args.args.get(2))?;
+        match &args.args[0] {
+            ColumnarValue::Scalar(csv) => {
+                let csv = csv.try_as_str().flatten();
+                Ok(ColumnarValue::Scalar(csv_to_scalar(csv, fields, &options)?))
+            }
+            ColumnarValue::Array(csv) => {
+                let csv = csv.as_string::<i32>();
+                if csv.is_empty() {
+                    let data_type = args.return_field.data_type();
+                    return Ok(ColumnarValue::Array(new_empty_array(data_type)));
+                }
+                let values = csv
+                    .iter()
+                    .map(|csv| csv_to_scalar(csv, fields, &options))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(ColumnarValue::Array(ScalarValue::iter_to_array(values)?))
+            }
+        }
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        match arg_types {
+            [_, _] => coerce_string_args(self.name(), arg_types),
+            [csv, schema, options] => {
+                let mut coerced = coerce_string_args(self.name(), &[csv.clone(), schema.clone()])?;
+                coerced.push(coerce_options_arg(self.name(), options)?);
+                Ok(coerced)
+            }
+            _ => plan_err!(
+                "Spark `from_csv` function requires 2 or 3 arguments, got {}",
+                arg_types.len()
+            ),
+        }
+    }
+}
+
+/// Parses a CSV record into a struct with the given fields
+fn csv_to_scalar(
+    csv: Option<&str>,
+    fields: &Fields,
+    options: &CsvOptions,
+) -> Result<ScalarValue> {
+    let Some(csv) = csv else {
+        return ScalarValue::try_new_null(&DataType::Struct(fields.clone()));
+    };
+    let values = options.parse_record(csv);
+    let columns = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let value = values.get(i).cloned().flatten();
+            convert_field(value, field.data_type())?.to_array()
+        })
+        .collect::<Result<Vec<ArrayRef>>>()?;
+    let array = StructArray::try_new(fields.clone(), columns, None)?;
+    Ok(ScalarValue::Struct(Arc::new(array)))
+}
+
+/// Converts a field of a CSV record to a value of type `data_type`, returning
+/// null if it can not be converted
+fn convert_field(value: Option<String>, data_type: &DataType) -> Result<ScalarValue> {
+    let converted = match (value, data_type) {
+        (None, _) => None,
+        (Some(value), DataType::Utf8) => Some(ScalarValue::from(value)),
+        (Some(value), DataType::Boolean) => {
+            if value.eq_ignore_ascii_case("true") {
+                Some(ScalarValue::from(true))
+            } else if value.eq_ignore_ascii_case("false") {
+                Some(ScalarValue::from(false))
+            } else {
+                None
+            }
+        }
+        (Some(value), DataType::Float32 | DataType::Float64) => {
+            let value = match value.as_str() {
+                "NaN" => "NaN".to_string(),
+                "Infinity" | "+Infinity" | "Inf" | "+Inf" => "inf".to_string(),
+                "-Infinity" | "-Inf" => "-inf".to_string(),
+                _ if value.chars().all(|c| c.is_ascii_digit() || "+-.eE".contains(c)) => {
+                    value
+                }
+                _ => return ScalarValue::try_new_null(data_type),
+            };
+            ScalarValue::try_from_string(value, data_type).ok()
+        }
+        (Some(value), data_type)
+            if data_type.is_numeric()
+                || matches!(
+                    data_type,
+                    DataType::Date32 | DataType::Timestamp(_, _) | DataType::Binary
+                ) =>
+        {
+            ScalarValue::try_from_string(value, data_type).ok()
+        }
+        (Some(_), _) => None,
+    };
+    match converted {
+        Some(converted) => Ok(converted),
+        None => ScalarValue::try_new_null(data_type),
+    }
+}
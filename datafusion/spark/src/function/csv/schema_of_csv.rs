@@ -0,0 +1,162 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::function::csv::utils::{coerce_options_arg, CsvOptions};
+use crate::function::ddl::format_ddl_type;
+use crate::function::utils::coerce_string_args;
+use arrow::array::{AsArray, StringArray};
+use arrow::datatypes::{DataType, Field, Fields, TimeUnit};
+use chrono::NaiveDateTime;
+use datafusion_common::{plan_err, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+
+/// Spark-compatible `schema_of_csv` expression
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#schema_of_csv>
+#[derive(Debug)]
+pub struct SparkSchemaOfCsv {
+    signature: Signature,
+}
+
+impl Default for SparkSchemaOfCsv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkSchemaOfCsv {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for SparkSchemaOfCsv {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "schema_of_csv"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let options = CsvOptions::try_from_arg(self.name(), args.args.get(1))?;
+        match &args.args[0] {
+            ColumnarValue::Scalar(csv) => {
+                let schema = csv.try_as_str().flatten().map(|csv| infer_schema(csv, &options));
+                Ok(ColumnarValue::Scalar(ScalarValue::Utf8(schema)))
+            }
+            ColumnarValue::Array(csv) => {
+                let result = csv
+                    .as_string::<i32>()
+                    .iter()
+                    .map(|csv| csv.map(|csv| infer_schema(csv, &options)))
+                    .collect::<StringArray>();
+                Ok(ColumnarValue::Array(Arc::new(result)))
+            }
+        }
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        match arg_types {
+            [_] => coerce_string_args(self.name(), arg_types),
+            [csv, options] => Ok(vec![
+                coerce_string_args(self.name(), &[csv.clone()])?.remove(0),
+                coerce_options_arg(self.name(), options)?,
+            ]),
+            _ => plan_err!(
+                "Spark `schema_of_csv` function requires 1 or 2 arguments, got {}",
+                arg_types.len()
+            ),
+        }
+    }
+}
+
+/// Infers the schema of a CSV record, formatted as a DDL string, with fields
+/// named `_c0`, `_c1`, ...
+fn infer_schema(csv: &str, options: &CsvOptions) -> String {
+    let fields = options
+        .parse_record(csv)
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let data_type = value.as_deref().map_or(DataType::Utf8, infer_type);
+            Field::new(format!("_c{i}"), data_type, true)
+        })
+        .collect::<Fields>();
+    format_ddl_type(&DataType::Struct(fields))
+}
+
+/// Infers the type of a CSV field the way Spark does, trying `INT`, `BIGINT`,
+/// `DOUBLE`, `TIMESTAMP` and `BOOLEAN` before falling back to `STRING`
+fn infer_type(value: &str) -> DataType {
+    let is_number_text = value.chars().any(|c| c.is_ascii_digit())
+        && value.chars().all(|c| c.is_ascii_digit() || "+-.eE".contains(c));
+    if value.parse::<i32>().is_ok() {
+        DataType::Int32
+    } else if value.parse::<i64>().is_ok() {
+        DataType::Int64
+    } else if (is_number_text && value.parse::<f64>().is_ok())
+        || matches!(value, "NaN" | "Infinity" | "-Infinity")
+    {
+        DataType::Float64
+    } else if is_timestamp(value) {
+        DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+    } else if value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("false") {
+        DataType::Boolean
+    } else {
+        DataType::Utf8
+    }
+}
+
+fn is_timestamp(value: &str) -> bool {
+    ["%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M:%S%.f"]
+        .iter()
+        .any(|format| NaiveDateTime::parse_from_str(value, format).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_infer_schema() {
+        let options = CsvOptions::default();
+        assert_eq!(
+            infer_schema("1,abc", &options),
+            "STRUCT<_c0: INT, _c1: STRING>"
+        );
+        assert_eq!(
+            infer_schema("3000000000,1.5,,true,2024-01-02 03:04:05", &options),
+            "STRUCT<_c0: BIGINT, _c1: DOUBLE, _c2: STRING, _c3: BOOLEAN, _c4: TIMESTAMP>"
+        );
+    }
+}
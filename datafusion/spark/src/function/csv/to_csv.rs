@@ -0,0 +1,171 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::function::csv::utils::{coerce_options_arg, CsvOptions};
+use arrow::array::{Array, ArrayRef, AsArray, StringBuilder};
+use arrow::datatypes::DataType;
+use arrow::util::display::{ArrayFormatter, FormatOptions};
+use datafusion_common::{exec_err, plan_err, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+
+/// Spark-compatible `to_csv` expression
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#to_csv>
+///
+/// Converts a struct of atomic values into a CSV record.
+#[derive(Debug)]
+pub struct SparkToCsv {
+    signature: Signature,
+}
+
+impl Default for SparkToCsv {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkToCsv {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for SparkToCsv {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "to_csv"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let options = CsvOptions::try_from_arg(self.name(), args.args.get(1))?;
+        let is_scalar = matches!(args.args[0], ColumnarValue::Scalar(_));
+        let array = args.args[0].to_array(args.number_rows)?;
+        let result = spark_to_csv(&array, &options)?;
+        if is_scalar {
+            Ok(ColumnarValue::Scalar(ScalarValue::try_from_array(&result, 0)?))
+        } else {
+            Ok(ColumnarValue::Array(result))
+        }
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let (value, options) = match arg_types {
+            [value] => (value, None),
+            [value, options] => (value, Some(coerce_options_arg(self.name(), options)?)),
+            _ => {
+                return plan_err!(
+                    "Spark `to_csv` function requires 1 or 2 arguments, got {}",
+                    arg_types.len()
+                )
+            }
+        };
+        match value {
+            DataType::Struct(fields) => {
+                if let Some(field) = fields.iter().find(|f| f.data_type().is_nested()) {
+                    return plan_err!(
+                        "Spark `to_csv` function does not support field {} of type {}",
+                        field.name(),
+                        field.data_type()
+                    );
+                }
+            }
+            other => {
+                return plan_err!(
+                    "Spark `to_csv` function expects a struct argument, got {other}"
+                )
+            }
+        }
+        Ok(std::iter::once(value.clone()).chain(options).collect())
+    }
+}
+
+/// Converts each struct to a CSV record
+fn spark_to_csv(array: &ArrayRef, options: &CsvOptions) -> Result<ArrayRef> {
+    let Some(array) = array.as_struct_opt() else {
+        return exec_err!(
+            "Spark `to_csv` function expects a struct argument, got {}",
+            array.data_type()
+        );
+    };
+    let format_options = FormatOptions::default();
+    let formatters = array
+        .columns()
+        .iter()
+        .map(|column| ArrayFormatter::try_new(column.as_ref(), &format_options))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut builder = StringBuilder::with_capacity(array.len(), 0);
+    let mut record = String::new();
+    for row in 0..array.len() {
+        if array.is_null(row) {
+            builder.append_null();
+            continue;
+        }
+        record.clear();
+        for (i, (column, formatter)) in
+            array.columns().iter().zip(&formatters).enumerate()
+        {
+            if i > 0 {
+                record.push(options.sep);
+            }
+            let value = if column.is_null(row) {
+                None
+            } else {
+                Some(format_value(column.data_type(), formatter, row)?)
+            };
+            options.write_field(value.as_deref(), &mut record);
+        }
+        builder.append_value(&record);
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Formats a value as Spark does, writing non-finite floating point numbers
+/// as `NaN`, `Infinity` and `-Infinity`
+fn format_value(
+    data_type: &DataType,
+    formatter: &ArrayFormatter,
+    row: usize,
+) -> Result<String> {
+    let value = formatter.value(row).try_to_string()?;
+    Ok(match (data_type, value.as_str()) {
+        (DataType::Float16 | DataType::Float32 | DataType::Float64, "inf") => {
+            "Infinity".to_string()
+        }
+        (DataType::Float16 | DataType::Float32 | DataType::Float64, "-inf") => {
+            "-Infinity".to_string()
+        }
+        _ => value,
+    })
+}
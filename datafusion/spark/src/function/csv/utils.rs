@@ -0,0 +1,240 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! CSV options, parsing and formatting shared by the Spark CSV functions
+
+use arrow::array::{Array, AsArray};
+use arrow::datatypes::DataType;
+use datafusion_common::{exec_err, plan_err, Result, ScalarValue};
+use datafusion_expr::ColumnarValue;
+
+/// The options of the Spark CSV functions, given as a constant
+/// `MAP<STRING, STRING>` such as `map('sep', ';')`. Options which do not
+/// apply to a single record are ignored, as Spark does.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsvOptions {
+    /// The field separator, set with `sep` or `delimiter`
+    pub sep: char,
+    /// The quote character, `None` to disable quoting
+    pub quote: Option<char>,
+    /// The character escaping quotes within quoted values
+    pub escape: char,
+    /// The string representation of null values
+    pub null_value: String,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            sep: ',',
+            quote: Some('"'),
+            escape: '\\',
+            null_value: String::new(),
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Reads the options of the function `name` from its options argument
+    pub fn try_from_arg(name: &str, options: Option<&ColumnarValue>) -> Result<Self> {
+        let mut csv_options = Self::default();
+        let map = match options {
+            None | Some(ColumnarValue::Scalar(ScalarValue::Null)) => {
+                return Ok(csv_options)
+            }
+            Some(ColumnarValue::Scalar(ScalarValue::Map(map))) if map.len() == 1 => {
+                map.value(0)
+            }
+            _ => {
+                return exec_err!(
+                    "Spark `{name}` function requires its options to be a constant map"
+                )
+            }
+        };
+        let (keys, values) = (map.column(0).as_string::<i32>(), map.column(1));
+        let values = values.as_string::<i32>();
+        for row in 0..map.len() {
+            let value = values.is_valid(row).then(|| values.value(row));
+            match (keys.value(row), value) {
+                ("sep" | "delimiter", Some(sep)) => {
+                    csv_options.sep = parse_char(name, "sep", sep)?
+                }
+                ("quote", Some("")) => csv_options.quote = None,
+                ("quote", Some(quote)) => {
+                    csv_options.quote = Some(parse_char(name, "quote", quote)?)
+                }
+                ("escape", Some(escape)) => {
+                    csv_options.escape = parse_char(name, "escape", escape)?
+                }
+                ("nullValue", Some(null_value)) => {
+                    csv_options.null_value = null_value.to_string()
+                }
+                _ => {}
+            }
+        }
+        Ok(csv_options)
+    }
+
+    /// Splits a CSV record into its fields, with `None` for null fields: the
+    /// unquoted fields equal to `nullValue`
+    pub fn parse_record(&self, record: &str) -> Vec<Option<String>> {
+        let mut fields = vec![];
+        let mut chars = record.chars().peekable();
+        loop {
+            let mut field = String::new();
+            let quoted = self.quote.is_some() && chars.peek().copied() == self.quote;
+            if quoted {
+                chars.next();
+                while let Some(c) = chars.next() {
+                    let next = chars.peek().copied();
+                    if (c == self.escape || Some(c) == self.quote) && next == self.quote {
+                        // an escaped or doubled quote
+                        field.extend(chars.next());
+                    } else if Some(c) == self.quote {
+                        break;
+                    } else {
+                        field.push(c);
+                    }
+                }
+            }
+            // unquoted fields, and any characters after a closing quote
+            let mut at_sep = false;
+            for c in chars.by_ref() {
+                if c == self.sep {
+                    at_sep = true;
+                    break;
+                }
+                field.push(c);
+            }
+            fields.push((quoted || field != self.null_value).then_some(field));
+            if !at_sep {
+                return fields;
+            }
+        }
+    }
+
+    /// Writes a field of a CSV record, quoting it if it contains special
+    /// characters. Leading and trailing white space is trimmed, as Spark does
+    /// when writing CSV.
+    pub fn write_field(&self, value: Option<&str>, out: &mut String) {
+        let Some(value) = value else {
+            out.push_str(&self.null_value);
+            return;
+        };
+        let value = value.trim_matches(|c: char| c <= ' ');
+        let Some(quote) = self.quote else {
+            out.push_str(value);
+            return;
+        };
+        let needs_quotes = value.is_empty()
+            || value
+                .chars()
+                .any(|c| c == self.sep || c == quote || c == '\n' || c == '\r');
+        if !needs_quotes {
+            out.push_str(value);
+            return;
+        }
+        out.push(quote);
+        for c in value.chars() {
+            if c == quote {
+                out.push(self.escape);
+            }
+            out.push(c);
+        }
+        out.push(quote);
+    }
+}
+
+fn parse_char(name: &str, option: &str, value: &str) -> Result<char> {
+    let mut chars = value.chars();
+    match (chars.next(), chars.next(), chars.next()) {
+        (Some(c), None, _) => Ok(c),
+        (Some('\\'), Some('t'), None) => Ok('\t'),
+        (Some('\\'), Some('\\'), None) => Ok('\\'),
+        _ => exec_err!(
+            "Spark `{name}` function only supports a single character as {option}, got '{value}'"
+        ),
+    }
+}
+
+/// Coerces the optional options argument of the CSV functions, which must be
+/// a map of strings
+pub fn coerce_options_arg(name: &str, options: &DataType) -> Result<DataType> {
+    match options {
+        DataType::Map(entries, _) => match entries.data_type() {
+            DataType::Struct(kv)
+                if kv.len() == 2
+                    && kv[0].data_type() == &DataType::Utf8
+                    && kv[1].data_type() == &DataType::Utf8 =>
+            {
+                Ok(options.clone())
+            }
+            _ => plan_err!(
+                "Spark `{name}` function expects its options to be a map of strings, got {options}"
+            ),
+        },
+        DataType::Null => Ok(DataType::Null),
+        other => plan_err!(
+            "Spark `{name}` function expects its options to be a map of strings, got {other}"
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(values: &[Option<&str>]) -> Vec<Option<String>> {
+        values.iter().map(|v| v.map(String::from)).collect()
+    }
+
+    #[test]
+    fn test_parse_record() {
+        let options = CsvOptions::default();
+        assert_eq!(
+            options.parse_record("1,abc,,\"x,y\",\"\""),
+            fields(&[Some("1"), Some("abc"), None, Some("x,y"), Some("")])
+        );
+        assert_eq!(
+            options.parse_record(r#""a\"b","c""d""#),
+            fields(&[Some("a\"b"), Some("c\"d")])
+        );
+        assert_eq!(options.parse_record(""), fields(&[None]));
+        assert_eq!(options.parse_record("a,"), fields(&[Some("a"), None]));
+
+        let options = CsvOptions {
+            sep: ';',
+            null_value: "NA".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            options.parse_record("NA;1,5;\"NA\""),
+            fields(&[None, Some("1,5"), Some("NA")])
+        );
+    }
+
+    #[test]
+    fn test_write_field() {
+        let options = CsvOptions::default();
+        let mut out = String::new();
+        for value in [Some(" a "), None, Some("b,c"), Some("say \"hi\""), Some("")] {
+            options.write_field(value, &mut out);
+            out.push(options.sep);
+        }
+        assert_eq!(out, r#"a,,"b,c","say \"hi\"","","#);
+    }
+}
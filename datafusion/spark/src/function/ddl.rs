@@ -0,0 +1,395 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Parsing and formatting of Spark DDL type strings, such as
+//! `a INT, b ARRAY<STRING>` or `STRUCT<a: INT, b: MAP<STRING, DOUBLE>>`
+
+use std::sync::Arc;
+
+use arrow::datatypes::{DataType, Field, Fields, TimeUnit};
+use datafusion_common::{plan_datafusion_err, plan_err, Result};
+
+/// Parses a Spark DDL string into a [`DataType`]
+///
+/// The string is either a single data type, or a comma separated list of
+/// `name type` column definitions describing a struct.
+pub fn parse_ddl_schema(ddl: &str) -> Result<DataType> {
+    let mut parser = DdlParser::new(ddl);
+    if let Ok(data_type) = parser.parse_data_type() {
+        if parser.is_at_end() {
+            return Ok(data_type);
+        }
+    }
+
+    let mut parser = DdlParser::new(ddl);
+    let fields = parser.parse_fields(None)?;
+    if !parser.is_at_end() {
+        return parser.error("end of input");
+    }
+    Ok(DataType::Struct(fields))
+}
+
+/// Formats a [`DataType`] as a Spark DDL type string, such as
+/// `STRUCT<a: BIGINT, b: ARRAY<STRING>>`
+pub fn format_ddl_type(data_type: &DataType) -> String {
+    match data_type {
+        DataType::Null => "VOID".to_string(),
+        DataType::Boolean => "BOOLEAN".to_string(),
+        DataType::Int8 => "TINYINT".to_string(),
+        DataType::Int16 => "SMALLINT".to_string(),
+        DataType::Int32 => "INT".to_string(),
+        DataType::Int64 => "BIGINT".to_string(),
+        DataType::Float32 => "FLOAT".to_string(),
+        DataType::Float64 => "DOUBLE".to_string(),
+        DataType::Decimal128(p, s) | DataType::Decimal256(p, s) => {
+            format!("DECIMAL({p},{s})")
+        }
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View => "STRING".to_string(),
+        DataType::Binary | DataType::LargeBinary | DataType::BinaryView => {
+            "BINARY".to_string()
+        }
+        DataType::Date32 => "DATE".to_string(),
+        DataType::Timestamp(_, Some(_)) => "TIMESTAMP".to_string(),
+        DataType::Timestamp(_, None) => "TIMESTAMP_NTZ".to_string(),
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _) => {
+            format!("ARRAY<{}>", format_ddl_type(field.data_type()))
+        }
+        DataType::Map(entries, _) => match entries.data_type() {
+            DataType::Struct(kv) if kv.len() == 2 => format!(
+                "MAP<{}, {}>",
+                format_ddl_type(kv[0].data_type()),
+                format_ddl_type(kv[1].data_type())
+            ),
+            other => format_ddl_type(other),
+        },
+        DataType::Struct(fields) => format!(
+            "STRUCT<{}>",
+            fields
+                .iter()
+                .map(|f| format!(
+                    "{}: {}",
+                    quote_identifier(f.name()),
+                    format_ddl_type(f.data_type())
+                ))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        other => other.to_string().to_uppercase(),
+    }
+}
+
+/// Quotes a field name with backticks, unless it is a plain identifier
+fn quote_identifier(name: &str) -> String {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if is_identifier {
+        name.to_string()
+    } else {
+        format!("`{}`", name.replace('`', "``"))
+    }
+}
+
+struct DdlParser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> DdlParser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    fn error<T>(&self, expected: &str) -> Result<T> {
+        plan_err!(
+            "Invalid DDL schema '{}': expected {expected} at position {}",
+            self.input,
+            self.pos
+        )
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn is_at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.pos == self.input.len()
+    }
+
+    fn peek(&mut self) -> Option<char> {
+        self.skip_whitespace();
+        self.input[self.pos..].chars().next()
+    }
+
+    /// Consumes `c` if it is the next non whitespace character
+    fn consume(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<()> {
+        if self.consume(c) {
+            Ok(())
+        } else {
+            self.error(&format!("'{c}'"))
+        }
+    }
+
+    /// Parses a word made of alphanumeric characters and underscores
+    fn parse_word(&mut self) -> Result<&'a str> {
+        self.skip_whitespace();
+        let rest = &self.input[self.pos..];
+        let len = rest
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return self.error("an identifier");
+        }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    /// Consumes `keyword`, ignoring case, if it is the next word
+    fn consume_keyword(&mut self, keyword: &str) -> bool {
+        let pos = self.pos;
+        match self.parse_word() {
+            Ok(word) if word.eq_ignore_ascii_case(keyword) => true,
+            _ => {
+                self.pos = pos;
+                false
+            }
+        }
+    }
+
+    /// Parses a field name, optionally quoted with backticks
+    fn parse_identifier(&mut self) -> Result<String> {
+        if !self.consume('`') {
+            return self.parse_word().map(str::to_string);
+        }
+        let mut name = String::new();
+        loop {
+            let rest = &self.input[self.pos..];
+            let Some(end) = rest.find('`') else {
+                return self.error("'`'");
+            };
+            name.push_str(&rest[..end]);
+            self.pos += end + 1;
+            // a doubled backtick is an escaped backtick
+            if self.input[self.pos..].starts_with('`') {
+                name.push('`');
+                self.pos += 1;
+            } else {
+                return Ok(name);
+            }
+        }
+    }
+
+    fn parse_integer(&mut self) -> Result<u64> {
+        self.skip_whitespace();
+        let rest = &self.input[self.pos..];
+        let len = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        match rest[..len].parse() {
+            Ok(value) => {
+                self.pos += len;
+                Ok(value)
+            }
+            Err(_) => self.error("an integer"),
+        }
+    }
+
+    /// Parses a comma separated list of `name [:] type` definitions, up to
+    /// `terminator` or the end of the input
+    fn parse_fields(&mut self, terminator: Option<char>) -> Result<Fields> {
+        let mut fields = vec![];
+        if terminator.is_some() && self.peek() == terminator {
+            return Ok(fields.into());
+        }
+        loop {
+            let name = self.parse_identifier()?;
+            self.consume(':');
+            let data_type = self.parse_data_type()?;
+            let nullable = !(self.consume_keyword("NOT") && self.consume_keyword("NULL"));
+            if self.consume_keyword("COMMENT") {
+                self.parse_string_literal()?;
+            }
+            fields.push(Field::new(name, data_type, nullable));
+            if !self.consume(',') {
+                return Ok(fields.into());
+            }
+        }
+    }
+
+    fn parse_string_literal(&mut self) -> Result<String> {
+        let quote = match self.peek() {
+            Some(c @ ('\'' | '"')) => c,
+            _ => return self.error("a string literal"),
+        };
+        self.pos += 1;
+        let rest = &self.input[self.pos..];
+        let Some(end) = rest.find(quote) else {
+            return self.error(&format!("{quote}"));
+        };
+        self.pos += end + 1;
+        Ok(rest[..end].to_string())
+    }
+
+    /// Parses an optional `(n)` or `(n, m)` parameter list
+    fn parse_parameters(&mut self) -> Result<Vec<u64>> {
+        let mut params = vec![];
+        if self.consume('(') {
+            loop {
+                params.push(self.parse_integer()?);
+                if !self.consume(',') {
+                    break;
+                }
+            }
+            self.expect(')')?;
+        }
+        Ok(params)
+    }
+
+    fn parse_data_type(&mut self) -> Result<DataType> {
+        let start = self.pos;
+        let name = self.parse_word()?.to_ascii_lowercase();
+        let data_type = match name.as_str() {
+            "boolean" => DataType::Boolean,
+            "tinyint" | "byte" => DataType::Int8,
+            "smallint" | "short" => DataType::Int16,
+            "int" | "integer" => DataType::Int32,
+            "bigint" | "long" => DataType::Int64,
+            "float" | "real" => DataType::Float32,
+            "double" => DataType::Float64,
+            "decimal" | "dec" | "numeric" => match self.parse_parameters()?.as_slice() {
+                [] => DataType::Decimal128(10, 0),
+                [p] => decimal_type(*p, 0)?,
+                [p, s] => decimal_type(*p, *s)?,
+                _ => return self.error("a precision and a scale"),
+            },
+            "string" => DataType::Utf8,
+            "varchar" | "char" => {
+                self.parse_parameters()?;
+                DataType::Utf8
+            }
+            "binary" => DataType::Binary,
+            "date" => DataType::Date32,
+            "timestamp" | "timestamp_ltz" => {
+                DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+            }
+            "timestamp_ntz" => DataType::Timestamp(TimeUnit::Microsecond, None),
+            "array" => {
+                self.expect('<')?;
+                let element = self.parse_data_type()?;
+                self.expect('>')?;
+                DataType::new_list(element, true)
+            }
+            "map" => {
+                self.expect('<')?;
+                let key = self.parse_data_type()?;
+                self.expect(',')?;
+                let value = self.parse_data_type()?;
+                self.expect('>')?;
+                let entries = Fields::from(vec![
+                    Field::new("key", key, false),
+                    Field::new("value", value, true),
+                ]);
+                DataType::Map(
+                    Arc::new(Field::new("entries", DataType::Struct(entries), false)),
+                    false,
+                )
+            }
+            "struct" => {
+                self.expect('<')?;
+                let fields = self.parse_fields(Some('>'))?;
+                self.expect('>')?;
+                DataType::Struct(fields)
+            }
+            _ => {
+                self.pos = start;
+                return self.error("a data type");
+            }
+        };
+        Ok(data_type)
+    }
+}
+
+fn decimal_type(precision: u64, scale: u64) -> Result<DataType> {
+    match (u8::try_from(precision), i8::try_from(scale)) {
+        (Ok(p @ 1..=38), Ok(s)) if s as u8 <= p => Ok(DataType::Decimal128(p, s)),
+        _ => Err(plan_datafusion_err!(
+            "Invalid DDL schema: unsupported decimal precision {precision} and scale {scale}"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ddl_schema() -> Result<()> {
+        let data_type = parse_ddl_schema("a INT, `b c` ARRAY<STRING> NOT NULL")?;
+        assert_eq!(
+            data_type,
+            DataType::Struct(Fields::from(vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b c", DataType::new_list(DataType::Utf8, true), false),
+            ]))
+        );
+
+        let data_type = parse_ddl_schema("struct<a:decimal(5,2),b:map<string,bigint>>")?;
+        assert_eq!(
+            format_ddl_type(&data_type),
+            "STRUCT<a: DECIMAL(5,2), b: MAP<STRING, BIGINT>>"
+        );
+
+        assert_eq!(
+            parse_ddl_schema("array<double>")?,
+            DataType::new_list(DataType::Float64, true)
+        );
+        assert_eq!(
+            format_ddl_type(&parse_ddl_schema("`a``b` timestamp COMMENT 'x'")?),
+            "STRUCT<`a``b`: TIMESTAMP>"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_ddl_schema_invalid() {
+        for ddl in [
+            "",
+            "a",
+            "a INTEGRAL",
+            "a INT,",
+            "array<int",
+            "decimal(40, 2)",
+        ] {
+            assert!(parse_ddl_schema(ddl).is_err(), "{ddl}");
+        }
+    }
+}
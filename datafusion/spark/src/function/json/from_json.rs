@@ -0,0 +1,266 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::function::ddl::parse_ddl_schema;
+use crate::function::json::utils::JsonValue;
+use crate::function::utils::coerce_string_args;
+use arrow::array::{
+    new_empty_array, new_null_array, Array, ArrayRef, AsArray, MapArray, StructArray,
+};
+use arrow::buffer::OffsetBuffer;
+use arrow::datatypes::{DataType, Field, FieldRef, Fields};
+use datafusion_common::scalar::ScalarStructBuilder;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{internal_err, plan_err, Result, ScalarValue};
+use datafusion_expr::{
+    ColumnarValue, ReturnFieldArgs, ScalarFunctionArgs, ScalarUDFImpl, Signature,
+    Volatility,
+};
+
+/// Spark-compatible `from_json` expression
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#from_json>
+///
+/// The schema is a constant DDL string, such as `a INT, b ARRAY<STRING>`, and
+/// documents are parsed in Spark's `PERMISSIVE` mode: values which can not be
+/// converted to the type of their field are null, and invalid documents
+/// produce a struct whose fields are all null.
+#[derive(Debug)]
+pub struct SparkFromJson {
+    signature: Signature,
+}
+
+impl Default for SparkFromJson {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkFromJson {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for SparkFromJson {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "from_json"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        internal_err!("return_field_from_args should be called instead")
+    }
+
+    fn return_field_from_args(&self, args: ReturnFieldArgs) -> Result<FieldRef> {
+        let [_, schema] = take_function_args(self.name(), args.scalar_arguments)?;
+        match schema.and_then(|sv| sv.try_as_str().flatten()) {
+            Some(ddl) => Ok(Arc::new(Field::new(
+                self.name(),
+                as_nullable(parse_ddl_schema(ddl)?),
+                true,
+            ))),
+            None => plan_err!(
+                "Spark `from_json` function requires its schema to be a constant string"
+            ),
+        }
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        let [json, _] = take_function_args(self.name(), &args.args)?;
+        let data_type = args.return_field.data_type();
+        match json {
+            ColumnarValue::Scalar(json) => {
+                let json = json.try_as_str().flatten();
+                Ok(ColumnarValue::Scalar(json_to_scalar(json, data_type)?))
+            }
+            ColumnarValue::Array(json) => {
+                let json = json.as_string::<i32>();
+                if json.is_empty() {
+                    return Ok(ColumnarValue::Array(new_empty_array(data_type)));
+                }
+                let values = json
+                    .iter()
+                    .map(|json| json_to_scalar(json, data_type))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(ColumnarValue::Array(ScalarValue::iter_to_array(values)?))
+            }
+        }
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [_, _] = take_function_args(self.name(), arg_types)?;
+        coerce_string_args(self.name(), arg_types)
+    }
+}
+
+/// Makes all the fields of `data_type` nullable, as Spark does with the schema
+/// of `from_json`: any field may be missing from a document
+fn as_nullable(data_type: DataType) -> DataType {
+    let nullable_field = |field: &FieldRef| {
+        Arc::new(
+            field
+                .as_ref()
+                .clone()
+                .with_data_type(as_nullable(field.data_type().clone()))
+                .with_nullable(true),
+        )
+    };
+    match data_type {
+        DataType::Struct(fields) => {
+            DataType::Struct(fields.iter().map(nullable_field).collect())
+        }
+        DataType::List(field) => DataType::List(nullable_field(&field)),
+        DataType::Map(entries, sorted) => match entries.data_type() {
+            DataType::Struct(kv) if kv.len() == 2 => {
+                let kv = Fields::from(vec![Arc::clone(&kv[0]), nullable_field(&kv[1])]);
+                let entries = entries
+                    .as_ref()
+                    .clone()
+                    .with_data_type(DataType::Struct(kv));
+                DataType::Map(Arc::new(entries), sorted)
+            }
+            _ => DataType::Map(entries, sorted),
+        },
+        other => other,
+    }
+}
+
+/// Parses a JSON document into a value of type `data_type`
+fn json_to_scalar(json: Option<&str>, data_type: &DataType) -> Result<ScalarValue> {
+    let Some(json) = json else {
+        return ScalarValue::try_new_null(data_type);
+    };
+    match (JsonValue::parse(json), data_type) {
+        (Some(value), _) => convert_value(&value, data_type),
+        (None, DataType::Struct(fields)) => {
+            let columns = fields
+                .iter()
+                .map(|f| new_null_array(f.data_type(), 1))
+                .collect();
+            let array = StructArray::try_new(fields.clone(), columns, None)?;
+            Ok(ScalarValue::Struct(Arc::new(array)))
+        }
+        (None, _) => ScalarValue::try_new_null(data_type),
+    }
+}
+
+/// Converts a JSON value to a value of type `data_type`, returning null if the
+/// JSON value has an incompatible type
+fn convert_value(value: &JsonValue, data_type: &DataType) -> Result<ScalarValue> {
+    let converted = match (value, data_type) {
+        (JsonValue::Null, _) => None,
+        (JsonValue::String(s), DataType::Utf8) => Some(ScalarValue::from(s.as_str())),
+        (value, DataType::Utf8) => Some(ScalarValue::from(value.to_string())),
+        (JsonValue::Bool(b), DataType::Boolean) => Some(ScalarValue::from(*b)),
+        (JsonValue::Number(n), data_type) if data_type.is_numeric() => {
+            ScalarValue::try_from_string(n.clone(), data_type).ok()
+        }
+        (JsonValue::String(s), DataType::Float32 | DataType::Float64)
+            if matches!(s.as_str(), "NaN" | "Infinity" | "-Infinity") =>
+        {
+            ScalarValue::try_from_string(s.replace("Infinity", "inf"), data_type).ok()
+        }
+        (
+            JsonValue::String(s),
+            DataType::Date32 | DataType::Timestamp(_, _) | DataType::Binary,
+        ) => ScalarValue::try_from_string(s.clone(), data_type).ok(),
+        (JsonValue::Array(values), DataType::List(field)) => {
+            let values = values
+                .iter()
+                .map(|v| convert_value(v, field.data_type()))
+                .collect::<Result<Vec<_>>>()?;
+            Some(ScalarValue::List(ScalarValue::new_list_nullable(
+                &values,
+                field.data_type(),
+            )))
+        }
+        (JsonValue::Object(members), DataType::Map(entries, sorted)) => {
+            Some(convert_map(members, entries, *sorted)?)
+        }
+        (value @ JsonValue::Object(_), DataType::Struct(fields)) => {
+            let mut builder = ScalarStructBuilder::new();
+            for field in fields {
+                let field_value = match value.get(field.name()) {
+                    Some(v) => convert_value(v, field.data_type())?,
+                    None => ScalarValue::try_new_null(field.data_type())?,
+                };
+                builder = builder.with_scalar(Arc::clone(field), field_value);
+            }
+            Some(builder.build()?)
+        }
+        _ => None,
+    };
+    match converted {
+        Some(converted) => Ok(converted),
+        None => ScalarValue::try_new_null(data_type),
+    }
+}
+
+fn convert_map(
+    members: &[(String, JsonValue)],
+    entries: &FieldRef,
+    sorted: bool,
+) -> Result<ScalarValue> {
+    let DataType::Struct(entry_fields) = entries.data_type() else {
+        return internal_err!("Unexpected map entries type {}", entries.data_type());
+    };
+    let [key_field, value_field] = &entry_fields[..] else {
+        return internal_err!("Unexpected map entries type {}", entries.data_type());
+    };
+
+    let mut keys = Vec::with_capacity(members.len());
+    let mut values = Vec::with_capacity(members.len());
+    for (key, value) in members {
+        keys.push(convert_value(
+            &JsonValue::String(key.clone()),
+            key_field.data_type(),
+        )?);
+        values.push(convert_value(value, value_field.data_type())?);
+    }
+    let to_array = |scalars: Vec<ScalarValue>, field: &FieldRef| -> Result<ArrayRef> {
+        if scalars.is_empty() {
+            Ok(new_empty_array(field.data_type()))
+        } else {
+            ScalarValue::iter_to_array(scalars)
+        }
+    };
+    let entries_array = StructArray::try_new(
+        entry_fields.clone(),
+        vec![to_array(keys, key_field)?, to_array(values, value_field)?],
+        None,
+    )?;
+    let map = MapArray::try_new(
+        Arc::clone(entries),
+        OffsetBuffer::from_lengths([members.len()]),
+        entries_array,
+        None,
+        sorted,
+    )?;
+    Ok(ScalarValue::Map(Arc::new(map)))
+}
@@ -0,0 +1,210 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::function::json::utils::JsonValue;
+use crate::function::utils::coerce_string_args;
+use arrow::array::{ArrayRef, AsArray, StringArray};
+use arrow::datatypes::DataType;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::Result;
+use datafusion_expr::{
+    ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_functions::utils::make_scalar_function;
+
+/// Spark-compatible `get_json_object` expression
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#get_json_object>
+#[derive(Debug)]
+pub struct SparkGetJsonObject {
+    signature: Signature,
+}
+
+impl Default for SparkGetJsonObject {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkGetJsonObject {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for SparkGetJsonObject {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "get_json_object"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(spark_get_json_object, vec![])(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [_, _] = take_function_args(self.name(), arg_types)?;
+        coerce_string_args(self.name(), arg_types)
+    }
+}
+
+/// Extracts the value at a JSONPath from a JSON document, returning null if
+/// the document or the path are invalid, or if nothing matches the path.
+pub fn spark_get_json_object(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [json, path] = take_function_args("get_json_object", args)?;
+    let json = json.as_string::<i32>();
+    let path = path.as_string::<i32>();
+
+    let result = json
+        .iter()
+        .zip(path.iter())
+        .map(|(json, path)| {
+            let path = parse_json_path(path?)?;
+            let value = JsonValue::parse(json?)?;
+            evaluate_json_path(&value, &path)
+        })
+        .collect::<StringArray>();
+    Ok(Arc::new(result))
+}
+
+/// A step of a JSONPath
+#[derive(Debug, Clone, PartialEq)]
+enum PathSegment {
+    /// `.name` or `['name']`
+    Key(String),
+    /// `[n]`
+    Index(usize),
+    /// `[*]` or `.*`
+    Wildcard,
+}
+
+/// Parses the JSONPath subset supported by Spark, returning `None` if the
+/// path is invalid
+fn parse_json_path(path: &str) -> Option<Vec<PathSegment>> {
+    let mut rest = path.strip_prefix('$')?;
+    let mut segments = vec![];
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix('.') {
+            if let Some(after) = after.strip_prefix('*') {
+                segments.push(PathSegment::Wildcard);
+                rest = after;
+                continue;
+            }
+            let end = after.find(['.', '[']).unwrap_or(after.len());
+            if end == 0 {
+                return None;
+            }
+            segments.push(PathSegment::Key(after[..end].to_string()));
+            rest = &after[end..];
+        } else if let Some(after) = rest.strip_prefix('[') {
+            let end = after.find(']')?;
+            let subscript = after[..end].trim();
+            let segment = if subscript == "*" {
+                PathSegment::Wildcard
+            } else if let Some(key) = subscript
+                .strip_prefix('\'')
+                .and_then(|s| s.strip_suffix('\''))
+            {
+                PathSegment::Key(key.to_string())
+            } else {
+                PathSegment::Index(subscript.parse().ok()?)
+            };
+            segments.push(segment);
+            rest = &after[end + 1..];
+        } else {
+            return None;
+        }
+    }
+    Some(segments)
+}
+
+fn evaluate_json_path(value: &JsonValue, path: &[PathSegment]) -> Option<String> {
+    let mut matches = vec![value];
+    for segment in path {
+        matches = matches
+            .into_iter()
+            .flat_map(|value| -> Vec<&JsonValue> {
+                match (segment, value) {
+                    (PathSegment::Key(key), _) => value.get(key).into_iter().collect(),
+                    (PathSegment::Index(index), JsonValue::Array(values)) => {
+                        values.get(*index).into_iter().collect()
+                    }
+                    (PathSegment::Wildcard, JsonValue::Array(values)) => {
+                        values.iter().collect()
+                    }
+                    (PathSegment::Wildcard, JsonValue::Object(members)) => {
+                        members.iter().map(|(_, v)| v).collect()
+                    }
+                    _ => vec![],
+                }
+            })
+            .collect();
+    }
+
+    // Paths with wildcards return all their matches as a JSON array, unless
+    // there is a single one
+    match matches.as_slice() {
+        [] => None,
+        [value] => value.to_unquoted_string(),
+        values => Some(
+            JsonValue::Array(values.iter().map(|v| (*v).clone()).collect()).to_string(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get(json: &str, path: &str) -> Option<String> {
+        let path = parse_json_path(path)?;
+        evaluate_json_path(&JsonValue::parse(json)?, &path)
+    }
+
+    #[test]
+    fn test_get_json_object() {
+        let json =
+            r#"{"a":"b","c":{"d":[1,{"e":true}]},"f.g":null,"h":[{"i":1},{"i":2}]}"#;
+        assert_eq!(get(json, "$.a").as_deref(), Some("b"));
+        assert_eq!(get(json, "$.c").as_deref(), Some(r#"{"d":[1,{"e":true}]}"#));
+        assert_eq!(get(json, "$.c.d[1].e").as_deref(), Some("true"));
+        assert_eq!(get(json, "$['c']['d'][0]").as_deref(), Some("1"));
+        assert_eq!(get(json, "$['f.g']"), None);
+        assert_eq!(get(json, "$.h[*].i").as_deref(), Some("[1,2]"));
+        assert_eq!(get(json, "$.h[1].i").as_deref(), Some("2"));
+        assert_eq!(get(json, "$.x"), None);
+        assert_eq!(get(json, "$.c.d[5]"), None);
+        assert_eq!(get(json, "a"), None);
+        assert_eq!(get(json, "$."), None);
+        assert_eq!(get(json, "$[a]"), None);
+    }
+}
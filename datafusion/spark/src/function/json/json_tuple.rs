@@ -0,0 +1,144 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::function::error_utils::invalid_arg_count_exec_err;
+use crate::function::json::utils::JsonValue;
+use crate::function::utils::coerce_string_args;
+use arrow::array::{Array, ArrayRef, AsArray, StringBuilder, StructArray};
+use arrow::datatypes::{DataType, Field, Fields};
+use datafusion_common::{plan_err, Result};
+use datafusion_expr::{
+    ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_functions::utils::make_scalar_function;
+
+/// Spark-compatible `json_tuple` expression
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#json_tuple>
+///
+/// Spark returns one column per requested field. As a scalar function can only
+/// return a single column, the fields are returned as a struct with a `Utf8`
+/// field `c0`, `c1`, ... per requested field.
+#[derive(Debug)]
+pub struct SparkJsonTuple {
+    signature: Signature,
+}
+
+impl Default for SparkJsonTuple {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkJsonTuple {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for SparkJsonTuple {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "json_tuple"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Struct(json_tuple_fields(
+            arg_types.len().saturating_sub(1),
+        )))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(spark_json_tuple, vec![])(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if arg_types.len() < 2 {
+            return plan_err!(
+                "Spark `json_tuple` function requires at least 2 arguments, got {}",
+                arg_types.len()
+            );
+        }
+        coerce_string_args(self.name(), arg_types)
+    }
+}
+
+fn json_tuple_fields(num_fields: usize) -> Fields {
+    (0..num_fields)
+        .map(|i| Field::new(format!("c{i}"), DataType::Utf8, true))
+        .collect()
+}
+
+/// Extracts the top-level fields named by the second and following arguments
+/// from a JSON object. The fields are null if the document is null or not a
+/// valid JSON object.
+pub fn spark_json_tuple(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let Some((json, keys)) = args.split_first().filter(|(_, keys)| !keys.is_empty())
+    else {
+        return Err(invalid_arg_count_exec_err(
+            "json_tuple",
+            (2, i32::MAX),
+            args.len(),
+        ));
+    };
+    let json = json.as_string::<i32>();
+    let keys = keys
+        .iter()
+        .map(|k| k.as_string::<i32>())
+        .collect::<Vec<_>>();
+
+    let mut builders = keys
+        .iter()
+        .map(|_| StringBuilder::with_capacity(json.len(), 0))
+        .collect::<Vec<_>>();
+    for row in 0..json.len() {
+        let value = json
+            .is_valid(row)
+            .then(|| JsonValue::parse(json.value(row)))
+            .flatten();
+        for (builder, key) in builders.iter_mut().zip(&keys) {
+            let field = match &value {
+                Some(value) if key.is_valid(row) => value
+                    .get(key.value(row))
+                    .and_then(JsonValue::to_unquoted_string),
+                _ => None,
+            };
+            builder.append_option(field);
+        }
+    }
+
+    let columns = builders
+        .into_iter()
+        .map(|mut b| Arc::new(b.finish()) as ArrayRef)
+        .collect();
+    Ok(Arc::new(StructArray::try_new(
+        json_tuple_fields(keys.len()),
+        columns,
+        None,
+    )?))
+}
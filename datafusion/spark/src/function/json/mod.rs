@@ -15,11 +15,59 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub mod from_json;
+pub mod get_json_object;
+pub mod json_tuple;
+pub mod schema_of_json;
+pub mod to_json;
+mod utils;
+
 use datafusion_expr::ScalarUDF;
+use datafusion_functions::make_udf_function;
 use std::sync::Arc;
 
-pub mod expr_fn {}
+make_udf_function!(from_json::SparkFromJson, from_json);
+make_udf_function!(get_json_object::SparkGetJsonObject, get_json_object);
+make_udf_function!(json_tuple::SparkJsonTuple, json_tuple);
+make_udf_function!(schema_of_json::SparkSchemaOfJson, schema_of_json);
+make_udf_function!(to_json::SparkToJson, to_json);
+
+pub mod expr_fn {
+    use datafusion_functions::export_functions;
+
+    export_functions!((
+        from_json,
+        "Parses a JSON string into a struct, array or map described by a DDL schema string.",
+        json schema
+    ));
+    export_functions!((
+        get_json_object,
+        "Extracts a JSON object from a JSON string based on the specified JSON path.",
+        json path
+    ));
+    export_functions!((
+        json_tuple,
+        "Returns a struct of the values of the given top-level fields of a JSON object.",
+        args,
+    ));
+    export_functions!((
+        schema_of_json,
+        "Returns the schema of a JSON string in DDL format.",
+        json
+    ));
+    export_functions!((
+        to_json,
+        "Converts a struct, array or map into a JSON string.",
+        arg1
+    ));
+}
 
 pub fn functions() -> Vec<Arc<ScalarUDF>> {
-    vec![]
+    vec![
+        from_json(),
+        get_json_object(),
+        json_tuple(),
+        schema_of_json(),
+        to_json(),
+    ]
 }
@@ -0,0 +1,192 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::function::ddl::format_ddl_type;
+use crate::function::json::utils::JsonValue;
+use crate::function::utils::coerce_string_args;
+use arrow::array::{ArrayRef, AsArray, StringArray};
+use arrow::datatypes::{DataType, Field, Fields};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::Result;
+use datafusion_expr::{
+    ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_functions::utils::make_scalar_function;
+
+/// Spark-compatible `schema_of_json` expression
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#schema_of_json>
+#[derive(Debug)]
+pub struct SparkSchemaOfJson {
+    signature: Signature,
+}
+
+impl Default for SparkSchemaOfJson {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkSchemaOfJson {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for SparkSchemaOfJson {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "schema_of_json"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(spark_schema_of_json, vec![])(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [_] = take_function_args(self.name(), arg_types)?;
+        coerce_string_args(self.name(), arg_types)
+    }
+}
+
+/// Infers the schema of a JSON document, formatted as a DDL string. Returns
+/// null for null and invalid documents.
+pub fn spark_schema_of_json(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [json] = take_function_args("schema_of_json", args)?;
+    let result = json
+        .as_string::<i32>()
+        .iter()
+        .map(|json| {
+            let value = JsonValue::parse(json?)?;
+            Some(format_ddl_type(&canonicalize(infer_type(&value))))
+        })
+        .collect::<StringArray>();
+    Ok(Arc::new(result))
+}
+
+/// Infers the type of a JSON value the way Spark does, with integers as
+/// `BIGINT`, other numbers as `DOUBLE` and the fields of objects sorted by name
+fn infer_type(value: &JsonValue) -> DataType {
+    match value {
+        JsonValue::Null => DataType::Null,
+        JsonValue::Bool(_) => DataType::Boolean,
+        JsonValue::Number(n) if n.contains(['.', 'e', 'E']) => DataType::Float64,
+        JsonValue::Number(n) if n.parse::<i64>().is_ok() => DataType::Int64,
+        JsonValue::Number(n) => {
+            let digits = n.trim_start_matches('-').len();
+            match u8::try_from(digits) {
+                Ok(precision @ 1..=38) => DataType::Decimal128(precision, 0),
+                _ => DataType::Float64,
+            }
+        }
+        JsonValue::String(_) => DataType::Utf8,
+        JsonValue::Array(values) => {
+            let element = values
+                .iter()
+                .map(infer_type)
+                .reduce(merge_types)
+                .unwrap_or(DataType::Null);
+            DataType::new_list(element, true)
+        }
+        JsonValue::Object(members) => {
+            let mut fields = vec![];
+            for (name, value) in members {
+                add_field(&mut fields, name, infer_type(value));
+            }
+            struct_type(fields)
+        }
+    }
+}
+
+/// Adds a field to the fields of an inferred struct, merging its type with
+/// the one of an existing field of the same name
+fn add_field(fields: &mut Vec<(String, DataType)>, name: &str, data_type: DataType) {
+    match fields.iter_mut().find(|(n, _)| n == name) {
+        Some((_, existing)) => *existing = merge_types(existing.clone(), data_type),
+        None => fields.push((name.to_string(), data_type)),
+    }
+}
+
+fn struct_type(mut fields: Vec<(String, DataType)>) -> DataType {
+    fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+    DataType::Struct(
+        fields
+            .into_iter()
+            .map(|(name, data_type)| Field::new(name, data_type, true))
+            .collect(),
+    )
+}
+
+/// Returns the type of the values of two inferred types, such as the elements
+/// of an array
+fn merge_types(left: DataType, right: DataType) -> DataType {
+    match (left, right) {
+        (left, right) if left == right => left,
+        (DataType::Null, other) | (other, DataType::Null) => other,
+        (
+            DataType::Int64 | DataType::Float64 | DataType::Decimal128(_, _),
+            DataType::Int64 | DataType::Float64 | DataType::Decimal128(_, _),
+        ) => DataType::Float64,
+        (DataType::List(left), DataType::List(right)) => DataType::new_list(
+            merge_types(left.data_type().clone(), right.data_type().clone()),
+            true,
+        ),
+        (DataType::Struct(left), DataType::Struct(right)) => {
+            let mut fields = left
+                .iter()
+                .map(|f| (f.name().clone(), f.data_type().clone()))
+                .collect::<Vec<_>>();
+            for field in right.iter() {
+                add_field(&mut fields, field.name(), field.data_type().clone());
+            }
+            struct_type(fields)
+        }
+        _ => DataType::Utf8,
+    }
+}
+
+/// Replaces the types of values which were always null with `STRING`
+fn canonicalize(data_type: DataType) -> DataType {
+    match data_type {
+        DataType::Null => DataType::Utf8,
+        DataType::List(field) => {
+            DataType::new_list(canonicalize(field.data_type().clone()), true)
+        }
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|f| Field::new(f.name(), canonicalize(f.data_type().clone()), true))
+                .collect::<Fields>(),
+        ),
+        other => other,
+    }
+}
@@ -0,0 +1,273 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::fmt::Write;
+use std::sync::Arc;
+
+use crate::function::json::utils::write_json_string;
+use arrow::array::{Array, ArrayRef, AsArray, StringBuilder};
+use arrow::datatypes::DataType;
+use arrow::util::display::{ArrayFormatter, FormatOptions};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{plan_err, Result};
+use datafusion_expr::{
+    ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_functions::utils::make_scalar_function;
+
+/// Spark-compatible `to_json` expression
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#to_json>
+#[derive(Debug)]
+pub struct SparkToJson {
+    signature: Signature,
+}
+
+impl Default for SparkToJson {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkToJson {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for SparkToJson {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "to_json"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(spark_to_json, vec![])(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [arg] = take_function_args(self.name(), arg_types)?;
+        match arg {
+            DataType::Struct(_)
+            | DataType::List(_)
+            | DataType::LargeList(_)
+            | DataType::FixedSizeList(_, _)
+            | DataType::Map(_, _)
+            | DataType::Null => Ok(vec![arg.clone()]),
+            other => plan_err!(
+                "Spark `to_json` function expects a struct, array or map argument, got {other}"
+            ),
+        }
+    }
+}
+
+/// Converts structs, arrays and maps to JSON strings. Null fields of structs
+/// are omitted.
+pub fn spark_to_json(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [array] = take_function_args("to_json", args)?;
+    let options = FormatOptions::default();
+    let encoder = JsonEncoder::try_new(array.as_ref(), &options)?;
+
+    let mut builder = StringBuilder::with_capacity(array.len(), 0);
+    let mut buffer = String::new();
+    for row in 0..array.len() {
+        if encoder.is_null(row) {
+            builder.append_null();
+        } else {
+            buffer.clear();
+            encoder.encode(row, &mut buffer)?;
+            builder.append_value(&buffer);
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Writes the values of an array as JSON
+struct JsonEncoder<'a> {
+    array: &'a dyn Array,
+    kind: EncoderKind<'a>,
+}
+
+enum EncoderKind<'a> {
+    Null,
+    /// Booleans and numbers, written as they are displayed
+    Literal(ArrayFormatter<'a>),
+    /// Floating point numbers, whose non-finite values are written as strings
+    Float(ArrayFormatter<'a>),
+    /// Other values, written as the JSON string of how they are displayed
+    String(ArrayFormatter<'a>),
+    List {
+        offsets: Vec<usize>,
+        values: Box<JsonEncoder<'a>>,
+    },
+    Map {
+        offsets: Vec<usize>,
+        keys: ArrayFormatter<'a>,
+        values: Box<JsonEncoder<'a>>,
+    },
+    Struct(Vec<(&'a str, JsonEncoder<'a>)>),
+}
+
+impl<'a> JsonEncoder<'a> {
+    fn try_new(array: &'a dyn Array, options: &FormatOptions<'a>) -> Result<Self> {
+        let kind = match array.data_type() {
+            DataType::Null => EncoderKind::Null,
+            DataType::Boolean
+            | DataType::Int8
+            | DataType::Int16
+            | DataType::Int32
+            | DataType::Int64
+            | DataType::UInt8
+            | DataType::UInt16
+            | DataType::UInt32
+            | DataType::UInt64
+            | DataType::Decimal128(_, _)
+            | DataType::Decimal256(_, _) => {
+                EncoderKind::Literal(ArrayFormatter::try_new(array, options)?)
+            }
+            DataType::Float16 | DataType::Float32 | DataType::Float64 => {
+                EncoderKind::Float(ArrayFormatter::try_new(array, options)?)
+            }
+            DataType::List(_) => {
+                let list = array.as_list::<i32>();
+                EncoderKind::List {
+                    offsets: list.value_offsets().iter().map(|o| *o as usize).collect(),
+                    values: Box::new(Self::try_new(list.values().as_ref(), options)?),
+                }
+            }
+            DataType::LargeList(_) => {
+                let list = array.as_list::<i64>();
+                EncoderKind::List {
+                    offsets: list.value_offsets().iter().map(|o| *o as usize).collect(),
+                    values: Box::new(Self::try_new(list.values().as_ref(), options)?),
+                }
+            }
+            DataType::FixedSizeList(_, size) => {
+                let list = array.as_fixed_size_list();
+                EncoderKind::List {
+                    offsets: (0..=list.len()).map(|i| i * *size as usize).collect(),
+                    values: Box::new(Self::try_new(list.values().as_ref(), options)?),
+                }
+            }
+            DataType::Map(_, _) => {
+                let map = array.as_map();
+                EncoderKind::Map {
+                    offsets: map.value_offsets().iter().map(|o| *o as usize).collect(),
+                    keys: ArrayFormatter::try_new(map.keys().as_ref(), options)?,
+                    values: Box::new(Self::try_new(map.values().as_ref(), options)?),
+                }
+            }
+            DataType::Struct(fields) => {
+                let array = array.as_struct();
+                EncoderKind::Struct(
+                    fields
+                        .iter()
+                        .zip(array.columns())
+                        .map(|(field, column)| {
+                            Ok((
+                                field.name().as_str(),
+                                Self::try_new(column.as_ref(), options)?,
+                            ))
+                        })
+                        .collect::<Result<_>>()?,
+                )
+            }
+            _ => EncoderKind::String(ArrayFormatter::try_new(array, options)?),
+        };
+        Ok(Self { array, kind })
+    }
+
+    fn is_null(&self, row: usize) -> bool {
+        matches!(self.kind, EncoderKind::Null) || self.array.is_null(row)
+    }
+
+    fn encode(&self, row: usize, out: &mut String) -> Result<()> {
+        if self.is_null(row) {
+            out.push_str("null");
+            return Ok(());
+        }
+        match &self.kind {
+            EncoderKind::Null => out.push_str("null"),
+            EncoderKind::Literal(formatter) => write!(out, "{}", formatter.value(row))?,
+            EncoderKind::Float(formatter) => {
+                match formatter.value(row).try_to_string()? {
+                    s if s == "NaN" => out.push_str("\"NaN\""),
+                    s if s == "inf" => out.push_str("\"Infinity\""),
+                    s if s == "-inf" => out.push_str("\"-Infinity\""),
+                    s => out.push_str(&s),
+                }
+            }
+            EncoderKind::String(formatter) => {
+                write_json_string(out, &formatter.value(row).try_to_string()?)?
+            }
+            EncoderKind::List { offsets, values } => {
+                out.push('[');
+                for (i, index) in (offsets[row]..offsets[row + 1]).enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    values.encode(index, out)?;
+                }
+                out.push(']');
+            }
+            EncoderKind::Map {
+                offsets,
+                keys,
+                values,
+            } => {
+                out.push('{');
+                for (i, index) in (offsets[row]..offsets[row + 1]).enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    write_json_string(out, &keys.value(index).try_to_string()?)?;
+                    out.push(':');
+                    values.encode(index, out)?;
+                }
+                out.push('}');
+            }
+            EncoderKind::Struct(fields) => {
+                out.push('{');
+                let mut first = true;
+                for (name, field) in fields.iter().filter(|(_, f)| !f.is_null(row)) {
+                    if !first {
+                        out.push(',');
+                    }
+                    first = false;
+                    write_json_string(out, name)?;
+                    out.push(':');
+                    field.encode(row, out)?;
+                }
+                out.push('}');
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,358 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! JSON document model shared by the Spark JSON functions
+//!
+//! Spark preserves the order of the members of JSON objects and the literal
+//! text of numbers when returning parts of a document, which is why documents
+//! are parsed into [`JsonValue`] rather than a general purpose JSON library.
+
+use std::fmt::{self, Write};
+
+/// Maximum nesting depth of the JSON documents, deeper documents are
+/// considered invalid
+const MAX_DEPTH: usize = 1000;
+
+/// A parsed JSON value
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonValue {
+    Null,
+    Bool(bool),
+    /// The literal text of the number in the document
+    Number(String),
+    String(String),
+    Array(Vec<JsonValue>),
+    /// The members of the object, in document order
+    Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+    /// Parses a JSON document, returning `None` if it is not valid JSON
+    pub fn parse(input: &str) -> Option<Self> {
+        let mut parser = Parser {
+            bytes: input.as_bytes(),
+            pos: 0,
+        };
+        let value = parser.parse_value(0)?;
+        parser.skip_whitespace();
+        (parser.pos == parser.bytes.len()).then_some(value)
+    }
+
+    /// Returns the value of the first member named `key`, if this is an object
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        match self {
+            JsonValue::Object(members) => {
+                members.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the value as extracted by `get_json_object` and `json_tuple`:
+    /// strings without their quotes, `None` for nulls and the JSON text of
+    /// other values.
+    pub fn to_unquoted_string(&self) -> Option<String> {
+        match self {
+            JsonValue::Null => None,
+            JsonValue::String(s) => Some(s.clone()),
+            other => Some(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonValue::Null => f.write_str("null"),
+            JsonValue::Bool(b) => write!(f, "{b}"),
+            JsonValue::Number(n) => f.write_str(n),
+            JsonValue::String(s) => write_json_string(f, s),
+            JsonValue::Array(values) => {
+                f.write_char('[')?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_char(']')
+            }
+            JsonValue::Object(members) => {
+                f.write_char('{')?;
+                for (i, (key, value)) in members.iter().enumerate() {
+                    if i > 0 {
+                        f.write_char(',')?;
+                    }
+                    write_json_string(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                f.write_char('}')
+            }
+        }
+    }
+}
+
+/// Writes `s` as a quoted and escaped JSON string
+pub fn write_json_string(out: &mut impl Write, s: &str) -> fmt::Result {
+    out.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => out.write_str("\\\"")?,
+            '\\' => out.write_str("\\\\")?,
+            '\n' => out.write_str("\\n")?,
+            '\r' => out.write_str("\\r")?,
+            '\t' => out.write_str("\\t")?,
+            '\u{08}' => out.write_str("\\b")?,
+            '\u{0c}' => out.write_str("\\f")?,
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32)?,
+            c => out.write_char(c)?,
+        }
+    }
+    out.write_char('"')
+}
+
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn expect(&mut self, b: u8) -> Option<()> {
+        (self.peek()? == b).then(|| self.pos += 1)
+    }
+
+    fn expect_literal(&mut self, literal: &[u8]) -> Option<()> {
+        self.bytes[self.pos..]
+            .starts_with(literal)
+            .then(|| self.pos += literal.len())
+    }
+
+    fn parse_value(&mut self, depth: usize) -> Option<JsonValue> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        self.skip_whitespace();
+        match self.peek()? {
+            b'n' => self.expect_literal(b"null").map(|_| JsonValue::Null),
+            b't' => self.expect_literal(b"true").map(|_| JsonValue::Bool(true)),
+            b'f' => self
+                .expect_literal(b"false")
+                .map(|_| JsonValue::Bool(false)),
+            b'"' => self.parse_string().map(JsonValue::String),
+            b'[' => self.parse_array(depth),
+            b'{' => self.parse_object(depth),
+            b'-' | b'0'..=b'9' => self.parse_number(),
+            _ => None,
+        }
+    }
+
+    fn parse_array(&mut self, depth: usize) -> Option<JsonValue> {
+        self.expect(b'[')?;
+        let mut values = vec![];
+        self.skip_whitespace();
+        if self.expect(b']').is_some() {
+            return Some(JsonValue::Array(values));
+        }
+        loop {
+            values.push(self.parse_value(depth + 1)?);
+            self.skip_whitespace();
+            match self.peek()? {
+                b',' => self.pos += 1,
+                b']' => {
+                    self.pos += 1;
+                    return Some(JsonValue::Array(values));
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    fn parse_object(&mut self, depth: usize) -> Option<JsonValue> {
+        self.expect(b'{')?;
+        let mut members = vec![];
+        self.skip_whitespace();
+        if self.expect(b'}').is_some() {
+            return Some(JsonValue::Object(members));
+        }
+        loop {
+            self.skip_whitespace();
+            let key = self.parse_string()?;
+            self.skip_whitespace();
+            self.expect(b':')?;
+            members.push((key, self.parse_value(depth + 1)?));
+            self.skip_whitespace();
+            match self.peek()? {
+                b',' => self.pos += 1,
+                b'}' => {
+                    self.pos += 1;
+                    return Some(JsonValue::Object(members));
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    fn parse_number(&mut self) -> Option<JsonValue> {
+        let start = self.pos;
+        let _ = self.expect(b'-');
+        match self.peek()? {
+            b'0' => self.pos += 1,
+            b'1'..=b'9' => self.skip_digits(),
+            _ => return None,
+        }
+        if self.expect(b'.').is_some() {
+            self.require_digits()?;
+        }
+        if matches!(self.peek(), Some(b'e' | b'E')) {
+            self.pos += 1;
+            if matches!(self.peek(), Some(b'+' | b'-')) {
+                self.pos += 1;
+            }
+            self.require_digits()?;
+        }
+        // the number only contains ASCII characters
+        let text = std::str::from_utf8(&self.bytes[start..self.pos]).ok()?;
+        Some(JsonValue::Number(text.to_string()))
+    }
+
+    fn skip_digits(&mut self) {
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.pos += 1;
+        }
+    }
+
+    fn require_digits(&mut self) -> Option<()> {
+        let start = self.pos;
+        self.skip_digits();
+        (self.pos > start).then_some(())
+    }
+
+    fn parse_string(&mut self) -> Option<String> {
+        self.expect(b'"')?;
+        let mut out = Vec::new();
+        loop {
+            match self.peek()? {
+                b'"' => {
+                    self.pos += 1;
+                    // the input is valid UTF-8 and escapes are decoded as such
+                    return String::from_utf8(out).ok();
+                }
+                b'\\' => {
+                    self.pos += 1;
+                    let escaped = match self.peek()? {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{08}',
+                        b'f' => '\u{0c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            self.pos += 1;
+                            let c = self.parse_unicode_escape()?;
+                            let mut buf = [0; 4];
+                            out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                            continue;
+                        }
+                        _ => return None,
+                    };
+                    self.pos += 1;
+                    out.push(escaped as u8);
+                }
+                b => {
+                    self.pos += 1;
+                    out.push(b);
+                }
+            }
+        }
+    }
+
+    /// Parses the hex digits of a `\u` escape, including a following low
+    /// surrogate escape if the first one is a high surrogate
+    fn parse_unicode_escape(&mut self) -> Option<char> {
+        let high = self.parse_hex4()?;
+        if !(0xD800..0xDC00).contains(&high) {
+            return char::from_u32(high);
+        }
+        self.expect_literal(b"\\u")?;
+        let low = self.parse_hex4()?;
+        if !(0xDC00..0xE000).contains(&low) {
+            return None;
+        }
+        char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+    }
+
+    fn parse_hex4(&mut self) -> Option<u32> {
+        let digits = self.bytes.get(self.pos..self.pos + 4)?;
+        let digits = std::str::from_utf8(digits).ok()?;
+        let value = u32::from_str_radix(digits, 16).ok()?;
+        self.pos += 4;
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_preserves_order_and_numbers() {
+        let value =
+            JsonValue::parse(r#" {"b": 1.50, "a": [true, null, -2e3]} "#).unwrap();
+        assert_eq!(value.to_string(), r#"{"b":1.50,"a":[true,null,-2e3]}"#);
+        assert_eq!(value.get("b"), Some(&JsonValue::Number("1.50".to_string())));
+        assert_eq!(value.get("c"), None);
+    }
+
+    #[test]
+    fn test_parse_strings() {
+        let value = JsonValue::parse(r#""a\"b\\c\n\u00e9\ud83d\ude00""#).unwrap();
+        assert_eq!(value, JsonValue::String("a\"b\\c\né😀".to_string()));
+        assert_eq!(value.to_string(), "\"a\\\"b\\\\c\\né😀\"");
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for input in [
+            "",
+            "{",
+            "[1,]",
+            "{\"a\" 1}",
+            "01",
+            "1.",
+            "-",
+            "tru",
+            "\"abc",
+            "{} {}",
+            "{a: 1}",
+            "\"\\x\"",
+        ] {
+            assert_eq!(JsonValue::parse(input), None, "{input}");
+        }
+    }
+}
@@ -23,6 +23,7 @@ pub mod conditional;
 pub mod conversion;
 pub mod csv;
 pub mod datetime;
+pub mod ddl;
 pub mod error_utils;
 pub mod generator;
 pub mod hash;
@@ -15,11 +15,38 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub mod parse_url;
+pub mod url_decode;
+pub mod url_encode;
+
 use datafusion_expr::ScalarUDF;
+use datafusion_functions::make_udf_function;
 use std::sync::Arc;
 
-pub mod expr_fn {}
+make_udf_function!(parse_url::SparkParseUrl, parse_url);
+make_udf_function!(url_decode::SparkUrlDecode, url_decode);
+make_udf_function!(url_encode::SparkUrlEncode, url_encode);
+
+pub mod expr_fn {
+    use datafusion_functions::export_functions;
+
+    export_functions!((
+        parse_url,
+        "Extracts a part of a URL, or the value of a query parameter.",
+        args,
+    ));
+    export_functions!((
+        url_decode,
+        "Decodes a string in the application/x-www-form-urlencoded format.",
+        arg1
+    ));
+    export_functions!((
+        url_encode,
+        "Encodes a string in the application/x-www-form-urlencoded format.",
+        arg1
+    ));
+}
 
 pub fn functions() -> Vec<Arc<ScalarUDF>> {
-    vec![]
+    vec![parse_url(), url_decode(), url_encode()]
 }
@@ -0,0 +1,323 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::function::utils::coerce_string_args;
+use arrow::array::{ArrayRef, AsArray, StringArray};
+use arrow::datatypes::DataType;
+use datafusion_common::{plan_err, Result};
+use datafusion_expr::{
+    ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_functions::utils::make_scalar_function;
+
+/// Spark-compatible `parse_url` expression
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#parse_url>
+///
+/// Extracts a part of a URL: `HOST`, `PATH`, `QUERY`, `REF`, `PROTOCOL`,
+/// `FILE`, `AUTHORITY` or `USERINFO`, or the value of a query parameter if a
+/// key is given along with `QUERY`. Invalid URLs and unknown parts are null.
+#[derive(Debug)]
+pub struct SparkParseUrl {
+    signature: Signature,
+}
+
+impl Default for SparkParseUrl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkParseUrl {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for SparkParseUrl {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "parse_url"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(spark_parse_url, vec![])(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        if !(2..=3).contains(&arg_types.len()) {
+            return plan_err!(
+                "Spark `parse_url` function requires 2 or 3 arguments, got {}",
+                arg_types.len()
+            );
+        }
+        coerce_string_args(self.name(), arg_types)
+    }
+}
+
+/// Extracts the part of each URL, or the value of the query parameter `key`
+pub fn spark_parse_url(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let url = args[0].as_string::<i32>();
+    let part = args[1].as_string::<i32>();
+    let key = args.get(2).map(|key| key.as_string::<i32>());
+
+    let result = (0..url.len())
+        .map(|row| {
+            let url = url.is_valid(row).then(|| url.value(row))?;
+            let part = part.is_valid(row).then(|| part.value(row))?;
+            let uri = Uri::parse(url)?;
+            match key {
+                None => uri.part(part),
+                Some(key) if part == "QUERY" => {
+                    let key = key.is_valid(row).then(|| key.value(row))?;
+                    query_parameter(uri.query?, key)
+                }
+                Some(_) => None,
+            }
+        })
+        .collect::<StringArray>();
+    Ok(Arc::new(result))
+}
+
+/// Returns the value of the first parameter named `key` in `query`
+fn query_parameter<'a>(query: &'a str, key: &str) -> Option<&'a str> {
+    query.split('&').find_map(|param| {
+        param
+            .strip_prefix(key)
+            .and_then(|rest| rest.strip_prefix('='))
+    })
+}
+
+/// The raw components of a URI, as parsed by Java's `java.net.URI` which
+/// Spark uses to parse URLs
+#[derive(Debug, Default, PartialEq)]
+struct Uri<'a> {
+    scheme: Option<&'a str>,
+    authority: Option<&'a str>,
+    user_info: Option<&'a str>,
+    host: Option<&'a str>,
+    path: Option<&'a str>,
+    /// The path followed by the query, if any
+    file: Option<&'a str>,
+    query: Option<&'a str>,
+    fragment: Option<&'a str>,
+}
+
+impl<'a> Uri<'a> {
+    /// Parses a URI reference, returning `None` if it is not a valid URI
+    fn parse(input: &'a str) -> Option<Self> {
+        if !is_valid_uri_text(input) {
+            return None;
+        }
+        let (rest, fragment) = match input.split_once('#') {
+            Some((rest, fragment)) if !fragment.contains('#') => (rest, Some(fragment)),
+            Some(_) => return None,
+            None => (input, None),
+        };
+        let mut uri = Uri {
+            fragment,
+            ..Default::default()
+        };
+
+        let rest = match rest.split_once(':') {
+            Some((scheme, ssp)) if is_scheme(scheme) => {
+                if ssp.is_empty() {
+                    return None;
+                }
+                uri.scheme = Some(scheme);
+                if !ssp.starts_with('/') {
+                    // an opaque URI, such as `mailto:user@example.com`
+                    return Some(uri);
+                }
+                ssp
+            }
+            _ => rest,
+        };
+
+        let file = match rest.strip_prefix("//") {
+            Some(rest) => {
+                let end = rest.find(['/', '?']).unwrap_or(rest.len());
+                let (authority, file) = rest.split_at(end);
+                if !authority.is_empty() {
+                    uri.authority = Some(authority);
+                    uri.parse_server_authority(authority);
+                } else if !file.starts_with('/') {
+                    // `//` must be followed by an authority or an absolute path
+                    return None;
+                }
+                file
+            }
+            None => rest,
+        };
+        uri.file = Some(file);
+        match file.split_once('?') {
+            Some((path, query)) => {
+                uri.path = Some(path);
+                uri.query = Some(query);
+            }
+            None => uri.path = Some(file),
+        }
+        Some(uri)
+    }
+
+    /// Sets the user information and host of a server-based authority, which
+    /// are left unset if the authority is registry-based
+    fn parse_server_authority(&mut self, authority: &'a str) {
+        let (user_info, host_port) = match authority.rsplit_once('@') {
+            Some((user_info, host_port)) => (Some(user_info), host_port),
+            None => (None, authority),
+        };
+        let (host, port) = if host_port.starts_with('[') {
+            match host_port.find(']') {
+                Some(end) => (&host_port[..=end], &host_port[end + 1..]),
+                None => return,
+            }
+        } else {
+            match host_port.rsplit_once(':') {
+                Some((host, port)) => (host, &host_port[host.len()..]),
+                None => (host_port, ""),
+            }
+        };
+        let valid_port = port.is_empty()
+            || port
+                .strip_prefix(':')
+                .is_some_and(|p| p.bytes().all(|b| b.is_ascii_digit()));
+        if valid_port && is_host(host) {
+            self.user_info = user_info;
+            self.host = Some(host);
+        }
+    }
+
+    /// Returns the part of the URI extracted by `parse_url`
+    fn part(&self, part: &str) -> Option<&'a str> {
+        match part {
+            "HOST" => self.host,
+            "PATH" => self.path,
+            "QUERY" => self.query,
+            "REF" => self.fragment,
+            "PROTOCOL" => self.scheme,
+            "FILE" => self.file,
+            "AUTHORITY" => self.authority,
+            "USERINFO" => self.user_info,
+            _ => None,
+        }
+    }
+}
+
+/// Returns true if `input` only contains characters allowed in URIs, and if
+/// all its `%` characters start escaped octets
+fn is_valid_uri_text(input: &str) -> bool {
+    let bytes = input.as_bytes();
+    bytes.iter().enumerate().all(|(i, b)| match b {
+        b'%' => {
+            bytes.get(i + 1).is_some_and(u8::is_ascii_hexdigit)
+                && bytes.get(i + 2).is_some_and(u8::is_ascii_hexdigit)
+        }
+        b' ' | b'"' | b'<' | b'>' | b'\\' | b'^' | b'`' | b'{' | b'|' | b'}' => false,
+        b => !b.is_ascii_control(),
+    })
+}
+
+fn is_scheme(scheme: &str) -> bool {
+    let mut bytes = scheme.bytes();
+    bytes.next().is_some_and(|b| b.is_ascii_alphabetic())
+        && bytes.all(|b| b.is_ascii_alphanumeric() || matches!(b, b'+' | b'-' | b'.'))
+}
+
+/// Returns true if `host` is a host name, an IPv4 address or a bracketed IPv6
+/// address
+fn is_host(host: &str) -> bool {
+    if let Some(address) = host.strip_prefix('[').and_then(|h| h.strip_suffix(']')) {
+        return address.parse::<std::net::Ipv6Addr>().is_ok();
+    }
+    if host.parse::<std::net::Ipv4Addr>().is_ok() {
+        return true;
+    }
+    let host = host.strip_suffix('.').unwrap_or(host);
+    let is_label = |label: &str| {
+        !label.is_empty()
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+    };
+    // the top label of a host name starts with a letter
+    host.split('.').all(is_label)
+        && host
+            .rsplit('.')
+            .next()
+            .is_some_and(|top| top.starts_with(|c: char| c.is_ascii_alphabetic()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_uri() {
+        let uri = Uri::parse("http://user:pw@spark.apache.org:80/a/b?x=1&y=2#frag").unwrap();
+        assert_eq!(uri.part("PROTOCOL"), Some("http"));
+        assert_eq!(uri.part("AUTHORITY"), Some("user:pw@spark.apache.org:80"));
+        assert_eq!(uri.part("USERINFO"), Some("user:pw"));
+        assert_eq!(uri.part("HOST"), Some("spark.apache.org"));
+        assert_eq!(uri.part("PATH"), Some("/a/b"));
+        assert_eq!(uri.part("QUERY"), Some("x=1&y=2"));
+        assert_eq!(uri.part("FILE"), Some("/a/b?x=1&y=2"));
+        assert_eq!(uri.part("REF"), Some("frag"));
+        assert_eq!(uri.part("host"), None);
+        assert_eq!(query_parameter("x=1&y=2", "y"), Some("2"));
+        assert_eq!(query_parameter("x=1&xy=2", "y"), None);
+    }
+
+    #[test]
+    fn test_parse_uri_special_cases() {
+        let uri = Uri::parse("mailto:user@example.com").unwrap();
+        assert_eq!(uri.part("PROTOCOL"), Some("mailto"));
+        assert_eq!(uri.part("PATH"), None);
+
+        let uri = Uri::parse("file:///tmp/a.txt").unwrap();
+        assert_eq!(uri.part("HOST"), None);
+        assert_eq!(uri.part("PATH"), Some("/tmp/a.txt"));
+
+        let uri = Uri::parse("http://[::1]:8080").unwrap();
+        assert_eq!(uri.part("HOST"), Some("[::1]"));
+        assert_eq!(uri.part("PATH"), Some(""));
+
+        // registry-based authorities have no host
+        let uri = Uri::parse("http://a_b.com/").unwrap();
+        assert_eq!(uri.part("AUTHORITY"), Some("a_b.com"));
+        assert_eq!(uri.part("HOST"), None);
+
+        for invalid in ["http://a b.com", "http:", "http://", "a%2", "a#b#c"] {
+            assert_eq!(Uri::parse(invalid), None, "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::fmt::Write;
+use std::sync::Arc;
+
+use crate::function::utils::coerce_string_args;
+use arrow::array::{ArrayRef, AsArray, StringArray};
+use arrow::datatypes::DataType;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::Result;
+use datafusion_expr::{
+    ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_functions::utils::make_scalar_function;
+
+/// Spark-compatible `url_encode` expression
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#url_encode>
+///
+/// Encodes a string in the `application/x-www-form-urlencoded` format, like
+/// Java's `URLEncoder`.
+#[derive(Debug)]
+pub struct SparkUrlEncode {
+    signature: Signature,
+}
+
+impl Default for SparkUrlEncode {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkUrlEncode {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for SparkUrlEncode {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "url_encode"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Utf8)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(spark_url_encode, vec![])(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [_] = take_function_args(self.name(), arg_types)?;
+        coerce_string_args(self.name(), arg_types)
+    }
+}
+
+/// Percent-encodes the UTF-8 bytes of each string, except for alphanumeric
+/// characters and `.`, `-`, `*` and `_`, and encodes spaces as `+`
+pub fn spark_url_encode(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let [strings] = take_function_args("url_encode", args)?;
+    let result = strings
+        .as_string::<i32>()
+        .iter()
+        .map(|s| s.map(url_encode))
+        .collect::<StringArray>();
+    Ok(Arc::new(result))
+}
+
+fn url_encode(s: &str) -> String {
+    let mut encoded = String::with_capacity(s.len());
+    for b in s.bytes() {
+        match b {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'.' | b'-' | b'*' | b'_' => {
+                encoded.push(b as char)
+            }
+            b' ' => encoded.push('+'),
+            // writing to a String does not fail
+            b => write!(encoded, "%{b:02X}").unwrap(),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_url_encode() {
+        assert_eq!(
+            url_encode("https://spark.apache.org"),
+            "https%3A%2F%2Fspark.apache.org"
+        );
+        assert_eq!(url_encode("a b*c~é"), "a+b*c%7E%C3%A9");
+        assert_eq!(url_encode(""), "");
+    }
+}
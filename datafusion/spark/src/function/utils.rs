@@ -15,6 +15,50 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::sync::Arc;
+
+use arrow::array::RecordBatch;
+use arrow::datatypes::{DataType, Schema};
+use datafusion_common::{exec_err, plan_err, Result, ScalarValue};
+use datafusion_expr::ColumnarValue;
+use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
+
+/// Returns the value of the constant argument `index` of the aggregate or
+/// window function `name`, or `None` if there is no such argument
+pub fn get_literal_arg(
+    name: &str,
+    exprs: &[Arc<dyn PhysicalExpr>],
+    index: usize,
+) -> Result<Option<ScalarValue>> {
+    let Some(expr) = exprs.get(index) else {
+        return Ok(None);
+    };
+    let batch = RecordBatch::new_empty(Arc::new(Schema::empty()));
+    match expr.evaluate(&batch) {
+        Ok(ColumnarValue::Scalar(value)) => Ok(Some(value)),
+        _ => exec_err!(
+            "Spark `{name}` function expects argument {} to be a constant, got {expr}",
+            index + 1
+        ),
+    }
+}
+
+/// Coerces the string arguments of the function `name` to `Utf8`
+pub fn coerce_string_args(name: &str, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+    arg_types
+        .iter()
+        .map(|data_type| match data_type {
+            DataType::Utf8
+            | DataType::LargeUtf8
+            | DataType::Utf8View
+            | DataType::Null => Ok(DataType::Utf8),
+            other => {
+                plan_err!("Spark `{name}` function expects string arguments, got {other}")
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 pub mod test {
     /// $FUNC ScalarUDFImpl to test
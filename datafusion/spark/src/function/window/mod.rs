@@ -15,11 +15,39 @@
 // specific language governing permissions and limitations
 // under the License.
 
+pub mod nth_value;
+
 use datafusion_expr::WindowUDF;
 use std::sync::Arc;
 
-pub mod expr_fn {}
+/// Creates a singleton `WindowUDF` of the `$UDWF` function and a function
+/// named `$NAME` which returns that singleton.
+macro_rules! make_udwf_function {
+    ($UDWF:ty, $NAME:ident) => {
+        #[doc = concat!("Return a [`WindowUDF`](datafusion_expr::WindowUDF) implementation of ", stringify!($NAME))]
+        pub fn $NAME() -> Arc<WindowUDF> {
+            // Singleton instance of the function
+            static INSTANCE: std::sync::LazyLock<Arc<WindowUDF>> =
+                std::sync::LazyLock::new(|| {
+                    Arc::new(WindowUDF::new_from_impl(<$UDWF>::new()))
+                });
+            Arc::clone(&INSTANCE)
+        }
+    };
+}
+
+make_udwf_function!(nth_value::SparkNthValue, nth_value);
+
+pub mod expr_fn {
+    use datafusion_functions::export_functions;
+
+    export_functions!((
+        nth_value,
+        "Returns the value at the given 1-based offset of the window frame.",
+        args,
+    ));
+}
 
 pub fn functions() -> Vec<Arc<WindowUDF>> {
-    vec![]
+    vec![nth_value()]
 }
@@ -0,0 +1,163 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::ops::Range;
+
+use crate::function::utils::get_literal_arg;
+use arrow::array::{Array, ArrayRef};
+use arrow::datatypes::{DataType, Field, FieldRef};
+use datafusion_common::{exec_err, plan_err, Result, ScalarValue};
+use datafusion_expr::function::{PartitionEvaluatorArgs, WindowUDFFieldArgs};
+use datafusion_expr::{PartitionEvaluator, Signature, Volatility, WindowUDFImpl};
+
+/// Spark-compatible `nth_value` window function
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#nth_value>
+///
+/// Returns the value at the `offset`-th row of the window frame, counting
+/// from 1, skipping nulls if the optional third argument is true or
+/// `IGNORE NULLS` is specified. Unlike DataFusion's `nth_value`, the offset
+/// must be positive.
+#[derive(Debug)]
+pub struct SparkNthValue {
+    signature: Signature,
+}
+
+impl Default for SparkNthValue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkNthValue {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl WindowUDFImpl for SparkNthValue {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "nth_value"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        match arg_types {
+            [value, offset] if offset.is_integer() || offset.is_null() => {
+                Ok(vec![value.clone(), DataType::Int64])
+            }
+            [value, offset, DataType::Boolean | DataType::Null]
+                if offset.is_integer() || offset.is_null() =>
+            {
+                Ok(vec![value.clone(), DataType::Int64, DataType::Boolean])
+            }
+            [_, _] | [_, _, _] => plan_err!(
+                "Spark `nth_value` function expects an integer offset and a boolean ignoreNulls argument, got {}",
+                arg_types[1..]
+                    .iter()
+                    .map(|t| t.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            _ => plan_err!(
+                "Spark `nth_value` function requires 2 or 3 arguments, got {}",
+                arg_types.len()
+            ),
+        }
+    }
+
+    fn partition_evaluator(
+        &self,
+        args: PartitionEvaluatorArgs,
+    ) -> Result<Box<dyn PartitionEvaluator>> {
+        let offset = match get_literal_arg(self.name(), args.input_exprs(), 1)? {
+            Some(ScalarValue::Int64(Some(offset))) if offset > 0 => offset as usize,
+            Some(ScalarValue::Int64(Some(offset))) => {
+                return exec_err!(
+                    "Spark `nth_value` function requires a positive offset, got {offset}"
+                )
+            }
+            _ => {
+                return exec_err!("Spark `nth_value` function requires a non-null offset")
+            }
+        };
+        let ignore_nulls = match get_literal_arg(self.name(), args.input_exprs(), 2)? {
+            None => false,
+            Some(ScalarValue::Boolean(Some(ignore_nulls))) => ignore_nulls,
+            Some(other) => {
+                return exec_err!(
+                    "Spark `nth_value` function expects a non-null boolean ignoreNulls argument, got {other}"
+                )
+            }
+        };
+        Ok(Box::new(NthValueEvaluator {
+            offset,
+            ignore_nulls: ignore_nulls || args.ignore_nulls(),
+        }))
+    }
+
+    fn field(&self, field_args: WindowUDFFieldArgs) -> Result<FieldRef> {
+        let data_type = field_args
+            .input_fields()
+            .first()
+            .map(|f| f.data_type().clone())
+            .unwrap_or(DataType::Null);
+        Ok(Field::new(field_args.name(), data_type, true).into())
+    }
+}
+
+#[derive(Debug)]
+struct NthValueEvaluator {
+    /// The position of the value in the window frame, starting at 1
+    offset: usize,
+    ignore_nulls: bool,
+}
+
+impl PartitionEvaluator for NthValueEvaluator {
+    fn uses_window_frame(&self) -> bool {
+        true
+    }
+
+    fn evaluate(
+        &mut self,
+        values: &[ArrayRef],
+        range: &Range<usize>,
+    ) -> Result<ScalarValue> {
+        let array = &values[0];
+        let row = if self.ignore_nulls {
+            range
+                .clone()
+                .filter(|row| array.is_valid(*row))
+                .nth(self.offset - 1)
+        } else {
+            range.clone().nth(self.offset - 1)
+        };
+        match row {
+            Some(row) => ScalarValue::try_from_array(array, row),
+            None => ScalarValue::try_from(array.data_type()),
+        }
+    }
+}
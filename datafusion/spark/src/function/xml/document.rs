@@ -0,0 +1,415 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A minimal XML document model for the Spark XPath functions
+
+/// The index of a node in a [`Document`]. Nodes are stored in document order,
+/// with the attributes of an element between the element and its children.
+pub type NodeId = usize;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum NodeKind {
+    Root,
+    Element { name: String },
+    Attribute { name: String, value: String },
+    Text(String),
+    Comment(String),
+    ProcessingInstruction { target: String, data: String },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    pub kind: NodeKind,
+    pub parent: Option<NodeId>,
+    pub attributes: Vec<NodeId>,
+    pub children: Vec<NodeId>,
+}
+
+/// A parsed XML document, whose root node is [`Document::ROOT`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Document {
+    nodes: Vec<Node>,
+}
+
+impl Document {
+    pub const ROOT: NodeId = 0;
+
+    /// Parses an XML document, returning `None` if it is not well-formed
+    pub fn parse(input: &str) -> Option<Self> {
+        let mut parser = Parser {
+            input,
+            pos: 0,
+            document: Document {
+                nodes: vec![Node {
+                    kind: NodeKind::Root,
+                    parent: None,
+                    attributes: vec![],
+                    children: vec![],
+                }],
+            },
+        };
+        parser.parse_document()?;
+        Some(parser.document)
+    }
+
+    pub fn node(&self, id: NodeId) -> &Node {
+        &self.nodes[id]
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the XPath string value of a node: the concatenated text of
+    /// the descendants of root and element nodes, and the value of others
+    pub fn string_value(&self, id: NodeId) -> String {
+        match &self.nodes[id].kind {
+            NodeKind::Root | NodeKind::Element { .. } => {
+                let mut value = String::new();
+                self.push_text(id, &mut value);
+                value
+            }
+            NodeKind::Attribute { value, .. } => value.clone(),
+            NodeKind::Text(text) | NodeKind::Comment(text) => text.clone(),
+            NodeKind::ProcessingInstruction { data, .. } => data.clone(),
+        }
+    }
+
+    fn push_text(&self, id: NodeId, out: &mut String) {
+        for child in &self.nodes[id].children {
+            match &self.nodes[*child].kind {
+                NodeKind::Text(text) => out.push_str(text),
+                NodeKind::Element { .. } => self.push_text(*child, out),
+                _ => {}
+            }
+        }
+    }
+
+    /// Returns the value of a node as Java's `Node.getNodeValue`, which is
+    /// `None` for root and element nodes
+    pub fn node_value(&self, id: NodeId) -> Option<String> {
+        match &self.nodes[id].kind {
+            NodeKind::Root | NodeKind::Element { .. } => None,
+            _ => Some(self.string_value(id)),
+        }
+    }
+
+    /// Returns the qualified name of element, attribute and processing
+    /// instruction nodes, and an empty string for other nodes
+    pub fn name(&self, id: NodeId) -> &str {
+        match &self.nodes[id].kind {
+            NodeKind::Element { name } | NodeKind::Attribute { name, .. } => name,
+            NodeKind::ProcessingInstruction { target, .. } => target,
+            _ => "",
+        }
+    }
+
+    fn add_node(&mut self, kind: NodeKind, parent: NodeId) -> NodeId {
+        let id = self.nodes.len();
+        let is_attribute = matches!(kind, NodeKind::Attribute { .. });
+        self.nodes.push(Node {
+            kind,
+            parent: Some(parent),
+            attributes: vec![],
+            children: vec![],
+        });
+        if is_attribute {
+            self.nodes[parent].attributes.push(id);
+        } else {
+            self.nodes[parent].children.push(id);
+        }
+        id
+    }
+
+    /// Adds text to `parent`, merging it with a preceding text node
+    fn add_text(&mut self, text: String, parent: NodeId) {
+        if text.is_empty() {
+            return;
+        }
+        if let Some(&last) = self.nodes[parent].children.last() {
+            if let NodeKind::Text(existing) = &mut self.nodes[last].kind {
+                existing.push_str(&text);
+                return;
+            }
+        }
+        self.add_node(NodeKind::Text(text), parent);
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    document: Document,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn eat(&mut self, s: &str) -> bool {
+        if self.rest().starts_with(s) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start_matches(is_xml_whitespace).len();
+    }
+
+    /// Returns the text up to `end`, and moves past `end`
+    fn take_until(&mut self, end: &str) -> Option<&'a str> {
+        let start = self.pos;
+        let len = self.rest().find(end)?;
+        self.pos += len + end.len();
+        Some(&self.input[start..start + len])
+    }
+
+    fn parse_name(&mut self) -> Option<String> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | ':' | '-' | '.')))
+            .unwrap_or(rest.len());
+        let name = &rest[..len];
+        let first = name.chars().next()?;
+        if first.is_numeric() || matches!(first, '-' | '.') {
+            return None;
+        }
+        self.pos += len;
+        Some(name.to_string())
+    }
+
+    fn parse_document(&mut self) -> Option<()> {
+        self.eat("\u{feff}");
+        if self.rest().starts_with("<?xml") {
+            self.take_until("?>")?;
+        }
+        let mut seen_root = false;
+        loop {
+            self.skip_whitespace();
+            if self.rest().is_empty() {
+                return seen_root.then_some(());
+            } else if self.eat("<!--") {
+                let comment = self.take_until("-->")?.to_string();
+                self.document
+                    .add_node(NodeKind::Comment(comment), Document::ROOT);
+            } else if self.eat("<!DOCTYPE") {
+                self.skip_doctype()?;
+            } else if self.rest().starts_with("<?") {
+                self.parse_processing_instruction(Document::ROOT)?;
+            } else if !seen_root && self.rest().starts_with('<') {
+                self.parse_element(Document::ROOT, 0)?;
+                seen_root = true;
+            } else {
+                return None;
+            }
+        }
+    }
+
+    fn skip_doctype(&mut self) -> Option<()> {
+        // an internal subset, between brackets, may contain `>`
+        let mut in_subset = false;
+        for (i, c) in self.rest().char_indices() {
+            match c {
+                '[' => in_subset = true,
+                ']' => in_subset = false,
+                '>' if !in_subset => {
+                    self.pos += i + 1;
+                    return Some(());
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    fn parse_processing_instruction(&mut self, parent: NodeId) -> Option<()> {
+        self.eat("<?");
+        let target = self.parse_name()?;
+        let data = self
+            .take_until("?>")?
+            .trim_start_matches(is_xml_whitespace)
+            .to_string();
+        self.document
+            .add_node(NodeKind::ProcessingInstruction { target, data }, parent);
+        Some(())
+    }
+
+    fn parse_element(&mut self, parent: NodeId, depth: usize) -> Option<()> {
+        if depth > MAX_DEPTH || !self.eat("<") {
+            return None;
+        }
+        let name = self.parse_name()?;
+        let element = self
+            .document
+            .add_node(NodeKind::Element { name: name.clone() }, parent);
+        loop {
+            let before = self.pos;
+            self.skip_whitespace();
+            if self.eat("/>") {
+                return Some(());
+            }
+            if self.eat(">") {
+                break;
+            }
+            if self.pos == before {
+                // attributes are separated by white space
+                return None;
+            }
+            let attribute = self.parse_name()?;
+            self.skip_whitespace();
+            if !self.eat("=") {
+                return None;
+            }
+            self.skip_whitespace();
+            let quote = self
+                .rest()
+                .chars()
+                .next()
+                .filter(|c| matches!(c, '"' | '\''))?;
+            self.pos += 1;
+            let raw = self.take_until(&quote.to_string())?;
+            if raw.contains('<') {
+                return None;
+            }
+            let value = decode_entities(raw)?;
+            let attributes = &self.document.nodes[element].attributes;
+            if attributes
+                .iter()
+                .any(|a| self.document.name(*a) == attribute)
+            {
+                return None;
+            }
+            self.document.add_node(
+                NodeKind::Attribute {
+                    name: attribute,
+                    value,
+                },
+                element,
+            );
+        }
+
+        loop {
+            let text_len = self.rest().find('<')?;
+            let text = &self.rest()[..text_len];
+            let text = decode_entities(text)?;
+            self.pos += text_len;
+            self.document.add_text(text, element);
+
+            if self.eat("</") {
+                let end_name = self.parse_name()?;
+                self.skip_whitespace();
+                return (end_name == name && self.eat(">")).then_some(());
+            } else if self.eat("<!--") {
+                let comment = self.take_until("-->")?.to_string();
+                self.document.add_node(NodeKind::Comment(comment), element);
+            } else if self.eat("<![CDATA[") {
+                let text = self.take_until("]]>")?.to_string();
+                self.document.add_text(text, element);
+            } else if self.rest().starts_with("<?") {
+                self.parse_processing_instruction(element)?;
+            } else {
+                self.parse_element(element, depth + 1)?;
+            }
+        }
+    }
+}
+
+/// Maximum nesting depth of the elements of XML documents, deeper documents
+/// are considered invalid
+const MAX_DEPTH: usize = 1000;
+
+pub fn is_xml_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r')
+}
+
+/// Replaces the predefined entities and character references of `text`,
+/// returning `None` if it contains an invalid reference
+fn decode_entities(text: &str) -> Option<String> {
+    if !text.contains('&') {
+        return Some(text.to_string());
+    }
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        let end = rest[start..].find(';')? + start;
+        let entity = &rest[start + 1..end];
+        let c = match entity {
+            "lt" => '<',
+            "gt" => '>',
+            "amp" => '&',
+            "quot" => '"',
+            "apos" => '\'',
+            _ => {
+                let code = if let Some(hex) = entity.strip_prefix("#x") {
+                    u32::from_str_radix(hex, 16).ok()?
+                } else {
+                    entity.strip_prefix('#')?.parse().ok()?
+                };
+                char::from_u32(code)?
+            }
+        };
+        decoded.push(c);
+        rest = &rest[end + 1..];
+    }
+    decoded.push_str(rest);
+    Some(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_document() {
+        let document = Document::parse(
+            "<?xml version=\"1.0\"?><!-- c --><a x='1' y=\"&lt;2&gt;\">t<b/>&amp;<![CDATA[<u>]]></a>",
+        )
+        .unwrap();
+        let root = document.node(Document::ROOT);
+        assert_eq!(root.children.len(), 2);
+        let a = root.children[1];
+        assert_eq!(document.name(a), "a");
+        let attributes = &document.node(a).attributes;
+        assert_eq!(document.string_value(attributes[1]), "<2>");
+        assert_eq!(document.string_value(a), "t&<u>");
+        assert_eq!(document.node_value(a), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        for input in [
+            "",
+            "text",
+            "<a>",
+            "<a></b>",
+            "<a><b></a></b>",
+            "<a/><b/>",
+            "<a x='1' x='2'/>",
+            "<a x=1/>",
+            "<a>&unknown;</a>",
+            "<a/>text",
+        ] {
+            assert_eq!(Document::parse(input), None, "{input}");
+        }
+    }
+}
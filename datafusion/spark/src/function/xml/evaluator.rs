@@ -0,0 +1,1361 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! An XPath 1.0 evaluator for the Spark XPath functions
+//!
+//! Variables, namespaces and the `id` and `lang` functions are not
+//! supported.
+
+use crate::function::xml::document::{is_xml_whitespace, Document, NodeId, NodeKind};
+use arrow::array::{ArrayRef, AsArray};
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{exec_err, DataFusionError, Result};
+
+/// Evaluates the XPath expressions of the second argument on the XML
+/// documents of the first, calling `f` with the document and value of each
+/// row, or with `None` if either argument is null or empty
+pub fn evaluate_rows(
+    name: &str,
+    args: &[ArrayRef],
+    mut f: impl FnMut(Option<(&Document, Value)>) -> Result<()>,
+) -> Result<()> {
+    let [xmls, paths] = take_function_args(name, args)?;
+    let (xmls, paths) = (xmls.as_string::<i32>(), paths.as_string::<i32>());
+    // the path of the previous row, which is usually a literal
+    let mut compiled: Option<(&str, XPath)> = None;
+    for (xml, path) in xmls.iter().zip(paths.iter()) {
+        let (Some(xml), Some(path)) = (xml, path) else {
+            f(None)?;
+            continue;
+        };
+        if xml.is_empty() || path.is_empty() {
+            f(None)?;
+            continue;
+        }
+        let xpath = match compiled.take() {
+            Some((previous, xpath)) if previous == path => xpath,
+            _ => XPath::parse(path)?,
+        };
+        let Some(document) = Document::parse(xml) else {
+            return exec_err!("Spark `{name}` function cannot parse invalid XML '{xml}'");
+        };
+        let value = xpath.evaluate(&document)?;
+        f(Some((&document, value)))?;
+        compiled = Some((path, xpath));
+    }
+    Ok(())
+}
+
+/// The value of an XPath expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// Nodes in document order, without duplicates
+    NodeSet(Vec<NodeId>),
+    Boolean(bool),
+    Number(f64),
+    String(String),
+}
+
+impl Value {
+    pub fn to_boolean(&self) -> bool {
+        match self {
+            Value::NodeSet(nodes) => !nodes.is_empty(),
+            Value::Boolean(b) => *b,
+            Value::Number(n) => *n != 0.0 && !n.is_nan(),
+            Value::String(s) => !s.is_empty(),
+        }
+    }
+
+    pub fn to_number(&self, document: &Document) -> f64 {
+        match self {
+            Value::Boolean(b) => f64::from(u8::from(*b)),
+            Value::Number(n) => *n,
+            other => string_to_number(&other.string_value(document)),
+        }
+    }
+
+    /// Returns the XPath string value: the string value of the first node of
+    /// node sets and the canonical text of booleans and numbers
+    pub fn string_value(&self, document: &Document) -> String {
+        match self {
+            Value::NodeSet(nodes) => nodes
+                .first()
+                .map(|node| document.string_value(*node))
+                .unwrap_or_default(),
+            Value::Boolean(b) => b.to_string(),
+            Value::Number(n) => number_to_string(*n),
+            Value::String(s) => s.clone(),
+        }
+    }
+}
+
+/// A compiled XPath expression
+#[derive(Debug, Clone, PartialEq)]
+pub struct XPath {
+    expr: Expr,
+}
+
+impl XPath {
+    pub fn parse(path: &str) -> Result<Self> {
+        let invalid = |message: String| {
+            DataFusionError::Execution(format!("Invalid XPath '{path}': {message}"))
+        };
+        let tokens = tokenize(path).map_err(invalid)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.parse_expr().map_err(invalid)?;
+        match parser.peek() {
+            None => Ok(Self { expr }),
+            Some(token) => Err(invalid(format!("unexpected {token:?}"))),
+        }
+    }
+
+    /// Evaluates the expression with the root of `document` as context node
+    pub fn evaluate(&self, document: &Document) -> Result<Value> {
+        let context = Context {
+            document,
+            node: Document::ROOT,
+            position: 1,
+            size: 1,
+        };
+        context.evaluate(&self.expr)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Slash,
+    DoubleSlash,
+    LeftBracket,
+    RightBracket,
+    LeftParen,
+    RightParen,
+    At,
+    Comma,
+    Pipe,
+    Dot,
+    DoubleDot,
+    DoubleColon,
+    Plus,
+    Minus,
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    /// `*` as a name test
+    Star,
+    Multiply,
+    And,
+    Or,
+    Div,
+    Mod,
+    /// A qualified name, or a `prefix:*` name test
+    Name(String),
+    Number(f64),
+    Literal(String),
+}
+
+impl Token {
+    /// Returns true if a `*` or a name following this token is a name test
+    /// rather than an operator
+    fn precedes_name_test(&self) -> bool {
+        matches!(
+            self,
+            Token::At
+                | Token::DoubleColon
+                | Token::LeftParen
+                | Token::LeftBracket
+                | Token::Comma
+                | Token::Slash
+                | Token::DoubleSlash
+                | Token::Pipe
+                | Token::Plus
+                | Token::Minus
+                | Token::Equal
+                | Token::NotEqual
+                | Token::Less
+                | Token::LessEqual
+                | Token::Greater
+                | Token::GreaterEqual
+                | Token::Multiply
+                | Token::And
+                | Token::Or
+                | Token::Div
+                | Token::Mod
+        )
+    }
+}
+
+fn tokenize(path: &str) -> std::result::Result<Vec<Token>, String> {
+    let mut tokens: Vec<Token> = vec![];
+    let chars = path.chars().collect::<Vec<_>>();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let name_test = tokens.last().is_none_or(Token::precedes_name_test);
+        let (token, len) = match (c, next) {
+            (c, _) if is_xml_whitespace(c) => {
+                i += 1;
+                continue;
+            }
+            ('/', Some('/')) => (Token::DoubleSlash, 2),
+            ('/', _) => (Token::Slash, 1),
+            ('[', _) => (Token::LeftBracket, 1),
+            (']', _) => (Token::RightBracket, 1),
+            ('(', _) => (Token::LeftParen, 1),
+            (')', _) => (Token::RightParen, 1),
+            ('@', _) => (Token::At, 1),
+            (',', _) => (Token::Comma, 1),
+            ('|', _) => (Token::Pipe, 1),
+            ('.', Some('.')) => (Token::DoubleDot, 2),
+            ('.', next) if !next.is_some_and(|c| c.is_ascii_digit()) => (Token::Dot, 1),
+            (':', Some(':')) => (Token::DoubleColon, 2),
+            ('+', _) => (Token::Plus, 1),
+            ('-', _) => (Token::Minus, 1),
+            ('=', _) => (Token::Equal, 1),
+            ('!', Some('=')) => (Token::NotEqual, 2),
+            ('<', Some('=')) => (Token::LessEqual, 2),
+            ('<', _) => (Token::Less, 1),
+            ('>', Some('=')) => (Token::GreaterEqual, 2),
+            ('>', _) => (Token::Greater, 1),
+            ('*', _) if name_test => (Token::Star, 1),
+            ('*', _) => (Token::Multiply, 1),
+            ('"' | '\'', _) => {
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|q| *q == c)
+                    .ok_or("unterminated string literal")?;
+                let literal = chars[i + 1..i + 1 + end].iter().collect();
+                (Token::Literal(literal), end + 2)
+            }
+            (c, _) if c.is_ascii_digit() || c == '.' => {
+                let len = chars[i..]
+                    .iter()
+                    .position(|c| !(c.is_ascii_digit() || *c == '.'))
+                    .unwrap_or(chars.len() - i);
+                let number = chars[i..i + len].iter().collect::<String>();
+                let number = number
+                    .parse()
+                    .map_err(|_| format!("invalid number {number}"))?;
+                (Token::Number(number), len)
+            }
+            (c, _) if c.is_alphabetic() || c == '_' => {
+                let is_name_char =
+                    |c: &char| c.is_alphanumeric() || matches!(c, '_' | '-' | '.');
+                let mut len = chars[i..]
+                    .iter()
+                    .position(|c| !is_name_char(c))
+                    .unwrap_or(chars.len() - i);
+                // a prefixed name or `prefix:*`, but not an axis name
+                if chars.get(i + len) == Some(&':')
+                    && chars.get(i + len + 1) != Some(&':')
+                {
+                    match chars.get(i + len + 1) {
+                        Some('*') => len += 2,
+                        Some(c) if c.is_alphabetic() || *c == '_' => {
+                            len += 1 + chars[i + len + 1..]
+                                .iter()
+                                .position(|c| !is_name_char(c))
+                                .unwrap_or(chars.len() - i - len - 1);
+                        }
+                        _ => return Err("invalid qualified name".to_string()),
+                    }
+                }
+                let name = chars[i..i + len].iter().collect::<String>();
+                let token = match name.as_str() {
+                    "and" if !name_test => Token::And,
+                    "or" if !name_test => Token::Or,
+                    "div" if !name_test => Token::Div,
+                    "mod" if !name_test => Token::Mod,
+                    _ => Token::Name(name),
+                };
+                (token, len)
+            }
+            (c, _) => return Err(format!("unexpected character '{c}'")),
+        };
+        tokens.push(token);
+        i += len;
+    }
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CompareOp {
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+impl CompareOp {
+    /// Returns the operator comparing the operands in the other order
+    fn swap(self) -> Self {
+        match self {
+            CompareOp::Less => CompareOp::Greater,
+            CompareOp::LessEqual => CompareOp::GreaterEqual,
+            CompareOp::Greater => CompareOp::Less,
+            CompareOp::GreaterEqual => CompareOp::LessEqual,
+            op => op,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ArithmeticOp {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Modulo,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    Or(Box<Expr>, Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Compare(CompareOp, Box<Expr>, Box<Expr>),
+    Arithmetic(ArithmeticOp, Box<Expr>, Box<Expr>),
+    Negate(Box<Expr>),
+    Union(Box<Expr>, Box<Expr>),
+    /// A location path, starting at the root if it is absolute
+    Path {
+        absolute: bool,
+        steps: Vec<Step>,
+    },
+    /// A primary expression filtered by predicates and followed by steps
+    Filter {
+        primary: Box<Expr>,
+        predicates: Vec<Expr>,
+        steps: Vec<Step>,
+    },
+    Literal(String),
+    Number(f64),
+    Function(Function, Vec<Expr>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Step {
+    axis: Axis,
+    test: NodeTest,
+    predicates: Vec<Expr>,
+}
+
+impl Step {
+    fn descendant_or_self() -> Self {
+        Step {
+            axis: Axis::DescendantOrSelf,
+            test: NodeTest::Node,
+            predicates: vec![],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Axis {
+    Ancestor,
+    AncestorOrSelf,
+    Attribute,
+    Child,
+    Descendant,
+    DescendantOrSelf,
+    Following,
+    FollowingSibling,
+    Parent,
+    Preceding,
+    PrecedingSibling,
+    SelfAxis,
+}
+
+impl Axis {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "ancestor" => Axis::Ancestor,
+            "ancestor-or-self" => Axis::AncestorOrSelf,
+            "attribute" => Axis::Attribute,
+            "child" => Axis::Child,
+            "descendant" => Axis::Descendant,
+            "descendant-or-self" => Axis::DescendantOrSelf,
+            "following" => Axis::Following,
+            "following-sibling" => Axis::FollowingSibling,
+            "parent" => Axis::Parent,
+            "preceding" => Axis::Preceding,
+            "preceding-sibling" => Axis::PrecedingSibling,
+            "self" => Axis::SelfAxis,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum NodeTest {
+    /// `*`
+    Any,
+    /// `prefix:*`
+    Prefix(String),
+    Name(String),
+    Text,
+    Comment,
+    ProcessingInstruction(Option<String>),
+    Node,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Function {
+    Last,
+    Position,
+    Count,
+    LocalName,
+    NamespaceUri,
+    Name,
+    String,
+    Concat,
+    StartsWith,
+    Contains,
+    SubstringBefore,
+    SubstringAfter,
+    Substring,
+    StringLength,
+    NormalizeSpace,
+    Translate,
+    Boolean,
+    Not,
+    True,
+    False,
+    Number,
+    Sum,
+    Floor,
+    Ceiling,
+    Round,
+}
+
+impl Function {
+    fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "last" => Function::Last,
+            "position" => Function::Position,
+            "count" => Function::Count,
+            "local-name" => Function::LocalName,
+            "namespace-uri" => Function::NamespaceUri,
+            "name" => Function::Name,
+            "string" => Function::String,
+            "concat" => Function::Concat,
+            "starts-with" => Function::StartsWith,
+            "contains" => Function::Contains,
+            "substring-before" => Function::SubstringBefore,
+            "substring-after" => Function::SubstringAfter,
+            "substring" => Function::Substring,
+            "string-length" => Function::StringLength,
+            "normalize-space" => Function::NormalizeSpace,
+            "translate" => Function::Translate,
+            "boolean" => Function::Boolean,
+            "not" => Function::Not,
+            "true" => Function::True,
+            "false" => Function::False,
+            "number" => Function::Number,
+            "sum" => Function::Sum,
+            "floor" => Function::Floor,
+            "ceiling" => Function::Ceiling,
+            "round" => Function::Round,
+            _ => return None,
+        })
+    }
+
+    /// Returns the minimum and maximum number of arguments of the function
+    fn arity(&self) -> (usize, usize) {
+        match self {
+            Function::Last | Function::Position | Function::True | Function::False => {
+                (0, 0)
+            }
+            Function::LocalName
+            | Function::NamespaceUri
+            | Function::Name
+            | Function::String
+            | Function::StringLength
+            | Function::NormalizeSpace
+            | Function::Number => (0, 1),
+            Function::Count
+            | Function::Boolean
+            | Function::Not
+            | Function::Sum
+            | Function::Floor
+            | Function::Ceiling
+            | Function::Round => (1, 1),
+            Function::StartsWith
+            | Function::Contains
+            | Function::SubstringBefore
+            | Function::SubstringAfter => (2, 2),
+            Function::Substring => (2, 3),
+            Function::Translate => (3, 3),
+            Function::Concat => (2, usize::MAX),
+        }
+    }
+}
+
+type ParseResult<T> = std::result::Result<T, String>;
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&Token> {
+        self.tokens.get(self.pos + offset)
+    }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, token: &Token) -> ParseResult<()> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(format!("expected {token:?}, got {:?}", self.peek()))
+        }
+    }
+
+    fn parse_expr(&mut self) -> ParseResult<Expr> {
+        let mut expr = self.parse_and()?;
+        while self.eat(&Token::Or) {
+            expr = Expr::Or(Box::new(expr), Box::new(self.parse_and()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_and(&mut self) -> ParseResult<Expr> {
+        let mut expr = self.parse_equality()?;
+        while self.eat(&Token::And) {
+            expr = Expr::And(Box::new(expr), Box::new(self.parse_equality()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_equality(&mut self) -> ParseResult<Expr> {
+        let mut expr = self.parse_relational()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Equal) => CompareOp::Equal,
+                Some(Token::NotEqual) => CompareOp::NotEqual,
+                _ => return Ok(expr),
+            };
+            self.pos += 1;
+            let right = self.parse_relational()?;
+            expr = Expr::Compare(op, Box::new(expr), Box::new(right));
+        }
+    }
+
+    fn parse_relational(&mut self) -> ParseResult<Expr> {
+        let mut expr = self.parse_additive()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Less) => CompareOp::Less,
+                Some(Token::LessEqual) => CompareOp::LessEqual,
+                Some(Token::Greater) => CompareOp::Greater,
+                Some(Token::GreaterEqual) => CompareOp::GreaterEqual,
+                _ => return Ok(expr),
+            };
+            self.pos += 1;
+            let right = self.parse_additive()?;
+            expr = Expr::Compare(op, Box::new(expr), Box::new(right));
+        }
+    }
+
+    fn parse_additive(&mut self) -> ParseResult<Expr> {
+        let mut expr = self.parse_multiplicative()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Plus) => ArithmeticOp::Add,
+                Some(Token::Minus) => ArithmeticOp::Subtract,
+                _ => return Ok(expr),
+            };
+            self.pos += 1;
+            let right = self.parse_multiplicative()?;
+            expr = Expr::Arithmetic(op, Box::new(expr), Box::new(right));
+        }
+    }
+
+    fn parse_multiplicative(&mut self) -> ParseResult<Expr> {
+        let mut expr = self.parse_unary()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Multiply) => ArithmeticOp::Multiply,
+                Some(Token::Div) => ArithmeticOp::Divide,
+                Some(Token::Mod) => ArithmeticOp::Modulo,
+                _ => return Ok(expr),
+            };
+            self.pos += 1;
+            let right = self.parse_unary()?;
+            expr = Expr::Arithmetic(op, Box::new(expr), Box::new(right));
+        }
+    }
+
+    fn parse_unary(&mut self) -> ParseResult<Expr> {
+        if self.eat(&Token::Minus) {
+            Ok(Expr::Negate(Box::new(self.parse_unary()?)))
+        } else {
+            self.parse_union()
+        }
+    }
+
+    fn parse_union(&mut self) -> ParseResult<Expr> {
+        let mut expr = self.parse_path()?;
+        while self.eat(&Token::Pipe) {
+            expr = Expr::Union(Box::new(expr), Box::new(self.parse_path()?));
+        }
+        Ok(expr)
+    }
+
+    fn parse_path(&mut self) -> ParseResult<Expr> {
+        match self.peek() {
+            Some(Token::Slash) => {
+                self.pos += 1;
+                let steps = if self.starts_step() {
+                    self.parse_relative_path()?
+                } else {
+                    vec![]
+                };
+                Ok(Expr::Path {
+                    absolute: true,
+                    steps,
+                })
+            }
+            Some(Token::DoubleSlash) => {
+                self.pos += 1;
+                let mut steps = vec![Step::descendant_or_self()];
+                steps.extend(self.parse_relative_path()?);
+                Ok(Expr::Path {
+                    absolute: true,
+                    steps,
+                })
+            }
+            _ if self.starts_step() => Ok(Expr::Path {
+                absolute: false,
+                steps: self.parse_relative_path()?,
+            }),
+            _ => {
+                let primary = self.parse_primary()?;
+                let mut predicates = vec![];
+                while self.eat(&Token::LeftBracket) {
+                    predicates.push(self.parse_expr()?);
+                    self.expect(&Token::RightBracket)?;
+                }
+                let mut steps = vec![];
+                if self.eat(&Token::DoubleSlash) {
+                    steps.push(Step::descendant_or_self());
+                    steps.extend(self.parse_relative_path()?);
+                } else if self.eat(&Token::Slash) {
+                    steps.extend(self.parse_relative_path()?);
+                }
+                if predicates.is_empty() && steps.is_empty() {
+                    Ok(primary)
+                } else {
+                    Ok(Expr::Filter {
+                        primary: Box::new(primary),
+                        predicates,
+                        steps,
+                    })
+                }
+            }
+        }
+    }
+
+    /// Returns true if the next token starts a location step rather than a
+    /// primary expression
+    fn starts_step(&self) -> bool {
+        match self.peek() {
+            Some(Token::Star | Token::At | Token::Dot | Token::DoubleDot) => true,
+            Some(Token::Name(name)) => {
+                self.peek_at(1) != Some(&Token::LeftParen) || is_node_type(name)
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_relative_path(&mut self) -> ParseResult<Vec<Step>> {
+        let mut steps = vec![self.parse_step()?];
+        loop {
+            if self.eat(&Token::DoubleSlash) {
+                steps.push(Step::descendant_or_self());
+            } else if !self.eat(&Token::Slash) {
+                return Ok(steps);
+            }
+            steps.push(self.parse_step()?);
+        }
+    }
+
+    fn parse_step(&mut self) -> ParseResult<Step> {
+        if self.eat(&Token::Dot) {
+            return Ok(Step {
+                axis: Axis::SelfAxis,
+                test: NodeTest::Node,
+                predicates: vec![],
+            });
+        }
+        if self.eat(&Token::DoubleDot) {
+            return Ok(Step {
+                axis: Axis::Parent,
+                test: NodeTest::Node,
+                predicates: vec![],
+            });
+        }
+        let axis = if self.eat(&Token::At) {
+            Axis::Attribute
+        } else if let (Some(Token::Name(name)), Some(Token::DoubleColon)) =
+            (self.peek(), self.peek_at(1))
+        {
+            let axis = Axis::from_name(name).ok_or(format!("unknown axis {name}"))?;
+            self.pos += 2;
+            axis
+        } else {
+            Axis::Child
+        };
+        let test = match self.tokens.get(self.pos).cloned() {
+            Some(Token::Star) => {
+                self.pos += 1;
+                NodeTest::Any
+            }
+            Some(Token::Name(name)) if self.peek_at(1) == Some(&Token::LeftParen) => {
+                self.pos += 2;
+                let test = match name.as_str() {
+                    "text" => NodeTest::Text,
+                    "comment" => NodeTest::Comment,
+                    "node" => NodeTest::Node,
+                    "processing-instruction" => match self.peek().cloned() {
+                        Some(Token::Literal(target)) => {
+                            self.pos += 1;
+                            NodeTest::ProcessingInstruction(Some(target))
+                        }
+                        _ => NodeTest::ProcessingInstruction(None),
+                    },
+                    _ => return Err(format!("unexpected function {name} in a step")),
+                };
+                self.expect(&Token::RightParen)?;
+                test
+            }
+            Some(Token::Name(name)) => {
+                self.pos += 1;
+                match name.strip_suffix(":*") {
+                    Some(prefix) => NodeTest::Prefix(format!("{prefix}:")),
+                    None => NodeTest::Name(name),
+                }
+            }
+            other => return Err(format!("expected a node test, got {other:?}")),
+        };
+        let mut predicates = vec![];
+        while self.eat(&Token::LeftBracket) {
+            predicates.push(self.parse_expr()?);
+            self.expect(&Token::RightBracket)?;
+        }
+        Ok(Step {
+            axis,
+            test,
+            predicates,
+        })
+    }
+
+    fn parse_primary(&mut self) -> ParseResult<Expr> {
+        match self.tokens.get(self.pos).cloned() {
+            Some(Token::Literal(literal)) => {
+                self.pos += 1;
+                Ok(Expr::Literal(literal))
+            }
+            Some(Token::Number(number)) => {
+                self.pos += 1;
+                Ok(Expr::Number(number))
+            }
+            Some(Token::LeftParen) => {
+                self.pos += 1;
+                let expr = self.parse_expr()?;
+                self.expect(&Token::RightParen)?;
+                Ok(expr)
+            }
+            Some(Token::Name(name)) => {
+                let function = Function::from_name(&name)
+                    .ok_or(format!("unknown function {name}"))?;
+                self.pos += 1;
+                self.expect(&Token::LeftParen)?;
+                let mut args = vec![];
+                if !self.eat(&Token::RightParen) {
+                    loop {
+                        args.push(self.parse_expr()?);
+                        if self.eat(&Token::RightParen) {
+                            break;
+                        }
+                        self.expect(&Token::Comma)?;
+                    }
+                }
+                let (min, max) = function.arity();
+                if args.len() < min || args.len() > max {
+                    return Err(format!(
+                        "wrong number of arguments for function {name}: {}",
+                        args.len()
+                    ));
+                }
+                Ok(Expr::Function(function, args))
+            }
+            other => Err(format!("unexpected {other:?}")),
+        }
+    }
+}
+
+fn is_node_type(name: &str) -> bool {
+    matches!(name, "text" | "comment" | "node" | "processing-instruction")
+}
+
+struct Context<'a> {
+    document: &'a Document,
+    node: NodeId,
+    position: usize,
+    size: usize,
+}
+
+impl Context<'_> {
+    fn with_node(&self, node: NodeId, position: usize, size: usize) -> Self {
+        Context {
+            document: self.document,
+            node,
+            position,
+            size,
+        }
+    }
+
+    fn evaluate(&self, expr: &Expr) -> Result<Value> {
+        let document = self.document;
+        Ok(match expr {
+            Expr::Or(left, right) => Value::Boolean(
+                self.evaluate(left)?.to_boolean() || self.evaluate(right)?.to_boolean(),
+            ),
+            Expr::And(left, right) => Value::Boolean(
+                self.evaluate(left)?.to_boolean() && self.evaluate(right)?.to_boolean(),
+            ),
+            Expr::Compare(op, left, right) => {
+                let (left, right) = (self.evaluate(left)?, self.evaluate(right)?);
+                Value::Boolean(compare(*op, &left, &right, document))
+            }
+            Expr::Arithmetic(op, left, right) => {
+                let left = self.evaluate(left)?.to_number(document);
+                let right = self.evaluate(right)?.to_number(document);
+                Value::Number(match op {
+                    ArithmeticOp::Add => left + right,
+                    ArithmeticOp::Subtract => left - right,
+                    ArithmeticOp::Multiply => left * right,
+                    ArithmeticOp::Divide => left / right,
+                    ArithmeticOp::Modulo => left % right,
+                })
+            }
+            Expr::Negate(expr) => {
+                Value::Number(-self.evaluate(expr)?.to_number(document))
+            }
+            Expr::Union(left, right) => {
+                let mut nodes = self.evaluate_node_set(left)?;
+                nodes.extend(self.evaluate_node_set(right)?);
+                nodes.sort_unstable();
+                nodes.dedup();
+                Value::NodeSet(nodes)
+            }
+            Expr::Path { absolute, steps } => {
+                let start = if *absolute { Document::ROOT } else { self.node };
+                Value::NodeSet(self.evaluate_steps(vec![start], steps)?)
+            }
+            Expr::Filter {
+                primary,
+                predicates,
+                steps,
+            } => {
+                let mut nodes = self.evaluate_node_set(primary)?;
+                for predicate in predicates {
+                    nodes = self.filter(nodes, predicate)?;
+                }
+                Value::NodeSet(self.evaluate_steps(nodes, steps)?)
+            }
+            Expr::Literal(literal) => Value::String(literal.clone()),
+            Expr::Number(number) => Value::Number(*number),
+            Expr::Function(function, args) => self.evaluate_function(*function, args)?,
+        })
+    }
+
+    fn evaluate_node_set(&self, expr: &Expr) -> Result<Vec<NodeId>> {
+        match self.evaluate(expr)? {
+            Value::NodeSet(nodes) => Ok(nodes),
+            other => {
+                exec_err!("XPath expression does not evaluate to a node set: {other:?}")
+            }
+        }
+    }
+
+    fn evaluate_steps(
+        &self,
+        mut nodes: Vec<NodeId>,
+        steps: &[Step],
+    ) -> Result<Vec<NodeId>> {
+        for step in steps {
+            let mut result = vec![];
+            for node in nodes {
+                let mut candidates = axis_nodes(self.document, step.axis, node)
+                    .into_iter()
+                    .filter(|candidate| matches_test(self.document, step, *candidate))
+                    .collect::<Vec<_>>();
+                for predicate in &step.predicates {
+                    candidates = self.filter(candidates, predicate)?;
+                }
+                result.extend(candidates);
+            }
+            result.sort_unstable();
+            result.dedup();
+            nodes = result;
+        }
+        Ok(nodes)
+    }
+
+    /// Keeps the nodes for which the predicate is true, the nodes being in
+    /// the order of the axis which selected them
+    fn filter(&self, nodes: Vec<NodeId>, predicate: &Expr) -> Result<Vec<NodeId>> {
+        let size = nodes.len();
+        let mut filtered = vec![];
+        for (i, node) in nodes.into_iter().enumerate() {
+            let context = self.with_node(node, i + 1, size);
+            let keep = match context.evaluate(predicate)? {
+                Value::Number(n) => n == (i + 1) as f64,
+                other => other.to_boolean(),
+            };
+            if keep {
+                filtered.push(node);
+            }
+        }
+        Ok(filtered)
+    }
+
+    fn evaluate_function(&self, function: Function, args: &[Expr]) -> Result<Value> {
+        let document = self.document;
+        let string_arg = |i: usize| -> Result<String> {
+            match args.get(i) {
+                Some(arg) => Ok(self.evaluate(arg)?.string_value(document)),
+                None => Ok(document.string_value(self.node)),
+            }
+        };
+        let number_arg = |i: usize| -> Result<f64> {
+            Ok(self.evaluate(&args[i])?.to_number(document))
+        };
+        // the first node of the argument, or the context node
+        let node_arg = || -> Result<Option<NodeId>> {
+            match args.first() {
+                Some(arg) => Ok(self.evaluate_node_set(arg)?.first().copied()),
+                None => Ok(Some(self.node)),
+            }
+        };
+        Ok(match function {
+            Function::Last => Value::Number(self.size as f64),
+            Function::Position => Value::Number(self.position as f64),
+            Function::Count => {
+                Value::Number(self.evaluate_node_set(&args[0])?.len() as f64)
+            }
+            Function::LocalName => {
+                let name = node_arg()?.map_or("", |node| document.name(node));
+                let local = name.rsplit_once(':').map_or(name, |(_, local)| local);
+                Value::String(local.to_string())
+            }
+            Function::NamespaceUri => {
+                node_arg()?;
+                Value::String(String::new())
+            }
+            Function::Name => Value::String(
+                node_arg()?
+                    .map_or("", |node| document.name(node))
+                    .to_string(),
+            ),
+            Function::String => Value::String(string_arg(0)?),
+            Function::Concat => Value::String(
+                (0..args.len())
+                    .map(string_arg)
+                    .collect::<Result<Vec<_>>>()?
+                    .concat(),
+            ),
+            Function::StartsWith => {
+                Value::Boolean(string_arg(0)?.starts_with(&string_arg(1)?))
+            }
+            Function::Contains => {
+                Value::Boolean(string_arg(0)?.contains(&string_arg(1)?))
+            }
+            Function::SubstringBefore => {
+                let (s, pattern) = (string_arg(0)?, string_arg(1)?);
+                let before = s.find(&pattern).map_or("", |i| &s[..i]);
+                Value::String(before.to_string())
+            }
+            Function::SubstringAfter => {
+                let (s, pattern) = (string_arg(0)?, string_arg(1)?);
+                let after = s.find(&pattern).map_or("", |i| &s[i + pattern.len()..]);
+                Value::String(after.to_string())
+            }
+            Function::Substring => {
+                let s = string_arg(0)?;
+                let start = xpath_round(number_arg(1)?);
+                let end = match args.get(2) {
+                    Some(_) => start + xpath_round(number_arg(2)?),
+                    None => f64::INFINITY,
+                };
+                let substring = s
+                    .chars()
+                    .enumerate()
+                    .filter(|(i, _)| {
+                        let position = (*i + 1) as f64;
+                        position >= start && position < end
+                    })
+                    .map(|(_, c)| c)
+                    .collect();
+                Value::String(substring)
+            }
+            Function::StringLength => {
+                Value::Number(string_arg(0)?.chars().count() as f64)
+            }
+            Function::NormalizeSpace => Value::String(
+                string_arg(0)?
+                    .split(is_xml_whitespace)
+                    .filter(|s| !s.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+            ),
+            Function::Translate => {
+                let (s, from, to) = (string_arg(0)?, string_arg(1)?, string_arg(2)?);
+                let to = to.chars().collect::<Vec<_>>();
+                let translated = s
+                    .chars()
+                    .filter_map(|c| match from.chars().position(|f| f == c) {
+                        Some(i) => to.get(i).copied(),
+                        None => Some(c),
+                    })
+                    .collect();
+                Value::String(translated)
+            }
+            Function::Boolean => Value::Boolean(self.evaluate(&args[0])?.to_boolean()),
+            Function::Not => Value::Boolean(!self.evaluate(&args[0])?.to_boolean()),
+            Function::True => Value::Boolean(true),
+            Function::False => Value::Boolean(false),
+            Function::Number => Value::Number(match args.first() {
+                Some(arg) => self.evaluate(arg)?.to_number(document),
+                None => string_to_number(&document.string_value(self.node)),
+            }),
+            Function::Sum => Value::Number(
+                self.evaluate_node_set(&args[0])?
+                    .iter()
+                    .map(|node| string_to_number(&document.string_value(*node)))
+                    .sum(),
+            ),
+            Function::Floor => Value::Number(number_arg(0)?.floor()),
+            Function::Ceiling => Value::Number(number_arg(0)?.ceil()),
+            Function::Round => Value::Number(xpath_round(number_arg(0)?)),
+        })
+    }
+}
+
+/// Returns the nodes of the axis of `node`, in the order of the axis: reverse
+/// document order for the reverse axes
+fn axis_nodes(document: &Document, axis: Axis, node: NodeId) -> Vec<NodeId> {
+    let parent = document.node(node).parent;
+    let is_attribute = matches!(document.node(node).kind, NodeKind::Attribute { .. });
+    match axis {
+        Axis::Child => document.node(node).children.clone(),
+        Axis::Attribute => document.node(node).attributes.clone(),
+        Axis::SelfAxis => vec![node],
+        Axis::Parent => parent.into_iter().collect(),
+        Axis::Descendant => {
+            let mut nodes = vec![];
+            push_descendants(document, node, &mut nodes);
+            nodes
+        }
+        Axis::DescendantOrSelf => {
+            let mut nodes = vec![node];
+            push_descendants(document, node, &mut nodes);
+            nodes
+        }
+        Axis::Ancestor | Axis::AncestorOrSelf => {
+            let mut nodes = vec![];
+            if axis == Axis::AncestorOrSelf {
+                nodes.push(node);
+            }
+            let mut current = parent;
+            while let Some(ancestor) = current {
+                nodes.push(ancestor);
+                current = document.node(ancestor).parent;
+            }
+            nodes
+        }
+        Axis::FollowingSibling | Axis::PrecedingSibling => {
+            let Some(parent) = parent.filter(|_| !is_attribute) else {
+                return vec![];
+            };
+            let siblings = &document.node(parent).children;
+            let index = siblings.iter().position(|s| *s == node).unwrap_or(0);
+            if axis == Axis::FollowingSibling {
+                siblings[index + 1..].to_vec()
+            } else {
+                siblings[..index].iter().rev().copied().collect()
+            }
+        }
+        Axis::Following => {
+            // the nodes after the descendants of the node, or after the
+            // attribute, in document order
+            let mut end = node;
+            if !is_attribute {
+                let mut descendants = vec![];
+                push_descendants(document, node, &mut descendants);
+                end = descendants.last().copied().unwrap_or(node);
+                end = document.node(end).attributes.last().copied().unwrap_or(end);
+            }
+            (end + 1..document.len())
+                .filter(|n| !matches!(document.node(*n).kind, NodeKind::Attribute { .. }))
+                .collect()
+        }
+        Axis::Preceding => {
+            let ancestors = axis_nodes(document, Axis::Ancestor, node);
+            (0..node)
+                .rev()
+                .filter(|n| {
+                    !ancestors.contains(n)
+                        && !matches!(document.node(*n).kind, NodeKind::Attribute { .. })
+                })
+                .collect()
+        }
+    }
+}
+
+fn push_descendants(document: &Document, node: NodeId, nodes: &mut Vec<NodeId>) {
+    for child in &document.node(node).children {
+        nodes.push(*child);
+        push_descendants(document, *child, nodes);
+    }
+}
+
+fn matches_test(document: &Document, step: &Step, node: NodeId) -> bool {
+    let kind = &document.node(node).kind;
+    // the principal node type of the axis
+    let is_principal = if step.axis == Axis::Attribute {
+        matches!(kind, NodeKind::Attribute { .. })
+    } else {
+        matches!(kind, NodeKind::Element { .. })
+    };
+    match &step.test {
+        NodeTest::Any => is_principal,
+        NodeTest::Prefix(prefix) => {
+            is_principal && document.name(node).starts_with(prefix)
+        }
+        NodeTest::Name(name) => is_principal && document.name(node) == name,
+        NodeTest::Text => matches!(kind, NodeKind::Text(_)),
+        NodeTest::Comment => matches!(kind, NodeKind::Comment(_)),
+        NodeTest::ProcessingInstruction(target) => match kind {
+            NodeKind::ProcessingInstruction { target: t, .. } => {
+                target.as_ref().is_none_or(|target| target == t)
+            }
+            _ => false,
+        },
+        NodeTest::Node => true,
+    }
+}
+
+fn compare(op: CompareOp, left: &Value, right: &Value, document: &Document) -> bool {
+    match (left, right) {
+        (Value::NodeSet(left), Value::NodeSet(right)) => {
+            let right = right
+                .iter()
+                .map(|node| Value::String(document.string_value(*node)))
+                .collect::<Vec<_>>();
+            left.iter().any(|node| {
+                let left = Value::String(document.string_value(*node));
+                right.iter().any(|right| compare_atoms(op, &left, right))
+            })
+        }
+        (Value::NodeSet(nodes), Value::Boolean(_)) => {
+            compare_atoms(op, &Value::Boolean(!nodes.is_empty()), right)
+        }
+        (Value::NodeSet(nodes), _) => nodes.iter().any(|node| {
+            compare_atoms(op, &Value::String(document.string_value(*node)), right)
+        }),
+        (_, Value::NodeSet(_)) => compare(op.swap(), right, left, document),
+        _ => compare_atoms(op, left, right),
+    }
+}
+
+/// Compares values which are not node sets
+fn compare_atoms(op: CompareOp, left: &Value, right: &Value) -> bool {
+    let number = |value: &Value| match value {
+        Value::Boolean(b) => f64::from(u8::from(*b)),
+        Value::Number(n) => *n,
+        Value::String(s) => string_to_number(s),
+        Value::NodeSet(_) => f64::NAN,
+    };
+    match op {
+        CompareOp::Equal | CompareOp::NotEqual => {
+            let equal = match (left, right) {
+                (Value::Boolean(_), _) | (_, Value::Boolean(_)) => {
+                    left.to_boolean() == right.to_boolean()
+                }
+                (Value::Number(_), _) | (_, Value::Number(_)) => {
+                    number(left) == number(right)
+                }
+                (Value::String(left), Value::String(right)) => left == right,
+                _ => false,
+            };
+            equal == (op == CompareOp::Equal)
+        }
+        CompareOp::Less => number(left) < number(right),
+        CompareOp::LessEqual => number(left) <= number(right),
+        CompareOp::Greater => number(left) > number(right),
+        CompareOp::GreaterEqual => number(left) >= number(right),
+    }
+}
+
+/// Converts a string to a number as XPath's `number` function, which only
+/// accepts decimal numbers without exponents
+fn string_to_number(s: &str) -> f64 {
+    let s = s.trim_matches(is_xml_whitespace);
+    let digits = s.strip_prefix('-').unwrap_or(s);
+    let is_decimal = !digits.is_empty()
+        && digits != "."
+        && digits.matches('.').count() <= 1
+        && digits.chars().all(|c| c.is_ascii_digit() || c == '.');
+    if is_decimal {
+        s.parse().unwrap_or(f64::NAN)
+    } else {
+        f64::NAN
+    }
+}
+
+/// Formats a number as XPath's `string` function
+fn number_to_string(n: f64) -> String {
+    if n.is_nan() {
+        "NaN".to_string()
+    } else if n.is_infinite() {
+        if n > 0.0 { "Infinity" } else { "-Infinity" }.to_string()
+    } else if n == 0.0 {
+        "0".to_string()
+    } else {
+        n.to_string()
+    }
+}
+
+/// Rounds to the closest integer, rounding halves towards positive infinity
+fn xpath_round(n: f64) -> f64 {
+    if n.is_nan() || n.is_infinite() || n == 0.0 {
+        n
+    } else if (-0.5..0.0).contains(&n) {
+        -0.0
+    } else {
+        (n + 0.5).floor()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluate(xml: &str, path: &str) -> (Document, Value) {
+        let document = Document::parse(xml).unwrap();
+        let value = XPath::parse(path).unwrap().evaluate(&document).unwrap();
+        (document, value)
+    }
+
+    fn evaluate_string(xml: &str, path: &str) -> String {
+        let (document, value) = evaluate(xml, path);
+        value.string_value(&document)
+    }
+
+    fn evaluate_values(xml: &str, path: &str) -> Vec<Option<String>> {
+        let (document, value) = evaluate(xml, path);
+        let Value::NodeSet(nodes) = value else {
+            panic!("{path} is not a node set");
+        };
+        nodes.iter().map(|n| document.node_value(*n)).collect()
+    }
+
+    #[test]
+    fn test_location_paths() {
+        let xml = "<a><b id='1'>b1</b><b id='2'>b2</b><c><b>b3</b></c></a>";
+        let values = |path| evaluate_values(xml, path);
+        assert_eq!(values("a/b"), vec![None, None]);
+        assert_eq!(
+            values("a/b/text()"),
+            vec![Some("b1".into()), Some("b2".into())]
+        );
+        assert_eq!(values("//b/text()").len(), 3);
+        assert_eq!(values("/a/b/@id"), vec![Some("1".into()), Some("2".into())]);
+        assert_eq!(values("a/b[@id='2']/text()"), vec![Some("b2".into())]);
+        assert_eq!(values("a/b[last()]/text()"), vec![Some("b2".into())]);
+        assert_eq!(values("//b[1]/text()").len(), 2);
+        assert_eq!(values("(//b)[1]/text()"), vec![Some("b1".into())]);
+        assert_eq!(values("//c/../b[2]/@id"), vec![Some("2".into())]);
+        assert_eq!(values("a/*").len(), 3);
+        assert_eq!(values("a/b | a/c").len(), 3);
+        assert_eq!(values("//b[3]"), vec![]);
+        assert_eq!(values("a/c/b/ancestor::*"), vec![None, None],);
+        assert_eq!(
+            values("a/b[1]/following-sibling::*[1]/@id"),
+            vec![Some("2".into())]
+        );
+        assert_eq!(
+            values("a/c/preceding-sibling::b[1]/@id"),
+            vec![Some("2".into())]
+        );
+    }
+
+    #[test]
+    fn test_functions_and_operators() {
+        let xml = "<a><b>1</b><b>2</b><c>cc</c><d> x  y </d></a>";
+        let string = |path| evaluate_string(xml, path);
+        assert_eq!(string("sum(a/b)"), "3");
+        assert_eq!(string("count(a/*)"), "4");
+        assert_eq!(string("a/b[2] * 2 + 1"), "5");
+        assert_eq!(string("7 mod 3 div 2"), "0.5");
+        assert_eq!(string("a/b = 2"), "true");
+        assert_eq!(string("a/b != 1"), "true");
+        assert_eq!(string("a/b > 2"), "false");
+        assert_eq!(string("a/c"), "cc");
+        assert_eq!(string("a/e"), "");
+        assert_eq!(string("number(a/c)"), "NaN");
+        assert_eq!(string("1 div 0"), "Infinity");
+        assert_eq!(string("concat(a/c, '-', name(a/*[1]))"), "cc-b");
+        assert_eq!(string("normalize-space(a/d)"), "x y");
+        assert_eq!(string("substring('12345', 1.5, 2.6)"), "234");
+        assert_eq!(string("translate('bar', 'abc', 'AB')"), "BAr");
+        assert_eq!(string("round(-0.5)"), "0");
+        assert_eq!(string("round(2.5)"), "3");
+        assert_eq!(string("not(a/e) and true()"), "true");
+        assert_eq!(string("string-length(a/c)"), "2");
+    }
+
+    #[test]
+    fn test_invalid_paths() {
+        for path in ["a/", "a[", "unknown()", "a::b", "count()", "'a", "a/b)"] {
+            assert!(XPath::parse(path).is_err(), "{path}");
+        }
+        let document = Document::parse("<a/>").unwrap();
+        let value = XPath::parse("'a'/b").unwrap().evaluate(&document);
+        assert!(value.is_err());
+    }
+}
@@ -15,11 +15,80 @@
 // specific language governing permissions and limitations
 // under the License.
 
+mod document;
+mod evaluator;
+pub mod xpath;
+pub mod xpath_boolean;
+pub mod xpath_numeric;
+pub mod xpath_string;
+
 use datafusion_expr::ScalarUDF;
+use datafusion_functions::make_udf_function;
 use std::sync::Arc;
 
-pub mod expr_fn {}
+make_udf_function!(xpath::SparkXPath, xpath);
+make_udf_function!(xpath_boolean::SparkXPathBoolean, xpath_boolean);
+make_udf_function!(xpath_numeric::SparkXPathDouble, xpath_double);
+make_udf_function!(xpath_numeric::SparkXPathFloat, xpath_float);
+make_udf_function!(xpath_numeric::SparkXPathInt, xpath_int);
+make_udf_function!(xpath_numeric::SparkXPathLong, xpath_long);
+make_udf_function!(xpath_numeric::SparkXPathShort, xpath_short);
+make_udf_function!(xpath_string::SparkXPathString, xpath_string);
+
+pub mod expr_fn {
+    use datafusion_functions::export_functions;
+
+    export_functions!((
+        xpath,
+        "Returns the values of the nodes selected by an XPath expression.",
+        arg1 arg2
+    ));
+    export_functions!((
+        xpath_boolean,
+        "Returns true if an XPath expression evaluates to true or selects a node.",
+        arg1 arg2
+    ));
+    export_functions!((
+        xpath_double,
+        "Returns the value of an XPath expression as a double.",
+        arg1 arg2
+    ));
+    export_functions!((
+        xpath_float,
+        "Returns the value of an XPath expression as a float.",
+        arg1 arg2
+    ));
+    export_functions!((
+        xpath_int,
+        "Returns the value of an XPath expression as an int.",
+        arg1 arg2
+    ));
+    export_functions!((
+        xpath_long,
+        "Returns the value of an XPath expression as a long.",
+        arg1 arg2
+    ));
+    export_functions!((
+        xpath_short,
+        "Returns the value of an XPath expression as a short.",
+        arg1 arg2
+    ));
+    export_functions!((
+        xpath_string,
+        "Returns the string value of an XPath expression.",
+        arg1 arg2
+    ));
+}
 
 pub fn functions() -> Vec<Arc<ScalarUDF>> {
-    vec![]
+    vec![
+        xpath(),
+        xpath_boolean(),
+        xpath_double(),
+        xpath_float(),
+        xpath_int(),
+        xpath_long(),
+        xpath_short(),
+        xpath_string(),
+    ]
 }
@@ -0,0 +1,103 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::function::utils::coerce_string_args;
+use crate::function::xml::evaluator::{evaluate_rows, Value};
+use arrow::array::{ArrayRef, ListBuilder, StringBuilder};
+use arrow::datatypes::DataType;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::{exec_err, Result};
+use datafusion_expr::{
+    ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_functions::utils::make_scalar_function;
+
+/// Spark-compatible `xpath` expression
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#xpath>
+///
+/// Returns the values of the nodes selected by an XPath expression, which are
+/// null for elements.
+#[derive(Debug)]
+pub struct SparkXPath {
+    signature: Signature,
+}
+
+impl Default for SparkXPath {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkXPath {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for SparkXPath {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "xpath"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::new_list(DataType::Utf8, true))
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(spark_xpath, vec![])(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [_, _] = take_function_args(self.name(), arg_types)?;
+        coerce_string_args(self.name(), arg_types)
+    }
+}
+
+pub fn spark_xpath(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let mut builder = ListBuilder::new(StringBuilder::new());
+    evaluate_rows("xpath", args, |row| {
+        match row {
+            None => builder.append_null(),
+            Some((document, Value::NodeSet(nodes))) => {
+                for node in nodes {
+                    builder.values().append_option(document.node_value(node));
+                }
+                builder.append(true);
+            }
+            Some((_, value)) => {
+                return exec_err!(
+                    "Spark `xpath` function requires an XPath expression selecting nodes, got {value:?}"
+                )
+            }
+        }
+        Ok(())
+    })?;
+    Ok(Arc::new(builder.finish()))
+}
@@ -0,0 +1,90 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::function::utils::coerce_string_args;
+use crate::function::xml::evaluator::evaluate_rows;
+use arrow::array::{ArrayRef, BooleanBuilder};
+use arrow::datatypes::DataType;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::Result;
+use datafusion_expr::{
+    ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_functions::utils::make_scalar_function;
+
+/// Spark-compatible `xpath_boolean` expression
+/// <https://spark.apache.org/docs/latest/api/sql/index.html#xpath_boolean>
+///
+/// Returns true if an XPath expression evaluates to true, or if it selects
+/// any node.
+#[derive(Debug)]
+pub struct SparkXPathBoolean {
+    signature: Signature,
+}
+
+impl Default for SparkXPathBoolean {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SparkXPathBoolean {
+    pub fn new() -> Self {
+        Self {
+            signature: Signature::user_defined(Volatility::Immutable),
+        }
+    }
+}
+
+impl ScalarUDFImpl for SparkXPathBoolean {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        "xpath_boolean"
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+        Ok(DataType::Boolean)
+    }
+
+    fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+        make_scalar_function(spark_xpath_boolean, vec![])(&args.args)
+    }
+
+    fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+        let [_, _] = take_function_args(self.name(), arg_types)?;
+        coerce_string_args(self.name(), arg_types)
+    }
+}
+
+pub fn spark_xpath_boolean(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let mut builder = BooleanBuilder::new();
+    evaluate_rows("xpath_boolean", args, |row| {
+        builder.append_option(row.map(|(_, value)| value.to_boolean()));
+        Ok(())
+    })?;
+    Ok(Arc::new(builder.finish()))
+}
@@ -0,0 +1,154 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Spark-compatible `xpath_int`, `xpath_long`, `xpath_short`, `xpath_float`
+//! and `xpath_double` expressions, which return the numeric value of an XPath
+//! expression converted like Java's narrowing conversions: NaN becomes 0 and
+//! out of range values saturate.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use crate::function::utils::coerce_string_args;
+use crate::function::xml::evaluator::evaluate_rows;
+use arrow::array::{
+    ArrayRef, Float32Builder, Float64Builder, Int16Builder, Int32Builder, Int64Builder,
+};
+use arrow::datatypes::DataType;
+use datafusion_common::utils::take_function_args;
+use datafusion_common::Result;
+use datafusion_expr::{
+    ColumnarValue, ScalarFunctionArgs, ScalarUDFImpl, Signature, Volatility,
+};
+use datafusion_functions::utils::make_scalar_function;
+
+macro_rules! make_xpath_numeric_function {
+    ($UDF:ident, $NAME:literal, [$($ALIAS:literal),*], $DATA_TYPE:expr, $BUILDER:ty, $CONVERT:expr, $DOC:literal) => {
+        #[doc = $DOC]
+        #[derive(Debug)]
+        pub struct $UDF {
+            signature: Signature,
+            aliases: Vec<String>,
+        }
+
+        impl Default for $UDF {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl $UDF {
+            pub fn new() -> Self {
+                Self {
+                    signature: Signature::user_defined(Volatility::Immutable),
+                    aliases: vec![$($ALIAS.to_string()),*],
+                }
+            }
+        }
+
+        impl ScalarUDFImpl for $UDF {
+            fn as_any(&self) -> &dyn Any {
+                self
+            }
+
+            fn name(&self) -> &str {
+                $NAME
+            }
+
+            fn aliases(&self) -> &[String] {
+                &self.aliases
+            }
+
+            fn signature(&self) -> &Signature {
+                &self.signature
+            }
+
+            fn return_type(&self, _arg_types: &[DataType]) -> Result<DataType> {
+                Ok($DATA_TYPE)
+            }
+
+            fn invoke_with_args(&self, args: ScalarFunctionArgs) -> Result<ColumnarValue> {
+                make_scalar_function(
+                    |args: &[ArrayRef]| -> Result<ArrayRef> {
+                        let mut builder = <$BUILDER>::new();
+                        evaluate_rows($NAME, args, |row| {
+                            builder.append_option(
+                                row.map(|(document, value)| value.to_number(document))
+                                    .map($CONVERT),
+                            );
+                            Ok(())
+                        })?;
+                        Ok(Arc::new(builder.finish()))
+                    },
+                    vec![],
+                )(&args.args)
+            }
+
+            fn coerce_types(&self, arg_types: &[DataType]) -> Result<Vec<DataType>> {
+                let [_, _] = take_function_args(self.name(), arg_types)?;
+                coerce_string_args(self.name(), arg_types)
+            }
+        }
+    };
+}
+
+make_xpath_numeric_function!(
+    SparkXPathInt,
+    "xpath_int",
+    [],
+    DataType::Int32,
+    Int32Builder,
+    |n: f64| n as i32,
+    "Spark-compatible `xpath_int` expression\n<https://spark.apache.org/docs/latest/api/sql/index.html#xpath_int>"
+);
+make_xpath_numeric_function!(
+    SparkXPathLong,
+    "xpath_long",
+    [],
+    DataType::Int64,
+    Int64Builder,
+    |n: f64| n as i64,
+    "Spark-compatible `xpath_long` expression\n<https://spark.apache.org/docs/latest/api/sql/index.html#xpath_long>"
+);
+make_xpath_numeric_function!(
+    SparkXPathShort,
+    "xpath_short",
+    [],
+    DataType::Int16,
+    Int16Builder,
+    // Java converts doubles to shorts through ints
+    |n: f64| n as i32 as i16,
+    "Spark-compatible `xpath_short` expression\n<https://spark.apache.org/docs/latest/api/sql/index.html#xpath_short>"
+);
+make_xpath_numeric_function!(
+    SparkXPathFloat,
+    "xpath_float",
+    [],
+    DataType::Float32,
+    Float32Builder,
+    |n: f64| n as f32,
+    "Spark-compatible `xpath_float` expression\n<https://spark.apache.org/docs/latest/api/sql/index.html#xpath_float>"
+);
+make_xpath_numeric_function!(
+    SparkXPathDouble,
+    "xpath_double",
+    ["xpath_number"],
+    DataType::Float64,
+    Float64Builder,
+    |n: f64| n,
+    "Spark-compatible `xpath_double` expression\n<https://spark.apache.org/docs/latest/api/sql/index.html#xpath_double>"
+);
@@ -22,16 +22,18 @@ use datafusion_common::{
     internal_datafusion_err, internal_err, not_impl_err, plan_datafusion_err, plan_err,
     DFSchema, Dependency, Diagnostic, Result, Span,
 };
-use datafusion_expr::expr::{ScalarFunction, Unnest, WildcardOptions};
+use datafusion_expr::expr::{Lambda, ScalarFunction, Unnest, WildcardOptions};
 use datafusion_expr::planner::{PlannerResult, RawAggregateExpr, RawWindowExpr};
 use datafusion_expr::{
-    expr, Expr, ExprFunctionExt, ExprSchemable, WindowFrame, WindowFunctionDefinition,
+    expr, Expr, ExprFunctionExt, ExprSchemable, ScalarUDF, WindowFrame,
+    WindowFunctionDefinition,
 };
 use sqlparser::ast::{
     DuplicateTreatment, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
     FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments,
     NullTreatment, ObjectName, OrderByExpr, Spanned, WindowType,
 };
+use std::collections::HashSet;
 
 /// Suggest a valid function based on an invalid input function name
 ///
@@ -269,7 +271,7 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         }
         // User-defined function (UDF) should have precedence
         if let Some(fm) = self.context_provider.get_function_meta(&name) {
-            let args = self.function_args_to_expr(args, schema, planner_context)?;
+            let args = self.udf_args_to_expr(&fm, args, schema, planner_context)?;
             return Ok(Expr::ScalarFunction(ScalarFunction::new_udf(fm, args)));
         }
 
@@ -626,6 +628,68 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
             .collect::<Result<Vec<Expr>>>()
     }
 
+    /// Plans the arguments of a scalar function, which may include lambda
+    /// functions. The types of the parameters of a lambda are determined by
+    /// the function from the types of the arguments preceding it.
+    fn udf_args_to_expr(
+        &self,
+        func: &ScalarUDF,
+        args: Vec<FunctionArg>,
+        schema: &DFSchema,
+        planner_context: &mut PlannerContext,
+    ) -> Result<Vec<Expr>> {
+        let mut exprs = Vec::with_capacity(args.len());
+        for (index, arg) in args.into_iter().enumerate() {
+            let lambda = match arg {
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(SQLExpr::Lambda(lambda))) => {
+                    lambda
+                }
+                arg => {
+                    exprs.push(self.sql_fn_arg_to_logical_expr(
+                        arg,
+                        schema,
+                        planner_context,
+                    )?);
+                    continue;
+                }
+            };
+
+            let arg_types = exprs
+                .iter()
+                .map(|e| e.get_type(schema))
+                .collect::<Result<Vec<_>>>()?;
+            let param_types = func.lambda_parameters(index, &arg_types)?;
+            let params = lambda
+                .params
+                .into_iter()
+                .map(|p| self.ident_normalizer.normalize(p))
+                .collect::<Vec<_>>();
+            if params.len() > param_types.len() {
+                return plan_err!(
+                    "Lambda function for argument {} of {}() can have at most {} parameter(s), got {}",
+                    index + 1,
+                    func.name(),
+                    param_types.len(),
+                    params.len()
+                );
+            }
+            if params.iter().collect::<HashSet<_>>().len() != params.len() {
+                return plan_err!(
+                    "Duplicate parameter name in lambda function: ({})",
+                    params.join(", ")
+                );
+            }
+
+            planner_context
+                .push_lambda_variables(params.iter().cloned().zip(param_types));
+            let body =
+                self.sql_expr_to_logical_expr(*lambda.body, schema, planner_context);
+            planner_context.pop_lambda_variables(params.len());
+            exprs.push(Expr::Lambda(Lambda::new(params, body?)));
+        }
+        Ok(exprs)
+    }
+
     pub(crate) fn check_unnest_arg(arg: &Expr, schema: &DFSchema) -> Result<()> {
         // Check argument type, array types are supported
         match arg.get_type(schema)? {
@@ -20,6 +20,7 @@ use datafusion_common::{
     internal_err, not_impl_err, plan_datafusion_err, plan_err, Column, DFSchema,
     DataFusionError, Result, Span, TableReference,
 };
+use datafusion_expr::expr::LambdaVariable;
 use datafusion_expr::planner::PlannerResult;
 use datafusion_expr::{Case, Expr};
 use sqlparser::ast::{CaseWhen, Expr as SQLExpr, Ident};
@@ -52,6 +53,16 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
             // identifier. (e.g. it is "foo.bar" not foo.bar)
             let normalize_ident = self.ident_normalizer.normalize(id);
 
+            // Parameters of enclosing lambda functions shadow columns
+            if let Some(data_type) =
+                planner_context.lambda_variable_type(normalize_ident.as_str())
+            {
+                return Ok(Expr::LambdaVariable(LambdaVariable::new(
+                    normalize_ident,
+                    data_type.clone(),
+                )));
+            }
+
             // Check for qualified field with unqualified name
             if let Ok((qualifier, _)) =
                 schema.qualified_field_with_unqualified_name(normalize_ident.as_str())
@@ -243,6 +243,86 @@ impl fmt::Display for CreateExternalTable {
     }
 }
 
+/// DataFusion extension DDL for `CREATE MATERIALIZED VIEW`
+///
+/// Syntax:
+///
+/// ```text
+/// CREATE [ OR REPLACE ] MATERIALIZED VIEW
+/// [ IF NOT EXISTS ]
+/// <VIEW_NAME>
+/// [ STORED AS <file_type> LOCATION <literal> ]
+/// [ OPTIONS (<key_value_list>) ]
+/// AS <query>
+/// ```
+///
+/// The content of the view is stored in memory unless a `LOCATION` is given.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateMaterializedView {
+    /// View name
+    pub name: ObjectName,
+    /// The query computing the content of the view
+    pub query: Box<Query>,
+    /// File type (Parquet, NDJSON, CSV, etc) of the stored content
+    pub file_type: Option<String>,
+    /// Directory storing the content
+    pub location: Option<String>,
+    /// File type specific options
+    pub options: Vec<(String, Value)>,
+    /// Option to replace the view if it already exists
+    pub or_replace: bool,
+    /// Option to not error if the view already exists
+    pub if_not_exists: bool,
+}
+
+impl fmt::Display for CreateMaterializedView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CREATE ")?;
+        if self.or_replace {
+            write!(f, "OR REPLACE ")?;
+        }
+        write!(f, "MATERIALIZED VIEW ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        if let Some(file_type) = &self.file_type {
+            write!(f, " STORED AS {file_type}")?;
+        }
+        if let Some(location) = &self.location {
+            write!(f, " LOCATION '{location}'")?;
+        }
+        if !self.options.is_empty() {
+            let opts: Vec<_> = self
+                .options
+                .iter()
+                .map(|(k, v)| format!("'{k}' {v}"))
+                .collect();
+            write!(f, " OPTIONS ({})", opts.join(", "))?;
+        }
+        write!(f, " AS {}", self.query)
+    }
+}
+
+/// DataFusion extension statement for `REFRESH MATERIALIZED VIEW`
+///
+/// Syntax:
+///
+/// ```text
+/// REFRESH MATERIALIZED VIEW <VIEW_NAME>
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RefreshMaterializedView {
+    /// View name
+    pub name: ObjectName,
+}
+
+impl fmt::Display for RefreshMaterializedView {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "REFRESH MATERIALIZED VIEW {}", self.name)
+    }
+}
+
 /// DataFusion SQL Statement.
 ///
 /// This can either be a [`Statement`] from [`sqlparser`] from a
@@ -258,6 +338,10 @@ pub enum Statement {
     CreateExternalTable(CreateExternalTable),
     /// Extension: `COPY TO`
     CopyTo(CopyToStatement),
+    /// Extension: `CREATE MATERIALIZED VIEW`
+    CreateMaterializedView(CreateMaterializedView),
+    /// Extension: `REFRESH MATERIALIZED VIEW`
+    RefreshMaterializedView(RefreshMaterializedView),
     /// EXPLAIN for extensions
     Explain(ExplainStatement),
 }
@@ -268,6 +352,8 @@ impl fmt::Display for Statement {
             Statement::Statement(stmt) => write!(f, "{stmt}"),
             Statement::CreateExternalTable(stmt) => write!(f, "{stmt}"),
             Statement::CopyTo(stmt) => write!(f, "{stmt}"),
+            Statement::CreateMaterializedView(stmt) => write!(f, "{stmt}"),
+            Statement::RefreshMaterializedView(stmt) => write!(f, "{stmt}"),
             Statement::Explain(stmt) => write!(f, "{stmt}"),
         }
     }
@@ -365,9 +451,10 @@ impl<'a> DFParserBuilder<'a> {
     pub fn build(self) -> Result<DFParser<'a>, DataFusionError> {
         let mut tokenizer = Tokenizer::new(self.dialect, self.sql);
         // Convert TokenizerError -> ParserError
-        let tokens = tokenizer
+        let mut tokens = tokenizer
             .tokenize_with_location()
             .map_err(ParserError::from)?;
+        insert_implicit_asof_match_conditions(&mut tokens);
 
         Ok(DFParser {
             parser: Parser::new(self.dialect)
@@ -381,6 +468,69 @@ impl<'a> DFParserBuilder<'a> {
     }
 }
 
+/// `sqlparser` requires `ASOF JOIN <relation>` to be followed by a
+/// `MATCH_CONDITION (..)` clause. DataFusion also accepts the match condition
+/// as the only inequality of the ON clause, so when the relation is directly
+/// followed by `ON`, a `MATCH_CONDITION (TRUE)` placeholder is inserted, which
+/// the SQL planner replaces by the inequality found in the ON clause.
+fn insert_implicit_asof_match_conditions(tokens: &mut Vec<TokenWithSpan>) {
+    let is_keyword = |token: &TokenWithSpan, keyword: Keyword| matches!(&token.token, Token::Word(w) if w.keyword == keyword);
+    let next_token = |tokens: &[TokenWithSpan], from: usize| {
+        (from..tokens.len()).find(|&i| !matches!(tokens[i].token, Token::Whitespace(_)))
+    };
+
+    let mut i = 0;
+    while i < tokens.len() {
+        let asof_join = is_keyword(&tokens[i], Keyword::ASOF)
+            && next_token(tokens, i + 1)
+                .is_some_and(|j| is_keyword(&tokens[j], Keyword::JOIN));
+        i += 1;
+        if !asof_join {
+            continue;
+        }
+
+        // Find the end of the relation, skipping parenthesized subqueries
+        let mut depth = 0usize;
+        let mut on_position = None;
+        for (j, token) in tokens.iter().enumerate().skip(i + 1) {
+            match &token.token {
+                Token::LParen => depth += 1,
+                Token::RParen if depth == 0 => break,
+                Token::RParen => depth -= 1,
+                Token::SemiColon | Token::EOF => break,
+                Token::Word(w) if depth == 0 => match w.keyword {
+                    Keyword::ON => {
+                        on_position = Some(j);
+                        break;
+                    }
+                    Keyword::MATCH_CONDITION
+                    | Keyword::USING
+                    | Keyword::JOIN
+                    | Keyword::WHERE
+                    | Keyword::GROUP
+                    | Keyword::ORDER
+                    | Keyword::LIMIT
+                    | Keyword::UNION => break,
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+
+        if let Some(j) = on_position {
+            let span = tokens[j].span;
+            let placeholder = [
+                Token::make_keyword("MATCH_CONDITION"),
+                Token::LParen,
+                Token::make_keyword("TRUE"),
+                Token::RParen,
+            ]
+            .map(|token| TokenWithSpan::new(token, span));
+            tokens.splice(j..j, placeholder);
+        }
+    }
+}
+
 impl<'a> DFParser<'a> {
     #[deprecated(since = "46.0.0", note = "DFParserBuilder")]
     pub fn new(sql: &'a str) -> Result<Self, DataFusionError> {
@@ -506,6 +656,11 @@ impl<'a> DFParser<'a> {
                         self.parser.next_token(); // EXPLAIN
                         self.parse_explain()
                     }
+                    // REFRESH is not a keyword of all the sqlparser versions
+                    _ if w.value.eq_ignore_ascii_case("REFRESH") => {
+                        self.parser.next_token(); // REFRESH
+                        self.parse_refresh()
+                    }
                     _ => {
                         // use sqlparser-rs parser
                         self.parse_and_handle_statement()
@@ -722,6 +877,16 @@ impl<'a> DFParser<'a> {
         Ok(Some(format))
     }
 
+    /// Parse a SQL `REFRESH MATERIALIZED VIEW` statement
+    pub fn parse_refresh(&mut self) -> Result<Statement, DataFusionError> {
+        self.parser
+            .expect_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW])?;
+        let name = self.parser.parse_object_name(true)?;
+        Ok(Statement::RefreshMaterializedView(
+            RefreshMaterializedView { name },
+        ))
+    }
+
     /// Parse a SQL `CREATE` statement handling `CREATE EXTERNAL TABLE`
     pub fn parse_create(&mut self) -> Result<Statement, DataFusionError> {
         if self.parser.parse_keyword(Keyword::EXTERNAL) {
@@ -729,6 +894,8 @@ impl<'a> DFParser<'a> {
         } else if self.parser.parse_keyword(Keyword::UNBOUNDED) {
             self.parser.expect_keyword(Keyword::EXTERNAL)?;
             self.parse_create_external_table(true)
+        } else if self.peek_materialized_view() {
+            self.parse_create_materialized_view()
         } else {
             Ok(Statement::Statement(Box::from(self.parser.parse_create()?)))
         }
@@ -873,6 +1040,84 @@ impl<'a> DFParser<'a> {
         })
     }
 
+    /// Returns true if the next tokens are `[OR REPLACE] MATERIALIZED VIEW`
+    fn peek_materialized_view(&self) -> bool {
+        let is_keyword = |n: usize, keyword: Keyword| {
+            matches!(
+                self.parser.peek_nth_token(n).token,
+                Token::Word(w) if w.keyword == keyword
+            )
+        };
+        let offset = if is_keyword(0, Keyword::OR) && is_keyword(1, Keyword::REPLACE) {
+            2
+        } else {
+            0
+        };
+        is_keyword(offset, Keyword::MATERIALIZED) && is_keyword(offset + 1, Keyword::VIEW)
+    }
+
+    fn parse_create_materialized_view(&mut self) -> Result<Statement, DataFusionError> {
+        let or_replace = self.parser.parse_keywords(&[Keyword::OR, Keyword::REPLACE]);
+        self.parser
+            .expect_keywords(&[Keyword::MATERIALIZED, Keyword::VIEW])?;
+        let if_not_exists =
+            self.parser
+                .parse_keywords(&[Keyword::IF, Keyword::NOT, Keyword::EXISTS]);
+        let name = self.parser.parse_object_name(true)?;
+
+        #[derive(Default)]
+        struct Builder {
+            file_type: Option<String>,
+            location: Option<String>,
+            options: Option<Vec<(String, Value)>>,
+        }
+        let mut builder = Builder::default();
+
+        while let Some(keyword) = self.parser.parse_one_of_keywords(&[
+            Keyword::STORED,
+            Keyword::LOCATION,
+            Keyword::OPTIONS,
+        ]) {
+            match keyword {
+                Keyword::STORED => {
+                    self.parser.expect_keyword(Keyword::AS)?;
+                    ensure_not_set(&builder.file_type, "STORED AS")?;
+                    builder.file_type = Some(self.parse_file_format()?);
+                }
+                Keyword::LOCATION => {
+                    ensure_not_set(&builder.location, "LOCATION")?;
+                    builder.location = Some(self.parser.parse_literal_string()?);
+                }
+                Keyword::OPTIONS => {
+                    ensure_not_set(&builder.options, "OPTIONS")?;
+                    builder.options = Some(self.parse_value_options()?);
+                }
+                _ => {
+                    unreachable!()
+                }
+            }
+        }
+        self.parser.expect_keyword(Keyword::AS)?;
+        let query = self.parser.parse_query()?;
+
+        // Validations: the files are only read back with a known file type
+        if builder.file_type.is_some() != builder.location.is_some() {
+            return parser_err!(
+                "STORED AS and LOCATION must be used together in CREATE MATERIALIZED VIEW statement"
+            );
+        }
+
+        Ok(Statement::CreateMaterializedView(CreateMaterializedView {
+            name,
+            query,
+            file_type: builder.file_type,
+            location: builder.location,
+            options: builder.options.unwrap_or_default(),
+            or_replace,
+            if_not_exists,
+        }))
+    }
+
     fn parse_create_external_table(
         &mut self,
         unbounded: bool,
@@ -1567,6 +1812,69 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn create_materialized_view() -> Result<(), DataFusionError> {
+        let Statement::Statement(query) = verified_stmt("SELECT 1") else {
+            panic!("Expected statement");
+        };
+        let SQLStatement::Query(query) = *query else {
+            panic!("Expected query");
+        };
+
+        let sql = "CREATE MATERIALIZED VIEW v AS SELECT 1";
+        let expected = Statement::CreateMaterializedView(CreateMaterializedView {
+            name: ObjectName::from(vec![Ident::from("v")]),
+            query: query.clone(),
+            file_type: None,
+            location: None,
+            options: vec![],
+            or_replace: false,
+            if_not_exists: false,
+        });
+        assert_eq!(verified_stmt(sql), expected);
+
+        let sql = "CREATE OR REPLACE MATERIALIZED VIEW v STORED AS PARQUET LOCATION 'foo/' OPTIONS ('format.compression' 'zstd') AS SELECT 1";
+        let expected = Statement::CreateMaterializedView(CreateMaterializedView {
+            name: ObjectName::from(vec![Ident::from("v")]),
+            query,
+            file_type: Some("PARQUET".to_string()),
+            location: Some("foo/".to_string()),
+            options: vec![(
+                "format.compression".to_string(),
+                Value::SingleQuotedString("zstd".to_string()),
+            )],
+            or_replace: true,
+            if_not_exists: false,
+        });
+        assert_eq!(verified_stmt(sql), expected);
+
+        expect_parse_error(
+            "CREATE MATERIALIZED VIEW v LOCATION 'foo/' AS SELECT 1",
+            "STORED AS and LOCATION must be used together",
+        );
+        expect_parse_error(
+            "CREATE MATERIALIZED VIEW v STORED AS CSV STORED AS CSV LOCATION 'foo/' AS SELECT 1",
+            "STORED AS specified more than once",
+        );
+
+        // other views are still parsed by sqlparser
+        let statement = verified_stmt("CREATE VIEW v AS SELECT 1");
+        assert!(matches!(statement, Statement::Statement(_)));
+        Ok(())
+    }
+
+    #[test]
+    fn refresh_materialized_view() -> Result<(), DataFusionError> {
+        let sql = "REFRESH MATERIALIZED VIEW staging.v";
+        let expected = Statement::RefreshMaterializedView(RefreshMaterializedView {
+            name: ObjectName::from(vec![Ident::from("staging"), Ident::from("v")]),
+        });
+        assert_eq!(verified_stmt(sql), expected);
+
+        expect_parse_error("REFRESH VIEW v", "Expected: MATERIALIZED");
+        Ok(())
+    }
+
     #[test]
     fn copy_to_table_to_table() -> Result<(), DataFusionError> {
         // positive case
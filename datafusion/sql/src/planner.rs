@@ -267,6 +267,8 @@ pub struct PlannerContext {
     outer_from_schema: Option<DFSchemaRef>,
     /// The query schema defined by the table
     create_table_schema: Option<DFSchemaRef>,
+    /// The parameters of the enclosing lambda functions, innermost last
+    lambda_variables: Vec<(String, DataType)>,
 }
 
 impl Default for PlannerContext {
@@ -284,6 +286,7 @@ impl PlannerContext {
             outer_query_schema: None,
             outer_from_schema: None,
             create_table_schema: None,
+            lambda_variables: vec![],
         }
     }
 
@@ -374,6 +377,31 @@ impl PlannerContext {
     pub(super) fn remove_cte(&mut self, cte_name: &str) {
         self.ctes.remove(cte_name);
     }
+
+    /// Brings the parameters of a lambda function into scope, shadowing
+    /// parameters of enclosing lambdas with the same name
+    pub(super) fn push_lambda_variables(
+        &mut self,
+        variables: impl IntoIterator<Item = (String, DataType)>,
+    ) {
+        self.lambda_variables.extend(variables);
+    }
+
+    /// Removes the `n` innermost lambda parameters from scope
+    pub(super) fn pop_lambda_variables(&mut self, n: usize) {
+        let len = self.lambda_variables.len().saturating_sub(n);
+        self.lambda_variables.truncate(len);
+    }
+
+    /// Return the type of the lambda parameter with the specified name, if in
+    /// scope
+    pub fn lambda_variable_type(&self, name: &str) -> Option<&DataType> {
+        self.lambda_variables
+            .iter()
+            .rev()
+            .find(|(n, _)| n == name)
+            .map(|(_, data_type)| data_type)
+    }
 }
 
 /// SQL query planner and binder
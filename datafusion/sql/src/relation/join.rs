@@ -16,10 +16,17 @@
 // under the License.
 
 use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
-use datafusion_common::{not_impl_err, plan_datafusion_err, Column, Result};
-use datafusion_expr::{JoinType, LogicalPlan, LogicalPlanBuilder};
+use datafusion_common::{
+    internal_err, not_impl_err, plan_datafusion_err, plan_err, Column, Result,
+};
+use datafusion_expr::logical_plan::Join as LogicalJoin;
+use datafusion_expr::utils::split_conjunction_owned;
+use datafusion_expr::{
+    BinaryExpr, Expr, JoinType, LogicalPlan, LogicalPlanBuilder, Operator,
+};
 use sqlparser::ast::{
-    Join, JoinConstraint, JoinOperator, ObjectName, TableFactor, TableWithJoins,
+    Expr as SQLExpr, Join, JoinConstraint, JoinOperator, ObjectName, TableFactor,
+    TableWithJoins, Value, ValueWithSpan,
 };
 use std::collections::HashSet;
 
@@ -95,6 +102,16 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
             JoinOperator::FullOuter(constraint) => {
                 self.parse_join(left, right, constraint, JoinType::Full, planner_context)
             }
+            JoinOperator::AsOf {
+                match_condition,
+                constraint,
+            } => self.parse_asof_join(
+                left,
+                right,
+                match_condition,
+                constraint,
+                planner_context,
+            ),
             JoinOperator::CrossJoin => self.parse_cross_join(left, right),
             other => not_impl_err!("Unsupported JOIN operator {other:?}"),
         }
@@ -108,6 +125,122 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         LogicalPlanBuilder::from(left).cross_join(right)?.build()
     }
 
+    /// Plans `left ASOF JOIN right MATCH_CONDITION (..) [ON .. | USING (..)]`.
+    ///
+    /// The ON / USING clause may only contain equality conditions, which become
+    /// the join keys, while the match condition becomes the join filter. When
+    /// `MATCH_CONDITION` is omitted (the parser then supplies `TRUE`), the ON
+    /// clause must contain exactly one inequality, which is used as the match
+    /// condition.
+    fn parse_asof_join(
+        &self,
+        left: LogicalPlan,
+        right: LogicalPlan,
+        match_condition: SQLExpr,
+        constraint: JoinConstraint,
+        planner_context: &mut PlannerContext,
+    ) -> Result<LogicalPlan> {
+        let join_schema = left.schema().join(right.schema())?;
+        let match_condition = match match_condition {
+            SQLExpr::Value(ValueWithSpan {
+                value: Value::Boolean(true),
+                ..
+            }) => None,
+            match_condition => {
+                Some(self.sql_to_expr(match_condition, &join_schema, planner_context)?)
+            }
+        };
+        if match_condition.is_none() && !matches!(constraint, JoinConstraint::On(_)) {
+            return plan_err!(
+                "ASOF join requires a MATCH_CONDITION or an inequality condition in the ON clause"
+            );
+        }
+
+        let join = match constraint {
+            JoinConstraint::On(sql_expr) => {
+                let expr = self.sql_to_expr(sql_expr, &join_schema, planner_context)?;
+                let (mut left_keys, mut right_keys, mut others) =
+                    (vec![], vec![], vec![]);
+                for expr in split_conjunction_owned(expr) {
+                    match expr {
+                        Expr::BinaryExpr(BinaryExpr {
+                            left,
+                            op: Operator::Eq,
+                            right,
+                        }) => {
+                            left_keys.push(*left);
+                            right_keys.push(*right);
+                        }
+                        other => others.push(other),
+                    }
+                }
+                let match_condition = match match_condition {
+                    Some(match_condition) => {
+                        if let Some(other) = others.first() {
+                            return not_impl_err!(
+                                "ASOF join only supports equality conditions in the ON clause, got {other}"
+                            );
+                        }
+                        match_condition
+                    }
+                    None if others.len() == 1 => others.remove(0),
+                    None => {
+                        return plan_err!(
+                            "ASOF join without MATCH_CONDITION requires exactly one inequality condition in the ON clause, got {}",
+                            others.len()
+                        );
+                    }
+                };
+                LogicalPlanBuilder::from(left)
+                    .join_with_expr_keys(
+                        right,
+                        JoinType::AsOf,
+                        (left_keys, right_keys),
+                        Some(match_condition),
+                    )?
+                    .build()?
+            }
+            JoinConstraint::Using(_) => {
+                let LogicalPlan::Join(join) = self.parse_join(
+                    left,
+                    right,
+                    constraint,
+                    JoinType::AsOf,
+                    planner_context,
+                )?
+                else {
+                    return internal_err!("Expected USING constraint to plan a join");
+                };
+                if let Some(filter) = join.filter {
+                    return not_impl_err!(
+                        "ASOF join does not support USING columns that can not be hashed, got {filter}"
+                    );
+                }
+                LogicalPlan::Join(LogicalJoin::try_new(
+                    join.left,
+                    join.right,
+                    join.on,
+                    match_condition,
+                    JoinType::AsOf,
+                    join.join_constraint,
+                    join.null_equality,
+                )?)
+            }
+            JoinConstraint::None => LogicalPlanBuilder::from(left)
+                .join_on(right, JoinType::AsOf, match_condition)?
+                .build()?,
+            JoinConstraint::Natural => {
+                return not_impl_err!("NATURAL ASOF JOIN is not supported");
+            }
+        };
+
+        // Validate the shape of the match condition up front
+        if let LogicalPlan::Join(join) = &join {
+            join.asof_match_condition()?;
+        }
+        Ok(join)
+    }
+
     fn parse_join(
         &self,
         left: LogicalPlan,
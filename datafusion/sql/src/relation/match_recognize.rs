@@ -0,0 +1,662 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+use std::sync::Arc;
+
+use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
+use crate::utils::normalize_ident;
+
+use arrow::datatypes::{DataType, Field};
+use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
+use datafusion_common::{
+    internal_err, not_impl_err, plan_err, DFSchema, DataFusionError, Result, ScalarValue,
+    TableReference,
+};
+use datafusion_expr::expr::ScalarFunction;
+use datafusion_expr::utils::find_aggregate_exprs;
+use datafusion_expr::{
+    lit, AfterMatchSkip, Expr, ExprSchemable, LogicalPlan, LogicalPlanBuilder,
+    MatchAggregate, MatchMeasure, MatchNavigation, MatchRecognize, PatternDefinition,
+    RowPattern, RowsPerMatch, ScalarUDF,
+};
+use sqlparser::ast::{
+    visit_expressions_mut, AfterMatchSkip as SQLAfterMatchSkip, EmptyMatchesMode,
+    Expr as SQLExpr, Function as SQLFunction, FunctionArg, FunctionArgExpr,
+    FunctionArguments, Ident, MatchRecognizePattern, MatchRecognizeSymbol, Measure,
+    ObjectName, OrderByExpr, RepetitionQuantifier, RowsPerMatch as SQLRowsPerMatch,
+    SymbolDefinition, VisitMut, VisitorMut,
+};
+
+impl<S: ContextProvider> SqlToRel<'_, S> {
+    /// Plan `input MATCH_RECOGNIZE(...)` as a [`MatchRecognize`] node
+    /// computing the aggregates, `MATCH_NUMBER()` and `CLASSIFIER()` calls of
+    /// the measures, followed by a projection computing the measures from
+    /// them.
+    ///
+    /// A column qualified by a pattern variable, such as `A.price`, refers to
+    /// the last row mapped to that variable, and so does a bare column in
+    /// `ONE ROW PER MATCH` mode. An aggregate whose arguments are qualified
+    /// by a pattern variable only aggregates the rows mapped to that
+    /// variable.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn plan_match_recognize(
+        &self,
+        input: LogicalPlan,
+        partition_by: Vec<SQLExpr>,
+        order_by: Vec<OrderByExpr>,
+        measures: Vec<Measure>,
+        rows_per_match: Option<SQLRowsPerMatch>,
+        after_match_skip: Option<SQLAfterMatchSkip>,
+        pattern: MatchRecognizePattern,
+        symbols: Vec<SymbolDefinition>,
+        planner_context: &mut PlannerContext,
+    ) -> Result<LogicalPlan> {
+        let rows_per_match = match rows_per_match {
+            None | Some(SQLRowsPerMatch::OneRow) => RowsPerMatch::OneRow,
+            Some(SQLRowsPerMatch::AllRows(None | Some(EmptyMatchesMode::Omit))) => {
+                RowsPerMatch::AllRows
+            }
+            Some(SQLRowsPerMatch::AllRows(Some(mode))) => {
+                return not_impl_err!(
+                    "MATCH_RECOGNIZE with ALL ROWS PER MATCH {mode} is not supported yet"
+                )
+            }
+        };
+        let after_match_skip = match after_match_skip {
+            None | Some(SQLAfterMatchSkip::PastLastRow) => AfterMatchSkip::PastLastRow,
+            Some(SQLAfterMatchSkip::ToNextRow) => AfterMatchSkip::ToNextRow,
+            Some(SQLAfterMatchSkip::ToFirst(symbol)) => {
+                AfterMatchSkip::ToFirst(self.ident_normalizer.normalize(symbol))
+            }
+            Some(SQLAfterMatchSkip::ToLast(symbol)) => {
+                AfterMatchSkip::ToLast(self.ident_normalizer.normalize(symbol))
+            }
+        };
+        let pattern = self.sql_to_row_pattern(pattern)?;
+        let symbol_names = pattern
+            .symbols()
+            .into_iter()
+            .map(String::from)
+            .collect::<Vec<_>>();
+
+        let input_schema = Arc::clone(input.schema());
+        let partition_by = partition_by
+            .into_iter()
+            .map(|e| self.sql_expr_to_logical_expr(e, &input_schema, planner_context))
+            .collect::<Result<Vec<_>>>()?;
+        let order_by = self.order_by_to_sort_expr(
+            order_by,
+            &input_schema,
+            planner_context,
+            false,
+            None,
+        )?;
+
+        let mut definitions: Vec<PatternDefinition> = vec![];
+        for SymbolDefinition { symbol, definition } in symbols {
+            let symbol = self.ident_normalizer.normalize(symbol);
+            if definitions.iter().any(|d| d.symbol == symbol) {
+                return plan_err!("Pattern variable {symbol} is defined more than once");
+            }
+            let predicate = self.plan_pattern_definition(
+                &symbol,
+                definition,
+                &symbol_names,
+                &input_schema,
+                planner_context,
+            )?;
+            definitions.push(PatternDefinition { symbol, predicate });
+        }
+
+        let mut rewriter = MeasureRewriter {
+            planner: self,
+            planner_context,
+            input_schema: &input_schema,
+            symbols: &symbol_names,
+            mode: RewriteMode::Measures(rows_per_match),
+            measures: vec![],
+            navigations: vec![],
+        };
+        let mut rewritten = vec![];
+        for Measure { mut expr, alias } in measures {
+            if let ControlFlow::Break(e) = expr.visit(&mut rewriter) {
+                return Err(e);
+            }
+            rewritten.push((expr, alias));
+        }
+        let MeasureRewriter {
+            planner_context,
+            measures: primitives,
+            ..
+        } = rewriter;
+
+        // ONE ROW PER MATCH outputs the partition columns, ALL ROWS PER MATCH
+        // the input columns
+        let num_leading_columns = match rows_per_match {
+            RowsPerMatch::OneRow => partition_by.len(),
+            RowsPerMatch::AllRows => input_schema.fields().len(),
+        };
+        let plan = LogicalPlan::MatchRecognize(MatchRecognize::try_new(
+            Arc::new(input),
+            partition_by,
+            order_by,
+            primitives,
+            rows_per_match,
+            after_match_skip,
+            pattern,
+            definitions,
+        )?);
+        let schema = Arc::clone(plan.schema());
+        let mut exprs = schema
+            .columns()
+            .into_iter()
+            .take(num_leading_columns)
+            .map(Expr::Column)
+            .collect::<Vec<_>>();
+        for (expr, alias) in rewritten {
+            let expr = self.sql_expr_to_logical_expr(expr, &schema, planner_context)?;
+            exprs.push(expr.alias(self.ident_normalizer.normalize(alias)));
+        }
+        LogicalPlanBuilder::from(plan).project(exprs)?.build()
+    }
+
+    fn sql_to_row_pattern(&self, pattern: MatchRecognizePattern) -> Result<RowPattern> {
+        Ok(match pattern {
+            MatchRecognizePattern::Symbol(MatchRecognizeSymbol::Named(symbol)) => {
+                RowPattern::Symbol(self.ident_normalizer.normalize(symbol))
+            }
+            MatchRecognizePattern::Symbol(MatchRecognizeSymbol::Start) => {
+                RowPattern::Start
+            }
+            MatchRecognizePattern::Symbol(MatchRecognizeSymbol::End) => RowPattern::End,
+            MatchRecognizePattern::Exclude(_) => {
+                return not_impl_err!(
+                    "MATCH_RECOGNIZE pattern exclusion is not supported yet"
+                )
+            }
+            MatchRecognizePattern::Permute(_) => {
+                return not_impl_err!("MATCH_RECOGNIZE PERMUTE is not supported yet")
+            }
+            MatchRecognizePattern::Concat(patterns) => RowPattern::Concat(
+                patterns
+                    .into_iter()
+                    .map(|p| self.sql_to_row_pattern(p))
+                    .collect::<Result<_>>()?,
+            ),
+            MatchRecognizePattern::Group(pattern) => self.sql_to_row_pattern(*pattern)?,
+            MatchRecognizePattern::Alternation(patterns) => RowPattern::Alternation(
+                patterns
+                    .into_iter()
+                    .map(|p| self.sql_to_row_pattern(p))
+                    .collect::<Result<_>>()?,
+            ),
+            MatchRecognizePattern::Repetition(pattern, quantifier) => {
+                let (min, max) = match quantifier {
+                    RepetitionQuantifier::ZeroOrMore => (0, None),
+                    RepetitionQuantifier::OneOrMore => (1, None),
+                    RepetitionQuantifier::AtMostOne => (0, Some(1)),
+                    RepetitionQuantifier::Exactly(n) => (n, Some(n)),
+                    RepetitionQuantifier::AtLeast(n) => (n, None),
+                    RepetitionQuantifier::AtMost(n) => (0, Some(n)),
+                    RepetitionQuantifier::Range(min, max) => {
+                        if max < min {
+                            return plan_err!(
+                                "Invalid pattern quantifier {{{min},{max}}}: the maximum is smaller than the minimum"
+                            );
+                        }
+                        (min, Some(max))
+                    }
+                };
+                RowPattern::Repetition {
+                    pattern: Box::new(self.sql_to_row_pattern(*pattern)?),
+                    min,
+                    max,
+                }
+            }
+        })
+    }
+
+    /// Plan the predicate of pattern variable `symbol`.
+    ///
+    /// Columns may be qualified by `symbol` itself, and `PREV(expr [, offset])`
+    /// and `NEXT(expr [, offset])` evaluate `expr` on a preceding or following
+    /// row of the partition. Columns qualified by other pattern variables,
+    /// `FIRST`, `LAST` and aggregates refer to the rows of the match in
+    /// progress as in the measures, the current row being mapped to `symbol`.
+    fn plan_pattern_definition(
+        &self,
+        symbol: &str,
+        mut definition: SQLExpr,
+        symbols: &[String],
+        input_schema: &DFSchema,
+        planner_context: &mut PlannerContext,
+    ) -> Result<Expr> {
+        let mut rewriter = MeasureRewriter {
+            planner: self,
+            planner_context,
+            input_schema,
+            symbols,
+            mode: RewriteMode::Definition(symbol),
+            measures: vec![],
+            navigations: vec![],
+        };
+        if let ControlFlow::Break(e) = definition.visit(&mut rewriter) {
+            return Err(e);
+        }
+        let MeasureRewriter {
+            planner_context,
+            measures,
+            navigations,
+            ..
+        } = rewriter;
+
+        // Plan the navigations and the references to the match in progress
+        // separately and refer to them through placeholder columns while
+        // planning the predicate
+        let mut fields = vec![];
+        let mut replacements = HashMap::new();
+        for (i, (navigation, arg, offset)) in navigations.into_iter().enumerate() {
+            let arg =
+                self.sql_expr_to_logical_expr(arg, input_schema, planner_context)?;
+            if !find_aggregate_exprs([&arg]).is_empty() {
+                return not_impl_err!(
+                    "Aggregate functions in {} are not supported yet",
+                    navigation.name()
+                );
+            }
+            let offset = match offset {
+                None => 1,
+                Some(offset) => match self.sql_expr_to_logical_expr(
+                    offset,
+                    &DFSchema::empty(),
+                    planner_context,
+                )? {
+                    Expr::Literal(ScalarValue::Int64(Some(offset)), _) if offset >= 0 => {
+                        offset
+                    }
+                    offset => {
+                        return plan_err!(
+                            "The offset of {} must be a non-negative integer literal, got {offset}",
+                            navigation.name()
+                        )
+                    }
+                },
+            };
+            let name = format!("__match_recognize_nav_{i}");
+            fields.push(Field::new(&name, arg.get_type(input_schema)?, true));
+            replacements.insert(
+                name,
+                Expr::ScalarFunction(ScalarFunction::new_udf(
+                    navigation,
+                    vec![arg, lit(offset)],
+                )),
+            );
+        }
+        for measure in measures {
+            fields.push(measure.to_field(input_schema)?);
+            let name = measure.name();
+            let MatchMeasure::Aggregate { expr, symbol } = measure else {
+                return internal_err!(
+                    "MATCH_RECOGNIZE DEFINE can only refer to aggregates, got {measure}"
+                );
+            };
+            replacements.insert(name, MatchAggregate::call(expr, symbol));
+        }
+        let schema = input_schema.join(&DFSchema::from_unqualified_fields(
+            fields.into(),
+            HashMap::new(),
+        )?)?;
+        let predicate = self
+            .sql_expr_to_logical_expr(definition, &schema, planner_context)?
+            .transform(|expr| match expr {
+                Expr::Column(column) if column.relation.is_none() => {
+                    match replacements.get(&column.name) {
+                        Some(replacement) => Ok(Transformed::yes(replacement.clone())),
+                        None => Ok(Transformed::no(Expr::Column(column))),
+                    }
+                }
+                _ => Ok(Transformed::no(expr)),
+            })
+            .data()?;
+
+        let data_type = predicate.get_type(input_schema)?;
+        if data_type != DataType::Boolean {
+            return plan_err!(
+                "The definition of pattern variable {symbol} must be a boolean expression, got {data_type}"
+            );
+        }
+        Ok(predicate)
+    }
+}
+
+/// Where a [`MeasureRewriter`] rewrites expressions
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum RewriteMode<'a> {
+    /// The `MEASURES` clause
+    Measures(RowsPerMatch),
+    /// The `DEFINE` predicate of the given pattern variable
+    Definition(&'a str),
+}
+
+/// Replaces the aggregates, `MATCH_NUMBER()`, `CLASSIFIER()` and
+/// symbol-qualified columns of a measure with identifiers of the
+/// corresponding [`MatchMeasure`] columns.
+///
+/// In a `DEFINE` predicate, it also strips the qualifier of the defined
+/// pattern variable from columns and replaces `PREV` and `NEXT` calls with
+/// placeholder identifiers, collecting the navigation function, its argument
+/// and its offset.
+struct MeasureRewriter<'a, 'b, S: ContextProvider> {
+    planner: &'a SqlToRel<'b, S>,
+    planner_context: &'a mut PlannerContext,
+    input_schema: &'a DFSchema,
+    symbols: &'a [String],
+    mode: RewriteMode<'a>,
+    /// The distinct measures found so far
+    measures: Vec<MatchMeasure>,
+    /// The navigations found so far
+    navigations: Vec<(Arc<ScalarUDF>, SQLExpr, Option<SQLExpr>)>,
+}
+
+impl<S: ContextProvider> MeasureRewriter<'_, '_, S> {
+    /// The name of the clause being rewritten, for error messages
+    fn clause(&self) -> &'static str {
+        match self.mode {
+            RewriteMode::Measures(_) => "MEASURES",
+            RewriteMode::Definition(_) => "DEFINE",
+        }
+    }
+
+    /// Rewrites the expressions of a `DEFINE` predicate that refer to the
+    /// current row, returning whether `expr` was rewritten
+    fn rewrite_definition(&mut self, expr: &mut SQLExpr, symbol: &str) -> Result<bool> {
+        match expr {
+            SQLExpr::CompoundIdentifier(idents) if idents.len() >= 2 => {
+                let qualifier =
+                    self.planner.ident_normalizer.normalize(idents[0].clone());
+                if qualifier != symbol {
+                    return Ok(false);
+                }
+                *expr = strip_qualifier(idents);
+                Ok(true)
+            }
+            SQLExpr::Function(function) => {
+                let navigation = match function_name(function).as_deref() {
+                    Some("prev") => MatchNavigation::prev(),
+                    Some("next") => MatchNavigation::next(),
+                    _ => return Ok(false),
+                };
+                let (mut arg, offset) = match unnamed_function_args(function).as_slice() {
+                    [arg] => ((*arg).clone(), None),
+                    [arg, offset] => ((*arg).clone(), Some((*offset).clone())),
+                    _ => {
+                        return plan_err!(
+                            "{} expects an expression and an optional offset",
+                            function.name
+                        )
+                    }
+                };
+                let result = visit_expressions_mut(&mut arg, |arg| {
+                    let SQLExpr::CompoundIdentifier(idents) = arg else {
+                        return ControlFlow::Continue(());
+                    };
+                    if idents.len() < 2 {
+                        return ControlFlow::Continue(());
+                    }
+                    let qualifier =
+                        self.planner.ident_normalizer.normalize(idents[0].clone());
+                    if qualifier == symbol {
+                        *arg = strip_qualifier(idents);
+                    } else if self.symbols.contains(&qualifier) {
+                        return ControlFlow::Break(qualifier);
+                    }
+                    ControlFlow::Continue(())
+                });
+                if let ControlFlow::Break(qualifier) = result {
+                    return not_impl_err!(
+                        "{} of a column of pattern variable {qualifier} in the definition of {symbol} is not supported yet",
+                        function.name
+                    );
+                }
+                *expr = SQLExpr::Identifier(Ident::with_quote(
+                    '"',
+                    format!("__match_recognize_nav_{}", self.navigations.len()),
+                ));
+                self.navigations.push((
+                    Arc::new(ScalarUDF::from(navigation)),
+                    arg,
+                    offset,
+                ));
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    fn rewrite(&mut self, expr: &SQLExpr) -> Result<Option<MatchMeasure>> {
+        match expr {
+            SQLExpr::Function(function) => {
+                let Some(name) = function_name(function) else {
+                    return Ok(None);
+                };
+                match name.as_str() {
+                    "match_number" | "classifier" => {
+                        if matches!(self.mode, RewriteMode::Definition(_)) {
+                            return not_impl_err!(
+                                "{name} in MATCH_RECOGNIZE DEFINE is not supported yet"
+                            );
+                        }
+                        if !unnamed_function_args(function).is_empty() {
+                            return plan_err!("{name} does not take arguments");
+                        }
+                        Ok(Some(if name == "match_number" {
+                            MatchMeasure::MatchNumber
+                        } else {
+                            MatchMeasure::Classifier
+                        }))
+                    }
+                    "prev" | "next" => not_impl_err!(
+                        "{name} in MATCH_RECOGNIZE MEASURES is not supported yet"
+                    ),
+                    _ => {
+                        let name = match name.as_str() {
+                            "first" => "first_value",
+                            "last" => "last_value",
+                            name => name,
+                        };
+                        if self
+                            .planner
+                            .context_provider
+                            .get_aggregate_meta(name)
+                            .is_none()
+                        {
+                            return Ok(None);
+                        }
+                        if function.over.is_some() {
+                            return plan_err!(
+                                "Window functions are not allowed in MATCH_RECOGNIZE {}",
+                                self.clause()
+                            );
+                        }
+                        let mut function = function.clone();
+                        function.name = ObjectName::from(vec![Ident::new(name)]);
+                        let symbol = self.strip_symbol_qualifiers(&mut function)?;
+                        let expr = self.planner.sql_expr_to_logical_expr(
+                            SQLExpr::Function(function),
+                            self.input_schema,
+                            self.planner_context,
+                        )?;
+                        Ok(Some(MatchMeasure::Aggregate { expr, symbol }))
+                    }
+                }
+            }
+            SQLExpr::CompoundIdentifier(idents) if idents.len() >= 2 => {
+                let qualifier =
+                    self.planner.ident_normalizer.normalize(idents[0].clone());
+                if self.symbols.contains(&qualifier) {
+                    self.last_value(strip_qualifier(idents), Some(qualifier))
+                        .map(Some)
+                } else if self.mode == RewriteMode::Measures(RowsPerMatch::OneRow) {
+                    self.last_value(expr.clone(), None).map(Some)
+                } else {
+                    Ok(None)
+                }
+            }
+            SQLExpr::Identifier(_)
+                if self.mode == RewriteMode::Measures(RowsPerMatch::OneRow) =>
+            {
+                self.last_value(expr.clone(), None).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// `last_value(column)` over the rows mapped to `symbol`
+    fn last_value(
+        &mut self,
+        column: SQLExpr,
+        symbol: Option<String>,
+    ) -> Result<MatchMeasure> {
+        let Some(last_value) = self
+            .planner
+            .context_provider
+            .get_aggregate_meta("last_value")
+        else {
+            return plan_err!(
+                "MATCH_RECOGNIZE requires the last_value aggregate function to be registered"
+            );
+        };
+        let arg = self.planner.sql_expr_to_logical_expr(
+            column,
+            self.input_schema,
+            self.planner_context,
+        )?;
+        Ok(MatchMeasure::Aggregate {
+            expr: last_value.call(vec![arg]),
+            symbol,
+        })
+    }
+
+    /// Removes the pattern variable qualifiers from the arguments of an
+    /// aggregate, returning the pattern variable if there is one
+    fn strip_symbol_qualifiers(
+        &self,
+        function: &mut SQLFunction,
+    ) -> Result<Option<String>> {
+        let mut found = vec![];
+        if let FunctionArguments::List(list) = &mut function.args {
+            for arg in &mut list.args {
+                if let FunctionArg::Unnamed(arg) = arg {
+                    let FunctionArgExpr::QualifiedWildcard(name) = arg else {
+                        continue;
+                    };
+                    let qualifier =
+                        self.planner.object_name_to_table_reference(name.clone())?;
+                    if let TableReference::Bare { table } = qualifier {
+                        if self.symbols.iter().any(|s| **s == *table) {
+                            found.push(table.to_string());
+                            *arg = FunctionArgExpr::Wildcard;
+                        }
+                    }
+                }
+            }
+        }
+        let _ = visit_expressions_mut(function, |expr| {
+            if let SQLExpr::CompoundIdentifier(idents) = expr {
+                if idents.len() >= 2 {
+                    let qualifier =
+                        self.planner.ident_normalizer.normalize(idents[0].clone());
+                    if self.symbols.contains(&qualifier) {
+                        *expr = strip_qualifier(idents);
+                        found.push(qualifier);
+                    }
+                }
+            }
+            ControlFlow::<()>::Continue(())
+        });
+        found.sort();
+        found.dedup();
+        match found.len() {
+            0 => Ok(None),
+            1 => Ok(found.pop()),
+            _ => plan_err!(
+                "An aggregate in MATCH_RECOGNIZE {} can refer to only one pattern variable, got {}",
+                self.clause(),
+                found.join(", ")
+            ),
+        }
+    }
+}
+
+impl<S: ContextProvider> VisitorMut for MeasureRewriter<'_, '_, S> {
+    type Break = DataFusionError;
+
+    fn pre_visit_expr(&mut self, expr: &mut SQLExpr) -> ControlFlow<Self::Break> {
+        if let RewriteMode::Definition(symbol) = self.mode {
+            match self.rewrite_definition(expr, symbol) {
+                Ok(true) => return ControlFlow::Continue(()),
+                Ok(false) => {}
+                Err(e) => return ControlFlow::Break(e),
+            }
+        }
+        match self.rewrite(expr) {
+            Ok(Some(measure)) => {
+                let name = measure.name();
+                if !self.measures.contains(&measure) {
+                    self.measures.push(measure);
+                }
+                *expr = SQLExpr::Identifier(Ident::with_quote('"', name));
+                ControlFlow::Continue(())
+            }
+            Ok(None) => ControlFlow::Continue(()),
+            Err(e) => ControlFlow::Break(e),
+        }
+    }
+}
+
+/// The normalized name of a function with an unqualified name
+fn function_name(function: &SQLFunction) -> Option<String> {
+    match function.name.0.as_slice() {
+        [part] => part.as_ident().map(|ident| normalize_ident(ident.clone())),
+        _ => None,
+    }
+}
+
+/// The arguments of a function, excluding named arguments and wildcards
+fn unnamed_function_args(function: &SQLFunction) -> Vec<&SQLExpr> {
+    match &function.args {
+        FunctionArguments::List(list) => list
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                FunctionArg::Unnamed(FunctionArgExpr::Expr(expr)) => Some(expr),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// `a.b.c` without its first part
+fn strip_qualifier(idents: &[Ident]) -> SQLExpr {
+    match &idents[1..] {
+        [ident] => SQLExpr::Identifier(ident.clone()),
+        idents => SQLExpr::CompoundIdentifier(idents.to_vec()),
+    }
+}
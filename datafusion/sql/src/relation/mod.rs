@@ -22,6 +22,7 @@ use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
 use datafusion_common::tree_node::{Transformed, TreeNode};
 use datafusion_common::{
     not_impl_err, plan_err, DFSchema, Diagnostic, Result, Span, Spans, TableReference,
+    UnnestOrdinality,
 };
 use datafusion_expr::builder::subquery_alias;
 use datafusion_expr::{expr::Unnest, Expr, LogicalPlan, LogicalPlanBuilder};
@@ -29,6 +30,8 @@ use datafusion_expr::{Subquery, SubqueryAlias};
 use sqlparser::ast::{FunctionArg, FunctionArgExpr, Spanned, TableFactor};
 
 mod join;
+mod match_recognize;
+mod pivot;
 
 impl<S: ContextProvider> SqlToRel<'_, S> {
     /// Create a `LogicalPlan` that scans the named relation
@@ -118,13 +121,25 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
             TableFactor::UNNEST {
                 alias,
                 array_exprs,
-                with_offset: false,
-                with_offset_alias: None,
+                with_offset,
+                with_offset_alias,
                 with_ordinality,
             } => {
-                if with_ordinality {
-                    return not_impl_err!("UNNEST with ordinality is not supported yet");
-                }
+                let ordinality = match (with_ordinality, with_offset) {
+                    (true, true) => {
+                        return plan_err!(
+                            "UNNEST can not have both WITH ORDINALITY and WITH OFFSET"
+                        );
+                    }
+                    (true, false) => Some(UnnestOrdinality::new("ordinality", 1)),
+                    (false, true) => {
+                        let name = with_offset_alias
+                            .map(|alias| self.ident_normalizer.normalize(alias))
+                            .unwrap_or_else(|| "offset".to_string());
+                        Some(UnnestOrdinality::new(name, 0))
+                    }
+                    (false, false) => None,
+                };
 
                 // Unnest table factor has empty input
                 let schema = DFSchema::empty();
@@ -146,14 +161,13 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 if unnest_exprs.is_empty() {
                     return plan_err!("UNNEST must have at least one argument");
                 }
-                let logical_plan = self.try_process_unnest(input, unnest_exprs)?;
+                let logical_plan = self.try_process_unnest_with_ordinality(
+                    input,
+                    unnest_exprs,
+                    ordinality,
+                )?;
                 (logical_plan, alias)
             }
-            TableFactor::UNNEST { .. } => {
-                return not_impl_err!(
-                    "UNNEST table factor with offset is not supported yet"
-                );
-            }
             TableFactor::Function {
                 name, args, alias, ..
             } => {
@@ -183,6 +197,63 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                         .build()?;
                 (plan, alias)
             }
+            TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+                ..
+            } => {
+                let input = self.create_relation(*table, planner_context)?;
+                let plan = self.plan_pivot(
+                    input,
+                    aggregate_functions,
+                    value_column,
+                    value_source,
+                    default_on_null,
+                    planner_context,
+                )?;
+                (plan, alias)
+            }
+            TableFactor::MatchRecognize {
+                table,
+                partition_by,
+                order_by,
+                measures,
+                rows_per_match,
+                after_match_skip,
+                pattern,
+                symbols,
+                alias,
+            } => {
+                let input = self.create_relation(*table, planner_context)?;
+                let plan = self.plan_match_recognize(
+                    input,
+                    partition_by,
+                    order_by,
+                    measures,
+                    rows_per_match,
+                    after_match_skip,
+                    pattern,
+                    symbols,
+                    planner_context,
+                )?;
+                (plan, alias)
+            }
+            TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                alias,
+                ..
+            } => {
+                let input = self.create_relation(*table, planner_context)?;
+                let plan = self.plan_unpivot(input, value, name, columns)?;
+                (plan, alias)
+            }
             // @todo Support TableFactory::TableFunction?
             _ => {
                 return not_impl_err!(
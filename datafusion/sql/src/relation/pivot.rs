@@ -0,0 +1,116 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::planner::{ContextProvider, PlannerContext, SqlToRel};
+use datafusion_common::{not_impl_err, plan_err, Column, DFSchema, Result};
+use datafusion_expr::{Expr, LogicalPlan, LogicalPlanBuilder};
+use sqlparser::ast::{Expr as SQLExpr, ExprWithAlias, Ident, PivotValueSource};
+
+impl<S: ContextProvider> SqlToRel<'_, S> {
+    /// Plan `input PIVOT(aggregate FOR column IN (values))` as an aggregate
+    /// with one filtered aggregate per pivot value.
+    ///
+    /// See [`LogicalPlanBuilder::pivot`] for the naming of the output columns.
+    pub(super) fn plan_pivot(
+        &self,
+        input: LogicalPlan,
+        aggregate_functions: Vec<ExprWithAlias>,
+        value_column: Vec<Ident>,
+        value_source: PivotValueSource,
+        default_on_null: Option<SQLExpr>,
+        planner_context: &mut PlannerContext,
+    ) -> Result<LogicalPlan> {
+        let values = match value_source {
+            PivotValueSource::List(values) => values,
+            PivotValueSource::Any(_) => {
+                return not_impl_err!("PIVOT with ANY is not supported yet")
+            }
+            PivotValueSource::Subquery(_) => {
+                return not_impl_err!("PIVOT with a subquery is not supported yet")
+            }
+        };
+
+        let schema = input.schema();
+        let aggr_exprs = aggregate_functions
+            .into_iter()
+            .map(|ExprWithAlias { expr, alias }| {
+                let expr =
+                    self.sql_expr_to_logical_expr(expr, schema, planner_context)?;
+                Ok(match alias {
+                    Some(alias) => expr.alias(self.ident_normalizer.normalize(alias)),
+                    None => expr,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let value_column = match value_column.len() {
+            0 => return plan_err!("PIVOT requires a value column"),
+            1 => SQLExpr::Identifier(value_column.into_iter().next().unwrap()),
+            _ => SQLExpr::CompoundIdentifier(value_column),
+        };
+        let Expr::Column(pivot_column) =
+            self.sql_expr_to_logical_expr(value_column, schema, planner_context)?
+        else {
+            return plan_err!("PIVOT value column must be a column reference");
+        };
+
+        let empty_schema = DFSchema::empty();
+        let pivot_values = values
+            .into_iter()
+            .map(|ExprWithAlias { expr, alias }| {
+                let expr =
+                    self.sql_expr_to_logical_expr(expr, &empty_schema, planner_context)?;
+                Ok(match alias {
+                    Some(alias) => expr.alias(self.ident_normalizer.normalize(alias)),
+                    None => expr,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let default_on_null = default_on_null
+            .map(|expr| {
+                self.sql_expr_to_logical_expr(expr, &empty_schema, planner_context)
+            })
+            .transpose()?;
+
+        LogicalPlanBuilder::from(input)
+            .pivot(aggr_exprs, pivot_column, pivot_values, default_on_null)?
+            .build()
+    }
+
+    /// Plan `input UNPIVOT(value FOR name IN (columns))` as a union with one
+    /// input per unpivoted column.
+    pub(super) fn plan_unpivot(
+        &self,
+        input: LogicalPlan,
+        value: Ident,
+        name: Ident,
+        columns: Vec<Ident>,
+    ) -> Result<LogicalPlan> {
+        let columns = columns
+            .into_iter()
+            .map(|ident| Column::from_name(self.ident_normalizer.normalize(ident)))
+            .collect::<Vec<_>>();
+        LogicalPlanBuilder::from(input)
+            .unpivot(
+                self.ident_normalizer.normalize(value),
+                self.ident_normalizer.normalize(name),
+                columns,
+                false,
+            )?
+            .build()
+    }
+}
@@ -146,6 +146,12 @@ fn visit_statement(statement: &DFStatement, visitor: &mut RelationVisitor) {
                 let _ = query.visit(visitor);
             }
         },
+        DFStatement::CreateMaterializedView(view) => {
+            let _ = view.query.visit(visitor);
+        }
+        DFStatement::RefreshMaterializedView(view) => {
+            visitor.insert_relation(&view.name);
+        }
         DFStatement::Explain(explain) => visit_statement(&explain.statement, visitor),
     }
 }
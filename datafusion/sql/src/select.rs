@@ -30,7 +30,7 @@ use crate::utils::{
 use datafusion_common::error::DataFusionErrorBuilder;
 use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
 use datafusion_common::{not_impl_err, plan_err, Result};
-use datafusion_common::{RecursionUnnestOption, UnnestOptions};
+use datafusion_common::{Column, RecursionUnnestOption, UnnestOptions, UnnestOrdinality};
 use datafusion_expr::expr::{Alias, PlannedReplaceSelectItem, WildcardOptions};
 use datafusion_expr::expr_rewriter::{
     normalize_col, normalize_col_with_schemas_and_ambiguity_check, normalize_sorts,
@@ -66,9 +66,6 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         if !select.lateral_views.is_empty() {
             return not_impl_err!("LATERAL VIEWS");
         }
-        if select.qualify.is_some() {
-            return not_impl_err!("QUALIFY");
-        }
         if select.top.is_some() {
             return not_impl_err!("TOP");
         }
@@ -85,7 +82,11 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
 
         // Handle named windows before processing the projection expression
         check_conflicting_windows(&select.named_window)?;
-        self.match_window_definitions(&mut select.projection, &select.named_window)?;
+        self.match_window_definitions(
+            &mut select.projection,
+            select.qualify.as_mut(),
+            &select.named_window,
+        )?;
         // Process the SELECT expressions
         let select_exprs = self.prepare_select_exprs(
             &base_plan,
@@ -148,9 +149,33 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
             })
             .transpose()?;
 
+        // Optionally the QUALIFY expression.
+        let qualify_expr_opt = select
+            .qualify
+            .map::<Result<Expr>, _>(|qualify_expr| {
+                let qualify_expr = self.sql_expr_to_logical_expr(
+                    qualify_expr,
+                    &combined_schema,
+                    planner_context,
+                )?;
+                // Like HAVING, QUALIFY may refer to aliases defined in the
+                // projection, which is how window results are usually filtered:
+                //
+                //   SELECT a, ROW_NUMBER() OVER (PARTITION BY a ORDER BY b) AS rn
+                //   FROM t QUALIFY rn = 1;
+                //
+                let qualify_expr = resolve_aliases_to_exprs(qualify_expr, &alias_map)?;
+                normalize_col(qualify_expr, &projected_plan)
+            })
+            .transpose()?;
+
         // The outer expressions we will search through for aggregates.
-        // Aggregates may be sourced from the SELECT list or from the HAVING expression.
-        let aggr_expr_haystack = select_exprs.iter().chain(having_expr_opt.iter());
+        // Aggregates may be sourced from the SELECT list, the HAVING expression
+        // or the QUALIFY expression.
+        let aggr_expr_haystack = select_exprs
+            .iter()
+            .chain(having_expr_opt.iter())
+            .chain(qualify_expr_opt.iter());
         // All of the aggregate expressions (deduplicated).
         let aggr_exprs = find_aggregate_exprs(aggr_expr_haystack);
 
@@ -199,21 +224,24 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         };
 
         // Process group by, aggregation or having
-        let (plan, mut select_exprs_post_aggr, having_expr_post_aggr) = if !group_by_exprs
-            .is_empty()
-            || !aggr_exprs.is_empty()
-        {
+        let (
+            plan,
+            mut select_exprs_post_aggr,
+            having_expr_post_aggr,
+            qualify_expr_post_aggr,
+        ) = if !group_by_exprs.is_empty() || !aggr_exprs.is_empty() {
             self.aggregate(
                 &base_plan,
                 &select_exprs,
                 having_expr_opt.as_ref(),
+                qualify_expr_opt.as_ref(),
                 &group_by_exprs,
                 &aggr_exprs,
             )?
         } else {
             match having_expr_opt {
                 Some(having_expr) => return plan_err!("HAVING clause references: {having_expr} must appear in the GROUP BY clause or be used in an aggregate function"),
-                None => (base_plan.clone(), select_exprs.clone(), having_expr_opt)
+                None => (base_plan.clone(), select_exprs.clone(), having_expr_opt, qualify_expr_opt)
             }
         };
 
@@ -225,8 +253,13 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
             plan
         };
 
-        // Process window function
-        let window_func_exprs = find_window_exprs(&select_exprs_post_aggr);
+        // Process window function. Window functions may be sourced from the
+        // SELECT list or from the QUALIFY expression.
+        let window_func_exprs = find_window_exprs(
+            select_exprs_post_aggr
+                .iter()
+                .chain(qualify_expr_post_aggr.iter()),
+        );
 
         let plan = if window_func_exprs.is_empty() {
             plan
@@ -242,6 +275,27 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
             plan
         };
 
+        // Process QUALIFY, which filters the output of the window functions in
+        // the same way HAVING filters the output of the aggregates.
+        let plan = if let Some(qualify_expr) = qualify_expr_post_aggr {
+            if window_func_exprs.is_empty() {
+                return plan_err!(
+                    "QUALIFY clause requires window functions in the SELECT list or QUALIFY clause"
+                );
+            }
+            let qualify_expr_post_window =
+                rebase_expr(&qualify_expr, &window_func_exprs, &plan)?;
+            self.validate_schema_satisfies_exprs(
+                plan.schema(),
+                std::slice::from_ref(&qualify_expr_post_window),
+            )?;
+            LogicalPlanBuilder::from(plan)
+                .filter(qualify_expr_post_window)?
+                .build()?
+        } else {
+            plan
+        };
+
         // Try processing unnest expression or do the final projection
         let plan = self.try_process_unnest(plan, select_exprs_post_aggr)?;
 
@@ -301,6 +355,18 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         &self,
         input: LogicalPlan,
         select_exprs: Vec<Expr>,
+    ) -> Result<LogicalPlan> {
+        self.try_process_unnest_with_ordinality(input, select_exprs, None)
+    }
+
+    /// Like [`Self::try_process_unnest`], additionally emitting the position
+    /// of each unnested element as a column described by `ordinality`, which
+    /// is added at the end of the output.
+    pub(super) fn try_process_unnest_with_ordinality(
+        &self,
+        input: LogicalPlan,
+        select_exprs: Vec<Expr>,
+        mut ordinality: Option<UnnestOrdinality>,
     ) -> Result<LogicalPlan> {
         // Try process group by unnest
         let input = self.try_process_aggregate_unnest(input)?;
@@ -350,6 +416,14 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
             } else {
                 // Set preserve_nulls to false to ensure compatibility with DuckDB and PostgreSQL
                 let mut unnest_options = UnnestOptions::new().with_preserve_nulls(false);
+                // The position is computed by the first unnest, which is the
+                // one expanding the rows, and then carried through the
+                // following ones as a regular column
+                let ordinality_column = ordinality.take().map(|ordinality| {
+                    let column = Expr::Column(Column::from_name(&ordinality.name));
+                    unnest_options.ordinality = Some(ordinality);
+                    column
+                });
                 let mut unnest_col_vec = vec![];
 
                 for (col, maybe_list_unnest) in unnest_columns.into_iter() {
@@ -373,6 +447,7 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                     .build()?;
                 intermediate_plan = plan;
                 intermediate_select_exprs = outer_projection_exprs;
+                intermediate_select_exprs.extend(ordinality_column);
             }
         }
 
@@ -770,6 +845,8 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
     ///
     /// An aggregate plan consists of grouping expressions, aggregate expressions, and an
     /// optional HAVING expression (which is a filter on the output of the aggregate).
+    /// An optional QUALIFY expression is rewritten alongside, since it may reference
+    /// aggregates as well.
     ///
     /// # Arguments
     ///
@@ -777,27 +854,31 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
     ///   "having" expressions must all be resolvable from this plan.
     /// * `select_exprs`    - The projection expressions from the SELECT clause.
     /// * `having_expr_opt` - Optional HAVING clause.
+    /// * `qualify_expr_opt` - Optional QUALIFY clause.
     /// * `group_by_exprs`  - Grouping expressions from the GROUP BY clause. These can be column
     ///   references or more complex expressions.
     /// * `aggr_exprs`      - Aggregate expressions, such as `SUM(a)` or `COUNT(1)`.
     ///
     /// # Return
     ///
-    /// The return value is a triplet of the following items:
+    /// The return value is a quadruplet of the following items:
     ///
     /// * `plan`                   - A [LogicalPlan::Aggregate] plan for the newly created aggregate.
     /// * `select_exprs_post_aggr` - The projection expressions rewritten to reference columns from
     ///   the aggregate
     /// * `having_expr_post_aggr`  - The "having" expression rewritten to reference a column from
     ///   the aggregate
+    /// * `qualify_expr_post_aggr` - The "qualify" expression rewritten to reference columns from
+    ///   the aggregate
     fn aggregate(
         &self,
         input: &LogicalPlan,
         select_exprs: &[Expr],
         having_expr_opt: Option<&Expr>,
+        qualify_expr_opt: Option<&Expr>,
         group_by_exprs: &[Expr],
         aggr_exprs: &[Expr],
-    ) -> Result<(LogicalPlan, Vec<Expr>, Option<Expr>)> {
+    ) -> Result<(LogicalPlan, Vec<Expr>, Option<Expr>, Option<Expr>)> {
         // create the aggregate plan
         let options =
             LogicalPlanBuilderOptions::new().with_add_implicit_group_by_exprs(true);
@@ -881,59 +962,72 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
             None
         };
 
-        Ok((plan, select_exprs_post_aggr, having_expr_post_aggr))
+        // Rewrite the QUALIFY expression to use the columns produced by the
+        // aggregation. Window functions in it are checked once they have been
+        // planned on top of the aggregate.
+        let qualify_expr_post_aggr = qualify_expr_opt
+            .map(|qualify_expr| rebase_expr(qualify_expr, &aggr_projection_exprs, input))
+            .transpose()?;
+
+        Ok((
+            plan,
+            select_exprs_post_aggr,
+            having_expr_post_aggr,
+            qualify_expr_post_aggr,
+        ))
     }
 
-    // If the projection is done over a named window, that window
-    // name must be defined. Otherwise, it gives an error.
+    // If the projection or the QUALIFY clause is done over a named window,
+    // that window name must be defined. Otherwise, it gives an error.
     fn match_window_definitions(
         &self,
         projection: &mut [SelectItem],
+        qualify: Option<&mut SQLExpr>,
         named_windows: &[NamedWindowDefinition],
     ) -> Result<()> {
         let named_windows: Vec<(&NamedWindowDefinition, String)> = named_windows
             .iter()
             .map(|w| (w, self.ident_normalizer.normalize(w.0.clone())))
             .collect();
-        for proj in projection.iter_mut() {
-            if let SelectItem::ExprWithAlias { expr, alias: _ }
-            | SelectItem::UnnamedExpr(expr) = proj
-            {
-                let mut err = None;
-                let _ = visit_expressions_mut(expr, |expr| {
-                    if let SQLExpr::Function(f) = expr {
-                        if let Some(WindowType::NamedWindow(ident)) = &f.over {
-                            let normalized_ident =
-                                self.ident_normalizer.normalize(ident.clone());
-                            for (
-                                NamedWindowDefinition(_, window_expr),
-                                normalized_window_ident,
-                            ) in named_windows.iter()
-                            {
-                                if normalized_ident.eq(normalized_window_ident) {
-                                    f.over = Some(match window_expr {
-                                        NamedWindowExpr::NamedWindow(ident) => {
-                                            WindowType::NamedWindow(ident.clone())
-                                        }
-                                        NamedWindowExpr::WindowSpec(spec) => {
-                                            WindowType::WindowSpec(spec.clone())
-                                        }
-                                    })
-                                }
-                            }
-                            // All named windows must be defined with a WindowSpec.
-                            if let Some(WindowType::NamedWindow(ident)) = &f.over {
-                                err =
-                                    Some(plan_err!("The window {ident} is not defined!"));
-                                return ControlFlow::Break(());
+        let projection_exprs = projection.iter_mut().filter_map(|proj| match proj {
+            SelectItem::ExprWithAlias { expr, alias: _ }
+            | SelectItem::UnnamedExpr(expr) => Some(expr),
+            _ => None,
+        });
+        for expr in projection_exprs.chain(qualify) {
+            let mut err = None;
+            let _ = visit_expressions_mut(expr, |expr| {
+                if let SQLExpr::Function(f) = expr {
+                    if let Some(WindowType::NamedWindow(ident)) = &f.over {
+                        let normalized_ident =
+                            self.ident_normalizer.normalize(ident.clone());
+                        for (
+                            NamedWindowDefinition(_, window_expr),
+                            normalized_window_ident,
+                        ) in named_windows.iter()
+                        {
+                            if normalized_ident.eq(normalized_window_ident) {
+                                f.over = Some(match window_expr {
+                                    NamedWindowExpr::NamedWindow(ident) => {
+                                        WindowType::NamedWindow(ident.clone())
+                                    }
+                                    NamedWindowExpr::WindowSpec(spec) => {
+                                        WindowType::WindowSpec(spec.clone())
+                                    }
+                                })
                             }
                         }
+                        // All named windows must be defined with a WindowSpec.
+                        if let Some(WindowType::NamedWindow(ident)) = &f.over {
+                            err = Some(plan_err!("The window {ident} is not defined!"));
+                            return ControlFlow::Break(());
+                        }
                     }
-                    ControlFlow::Continue(())
-                });
-                if let Some(err) = err {
-                    return err;
                 }
+                ControlFlow::Continue(())
+            });
+            if let Some(err) = err {
+                return err;
             }
         }
         Ok(())
@@ -16,13 +16,14 @@
 // under the License.
 
 use std::collections::{BTreeMap, HashMap, HashSet};
+use std::iter::once;
 use std::path::Path;
 use std::str::FromStr;
 use std::sync::Arc;
 
 use crate::parser::{
-    CopyToSource, CopyToStatement, CreateExternalTable, DFParser, ExplainStatement,
-    LexOrdering, Statement as DFStatement,
+    CopyToSource, CopyToStatement, CreateExternalTable, CreateMaterializedView, DFParser,
+    ExplainStatement, LexOrdering, RefreshMaterializedView, Statement as DFStatement,
 };
 use crate::planner::{
     object_name_to_qualifier, ContextProvider, PlannerContext, SqlToRel,
@@ -38,19 +39,25 @@ use datafusion_common::{
     DataFusionError, Result, ScalarValue, SchemaError, SchemaReference, TableReference,
     ToDFSchema,
 };
-use datafusion_expr::dml::{CopyTo, InsertOp};
+use datafusion_expr::dml::{
+    CopyTo, InsertOp, MergeAction, MergeClause, MergeClauseKind, MergeOp,
+    MERGE_CLAUSE_COLUMN, MERGE_TARGET_ROW_COLUMN,
+};
+use datafusion_expr::expr::{Case, WindowFunction};
 use datafusion_expr::expr_rewriter::normalize_col_with_schemas_and_ambiguity_check;
 use datafusion_expr::logical_plan::builder::project;
 use datafusion_expr::logical_plan::DdlStatement;
 use datafusion_expr::utils::expr_to_columns;
 use datafusion_expr::{
-    cast, col, Analyze, CreateCatalog, CreateCatalogSchema,
+    cast, col, lit, Analyze, AnalyzeTable, CreateCatalog, CreateCatalogSchema,
     CreateExternalTable as PlanCreateExternalTable, CreateFunction, CreateFunctionBody,
-    CreateIndex as PlanCreateIndex, CreateMemoryTable, CreateView, Deallocate,
-    DescribeTable, DmlStatement, DropCatalogSchema, DropFunction, DropTable, DropView,
-    EmptyRelation, Execute, Explain, ExplainFormat, Expr, ExprSchemable, Filter,
-    LogicalPlan, LogicalPlanBuilder, OperateFunctionArg, PlanType, Prepare, SetVariable,
-    SortExpr, Statement as PlanStatement, ToStringifiedPlan, TransactionAccessMode,
+    CreateIndex as PlanCreateIndex, CreateMaterializedView as PlanCreateMaterializedView,
+    CreateMemoryTable, CreateView, Deallocate, DescribeTable, DmlStatement,
+    DropCatalogSchema, DropFunction, DropTable, DropView, EmptyRelation, Execute,
+    Explain, ExplainFormat, Expr, ExprSchemable, Filter, JoinType, LogicalPlan,
+    LogicalPlanBuilder, OperateFunctionArg, PlanType, Prepare,
+    RefreshMaterializedView as PlanRefreshMaterializedView, SetVariable, SortExpr,
+    Statement as PlanStatement, TableSource, ToStringifiedPlan, TransactionAccessMode,
     TransactionConclusion, TransactionEnd, TransactionIsolationLevel, TransactionStart,
     Volatility, WriteOp,
 };
@@ -68,6 +75,25 @@ use sqlparser::ast::{
 };
 use sqlparser::parser::ParserError::ParserError;
 
+/// Name of the column marking the rows of the target table in a `MERGE`,
+/// holding the row number of each target row
+const MERGE_TARGET_MARKER: &str = "__merge_target_marker";
+/// Name of the column marking the rows of the source relation in a `MERGE`
+const MERGE_SOURCE_MARKER: &str = "__merge_source_marker";
+
+/// Add a non null boolean column named `marker` to `plan`, so that its rows
+/// can be told apart from the NULL padding of an outer join.
+fn with_merge_marker(plan: LogicalPlan, marker: &str) -> Result<LogicalPlan> {
+    let exprs = plan
+        .schema()
+        .columns()
+        .into_iter()
+        .map(Expr::Column)
+        .chain(once(lit(true).alias(marker)))
+        .collect::<Vec<_>>();
+    project(plan, exprs)
+}
+
 fn ident_to_string(ident: &Ident) -> String {
     normalize_ident(ident.to_owned())
 }
@@ -182,6 +208,12 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
             DFStatement::CreateExternalTable(s) => self.external_table_to_plan(s),
             DFStatement::Statement(s) => self.sql_statement_to_plan(*s),
             DFStatement::CopyTo(s) => self.copy_to_plan(s),
+            DFStatement::CreateMaterializedView(s) => {
+                self.create_materialized_view_to_plan(s)
+            }
+            DFStatement::RefreshMaterializedView(s) => {
+                self.refresh_materialized_view_to_plan(s)
+            }
             DFStatement::Explain(ExplainStatement {
                 verbose,
                 analyze,
@@ -291,9 +323,6 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 catalog_sync,
                 storage_serialization_policy,
             }) if table_properties.is_empty() && with_options.is_empty() => {
-                if temporary {
-                    return not_impl_err!("Temporary tables not supported")?;
-                }
                 if external {
                     return not_impl_err!("External tables not supported")?;
                 }
@@ -535,9 +564,6 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 to,
                 params,
             } => {
-                if materialized {
-                    return not_impl_err!("Materialized views not supported")?;
-                }
                 if !cluster_by.is_empty() {
                     return not_impl_err!("Cluster by not supported")?;
                 }
@@ -547,12 +573,35 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 if with_no_schema_binding {
                     return not_impl_err!("With no schema binding not supported")?;
                 }
-                if if_not_exists {
-                    return not_impl_err!("If not exists not supported")?;
-                }
                 if to.is_some() {
                     return not_impl_err!("To not supported")?;
                 }
+                if materialized {
+                    if !columns.is_empty() {
+                        return not_impl_err!(
+                            "Column list of materialized views not supported"
+                        )?;
+                    }
+                    if temporary {
+                        return not_impl_err!(
+                            "Temporary materialized views not supported"
+                        )?;
+                    }
+                    return self.create_materialized_view_to_plan(
+                        CreateMaterializedView {
+                            name,
+                            query,
+                            file_type: None,
+                            location: None,
+                            options: vec![],
+                            or_replace,
+                            if_not_exists,
+                        },
+                    );
+                }
+                if if_not_exists {
+                    return not_impl_err!("If not exists not supported")?;
+                }
 
                 // put the statement back together temporarily to get the SQL
                 // string representation
@@ -1013,6 +1062,14 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                 self.delete_to_plan(table_name, selection)
             }
 
+            Statement::Merge {
+                table,
+                source,
+                on,
+                clauses,
+                ..
+            } => self.merge_to_plan(table, source, *on, clauses),
+
             Statement::StartTransaction {
                 modes,
                 begin: false,
@@ -1271,12 +1328,68 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
                     },
                 )))
             }
+            Statement::Analyze {
+                table_name,
+                partitions,
+                columns,
+                cache_metadata,
+                noscan,
+                ..
+            } => {
+                if partitions.is_some() {
+                    return not_impl_err!("ANALYZE TABLE with PARTITION not supported");
+                }
+                if cache_metadata {
+                    return not_impl_err!(
+                        "ANALYZE TABLE with CACHE METADATA not supported"
+                    );
+                }
+                if noscan {
+                    return not_impl_err!("ANALYZE TABLE with NOSCAN not supported");
+                }
+                self.analyze_table_to_plan(table_name, columns)
+            }
             stmt => {
                 not_impl_err!("Unsupported SQL statement: {stmt}")
             }
         }
     }
 
+    /// Generate a logical plan from an `ANALYZE TABLE` statement, which
+    /// analyzes all the columns of the table unless `FOR COLUMNS` lists some
+    fn analyze_table_to_plan(
+        &self,
+        table_name: ObjectName,
+        columns: Vec<Ident>,
+    ) -> Result<LogicalPlan> {
+        let name = self.object_name_to_table_reference(table_name)?;
+        let schema = self
+            .context_provider
+            .get_table_source(name.clone())?
+            .schema();
+        let columns = if columns.is_empty() {
+            schema.fields().iter().map(|f| f.name().clone()).collect()
+        } else {
+            let mut names: Vec<String> = vec![];
+            for ident in columns {
+                let column = self.ident_normalizer.normalize(ident);
+                if schema.field_with_name(&column).is_err() {
+                    return plan_err!("Column '{column}' not found in table '{name}'");
+                }
+                if !names.contains(&column) {
+                    names.push(column);
+                }
+            }
+            names
+        };
+
+        Ok(LogicalPlan::Ddl(DdlStatement::AnalyzeTable(AnalyzeTable {
+            name,
+            columns,
+            schema: DFSchemaRef::new(DFSchema::empty()),
+        })))
+    }
+
     fn get_delete_target(&self, from: FromTable) -> Result<ObjectName> {
         let mut from = match from {
             FromTable::WithFromKeyword(v) => v,
@@ -1455,6 +1568,64 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         Ok(all_results)
     }
 
+    /// Generate a logical plan from a `CREATE MATERIALIZED VIEW` statement
+    fn create_materialized_view_to_plan(
+        &self,
+        statement: CreateMaterializedView,
+    ) -> Result<LogicalPlan> {
+        let definition = Some(statement.to_string());
+        let CreateMaterializedView {
+            name,
+            query,
+            file_type,
+            location,
+            options,
+            or_replace,
+            if_not_exists,
+        } = statement;
+
+        if or_replace && if_not_exists {
+            return plan_err!("'IF NOT EXISTS' cannot coexist with 'REPLACE'");
+        }
+        if let Some(file_type) = &file_type {
+            // fail early if the file type is not registered
+            self.context_provider.get_file_type(file_type)?;
+        }
+        let mut options = self
+            .parse_options_map(options, false)?
+            .into_iter()
+            .collect::<Vec<_>>();
+        options.sort();
+
+        let plan = self.query_to_plan(*query, &mut PlannerContext::new())?;
+
+        Ok(LogicalPlan::Ddl(DdlStatement::CreateMaterializedView(
+            PlanCreateMaterializedView {
+                name: self.object_name_to_table_reference(name)?,
+                input: Arc::new(plan),
+                location,
+                file_type,
+                options,
+                or_replace,
+                if_not_exists,
+                definition,
+            },
+        )))
+    }
+
+    /// Generate a logical plan from a `REFRESH MATERIALIZED VIEW` statement
+    fn refresh_materialized_view_to_plan(
+        &self,
+        statement: RefreshMaterializedView,
+    ) -> Result<LogicalPlan> {
+        Ok(LogicalPlan::Ddl(DdlStatement::RefreshMaterializedView(
+            PlanRefreshMaterializedView {
+                name: self.object_name_to_table_reference(statement.name)?,
+                schema: DFSchemaRef::new(DFSchema::empty()),
+            },
+        )))
+    }
+
     /// Generate a logical plan from a CREATE EXTERNAL TABLE statement
     fn external_table_to_plan(
         &self,
@@ -1959,6 +2130,365 @@ impl<S: ContextProvider> SqlToRel<'_, S> {
         Ok(plan)
     }
 
+    fn merge_to_plan(
+        &self,
+        table: TableFactor,
+        source: TableFactor,
+        on: SQLExpr,
+        clauses: Vec<ast::MergeClause>,
+    ) -> Result<LogicalPlan> {
+        let table_name = match &table {
+            TableFactor::Table { name, .. } => name.clone(),
+            _ => plan_err!("Cannot merge into non-table relation!")?,
+        };
+        if clauses.is_empty() {
+            return plan_err!("MERGE requires at least one WHEN clause");
+        }
+
+        // Do a table lookup to verify the table exists
+        let table_name = self.object_name_to_table_reference(table_name)?;
+        let table_source = self.context_provider.get_table_source(table_name.clone())?;
+        let table_schema = DFSchema::try_from(table_source.schema().as_ref().clone())?;
+
+        let mut planner_context = PlannerContext::new();
+        let target = self.plan_from_tables(
+            vec![TableWithJoins {
+                relation: table,
+                joins: vec![],
+            }],
+            &mut planner_context,
+        )?;
+        let source = self.plan_from_tables(
+            vec![TableWithJoins {
+                relation: source,
+                joins: vec![],
+            }],
+            &mut planner_context,
+        )?;
+        // The columns of the target table, qualified by its alias if any
+        let target_columns = target.schema().columns();
+
+        // Mark the rows of both sides to tell them apart after the full join
+        let target_marker = Expr::Column(Column::from_name(MERGE_TARGET_MARKER));
+        let source_marker = Expr::Column(Column::from_name(MERGE_SOURCE_MARKER));
+        let target = self.with_merge_row_number(target, MERGE_TARGET_MARKER)?;
+        let source = with_merge_marker(source, MERGE_SOURCE_MARKER)?;
+
+        let join_schema = target.schema().join(source.schema())?;
+        let on = self.sql_to_expr(on, &join_schema, &mut planner_context)?;
+        let joined = LogicalPlanBuilder::from(target)
+            .join_on(source, JoinType::Full, [on.clone()])?
+            .build()?;
+        let schema = Arc::clone(joined.schema());
+
+        // For each clause, the condition selecting its rows, and for each
+        // column of the target table, the values set by the clauses
+        let mut clause_conditions = vec![];
+        let mut column_values = vec![vec![]; table_schema.fields().len()];
+        let mut merge_clauses = vec![];
+        for (index, clause) in clauses.into_iter().enumerate() {
+            let kind = match clause.clause_kind {
+                ast::MergeClauseKind::Matched => MergeClauseKind::Matched,
+                ast::MergeClauseKind::NotMatched
+                | ast::MergeClauseKind::NotMatchedByTarget => {
+                    MergeClauseKind::NotMatchedByTarget
+                }
+                ast::MergeClauseKind::NotMatchedBySource => {
+                    MergeClauseKind::NotMatchedBySource
+                }
+            };
+            let (is_insert, action_name) = match &clause.action {
+                ast::MergeAction::Insert(_) => (true, "INSERT"),
+                ast::MergeAction::Update { .. } => (false, "UPDATE"),
+                ast::MergeAction::Delete => (false, "DELETE"),
+            };
+            if is_insert != (kind == MergeClauseKind::NotMatchedByTarget) {
+                return plan_err!(
+                    "{action_name} is not allowed in a {kind} merge clause"
+                );
+            }
+
+            let predicate = clause
+                .predicate
+                .map(|predicate| {
+                    self.sql_to_expr(predicate, &schema, &mut planner_context)
+                })
+                .transpose()?;
+            let mut condition = match kind {
+                MergeClauseKind::Matched => target_marker
+                    .clone()
+                    .is_not_null()
+                    .and(source_marker.clone().is_not_null()),
+                MergeClauseKind::NotMatchedByTarget => target_marker.clone().is_null(),
+                MergeClauseKind::NotMatchedBySource => source_marker.clone().is_null(),
+            };
+            if let Some(predicate) = &predicate {
+                condition = condition.and(predicate.clone());
+            }
+            let clause_index = lit(index as u32);
+            clause_conditions.push((Box::new(condition), Box::new(clause_index.clone())));
+
+            let action = match clause.action {
+                ast::MergeAction::Update { assignments } => {
+                    let assignments = self.merge_assignments_to_exprs(
+                        assignments,
+                        &table_schema,
+                        &schema,
+                        &mut planner_context,
+                    )?;
+                    for (column_index, value) in &assignments {
+                        column_values[*column_index]
+                            .push((clause_index.clone(), value.clone()));
+                    }
+                    MergeAction::Update(
+                        assignments
+                            .into_iter()
+                            .map(|(column_index, value)| {
+                                (table_schema.field(column_index).name().clone(), value)
+                            })
+                            .collect(),
+                    )
+                }
+                ast::MergeAction::Insert(insert) => {
+                    let row = self.merge_insert_to_exprs(
+                        insert,
+                        &table_schema,
+                        table_source.as_ref(),
+                        &schema,
+                        &mut planner_context,
+                    )?;
+                    for (values, value) in column_values.iter_mut().zip(&row) {
+                        values.push((clause_index.clone(), value.clone()));
+                    }
+                    MergeAction::Insert(row)
+                }
+                ast::MergeAction::Delete => MergeAction::Delete,
+            };
+            merge_clauses.push(MergeClause {
+                kind,
+                predicate,
+                action,
+            });
+        }
+
+        // Find the first clause applying to each row
+        let clause_column = Expr::Column(Column::from_name(MERGE_CLAUSE_COLUMN));
+        let clause_index = Expr::Case(Case::new(None, clause_conditions, None));
+        let exprs = schema
+            .columns()
+            .into_iter()
+            .map(Expr::Column)
+            .chain(once(clause_index.alias(MERGE_CLAUSE_COLUMN)))
+            .collect::<Vec<_>>();
+        let plan = project(joined, exprs)?;
+
+        // Drop the source rows no clause applies to
+        let plan = LogicalPlan::Filter(Filter::try_new(
+            clause_column
+                .clone()
+                .is_not_null()
+                .or(target_marker.clone().is_not_null()),
+            Arc::new(plan),
+        )?);
+
+        // Compute the values of the target columns after the merge, keeping the
+        // previous value for the rows not modified by any clause. The number of
+        // each matched target row is kept to detect the rows matched more than
+        // once.
+        let target_row = Expr::Case(Case::new(
+            None,
+            vec![(
+                Box::new(source_marker.is_not_null()),
+                Box::new(target_marker),
+            )],
+            None,
+        ));
+        let exprs = table_schema
+            .fields()
+            .iter()
+            .zip(target_columns)
+            .zip(column_values)
+            .map(|((field, target_column), values)| {
+                let previous_value = Expr::Column(target_column);
+                let expr = if values.is_empty() {
+                    previous_value
+                } else {
+                    Expr::Case(Case::new(
+                        Some(Box::new(clause_column.clone())),
+                        values
+                            .into_iter()
+                            .map(|(index, value)| (Box::new(index), Box::new(value)))
+                            .collect(),
+                        Some(Box::new(previous_value)),
+                    ))
+                };
+                expr.alias(field.name())
+            })
+            .chain(once(target_row.alias(MERGE_TARGET_ROW_COLUMN)))
+            .chain(once(clause_column))
+            .collect::<Vec<_>>();
+        let plan = project(plan, exprs)?;
+
+        Ok(LogicalPlan::Dml(DmlStatement::new(
+            table_name,
+            table_source,
+            WriteOp::Merge(MergeOp::new(on, merge_clauses)),
+            Arc::new(plan),
+        )))
+    }
+
+    /// Add a column named `marker` numbering the rows of `plan`
+    fn with_merge_row_number(
+        &self,
+        plan: LogicalPlan,
+        marker: &str,
+    ) -> Result<LogicalPlan> {
+        let Some(row_number) = self.context_provider.get_window_meta("row_number") else {
+            return plan_err!("MERGE requires the row_number window function");
+        };
+        let plan = LogicalPlanBuilder::window_plan(
+            plan,
+            vec![Expr::from(WindowFunction::new(row_number, vec![]))],
+        )?;
+        let mut columns = plan.schema().columns();
+        let Some(row_number) = columns.pop() else {
+            return internal_err!("Expected a row_number column");
+        };
+        let exprs = columns
+            .into_iter()
+            .map(Expr::Column)
+            .chain(once(Expr::Column(row_number).alias(marker)))
+            .collect::<Vec<_>>();
+        project(plan, exprs)
+    }
+
+    /// Plan the assignments of a `WHEN MATCHED THEN UPDATE` merge clause,
+    /// returning the index of each assigned column of the target table with
+    /// its new value.
+    fn merge_assignments_to_exprs(
+        &self,
+        assignments: Vec<Assignment>,
+        table_schema: &DFSchema,
+        schema: &DFSchema,
+        planner_context: &mut PlannerContext,
+    ) -> Result<Vec<(usize, Expr)>> {
+        let mut assigned = HashSet::new();
+        assignments
+            .into_iter()
+            .map(|assign| {
+                let cols = match &assign.target {
+                    AssignmentTarget::ColumnName(cols) => cols,
+                    _ => plan_err!("Tuples are not supported")?,
+                };
+                let col_name = cols
+                    .0
+                    .iter()
+                    .last()
+                    .and_then(|part| part.as_ident())
+                    .ok_or_else(|| plan_datafusion_err!("Empty column id"))?;
+                let col_name = self.ident_normalizer.normalize(col_name.clone());
+                let column_index = table_schema
+                    .index_of_column_by_name(None, &col_name)
+                    .ok_or_else(|| {
+                        unqualified_field_not_found(&col_name, table_schema)
+                    })?;
+                if !assigned.insert(column_index) {
+                    return schema_err!(SchemaError::DuplicateUnqualifiedField {
+                        name: col_name,
+                    });
+                }
+
+                let field = table_schema.field(column_index);
+                let mut expr = self.sql_to_expr(assign.value, schema, planner_context)?;
+                // Update placeholder's datatype to the type of the target column
+                if let Expr::Placeholder(placeholder) = &mut expr {
+                    placeholder.data_type = placeholder
+                        .data_type
+                        .take()
+                        .or_else(|| Some(field.data_type().clone()));
+                }
+                Ok((column_index, expr.cast_to(field.data_type(), schema)?))
+            })
+            .collect()
+    }
+
+    /// Plan the values of a `WHEN NOT MATCHED THEN INSERT` merge clause,
+    /// returning one value for each column of the target table.
+    fn merge_insert_to_exprs(
+        &self,
+        insert: ast::MergeInsertExpr,
+        table_schema: &DFSchema,
+        table_source: &dyn TableSource,
+        schema: &DFSchema,
+        planner_context: &mut PlannerContext,
+    ) -> Result<Vec<Expr>> {
+        let ast::MergeInsertKind::Values(ast::Values { rows, .. }) = insert.kind else {
+            return not_impl_err!("INSERT ROW is not supported in MERGE");
+        };
+        let mut rows = rows.into_iter();
+        let (Some(row), None) = (rows.next(), rows.next()) else {
+            return plan_err!("INSERT in MERGE must have exactly one row of values");
+        };
+
+        // The index of the column of the target table set by each value
+        let column_indices = if insert.columns.is_empty() {
+            (0..table_schema.fields().len()).collect::<Vec<_>>()
+        } else {
+            let mut column_indices = Vec::with_capacity(insert.columns.len());
+            for column in insert.columns {
+                let column = self.ident_normalizer.normalize(column);
+                let column_index = table_schema
+                    .index_of_column_by_name(None, &column)
+                    .ok_or_else(|| unqualified_field_not_found(&column, table_schema))?;
+                if column_indices.contains(&column_index) {
+                    return schema_err!(SchemaError::DuplicateUnqualifiedField {
+                        name: column,
+                    });
+                }
+                column_indices.push(column_index);
+            }
+            column_indices
+        };
+        if column_indices.len() != row.len() {
+            return plan_err!("Column count doesn't match insert query!");
+        }
+
+        let mut values = vec![None; table_schema.fields().len()];
+        for (column_index, value) in column_indices.into_iter().zip(row) {
+            values[column_index] = Some(value);
+        }
+        table_schema
+            .fields()
+            .iter()
+            .zip(values)
+            .map(|(field, value)| {
+                let expr = match value {
+                    Some(value) => {
+                        let mut expr =
+                            self.sql_to_expr(value, schema, planner_context)?;
+                        // Update placeholder's datatype to the type of the target column
+                        if let Expr::Placeholder(placeholder) = &mut expr {
+                            placeholder.data_type = placeholder
+                                .data_type
+                                .take()
+                                .or_else(|| Some(field.data_type().clone()));
+                        }
+                        expr
+                    }
+                    // The value is not specified. Fill in the default value for the column.
+                    None => table_source
+                        .get_column_default(field.name())
+                        .cloned()
+                        .unwrap_or_else(|| {
+                            // If there is no default for the column, then the default is NULL
+                            Expr::Literal(ScalarValue::Null, None)
+                        }),
+                };
+                expr.cast_to(field.data_type(), schema)
+            })
+            .collect()
+    }
+
     fn insert_to_plan(
         &self,
         table_name: ObjectName,
@@ -15,12 +15,14 @@
 // specific language governing permissions and limitations
 // under the License.
 
-use datafusion_expr::expr::{AggregateFunctionParams, Unnest, WindowFunctionParams};
+use datafusion_expr::expr::{
+    AggregateFunctionParams, Lambda, LambdaVariable, Unnest, WindowFunctionParams,
+};
 use sqlparser::ast::Value::SingleQuotedString;
 use sqlparser::ast::{
     self, Array, BinaryOperator, CaseWhen, DuplicateTreatment, Expr as AstExpr, Function,
-    Ident, Interval, ObjectName, OrderByOptions, Subscript, TimezoneInfo, UnaryOperator,
-    ValueWithSpan,
+    Ident, Interval, ObjectName, OneOrManyWithParens, OrderByOptions, Subscript,
+    TimezoneInfo, UnaryOperator, ValueWithSpan,
 };
 use std::sync::Arc;
 use std::vec;
@@ -232,6 +234,14 @@ impl Unparser<'_> {
                     &start_bound,
                     &end_bound,
                 ) {
+                    // The sqlparser AST has no representation of the EXCLUDE
+                    // clause, so dropping it would silently change the result
+                    if window_frame.has_exclusion() {
+                        return not_impl_err!(
+                            "Unparsing window frame {} is not supported: the SQL AST cannot represent EXCLUDE",
+                            window_frame.exclusion
+                        );
+                    }
                     Some(ast::WindowFrame {
                         units,
                         start_bound,
@@ -517,6 +527,26 @@ impl Unparser<'_> {
             }
             Expr::OuterReferenceColumn(_, col) => self.col_to_sql(col),
             Expr::Unnest(unnest) => self.unnest_to_sql(unnest),
+            Expr::Lambda(Lambda { params, body }) => {
+                let params = match params.as_slice() {
+                    [param] => OneOrManyWithParens::One(
+                        self.new_ident_quoted_if_needs(param.clone()),
+                    ),
+                    params => OneOrManyWithParens::Many(
+                        params
+                            .iter()
+                            .map(|p| self.new_ident_quoted_if_needs(p.clone()))
+                            .collect(),
+                    ),
+                };
+                Ok(ast::Expr::Lambda(ast::LambdaFunction {
+                    params,
+                    body: Box::new(self.expr_to_sql_inner(body)?),
+                }))
+            }
+            Expr::LambdaVariable(LambdaVariable { name, .. }) => Ok(
+                ast::Expr::Identifier(self.new_ident_quoted_if_needs(name.clone())),
+            ),
         }
     }
 
@@ -1756,7 +1786,8 @@ mod tests {
         case, cast, col, cube, exists, grouping_set, interval_datetime_lit,
         interval_year_month_lit, lit, not, not_exists, out_ref_col, placeholder, rollup,
         table_scan, try_cast, when, ColumnarValue, ScalarFunctionArgs, ScalarUDF,
-        ScalarUDFImpl, Signature, Volatility, WindowFrame, WindowFunctionDefinition,
+        ScalarUDFImpl, Signature, Volatility, WindowFrame, WindowFrameExclusion,
+        WindowFunctionDefinition,
     };
     use datafusion_expr::{interval_month_day_nano_lit, ExprFunctionExt};
     use datafusion_functions::datetime::from_unixtime::FromUnixtimeFunc;
@@ -2963,6 +2994,25 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_window_frame_exclusion_unsupported() {
+        let dialect = CustomDialectBuilder::new().build();
+        let unparser = Unparser::new(&dialect);
+        let func = WindowFunctionDefinition::AggregateUDF(count_udaf());
+        let mut window_func = WindowFunction::new(func, vec![col("b")]);
+        window_func.params.order_by = vec![Sort::new(col("a"), true, true)];
+        window_func.params.window_frame =
+            WindowFrame::new(Some(true)).with_exclusion(WindowFrameExclusion::CurrentRow);
+        let expr = Expr::from(window_func);
+
+        let err = unparser.expr_to_sql(&expr).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Unparsing window frame EXCLUDE CURRENT ROW is not supported"),
+            "{err}"
+        );
+    }
+
     #[test]
     fn test_from_unixtime() -> Result<()> {
         let default_dialect: Arc<dyn Dialect> = Arc::new(DefaultDialect {});
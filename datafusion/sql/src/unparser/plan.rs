@@ -124,7 +124,10 @@ impl Unparser<'_> {
             | LogicalPlan::Copy(_)
             | LogicalPlan::DescribeTable(_)
             | LogicalPlan::RecursiveQuery(_)
-            | LogicalPlan::Unnest(_) => not_impl_err!("Unsupported plan: {plan:?}"),
+            | LogicalPlan::Unnest(_)
+            | LogicalPlan::MatchRecognize(_) => {
+                not_impl_err!("Unsupported plan: {plan:?}")
+            }
         }
     }
 
@@ -421,7 +424,28 @@ impl Unparser<'_> {
                 self.select_to_sql_recursively(p.input.as_ref(), query, select, relation)
             }
             LogicalPlan::Filter(filter) => {
-                if let Some(agg) =
+                if let Some(window) = find_window_nodes_within_select(
+                    plan,
+                    None,
+                    select.already_projected(),
+                ) {
+                    // A filter on top of window nodes within the same select is a QUALIFY
+                    let unprojected = match find_agg_node_within_select(
+                        plan,
+                        select.already_projected(),
+                    ) {
+                        Some(agg) => unproject_agg_exprs(
+                            filter.predicate.clone(),
+                            agg,
+                            Some(window.as_slice()),
+                        )?,
+                        None => {
+                            unproject_window_exprs(filter.predicate.clone(), &window)?
+                        }
+                    };
+                    let filter_expr = self.expr_to_sql(&unprojected)?;
+                    select.qualify(Some(filter_expr));
+                } else if let Some(agg) =
                     find_agg_node_within_select(plan, select.already_projected())
                 {
                     let unprojected =
@@ -775,15 +799,39 @@ impl Unparser<'_> {
                     JoinType::Inner
                     | JoinType::Left
                     | JoinType::Right
-                    | JoinType::Full => {
+                    | JoinType::Full
+                    | JoinType::AsOf => {
                         let Ok(Some(relation)) = right_relation.build() else {
                             return internal_err!("Failed to build right relation");
                         };
+                        let join_operator = if join.join_type == JoinType::AsOf {
+                            // The join filter is the match condition, the equi-join
+                            // conditions form the ON clause
+                            let Some(match_condition) = &join.filter else {
+                                return internal_err!(
+                                    "AsOf join requires a match condition"
+                                );
+                            };
+                            if join_filters.as_ref() != Some(match_condition) {
+                                return not_impl_err!(
+                                    "Unparsing AsOf join with pushed down table scan filters"
+                                );
+                            }
+                            ast::JoinOperator::AsOf {
+                                match_condition: self.expr_to_sql(match_condition)?,
+                                constraint: self.join_constraint_to_sql(
+                                    join.join_constraint,
+                                    &join.on,
+                                    None,
+                                )?,
+                            }
+                        } else {
+                            self.join_operator_to_sql(join.join_type, join_constraint)?
+                        };
                         let ast_join = ast::Join {
                             relation,
                             global: false,
-                            join_operator: self
-                                .join_operator_to_sql(join.join_type, join_constraint)?,
+                            join_operator,
                         };
                         let mut from = select.pop_from().unwrap();
                         from.push_join(ast_join);
@@ -950,6 +998,11 @@ impl Unparser<'_> {
                         unnest.struct_type_columns
                     );
                 }
+                if unnest.options.ordinality.is_some() {
+                    return not_impl_err!(
+                        "Unparsing UNNEST with an ordinality column is not supported"
+                    );
+                }
 
                 // In the case of UNNEST, the Unnest node is followed by a duplicate Projection node that we should skip.
                 // Otherwise, there will be a duplicate SELECT clause.
@@ -1270,6 +1323,11 @@ impl Unparser<'_> {
             JoinType::LeftMark | JoinType::RightMark => {
                 unimplemented!("Unparsing of Mark join type")
             }
+            JoinType::AsOf => {
+                return internal_err!(
+                    "AsOf join must be unparsed together with its match condition"
+                )
+            }
         })
     }
 
@@ -120,6 +120,8 @@ fn roundtrip_statement() -> Result<()> {
             "select ta.j1_id from j1 ta where ta.j1_id > 1;",
             "select ta.j1_id, tb.j2_string from j1 ta join j2 tb on (ta.j1_id = tb.j2_id);",
             "select ta.j1_id, tb.j2_string, tc.j3_string from j1 ta join j2 tb on (ta.j1_id = tb.j2_id) join j3 tc on (ta.j1_id = tc.j3_id);",
+            "select ta.j1_id, tb.j2_string from j1 ta asof join j2 tb match_condition (ta.j1_id >= tb.j2_id) on (ta.j1_string = tb.j2_string);",
+            "select ta.j1_id, tb.j2_id from j1 ta asof join j2 tb match_condition (ta.j1_id < tb.j2_id);",
             "select * from (select id, first_name from person)",
             "select * from (select id, first_name from (select * from person))",
             "select id, count(*) as cnt from (select id from person) group by id",
@@ -207,6 +209,14 @@ fn roundtrip_statement() -> Result<()> {
             SUM(id) OVER (ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) AS running_total
             FROM person
             GROUP BY GROUPING SETS ((id, first_name, last_name), (first_name, last_name), (last_name))"#,
+            r#"SELECT id, sum(id) OVER (PARTITION BY first_name ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING) AS total
+            FROM person
+            QUALIFY total > 10"#,
+            r#"SELECT first_name, SUM(id) AS total_sum,
+            MAX(SUM(id)) OVER (ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING) AS max_total
+            FROM person
+            GROUP BY first_name
+            QUALIFY total_sum = max_total"#,
             "SELECT ARRAY[1, 2, 3]",
             "SELECT ARRAY[1, 2, 3][1]",
             "SELECT [1, 2, 3]",
@@ -44,6 +44,7 @@ use datafusion_functions_aggregate::{
 use datafusion_functions_aggregate::{average::avg_udaf, grouping::grouping_udaf};
 use datafusion_functions_nested::make_array::make_array_udf;
 use datafusion_functions_window::rank::rank_udwf;
+use datafusion_functions_window::row_number::row_number_udwf;
 use insta::{allow_duplicates, assert_snapshot};
 use rstest::rstest;
 use sqlparser::dialect::{Dialect, GenericDialect, HiveDialect, MySqlDialect};
@@ -749,6 +750,72 @@ fn update_column_does_not_exist(#[case] sql: &str) {
     assert_field_not_found(err, "doesnotexist");
 }
 
+#[test]
+fn plan_merge() {
+    let sql = "MERGE INTO j1 USING j2 ON j1.j1_id = j2.j2_id \
+        WHEN MATCHED AND j2.j2_string = 'x' THEN DELETE \
+        WHEN MATCHED THEN UPDATE SET j1_string = j2.j2_string \
+        WHEN NOT MATCHED THEN INSERT VALUES (j2.j2_id, j2.j2_string)";
+    let plan = logical_plan(sql).unwrap();
+    assert_snapshot!(
+        plan,
+        @r#"
+        Dml: op=[Merge] table=[j1]
+          Projection: CASE __merge_clause WHEN UInt32(2) THEN j2.j2_id ELSE j1.j1_id END AS j1_id, CASE __merge_clause WHEN UInt32(1) THEN j2.j2_string WHEN UInt32(2) THEN j2.j2_string ELSE j1.j1_string END AS j1_string, CASE WHEN __merge_source_marker IS NOT NULL THEN __merge_target_marker END AS __merge_target_row, __merge_clause
+            Filter: __merge_clause IS NOT NULL OR __merge_target_marker IS NOT NULL
+              Projection: j1.j1_id, j1.j1_string, __merge_target_marker, j2.j2_id, j2.j2_string, __merge_source_marker, CASE WHEN __merge_target_marker IS NOT NULL AND __merge_source_marker IS NOT NULL AND j2.j2_string = Utf8("x") THEN UInt32(0) WHEN __merge_target_marker IS NOT NULL AND __merge_source_marker IS NOT NULL THEN UInt32(1) WHEN __merge_target_marker IS NULL THEN UInt32(2) END AS __merge_clause
+                Full Join:  Filter: j1.j1_id = j2.j2_id
+                  Projection: j1.j1_id, j1.j1_string, row_number() ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING AS __merge_target_marker
+                    WindowAggr: windowExpr=[[row_number() ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING]]
+                      TableScan: j1
+                  Projection: j2.j2_id, j2.j2_string, Boolean(true) AS __merge_source_marker
+                    TableScan: j2
+        "#
+    );
+}
+
+#[test]
+fn plan_merge_not_matched_by_source() {
+    let sql =
+        "MERGE INTO j1 AS t USING (SELECT j2_id AS id FROM j2) AS s ON t.j1_id = s.id \
+        WHEN NOT MATCHED BY SOURCE THEN UPDATE SET j1_string = 'orphan'";
+    let plan = logical_plan(sql).unwrap();
+    assert_snapshot!(
+        plan,
+        @r#"
+        Dml: op=[Merge] table=[j1]
+          Projection: t.j1_id AS j1_id, CASE __merge_clause WHEN UInt32(0) THEN Utf8("orphan") ELSE t.j1_string END AS j1_string, CASE WHEN __merge_source_marker IS NOT NULL THEN __merge_target_marker END AS __merge_target_row, __merge_clause
+            Filter: __merge_clause IS NOT NULL OR __merge_target_marker IS NOT NULL
+              Projection: t.j1_id, t.j1_string, __merge_target_marker, s.id, __merge_source_marker, CASE WHEN __merge_source_marker IS NULL THEN UInt32(0) END AS __merge_clause
+                Full Join:  Filter: t.j1_id = s.id
+                  Projection: t.j1_id, t.j1_string, row_number() ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING AS __merge_target_marker
+                    WindowAggr: windowExpr=[[row_number() ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING]]
+                      SubqueryAlias: t
+                        TableScan: j1
+                  Projection: s.id, Boolean(true) AS __merge_source_marker
+                    SubqueryAlias: s
+                      Projection: j2.j2_id AS id
+                        TableScan: j2
+        "#
+    );
+}
+
+#[rstest]
+#[case::missing_assignement_target(
+    "MERGE INTO j1 USING j2 ON j1_id = j2_id WHEN MATCHED THEN UPDATE SET doesnotexist = j2_string"
+)]
+#[case::missing_insert_column(
+    "MERGE INTO j1 USING j2 ON j1_id = j2_id WHEN NOT MATCHED THEN INSERT (doesnotexist) VALUES (j2_id)"
+)]
+#[case::missing_on_expression(
+    "MERGE INTO j1 USING j2 ON doesnotexist = j2_id WHEN MATCHED THEN DELETE"
+)]
+#[test]
+fn merge_column_does_not_exist(#[case] sql: &str) {
+    let err = logical_plan(sql).expect_err("query should have failed");
+    assert_field_not_found(err, "doesnotexist");
+}
+
 #[test]
 fn plan_delete() {
     let sql = "delete from person where id=1";
@@ -1050,6 +1117,46 @@ fn join_with_ambiguous_column() {
     );
 }
 
+#[test]
+fn asof_join() {
+    let sql = "SELECT p.id, o.order_id FROM person p \
+               ASOF JOIN orders o MATCH_CONDITION (p.age >= o.qty) ON p.id = o.customer_id";
+    let plan = logical_plan(sql).unwrap();
+    assert_snapshot!(
+        plan,
+        @r#"
+        Projection: p.id, o.order_id
+          AsOf Join: p.id = o.customer_id Filter: p.age >= o.qty
+            SubqueryAlias: p
+              TableScan: person
+            SubqueryAlias: o
+              TableScan: orders
+        "#
+    );
+}
+
+#[test]
+fn asof_join_non_equality_on_clause() {
+    let sql = "SELECT p.id FROM person p \
+               ASOF JOIN orders o MATCH_CONDITION (p.age >= o.qty) ON p.id > o.customer_id";
+    let err = logical_plan(sql).unwrap_err();
+    assert_snapshot!(
+        err.strip_backtrace(),
+        @"This feature is not implemented: ASOF join only supports equality conditions in the ON clause, got p.id > o.customer_id"
+    );
+}
+
+#[test]
+fn asof_join_invalid_match_condition() {
+    let sql = "SELECT p.id FROM person p \
+               ASOF JOIN orders o MATCH_CONDITION (p.age >= p.id) ON p.id = o.customer_id";
+    let err = logical_plan(sql).unwrap_err();
+    assert_snapshot!(
+        err.strip_backtrace(),
+        @"Error during planning: ASOF join match condition must compare an expression of the left input with an expression of the right input, got p.age >= p.id"
+    );
+}
+
 #[test]
 fn natural_left_join() {
     let sql = "SELECT l_item_id FROM lineitem a NATURAL LEFT JOIN lineitem b";
@@ -2293,6 +2400,73 @@ CreateExternalTable: Partial { schema: "staging", table: "foo" }
     );
 }
 
+#[test]
+fn create_materialized_view() {
+    let sql = "CREATE MATERIALIZED VIEW v AS SELECT id FROM person WHERE age > 20";
+    let plan = logical_plan(sql).unwrap();
+    assert_snapshot!(
+        plan,
+        @r#"
+    CreateMaterializedView: Bare { table: "v" }
+      Projection: person.id
+        Filter: person.age > Int64(20)
+          TableScan: person
+    "#
+    );
+
+    let sql = "CREATE MATERIALIZED VIEW v STORED AS PARQUET LOCATION 'foo/' AS SELECT id FROM person";
+    let plan = logical_plan(sql).unwrap();
+    assert_snapshot!(
+        plan,
+        @r#"
+    CreateMaterializedView: Bare { table: "v" } location=foo/
+      Projection: person.id
+        TableScan: person
+    "#
+    );
+}
+
+#[test]
+fn refresh_materialized_view() {
+    let sql = "REFRESH MATERIALIZED VIEW staging.v";
+    let plan = logical_plan(sql).unwrap();
+    assert_snapshot!(
+        plan,
+        @r#"RefreshMaterializedView: Partial { schema: "staging", table: "v" }"#
+    );
+}
+
+#[test]
+fn analyze_table() {
+    let sql = "ANALYZE TABLE person";
+    let plan = logical_plan(sql).unwrap();
+    assert_snapshot!(
+        plan,
+        @r#"AnalyzeTable: Bare { table: "person" } columns=[id, first_name, last_name, age, state, salary, birth_date, 😀]"#
+    );
+
+    let sql = "ANALYZE TABLE person COMPUTE STATISTICS FOR COLUMNS AGE, state, age";
+    let plan = logical_plan(sql).unwrap();
+    assert_snapshot!(
+        plan,
+        @r#"AnalyzeTable: Bare { table: "person" } columns=[age, state]"#
+    );
+
+    let sql = "ANALYZE TABLE person COMPUTE STATISTICS FOR COLUMNS height";
+    let err = logical_plan(sql).unwrap_err().strip_backtrace();
+    assert_snapshot!(
+        err,
+        @"Error during planning: Column 'height' not found in table 'person'"
+    );
+
+    let sql = "ANALYZE TABLE person COMPUTE STATISTICS NOSCAN";
+    let err = logical_plan(sql).unwrap_err().strip_backtrace();
+    assert_snapshot!(
+        err,
+        @"This feature is not implemented: ANALYZE TABLE with NOSCAN not supported"
+    );
+}
+
 #[test]
 fn create_schema_with_quoted_name() {
     let sql = "CREATE SCHEMA \"quoted_schema_name\"";
@@ -3037,6 +3211,63 @@ Projection: orders.order_id, max(orders.qty) PARTITION BY [orders.order_id] ORDE
     );
 }
 
+#[test]
+fn qualify_with_window_alias() {
+    let sql = "SELECT order_id, MAX(qty) OVER (PARTITION BY order_id) AS max_qty FROM orders QUALIFY max_qty > 10";
+    let plan = logical_plan(sql).unwrap();
+    assert_snapshot!(
+        plan,
+        @r#"
+Projection: orders.order_id, max(orders.qty) PARTITION BY [orders.order_id] ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING AS max_qty
+  Filter: max(orders.qty) PARTITION BY [orders.order_id] ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING > Int64(10)
+    WindowAggr: windowExpr=[[max(orders.qty) PARTITION BY [orders.order_id] ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING]]
+      TableScan: orders
+"#
+    );
+}
+
+#[test]
+fn qualify_with_window_not_in_select() {
+    let sql =
+        "SELECT order_id FROM orders QUALIFY MAX(qty) OVER (PARTITION BY order_id) > 10";
+    let plan = logical_plan(sql).unwrap();
+    assert_snapshot!(
+        plan,
+        @r#"
+Projection: orders.order_id
+  Filter: max(orders.qty) PARTITION BY [orders.order_id] ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING > Int64(10)
+    WindowAggr: windowExpr=[[max(orders.qty) PARTITION BY [orders.order_id] ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING]]
+      TableScan: orders
+"#
+    );
+}
+
+#[test]
+fn qualify_with_named_window() {
+    let sql = "SELECT order_id FROM orders QUALIFY MAX(qty) OVER w > 10 WINDOW w AS (PARTITION BY order_id)";
+    let plan = logical_plan(sql).unwrap();
+    assert_snapshot!(
+        plan,
+        @r#"
+Projection: orders.order_id
+  Filter: max(orders.qty) PARTITION BY [orders.order_id] ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING > Int64(10)
+    WindowAggr: windowExpr=[[max(orders.qty) PARTITION BY [orders.order_id] ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING]]
+      TableScan: orders
+"#
+    );
+}
+
+#[test]
+fn qualify_without_window_function() {
+    let sql = "SELECT order_id FROM orders QUALIFY order_id > 10";
+    let err = logical_plan(sql).expect_err("query should have failed");
+
+    assert_snapshot!(
+        err.strip_backtrace(),
+        @"Error during planning: QUALIFY clause requires window functions in the SELECT list or QUALIFY clause"
+    );
+}
+
 /// psql result
 /// ```text
 ///                               QUERY PLAN
@@ -3298,6 +3529,7 @@ fn logical_plan_with_dialect_and_options(
         .with_aggregate_function(max_udaf())
         .with_aggregate_function(grouping_udaf())
         .with_window_function(rank_udwf())
+        .with_window_function(row_number_udwf())
         .with_expr_planner(Arc::new(CoreFunctionPlanner::default()));
 
     let context = MockContextProvider { state };
@@ -4197,10 +4429,6 @@ fn test_select_distinct_order_by() {
     "SELECT id, number FROM person LATERAL VIEW explode(numbers) exploded_table AS number",
     "This feature is not implemented: LATERAL VIEWS"
 )]
-#[case::select_qualify_unsupported(
-    "SELECT i, p, o FROM person QUALIFY ROW_NUMBER() OVER (PARTITION BY p ORDER BY o) = 1",
-    "This feature is not implemented: QUALIFY"
-)]
 #[case::select_top_unsupported(
     "SELECT TOP (5) * FROM person",
     "This feature is not implemented: TOP"
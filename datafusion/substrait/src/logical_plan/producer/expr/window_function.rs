@@ -65,6 +65,13 @@ pub fn from_window_function(
         .map(|e| substrait_sort_field(producer, e, schema))
         .collect::<datafusion::common::Result<Vec<_>>>()?;
     // window frame
+    if window_frame.has_exclusion() {
+        // Substrait window functions have no notion of excluded frame rows
+        return not_impl_err!(
+            "Unsupported window frame exclusion: {}",
+            window_frame.exclusion
+        );
+    }
     let bounds = to_substrait_bounds(window_frame)?;
     let bound_type = to_substrait_bound_type(window_frame)?;
     Ok(make_substrait_window_function(
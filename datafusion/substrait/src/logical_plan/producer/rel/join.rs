@@ -30,6 +30,9 @@ pub fn from_join(
 ) -> datafusion::common::Result<Box<Rel>> {
     let left = producer.handle_plan(join.left.as_ref())?;
     let right = producer.handle_plan(join.right.as_ref())?;
+    if join.join_type == JoinType::AsOf {
+        return not_impl_err!("join type: `AsOf`");
+    }
     let join_type = to_substrait_jointype(join.join_type);
     // we only support basic joins so return an error for anything not yet supported
     match join.join_constraint {
@@ -115,7 +118,7 @@ fn to_substrait_jointype(join_type: JoinType) -> join_rel::JoinType {
         JoinType::LeftSemi => join_rel::JoinType::LeftSemi,
         JoinType::LeftMark => join_rel::JoinType::LeftMark,
         JoinType::RightMark => join_rel::JoinType::RightMark,
-        JoinType::RightAnti | JoinType::RightSemi => {
+        JoinType::RightAnti | JoinType::RightSemi | JoinType::AsOf => {
             unimplemented!()
         }
     }